@@ -0,0 +1,66 @@
+use backend_comparison::persistence::save;
+use burn::{
+    nn::attention::{MhaInput, MultiHeadAttention, MultiHeadAttentionConfig},
+    tensor::{backend::Backend, Distribution, Shape, Tensor},
+};
+use burn_common::benchmark::{run_benchmark, Benchmark};
+use derive_new::new;
+
+#[derive(new)]
+struct AttentionBenchmark<B: Backend> {
+    shape: Shape<3>,
+    n_heads: usize,
+    device: B::Device,
+}
+
+impl<B: Backend> Benchmark for AttentionBenchmark<B> {
+    type Args = (MultiHeadAttention<B>, Tensor<B, 3>);
+
+    fn name(&self) -> String {
+        "attention".into()
+    }
+
+    fn options(&self) -> Option<String> {
+        Some(format!("n_heads: {}", self.n_heads))
+    }
+
+    fn shapes(&self) -> Vec<Vec<usize>> {
+        vec![self.shape.dims.into()]
+    }
+
+    fn execute(&self, (mha, x): Self::Args) {
+        mha.forward(MhaInput::self_attn(x));
+    }
+
+    fn prepare(&self) -> Self::Args {
+        let [_, _, d_model] = self.shape.dims;
+        let mha = MultiHeadAttentionConfig::new(d_model, self.n_heads).init(&self.device);
+        let x = Tensor::random(self.shape.clone(), Distribution::Default, &self.device);
+
+        (mha, x)
+    }
+
+    fn sync(&self) {
+        B::sync(&self.device)
+    }
+}
+
+#[allow(dead_code)]
+fn bench<B: Backend>(device: &B::Device) {
+    let batch_size = 8;
+    let seq_length = 512;
+    let d_model = 512;
+    let n_heads = 8;
+
+    let benchmark = AttentionBenchmark::<B>::new(
+        [batch_size, seq_length, d_model].into(),
+        n_heads,
+        device.clone(),
+    );
+
+    save::<B>(vec![run_benchmark(benchmark)], device).unwrap();
+}
+
+fn main() {
+    backend_comparison::bench_on_backend!();
+}
@@ -0,0 +1,76 @@
+use backend_comparison::persistence::save;
+use burn::tensor::{
+    backend::Backend, module::conv2d, ops::ConvOptions, Distribution, Shape, Tensor,
+};
+use burn_common::benchmark::{run_benchmark, Benchmark};
+use derive_new::new;
+
+#[derive(new)]
+struct Conv2dBenchmark<B: Backend> {
+    input_shape: Shape<4>,
+    weight_shape: Shape<4>,
+    options: ConvOptions<2>,
+    device: B::Device,
+}
+
+impl<B: Backend> Benchmark for Conv2dBenchmark<B> {
+    type Args = (Tensor<B, 4>, Tensor<B, 4>, Tensor<B, 1>);
+
+    fn name(&self) -> String {
+        "conv2d".into()
+    }
+
+    fn shapes(&self) -> Vec<Vec<usize>> {
+        vec![self.input_shape.dims.into(), self.weight_shape.dims.into()]
+    }
+
+    fn execute(&self, (x, weight, bias): Self::Args) {
+        conv2d(x, weight, Some(bias), self.options.clone());
+    }
+
+    fn prepare(&self) -> Self::Args {
+        let x = Tensor::random(
+            self.input_shape.clone(),
+            Distribution::Default,
+            &self.device,
+        );
+        let weight = Tensor::random(
+            self.weight_shape.clone(),
+            Distribution::Default,
+            &self.device,
+        );
+        let bias = Tensor::random(
+            [self.weight_shape.dims[0]],
+            Distribution::Default,
+            &self.device,
+        );
+
+        (x, weight, bias)
+    }
+
+    fn sync(&self) {
+        B::sync(&self.device)
+    }
+}
+
+#[allow(dead_code)]
+fn bench<B: Backend>(device: &B::Device) {
+    let batch_size = 16;
+    let channels_in = 64;
+    let channels_out = 128;
+    let size = 64;
+    let kernel_size = 3;
+
+    let benchmark = Conv2dBenchmark::<B>::new(
+        [batch_size, channels_in, size, size].into(),
+        [channels_out, channels_in, kernel_size, kernel_size].into(),
+        ConvOptions::new([1, 1], [1, 1], [1, 1], 1),
+        device.clone(),
+    );
+
+    save::<B>(vec![run_benchmark(benchmark)], device).unwrap();
+}
+
+fn main() {
+    backend_comparison::bench_on_backend!();
+}
@@ -0,0 +1,104 @@
+use backend_comparison::persistence::save;
+use burn::{
+    module::Module,
+    nn::{Linear, LinearConfig, GELU},
+    tensor::{backend::Backend, Distribution, Shape, Tensor},
+};
+use burn_common::benchmark::{run_benchmark, Benchmark};
+use derive_new::new;
+
+#[derive(Module, Debug)]
+struct Mlp<B: Backend> {
+    layers: Vec<Linear<B>>,
+    activation: GELU,
+}
+
+impl<B: Backend> Mlp<B> {
+    fn new(d_model: usize, d_hidden: usize, num_layers: usize, device: &B::Device) -> Self {
+        let layers = (0..num_layers)
+            .flat_map(|_| {
+                [
+                    LinearConfig::new(d_model, d_hidden).init(device),
+                    LinearConfig::new(d_hidden, d_model).init(device),
+                ]
+            })
+            .collect();
+
+        Self {
+            layers,
+            activation: GELU::new(),
+        }
+    }
+
+    fn forward(&self, mut x: Tensor<B, 2>) -> Tensor<B, 2> {
+        for layer in self.layers.iter() {
+            x = self.activation.forward(layer.forward(x));
+        }
+
+        x
+    }
+}
+
+#[derive(new)]
+struct MlpBenchmark<B: Backend> {
+    shape: Shape<2>,
+    d_hidden: usize,
+    num_layers: usize,
+    device: B::Device,
+}
+
+impl<B: Backend> Benchmark for MlpBenchmark<B> {
+    type Args = (Mlp<B>, Tensor<B, 2>);
+
+    fn name(&self) -> String {
+        "mlp".into()
+    }
+
+    fn options(&self) -> Option<String> {
+        Some(format!(
+            "d_hidden: {}, num_layers: {}",
+            self.d_hidden, self.num_layers
+        ))
+    }
+
+    fn shapes(&self) -> Vec<Vec<usize>> {
+        vec![self.shape.dims.into()]
+    }
+
+    fn execute(&self, (mlp, x): Self::Args) {
+        mlp.forward(x);
+    }
+
+    fn prepare(&self) -> Self::Args {
+        let [_, d_model] = self.shape.dims;
+        let mlp = Mlp::new(d_model, self.d_hidden, self.num_layers, &self.device);
+        let x = Tensor::random(self.shape.clone(), Distribution::Default, &self.device);
+
+        (mlp, x)
+    }
+
+    fn sync(&self) {
+        B::sync(&self.device)
+    }
+}
+
+#[allow(dead_code)]
+fn bench<B: Backend>(device: &B::Device) {
+    let batch_size = 256;
+    let d_model = 1024;
+    let d_hidden = 4096;
+    let num_layers = 4;
+
+    let benchmark = MlpBenchmark::<B>::new(
+        [batch_size, d_model].into(),
+        d_hidden,
+        num_layers,
+        device.clone(),
+    );
+
+    save::<B>(vec![run_benchmark(benchmark)], device).unwrap();
+}
+
+fn main() {
+    backend_comparison::bench_on_backend!();
+}
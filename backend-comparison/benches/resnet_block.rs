@@ -0,0 +1,98 @@
+use backend_comparison::persistence::save;
+use burn::{
+    module::Module,
+    nn::{
+        conv::{Conv2d, Conv2dConfig},
+        BatchNorm, BatchNormConfig, PaddingConfig2d, ReLU,
+    },
+    tensor::{backend::Backend, Distribution, Shape, Tensor},
+};
+use burn_common::benchmark::{run_benchmark, Benchmark};
+use derive_new::new;
+
+/// Basic residual block of ResNet-18 and ResNet-34.
+#[derive(Module, Debug)]
+struct ResidualBlock<B: Backend> {
+    conv1: Conv2d<B>,
+    norm1: BatchNorm<B, 2>,
+    conv2: Conv2d<B>,
+    norm2: BatchNorm<B, 2>,
+    activation: ReLU,
+}
+
+impl<B: Backend> ResidualBlock<B> {
+    fn new(channels: usize, device: &B::Device) -> Self {
+        let conv = || {
+            Conv2dConfig::new([channels, channels], [3, 3])
+                .with_padding(PaddingConfig2d::Explicit(1, 1))
+                .with_bias(false)
+                .init(device)
+        };
+
+        Self {
+            conv1: conv(),
+            norm1: BatchNormConfig::new(channels).init(device),
+            conv2: conv(),
+            norm2: BatchNormConfig::new(channels).init(device),
+            activation: ReLU::new(),
+        }
+    }
+
+    fn forward(&self, input: Tensor<B, 4>) -> Tensor<B, 4> {
+        let x = self.conv1.forward(input.clone());
+        let x = self.activation.forward(self.norm1.forward(x));
+        let x = self.norm2.forward(self.conv2.forward(x));
+
+        self.activation.forward(x + input)
+    }
+}
+
+#[derive(new)]
+struct ResidualBlockBenchmark<B: Backend> {
+    shape: Shape<4>,
+    device: B::Device,
+}
+
+impl<B: Backend> Benchmark for ResidualBlockBenchmark<B> {
+    type Args = (ResidualBlock<B>, Tensor<B, 4>);
+
+    fn name(&self) -> String {
+        "resnet-block".into()
+    }
+
+    fn shapes(&self) -> Vec<Vec<usize>> {
+        vec![self.shape.dims.into()]
+    }
+
+    fn execute(&self, (block, x): Self::Args) {
+        block.forward(x);
+    }
+
+    fn prepare(&self) -> Self::Args {
+        let [_, channels, _, _] = self.shape.dims;
+        let block = ResidualBlock::new(channels, &self.device);
+        let x = Tensor::random(self.shape.clone(), Distribution::Default, &self.device);
+
+        (block, x)
+    }
+
+    fn sync(&self) {
+        B::sync(&self.device)
+    }
+}
+
+#[allow(dead_code)]
+fn bench<B: Backend>(device: &B::Device) {
+    let batch_size = 16;
+    let channels = 64;
+    let size = 56;
+
+    let benchmark =
+        ResidualBlockBenchmark::<B>::new([batch_size, channels, size, size].into(), device.clone());
+
+    save::<B>(vec![run_benchmark(benchmark)], device).unwrap();
+}
+
+fn main() {
+    backend_comparison::bench_on_backend!();
+}
@@ -1,5 +1,9 @@
-use crate::{grads::Gradients, graph::backward::backward, tensor::AutodiffTensor};
-use burn_tensor::backend::{AutodiffBackend, Backend};
+use crate::{
+    grads::Gradients,
+    graph::backward::{backward, backward_retain},
+    tensor::AutodiffTensor,
+};
+use burn_tensor::backend::{AutodiffBackend, Backend, GradHook};
 use core::marker::PhantomData;
 
 /// Enable auto-differentiation on a backend.
@@ -40,6 +44,10 @@ impl<B: Backend> Backend for Autodiff<B> {
     fn sync(device: &B::Device) {
         B::sync(device);
     }
+
+    fn non_deterministic_ops(device: &B::Device) -> &'static [&'static str] {
+        B::non_deterministic_ops(device)
+    }
 }
 
 impl<B: Backend> AutodiffBackend for Autodiff<B> {
@@ -50,6 +58,10 @@ impl<B: Backend> AutodiffBackend for Autodiff<B> {
         backward(tensor)
     }
 
+    fn backward_retain<const D: usize>(tensor: &AutodiffTensor<B, D>) -> Gradients {
+        backward_retain(tensor)
+    }
+
     fn grad<const D: usize>(
         tensor: &AutodiffTensor<B, D>,
         grads: &Gradients,
@@ -80,6 +92,17 @@ impl<B: Backend> AutodiffBackend for Autodiff<B> {
         grads.register::<B, D>(tensor.node.clone(), grad);
     }
 
+    fn no_grad<R, F: FnOnce() -> R>(func: F) -> R {
+        crate::no_grad(func)
+    }
+
+    fn register_grad_hook<const D: usize>(
+        tensor: AutodiffTensor<B, D>,
+        hook: GradHook<B, D>,
+    ) -> AutodiffTensor<B, D> {
+        tensor.register_hook(hook)
+    }
+
     fn int_inner<const D: usize>(
         tensor: burn_tensor::ops::IntTensor<Self, D>,
     ) -> burn_tensor::ops::IntTensor<Self::InnerBackend, D> {
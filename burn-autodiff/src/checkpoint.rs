@@ -0,0 +1,128 @@
+use crate::{
+    grads::Gradients,
+    graph::{
+        backward::backward_into, is_grad_enabled, with_grad_disabled_tracking_usage,
+        with_random_recorded, with_random_replayed, RandomTensor, Requirement,
+    },
+    ops::{Backward, Ops, OpsKind, OpsPrep},
+    tensor::AutodiffTensor,
+    Autodiff,
+};
+use burn_tensor::{backend::Backend, Tensor};
+use std::sync::Arc;
+
+/// Execute a function as a checkpointed scope.
+///
+/// The function is executed without registering any operation in the graph, so all the
+/// activations created inside the scope are freed as soon as the forward pass returns. Only the
+/// inputs are kept, and the function is executed again during the backward pass to recompute the
+/// activations before propagating the gradients through them. This trades compute for memory.
+///
+/// Tensors captured by the function, such as module parameters, receive their gradients as if the
+/// scope wasn't checkpointed, even when none of the inputs require gradients.
+///
+/// # Notes
+///
+/// The function is called twice, therefore it must be deterministic for the gradients to be
+/// correct:
+///
+/// - Random tensors (e.g. dropout masks) sampled during the forward pass are kept and
+///   [replayed](crate::replay_random) during the recomputation, so they are identical.
+/// - The inputs are kept by value, so the recomputation always sees the same inputs as the
+///   forward pass, even if the tensors are later used by in-place operations outside the scope.
+///   However, captured tensors are read again during the recomputation.
+pub fn checkpoint_scope<B, F, const D: usize, const N: usize>(
+    inputs: [Tensor<Autodiff<B>, D>; N],
+    func: F,
+) -> Tensor<Autodiff<B>, D>
+where
+    B: Backend,
+    F: Fn([Tensor<Autodiff<B>, D>; N]) -> Tensor<Autodiff<B>, D> + Send + Sync + 'static,
+{
+    let inputs = inputs.map(|tensor| tensor.into_primitive());
+    let nodes = inputs.clone().map(|tensor| tensor.node);
+    let graphs = inputs.clone().map(|tensor| tensor.graph);
+    let primitives = inputs.map(|tensor| tensor.primitive);
+
+    let ((output, captures_tracked), random) = with_random_recorded(|| {
+        with_grad_disabled_tracking_usage(|| {
+            let inputs = primitives
+                .clone()
+                .map(|primitive| Tensor::from_primitive(AutodiffTensor::new(primitive)));
+            func(inputs).into_primitive().primitive
+        })
+    });
+
+    // The inputs are untracked leaves inside the scope, so a tracked tensor used by the function
+    // is captured and the scope must be recomputed to compute its gradients.
+    let requirement = match captures_tracked && is_grad_enabled() {
+        true => Requirement::GradInBackward,
+        false => Requirement::from_nodes(&nodes),
+    };
+    let checkpoint = Checkpoint {
+        func: Arc::new(func),
+        random: Arc::new(random),
+    };
+
+    let output =
+        match OpsPrep::<_, B, _, D, N>::new(nodes, graphs, requirement, checkpoint).stateful() {
+            OpsKind::Tracked(prep) => prep.finish(primitives, output),
+            OpsKind::UnTracked(prep) => prep.finish(output),
+        };
+
+    Tensor::from_primitive(output)
+}
+
+struct Checkpoint<F> {
+    func: Arc<F>,
+    random: Arc<Vec<RandomTensor>>,
+}
+
+impl<F> Clone for Checkpoint<F> {
+    fn clone(&self) -> Self {
+        Self {
+            func: self.func.clone(),
+            random: self.random.clone(),
+        }
+    }
+}
+
+impl<F> core::fmt::Debug for Checkpoint<F> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("Checkpoint")
+    }
+}
+
+impl<B, F, const D: usize, const N: usize> Backward<B, D, N> for Checkpoint<F>
+where
+    B: Backend,
+    F: Fn([Tensor<Autodiff<B>, D>; N]) -> Tensor<Autodiff<B>, D> + Send + Sync + 'static,
+{
+    type State = [B::TensorPrimitive<D>; N];
+
+    fn backward(self, ops: Ops<Self::State, N>, grads: &mut Gradients) {
+        let grad = grads.consume::<B, D>(&ops.node);
+
+        // Fresh leaves are used for the inputs, so their gradients can be transferred to the
+        // parents once the recomputed graph is executed.
+        let inputs = ops
+            .state
+            .map(|primitive| AutodiffTensor::<B, D>::new(primitive).require_grad());
+        let output = with_random_replayed(self.random, || {
+            (self.func)(inputs.clone().map(Tensor::from_primitive))
+        })
+        .into_primitive();
+
+        if output.is_tracked() {
+            backward_into(output, grad, grads);
+        }
+
+        for (node, input) in ops.parents.into_iter().zip(inputs) {
+            let grad = grads.remove(&input);
+
+            if let (Some(node), Some(grad)) = (node, grad) {
+                grads.register::<B, D>(node, grad);
+            }
+        }
+    }
+}
@@ -0,0 +1,179 @@
+use crate::{graph::NodeID, Autodiff};
+use burn_tensor::{backend::Backend, Tensor};
+use core::fmt::Write;
+use std::collections::HashSet;
+
+/// Kind of a node in an [exported graph](GraphExport).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NodeKind {
+    /// Tensor marked as requiring gradients, such as a parameter.
+    Leaf,
+    /// Operation recorded in the graph, through which gradients flow.
+    Operation,
+    /// Tensor not recorded in the graph, no gradient flows through it.
+    Untracked,
+}
+
+/// Node of an [exported graph](GraphExport).
+#[derive(Debug, Clone)]
+pub struct NodeExport {
+    /// Unique identifier of the node.
+    pub id: u64,
+    /// Kind of the node.
+    pub kind: NodeKind,
+    /// Name of the operation that created the tensor.
+    pub name: &'static str,
+    /// Identifiers of the nodes used as inputs by the operation.
+    pub parents: Vec<u64>,
+    /// Shape of the tensor, unknown for untracked inputs.
+    pub shape: Option<Vec<usize>>,
+    /// Estimated number of bytes kept alive by the node until the backward pass.
+    pub retained_bytes: usize,
+}
+
+/// Autodiff graph exported for debugging, see [export_graph].
+#[derive(Debug, Clone)]
+pub struct GraphExport {
+    /// Nodes in topological order, from the leaves to the exported tensor.
+    pub nodes: Vec<NodeExport>,
+}
+
+/// Export the autodiff graph recorded up to the given tensor.
+///
+/// The graph is only inspected, so the backward pass can still be executed afterward. This is
+/// useful to understand where the memory retained for the backward pass goes, or why a parameter
+/// doesn't receive gradients, in which case there is no path from its [leaf](NodeKind::Leaf) node
+/// to the exported tensor.
+///
+/// # Example
+///
+/// ```rust, ignore
+/// let loss = model.forward(batch).loss;
+/// std::fs::write("graph.dot", burn_autodiff::export_graph(&loss).to_dot()).unwrap();
+/// ```
+pub fn export_graph<B: Backend, const D: usize>(tensor: &Tensor<Autodiff<B>, D>) -> GraphExport {
+    let tensor = tensor.clone().into_primitive();
+    let root = tensor.node;
+    let mut steps = tensor.graph.describe();
+    let mut nodes = Vec::new();
+    let mut visited = HashSet::new();
+    let mut to_visit = vec![root.id.clone()];
+
+    while let Some(id) = to_visit.pop() {
+        if !visited.insert(id.clone()) {
+            continue;
+        }
+
+        let (order, node) = match steps.remove(&id) {
+            Some((node, description)) => (
+                node.order,
+                NodeExport {
+                    id: id.value,
+                    kind: match node.parents.is_empty() {
+                        true => NodeKind::Leaf,
+                        false => NodeKind::Operation,
+                    },
+                    name: description.name,
+                    parents: node.parents.iter().map(|parent| parent.value).collect(),
+                    shape: Some(description.shape),
+                    retained_bytes: description.retained_bytes,
+                },
+            ),
+            None if id == root.id => (
+                root.order,
+                NodeExport {
+                    id: id.value,
+                    kind: NodeKind::Untracked,
+                    name: "Untracked",
+                    parents: root.parents.iter().map(|parent| parent.value).collect(),
+                    shape: Some(B::shape(&tensor.primitive).dims.to_vec()),
+                    retained_bytes: 0,
+                },
+            ),
+            None => (
+                0,
+                NodeExport {
+                    id: id.value,
+                    kind: NodeKind::Untracked,
+                    name: "Untracked",
+                    parents: Vec::new(),
+                    shape: None,
+                    retained_bytes: 0,
+                },
+            ),
+        };
+
+        to_visit.extend(node.parents.iter().map(|value| NodeID { value: *value }));
+        nodes.push((order, node));
+    }
+
+    nodes.sort_by_key(|(order, node)| (*order, node.id));
+
+    GraphExport {
+        nodes: nodes.into_iter().map(|(_, node)| node).collect(),
+    }
+}
+
+impl GraphExport {
+    /// Estimated number of bytes kept alive by the graph until the backward pass.
+    pub fn retained_bytes(&self) -> usize {
+        self.nodes.iter().map(|node| node.retained_bytes).sum()
+    }
+
+    /// Format the graph in the DOT language, which can be rendered with Graphviz.
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::from("digraph autodiff {\n    node [shape=box];\n");
+
+        for node in self.nodes.iter() {
+            let mut label = node.name.to_string();
+            if let Some(shape) = &node.shape {
+                write!(label, "\\n{:?}", shape).unwrap();
+            }
+            if node.retained_bytes > 0 {
+                write!(label, "\\nretained: {} B", node.retained_bytes).unwrap();
+            }
+            let style = match node.kind {
+                NodeKind::Leaf => ", style=filled",
+                NodeKind::Operation => "",
+                NodeKind::Untracked => ", style=dashed",
+            };
+
+            writeln!(dot, "    n{} [label=\"{}\"{}];", node.id, label, style).unwrap();
+        }
+
+        for node in self.nodes.iter() {
+            for parent in node.parents.iter() {
+                writeln!(dot, "    n{} -> n{};", parent, node.id).unwrap();
+            }
+        }
+
+        dot.push('}');
+        dot
+    }
+
+    /// Format the graph as JSON.
+    pub fn to_json(&self) -> String {
+        let nodes = self
+            .nodes
+            .iter()
+            .map(|node| {
+                let shape = match &node.shape {
+                    Some(shape) => format!("{:?}", shape),
+                    None => "null".to_string(),
+                };
+
+                format!(
+                    "{{\"id\":{},\"kind\":\"{:?}\",\"name\":\"{}\",\"parents\":{:?},\"shape\":{},\"retained_bytes\":{}}}",
+                    node.id,
+                    node.kind,
+                    node.name,
+                    node.parents,
+                    shape,
+                    node.retained_bytes
+                )
+            })
+            .collect::<Vec<_>>();
+
+        format!("{{\"nodes\":[{}]}}", nodes.join(","))
+    }
+}
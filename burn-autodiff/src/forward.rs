@@ -0,0 +1,286 @@
+use burn_tensor::{backend::Backend, Tensor};
+
+/// Tensor carrying a primal value along with its tangent, used for forward mode automatic
+/// differentiation.
+///
+/// Each operation computes the output primal and propagates the tangent using the chain rule,
+/// so the [Jacobian-vector product](jvp) of a function is obtained in a single forward pass,
+/// without building any graph.
+#[derive(Clone, Debug)]
+pub struct DualTensor<B: Backend, const D: usize> {
+    primal: Tensor<B, D>,
+    tangent: Tensor<B, D>,
+}
+
+impl<B: Backend, const D: usize> DualTensor<B, D> {
+    /// Create a new dual tensor from a primal value and its tangent.
+    ///
+    /// # Panics
+    ///
+    /// If the primal and the tangent don't have the same shape.
+    pub fn new(primal: Tensor<B, D>, tangent: Tensor<B, D>) -> Self {
+        assert_eq!(
+            primal.shape(),
+            tangent.shape(),
+            "The tangent should have the same shape as the primal."
+        );
+
+        Self { primal, tangent }
+    }
+
+    /// Create a dual tensor with a zero tangent, which is a constant with respect to the inputs.
+    pub fn constant(primal: Tensor<B, D>) -> Self {
+        let tangent = primal.zeros_like();
+
+        Self { primal, tangent }
+    }
+
+    /// The primal value.
+    pub fn primal(&self) -> Tensor<B, D> {
+        self.primal.clone()
+    }
+
+    /// The tangent value.
+    pub fn tangent(&self) -> Tensor<B, D> {
+        self.tangent.clone()
+    }
+
+    /// Consume the dual tensor and returns the primal and the tangent.
+    pub fn into_parts(self) -> (Tensor<B, D>, Tensor<B, D>) {
+        (self.primal, self.tangent)
+    }
+
+    /// Applies element wise addition operation.
+    #[allow(clippy::should_implement_trait)]
+    pub fn add(self, other: Self) -> Self {
+        Self {
+            primal: self.primal.add(other.primal),
+            tangent: self.tangent.add(other.tangent),
+        }
+    }
+
+    /// Applies element wise addition operation with a scalar.
+    pub fn add_scalar(self, other: f32) -> Self {
+        Self {
+            primal: self.primal.add_scalar(other),
+            tangent: self.tangent,
+        }
+    }
+
+    /// Applies element wise subtraction operation.
+    #[allow(clippy::should_implement_trait)]
+    pub fn sub(self, other: Self) -> Self {
+        Self {
+            primal: self.primal.sub(other.primal),
+            tangent: self.tangent.sub(other.tangent),
+        }
+    }
+
+    /// Applies element wise subtraction operation with a scalar.
+    pub fn sub_scalar(self, other: f32) -> Self {
+        Self {
+            primal: self.primal.sub_scalar(other),
+            tangent: self.tangent,
+        }
+    }
+
+    /// Applies element wise multiplication operation.
+    #[allow(clippy::should_implement_trait)]
+    pub fn mul(self, other: Self) -> Self {
+        let tangent = self
+            .tangent
+            .mul(other.primal.clone())
+            .add(other.tangent.mul(self.primal.clone()));
+
+        Self {
+            primal: self.primal.mul(other.primal),
+            tangent,
+        }
+    }
+
+    /// Applies element wise multiplication operation with a scalar.
+    pub fn mul_scalar(self, other: f32) -> Self {
+        Self {
+            primal: self.primal.mul_scalar(other),
+            tangent: self.tangent.mul_scalar(other),
+        }
+    }
+
+    /// Applies element wise division operation.
+    #[allow(clippy::should_implement_trait)]
+    pub fn div(self, other: Self) -> Self {
+        let primal = self.primal.div(other.primal.clone());
+        let tangent = self
+            .tangent
+            .sub(other.tangent.mul(primal.clone()))
+            .div(other.primal);
+
+        Self { primal, tangent }
+    }
+
+    /// Applies element wise division operation with a scalar.
+    pub fn div_scalar(self, other: f32) -> Self {
+        Self {
+            primal: self.primal.div_scalar(other),
+            tangent: self.tangent.div_scalar(other),
+        }
+    }
+
+    /// Applies the negation operation.
+    #[allow(clippy::should_implement_trait)]
+    pub fn neg(self) -> Self {
+        Self {
+            primal: self.primal.neg(),
+            tangent: self.tangent.neg(),
+        }
+    }
+
+    /// Applies the matrix multiplication operation.
+    pub fn matmul(self, other: Self) -> Self {
+        let tangent = self
+            .tangent
+            .matmul(other.primal.clone())
+            .add(self.primal.clone().matmul(other.tangent));
+
+        Self {
+            primal: self.primal.matmul(other.primal),
+            tangent,
+        }
+    }
+
+    /// Applies element wise exponential operation.
+    pub fn exp(self) -> Self {
+        let primal = self.primal.exp();
+        let tangent = self.tangent.mul(primal.clone());
+
+        Self { primal, tangent }
+    }
+
+    /// Applies element wise natural log operation.
+    pub fn log(self) -> Self {
+        Self {
+            tangent: self.tangent.div(self.primal.clone()),
+            primal: self.primal.log(),
+        }
+    }
+
+    /// Applies element wise power operation with a float scalar.
+    pub fn powf(self, value: f32) -> Self {
+        let tangent = self
+            .tangent
+            .mul(self.primal.clone().powf(value - 1.0).mul_scalar(value));
+
+        Self {
+            primal: self.primal.powf(value),
+            tangent,
+        }
+    }
+
+    /// Applies element wise square root operation.
+    pub fn sqrt(self) -> Self {
+        let primal = self.primal.sqrt();
+        let tangent = self.tangent.div(primal.clone().mul_scalar(2.0));
+
+        Self { primal, tangent }
+    }
+
+    /// Applies element wise sine operation.
+    pub fn sin(self) -> Self {
+        Self {
+            tangent: self.tangent.mul(self.primal.clone().cos()),
+            primal: self.primal.sin(),
+        }
+    }
+
+    /// Applies element wise cosine operation.
+    pub fn cos(self) -> Self {
+        Self {
+            tangent: self.tangent.mul(self.primal.clone().sin().neg()),
+            primal: self.primal.cos(),
+        }
+    }
+
+    /// Applies element wise hyperbolic tangent operation.
+    pub fn tanh(self) -> Self {
+        let primal = self.primal.tanh();
+        let tangent = self
+            .tangent
+            .mul(primal.clone().powf(2.0).neg().add_scalar(1.0));
+
+        Self { primal, tangent }
+    }
+
+    /// Applies the rectified linear unit function.
+    pub fn relu(self) -> Self {
+        let mask = self.primal.clone().lower_equal_elem(0.0);
+
+        Self {
+            primal: self.primal.mask_fill(mask.clone(), 0.0),
+            tangent: self.tangent.mask_fill(mask, 0.0),
+        }
+    }
+
+    /// Sum all elements.
+    pub fn sum(self) -> DualTensor<B, 1> {
+        DualTensor {
+            primal: self.primal.sum(),
+            tangent: self.tangent.sum(),
+        }
+    }
+
+    /// Sum all elements along the given dimension.
+    pub fn sum_dim(self, dim: usize) -> Self {
+        Self {
+            primal: self.primal.sum_dim(dim),
+            tangent: self.tangent.sum_dim(dim),
+        }
+    }
+
+    /// Mean of all elements.
+    pub fn mean(self) -> DualTensor<B, 1> {
+        DualTensor {
+            primal: self.primal.mean(),
+            tangent: self.tangent.mean(),
+        }
+    }
+
+    /// Mean of all elements along the given dimension.
+    pub fn mean_dim(self, dim: usize) -> Self {
+        Self {
+            primal: self.primal.mean_dim(dim),
+            tangent: self.tangent.mean_dim(dim),
+        }
+    }
+
+    /// Reshape the tensor to have the given shape.
+    pub fn reshape<const D2: usize>(self, shape: [usize; D2]) -> DualTensor<B, D2> {
+        DualTensor {
+            primal: self.primal.reshape(shape),
+            tangent: self.tangent.reshape(shape),
+        }
+    }
+
+    /// Transpose the tensor.
+    pub fn transpose(self) -> Self {
+        Self {
+            primal: self.primal.transpose(),
+            tangent: self.tangent.transpose(),
+        }
+    }
+}
+
+/// Compute the Jacobian-vector product of the function `func` at `primal` in the direction of
+/// `tangent`, using forward mode automatic differentiation.
+///
+/// Returns the output of the function along with the Jacobian-vector product.
+pub fn jvp<B, F, const D_IN: usize, const D_OUT: usize>(
+    func: F,
+    primal: Tensor<B, D_IN>,
+    tangent: Tensor<B, D_IN>,
+) -> (Tensor<B, D_OUT>, Tensor<B, D_OUT>)
+where
+    B: Backend,
+    F: FnOnce(DualTensor<B, D_IN>) -> DualTensor<B, D_OUT>,
+{
+    func(DualTensor::new(primal, tangent)).into_parts()
+}
@@ -0,0 +1,151 @@
+#![allow(clippy::single_range_in_vec_init)]
+
+use crate::Autodiff;
+use alloc::vec::Vec;
+use burn_tensor::{
+    backend::{AutodiffBackend, Backend},
+    Tensor,
+};
+
+/// Transform a scalar function into a function returning its gradient with respect to its input.
+///
+/// The input is detached from any existing graph before being tracked, so the gradients are
+/// never mixed with the ones of an outer computation.
+///
+/// # Example
+///
+/// ```rust, ignore
+/// let grad_fn = burn_autodiff::grad(|x: Tensor<B, 1>| x.clone().mul(x).sum());
+/// let grad = grad_fn(input); // 2 * input
+/// ```
+pub fn grad<B, F, const D: usize>(func: F) -> impl Fn(Tensor<B, D>) -> Tensor<B::InnerBackend, D>
+where
+    B: AutodiffBackend,
+    F: Fn(Tensor<B, D>) -> Tensor<B, 1>,
+{
+    move |input| value_and_grad(&func, input).1
+}
+
+/// Compute the output of a scalar function along with its gradient with respect to the input.
+pub fn value_and_grad<B, F, const D: usize>(
+    func: F,
+    input: Tensor<B, D>,
+) -> (Tensor<B::InnerBackend, 1>, Tensor<B::InnerBackend, D>)
+where
+    B: AutodiffBackend,
+    F: FnOnce(Tensor<B, D>) -> Tensor<B, 1>,
+{
+    let input = input.detach().require_grad();
+    let output = func(input.clone());
+    let grads = output.backward();
+    let grad = grad_or_zeros(&input, &grads);
+
+    (output.inner(), grad)
+}
+
+/// Compute the output of a function along with a closure evaluating the vector-Jacobian product
+/// for a given cotangent.
+///
+/// The closure consumes the graph, so it can only be called once.
+#[allow(clippy::type_complexity)]
+pub fn vjp<B, F, const D_IN: usize, const D_OUT: usize>(
+    func: F,
+    input: Tensor<B, D_IN>,
+) -> (
+    Tensor<B::InnerBackend, D_OUT>,
+    impl FnOnce(Tensor<B::InnerBackend, D_OUT>) -> Tensor<B::InnerBackend, D_IN>,
+)
+where
+    B: AutodiffBackend,
+    F: FnOnce(Tensor<B, D_IN>) -> Tensor<B, D_OUT>,
+{
+    let input = input.detach().require_grad();
+    let output = func(input.clone());
+    let value = output.clone().inner();
+
+    let pullback = move |cotangent: Tensor<B::InnerBackend, D_OUT>| {
+        let grads = output.mul(Tensor::from_inner(cotangent)).sum().backward();
+        grad_or_zeros(&input, &grads)
+    };
+
+    (value, pullback)
+}
+
+/// Compute the Jacobian matrix of a function at the given input.
+///
+/// The input and the output are flattened, so the Jacobian has the shape
+/// `[num_elements(output), num_elements(input)]`, where each row is the gradient of an output
+/// element with respect to the input.
+///
+/// The function is executed only once, the graph being [retained](Tensor::backward_retain)
+/// between the backward passes of each output element.
+pub fn jacobian<B, F, const D_IN: usize, const D_OUT: usize>(
+    func: F,
+    input: Tensor<B, D_IN>,
+) -> Tensor<B::InnerBackend, 2>
+where
+    B: AutodiffBackend,
+    F: FnOnce(Tensor<B, D_IN>) -> Tensor<B, D_OUT>,
+{
+    let input = input.detach().require_grad();
+    let output = func(input.clone());
+    let num_inputs = input.shape().num_elements();
+    let num_outputs = output.shape().num_elements();
+    let output = output.reshape([num_outputs]);
+
+    let rows = (0..num_outputs)
+        .map(|index| {
+            let element = output.clone().slice([index..index + 1]);
+            let grads = match index + 1 == num_outputs {
+                true => element.backward(),
+                false => element.backward_retain(),
+            };
+
+            grad_or_zeros(&input, &grads).reshape([1, num_inputs])
+        })
+        .collect::<Vec<_>>();
+
+    Tensor::cat(rows, 0)
+}
+
+/// Compute the Hessian-vector product of a scalar function at the given input, without
+/// materializing the Hessian.
+///
+/// The function is differentiated twice in reverse mode, so it is executed on a nested autodiff
+/// backend (`Autodiff<Autodiff<B>>`).
+///
+/// # Example
+///
+/// ```rust, ignore
+/// // The Hessian of sum(x³) is diag(6x), so the product is 6 * x * v.
+/// let product = burn_autodiff::hvp(|x| x.clone().mul(x.clone()).mul(x).sum(), input, vector);
+/// ```
+pub fn hvp<B, F, const D: usize>(func: F, input: Tensor<B, D>, vector: Tensor<B, D>) -> Tensor<B, D>
+where
+    B: Backend,
+    F: FnOnce(Tensor<Autodiff<Autodiff<B>>, D>) -> Tensor<Autodiff<Autodiff<B>>, 1>,
+{
+    let input_inner = Tensor::<Autodiff<B>, D>::from_inner(input).require_grad();
+    let input = Tensor::<Autodiff<Autodiff<B>>, D>::from_inner(input_inner.clone()).require_grad();
+
+    let grads = func(input.clone()).backward();
+    let grad = grad_or_zeros(&input, &grads);
+
+    // The gradient only depends on the input when the function isn't linear.
+    let product = grad.mul(Tensor::from_inner(vector)).sum();
+    if !product.clone().into_primitive().is_tracked() {
+        return input_inner.inner().zeros_like();
+    }
+
+    let grads = product.backward();
+    grad_or_zeros(&input_inner, &grads)
+}
+
+fn grad_or_zeros<B: AutodiffBackend, const D: usize>(
+    input: &Tensor<B, D>,
+    grads: &B::Gradients,
+) -> Tensor<B::InnerBackend, D> {
+    input
+        .grad(grads)
+        .unwrap_or_else(|| input.clone().inner().zeros_like())
+}
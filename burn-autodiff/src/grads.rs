@@ -1,9 +1,11 @@
-use burn_tensor::{backend::Backend, container::TensorContainer, Tensor};
+use burn_tensor::{backend::Backend, container::TensorContainer, ElementConversion, Tensor};
 
 use crate::{
-    graph::{NodeRef, Requirement},
+    graph::{NodeRef, OpProvenanceRef, Requirement},
     tensor::AutodiffTensor,
+    GradientPipeline,
 };
+use std::{any::Any, sync::Arc};
 
 /// Gradient identifier.
 pub type GradID = u64;
@@ -11,6 +13,8 @@ pub type GradID = u64;
 /// Gradients container used during the backward pass.
 pub struct Gradients {
     container: TensorContainer<GradID>,
+    provenance: Option<OpProvenanceRef>,
+    pipeline: Option<Arc<dyn Any + Send + Sync>>,
 }
 
 type TensorPrimitive<B, const D: usize> = <B as Backend>::TensorPrimitive<D>;
@@ -23,6 +27,8 @@ impl Gradients {
     ) -> Self {
         let mut gradients = Self {
             container: TensorContainer::new(),
+            provenance: None,
+            pipeline: None,
         };
         gradients.register::<B, D>(
             root_node,
@@ -71,6 +77,42 @@ impl Gradients {
             .map(|tensor| tensor.into_primitive())
     }
 
+    /// Apply a function on the grad tensor of the given node, if it is registered.
+    pub(crate) fn map<B: Backend, const D: usize, F>(&mut self, node: &NodeRef, func: F)
+    where
+        F: FnOnce(TensorPrimitive<B, D>) -> TensorPrimitive<B, D>,
+    {
+        if let Some(tensor) = self.container.remove::<B, D>(&node.id.value) {
+            let tensor = func(tensor.into_primitive());
+            self.container
+                .register::<B, D>(node.id.value, Tensor::from_primitive(tensor));
+        }
+    }
+
+    /// Set the [gradient pipeline](GradientPipeline) applied on the gradients of the leaves,
+    /// returning the previous one.
+    pub(crate) fn set_pipeline(
+        &mut self,
+        pipeline: Option<Arc<dyn Any + Send + Sync>>,
+    ) -> Option<Arc<dyn Any + Send + Sync>> {
+        core::mem::replace(&mut self.pipeline, pipeline)
+    }
+
+    /// Apply the [gradient pipeline](GradientPipeline) on the gradient of a leaf node, which
+    /// must be fully accumulated.
+    pub(crate) fn transform_leaf<B: Backend, const D: usize>(&mut self, node: &NodeRef) {
+        let pipeline = match &self.pipeline {
+            Some(pipeline) => pipeline.clone(),
+            None => return,
+        };
+
+        // The pipeline is only applied on the backend it was created for, which matters when
+        // autodiff backends are nested.
+        if let Some(pipeline) = pipeline.downcast_ref::<GradientPipeline<B>>() {
+            self.map::<B, D, _>(node, |grad| pipeline.apply(grad));
+        }
+    }
+
     /// Register a grad tensor in the container.
     ///
     /// If the tensor already exists, add both tensors together before saving the result.
@@ -79,6 +121,10 @@ impl Gradients {
         node: NodeRef,
         value: TensorPrimitive<B, D>,
     ) {
+        if let Some(provenance) = &self.provenance {
+            check_finite::<B, D>(provenance, &node, &value);
+        }
+
         if let Some(tensor_old) = self.container.remove::<B, D>(&node.id.value) {
             self.container
                 .register(node.id.value, Tensor::from_primitive(value).add(tensor_old));
@@ -87,4 +133,37 @@ impl Gradients {
                 .register::<B, D>(node.id.value, Tensor::from_primitive(value));
         }
     }
+
+    /// Execute the backward function of an operation, validating that all the gradients it
+    /// registers are finite.
+    pub(crate) fn with_provenance<F: FnOnce(&mut Self)>(
+        &mut self,
+        provenance: OpProvenanceRef,
+        func: F,
+    ) {
+        let previous = self.provenance.replace(provenance);
+        func(self);
+        self.provenance = previous;
+    }
+}
+
+fn check_finite<B: Backend, const D: usize>(
+    provenance: &OpProvenanceRef,
+    node: &NodeRef,
+    value: &TensorPrimitive<B, D>,
+) {
+    // NaN and infinite values propagate through the sum, so a single scalar is read back.
+    // Backends that can only read data asynchronously are skipped.
+    let sum = match B::into_data(B::sum(value.clone())).read_sync() {
+        Some(data) => data.value[0].elem::<f64>(),
+        None => return,
+    };
+
+    if !sum.is_finite() {
+        panic!(
+            "Anomaly detected: the backward pass of `{}` produced non-finite gradients for node \
+             {}.\n\nThe operation was executed at:\n{}",
+            provenance.name, node.id.value, provenance.backtrace
+        );
+    }
 }
@@ -0,0 +1,30 @@
+use std::{backtrace::Backtrace, sync::Arc};
+
+use super::is_anomaly_detection_enabled;
+
+/// Provenance of the forward operation that created a node, recorded when anomaly detection is
+/// enabled.
+#[derive(Debug)]
+pub struct OpProvenance {
+    /// Name of the operation.
+    pub name: &'static str,
+    /// Backtrace captured when the operation was executed.
+    pub backtrace: Backtrace,
+}
+
+/// Shared reference to the [provenance](OpProvenance) of an operation.
+pub type OpProvenanceRef = Arc<OpProvenance>;
+
+impl OpProvenance {
+    /// Record the provenance of the operation `O` if anomaly detection is enabled.
+    pub fn capture<O>() -> Option<OpProvenanceRef> {
+        if !is_anomaly_detection_enabled() {
+            return None;
+        }
+
+        Some(Arc::new(Self {
+            name: core::any::type_name::<O>(),
+            backtrace: Backtrace::force_capture(),
+        }))
+    }
+}
@@ -1,27 +1,64 @@
 use burn_tensor::backend::Backend;
 
 use crate::{grads::Gradients, tensor::AutodiffTensor};
+use std::{any::Any, sync::Arc};
 
-use super::{traversal::BreadthFirstSearch, Graph, NodeRef, StepBoxed};
+use super::{traversal::BreadthFirstSearch, NodeRef, NodeSteps, StepBoxed};
 
 pub fn backward<B: Backend, const D: usize>(root: AutodiffTensor<B, D>) -> Gradients {
     let grads = Gradients::new::<B, D>(root.node.clone(), root.primitive);
-    let tape = build_tape(root.node, root.graph);
+    let tape = build_tape(root.node, root.graph.steps());
 
     execute_steps(tape, grads)
 }
 
-fn build_tape(root: NodeRef, graph: Graph) -> Vec<Vec<StepBoxed>> {
-    let mut tape = (0..root.order)
+/// Execute the backward pass, applying the [gradient pipeline](crate::GradientPipeline) on the
+/// gradients of the leaves.
+pub fn backward_with_pipeline<B: Backend, const D: usize>(
+    root: AutodiffTensor<B, D>,
+    pipeline: Arc<dyn Any + Send + Sync>,
+) -> Gradients {
+    let mut grads = Gradients::new::<B, D>(root.node.clone(), root.primitive);
+    grads.set_pipeline(Some(pipeline));
+    let tape = build_tape(root.node, root.graph.steps());
+
+    execute_steps(tape, grads)
+}
+
+/// Execute the backward pass without consuming the graph, so it can be executed again from the
+/// same or another root sharing the same subgraph.
+pub fn backward_retain<B: Backend, const D: usize>(root: &AutodiffTensor<B, D>) -> Gradients {
+    let grads = Gradients::new::<B, D>(root.node.clone(), root.primitive.clone());
+    let tape = build_tape(root.node.clone(), root.graph.steps_retained());
+
+    execute_steps(tape, grads)
+}
+
+/// Execute the backward pass from the given root using the provided gradient, accumulating the
+/// gradients into an existing container.
+pub fn backward_into<B: Backend, const D: usize>(
+    root: AutodiffTensor<B, D>,
+    grad: B::TensorPrimitive<D>,
+    grads: &mut Gradients,
+) {
+    grads.register::<B, D>(root.node.clone(), grad);
+    let tape = build_tape(root.node, root.graph.steps());
+
+    // The leaves of a nested graph aren't leaves of the outer graph, so their gradients must not
+    // be transformed.
+    let pipeline = grads.set_pipeline(None);
+    execute_steps_mut(tape, grads);
+    grads.set_pipeline(pipeline);
+}
+
+fn build_tape(root: NodeRef, steps: NodeSteps) -> Vec<Vec<StepBoxed>> {
+    // Leaf steps (order 0) are kept, since gradient hooks may be registered on them.
+    let mut tape = (0..=root.order)
         .map(|_| Vec::with_capacity(1))
         .collect::<Vec<_>>();
 
-    BreadthFirstSearch.traverse(root, graph, |node, step| {
-        if node.order == 0 {
-            return;
-        }
-
-        if let Some(steps) = tape.get_mut(node.order - 1) {
+    BreadthFirstSearch.traverse(root, steps, |node, step| {
+        if let Some(steps) = tape.get_mut(node.order) {
             steps.push(step)
         };
     });
@@ -30,8 +67,12 @@ fn build_tape(root: NodeRef, graph: Graph) -> Vec<Vec<StepBoxed>> {
 }
 
 fn execute_steps(tape: Vec<Vec<StepBoxed>>, mut grads: Gradients) -> Gradients {
+    execute_steps_mut(tape, &mut grads);
+    grads
+}
+
+fn execute_steps_mut(tape: Vec<Vec<StepBoxed>>, grads: &mut Gradients) {
     tape.into_iter()
         .rev()
-        .for_each(|steps| steps.into_iter().for_each(|step| step.step(&mut grads)));
-    grads
+        .for_each(|steps| steps.into_iter().for_each(|step| step.step(grads)));
 }
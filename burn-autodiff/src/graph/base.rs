@@ -11,6 +11,21 @@ pub trait Step: Send + Sync + std::fmt::Debug {
     fn step(self: Box<Self>, grads: &mut Gradients);
     /// The node associated to the step.
     fn node(&self) -> NodeRef;
+    /// Clone the step, used when the graph is retained for multiple backward passes.
+    fn clone_step(&self) -> StepBoxed;
+    /// Describe the step, used when the graph is exported.
+    fn describe(&self) -> StepDescription;
+}
+
+/// Description of a [step](Step), used when the graph is exported.
+#[derive(new, Debug, Clone)]
+pub struct StepDescription {
+    /// Name of the operation that registered the step.
+    pub name: &'static str,
+    /// Shape of the tensor produced by the operation.
+    pub shape: Vec<usize>,
+    /// Estimated number of bytes kept alive by the step until it is executed.
+    pub retained_bytes: usize,
 }
 
 pub type StepBoxed = Box<dyn Step>;
@@ -47,6 +62,27 @@ impl Graph {
         map_drain
     }
 
+    /// Get a copy of all the steps for the graph, keeping the graph intact.
+    ///
+    /// This is useful when the graph needs to be executed multiple times, at the cost of keeping
+    /// all the tensors alive until the graph is consumed.
+    pub fn steps_retained(&self) -> NodeSteps {
+        self.steps
+            .lock()
+            .iter()
+            .map(|(id, step)| (id.clone(), step.clone_step()))
+            .collect()
+    }
+
+    /// Describe all the steps of the graph without consuming them.
+    pub fn describe(&self) -> HashMap<NodeID, (NodeRef, StepDescription)> {
+        self.steps
+            .lock()
+            .iter()
+            .map(|(id, step)| (id.clone(), (step.node(), step.describe())))
+            .collect()
+    }
+
     /// Register a new step into the graph.
     pub fn register(self, id: &NodeID, ops: StepBoxed) -> Self {
         self.execute_mut(|map| {
@@ -54,6 +90,15 @@ impl Graph {
         })
     }
 
+    /// Replace the step registered for the given node, if any.
+    pub fn map_step<F: FnOnce(StepBoxed) -> StepBoxed>(self, id: &NodeID, func: F) -> Self {
+        self.execute_mut(|map| {
+            if let Some(step) = map.remove(id) {
+                map.insert(id.clone(), func(step));
+            }
+        })
+    }
+
     /// Merge two graphs.
     pub fn merge(self, other: Self) -> Self {
         if Arc::ptr_eq(&self.steps, &other.steps) {
@@ -1,10 +1,16 @@
+mod anomaly;
 mod base;
+mod mode;
 mod node;
+mod random;
 mod requirement;
 
 pub mod backward;
 pub mod traversal;
 
+pub use anomaly::*;
 pub use base::*;
+pub use mode::*;
 pub use node::*;
+pub use random::*;
 pub use requirement::*;
@@ -0,0 +1,63 @@
+use std::cell::Cell;
+use std::thread::LocalKey;
+
+std::thread_local! {
+    static GRAD_ENABLED: Cell<bool> = const { Cell::new(true) };
+    static ANOMALY_DETECTION: Cell<bool> = const { Cell::new(false) };
+    static TRACKED_USED: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Returns true if operations executed on the current thread should be registered in the graph.
+pub fn is_grad_enabled() -> bool {
+    GRAD_ENABLED.with(|enabled| enabled.get())
+}
+
+/// Execute the function without registering any operation in the graph.
+///
+/// The previous mode is restored even if the function panics.
+pub fn with_grad_disabled<R, F: FnOnce() -> R>(func: F) -> R {
+    with_flag(&GRAD_ENABLED, false, func)
+}
+
+/// Execute the function without registering any operation in the graph, also returning whether
+/// it executed an operation on a tensor requiring gradients.
+pub fn with_grad_disabled_tracking_usage<R, F: FnOnce() -> R>(func: F) -> (R, bool) {
+    let previous = TRACKED_USED.with(|used| used.replace(false));
+    let output = with_grad_disabled(func);
+    // Enclosing calls also see the tracked tensors used by this one.
+    let used = TRACKED_USED.with(|used| used.replace(previous || used.get()));
+
+    (output, used)
+}
+
+/// Record that an operation was executed on a tensor requiring gradients while the graph mode
+/// is disabled.
+pub(crate) fn mark_tracked_used() {
+    TRACKED_USED.with(|used| used.set(true));
+}
+
+/// Returns true if operations executed on the current thread should record their provenance.
+pub fn is_anomaly_detection_enabled() -> bool {
+    ANOMALY_DETECTION.with(|enabled| enabled.get())
+}
+
+/// Execute the function with anomaly detection enabled.
+///
+/// The previous mode is restored even if the function panics.
+pub fn with_anomaly_detection_enabled<R, F: FnOnce() -> R>(func: F) -> R {
+    with_flag(&ANOMALY_DETECTION, true, func)
+}
+
+fn with_flag<R, F: FnOnce() -> R>(flag: &'static LocalKey<Cell<bool>>, value: bool, func: F) -> R {
+    struct Restore(&'static LocalKey<Cell<bool>>, bool);
+
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            self.0.with(|flag| flag.set(self.1));
+        }
+    }
+
+    let _restore = Restore(flag, flag.with(|flag| flag.replace(value)));
+
+    func()
+}
@@ -0,0 +1,138 @@
+use core::any::Any;
+use std::cell::{Cell, RefCell};
+use std::sync::Arc;
+
+/// Random tensor sampled in a recording scope, with its type erased.
+pub type RandomTensor = Arc<dyn Any + Send + Sync>;
+
+enum RandomScope {
+    Record(Vec<RandomTensor>),
+    Replay(Arc<Vec<RandomTensor>>, usize),
+}
+
+std::thread_local! {
+    static RANDOM_SCOPES: RefCell<Vec<RandomScope>> = const { RefCell::new(Vec::new()) };
+    static SAMPLING: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Sample a random tensor, or replay the one sampled at the same position in the innermost
+/// replay scope.
+///
+/// The tensor is recorded by all the recording scopes opened after that replay scope, so a
+/// recording started during a replay records the replayed tensors.
+pub fn sample_random<T, F>(func: F) -> T
+where
+    T: Clone + Send + Sync + 'static,
+    F: FnOnce() -> T,
+{
+    // Nested autodiff backends sample through the same function, only the outermost call is
+    // recorded and replayed.
+    if SAMPLING.with(|sampling| sampling.get()) {
+        return func();
+    }
+
+    let replayed = RANDOM_SCOPES.with(|scopes| {
+        let mut scopes = scopes.borrow_mut();
+        let (tensors, position) = scopes.iter_mut().rev().find_map(|scope| match scope {
+            RandomScope::Record(_) => None,
+            RandomScope::Replay(tensors, position) => Some((tensors, position)),
+        })?;
+
+        let tensor = tensors.get(*position).unwrap_or_else(|| {
+            panic!(
+                "Can't replay random operation #{}: only {} random tensors were recorded, the \
+                 function must sample the same random tensors in the same order.",
+                *position,
+                tensors.len()
+            )
+        });
+        let tensor = tensor.downcast_ref::<T>().cloned().unwrap_or_else(|| {
+            panic!(
+                "Can't replay random operation #{}: the recorded tensor has a different type, \
+                 the function must sample the same random tensors in the same order.",
+                *position
+            )
+        });
+        *position += 1;
+
+        Some(tensor)
+    });
+
+    let tensor = match replayed {
+        Some(tensor) => tensor,
+        None => {
+            let _sampling = SamplingGuard::new();
+            func()
+        }
+    };
+
+    RANDOM_SCOPES.with(|scopes| {
+        for scope in scopes.borrow_mut().iter_mut().rev() {
+            match scope {
+                RandomScope::Record(tensors) => tensors.push(Arc::new(tensor.clone())),
+                RandomScope::Replay(..) => break,
+            }
+        }
+    });
+
+    tensor
+}
+
+/// Execute the function, recording all the random tensors it samples.
+pub fn with_random_recorded<R, F: FnOnce() -> R>(func: F) -> (R, Vec<RandomTensor>) {
+    let scope = ScopeGuard::push(RandomScope::Record(Vec::new()));
+    let output = func();
+
+    match scope.pop() {
+        RandomScope::Record(tensors) => (output, tensors),
+        RandomScope::Replay(..) => unreachable!(),
+    }
+}
+
+/// Execute the function, replaying the recorded random tensors instead of sampling new ones.
+pub fn with_random_replayed<R, F: FnOnce() -> R>(tensors: Arc<Vec<RandomTensor>>, func: F) -> R {
+    let _scope = ScopeGuard::push(RandomScope::Replay(tensors, 0));
+
+    func()
+}
+
+/// Remove the scope when dropped, even if the function panics.
+struct ScopeGuard {
+    popped: bool,
+}
+
+impl ScopeGuard {
+    fn push(scope: RandomScope) -> Self {
+        RANDOM_SCOPES.with(|scopes| scopes.borrow_mut().push(scope));
+        Self { popped: false }
+    }
+
+    fn pop(mut self) -> RandomScope {
+        self.popped = true;
+        RANDOM_SCOPES.with(|scopes| scopes.borrow_mut().pop().unwrap())
+    }
+}
+
+impl Drop for ScopeGuard {
+    fn drop(&mut self) {
+        if !self.popped {
+            RANDOM_SCOPES.with(|scopes| scopes.borrow_mut().pop());
+        }
+    }
+}
+
+/// Mark the current thread as sampling until dropped, even if the sampling panics.
+struct SamplingGuard;
+
+impl SamplingGuard {
+    fn new() -> Self {
+        SAMPLING.with(|sampling| sampling.set(true));
+        Self
+    }
+}
+
+impl Drop for SamplingGuard {
+    fn drop(&mut self) {
+        SAMPLING.with(|sampling| sampling.set(false));
+    }
+}
@@ -1,4 +1,4 @@
-use super::NodeRef;
+use super::{is_grad_enabled, mark_tracked_used, NodeRef};
 
 /// Requirement for each tensor in the graph.
 #[derive(Debug, Clone, Copy)]
@@ -17,7 +17,17 @@ impl Requirement {
         matches!(self, Self::None)
     }
     /// Returns the right requirement from a list of nodes.
+    ///
+    /// No gradients are required when the graph mode is disabled on the current thread.
     pub fn from_nodes(nodes: &[NodeRef]) -> Self {
+        if !is_grad_enabled() {
+            if nodes.iter().any(|node| !node.requirement.is_none()) {
+                mark_tracked_used();
+            }
+
+            return Self::None;
+        }
+
         nodes
             .iter()
             .map(|node| node.requirement)
@@ -1,6 +1,6 @@
 use std::collections::HashSet;
 
-use super::{Graph, NodeRef, StepBoxed};
+use super::{NodeRef, NodeSteps, StepBoxed};
 
 /// Breadth for search algorithm.
 pub struct BreadthFirstSearch;
@@ -10,12 +10,11 @@ impl BreadthFirstSearch {
     pub fn traverse<F: FnMut(NodeRef, StepBoxed)>(
         &self,
         root: NodeRef,
-        graph: Graph,
+        mut steps: NodeSteps,
         mut callback: F,
     ) {
         let mut visited = HashSet::with_capacity(root.order);
         let mut parents = Vec::with_capacity(root.order);
-        let mut steps = graph.steps();
         let root_step = steps.remove(&root.id).expect(
             "Root node should have a step registered, did you forget to call \
              `Tensor::register_grad` on the tensor where you need gradients?",
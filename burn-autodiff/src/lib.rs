@@ -6,12 +6,17 @@
 //! that can be used to perform automatic differentiation on tensors. It is
 //! designed to be used with the Burn Tensor crate, but it can be used with any
 //! tensor library that implements the `Backend` trait.
+//!
+//! Since the backward pass is computed with the inner backend, higher order gradients are
+//! supported by nesting the decorator (`Autodiff<Autodiff<B>>`).
 
 #[macro_use]
 extern crate derive_new;
 
 extern crate alloc;
 
+/// Forward mode automatic differentiation module.
+pub mod forward;
 /// Gradients module.
 pub mod grads;
 /// Operation module.
@@ -22,7 +27,20 @@ pub(crate) mod tensor;
 pub(crate) mod utils;
 
 mod backend;
+mod checkpoint;
+mod export;
+mod functional;
+mod mode;
+mod pipeline;
+mod random;
+
 pub use backend::*;
+pub use checkpoint::*;
+pub use export::*;
+pub use functional::*;
+pub use mode::*;
+pub use pipeline::*;
+pub use random::*;
 
 #[cfg(feature = "export_tests")]
 mod tests;
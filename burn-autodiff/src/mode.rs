@@ -0,0 +1,36 @@
+use crate::graph::{with_anomaly_detection_enabled, with_grad_disabled};
+
+/// Execute the function with anomaly detection enabled.
+///
+/// Every operation executed inside the function records its provenance (name and backtrace).
+/// During the backward pass of those operations, the gradients are validated and the backward
+/// pass panics as soon as an operation produces NaN or infinite gradients, reporting the
+/// operation that produced them along with where it was executed.
+///
+/// The backward pass doesn't need to be called inside the function, but it is recommended since
+/// anomaly detection is slow: it synchronizes with the device to validate each gradient.
+///
+/// # Example
+///
+/// ```rust, ignore
+/// let grads = burn_autodiff::detect_anomaly(|| model.forward(batch).loss.backward());
+/// ```
+pub fn detect_anomaly<R, F: FnOnce() -> R>(func: F) -> R {
+    with_anomaly_detection_enabled(func)
+}
+
+/// Execute the function without building the autodiff graph.
+///
+/// Operations executed inside the function only run on the inner backend, no graph node is
+/// created and their outputs never require gradients, even when their inputs do. This removes the
+/// graph construction and memory costs of passes where gradients aren't needed, such as
+/// validation.
+///
+/// # Example
+///
+/// ```rust, ignore
+/// let output = burn_autodiff::no_grad(|| model.forward(batch));
+/// ```
+pub fn no_grad<R, F: FnOnce() -> R>(func: F) -> R {
+    with_grad_disabled(func)
+}
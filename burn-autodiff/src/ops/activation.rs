@@ -10,7 +10,7 @@ use burn_tensor::{
 
 impl<B: Backend> ActivationOps<Autodiff<B>> for Autodiff<B> {
     fn gelu<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
-        #[derive(Debug)]
+        #[derive(Debug, Clone)]
         struct Gelu<const D: usize>;
 
         impl<const D: usize, B: Backend> Backward<B, D, 1> for Gelu<D> {
@@ -35,7 +35,7 @@ impl<B: Backend> ActivationOps<Autodiff<B>> for Autodiff<B> {
     }
 
     fn relu<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
-        #[derive(Debug)]
+        #[derive(Debug, Clone)]
         struct Relu;
 
         impl<B: Backend, const D: usize> Backward<B, D, 1> for Relu {
@@ -13,7 +13,10 @@ use burn_tensor::backend::Backend;
 /// Concrete types implementing this trait should not have any state.
 /// If a state is necessary during the backward pass,
 /// they should be declared with the associated type 'State'.
-pub trait Backward<B, const D: usize, const N: usize>: Send + Sync + std::fmt::Debug
+///
+/// The operation is cloned when the graph is retained for multiple backward passes.
+pub trait Backward<B, const D: usize, const N: usize>:
+    Send + Sync + std::fmt::Debug + Clone
 where
     Self: Sized + 'static,
     B: Backend,
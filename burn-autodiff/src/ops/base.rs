@@ -2,12 +2,17 @@ use super::Backward;
 use crate::{
     grads::Gradients,
     graph::{
-        NodeRef, Requirement, {Graph, Step},
+        NodeRef, OpProvenance, OpProvenanceRef, Requirement, StepBoxed, StepDescription,
+        {Graph, Step},
     },
     tensor::AutodiffTensor,
 };
-use burn_tensor::{backend::Backend, Shape};
-use std::marker::PhantomData;
+use burn_tensor::{
+    backend::Backend,
+    memory::{MemoryProfiler, TrackedAllocation},
+    Shape,
+};
+use std::{marker::PhantomData, sync::Arc};
 
 /// Operation in preparation.
 ///
@@ -107,8 +112,21 @@ where
         );
         let parents = self.nodes.map(|node| node.clone_if_require_grad());
         let ops = Ops::new(parents, output.node.clone(), state);
+        let provenance = OpProvenance::capture::<BO>();
+        let shape = B::shape(&output.primitive);
+        let allocation = match retained_bytes::<B, S, D>(&shape) {
+            0 => None,
+            bytes => MemoryProfiler::track_with_label("autodiff", operation_name::<BO>(), bytes)
+                .map(Arc::new),
+        };
 
-        output.register_step(OpsStep::new(ops, self.backward))
+        output.register_step(OpsStep::new(
+            ops,
+            self.backward,
+            provenance,
+            shape,
+            allocation,
+        ))
     }
 }
 
@@ -121,7 +139,7 @@ pub enum OpsKind<BO, B, S, const D: usize, const N: usize> {
 }
 
 /// Operation containing its parent nodes, its own node and the backward step state.
-#[derive(new, Debug)]
+#[derive(new, Debug, Clone)]
 pub struct Ops<S, const N: usize> {
     /// Parents nodes.
     pub parents: [Option<NodeRef>; N],
@@ -141,6 +159,10 @@ where
 {
     ops: Ops<SB, N>,
     backward: T,
+    provenance: Option<OpProvenanceRef>,
+    shape: Shape<D>,
+    // Retained state recorded by the memory profiler, released with the step.
+    allocation: Option<Arc<TrackedAllocation>>,
     phantom: PhantomData<B>,
 }
 
@@ -151,12 +173,70 @@ where
     SB: Clone + Send + Sync + std::fmt::Debug + 'static,
 {
     fn step(self: Box<Self>, grads: &mut Gradients) {
-        self.backward.backward(self.ops, grads);
+        let this = *self;
+
+        match this.provenance {
+            Some(provenance) => {
+                grads.with_provenance(provenance, |grads| this.backward.backward(this.ops, grads))
+            }
+            None => this.backward.backward(this.ops, grads),
+        }
     }
 
     fn node(&self) -> NodeRef {
         self.ops.node.clone()
     }
+
+    fn clone_step(&self) -> StepBoxed {
+        Box::new(Self {
+            ops: self.ops.clone(),
+            backward: self.backward.clone(),
+            provenance: self.provenance.clone(),
+            shape: self.shape.clone(),
+            allocation: self.allocation.clone(),
+            phantom: PhantomData,
+        })
+    }
+
+    fn describe(&self) -> StepDescription {
+        StepDescription::new(
+            operation_name::<T>(),
+            self.shape.dims.to_vec(),
+            retained_bytes::<B, SB, D>(&self.shape),
+        )
+    }
+}
+
+/// Estimated number of bytes kept alive by the state of an operation until its backward step.
+fn retained_bytes<B: Backend, S, const D: usize>(shape: &Shape<D>) -> usize {
+    // The state is opaque, so stateful operations are assumed to keep a tensor of the size of
+    // their output, which is the case for most of them.
+    match core::mem::size_of::<S>() {
+        0 => 0,
+        _ => shape.num_elements() * core::mem::size_of::<B::FloatElem>(),
+    }
+}
+
+/// Name of an operation from the type name of its backward struct, without the module path and
+/// the generic arguments.
+fn operation_name<T>() -> &'static str {
+    let name = core::any::type_name::<T>();
+    let mut depth = 0;
+    let mut start = 0;
+    let mut previous = ' ';
+
+    for (index, char) in name.char_indices() {
+        match char {
+            '<' => depth += 1,
+            '>' if previous != '-' => depth -= 1,
+            ':' if depth == 0 && previous == ':' => start = index + 1,
+            _ => {}
+        }
+        previous = char;
+    }
+
+    let name = &name[start..];
+    name.split('<').next().unwrap_or(name)
 }
 
 /// Make sure the grad tensor has the given shape.
@@ -109,4 +109,8 @@ impl<B: Backend> BoolTensorOps<Self> for Autodiff<B> {
     ) -> Vec<BoolTensor<B, D>> {
         B::bool_chunk(tensor, chunks, dim)
     }
+
+    fn bool_nonzero<const D: usize>(tensor: BoolTensor<B, D>) -> Vec<IntTensor<B, 1>> {
+        B::bool_nonzero(tensor)
+    }
 }
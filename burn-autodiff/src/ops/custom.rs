@@ -0,0 +1,107 @@
+use super::{Backward, Ops, OpsKind};
+use crate::{grads::Gradients, Autodiff};
+use burn_tensor::{backend::Backend, Tensor};
+use std::sync::Arc;
+
+/// Execute a custom differentiable operation.
+///
+/// The `forward` closure is executed on the inner backend, while the `backward` closure receives
+/// the inputs, the output and the gradient of the output, and must return the gradient of each
+/// input. The operation is registered into the autodiff graph like any other built-in operation,
+/// so it can be freely mixed with them.
+///
+/// # Example
+///
+/// ```rust, ignore
+/// // Straight-through estimator of the clamp function.
+/// let output = custom_op(
+///     [tensor],
+///     |[x]| x.clamp(-1.0, 1.0),
+///     |_inputs, _output, grad| [grad],
+/// );
+/// ```
+pub fn custom_op<B, F, G, const D: usize, const N: usize>(
+    inputs: [Tensor<Autodiff<B>, D>; N],
+    forward: F,
+    backward: G,
+) -> Tensor<Autodiff<B>, D>
+where
+    B: Backend,
+    F: FnOnce([Tensor<B, D>; N]) -> Tensor<B, D>,
+    G: Fn(&[Tensor<B, D>; N], &Tensor<B, D>, Tensor<B, D>) -> [Tensor<B, D>; N]
+        + Send
+        + Sync
+        + 'static,
+{
+    let inputs = inputs.map(|tensor| tensor.into_primitive());
+    let nodes = inputs.clone().map(|tensor| tensor.node);
+    let graphs = inputs.clone().map(|tensor| tensor.graph);
+    let primitives = inputs.map(|tensor| tensor.primitive);
+
+    let output = match <CustomBackward<G> as Backward<B, D, N>>::prepare(
+        CustomBackward {
+            func: Arc::new(backward),
+        },
+        nodes,
+        graphs,
+    )
+    .stateful()
+    {
+        OpsKind::Tracked(prep) => {
+            let output = forward(primitives.clone().map(Tensor::from_primitive)).into_primitive();
+            prep.finish((primitives, output.clone()), output)
+        }
+        OpsKind::UnTracked(prep) => {
+            prep.finish(forward(primitives.map(Tensor::from_primitive)).into_primitive())
+        }
+    };
+
+    Tensor::from_primitive(output)
+}
+
+struct CustomBackward<G> {
+    func: Arc<G>,
+}
+
+impl<G> Clone for CustomBackward<G> {
+    fn clone(&self) -> Self {
+        Self {
+            func: self.func.clone(),
+        }
+    }
+}
+
+impl<G> core::fmt::Debug for CustomBackward<G> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("CustomBackward")
+    }
+}
+
+impl<B, G, const D: usize, const N: usize> Backward<B, D, N> for CustomBackward<G>
+where
+    B: Backend,
+    G: Fn(&[Tensor<B, D>; N], &Tensor<B, D>, Tensor<B, D>) -> [Tensor<B, D>; N]
+        + Send
+        + Sync
+        + 'static,
+{
+    type State = ([B::TensorPrimitive<D>; N], B::TensorPrimitive<D>);
+
+    fn backward(self, ops: Ops<Self::State, N>, grads: &mut Gradients) {
+        let grad = grads.consume::<B, D>(&ops.node);
+        let (inputs, output) = ops.state;
+        let inputs = inputs.map(Tensor::from_primitive);
+
+        let grads_inputs = (self.func)(
+            &inputs,
+            &Tensor::from_primitive(output),
+            Tensor::from_primitive(grad),
+        );
+
+        for (node, grad) in ops.parents.into_iter().zip(grads_inputs) {
+            if let Some(node) = node {
+                grads.register::<B, D>(node, grad.into_primitive());
+            }
+        }
+    }
+}
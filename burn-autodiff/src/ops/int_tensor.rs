@@ -3,7 +3,7 @@ use crate::{tensor::AutodiffTensor, Autodiff};
 use burn_tensor::{
     backend::Backend,
     ops::{BoolTensor, IntTensor, IntTensorOps},
-    Data, Device, Reader, Shape,
+    Data, Device, PadMode, Reader, Shape,
 };
 
 impl<B: Backend> IntTensorOps<Autodiff<B>> for Autodiff<B> {
@@ -333,4 +333,87 @@ impl<B: Backend> IntTensorOps<Autodiff<B>> for Autodiff<B> {
     ) -> Vec<<Autodiff<B> as Backend>::IntTensorPrimitive<D>> {
         B::int_chunk(tensor, chunks, dim)
     }
+
+    fn int_sort<const D: usize>(
+        tensor: IntTensor<B, D>,
+        dim: usize,
+        descending: bool,
+    ) -> IntTensor<B, D> {
+        B::int_sort(tensor, dim, descending)
+    }
+
+    fn int_sort_with_indices<const D: usize>(
+        tensor: IntTensor<B, D>,
+        dim: usize,
+        descending: bool,
+    ) -> (IntTensor<B, D>, IntTensor<B, D>) {
+        B::int_sort_with_indices(tensor, dim, descending)
+    }
+
+    fn int_argsort<const D: usize>(
+        tensor: IntTensor<B, D>,
+        dim: usize,
+        descending: bool,
+    ) -> IntTensor<B, D> {
+        B::int_argsort(tensor, dim, descending)
+    }
+
+    fn int_cumsum<const D: usize>(tensor: IntTensor<B, D>, dim: usize) -> IntTensor<B, D> {
+        B::int_cumsum(tensor, dim)
+    }
+
+    fn int_cumprod<const D: usize>(tensor: IntTensor<B, D>, dim: usize) -> IntTensor<B, D> {
+        B::int_cumprod(tensor, dim)
+    }
+
+    fn int_masked_select<const D: usize>(
+        tensor: IntTensor<B, D>,
+        mask: BoolTensor<B, D>,
+    ) -> IntTensor<B, 1> {
+        B::int_masked_select(tensor, mask)
+    }
+
+    fn int_searchsorted<const D: usize>(
+        sorted_sequence: IntTensor<B, D>,
+        values: IntTensor<B, D>,
+        right: bool,
+    ) -> IntTensor<B, D> {
+        B::int_searchsorted(sorted_sequence, values, right)
+    }
+
+    fn int_pad<const D: usize>(
+        tensor: IntTensor<B, D>,
+        padding: [(usize, usize); D],
+        mode: PadMode,
+    ) -> IntTensor<B, D> {
+        B::int_pad(tensor, padding, mode)
+    }
+
+    fn int_unique<const D: usize>(
+        tensor: IntTensor<B, D>,
+    ) -> (IntTensor<B, 1>, IntTensor<B, D>, IntTensor<B, 1>) {
+        B::int_unique(tensor)
+    }
+
+    fn int_bincount(tensor: IntTensor<B, 1>, min_length: usize) -> IntTensor<B, 1> {
+        B::int_bincount(tensor, min_length)
+    }
+
+    fn int_scatter_max<const D: usize>(
+        dim: usize,
+        tensor: IntTensor<B, D>,
+        indices: IntTensor<B, D>,
+        value: IntTensor<B, D>,
+    ) -> IntTensor<B, D> {
+        B::int_scatter_max(dim, tensor, indices, value)
+    }
+
+    fn int_scatter_min<const D: usize>(
+        dim: usize,
+        tensor: IntTensor<B, D>,
+        indices: IntTensor<B, D>,
+        value: IntTensor<B, D>,
+    ) -> IntTensor<B, D> {
+        B::int_scatter_min(dim, tensor, indices, value)
+    }
 }
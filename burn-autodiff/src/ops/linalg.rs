@@ -0,0 +1,73 @@
+use super::{unary, Backward, Ops};
+use crate::grads::Gradients;
+use burn_tensor::{backend::Backend, linalg, Tensor};
+
+/// The solution of `lhs @ x = rhs` has the gradients `grad_rhs = solve(lhs^T, grad)` and
+/// `grad_lhs = -grad_rhs @ x^T`, so the linear system is solved once for both parents.
+#[derive(Debug, Clone)]
+pub(crate) struct Solve;
+
+impl<B: Backend, const D: usize> Backward<B, D, 2> for Solve {
+    type State = (B::TensorPrimitive<D>, Option<B::TensorPrimitive<D>>);
+
+    fn backward(self, ops: Ops<Self::State, 2>, grads: &mut Gradients) {
+        let (lhs, output) = ops.state;
+        let [node_lhs, node_rhs] = ops.parents;
+
+        let grad = grads.consume::<B, D>(&ops.node);
+        let grad_rhs = B::solve(B::transpose(lhs), grad);
+
+        if let Some(node) = node_lhs {
+            let grad_lhs = B::matmul(grad_rhs.clone(), B::transpose(output.unwrap()));
+            grads.register::<B, D>(node, B::neg(grad_lhs));
+        }
+
+        if let Some(node) = node_rhs {
+            grads.register::<B, D>(node, grad_rhs);
+        }
+    }
+}
+
+/// The inverse `x` has the gradient `-x^T @ grad @ x^T`.
+#[derive(Debug, Clone)]
+pub(crate) struct Inverse;
+
+impl<B: Backend, const D: usize> Backward<B, D, 1> for Inverse {
+    type State = B::TensorPrimitive<D>;
+
+    fn backward(self, ops: Ops<Self::State, 1>, grads: &mut Gradients) {
+        unary::<B, D, D, _>(ops.parents, ops.node, grads, |grad| {
+            let output = B::transpose(ops.state);
+            let grad = B::matmul(B::matmul(output.clone(), grad), output);
+
+            B::neg(grad)
+        });
+    }
+}
+
+/// The gradient of the Cholesky factor `l` is `l^-T @ phi(l^T @ grad) @ l^-1`, where `phi` keeps
+/// the lower triangular part and halves the diagonal, symmetrized since only the lower
+/// triangular part of the input is read.
+#[derive(Debug, Clone)]
+pub(crate) struct Cholesky;
+
+impl<B: Backend, const D: usize> Backward<B, D, 1> for Cholesky {
+    type State = B::TensorPrimitive<D>;
+
+    fn backward(self, ops: Ops<Self::State, 1>, grads: &mut Gradients) {
+        unary::<B, D, D, _>(ops.parents, ops.node, grads, |grad| {
+            let l_t = Tensor::<B, D>::from_primitive(ops.state).transpose();
+            let grad = Tensor::<B, D>::from_primitive(grad);
+
+            let phi = l_t.clone().matmul(grad).tril(0);
+            let phi = (phi.clone() + phi.tril(-1).transpose()).mul_scalar(0.5);
+
+            // Both triangular systems are solved with the transposed factor:
+            // x @ l^-1 = (l^-T @ x^T)^T.
+            let grad = linalg::solve(l_t.clone(), phi);
+            let grad = linalg::solve(l_t, grad.transpose()).transpose();
+
+            grad.into_primitive()
+        });
+    }
+}
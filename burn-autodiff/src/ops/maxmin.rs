@@ -2,7 +2,7 @@ use super::{unary, Backward, Ops};
 use crate::grads::Gradients;
 use burn_tensor::{backend::Backend, Shape};
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub(crate) struct MaxMinDim;
 
 impl<B: Backend, const D: usize> Backward<B, D, 1> for MaxMinDim {
@@ -2,11 +2,15 @@ mod activation;
 mod backward;
 mod base;
 mod bool_tensor;
+mod custom;
 mod int_tensor;
 mod module;
 mod tensor;
 
+pub(crate) mod linalg;
 pub(crate) mod maxmin;
+pub(crate) mod sort;
 
 pub use backward::*;
 pub use base::*;
+pub use custom::*;
@@ -10,7 +10,7 @@ use super::OpsKind;
 
 impl<B: Backend> ModuleOps<Autodiff<B>> for Autodiff<B> {
     fn embedding(weights: AutodiffTensor<B, 2>, indices: IntTensor<B, 2>) -> AutodiffTensor<B, 3> {
-        #[derive(Debug)]
+        #[derive(Debug, Clone)]
         struct Embedding;
 
         impl<B: Backend> Backward<B, 3, 1> for Embedding {
@@ -51,9 +51,9 @@ impl<B: Backend> ModuleOps<Autodiff<B>> for Autodiff<B> {
         bias: Option<AutodiffTensor<B, 1>>,
         options: ConvOptions<2>,
     ) -> AutodiffTensor<B, 4> {
-        #[derive(Debug)]
+        #[derive(Debug, Clone)]
         struct Conv2DWithBias;
-        #[derive(Debug)]
+        #[derive(Debug, Clone)]
         struct Conv2DNoBias;
 
         impl<B: Backend> Backward<B, 4, 3> for Conv2DWithBias {
@@ -151,9 +151,9 @@ impl<B: Backend> ModuleOps<Autodiff<B>> for Autodiff<B> {
         bias: Option<AutodiffTensor<B, 1>>,
         options: ConvTransposeOptions<2>,
     ) -> AutodiffTensor<B, 4> {
-        #[derive(Debug)]
+        #[derive(Debug, Clone)]
         struct ConvTranspose2DWithBias;
-        #[derive(Debug)]
+        #[derive(Debug, Clone)]
         struct ConvTranspose2DNoBias;
 
         impl<B: Backend> Backward<B, 4, 3> for ConvTranspose2DWithBias {
@@ -263,9 +263,9 @@ impl<B: Backend> ModuleOps<Autodiff<B>> for Autodiff<B> {
         bias: Option<AutodiffTensor<B, 1>>,
         options: ConvOptions<1>,
     ) -> AutodiffTensor<B, 3> {
-        #[derive(Debug)]
+        #[derive(Debug, Clone)]
         struct Conv1DWithBias;
-        #[derive(Debug)]
+        #[derive(Debug, Clone)]
         struct Conv1DNoBias;
 
         impl<B: Backend> Backward<B, 3, 3> for Conv1DWithBias {
@@ -362,9 +362,9 @@ impl<B: Backend> ModuleOps<Autodiff<B>> for Autodiff<B> {
         bias: Option<AutodiffTensor<B, 1>>,
         options: ConvTransposeOptions<1>,
     ) -> AutodiffTensor<B, 3> {
-        #[derive(Debug)]
+        #[derive(Debug, Clone)]
         struct ConvTranspose1DWithBias;
-        #[derive(Debug)]
+        #[derive(Debug, Clone)]
         struct ConvTranspose1DNoBias;
 
         impl<B: Backend> Backward<B, 3, 3> for ConvTranspose1DWithBias {
@@ -483,6 +483,141 @@ impl<B: Backend> ModuleOps<Autodiff<B>> for Autodiff<B> {
     //     todo!()
     // }
 
+    fn attention(
+        query: AutodiffTensor<B, 4>,
+        key: AutodiffTensor<B, 4>,
+        value: AutodiffTensor<B, 4>,
+        scale: f32,
+    ) -> AutodiffTensor<B, 4> {
+        #[derive(Debug, Clone)]
+        struct Attention;
+
+        impl<B: Backend> Backward<B, 4, 3> for Attention {
+            type State = (
+                B::TensorPrimitive<4>,
+                B::TensorPrimitive<4>,
+                B::TensorPrimitive<4>,
+                B::TensorPrimitive<4>,
+                f32,
+            );
+
+            fn backward(self, ops: Ops<Self::State, 3>, grads: &mut Gradients) {
+                let [node_query, node_key, node_value] = ops.parents;
+                let grad = grads.consume::<B, 4>(&ops.node);
+
+                let (query, key, value, output, scale) = ops.state;
+                let backward = B::attention_backward(query, key, value, output, grad, scale);
+
+                if let Some(node) = node_query {
+                    grads.register::<B, 4>(node, backward.query_grad)
+                }
+                if let Some(node) = node_key {
+                    grads.register::<B, 4>(node, backward.key_grad)
+                }
+                if let Some(node) = node_value {
+                    grads.register::<B, 4>(node, backward.value_grad)
+                }
+            }
+        }
+
+        // Only the inputs and the output are kept for the backward pass, the attention weights
+        // are recomputed instead of being stored.
+        match Attention
+            .prepare(
+                [query.node, key.node, value.node],
+                [query.graph, key.graph, value.graph],
+            )
+            .stateful()
+        {
+            OpsKind::Tracked(prep) => {
+                let output = B::attention(
+                    query.primitive.clone(),
+                    key.primitive.clone(),
+                    value.primitive.clone(),
+                    scale,
+                );
+                prep.finish(
+                    (
+                        query.primitive,
+                        key.primitive,
+                        value.primitive,
+                        output.clone(),
+                        scale,
+                    ),
+                    output,
+                )
+            }
+            OpsKind::UnTracked(prep) => prep.finish(B::attention(
+                query.primitive,
+                key.primitive,
+                value.primitive,
+                scale,
+            )),
+        }
+    }
+
+    // The default attention backward isn't overridden, since it's composed of differentiable
+    // operations. This is what a nested autodiff backend calls to compute the gradients of the
+    // attention, which can then be differentiated again.
+
+    fn int4_matmul(
+        lhs: AutodiffTensor<B, 2>,
+        weight: IntTensor<B, 2>,
+        scales: AutodiffTensor<B, 2>,
+        zero_points: AutodiffTensor<B, 2>,
+        block_size: usize,
+    ) -> AutodiffTensor<B, 2> {
+        #[derive(Debug, Clone)]
+        struct Int4Matmul;
+
+        impl<B: Backend> Backward<B, 2, 1> for Int4Matmul {
+            type State = (
+                IntTensor<B, 2>,
+                B::TensorPrimitive<2>,
+                B::TensorPrimitive<2>,
+                usize,
+            );
+
+            fn backward(self, ops: Ops<Self::State, 1>, grads: &mut Gradients) {
+                let [node_lhs] = ops.parents;
+                let grad = grads.consume::<B, 2>(&ops.node);
+
+                let (weight, scales, zero_points, block_size) = ops.state;
+                let weight = B::int4_dequantize(weight, scales, zero_points, block_size);
+
+                if let Some(node) = node_lhs {
+                    grads.register::<B, 2>(node, B::matmul(grad, B::transpose(weight)))
+                }
+            }
+        }
+
+        // The quantized weights are constants, only the gradient of the input is computed.
+        match Int4Matmul.prepare([lhs.node], [lhs.graph]).stateful() {
+            OpsKind::Tracked(prep) => prep.finish(
+                (
+                    weight.clone(),
+                    scales.primitive.clone(),
+                    zero_points.primitive.clone(),
+                    block_size,
+                ),
+                B::int4_matmul(
+                    lhs.primitive,
+                    weight,
+                    scales.primitive,
+                    zero_points.primitive,
+                    block_size,
+                ),
+            ),
+            OpsKind::UnTracked(prep) => prep.finish(B::int4_matmul(
+                lhs.primitive,
+                weight,
+                scales.primitive,
+                zero_points.primitive,
+                block_size,
+            )),
+        }
+    }
+
     fn avg_pool1d(
         x: AutodiffTensor<B, 3>,
         kernel_size: usize,
@@ -490,7 +625,7 @@ impl<B: Backend> ModuleOps<Autodiff<B>> for Autodiff<B> {
         padding: usize,
         count_include_pad: bool,
     ) -> AutodiffTensor<B, 3> {
-        #[derive(Debug)]
+        #[derive(Debug, Clone)]
         struct AvgPool1D;
 
         impl<B: Backend> Backward<B, 3, 1> for AvgPool1D {
@@ -546,7 +681,7 @@ impl<B: Backend> ModuleOps<Autodiff<B>> for Autodiff<B> {
         padding: [usize; 2],
         count_include_pad: bool,
     ) -> AutodiffTensor<B, 4> {
-        #[derive(Debug)]
+        #[derive(Debug, Clone)]
         struct AvgPool2D;
 
         impl<B: Backend> Backward<B, 4, 1> for AvgPool2D {
@@ -803,7 +938,7 @@ impl<B: Backend> ModuleOps<Autodiff<B>> for Autodiff<B> {
         panic!("Can't differentiate max pool2d with indices backward.");
     }
     fn adaptive_avg_pool1d(x: AutodiffTensor<B, 3>, output_size: usize) -> AutodiffTensor<B, 3> {
-        #[derive(Debug)]
+        #[derive(Debug, Clone)]
         struct AdaptiveAvgPool1D;
 
         impl<B: Backend> Backward<B, 3, 1> for AdaptiveAvgPool1D {
@@ -835,7 +970,7 @@ impl<B: Backend> ModuleOps<Autodiff<B>> for Autodiff<B> {
         x: AutodiffTensor<B, 4>,
         output_size: [usize; 2],
     ) -> AutodiffTensor<B, 4> {
-        #[derive(Debug)]
+        #[derive(Debug, Clone)]
         struct AdaptiveAvgPool2D;
 
         impl<B: Backend> Backward<B, 4, 1> for AdaptiveAvgPool2D {
@@ -871,7 +1006,7 @@ impl<B: Backend> ModuleOps<Autodiff<B>> for Autodiff<B> {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 struct MaxPool1D;
 
 impl<B: Backend> Backward<B, 3, 1> for MaxPool1D {
@@ -905,7 +1040,7 @@ impl<B: Backend> Backward<B, 3, 1> for MaxPool1D {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 struct MaxPool2D;
 
 impl<B: Backend> Backward<B, 4, 1> for MaxPool2D {
@@ -0,0 +1,22 @@
+use super::{unary, Backward, Ops};
+use crate::grads::Gradients;
+use burn_tensor::{backend::Backend, Shape};
+
+/// Sorting permutes the elements along a dimension, the gradient of each input element is the
+/// gradient of the output element it was moved to.
+#[derive(Debug, Clone)]
+pub(crate) struct Sort;
+
+impl<B: Backend, const D: usize> Backward<B, D, 1> for Sort {
+    type State = (B::IntTensorPrimitive<D>, Shape<D>, usize);
+
+    fn backward(self, ops: Ops<Self::State, 1>, grads: &mut Gradients) {
+        unary::<B, D, D, _>(ops.parents, ops.node, grads, |grad| {
+            let (indices, shape, dim) = ops.state;
+            let device = B::device(&grad);
+            let zeros = B::zeros(shape, &device);
+
+            B::scatter(dim, zeros, indices, grad)
+        });
+    }
+}
@@ -2,7 +2,7 @@ use std::marker::PhantomData;
 
 use crate::{
     grads::Gradients,
-    graph::{NodeRef, Requirement, Step},
+    graph::{sample_random, NodeRef, Requirement, Step, StepBoxed, StepDescription},
     ops::{binary, broadcast_shape, unary, unary_different_backend, Backward, Ops, OpsKind},
     tensor::AutodiffTensor,
     utils::duplicate,
@@ -11,11 +11,14 @@ use crate::{
 
 use burn_tensor::{
     backend::Backend,
+    fft::FftError,
     ops::{BoolTensor, FloatElem, FloatTensor, FullPrecisionBackend, IntTensor, TensorOps},
     Data, Device, ElementConversion, Reader, Shape, Tensor,
 };
 
+use super::linalg::{Cholesky, Inverse, Solve};
 use super::maxmin::MaxMinDim;
+use super::sort::Sort;
 
 impl<B: Backend> TensorOps<Self> for Autodiff<B> {
     fn from_data<const D: usize>(
@@ -30,7 +33,8 @@ impl<B: Backend> TensorOps<Self> for Autodiff<B> {
         distribution: burn_tensor::Distribution,
         device: &Device<Self>,
     ) -> FloatTensor<Self, D> {
-        AutodiffTensor::new(B::random(shape, distribution, device))
+        // Random tensors are recorded so recomputed forward passes can replay them.
+        AutodiffTensor::new(sample_random(|| B::random(shape, distribution, device)))
     }
 
     fn zeros<const D: usize>(shape: Shape<D>, device: &Device<Self>) -> FloatTensor<Self, D> {
@@ -61,7 +65,7 @@ impl<B: Backend> TensorOps<Self> for Autodiff<B> {
         tensor: FloatTensor<Self, D>,
         device: &Device<Self>,
     ) -> FloatTensor<Self, D> {
-        #[derive(Debug)]
+        #[derive(Debug, Clone)]
         struct ToDevice;
 
         impl<B: Backend, const D: usize> Backward<B, D, 1> for ToDevice {
@@ -74,13 +78,16 @@ impl<B: Backend> TensorOps<Self> for Autodiff<B> {
             }
         }
 
-        match ToDevice.prepare([tensor.node], [tensor.graph]).stateful() {
+        let hooks = tensor.hooks;
+        let output = match ToDevice.prepare([tensor.node], [tensor.graph]).stateful() {
             OpsKind::Tracked(prep) => {
                 let device_old = B::device(&tensor.primitive);
                 prep.finish(device_old, B::to_device(tensor.primitive, device))
             }
             OpsKind::UnTracked(prep) => prep.finish(B::to_device(tensor.primitive, device)),
-        }
+        };
+
+        output.with_hooks(&hooks)
     }
 
     fn arange(range: std::ops::Range<usize>, device: &Device<Self>) -> IntTensor<Self, 1> {
@@ -95,7 +102,7 @@ impl<B: Backend> TensorOps<Self> for Autodiff<B> {
         lhs: FloatTensor<Self, D>,
         rhs: FloatTensor<Self, D>,
     ) -> FloatTensor<Self, D> {
-        #[derive(Debug)]
+        #[derive(Debug, Clone)]
         struct Add;
 
         impl<B: Backend, const D: usize> Backward<B, D, 2> for Add {
@@ -130,7 +137,7 @@ impl<B: Backend> TensorOps<Self> for Autodiff<B> {
         lhs: FloatTensor<Self, D>,
         rhs: FloatElem<B>,
     ) -> FloatTensor<Self, D> {
-        #[derive(Debug)]
+        #[derive(Debug, Clone)]
         struct AddScalar;
 
         impl<B: Backend, const D: usize> Backward<B, D, 1> for AddScalar {
@@ -150,7 +157,7 @@ impl<B: Backend> TensorOps<Self> for Autodiff<B> {
         lhs: FloatTensor<Self, D>,
         rhs: FloatTensor<Self, D>,
     ) -> FloatTensor<Self, D> {
-        #[derive(Debug)]
+        #[derive(Debug, Clone)]
         struct Sub;
 
         impl<B: Backend, const D: usize> Backward<B, D, 2> for Sub {
@@ -185,7 +192,7 @@ impl<B: Backend> TensorOps<Self> for Autodiff<B> {
         lhs: FloatTensor<Self, D>,
         rhs: FloatElem<B>,
     ) -> FloatTensor<Self, D> {
-        #[derive(Debug)]
+        #[derive(Debug, Clone)]
         struct SubScalar;
 
         impl<B: Backend, const D: usize> Backward<B, D, 1> for SubScalar {
@@ -205,7 +212,7 @@ impl<B: Backend> TensorOps<Self> for Autodiff<B> {
         lhs: FloatTensor<Self, D>,
         rhs: FloatTensor<Self, D>,
     ) -> FloatTensor<Self, D> {
-        #[derive(Debug)]
+        #[derive(Debug, Clone)]
         struct Mul;
 
         impl<B: Backend, const D: usize> Backward<B, D, 2> for Mul {
@@ -258,7 +265,7 @@ impl<B: Backend> TensorOps<Self> for Autodiff<B> {
         lhs: FloatTensor<Self, D>,
         rhs: FloatElem<B>,
     ) -> FloatTensor<Self, D> {
-        #[derive(Debug)]
+        #[derive(Debug, Clone)]
         struct MulScalar;
 
         impl<B: Backend, const D: usize> Backward<B, D, 1> for MulScalar {
@@ -281,7 +288,7 @@ impl<B: Backend> TensorOps<Self> for Autodiff<B> {
         lhs: FloatTensor<Self, D>,
         rhs: FloatTensor<Self, D>,
     ) -> FloatTensor<Self, D> {
-        #[derive(Debug)]
+        #[derive(Debug, Clone)]
         struct Div;
 
         impl<B: Backend, const D: usize> Backward<B, D, 2> for Div {
@@ -342,7 +349,7 @@ impl<B: Backend> TensorOps<Self> for Autodiff<B> {
         lhs: FloatTensor<Self, D>,
         rhs: FloatElem<B>,
     ) -> FloatTensor<Self, D> {
-        #[derive(Debug)]
+        #[derive(Debug, Clone)]
         struct DivScalar;
 
         impl<B: Backend, const D: usize> Backward<B, D, 1> for DivScalar {
@@ -366,7 +373,7 @@ impl<B: Backend> TensorOps<Self> for Autodiff<B> {
         lhs: FloatTensor<Self, D>,
         rhs: FloatTensor<Self, D>,
     ) -> FloatTensor<Self, D> {
-        #[derive(Debug)]
+        #[derive(Debug, Clone)]
         struct Matmul;
 
         impl<B: Backend, const D: usize> Backward<B, D, 2> for Matmul {
@@ -420,7 +427,7 @@ impl<B: Backend> TensorOps<Self> for Autodiff<B> {
     }
 
     fn neg<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
-        #[derive(Debug)]
+        #[derive(Debug, Clone)]
         struct Neg;
 
         impl<B: Backend, const D: usize> Backward<B, D, 1> for Neg {
@@ -436,7 +443,7 @@ impl<B: Backend> TensorOps<Self> for Autodiff<B> {
     }
 
     fn recip<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
-        #[derive(Debug)]
+        #[derive(Debug, Clone)]
         struct Recip;
 
         impl<B: Backend, const D: usize> Backward<B, D, 1> for Recip {
@@ -466,7 +473,7 @@ impl<B: Backend> TensorOps<Self> for Autodiff<B> {
         dim1: usize,
         dim2: usize,
     ) -> FloatTensor<Self, D> {
-        #[derive(Debug)]
+        #[derive(Debug, Clone)]
         struct SwapDim;
 
         impl<B: Backend, const D: usize> Backward<B, D, 1> for SwapDim {
@@ -493,7 +500,7 @@ impl<B: Backend> TensorOps<Self> for Autodiff<B> {
         tensor: FloatTensor<Self, D1>,
         shape: Shape<D2>,
     ) -> FloatTensor<Self, D2> {
-        #[derive(Debug)]
+        #[derive(Debug, Clone)]
         struct ReshapeDim<const D1: usize>;
 
         impl<B: Backend, const D1: usize, const D2: usize> Backward<B, D2, 1> for ReshapeDim<D1> {
@@ -531,7 +538,7 @@ impl<B: Backend> TensorOps<Self> for Autodiff<B> {
         tensor: FloatTensor<Self, D>,
         indices: IntTensor<B, D>,
     ) -> FloatTensor<Self, D> {
-        #[derive(Debug)]
+        #[derive(Debug, Clone)]
         struct Gather;
 
         impl<B: Backend, const D: usize> Backward<B, D, 1> for Gather {
@@ -567,7 +574,7 @@ impl<B: Backend> TensorOps<Self> for Autodiff<B> {
         indices: IntTensor<B, D>,
         value: FloatTensor<Self, D>,
     ) -> FloatTensor<Self, D> {
-        #[derive(Debug)]
+        #[derive(Debug, Clone)]
         struct Scatter;
 
         impl<B: Backend, const D: usize> Backward<B, D, 2> for Scatter {
@@ -613,12 +620,30 @@ impl<B: Backend> TensorOps<Self> for Autodiff<B> {
         }
     }
 
+    fn scatter_max<const D: usize>(
+        dim: usize,
+        tensor: FloatTensor<Self, D>,
+        indices: IntTensor<B, D>,
+        value: FloatTensor<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        scatter_reduce::<B, D>(dim, tensor, indices, value, B::scatter_max)
+    }
+
+    fn scatter_min<const D: usize>(
+        dim: usize,
+        tensor: FloatTensor<Self, D>,
+        indices: IntTensor<B, D>,
+        value: FloatTensor<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        scatter_reduce::<B, D>(dim, tensor, indices, value, B::scatter_min)
+    }
+
     fn select<const D: usize>(
         tensor: FloatTensor<Self, D>,
         dim: usize,
         indices: IntTensor<B, 1>,
     ) -> FloatTensor<Self, D> {
-        #[derive(Debug)]
+        #[derive(Debug, Clone)]
         struct IndexSelectDim;
 
         impl<B: Backend, const D: usize> Backward<B, D, 1> for IndexSelectDim {
@@ -657,28 +682,23 @@ impl<B: Backend> TensorOps<Self> for Autodiff<B> {
         indices: IntTensor<B, 1>,
         value: FloatTensor<Self, D>,
     ) -> FloatTensor<Self, D> {
-        #[derive(Debug)]
+        #[derive(Debug, Clone)]
         struct IndexSelectDimAssign<const D: usize>;
 
         impl<B: Backend, const D: usize> Backward<B, D, 2> for IndexSelectDimAssign<D> {
-            type State = (usize, IntTensor<B, 1>, Shape<D>, Shape<D>, B::Device);
+            type State = (usize, IntTensor<B, 1>);
 
             fn backward(self, ops: Ops<Self::State, 2>, grads: &mut Gradients) {
-                let (dim, indices, shape_lhs, shape_rhs, device) = ops.state;
-                let [indices_4lhs, indices_4rhs] = duplicate(&ops.parents, Some(indices));
+                let (dim, indices) = ops.state;
 
+                // The values are added to the selected elements, so the tensor receives the whole
+                // gradient and the values receive the gradient of the elements they were added to.
                 binary::<B, D, D, D, _, _>(
                     ops.parents,
                     ops.node,
                     grads,
-                    |grad| {
-                        let zeros = B::zeros(shape_lhs, &device);
-                        B::select_assign(grad, dim, indices_4lhs.unwrap(), zeros)
-                    },
-                    |grad| {
-                        let zeros = B::zeros(shape_rhs, &device);
-                        B::select_assign(zeros, dim, indices_4rhs.unwrap(), grad)
-                    },
+                    |grad| grad,
+                    |grad| B::select(grad, dim, indices),
                 );
             }
         }
@@ -688,13 +708,7 @@ impl<B: Backend> TensorOps<Self> for Autodiff<B> {
             .stateful()
         {
             OpsKind::Tracked(prep) => prep.finish(
-                (
-                    dim,
-                    indices.clone(),
-                    B::shape(&tensor.primitive),
-                    B::shape(&value.primitive),
-                    B::device(&value.primitive),
-                ),
+                (dim, indices.clone()),
                 B::select_assign(tensor.primitive, dim, indices, value.primitive),
             ),
             OpsKind::UnTracked(prep) => prep.finish(B::select_assign(
@@ -710,7 +724,7 @@ impl<B: Backend> TensorOps<Self> for Autodiff<B> {
         tensor: FloatTensor<Self, D1>,
         ranges: [std::ops::Range<usize>; D2],
     ) -> FloatTensor<Self, D1> {
-        #[derive(Debug)]
+        #[derive(Debug, Clone)]
         struct Index<const D2: usize>;
 
         impl<B: Backend, const D1: usize, const D2: usize> Backward<B, D1, 1> for Index<D2> {
@@ -744,7 +758,7 @@ impl<B: Backend> TensorOps<Self> for Autodiff<B> {
         ranges: [std::ops::Range<usize>; D2],
         value: FloatTensor<Self, D1>,
     ) -> FloatTensor<Self, D1> {
-        #[derive(Debug)]
+        #[derive(Debug, Clone)]
         struct IndexAssign<const D2: usize>;
 
         impl<B: Backend, const D1: usize, const D2: usize> Backward<B, D1, 2> for IndexAssign<D2> {
@@ -790,7 +804,7 @@ impl<B: Backend> TensorOps<Self> for Autodiff<B> {
         mask: BoolTensor<Self, D>,
         source: FloatTensor<Self, D>,
     ) -> FloatTensor<Self, D> {
-        #[derive(Debug)]
+        #[derive(Debug, Clone)]
         struct MaskWhere;
 
         impl<B: Backend, const D: usize> Backward<B, D, 2> for MaskWhere {
@@ -844,7 +858,7 @@ impl<B: Backend> TensorOps<Self> for Autodiff<B> {
         mask: BoolTensor<B, D>,
         value: FloatElem<B>,
     ) -> FloatTensor<Self, D> {
-        #[derive(Debug)]
+        #[derive(Debug, Clone)]
         struct MaskFill;
 
         impl<B: Backend, const D: usize> Backward<B, D, 1> for MaskFill {
@@ -939,7 +953,7 @@ impl<B: Backend> TensorOps<Self> for Autodiff<B> {
         // When we detach a tensor, we remove it from the graph, but we still want to keep the
         // `require_grad` setting.
         let is_require_grad = Self::is_require_grad(&tensor);
-        let tensor = AutodiffTensor::new(tensor.primitive);
+        let tensor = AutodiffTensor::new(tensor.primitive).with_hooks(&tensor.hooks);
 
         match is_require_grad {
             true => tensor.require_grad(),
@@ -955,7 +969,7 @@ impl<B: Backend> TensorOps<Self> for Autodiff<B> {
             return tensor.require_grad();
         }
 
-        AutodiffTensor::new(tensor.primitive)
+        AutodiffTensor::new(tensor.primitive).with_hooks(&tensor.hooks)
     }
 
     fn is_require_grad<const D: usize>(tensor: &FloatTensor<Self, D>) -> bool {
@@ -963,7 +977,7 @@ impl<B: Backend> TensorOps<Self> for Autodiff<B> {
     }
 
     fn mean<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, 1> {
-        #[derive(Debug)]
+        #[derive(Debug, Clone)]
         struct Mean<const D: usize>;
 
         impl<B: Backend, const D: usize> Backward<B, 1, 1> for Mean<D> {
@@ -993,7 +1007,7 @@ impl<B: Backend> TensorOps<Self> for Autodiff<B> {
     }
 
     fn sum<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, 1> {
-        #[derive(Debug)]
+        #[derive(Debug, Clone)]
         struct Sum<const D: usize>;
 
         impl<B: Backend, const D: usize> Backward<B, 1, 1> for Sum<D> {
@@ -1020,7 +1034,7 @@ impl<B: Backend> TensorOps<Self> for Autodiff<B> {
     }
 
     fn mean_dim<const D: usize>(tensor: FloatTensor<Self, D>, dim: usize) -> FloatTensor<Self, D> {
-        #[derive(Debug)]
+        #[derive(Debug, Clone)]
         struct MeamDim;
 
         impl<B: Backend, const D: usize> Backward<B, D, 1> for MeamDim {
@@ -1050,7 +1064,7 @@ impl<B: Backend> TensorOps<Self> for Autodiff<B> {
     }
 
     fn sum_dim<const D: usize>(tensor: FloatTensor<Self, D>, dim: usize) -> FloatTensor<Self, D> {
-        #[derive(Debug)]
+        #[derive(Debug, Clone)]
         struct SumDim;
 
         impl<B: Backend, const D: usize> Backward<B, D, 1> for SumDim {
@@ -1080,7 +1094,7 @@ impl<B: Backend> TensorOps<Self> for Autodiff<B> {
     fn to_full_precision<const D: usize>(
         tensor: &FloatTensor<Self, D>,
     ) -> FloatTensor<FullPrecisionBackend<Self>, D> {
-        #[derive(Debug)]
+        #[derive(Debug, Clone)]
         struct ToFullPrecision<B: Backend> {
             phantom: PhantomData<B>,
         }
@@ -1108,7 +1122,7 @@ impl<B: Backend> TensorOps<Self> for Autodiff<B> {
     fn from_full_precision<const D: usize>(
         tensor: FloatTensor<FullPrecisionBackend<Self>, D>,
     ) -> FloatTensor<Self, D> {
-        #[derive(Debug)]
+        #[derive(Debug, Clone)]
         struct FromFullPrecision<B: Backend> {
             phantom: PhantomData<B>,
         }
@@ -1143,7 +1157,7 @@ impl<B: Backend> TensorOps<Self> for Autodiff<B> {
     }
 
     fn exp<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
-        #[derive(Debug)]
+        #[derive(Debug, Clone)]
         struct Exp;
 
         impl<B: Backend, const D: usize> Backward<B, D, 1> for Exp {
@@ -1163,7 +1177,7 @@ impl<B: Backend> TensorOps<Self> for Autodiff<B> {
     }
 
     fn log<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
-        #[derive(Debug)]
+        #[derive(Debug, Clone)]
         struct Log;
 
         impl<B: Backend, const D: usize> Backward<B, D, 1> for Log {
@@ -1186,7 +1200,7 @@ impl<B: Backend> TensorOps<Self> for Autodiff<B> {
     }
 
     fn log1p<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
-        #[derive(Debug)]
+        #[derive(Debug, Clone)]
         struct Log1P;
 
         impl<B: Backend, const D: usize> Backward<B, D, 1> for Log1P {
@@ -1211,7 +1225,7 @@ impl<B: Backend> TensorOps<Self> for Autodiff<B> {
     }
 
     fn powf<const D: usize>(tensor: FloatTensor<Self, D>, value: f32) -> FloatTensor<Self, D> {
-        #[derive(Debug)]
+        #[derive(Debug, Clone)]
         struct PowF;
 
         impl<B: Backend, const D: usize> Backward<B, D, 1> for PowF {
@@ -1239,7 +1253,7 @@ impl<B: Backend> TensorOps<Self> for Autodiff<B> {
     }
 
     fn sqrt<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
-        #[derive(Debug)]
+        #[derive(Debug, Clone)]
         struct Sqrt;
 
         impl<B: Backend, const D: usize> Backward<B, D, 1> for Sqrt {
@@ -1264,7 +1278,7 @@ impl<B: Backend> TensorOps<Self> for Autodiff<B> {
     }
 
     fn abs<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
-        #[derive(Debug)]
+        #[derive(Debug, Clone)]
         struct Abs;
 
         impl<B: Backend, const D: usize> Backward<B, D, 1> for Abs {
@@ -1286,7 +1300,7 @@ impl<B: Backend> TensorOps<Self> for Autodiff<B> {
     }
 
     fn cos<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
-        #[derive(Debug)]
+        #[derive(Debug, Clone)]
         struct Cos;
 
         impl<B: Backend, const D: usize> Backward<B, D, 1> for Cos {
@@ -1311,7 +1325,7 @@ impl<B: Backend> TensorOps<Self> for Autodiff<B> {
     }
 
     fn sin<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
-        #[derive(Debug)]
+        #[derive(Debug, Clone)]
         struct Sin;
 
         impl<B: Backend, const D: usize> Backward<B, D, 1> for Sin {
@@ -1334,7 +1348,7 @@ impl<B: Backend> TensorOps<Self> for Autodiff<B> {
     }
 
     fn tanh<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
-        #[derive(Debug)]
+        #[derive(Debug, Clone)]
         struct Tanh;
 
         impl<B: Backend, const D: usize> Backward<B, D, 1> for Tanh {
@@ -1358,7 +1372,7 @@ impl<B: Backend> TensorOps<Self> for Autodiff<B> {
     }
 
     fn erf<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
-        #[derive(Debug)]
+        #[derive(Debug, Clone)]
         struct Erf;
 
         impl<B: Backend, const D: usize> Backward<B, D, 1> for Erf {
@@ -1392,6 +1406,7 @@ impl<B: Backend> TensorOps<Self> for Autodiff<B> {
             // This indicates the number of dimension concatenated for each tensor.
             dim_sizes: Vec<usize>,
             output: NodeRef,
+            shape: Shape<D>,
             phantom: PhantomData<B>,
             dim: usize,
         }
@@ -1404,14 +1419,19 @@ impl<B: Backend> TensorOps<Self> for Autodiff<B> {
 
                 let mut current_index = 0;
 
+                // The offset moves past every input, including the untracked ones.
                 self.nodes
                     .into_iter()
                     .zip(self.dim_sizes)
-                    .filter_map(|(node, dim_size)| node.map(|node| (node, dim_size)))
-                    .for_each(|(node, dim_size)| {
-                        let mut ranges = ranges.clone();
-                        ranges[self.dim] = current_index..dim_size + current_index;
+                    .map(|(node, dim_size)| {
+                        let start = current_index;
                         current_index += dim_size;
+                        (node, start..current_index)
+                    })
+                    .filter_map(|(node, range)| node.map(|node| (node, range)))
+                    .for_each(|(node, range)| {
+                        let mut ranges = ranges.clone();
+                        ranges[self.dim] = range;
                         grads.register::<B, D>(node, B::slice(grad.clone(), ranges));
                     });
             }
@@ -1419,6 +1439,21 @@ impl<B: Backend> TensorOps<Self> for Autodiff<B> {
             fn node(&self) -> NodeRef {
                 self.output.clone()
             }
+
+            fn clone_step(&self) -> StepBoxed {
+                Box::new(Self {
+                    nodes: self.nodes.clone(),
+                    dim_sizes: self.dim_sizes.clone(),
+                    output: self.output.clone(),
+                    shape: self.shape.clone(),
+                    phantom: PhantomData,
+                    dim: self.dim,
+                })
+            }
+
+            fn describe(&self) -> StepDescription {
+                StepDescription::new("Cat", self.shape.dims.to_vec(), 0)
+            }
         }
 
         let mut nodes = Vec::with_capacity(tensors.len());
@@ -1446,7 +1481,8 @@ impl<B: Backend> TensorOps<Self> for Autodiff<B> {
             .map(|node| node.clone_if_require_grad())
             .collect::<Vec<_>>();
 
-        let ops = CatStep::<B, D>::new(nodes, dim_sizes, output.node.clone(), dim);
+        let shape = B::shape(&output.primitive);
+        let ops = CatStep::<B, D>::new(nodes, dim_sizes, output.node.clone(), shape, dim);
         output.register_step(ops)
     }
 
@@ -1516,6 +1552,288 @@ impl<B: Backend> TensorOps<Self> for Autodiff<B> {
     ) -> <Autodiff<B> as Backend>::IntTensorPrimitive<D> {
         B::into_int(tensor.primitive)
     }
+
+    fn sort<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+        descending: bool,
+    ) -> FloatTensor<Self, D> {
+        Self::sort_with_indices(tensor, dim, descending).0
+    }
+
+    fn sort_with_indices<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+        descending: bool,
+    ) -> (FloatTensor<Self, D>, IntTensor<B, D>) {
+        match Sort.prepare([tensor.node], [tensor.graph]).stateful() {
+            OpsKind::Tracked(prep) => {
+                let shape = B::shape(&tensor.primitive);
+                let (tensor, indices) = B::sort_with_indices(tensor.primitive, dim, descending);
+                let tensor = prep.finish((indices.clone(), shape, dim), tensor);
+
+                (tensor, indices)
+            }
+            OpsKind::UnTracked(prep) => {
+                let (tensor, indices) = B::sort_with_indices(tensor.primitive, dim, descending);
+                let tensor = prep.finish(tensor);
+
+                (tensor, indices)
+            }
+        }
+    }
+
+    fn argsort<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+        descending: bool,
+    ) -> IntTensor<B, D> {
+        B::argsort(tensor.primitive, dim, descending)
+    }
+
+    fn searchsorted<const D: usize>(
+        sorted_sequence: FloatTensor<Self, D>,
+        values: FloatTensor<Self, D>,
+        right: bool,
+    ) -> IntTensor<B, D> {
+        B::searchsorted(sorted_sequence.primitive, values.primitive, right)
+    }
+
+    fn cumsum<const D: usize>(tensor: FloatTensor<Self, D>, dim: usize) -> FloatTensor<Self, D> {
+        #[derive(Debug, Clone)]
+        struct CumSum;
+
+        impl<B: Backend, const D: usize> Backward<B, D, 1> for CumSum {
+            type State = usize;
+
+            fn backward(self, ops: Ops<Self::State, 1>, grads: &mut Gradients) {
+                let dim = ops.state;
+
+                unary::<B, D, D, _>(ops.parents, ops.node, grads, |grad| {
+                    reverse_cumsum::<B, D>(grad, dim)
+                });
+            }
+        }
+
+        match CumSum.prepare([tensor.node], [tensor.graph]).stateful() {
+            OpsKind::Tracked(prep) => prep.finish(dim, B::cumsum(tensor.primitive, dim)),
+            OpsKind::UnTracked(prep) => prep.finish(B::cumsum(tensor.primitive, dim)),
+        }
+    }
+
+    fn cumprod<const D: usize>(tensor: FloatTensor<Self, D>, dim: usize) -> FloatTensor<Self, D> {
+        #[derive(Debug, Clone)]
+        struct CumProd;
+
+        impl<B: Backend, const D: usize> Backward<B, D, 1> for CumProd {
+            type State = (B::TensorPrimitive<D>, B::TensorPrimitive<D>, usize);
+
+            fn backward(self, ops: Ops<Self::State, 1>, grads: &mut Gradients) {
+                let (input, output, dim) = ops.state;
+
+                // Each input element is a factor of the outputs after it, the gradient is the sum
+                // of their contributions divided by the element, so it isn't finite for zeros.
+                unary::<B, D, D, _>(ops.parents, ops.node, grads, |grad| {
+                    let grad = reverse_cumsum::<B, D>(B::mul(grad, output), dim);
+
+                    B::div(grad, input)
+                });
+            }
+        }
+
+        match CumProd.prepare([tensor.node], [tensor.graph]).stateful() {
+            OpsKind::Tracked(prep) => {
+                let output = B::cumprod(tensor.primitive.clone(), dim);
+                prep.finish((tensor.primitive, output.clone(), dim), output)
+            }
+            OpsKind::UnTracked(prep) => prep.finish(B::cumprod(tensor.primitive, dim)),
+        }
+    }
+
+    fn solve<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatTensor<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        let lhs_tracked = lhs.is_tracked();
+
+        match Solve
+            .prepare([lhs.node, rhs.node], [lhs.graph, rhs.graph])
+            .stateful()
+        {
+            OpsKind::Tracked(prep) => {
+                let output = B::solve(lhs.primitive.clone(), rhs.primitive);
+                let state = (lhs.primitive, lhs_tracked.then(|| output.clone()));
+
+                prep.finish(state, output)
+            }
+            OpsKind::UnTracked(prep) => prep.finish(B::solve(lhs.primitive, rhs.primitive)),
+        }
+    }
+
+    fn inverse<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        let output = B::inverse(tensor.primitive);
+
+        match Inverse.prepare([tensor.node], [tensor.graph]).stateful() {
+            OpsKind::Tracked(prep) => prep.finish(output.clone(), output),
+            OpsKind::UnTracked(prep) => prep.finish(output),
+        }
+    }
+
+    fn cholesky<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        let output = B::cholesky(tensor.primitive);
+
+        match Cholesky.prepare([tensor.node], [tensor.graph]).stateful() {
+            OpsKind::Tracked(prep) => prep.finish(output.clone(), output),
+            OpsKind::UnTracked(prep) => prep.finish(output),
+        }
+    }
+
+    fn qr<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+    ) -> (FloatTensor<Self, D>, FloatTensor<Self, D>) {
+        let (q, r) = B::qr(tensor.primitive);
+
+        (AutodiffTensor::new(q), AutodiffTensor::new(r))
+    }
+
+    fn svd<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+    ) -> (
+        FloatTensor<Self, D>,
+        FloatTensor<Self, D>,
+        FloatTensor<Self, D>,
+    ) {
+        let (u, s, vt) = B::svd(tensor.primitive);
+
+        (
+            AutodiffTensor::new(u),
+            AutodiffTensor::new(s),
+            AutodiffTensor::new(vt),
+        )
+    }
+
+    fn fft<const D: usize>(
+        real: FloatTensor<Self, D>,
+        imag: FloatTensor<Self, D>,
+        dim: usize,
+        inverse: bool,
+    ) -> Result<(FloatTensor<Self, D>, FloatTensor<Self, D>), FftError> {
+        let (real, imag) = B::fft(real.primitive, imag.primitive, dim, inverse)?;
+
+        Ok((AutodiffTensor::new(real), AutodiffTensor::new(imag)))
+    }
+
+    fn rfft<const D: usize>(
+        signal: FloatTensor<Self, D>,
+        dim: usize,
+    ) -> Result<(FloatTensor<Self, D>, FloatTensor<Self, D>), FftError> {
+        let (real, imag) = B::rfft(signal.primitive, dim)?;
+
+        Ok((AutodiffTensor::new(real), AutodiffTensor::new(imag)))
+    }
+
+    fn irfft<const D: usize>(
+        real: FloatTensor<Self, D>,
+        imag: FloatTensor<Self, D>,
+        dim: usize,
+        n: usize,
+    ) -> Result<FloatTensor<Self, D>, FftError> {
+        let signal = B::irfft(real.primitive, imag.primitive, dim, n)?;
+
+        Ok(AutodiffTensor::new(signal))
+    }
+}
+
+/// Scatter keeping the maximum or the minimum of the elements, where the gradient flows to the
+/// elements equal to the output, so to both of them on ties.
+#[allow(clippy::type_complexity)]
+fn scatter_reduce<B: Backend, const D: usize>(
+    dim: usize,
+    tensor: AutodiffTensor<B, D>,
+    indices: IntTensor<B, D>,
+    value: AutodiffTensor<B, D>,
+    func: fn(
+        usize,
+        B::TensorPrimitive<D>,
+        IntTensor<B, D>,
+        B::TensorPrimitive<D>,
+    ) -> B::TensorPrimitive<D>,
+) -> AutodiffTensor<B, D> {
+    #[derive(Debug, Clone)]
+    struct ScatterReduce;
+
+    impl<B: Backend, const D: usize> Backward<B, D, 2> for ScatterReduce {
+        type State = (
+            usize,
+            IntTensor<B, D>,
+            B::TensorPrimitive<D>,
+            B::TensorPrimitive<D>,
+            B::TensorPrimitive<D>,
+        );
+
+        fn backward(self, ops: Ops<Self::State, 2>, grads: &mut Gradients) {
+            let (dim, indices, tensor, value, output) = ops.state;
+            let [output_4lhs, output_4rhs] = duplicate(&ops.parents, Some(output));
+
+            binary::<B, D, D, D, _, _>(
+                ops.parents,
+                ops.node,
+                grads,
+                |grad| {
+                    let zeros = B::zeros(B::shape(&grad), &B::device(&grad));
+                    let mask = B::equal(tensor, output_4lhs.unwrap());
+
+                    B::mask_where(zeros, mask, grad)
+                },
+                |grad| {
+                    let grad = B::gather(dim, grad, indices.clone());
+                    let zeros = B::zeros(B::shape(&grad), &B::device(&grad));
+                    let output = B::gather(dim, output_4rhs.unwrap(), indices);
+
+                    B::mask_where(zeros, B::equal(value, output), grad)
+                },
+            );
+        }
+    }
+
+    match ScatterReduce
+        .prepare([tensor.node, value.node], [tensor.graph, value.graph])
+        .stateful()
+    {
+        OpsKind::Tracked(prep) => {
+            let output = func(
+                dim,
+                tensor.primitive.clone(),
+                indices.clone(),
+                value.primitive.clone(),
+            );
+
+            prep.finish(
+                (
+                    dim,
+                    indices,
+                    tensor.primitive,
+                    value.primitive,
+                    output.clone(),
+                ),
+                output,
+            )
+        }
+        OpsKind::UnTracked(prep) => {
+            prep.finish(func(dim, tensor.primitive, indices, value.primitive))
+        }
+    }
+}
+
+/// Sum of the elements after each element along the given dimension, itself included.
+fn reverse_cumsum<B: Backend, const D: usize>(
+    tensor: B::TensorPrimitive<D>,
+    dim: usize,
+) -> B::TensorPrimitive<D> {
+    let total = B::sum_dim(tensor.clone(), dim);
+    let cumsum = B::cumsum(tensor.clone(), dim);
+
+    B::sub(B::add(tensor, total), cumsum)
 }
 
 #[derive(Debug, Clone)]
@@ -0,0 +1,131 @@
+use crate::{grads::Gradients, graph::backward::backward_with_pipeline, Autodiff};
+use burn_tensor::{backend::Backend, Element, Tensor};
+use std::sync::Arc;
+
+type GradientTransform<B> = Arc<dyn Fn(Tensor<B, 1>) -> Tensor<B, 1> + Send + Sync>;
+
+/// Pipeline of transformations applied on the gradients of the leaf tensors during the backward
+/// pass.
+///
+/// Each gradient is transformed as soon as it is fully accumulated, before the backward pass
+/// moves on to the remaining operations, so the transformations don't require another pass over
+/// all the parameters once the backward pass is completed. The transformations are applied in the
+/// order they are added to the pipeline, after the [hooks](burn_tensor::Tensor::register_grad_hook)
+/// registered on the tensors.
+///
+/// # Notes
+///
+/// Only the gradients of the leaves are transformed, the gradients flowing through the graph are
+/// left untouched. Tensors only used inside a [checkpointed scope](crate::checkpoint_scope) aren't
+/// part of the graph, so their gradients aren't transformed.
+///
+/// # Example
+///
+/// ```rust, ignore
+/// let pipeline = GradientPipeline::new().clip_value(-1.0, 1.0).clip_norm(10.0);
+/// let grads = pipeline.backward(&loss);
+/// ```
+pub struct GradientPipeline<B: Backend> {
+    transforms: Vec<GradientTransform<B>>,
+}
+
+impl<B: Backend> GradientPipeline<B> {
+    /// Create an empty pipeline.
+    pub fn new() -> Self {
+        Self {
+            transforms: Vec::new(),
+        }
+    }
+
+    /// Clamp every element of the gradients between `min` and `max`.
+    pub fn clip_value(self, min: f32, max: f32) -> Self {
+        self.map(move |grad| grad.clamp(min, max))
+    }
+
+    /// Rescale the gradient of each tensor so that its L2 norm doesn't exceed `max_norm`.
+    pub fn clip_norm(self, max_norm: f32) -> Self {
+        self.map(move |grad| {
+            let norm = grad.clone().powf(2.0).sum().sqrt();
+            // The scale is computed on the device to avoid reading the norm back.
+            let scale = norm
+                .add_scalar(f32::EPSILON)
+                .recip()
+                .mul_scalar(max_norm)
+                .clamp_max(1.0);
+
+            grad.mul(scale)
+        })
+    }
+
+    /// Round the gradients to the precision of the element type `E`, e.g. to simulate a training
+    /// with half precision gradients.
+    ///
+    /// The gradients are still stored with the float element type of the backend. Backends that
+    /// can only read data asynchronously are skipped.
+    pub fn cast<E: Element>(self) -> Self {
+        self.map(|grad| {
+            let device = grad.device();
+            match B::into_data(grad.clone().into_primitive()).read_sync() {
+                Some(data) => {
+                    Tensor::from_data(data.convert::<E>().convert::<B::FloatElem>(), &device)
+                }
+                None => grad,
+            }
+        })
+    }
+
+    /// Add a custom transformation to the pipeline.
+    ///
+    /// The gradients are flattened, so the transformation has to be independent of the shape of
+    /// the tensors.
+    pub fn map<F>(mut self, func: F) -> Self
+    where
+        F: Fn(Tensor<B, 1>) -> Tensor<B, 1> + Send + Sync + 'static,
+    {
+        self.transforms.push(Arc::new(func));
+        self
+    }
+
+    /// Execute the backward pass of the tensor, applying the pipeline on the gradients.
+    pub fn backward<const D: usize>(&self, tensor: &Tensor<Autodiff<B>, D>) -> Gradients {
+        backward_with_pipeline(tensor.clone().into_primitive(), Arc::new(self.clone()))
+    }
+
+    pub(crate) fn apply<const D: usize>(
+        &self,
+        grad: B::TensorPrimitive<D>,
+    ) -> B::TensorPrimitive<D> {
+        let grad = Tensor::<B, D>::from_primitive(grad);
+        let shape = grad.shape();
+        let grad = self
+            .transforms
+            .iter()
+            .fold(grad.reshape([shape.num_elements()]), |grad, transform| {
+                transform(grad)
+            });
+
+        grad.reshape(shape).into_primitive()
+    }
+}
+
+impl<B: Backend> Default for GradientPipeline<B> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<B: Backend> Clone for GradientPipeline<B> {
+    fn clone(&self) -> Self {
+        Self {
+            transforms: self.transforms.clone(),
+        }
+    }
+}
+
+impl<B: Backend> core::fmt::Debug for GradientPipeline<B> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("GradientPipeline")
+            .field("num_transforms", &self.transforms.len())
+            .finish()
+    }
+}
@@ -0,0 +1,63 @@
+use crate::graph::{with_random_recorded, with_random_replayed, RandomTensor};
+use std::sync::Arc;
+
+/// Random tensors sampled during the execution of a function, see [record_random].
+#[derive(Clone, Default)]
+pub struct RandomRecord {
+    tensors: Arc<Vec<RandomTensor>>,
+}
+
+impl RandomRecord {
+    /// Number of random tensors recorded.
+    pub fn len(&self) -> usize {
+        self.tensors.len()
+    }
+
+    /// Returns true if no random tensor was recorded.
+    pub fn is_empty(&self) -> bool {
+        self.tensors.is_empty()
+    }
+}
+
+impl core::fmt::Debug for RandomRecord {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("RandomRecord")
+            .field("num_tensors", &self.tensors.len())
+            .finish()
+    }
+}
+
+/// Execute the function, recording the random tensors sampled on the autodiff backend, such as
+/// dropout masks.
+///
+/// The record can be [replayed](replay_random) to execute the same forward pass again with
+/// identical random tensors, e.g. for the second pass of sharpness-aware minimization.
+///
+/// # Example
+///
+/// ```rust, ignore
+/// let (output, record) = burn_autodiff::record_random(|| model.forward(batch.clone()));
+/// // ... perturb the weights ...
+/// let output = burn_autodiff::replay_random(&record, || model.forward(batch));
+/// ```
+pub fn record_random<R, F: FnOnce() -> R>(func: F) -> (R, RandomRecord) {
+    let (output, tensors) = with_random_recorded(func);
+
+    (
+        output,
+        RandomRecord {
+            tensors: Arc::new(tensors),
+        },
+    )
+}
+
+/// Execute the function, replaying the random tensors of the [record](record_random) instead of
+/// sampling new ones.
+///
+/// # Panics
+///
+/// The function must sample the same random tensors in the same order as the recorded one,
+/// otherwise it panics when a random tensor can't be replayed.
+pub fn replay_random<R, F: FnOnce() -> R>(record: &RandomRecord, func: F) -> R {
+    with_random_replayed(record.tensors.clone(), func)
+}
@@ -1,9 +1,12 @@
-use burn_tensor::backend::Backend;
+use burn_tensor::backend::{Backend, GradHook};
+use core::marker::PhantomData;
+use spin::Mutex;
+use std::sync::Arc;
 
 use crate::{
     grads::Gradients,
     graph::{
-        Node, NodeID, NodeRef, Requirement, {Graph, Step},
+        Node, NodeID, NodeRef, Requirement, StepBoxed, StepDescription, {Graph, Step},
     },
 };
 
@@ -12,21 +15,111 @@ pub struct AutodiffTensor<B: Backend, const D: usize> {
     pub primitive: B::TensorPrimitive<D>,
     pub node: NodeRef,
     pub graph: Graph,
+    pub hooks: GradHooks<B, D>,
 }
 
+/// Gradient hooks registered on a tensor, shared with the step applying them.
+pub struct GradHooks<B: Backend, const D: usize> {
+    hooks: Arc<Mutex<Vec<Arc<GradHook<B, D>>>>>,
+}
+
+impl<B: Backend, const D: usize> GradHooks<B, D> {
+    fn is_empty(&self) -> bool {
+        self.hooks.lock().is_empty()
+    }
+
+    fn push(&self, hook: GradHook<B, D>) {
+        self.hooks.lock().push(Arc::new(hook));
+    }
+
+    /// Create new hooks not shared with the current ones, used when the tensor identity changes.
+    pub fn fork(&self) -> Self {
+        Self {
+            hooks: Arc::new(Mutex::new(self.hooks.lock().clone())),
+        }
+    }
+
+    fn apply(&self, grad: B::TensorPrimitive<D>) -> B::TensorPrimitive<D> {
+        let hooks = self.hooks.lock().clone();
+        hooks.iter().fold(grad, |grad, hook| hook(grad))
+    }
+}
+
+impl<B: Backend, const D: usize> Default for GradHooks<B, D> {
+    fn default() -> Self {
+        Self {
+            hooks: Arc::new(Mutex::new(Vec::new())),
+        }
+    }
+}
+
+impl<B: Backend, const D: usize> Clone for GradHooks<B, D> {
+    fn clone(&self) -> Self {
+        Self {
+            hooks: self.hooks.clone(),
+        }
+    }
+}
+
+impl<B: Backend, const D: usize> core::fmt::Debug for GradHooks<B, D> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("GradHooks")
+            .field("num_hooks", &self.hooks.lock().len())
+            .finish()
+    }
+}
+
+/// Step applying the gradient hooks of a node before executing its backward step.
 #[derive(new, Debug)]
-struct RootStep {
+struct HookStep<B: Backend, const D: usize> {
+    step: StepBoxed,
+    hooks: GradHooks<B, D>,
+}
+
+impl<B: Backend, const D: usize> Step for HookStep<B, D> {
+    fn step(self: Box<Self>, grads: &mut Gradients) {
+        let node = self.step.node();
+        grads.map::<B, D, _>(&node, |grad| self.hooks.apply(grad));
+        self.step.step(grads)
+    }
+
+    fn node(&self) -> NodeRef {
+        self.step.node()
+    }
+
+    fn clone_step(&self) -> StepBoxed {
+        Box::new(Self::new(self.step.clone_step(), self.hooks.clone()))
+    }
+
+    fn describe(&self) -> StepDescription {
+        self.step.describe()
+    }
+}
+
+#[derive(new, Debug)]
+struct RootStep<B: Backend, const D: usize> {
     node: NodeRef,
+    shape: Vec<usize>,
+    phantom: PhantomData<B>,
 }
 
-impl Step for RootStep {
-    fn step(self: Box<Self>, _grads: &mut Gradients) {
-        // Nothing to do
+impl<B: Backend, const D: usize> Step for RootStep<B, D> {
+    fn step(self: Box<Self>, grads: &mut Gradients) {
+        // Leaves are executed last, so their gradients are fully accumulated.
+        grads.transform_leaf::<B, D>(&self.node);
     }
 
     fn node(&self) -> NodeRef {
         self.node.clone()
     }
+
+    fn clone_step(&self) -> StepBoxed {
+        Box::new(Self::new(self.node.clone(), self.shape.clone()))
+    }
+
+    fn describe(&self) -> StepDescription {
+        StepDescription::new("Leaf", self.shape.clone(), 0)
+    }
 }
 
 impl<B: Backend, const D: usize> AutodiffTensor<B, D> {
@@ -39,6 +132,7 @@ impl<B: Backend, const D: usize> AutodiffTensor<B, D> {
             primitive,
             node: node.into(),
             graph: Graph::new(),
+            hooks: GradHooks::default(),
         }
     }
 
@@ -59,13 +153,55 @@ impl<B: Backend, const D: usize> AutodiffTensor<B, D> {
             }
             Requirement::None => {
                 self.node = Node::new(vec![], 0, self.node.id.clone(), Requirement::Grad).into();
-                let ops = RootStep::new(self.node.clone());
+                let shape = B::shape(&self.primitive).dims.to_vec();
+                let ops = RootStep::<B, D>::new(self.node.clone(), shape);
+                let tensor = self.register_step(ops);
 
-                self.register_step(ops)
+                match tensor.hooks.is_empty() {
+                    true => tensor,
+                    false => tensor.wrap_step_with_hooks(),
+                }
             }
         }
     }
 
+    /// Register a hook executed on the gradient of the tensor during the backward pass.
+    ///
+    /// When the tensor isn't tracked, the hook is kept and will be applied once the tensor
+    /// requires gradients.
+    pub fn register_hook(self, hook: GradHook<B, D>) -> Self {
+        let was_empty = self.hooks.is_empty();
+        self.hooks.push(hook);
+
+        match self.is_tracked() && was_empty {
+            true => self.wrap_step_with_hooks(),
+            false => self,
+        }
+    }
+
+    /// Transfer the hooks of another tensor representing the same value, e.g. after a
+    /// detach or a device transfer.
+    pub fn with_hooks(mut self, hooks: &GradHooks<B, D>) -> Self {
+        if hooks.is_empty() {
+            return self;
+        }
+
+        self.hooks = hooks.fork();
+
+        match self.is_tracked() {
+            true => self.wrap_step_with_hooks(),
+            false => self,
+        }
+    }
+
+    fn wrap_step_with_hooks(mut self) -> Self {
+        let hooks = self.hooks.clone();
+        self.graph = self.graph.map_step(&self.node.id, move |step| {
+            Box::new(HookStep::<B, D>::new(step, hooks))
+        });
+        self
+    }
+
     /// Create a tensor from parent infos.
     pub fn from_parents<I: Iterator<Item = Graph>>(
         output: B::TensorPrimitive<D>,
@@ -73,9 +209,14 @@ impl<B: Backend, const D: usize> AutodiffTensor<B, D> {
         parent_graphs: I,
         requirement: Requirement,
     ) -> Self {
-        let graph = parent_graphs
-            .reduce(|acc, graph| acc.merge(graph))
-            .unwrap_or_else(Graph::new);
+        // Untracked tensors don't need the steps of their parents, merging them would only move
+        // the steps of tracked parents into a graph that is never executed.
+        let graph = match requirement.is_none() {
+            true => Graph::new(),
+            false => parent_graphs
+                .reduce(|acc, graph| acc.merge(graph))
+                .unwrap_or_else(Graph::new),
+        };
 
         let order = parent_nodes
             .iter()
@@ -95,6 +236,7 @@ impl<B: Backend, const D: usize> AutodiffTensor<B, D> {
             primitive: output,
             node: node.into(),
             graph,
+            hooks: GradHooks::default(),
         }
     }
 
@@ -0,0 +1,42 @@
+#[burn_tensor_testgen::testgen(ad_anomaly)]
+mod tests {
+    use super::*;
+    use burn_autodiff::detect_anomaly;
+    use burn_tensor::Data;
+
+    #[test]
+    #[should_panic(expected = "Anomaly detected")]
+    fn should_report_non_finite_gradients() {
+        let data = Data::<f32, 1>::from([0.0, 4.0]);
+
+        let device = Default::default();
+        let tensor = TestAutodiffTensor::from_data(data, &device).require_grad();
+
+        detect_anomaly(|| tensor.clone().sqrt().sum().backward());
+    }
+
+    #[test]
+    fn should_not_report_finite_gradients() {
+        let data = Data::<f32, 1>::from([1.0, 4.0]);
+
+        let device = Default::default();
+        let tensor = TestAutodiffTensor::from_data(data, &device).require_grad();
+
+        let grads = detect_anomaly(|| tensor.clone().sqrt().sum().backward());
+        let grad = tensor.grad(&grads).unwrap();
+
+        assert_eq!(grad.to_data(), Data::from([0.5, 0.25]));
+    }
+
+    #[test]
+    fn should_not_check_gradients_without_anomaly_detection() {
+        let data = Data::<f32, 1>::from([0.0, 4.0]);
+
+        let device = Default::default();
+        let tensor = TestAutodiffTensor::from_data(data, &device).require_grad();
+
+        let grads = tensor.clone().sqrt().sum().backward();
+
+        assert!(tensor.grad(&grads).is_some());
+    }
+}
@@ -0,0 +1,130 @@
+#[burn_tensor_testgen::testgen(ad_attention)]
+mod tests {
+    use super::*;
+    use burn_tensor::{activation::softmax, module::attention, Distribution, Tensor};
+
+    #[test]
+    fn should_diff_attention() {
+        AttentionTestCase {
+            seq_length_1: 3,
+            seq_length_2: 5,
+        }
+        .assert_grads();
+    }
+
+    #[test]
+    fn should_diff_attention_across_multiple_blocks() {
+        AttentionTestCase {
+            seq_length_1: 130,
+            seq_length_2: 7,
+        }
+        .assert_grads();
+    }
+
+    #[test]
+    fn should_diff_attention_twice() {
+        type TestHigherOrderBackend = burn_autodiff::Autodiff<TestAutodiffBackend>;
+
+        let device = Default::default();
+        let scale = 0.5;
+        let query = TestAutodiffTensor::random([1, 2, 3, 4], Distribution::Default, &device);
+        let key = TestAutodiffTensor::random([1, 2, 5, 4], Distribution::Default, &device);
+        let value = TestAutodiffTensor::random([1, 2, 5, 3], Distribution::Default, &device);
+
+        let grad_grad = |composed: bool| {
+            let query_inner = query.clone().require_grad();
+            let query =
+                Tensor::<TestHigherOrderBackend, 4>::from_inner(query_inner.clone()).require_grad();
+            let key = Tensor::<TestHigherOrderBackend, 4>::from_inner(key.clone());
+            let value = Tensor::<TestHigherOrderBackend, 4>::from_inner(value.clone());
+
+            let output = match composed {
+                true => {
+                    let scores = query.clone().matmul(key.swap_dims(2, 3)).mul_scalar(scale);
+                    softmax(scores, 3).matmul(value)
+                }
+                false => attention(query.clone(), key, value, scale),
+            };
+            let grads = output.clone().mul(output).sum().backward();
+            let grad = query.grad(&grads).unwrap();
+            let grads = grad.clone().mul(grad).sum().backward();
+
+            query_inner.grad(&grads).unwrap()
+        };
+
+        grad_grad(false)
+            .into_data()
+            .assert_approx_eq(&grad_grad(true).into_data(), 3);
+    }
+
+    struct AttentionTestCase {
+        seq_length_1: usize,
+        seq_length_2: usize,
+    }
+
+    impl AttentionTestCase {
+        fn assert_grads(self) {
+            let device = Default::default();
+            let scale = 0.5;
+            let query = TestAutodiffTensor::random(
+                [2, 2, self.seq_length_1, 4],
+                Distribution::Default,
+                &device,
+            );
+            let key = TestAutodiffTensor::random(
+                [2, 2, self.seq_length_2, 4],
+                Distribution::Default,
+                &device,
+            );
+            let value = TestAutodiffTensor::random(
+                [2, 2, self.seq_length_2, 3],
+                Distribution::Default,
+                &device,
+            );
+            let weights = TestAutodiffTensor::random(
+                [2, 2, self.seq_length_1, 3],
+                Distribution::Default,
+                &device,
+            );
+
+            let (query_1, key_1, value_1) = (
+                query.clone().require_grad(),
+                key.clone().require_grad(),
+                value.clone().require_grad(),
+            );
+            let output_1 = attention(query_1.clone(), key_1.clone(), value_1.clone(), scale);
+            let grads_1 = output_1.clone().mul(weights.clone()).sum().backward();
+
+            let (query_2, key_2, value_2) = (
+                query.require_grad(),
+                key.require_grad(),
+                value.require_grad(),
+            );
+            let scores = query_2
+                .clone()
+                .matmul(key_2.clone().swap_dims(2, 3))
+                .mul_scalar(scale);
+            let output_2 = softmax(scores, 3).matmul(value_2.clone());
+            let grads_2 = output_2.clone().mul(weights).sum().backward();
+
+            output_1
+                .into_data()
+                .assert_approx_eq(&output_2.into_data(), 3);
+            query_1
+                .grad(&grads_1)
+                .unwrap()
+                .into_data()
+                .assert_approx_eq(&query_2.grad(&grads_2).unwrap().into_data(), 3);
+            key_1
+                .grad(&grads_1)
+                .unwrap()
+                .into_data()
+                .assert_approx_eq(&key_2.grad(&grads_2).unwrap().into_data(), 3);
+            value_1
+                .grad(&grads_1)
+                .unwrap()
+                .into_data()
+                .assert_approx_eq(&value_2.grad(&grads_2).unwrap().into_data(), 3);
+        }
+    }
+}
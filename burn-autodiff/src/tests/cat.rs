@@ -79,4 +79,21 @@ mod tests {
         assert_eq!(tensor_1.dims(), grad_1.dims());
         assert_eq!(tensor_2.dims(), grad_2.dims());
     }
+
+    #[test]
+    fn should_diff_cat_after_untracked_tensor() {
+        let device = Default::default();
+        let tensor_1 = TestAutodiffTensor::from_data([[1.0, 2.0]], &device);
+        let tensor_2 = TestAutodiffTensor::from_data([[3.0, 4.0]], &device).require_grad();
+        let weights = TestAutodiffTensor::from_data([[1.0, 2.0], [3.0, 4.0]], &device);
+
+        let tensor_3 = TestAutodiffTensor::cat(vec![tensor_1, tensor_2.clone()], 0).mul(weights);
+        let grads = tensor_3.sum().backward();
+
+        let grad_2 = tensor_2.grad(&grads).unwrap();
+
+        grad_2
+            .to_data()
+            .assert_approx_eq(&Data::from([[3.0, 4.0]]), 3);
+    }
 }
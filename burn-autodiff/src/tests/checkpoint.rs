@@ -0,0 +1,116 @@
+#[burn_tensor_testgen::testgen(ad_checkpoint)]
+mod tests {
+    use super::*;
+    use burn_autodiff::checkpoint_scope;
+    use burn_tensor::Data;
+    use std::sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    };
+
+    #[test]
+    fn should_diff_checkpointed_scope_like_regular_graph() {
+        let data_1 = Data::<f32, 2>::from([[1.0, 7.0], [2.0, 3.0]]);
+        let data_2 = Data::<f32, 2>::from([[4.0, 7.0], [2.0, 3.0]]);
+
+        let device = Default::default();
+        let tensor_1 = TestAutodiffTensor::from_data(data_1.clone(), &device).require_grad();
+        let tensor_2 = TestAutodiffTensor::from_data(data_2.clone(), &device).require_grad();
+        let weights = tensor_2.clone();
+
+        let tensor_3 = checkpoint_scope([tensor_1.clone()], move |[x]| {
+            x.clone().matmul(weights.clone()).tanh().mul(x)
+        });
+        let grads = tensor_3.mul_scalar(2.0).sum().backward();
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+        let grad_2 = tensor_2.grad(&grads).unwrap();
+
+        let tensor_1_expected = TestAutodiffTensor::from_data(data_1, &device).require_grad();
+        let tensor_2_expected = TestAutodiffTensor::from_data(data_2, &device).require_grad();
+        let tensor_3_expected = tensor_1_expected
+            .clone()
+            .matmul(tensor_2_expected.clone())
+            .tanh()
+            .mul(tensor_1_expected.clone());
+        let grads = tensor_3_expected.mul_scalar(2.0).sum().backward();
+
+        grad_1
+            .to_data()
+            .assert_approx_eq(&tensor_1_expected.grad(&grads).unwrap().to_data(), 3);
+        grad_2
+            .to_data()
+            .assert_approx_eq(&tensor_2_expected.grad(&grads).unwrap().to_data(), 3);
+    }
+
+    #[test]
+    fn should_recompute_scope_during_backward() {
+        let data = Data::<f32, 1>::from([1.0, 2.0]);
+        let counter = Arc::new(AtomicUsize::new(0));
+        let counter_scope = counter.clone();
+
+        let device = Default::default();
+        let tensor_1 = TestAutodiffTensor::from_data(data, &device).require_grad();
+        let tensor_2 = checkpoint_scope([tensor_1.clone()], move |[x]| {
+            counter_scope.fetch_add(1, Ordering::Relaxed);
+            x.clone().mul(x)
+        });
+
+        assert_eq!(counter.load(Ordering::Relaxed), 1);
+
+        let grads = tensor_2.backward();
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+
+        assert_eq!(counter.load(Ordering::Relaxed), 2);
+        assert_eq!(grad_1.to_data(), Data::from([2.0, 4.0]));
+    }
+
+    #[test]
+    fn should_diff_captured_tensor_with_untracked_inputs() {
+        let data_1 = Data::<f32, 2>::from([[1.0, 7.0], [2.0, 3.0]]);
+        let data_2 = Data::<f32, 2>::from([[4.0, 7.0], [2.0, 3.0]]);
+
+        let device = Default::default();
+        let tensor_1 = TestAutodiffTensor::from_data(data_1.clone(), &device);
+        let tensor_2 = TestAutodiffTensor::from_data(data_2.clone(), &device).require_grad();
+        let weights = tensor_2.clone();
+
+        let tensor_3 = checkpoint_scope([tensor_1], move |[x]| {
+            x.clone().matmul(weights.clone()).tanh().mul(x)
+        });
+        let grads = tensor_3.sum().backward();
+        let grad_2 = tensor_2.grad(&grads).unwrap();
+
+        let tensor_1_expected = TestAutodiffTensor::from_data(data_1, &device);
+        let tensor_2_expected = TestAutodiffTensor::from_data(data_2, &device).require_grad();
+        let tensor_3_expected = tensor_1_expected
+            .clone()
+            .matmul(tensor_2_expected.clone())
+            .tanh()
+            .mul(tensor_1_expected);
+        let grads = tensor_3_expected.sum().backward();
+
+        grad_2
+            .to_data()
+            .assert_approx_eq(&tensor_2_expected.grad(&grads).unwrap().to_data(), 3);
+    }
+
+    #[test]
+    fn should_not_recompute_untracked_scope() {
+        let data = Data::<f32, 1>::from([1.0, 2.0]);
+        let counter = Arc::new(AtomicUsize::new(0));
+        let counter_scope = counter.clone();
+
+        let device = Default::default();
+        let tensor_1 = TestAutodiffTensor::from_data(data.clone(), &device);
+        let tensor_2 = TestAutodiffTensor::from_data(data, &device).require_grad();
+        let tensor_3 = checkpoint_scope([tensor_1], move |[x]| {
+            counter_scope.fetch_add(1, Ordering::Relaxed);
+            x.exp()
+        });
+
+        let grads = tensor_3.mul(tensor_2.clone()).backward();
+
+        assert!(tensor_2.grad(&grads).is_some());
+        assert_eq!(counter.load(Ordering::Relaxed), 1);
+    }
+}
@@ -0,0 +1,39 @@
+#[burn_tensor_testgen::testgen(ad_cumulative)]
+mod tests {
+    use super::*;
+    use burn_tensor::Data;
+
+    #[test]
+    fn should_diff_cumsum() {
+        let device = Default::default();
+        let tensor_1 = TestAutodiffTensor::from_floats([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]], &device)
+            .require_grad();
+        let weights = TestAutodiffTensor::from_floats([[1.0, 2.0, 3.0], [1.0, 0.0, 2.0]], &device);
+
+        let tensor_2 = tensor_1.clone().cumsum(1).mul(weights);
+        let grads = tensor_2.sum().backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+
+        grad_1
+            .to_data()
+            .assert_approx_eq(&Data::from([[6.0, 5.0, 3.0], [3.0, 2.0, 2.0]]), 5);
+    }
+
+    #[test]
+    fn should_diff_cumprod() {
+        let device = Default::default();
+        let tensor_1 =
+            TestAutodiffTensor::from_floats([[2.0, 1.0], [3.0, -1.0], [4.0, 2.0]], &device)
+                .require_grad();
+
+        let tensor_2 = tensor_1.clone().cumprod(0);
+        let grads = tensor_2.sum().backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+
+        grad_1
+            .to_data()
+            .assert_approx_eq(&Data::from([[16.0, -2.0], [10.0, 3.0], [6.0, -1.0]]), 5);
+    }
+}
@@ -0,0 +1,72 @@
+#[burn_tensor_testgen::testgen(ad_custom)]
+mod tests {
+    use super::*;
+    use burn_autodiff::ops::custom_op;
+    use burn_tensor::Data;
+    use core::f32::consts::E;
+
+    #[test]
+    fn should_diff_custom_binary_op() {
+        let data_1 = Data::<f32, 1>::from([1.0, 2.0]);
+        let data_2 = Data::<f32, 1>::from([3.0, 5.0]);
+
+        let device = Default::default();
+        let tensor_1 = TestAutodiffTensor::from_data(data_1.clone(), &device).require_grad();
+        let tensor_2 = TestAutodiffTensor::from_data(data_2.clone(), &device).require_grad();
+
+        let tensor_3 = custom_op(
+            [tensor_1.clone(), tensor_2.clone()],
+            |[lhs, rhs]| lhs.mul(rhs),
+            |[lhs, rhs], _output, grad| [grad.clone().mul(rhs.clone()), grad.mul(lhs.clone())],
+        );
+        let tensor_4 = tensor_3.clone().mul_scalar(2.0);
+        let grads = tensor_4.backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+        let grad_2 = tensor_2.grad(&grads).unwrap();
+
+        assert_eq!(tensor_3.into_data(), Data::from([3.0, 10.0]));
+        assert_eq!(grad_1.to_data(), Data::from([6.0, 10.0]));
+        assert_eq!(grad_2.to_data(), Data::from([2.0, 4.0]));
+    }
+
+    #[test]
+    fn should_diff_custom_op_with_output_state() {
+        let data = Data::<f32, 1>::from([0.0, 1.0]);
+
+        let device = Default::default();
+        let tensor_1 = TestAutodiffTensor::from_data(data, &device).require_grad();
+
+        let tensor_2 = custom_op(
+            [tensor_1.clone()],
+            |[tensor]| tensor.exp(),
+            |_inputs, output, grad| [grad.mul(output.clone())],
+        );
+        let grads = tensor_2.backward();
+        let grad = tensor_1.grad(&grads).unwrap();
+
+        grad.to_data().assert_approx_eq(&Data::from([1.0, E]), 3);
+    }
+
+    #[test]
+    fn should_not_track_custom_op_on_untracked_inputs() {
+        let data = Data::<f32, 1>::from([1.0, 2.0]);
+
+        let device = Default::default();
+        let tensor_1 = TestAutodiffTensor::from_data(data.clone(), &device);
+        let tensor_2 = TestAutodiffTensor::from_data(data, &device).require_grad();
+
+        let tensor_3 = custom_op(
+            [tensor_1.clone()],
+            |[tensor]| tensor.mul_scalar(3.0),
+            |_inputs, _output, _grad| panic!("Untracked custom op shouldn't be executed"),
+        );
+        let grads = tensor_3.mul(tensor_2.clone()).backward();
+
+        assert!(tensor_1.grad(&grads).is_none());
+        assert_eq!(
+            tensor_2.grad(&grads).unwrap().into_data(),
+            Data::from([3.0, 6.0])
+        );
+    }
+}
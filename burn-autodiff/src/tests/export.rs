@@ -0,0 +1,71 @@
+#[burn_tensor_testgen::testgen(ad_export)]
+mod tests {
+    use super::*;
+    use burn_autodiff::{export_graph, NodeKind};
+    use burn_tensor::Data;
+
+    #[test]
+    fn should_export_graph_in_topological_order() {
+        let device = Default::default();
+        let tensor_1 = TestAutodiffTensor::from_data(Data::from([[1.0, 2.0], [3.0, 4.0]]), &device)
+            .require_grad();
+        let tensor_2 = TestAutodiffTensor::from_data(Data::from([[5.0, 6.0], [7.0, 8.0]]), &device);
+
+        let tensor_3 = tensor_1.clone().matmul(tensor_2).exp();
+        let graph = export_graph(&tensor_3);
+
+        let kinds = graph.nodes.iter().map(|node| node.kind).collect::<Vec<_>>();
+        let names = graph.nodes.iter().map(|node| node.name).collect::<Vec<_>>();
+        assert_eq!(
+            kinds,
+            vec![
+                NodeKind::Leaf,
+                NodeKind::Untracked,
+                NodeKind::Operation,
+                NodeKind::Operation
+            ]
+        );
+        assert_eq!(names, vec!["Leaf", "Untracked", "Matmul", "Exp"]);
+        assert_eq!(graph.nodes[3].shape, Some(vec![2, 2]));
+        assert_eq!(graph.nodes[1].shape, None);
+        assert!(graph.nodes[3].retained_bytes > 0);
+
+        let dot = graph.to_dot();
+        assert!(dot.starts_with("digraph autodiff {"));
+        assert!(dot.contains(&format!(
+            "n{} -> n{};",
+            graph.nodes[2].id, graph.nodes[3].id
+        )));
+        assert!(graph.to_json().starts_with("{\"nodes\":[{\"id\":"));
+    }
+
+    #[test]
+    fn should_not_consume_graph_when_exporting() {
+        let device = Default::default();
+        let tensor_1 =
+            TestAutodiffTensor::from_data(Data::from([1.0, 2.0]), &device).require_grad();
+
+        let tensor_2 = tensor_1.clone().mul_scalar(3.0).sum();
+        let graph = export_graph(&tensor_2);
+        let grads = tensor_2.backward();
+
+        assert_eq!(graph.nodes.len(), 3);
+        assert_eq!(
+            tensor_1.grad(&grads).unwrap().to_data(),
+            Data::from([3.0, 3.0])
+        );
+    }
+
+    #[test]
+    fn should_export_untracked_tensor() {
+        let device = Default::default();
+        let tensor = TestAutodiffTensor::from_data(Data::from([1.0, 2.0]), &device).exp();
+
+        let graph = export_graph(&tensor);
+
+        assert_eq!(graph.nodes.len(), 2);
+        assert_eq!(graph.nodes[1].kind, NodeKind::Untracked);
+        assert_eq!(graph.nodes[1].shape, Some(vec![2]));
+        assert_eq!(graph.retained_bytes(), 0);
+    }
+}
@@ -0,0 +1,111 @@
+#[burn_tensor_testgen::testgen(ad_functional)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, Tensor};
+
+    #[test]
+    fn should_compute_grad_with_closure() {
+        let data = Data::<f32, 1>::from([1.0, -2.0, 3.0]);
+        let device = Default::default();
+
+        let grad_fn = burn_autodiff::grad(|x: TestAutodiffTensor<1>| x.clone().mul(x).sum());
+        let grad = grad_fn(TestAutodiffTensor::from_data(data, &device));
+
+        assert_eq!(grad.into_data(), Data::from([2.0, -4.0, 6.0]));
+    }
+
+    #[test]
+    fn should_compute_value_and_grad() {
+        let data = Data::<f32, 1>::from([1.0, 2.0]);
+        let device = Default::default();
+
+        let (value, grad) = burn_autodiff::value_and_grad(
+            |x: TestAutodiffTensor<1>| x.mul_scalar(3.0).sum(),
+            TestAutodiffTensor::from_data(data, &device),
+        );
+
+        assert_eq!(value.into_data(), Data::from([9.0]));
+        assert_eq!(grad.into_data(), Data::from([3.0, 3.0]));
+    }
+
+    #[test]
+    fn should_return_zeros_when_output_is_independent_of_input() {
+        let data_1 = Data::<f32, 1>::from([1.0, 2.0]);
+        let data_2 = Data::<f32, 1>::from([3.0, 4.0]);
+        let device = Default::default();
+        let other = TestAutodiffTensor::from_data(data_2, &device).require_grad();
+
+        let grad =
+            burn_autodiff::grad(|x: TestAutodiffTensor<1>| x.detach().add(other.clone()).sum())(
+                TestAutodiffTensor::from_data(data_1, &device),
+            );
+
+        assert_eq!(grad.into_data(), Data::from([0.0, 0.0]));
+    }
+
+    #[test]
+    fn should_compute_vjp() {
+        let data = Data::<f32, 2>::from([[1.0, 2.0], [3.0, 4.0]]);
+        let cotangent = Data::<f32, 2>::from([[1.0, 0.0], [0.0, 2.0]]);
+        let device = Default::default();
+
+        let (value, pullback) = burn_autodiff::vjp(
+            |x: TestAutodiffTensor<2>| x.clone().mul(x),
+            TestAutodiffTensor::from_data(data, &device),
+        );
+        let grad = pullback(Tensor::<TestBackend, 2>::from_data(cotangent, &device));
+
+        assert_eq!(value.into_data(), Data::from([[1.0, 4.0], [9.0, 16.0]]));
+        assert_eq!(grad.into_data(), Data::from([[2.0, 0.0], [0.0, 16.0]]));
+    }
+
+    #[test]
+    fn should_compute_jacobian() {
+        let data = Data::<f32, 1>::from([1.0, 2.0]);
+        let device = Default::default();
+
+        let jacobian = burn_autodiff::jacobian(
+            |x: TestAutodiffTensor<1>| {
+                let squared = x.clone().mul(x.clone());
+                Tensor::cat(vec![squared, x.sum()], 0)
+            },
+            TestAutodiffTensor::from_data(data, &device),
+        );
+
+        assert_eq!(
+            jacobian.into_data(),
+            Data::from([[2.0, 0.0], [0.0, 4.0], [1.0, 1.0]])
+        );
+    }
+
+    #[test]
+    fn should_compute_hessian_vector_product() {
+        let device = Default::default();
+        let input = Tensor::<TestBackend, 1>::from_data(Data::from([1.0, 2.0]), &device);
+        let vector = Tensor::<TestBackend, 1>::from_data(Data::from([3.0, -1.0]), &device);
+
+        // The Hessian of sum(x³) + x₀x₁ is [[6x₀, 1], [1, 6x₁]].
+        let product = burn_autodiff::hvp(
+            |x| {
+                let cubed = x.clone().mul(x.clone()).mul(x.clone()).sum();
+                let cross = x.clone().slice([0..1]).mul(x.slice([1..2]));
+                cubed.add(cross)
+            },
+            input,
+            vector,
+        );
+
+        assert_eq!(product.into_data(), Data::from([17.0, -9.0]));
+    }
+
+    #[test]
+    fn should_compute_zero_hessian_vector_product_for_linear_function() {
+        let device = Default::default();
+        let input = Tensor::<TestBackend, 1>::from_data(Data::from([1.0, 2.0]), &device);
+        let vector = Tensor::<TestBackend, 1>::from_data(Data::from([3.0, -1.0]), &device);
+
+        let product = burn_autodiff::hvp(|x| x.mul_scalar(2.0).sum(), input, vector);
+
+        assert_eq!(product.into_data(), Data::from([0.0, 0.0]));
+    }
+}
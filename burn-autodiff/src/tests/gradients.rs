@@ -24,4 +24,38 @@ mod tests {
         assert_ne!(grad_1_new.to_data(), grad_1.into_data());
         assert_eq!(grad_1_new.into_data(), grad_1_updated.into_data());
     }
+
+    #[test]
+    fn should_diff_two_forwards_over_the_same_params() {
+        let device = Default::default();
+        let weight =
+            TestAutodiffTensor::from_data([[1.0, 2.0], [3.0, 4.0]], &device).require_grad();
+        let bias = TestAutodiffTensor::from_data([[1.0, -1.0]], &device).require_grad();
+        let input_1 = TestAutodiffTensor::from_data([[1.0, 2.0], [3.0, 4.0]], &device);
+        let input_2 = TestAutodiffTensor::from_data([[-1.0, 0.5], [2.0, 1.0]], &device);
+
+        let loss_1 = input_1.matmul(weight.clone()).add(bias.clone()).sum();
+        let loss_2 = input_2.matmul(weight.clone()).add(bias.clone()).sum();
+        let grads_1 = loss_1.backward();
+        let grads_2 = loss_2.backward();
+
+        weight
+            .grad(&grads_1)
+            .unwrap()
+            .to_data()
+            .assert_approx_eq(&Data::from([[4.0, 4.0], [6.0, 6.0]]), 3);
+        weight
+            .grad(&grads_2)
+            .unwrap()
+            .to_data()
+            .assert_approx_eq(&Data::from([[1.0, 1.0], [1.5, 1.5]]), 3);
+        bias.grad(&grads_1)
+            .unwrap()
+            .to_data()
+            .assert_approx_eq(&Data::from([[2.0, 2.0]]), 3);
+        bias.grad(&grads_2)
+            .unwrap()
+            .to_data()
+            .assert_approx_eq(&Data::from([[2.0, 2.0]]), 3);
+    }
 }
@@ -0,0 +1,86 @@
+#[burn_tensor_testgen::testgen(ad_higher_order)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, Tensor};
+    use core::f32::consts::E;
+
+    type TestHigherOrderBackend = burn_autodiff::Autodiff<TestAutodiffBackend>;
+
+    #[test]
+    fn should_diff_mul_twice() {
+        let data = Data::<f32, 1>::from([2.0, 3.0]);
+
+        let device = Default::default();
+        let tensor_inner = TestAutodiffTensor::from_data(data, &device).require_grad();
+        let tensor =
+            Tensor::<TestHigherOrderBackend, 1>::from_inner(tensor_inner.clone()).require_grad();
+
+        let tensor_out = tensor.clone().mul(tensor.clone()).mul(tensor.clone());
+        let grads = tensor_out.backward();
+        let grad = tensor.grad(&grads).unwrap();
+
+        assert_eq!(grad.to_data(), Data::from([12.0, 27.0]));
+
+        let grads = grad.sum().backward();
+        let grad_grad = tensor_inner.grad(&grads).unwrap();
+
+        assert_eq!(grad_grad.to_data(), Data::from([12.0, 18.0]));
+    }
+
+    #[test]
+    fn should_diff_exp_twice() {
+        let data = Data::<f32, 1>::from([0.0, 1.0]);
+
+        let device = Default::default();
+        let tensor_inner = TestAutodiffTensor::from_data(data, &device).require_grad();
+        let tensor =
+            Tensor::<TestHigherOrderBackend, 1>::from_inner(tensor_inner.clone()).require_grad();
+
+        let tensor_out = tensor.clone().exp();
+        let grads = tensor_out.backward();
+        let grad = tensor.grad(&grads).unwrap();
+
+        grad.to_data().assert_approx_eq(&Data::from([1.0, E]), 3);
+
+        let grads = grad.sum().backward();
+        let grad_grad = tensor_inner.grad(&grads).unwrap();
+
+        grad_grad
+            .to_data()
+            .assert_approx_eq(&Data::from([1.0, E]), 3);
+    }
+
+    #[test]
+    fn should_diff_gradient_penalty() {
+        let data_1 = Data::<f32, 2>::from([[1.0, 2.0], [3.0, 4.0]]);
+        let data_2 = Data::<f32, 2>::from([[0.5, -1.0], [2.0, 1.0]]);
+
+        let device = Default::default();
+        let weights_inner = TestAutodiffTensor::from_data(data_1, &device).require_grad();
+        let weights =
+            Tensor::<TestHigherOrderBackend, 2>::from_inner(weights_inner.clone()).require_grad();
+        let input = Tensor::<TestHigherOrderBackend, 2>::from_data(data_2, &device);
+
+        // Loss = sum(w * x), its gradient w.r.t. w is x (constant), so the penalty
+        // ||dL/dw||^2 has a zero gradient w.r.t. w.
+        let tensor_out = weights.clone().mul(input).sum();
+        let grads = tensor_out.backward();
+        let grad = weights.grad(&grads).unwrap();
+        let penalty = grad.clone().mul(grad).sum();
+
+        assert_eq!(penalty.clone().into_data(), Data::from([6.25]));
+
+        // Loss = sum(w * w), gradient is 2w, so the penalty sum((2w)^2) has gradient 8w.
+        let tensor_out = weights.clone().mul(weights.clone()).sum();
+        let grads = tensor_out.backward();
+        let grad = weights.grad(&grads).unwrap();
+        let penalty = grad.clone().mul(grad).sum();
+        let grads = penalty.backward();
+        let grad_penalty = weights_inner.grad(&grads).unwrap();
+
+        assert_eq!(
+            grad_penalty.to_data(),
+            Data::from([[8.0, 16.0], [24.0, 32.0]])
+        );
+    }
+}
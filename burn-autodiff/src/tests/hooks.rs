@@ -0,0 +1,86 @@
+#[burn_tensor_testgen::testgen(ad_hooks)]
+mod tests {
+    use super::*;
+    use burn_tensor::Data;
+    use std::sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    };
+
+    #[test]
+    fn should_modify_leaf_gradient_with_hook() {
+        let data_1 = Data::<f32, 1>::from([1.0, 2.0]);
+        let data_2 = Data::<f32, 1>::from([3.0, 4.0]);
+
+        let device = Default::default();
+        let tensor_1 = TestAutodiffTensor::from_data(data_1, &device)
+            .require_grad()
+            .register_grad_hook(|grad| grad.mul_scalar(2.0));
+        let tensor_2 = TestAutodiffTensor::from_data(data_2, &device).require_grad();
+
+        let grads = tensor_1.clone().mul(tensor_2.clone()).backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+        let grad_2 = tensor_2.grad(&grads).unwrap();
+
+        assert_eq!(grad_1.to_data(), Data::from([6.0, 8.0]));
+        assert_eq!(grad_2.to_data(), Data::from([1.0, 2.0]));
+    }
+
+    #[test]
+    fn should_propagate_modified_intermediate_gradient() {
+        let data = Data::<f32, 1>::from([1.0, 2.0]);
+
+        let device = Default::default();
+        let tensor_1 = TestAutodiffTensor::from_data(data, &device).require_grad();
+        let tensor_2 = tensor_1
+            .clone()
+            .mul_scalar(3.0)
+            .register_grad_hook(|grad| grad.clamp_max(0.5));
+        let tensor_3 = tensor_2.mul_scalar(2.0);
+
+        let grads = tensor_3.backward();
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+
+        assert_eq!(grad_1.to_data(), Data::from([1.5, 1.5]));
+    }
+
+    #[test]
+    fn should_execute_hooks_in_registration_order() {
+        let data = Data::<f32, 1>::from([1.0, 2.0]);
+
+        let device = Default::default();
+        let tensor = TestAutodiffTensor::from_data(data, &device)
+            .require_grad()
+            .register_grad_hook(|grad| grad.add_scalar(1.0))
+            .register_grad_hook(|grad| grad.mul_scalar(3.0));
+
+        let grads = tensor.clone().sum().backward();
+        let grad = tensor.grad(&grads).unwrap();
+
+        assert_eq!(grad.to_data(), Data::from([6.0, 6.0]));
+    }
+
+    #[test]
+    fn should_keep_hooks_after_detach_and_device_transfer() {
+        let data = Data::<f32, 1>::from([1.0, 2.0]);
+        let counter = Arc::new(AtomicUsize::new(0));
+        let counter_hook = counter.clone();
+
+        let device = Default::default();
+        let tensor = TestAutodiffTensor::from_data(data, &device)
+            .require_grad()
+            .register_grad_hook(move |grad| {
+                counter_hook.fetch_add(1, Ordering::Relaxed);
+                grad.neg()
+            })
+            .to_device(&device)
+            .detach();
+
+        let grads = tensor.clone().sum().backward();
+        let grad = tensor.grad(&grads).unwrap();
+
+        assert_eq!(grad.to_data(), Data::from([-1.0, -1.0]));
+        assert_eq!(counter.load(Ordering::Relaxed), 1);
+    }
+}
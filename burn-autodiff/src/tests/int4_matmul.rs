@@ -0,0 +1,82 @@
+#[burn_tensor_testgen::testgen(ad_int4_matmul)]
+mod tests {
+    use super::*;
+    use burn_tensor::{
+        module::{int4_dequantize, int4_matmul},
+        Data, Distribution, Int, Tensor,
+    };
+
+    #[test]
+    fn should_diff_int4_matmul() {
+        let device = Default::default();
+        let weight = Tensor::<TestAutodiffBackend, 2, Int>::from_ints(
+            [
+                [0x76543210, -1, 0x0F1E2D3C],
+                [0x01234567, 0x7FFFFFFF, -0x12345678],
+            ],
+            &device,
+        );
+        let scales = TestAutodiffTensor::from_floats(
+            [
+                [1.0, 0.5, 0.25],
+                [2.0, 0.25, 0.5],
+                [0.5, 1.0, 0.1],
+                [0.1, 0.2, 0.3],
+            ],
+            &device,
+        );
+        let zero_points = TestAutodiffTensor::from_floats(
+            [
+                [0.0, 15.0, 7.5],
+                [1.0, 14.0, 8.0],
+                [3.0, 0.0, 7.0],
+                [7.5, 2.0, 1.0],
+            ],
+            &device,
+        );
+        let lhs = TestAutodiffTensor::random([3, 16], Distribution::Default, &device);
+
+        let lhs_1 = lhs.clone().require_grad();
+        let output_1 = int4_matmul(
+            lhs_1.clone(),
+            weight.clone(),
+            scales.clone(),
+            zero_points.clone(),
+            4,
+        );
+        let grads_1 = output_1.clone().sum().backward();
+
+        let lhs_2 = lhs.require_grad();
+        let output_2 = lhs_2
+            .clone()
+            .matmul(int4_dequantize(weight, scales, zero_points, 4));
+        let grads_2 = output_2.clone().sum().backward();
+
+        output_1
+            .into_data()
+            .assert_approx_eq(&output_2.into_data(), 3);
+        lhs_1
+            .grad(&grads_1)
+            .unwrap()
+            .into_data()
+            .assert_approx_eq(&lhs_2.grad(&grads_2).unwrap().into_data(), 3);
+    }
+
+    #[test]
+    fn should_not_track_weights() {
+        let device = Default::default();
+        let weight = Tensor::<TestAutodiffBackend, 2, Int>::from_ints([[0x76543210]], &device);
+        let scales = TestAutodiffTensor::from_floats([[1.0]], &device).require_grad();
+        let zero_points = TestAutodiffTensor::from_floats([[0.0]], &device);
+        let lhs = TestAutodiffTensor::ones([1, 8], &device).require_grad();
+
+        let output = int4_matmul(lhs.clone(), weight, scales.clone(), zero_points, 8);
+        let grads = output.sum().backward();
+
+        lhs.grad(&grads)
+            .unwrap()
+            .into_data()
+            .assert_approx_eq(&Data::from([[0.0, 1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0]]), 3);
+        assert!(scales.grad(&grads).is_none());
+    }
+}
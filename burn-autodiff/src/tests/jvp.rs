@@ -0,0 +1,66 @@
+#[burn_tensor_testgen::testgen(ad_jvp)]
+mod tests {
+    use super::*;
+    use burn_autodiff::forward::{jvp, DualTensor};
+    use burn_tensor::{Data, Tensor};
+
+    #[test]
+    fn should_compute_jvp_mul() {
+        let device = Default::default();
+        let primal = Tensor::<TestBackend, 1>::from_data(Data::from([2.0, 3.0]), &device);
+        let tangent = Tensor::<TestBackend, 1>::from_data(Data::from([1.0, 0.5]), &device);
+
+        let (output, output_tangent) = jvp(
+            |x: DualTensor<TestBackend, 1>| x.clone().mul(x.clone()).mul(x),
+            primal,
+            tangent,
+        );
+
+        assert_eq!(output.into_data(), Data::from([8.0, 27.0]));
+        assert_eq!(output_tangent.into_data(), Data::from([12.0, 13.5]));
+    }
+
+    #[test]
+    fn should_compute_jvp_matmul_exp() {
+        let device = Default::default();
+        let weights =
+            Tensor::<TestBackend, 2>::from_data(Data::from([[1.0, 2.0], [3.0, 4.0]]), &device);
+        let primal = Tensor::<TestBackend, 2>::from_data(Data::from([[0.0, 1.0]]), &device);
+        let tangent = Tensor::<TestBackend, 2>::from_data(Data::from([[1.0, 0.0]]), &device);
+
+        let (_, output_tangent) = jvp(
+            |x: DualTensor<TestBackend, 2>| {
+                x.exp().matmul(DualTensor::constant(weights.clone())).sum()
+            },
+            primal,
+            tangent,
+        );
+
+        // d/dx0 sum(exp(x) W) = exp(x0) * (W00 + W01) = 3.
+        output_tangent
+            .into_data()
+            .assert_approx_eq(&Data::from([3.0]), 3);
+    }
+
+    #[test]
+    fn should_match_reverse_mode_tanh() {
+        let data = Data::<f32, 1>::from([0.5, -1.0, 2.0]);
+        let device = Default::default();
+
+        let primal = Tensor::<TestBackend, 1>::from_data(data.clone(), &device);
+        let tangent = primal.ones_like();
+        let (_, output_tangent) = jvp(
+            |x: DualTensor<TestBackend, 1>| x.tanh().mul_scalar(2.0),
+            primal,
+            tangent,
+        );
+
+        let tensor = TestAutodiffTensor::from_data(data, &device).require_grad();
+        let grads = tensor.clone().tanh().mul_scalar(2.0).sum().backward();
+        let grad = tensor.grad(&grads).unwrap();
+
+        output_tangent
+            .into_data()
+            .assert_approx_eq(&grad.into_data(), 3);
+    }
+}
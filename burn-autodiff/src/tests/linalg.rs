@@ -0,0 +1,55 @@
+#[burn_tensor_testgen::testgen(ad_linalg)]
+mod tests {
+    use super::*;
+    use burn_tensor::{linalg, Data};
+
+    #[test]
+    fn should_diff_solve() {
+        let device = Default::default();
+        let lhs = TestAutodiffTensor::from_floats([[3.0, 1.0], [1.0, 2.0]], &device).require_grad();
+        let rhs = TestAutodiffTensor::from_floats([[9.0], [8.0]], &device).require_grad();
+
+        let output = linalg::solve(lhs.clone(), rhs.clone());
+        let grads = output.sum().backward();
+
+        let grad_lhs = lhs.grad(&grads).unwrap();
+        let grad_rhs = rhs.grad(&grads).unwrap();
+
+        grad_lhs
+            .to_data()
+            .assert_approx_eq(&Data::from([[-0.4, -0.6], [-0.8, -1.2]]), 4);
+        grad_rhs
+            .to_data()
+            .assert_approx_eq(&Data::from([[0.2], [0.4]]), 4);
+    }
+
+    #[test]
+    fn should_diff_inverse() {
+        let device = Default::default();
+        let tensor =
+            TestAutodiffTensor::from_floats([[4.0, 7.0], [2.0, 6.0]], &device).require_grad();
+
+        let output = linalg::inverse(tensor.clone());
+        let grads = output.sum().backward();
+
+        let grad = tensor.grad(&grads).unwrap();
+
+        grad.to_data()
+            .assert_approx_eq(&Data::from([[0.04, -0.08], [-0.03, 0.06]]), 4);
+    }
+
+    #[test]
+    fn should_diff_cholesky() {
+        let device = Default::default();
+        let tensor =
+            TestAutodiffTensor::from_floats([[4.0, 2.0], [2.0, 5.0]], &device).require_grad();
+
+        let output = linalg::cholesky(tensor.clone());
+        let grads = output.sum().backward();
+
+        let grad = tensor.grad(&grads).unwrap();
+
+        grad.to_data()
+            .assert_approx_eq(&Data::from([[0.1875, 0.125], [0.125, 0.25]]), 4);
+    }
+}
@@ -58,4 +58,27 @@ mod tests {
             .into_data()
             .assert_approx_eq(&Data::from([[15., 18.], [23., 29.]]), 3);
     }
+
+    #[test]
+    fn should_diff_masked_select() {
+        let device = Default::default();
+        let tensor_1 =
+            TestAutodiffTensor::from_data([[1.0, 7.0], [2.0, 3.0]], &device).require_grad();
+        let tensor_2 =
+            TestAutodiffTensor::from_data([[4.0, 7.0], [2.0, 3.0]], &device).require_grad();
+        let mask = Tensor::<TestAutodiffBackend, 2, Bool>::from_data(
+            [[true, false], [false, true]],
+            &device,
+        );
+
+        let tensor_3 = tensor_1.clone().mul(tensor_2.clone());
+        let tensor_4 = tensor_3.masked_select(mask);
+        let grads = tensor_4.sum().backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+        let grad_2 = tensor_2.grad(&grads).unwrap();
+
+        assert_eq!(grad_1.to_data(), Data::from([[4.0, 0.0], [0.0, 3.0]]));
+        assert_eq!(grad_2.to_data(), Data::from([[1.0, 0.0], [0.0, 3.0]]));
+    }
 }
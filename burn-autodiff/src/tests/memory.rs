@@ -0,0 +1,25 @@
+#[burn_tensor_testgen::testgen(ad_memory)]
+mod tests {
+    use super::*;
+    use burn_tensor::{memory::MemoryProfiler, Data};
+
+    #[test]
+    fn should_report_state_retained_for_backward() {
+        let device = Default::default();
+        MemoryProfiler::enable();
+
+        let tensor_1 = TestAutodiffTensor::from_data(Data::from([[1.0, 2.0], [3.0, 4.0]]), &device)
+            .require_grad();
+        let tensor_2 = tensor_1.clone().exp();
+
+        let report = MemoryProfiler::report();
+        assert!(report.labels.iter().any(|usage| usage.source == "autodiff"
+            && usage.label == "Exp"
+            && usage.live_bytes >= 4 * core::mem::size_of::<f32>()));
+
+        let grads = tensor_2.backward();
+        assert!(tensor_1.grad(&grads).is_some());
+
+        MemoryProfiler::disable();
+    }
+}
@@ -5,11 +5,14 @@ mod adaptive_avgpool1d;
 mod adaptive_avgpool2d;
 mod add;
 mod aggregation;
+mod anomaly;
+mod attention;
 mod avgpool1d;
 mod avgpool2d;
 mod backward;
 mod broadcast;
 mod cat;
+mod checkpoint;
 mod complex;
 mod conv1d;
 mod conv2d;
@@ -17,12 +20,21 @@ mod conv_transpose1d;
 mod conv_transpose2d;
 mod cos;
 mod cross_entropy;
+mod cumulative;
+mod custom;
 mod div;
 mod erf;
 mod exp;
+mod export;
+mod functional;
 mod gather_scatter;
 mod gelu;
 mod gradients;
+mod higher_order;
+mod hooks;
+mod int4_matmul;
+mod jvp;
+mod linalg;
 mod log;
 mod log1p;
 mod mask;
@@ -30,17 +42,25 @@ mod matmul;
 mod maxmin;
 mod maxpool1d;
 mod maxpool2d;
+mod memory;
 mod mul;
 mod multithread;
 mod neg;
+mod no_grad;
+mod pad;
+mod pipeline;
 mod pow;
+mod random;
 mod recip;
 mod relu;
 mod reshape;
+mod retain;
 mod select;
 mod sin;
 mod slice;
+mod sort;
 mod softmax;
+mod sparse;
 mod sqrt;
 mod sub;
 mod tanh;
@@ -55,6 +75,19 @@ macro_rules! testgen_all {
         // Behavior
         burn_autodiff::testgen_ad_broadcast!();
         burn_autodiff::testgen_gradients!();
+        burn_autodiff::testgen_ad_higher_order!();
+        burn_autodiff::testgen_ad_jvp!();
+        burn_autodiff::testgen_ad_functional!();
+        burn_autodiff::testgen_ad_custom!();
+        burn_autodiff::testgen_ad_hooks!();
+        burn_autodiff::testgen_ad_checkpoint!();
+        burn_autodiff::testgen_ad_anomaly!();
+        burn_autodiff::testgen_ad_no_grad!();
+        burn_autodiff::testgen_ad_retain!();
+        burn_autodiff::testgen_ad_export!();
+        burn_autodiff::testgen_ad_memory!();
+        burn_autodiff::testgen_ad_pipeline!();
+        burn_autodiff::testgen_ad_random!();
 
         // Activation
         burn_autodiff::testgen_ad_relu!();
@@ -65,6 +98,8 @@ macro_rules! testgen_all {
         burn_autodiff::testgen_ad_conv2d!();
         burn_autodiff::testgen_ad_conv_transpose1d!();
         burn_autodiff::testgen_ad_conv_transpose2d!();
+        burn_autodiff::testgen_ad_attention!();
+        burn_autodiff::testgen_ad_int4_matmul!();
         burn_autodiff::testgen_ad_max_pool1d!();
         burn_autodiff::testgen_ad_max_pool2d!();
         burn_autodiff::testgen_ad_avg_pool1d!();
@@ -82,23 +117,28 @@ macro_rules! testgen_all {
         burn_autodiff::testgen_ad_cat!();
         burn_autodiff::testgen_ad_cos!();
         burn_autodiff::testgen_ad_cross_entropy_loss!();
+        burn_autodiff::testgen_ad_cumulative!();
         burn_autodiff::testgen_ad_div!();
         burn_autodiff::testgen_ad_erf!();
         burn_autodiff::testgen_ad_exp!();
         burn_autodiff::testgen_ad_slice!();
+        burn_autodiff::testgen_ad_sort!();
         burn_autodiff::testgen_ad_gather_scatter!();
         burn_autodiff::testgen_ad_select!();
+        burn_autodiff::testgen_ad_linalg!();
         burn_autodiff::testgen_ad_log!();
         burn_autodiff::testgen_ad_log1p!();
         burn_autodiff::testgen_ad_mask!();
         burn_autodiff::testgen_ad_matmul!();
         burn_autodiff::testgen_ad_mul!();
         burn_autodiff::testgen_ad_neg!();
+        burn_autodiff::testgen_ad_pad!();
         burn_autodiff::testgen_ad_powf!();
         burn_autodiff::testgen_ad_recip!();
         burn_autodiff::testgen_ad_reshape!();
         burn_autodiff::testgen_ad_sin!();
         burn_autodiff::testgen_ad_softmax!();
+        burn_autodiff::testgen_ad_sparse!();
         burn_autodiff::testgen_ad_sqrt!();
         burn_autodiff::testgen_ad_abs!();
         burn_autodiff::testgen_ad_sub!();
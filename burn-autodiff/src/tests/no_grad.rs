@@ -0,0 +1,55 @@
+#[burn_tensor_testgen::testgen(ad_no_grad)]
+mod tests {
+    use super::*;
+    use burn_autodiff::no_grad;
+    use burn_tensor::Data;
+
+    #[test]
+    fn should_not_track_operations_in_no_grad_scope() {
+        let data = Data::<f32, 1>::from([1.0, 2.0]);
+
+        let device = Default::default();
+        let tensor_1 = TestAutodiffTensor::from_data(data, &device).require_grad();
+
+        let tensor_2 = no_grad(|| tensor_1.clone().mul_scalar(2.0).exp());
+
+        assert!(!tensor_2.is_require_grad());
+        assert!(tensor_1.is_require_grad());
+    }
+
+    #[test]
+    fn should_treat_no_grad_outputs_as_constants() {
+        let data_1 = Data::<f32, 1>::from([1.0, 2.0]);
+        let data_2 = Data::<f32, 1>::from([3.0, 4.0]);
+
+        let device = Default::default();
+        let tensor_1 = TestAutodiffTensor::from_data(data_1, &device).require_grad();
+        let tensor_2 = TestAutodiffTensor::from_data(data_2, &device).require_grad();
+
+        let tensor_3 = no_grad(|| tensor_1.clone().mul(tensor_2.clone()));
+        let tensor_4 = tensor_3.mul(tensor_1.clone());
+        let grads = tensor_4.backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+
+        assert_eq!(grad_1.to_data(), Data::from([3.0, 8.0]));
+        assert!(tensor_2.grad(&grads).is_none());
+    }
+
+    #[test]
+    fn should_restore_graph_mode_after_no_grad_scope() {
+        let data = Data::<f32, 1>::from([1.0, 2.0]);
+
+        let device = Default::default();
+        let tensor_1 = TestAutodiffTensor::from_data(data, &device).require_grad();
+
+        no_grad(|| tensor_1.clone().exp());
+        let tensor_2 = tensor_1.clone().mul_scalar(2.0);
+        let grads = tensor_2.backward();
+
+        assert_eq!(
+            tensor_1.grad(&grads).unwrap().to_data(),
+            Data::from([2.0, 2.0])
+        );
+    }
+}
@@ -0,0 +1,78 @@
+#[burn_tensor_testgen::testgen(ad_pipeline)]
+mod tests {
+    use super::*;
+    use burn_autodiff::GradientPipeline;
+    use burn_tensor::Data;
+
+    #[test]
+    fn should_clip_leaf_gradients_by_value() {
+        let device = Default::default();
+        let tensor_1 = TestAutodiffTensor::from_data([1.0, 2.0], &device).require_grad();
+        let tensor_2 = TestAutodiffTensor::from_data([[3.0, -4.0]], &device).require_grad();
+
+        let loss = tensor_1
+            .clone()
+            .mul_scalar(10.0)
+            .unsqueeze::<2>()
+            .mul(tensor_2.clone())
+            .sum();
+        let grads = GradientPipeline::new()
+            .clip_value(-5.0, 5.0)
+            .backward(&loss);
+
+        assert_eq!(
+            tensor_1.grad(&grads).unwrap().to_data(),
+            Data::from([5.0, -5.0])
+        );
+        assert_eq!(
+            tensor_2.grad(&grads).unwrap().to_data(),
+            Data::from([[5.0, 5.0]])
+        );
+    }
+
+    #[test]
+    fn should_clip_leaf_gradients_by_norm() {
+        let device = Default::default();
+        let tensor_1 = TestAutodiffTensor::from_data([1.0, 2.0], &device).require_grad();
+        let tensor_2 = TestAutodiffTensor::from_data([3.0, 4.0], &device);
+
+        let loss = tensor_1.clone().mul(tensor_2).sum();
+        let grads = GradientPipeline::new().clip_norm(1.0).backward(&loss);
+
+        tensor_1
+            .grad(&grads)
+            .unwrap()
+            .to_data()
+            .assert_approx_eq(&Data::from([0.6, 0.8]), 3);
+    }
+
+    #[test]
+    fn should_only_transform_leaf_gradients() {
+        let device = Default::default();
+        let tensor_1 = TestAutodiffTensor::from_data([[1.0, 2.0]], &device).require_grad();
+
+        let loss = tensor_1.clone().mul_scalar(3.0).mul_scalar(4.0).sum();
+        let grads = GradientPipeline::new()
+            .map(|grad| grad.mul_scalar(2.0))
+            .backward(&loss);
+
+        assert_eq!(
+            tensor_1.grad(&grads).unwrap().to_data(),
+            Data::from([[24.0, 24.0]])
+        );
+    }
+
+    #[test]
+    fn should_cast_leaf_gradients() {
+        let device = Default::default();
+        let tensor_1 = TestAutodiffTensor::from_data([1.0, 2.0], &device).require_grad();
+
+        let loss = tensor_1.clone().mul_scalar(2.5).sum();
+        let grads = GradientPipeline::new().cast::<i32>().backward(&loss);
+
+        assert_eq!(
+            tensor_1.grad(&grads).unwrap().to_data(),
+            Data::from([2.0, 2.0])
+        );
+    }
+}
@@ -0,0 +1,56 @@
+#[burn_tensor_testgen::testgen(ad_random)]
+mod tests {
+    use super::*;
+    use burn_autodiff::{checkpoint_scope, record_random, replay_random};
+    use burn_tensor::{Distribution, Tensor};
+
+    #[test]
+    fn should_replay_recorded_random_tensors() {
+        let device = Default::default();
+        let sample = || {
+            (
+                TestAutodiffTensor::<2>::random([4, 8], Distribution::Default, &device),
+                TestAutodiffTensor::<1>::random([16], Distribution::Bernoulli(0.5), &device),
+            )
+        };
+
+        let ((tensor_1, tensor_2), record) = record_random(sample);
+        let (tensor_3, tensor_4) = replay_random(&record, sample);
+
+        assert_eq!(record.len(), 2);
+        assert_eq!(tensor_1.into_data(), tensor_3.into_data());
+        assert_eq!(tensor_2.into_data(), tensor_4.into_data());
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_replaying_more_random_tensors_than_recorded() {
+        let device = Default::default();
+        let sample = || TestAutodiffTensor::<1>::random([4], Distribution::Default, &device);
+
+        let (_, record) = record_random(sample);
+        replay_random(&record, || (sample(), sample()));
+    }
+
+    #[test]
+    fn should_replay_random_tensors_when_recomputing_checkpointed_scope() {
+        let device = Default::default();
+        let tensor = TestAutodiffTensor::<2>::ones([8, 8], &device).require_grad();
+
+        let output = checkpoint_scope([tensor.clone()], |[tensor]| {
+            let mask = Tensor::random(
+                tensor.shape(),
+                Distribution::Bernoulli(0.5),
+                &tensor.device(),
+            );
+            tensor.mul(mask)
+        });
+        let grads = output.clone().sum().backward();
+
+        // The input is filled with ones, so the output is the dropout mask.
+        assert_eq!(
+            tensor.grad(&grads).unwrap().into_data(),
+            output.inner().into_data()
+        );
+    }
+}
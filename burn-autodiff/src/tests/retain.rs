@@ -0,0 +1,57 @@
+#[burn_tensor_testgen::testgen(ad_retain)]
+mod tests {
+    use super::*;
+    use burn_tensor::Data;
+
+    #[test]
+    fn should_backward_multiple_times_with_retained_graph() {
+        let data_1 = Data::<f32, 1>::from([1.0, 2.0]);
+        let data_2 = Data::<f32, 1>::from([3.0, 4.0]);
+
+        let device = Default::default();
+        let tensor_1 = TestAutodiffTensor::from_data(data_1, &device).require_grad();
+        let tensor_2 = TestAutodiffTensor::from_data(data_2, &device).require_grad();
+
+        let shared = tensor_1.clone().mul(tensor_2.clone());
+        let loss_1 = shared.clone().mul_scalar(2.0).sum();
+        let loss_2 = shared.exp().sum();
+
+        let grads_1 = loss_1.backward_retain();
+        let grads_2 = loss_2.backward();
+
+        assert_eq!(
+            tensor_1.grad(&grads_1).unwrap().to_data(),
+            Data::from([6.0, 8.0])
+        );
+        assert_eq!(
+            tensor_2.grad(&grads_1).unwrap().to_data(),
+            Data::from([2.0, 4.0])
+        );
+        tensor_1
+            .grad(&grads_2)
+            .unwrap()
+            .to_data()
+            .assert_approx_eq(&Data::from([60.2566, 11923.832]), 1);
+    }
+
+    #[test]
+    fn should_backward_same_root_twice_with_retained_graph() {
+        let data = Data::<f32, 1>::from([1.0, 2.0]);
+
+        let device = Default::default();
+        let tensor_1 = TestAutodiffTensor::from_data(data, &device).require_grad();
+        let tensor_2 = tensor_1.clone().mul(tensor_1.clone()).sum();
+
+        let grads_1 = tensor_2.backward_retain();
+        let grads_2 = tensor_2.backward();
+
+        assert_eq!(
+            tensor_1.grad(&grads_1).unwrap().to_data(),
+            Data::from([2.0, 4.0])
+        );
+        assert_eq!(
+            tensor_1.grad(&grads_2).unwrap().to_data(),
+            Data::from([2.0, 4.0])
+        );
+    }
+}
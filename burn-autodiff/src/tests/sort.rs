@@ -0,0 +1,71 @@
+#[burn_tensor_testgen::testgen(ad_sort)]
+mod tests {
+    use super::*;
+    use burn_tensor::Data;
+
+    #[test]
+    fn should_diff_sort() {
+        let device = Default::default();
+        let tensor_1 = TestAutodiffTensor::from_floats([[3.0, 1.0, 2.0], [0.0, 5.0, 4.0]], &device)
+            .require_grad();
+        let weights = TestAutodiffTensor::from_floats([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]], &device);
+
+        let tensor_2 = tensor_1.clone().sort(1, false).mul(weights);
+        let grads = tensor_2.sum().backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+
+        grad_1
+            .to_data()
+            .assert_approx_eq(&Data::from([[3.0, 1.0, 2.0], [4.0, 6.0, 5.0]]), 5);
+    }
+
+    #[test]
+    fn should_diff_sort_with_indices_descending() {
+        let device = Default::default();
+        let tensor_1 =
+            TestAutodiffTensor::from_floats([[3.0, 1.0], [0.0, 5.0]], &device).require_grad();
+        let weights = TestAutodiffTensor::from_floats([[1.0, 2.0], [3.0, 4.0]], &device);
+
+        let (tensor_2, _indices) = tensor_1.clone().sort_with_indices(0, true);
+        let grads = tensor_2.mul(weights).sum().backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+
+        grad_1
+            .to_data()
+            .assert_approx_eq(&Data::from([[1.0, 4.0], [3.0, 2.0]]), 5);
+    }
+
+    #[test]
+    fn should_diff_quantile() {
+        let device = Default::default();
+        let tensor_1 =
+            TestAutodiffTensor::from_floats([[3.0, 1.0, 2.0, 4.0]], &device).require_grad();
+
+        let tensor_2 = tensor_1.clone().quantile(0.5, 1);
+        let grads = tensor_2.sum().backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+
+        grad_1
+            .to_data()
+            .assert_approx_eq(&Data::from([[0.5, 0.0, 0.5, 0.0]]), 5);
+    }
+
+    #[test]
+    fn should_diff_median() {
+        let device = Default::default();
+        let tensor_1 =
+            TestAutodiffTensor::from_floats([[3.0, 1.0, 2.0, 4.0]], &device).require_grad();
+
+        let tensor_2 = tensor_1.clone().median(1);
+        let grads = tensor_2.sum().backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+
+        grad_1
+            .to_data()
+            .assert_approx_eq(&Data::from([[0.0, 0.0, 1.0, 0.0]]), 5);
+    }
+}
@@ -0,0 +1,32 @@
+#[burn_tensor_testgen::testgen(ad_sparse)]
+mod tests {
+    use super::*;
+    use burn_tensor::sparse::SparseTensor;
+    use burn_tensor::{Data, Int, Tensor};
+
+    #[test]
+    fn should_diff_spmm() {
+        let device = Default::default();
+        let indices = Tensor::<TestAutodiffBackend, 2, Int>::from_data(
+            Data::from([[0, 1, 1], [1, 0, 2]]),
+            &device,
+        );
+        let values = TestAutodiffTensor::from_floats([2.0, 3.0, 4.0], &device).require_grad();
+        let dense = TestAutodiffTensor::from_floats([[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]], &device)
+            .require_grad();
+
+        let sparse = SparseTensor::from_coo(indices, values.clone(), [2, 3]);
+        let output = sparse.matmul(dense.clone());
+        let grads = output.sum().backward();
+
+        let grad_values = values.grad(&grads).unwrap();
+        let grad_dense = dense.grad(&grads).unwrap();
+
+        grad_values
+            .to_data()
+            .assert_approx_eq(&Data::from([7.0, 3.0, 11.0]), 4);
+        grad_dense
+            .to_data()
+            .assert_approx_eq(&Data::from([[3.0, 3.0], [2.0, 2.0], [4.0, 4.0]]), 4);
+    }
+}
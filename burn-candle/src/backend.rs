@@ -1,10 +1,12 @@
-use std::marker::PhantomData;
+use std::{collections::HashMap, marker::PhantomData, sync::Mutex};
 
 use burn_tensor::backend::Backend;
 use candle_core::DeviceLocation;
+use rand::{rngs::StdRng, SeedableRng};
 
 use crate::{
     element::{CandleElement, FloatCandleElement, IntCandleElement},
+    memory::driver_memory,
     CandleTensor,
 };
 
@@ -23,7 +25,7 @@ where
 }
 
 /// The device type for the candle backend.
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 /// The device struct when using the `candle` backend.
 ///
 /// Note that you need to provide the device index when using Cuda.
@@ -60,12 +62,114 @@ impl From<candle_core::Device> for CandleDevice {
     }
 }
 
+/// Description of a device of the candle backend, see [CandleDevice::cuda_devices].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CandleDeviceInfo {
+    /// The device.
+    pub device: CandleDevice,
+    /// The name of the device, as reported by the driver.
+    pub name: String,
+    /// The number of bytes of the device, as reported by the driver.
+    pub total_memory: Option<usize>,
+}
+
+impl CandleDevice {
+    /// The number of Cuda devices found on the system.
+    ///
+    /// Always zero when the `cuda` feature isn't enabled.
+    pub fn cuda_count() -> usize {
+        #[cfg(feature = "cuda")]
+        {
+            use candle_core::cuda_backend::cudarc::driver::CudaDevice;
+
+            CudaDevice::count().map(|count| count as usize).unwrap_or(0)
+        }
+
+        #[cfg(not(feature = "cuda"))]
+        0
+    }
+
+    /// The Cuda devices found on the system, in the order of their index.
+    pub fn cuda_devices() -> Vec<CandleDeviceInfo> {
+        (0..Self::cuda_count())
+            .map(|index| {
+                let device = CandleDevice::Cuda(index);
+
+                CandleDeviceInfo {
+                    device,
+                    name: device_name(&device),
+                    total_memory: driver_memory(&device).map(|(_reserved, total)| total),
+                }
+            })
+            .collect()
+    }
+}
+
+#[cfg(feature = "cuda")]
+fn device_name(device: &CandleDevice) -> String {
+    let candle_device: candle_core::Device = (*device).into();
+
+    match candle_device {
+        candle_core::Device::Cuda(cuda) => cuda.name().unwrap_or_default(),
+        _ => format!("{device:?}"),
+    }
+}
+
+#[cfg(not(feature = "cuda"))]
+fn device_name(device: &CandleDevice) -> String {
+    format!("{device:?}")
+}
+
 impl Default for CandleDevice {
     fn default() -> Self {
         Self::Cpu
     }
 }
 
+/// The random number generators of each device, created from the last manual seed.
+///
+/// Candle doesn't let us seed the generators of its devices, so once a seed is set, random tensors
+/// are sampled on the host with our own generators and then moved to their device.
+static SEEDED_RNGS: Mutex<Option<SeededRngs>> = Mutex::new(None);
+
+struct SeededRngs {
+    seed: u64,
+    devices: HashMap<CandleDevice, StdRng>,
+}
+
+/// Execute the function with the random number generator of the device, returning `None` when no
+/// seed has been set.
+///
+/// On wasm targets, the generators are always used since candle samples with the thread RNG of
+/// `rand`, which isn't available in the browser. They are seeded with the entropy of the host
+/// through `getrandom` until a seed is set.
+pub(crate) fn with_seeded_rng<T, Func>(device: &CandleDevice, func: Func) -> Option<T>
+where
+    Func: FnOnce(&mut StdRng) -> T,
+{
+    let mut rngs = SEEDED_RNGS.lock().unwrap();
+
+    #[cfg(target_family = "wasm")]
+    if rngs.is_none() {
+        let mut seed = [0; 8];
+        getrandom::getrandom(&mut seed).expect("Entropy should be available from the host");
+
+        *rngs = Some(SeededRngs {
+            seed: u64::from_le_bytes(seed),
+            devices: HashMap::new(),
+        });
+    }
+
+    let rngs = rngs.as_mut()?;
+    let seed = rngs.seed;
+    let rng = rngs
+        .devices
+        .entry(*device)
+        .or_insert_with(|| StdRng::seed_from_u64(seed));
+
+    Some(func(rng))
+}
+
 impl<F: FloatCandleElement, I: IntCandleElement> Backend for Candle<F, I> {
     type Device = CandleDevice;
 
@@ -89,7 +193,58 @@ impl<F: FloatCandleElement, I: IntCandleElement> Backend for Candle<F, I> {
     }
 
     fn seed(seed: u64) {
-        // TODO submit an issue at Candle
-        panic!("Manual seed not supported by Candle. ")
+        let mut rngs = SEEDED_RNGS.lock().unwrap();
+        *rngs = Some(SeededRngs {
+            seed,
+            devices: HashMap::new(),
+        });
+    }
+
+    fn sync(device: &Self::Device) {
+        if let CandleDevice::Cpu = device {
+            return;
+        }
+
+        // Candle doesn't expose the streams of its devices, but reading a tensor back to the host
+        // waits for every operation queued before it.
+        candle_core::Tensor::zeros((), candle_core::DType::U8, &(*device).into())
+            .and_then(|tensor| tensor.to_vec0::<u8>())
+            .unwrap();
+    }
+
+    fn non_deterministic_ops(device: &Self::Device) -> &'static [&'static str] {
+        match device {
+            // The CUDA kernels of Candle accumulate with atomics for these operations.
+            CandleDevice::Cuda(_) => &["scatter", "select_assign"],
+            _ => &[],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, Distribution};
+
+    type TestBackend = Candle<f32, i64>;
+
+    #[test]
+    fn should_sample_the_same_values_with_the_same_seed() {
+        // A device that no other test samples from, so they can't consume its generator.
+        let device = CandleDevice::Cuda(usize::MAX);
+        let sample = || {
+            with_seeded_rng(&device, |rng| {
+                Data::<f32, 2>::random([8, 8].into(), Distribution::Normal(0.0, 1.0), rng)
+            })
+            .unwrap()
+        };
+
+        TestBackend::seed(42);
+        let first = (sample(), sample());
+        TestBackend::seed(42);
+        let second = (sample(), sample());
+
+        assert_eq!(first, second);
+        assert_ne!(first.0, first.1);
     }
 }
@@ -0,0 +1,154 @@
+//! Custom candle operations differentiated with the [Autodiff](burn_autodiff::Autodiff) backend
+//! decorator.
+//!
+//! The gradients of the inputs are computed with the backward pass of the operation, so the
+//! `bwd` function of the candle operation must be implemented.
+
+use std::sync::Arc;
+
+use burn_autodiff::{ops::custom_op, Autodiff};
+use burn_tensor::Tensor;
+use candle_core::{CustomOp1, CustomOp2, CustomOp3};
+
+use crate::{
+    element::{FloatCandleElement, IntCandleElement},
+    Candle, CandleTensor,
+};
+
+/// Execute a differentiable custom [candle operation](CustomOp1) with one input.
+pub fn custom_op1<F, I, C, const D: usize>(
+    tensor: Tensor<Autodiff<Candle<F, I>>, D>,
+    op: C,
+) -> Tensor<Autodiff<Candle<F, I>>, D>
+where
+    F: FloatCandleElement,
+    I: IntCandleElement,
+    C: CustomOp1 + Send + Sync + 'static,
+{
+    let op = Arc::new(op);
+    let op_forward = op.clone();
+
+    custom_op(
+        [tensor],
+        move |[tensor]| super::custom_op1(tensor, op_forward.as_ref()),
+        move |[tensor], output, grad| {
+            let grad = op
+                .bwd(&candle(tensor), &candle(output), &candle(&grad))
+                .unwrap_or_else(|err| panic!("Backward of {} failed: {err}", op.name()));
+
+            [gradient(grad, tensor)]
+        },
+    )
+}
+
+/// Execute a differentiable custom [candle operation](CustomOp2) with two inputs.
+pub fn custom_op2<F, I, C, const D: usize>(
+    lhs: Tensor<Autodiff<Candle<F, I>>, D>,
+    rhs: Tensor<Autodiff<Candle<F, I>>, D>,
+    op: C,
+) -> Tensor<Autodiff<Candle<F, I>>, D>
+where
+    F: FloatCandleElement,
+    I: IntCandleElement,
+    C: CustomOp2 + Send + Sync + 'static,
+{
+    let op = Arc::new(op);
+    let op_forward = op.clone();
+
+    custom_op(
+        [lhs, rhs],
+        move |[lhs, rhs]| super::custom_op2(lhs, rhs, op_forward.as_ref()),
+        move |[lhs, rhs], output, grad| {
+            let (grad_lhs, grad_rhs) = op
+                .bwd(&candle(lhs), &candle(rhs), &candle(output), &candle(&grad))
+                .unwrap_or_else(|err| panic!("Backward of {} failed: {err}", op.name()));
+
+            [gradient(grad_lhs, lhs), gradient(grad_rhs, rhs)]
+        },
+    )
+}
+
+/// Execute a differentiable custom [candle operation](CustomOp3) with three inputs.
+pub fn custom_op3<F, I, C, const D: usize>(
+    tensor_1: Tensor<Autodiff<Candle<F, I>>, D>,
+    tensor_2: Tensor<Autodiff<Candle<F, I>>, D>,
+    tensor_3: Tensor<Autodiff<Candle<F, I>>, D>,
+    op: C,
+) -> Tensor<Autodiff<Candle<F, I>>, D>
+where
+    F: FloatCandleElement,
+    I: IntCandleElement,
+    C: CustomOp3 + Send + Sync + 'static,
+{
+    let op = Arc::new(op);
+    let op_forward = op.clone();
+
+    custom_op(
+        [tensor_1, tensor_2, tensor_3],
+        move |[tensor_1, tensor_2, tensor_3]| {
+            super::custom_op3(tensor_1, tensor_2, tensor_3, op_forward.as_ref())
+        },
+        move |[tensor_1, tensor_2, tensor_3], output, grad| {
+            let (grad_1, grad_2, grad_3) = op
+                .bwd(
+                    &candle(tensor_1),
+                    &candle(tensor_2),
+                    &candle(tensor_3),
+                    &candle(output),
+                    &candle(&grad),
+                )
+                .unwrap_or_else(|err| panic!("Backward of {} failed: {err}", op.name()));
+
+            [
+                gradient(grad_1, tensor_1),
+                gradient(grad_2, tensor_2),
+                gradient(grad_3, tensor_3),
+            ]
+        },
+    )
+}
+
+fn candle<F, I, const D: usize>(tensor: &Tensor<Candle<F, I>, D>) -> candle_core::Tensor
+where
+    F: FloatCandleElement,
+    I: IntCandleElement,
+{
+    tensor.clone().into_primitive().tensor
+}
+
+/// The gradient of an input, which is zero when the operation doesn't return one.
+fn gradient<F, I, const D: usize>(
+    grad: Option<candle_core::Tensor>,
+    input: &Tensor<Candle<F, I>, D>,
+) -> Tensor<Candle<F, I>, D>
+where
+    F: FloatCandleElement,
+    I: IntCandleElement,
+{
+    match grad {
+        Some(grad) => Tensor::from_primitive(CandleTensor::new(grad)),
+        None => input.zeros_like(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::custom_op::tests::{Square, TestBackend};
+    use burn_tensor::Data;
+
+    #[test]
+    fn should_differentiate_custom_op() {
+        let tensor = Tensor::<Autodiff<TestBackend>, 2>::from_data(
+            Data::from([[1.0, -2.0], [3.0, 0.5]]),
+            &Default::default(),
+        )
+        .require_grad();
+
+        let output = custom_op1(tensor.clone(), Square);
+        let grads = output.sum().backward();
+        let grad = tensor.grad(&grads).unwrap();
+
+        assert_eq!(grad.into_data(), Data::from([[2.0, -4.0], [6.0, 1.0]]));
+    }
+}
@@ -0,0 +1,128 @@
+#[cfg(feature = "autodiff")]
+pub mod autodiff;
+
+use burn_tensor::Tensor;
+use candle_core::{CustomOp1, CustomOp2, CustomOp3};
+
+use crate::{
+    element::{FloatCandleElement, IntCandleElement},
+    Candle, CandleTensor,
+};
+
+/// Execute a custom [candle operation](CustomOp1) with one input.
+///
+/// This is an escape hatch to run hand-written CPU, CUDA or Metal kernels on tensors of the candle
+/// backend. The backward pass of the operation is ignored, see
+/// [autodiff::custom_op1](crate::autodiff::custom_op1) to differentiate it.
+pub fn custom_op1<F, I, C, const D: usize>(
+    tensor: Tensor<Candle<F, I>, D>,
+    op: &C,
+) -> Tensor<Candle<F, I>, D>
+where
+    F: FloatCandleElement,
+    I: IntCandleElement,
+    C: CustomOp1,
+{
+    let tensor = tensor.into_primitive().tensor;
+    let output = tensor.apply_op1_no_bwd(op).unwrap();
+
+    Tensor::from_primitive(CandleTensor::new(output))
+}
+
+/// Execute a custom [candle operation](CustomOp2) with two inputs.
+///
+/// The backward pass of the operation is ignored, see
+/// [autodiff::custom_op2](crate::autodiff::custom_op2) to differentiate it.
+pub fn custom_op2<F, I, C, const D: usize>(
+    lhs: Tensor<Candle<F, I>, D>,
+    rhs: Tensor<Candle<F, I>, D>,
+    op: &C,
+) -> Tensor<Candle<F, I>, D>
+where
+    F: FloatCandleElement,
+    I: IntCandleElement,
+    C: CustomOp2,
+{
+    let lhs = lhs.into_primitive().tensor;
+    let rhs = rhs.into_primitive().tensor;
+    let output = lhs.apply_op2_no_bwd(&rhs, op).unwrap();
+
+    Tensor::from_primitive(CandleTensor::new(output))
+}
+
+/// Execute a custom [candle operation](CustomOp3) with three inputs.
+///
+/// The backward pass of the operation is ignored, see
+/// [autodiff::custom_op3](crate::autodiff::custom_op3) to differentiate it.
+pub fn custom_op3<F, I, C, const D: usize>(
+    tensor_1: Tensor<Candle<F, I>, D>,
+    tensor_2: Tensor<Candle<F, I>, D>,
+    tensor_3: Tensor<Candle<F, I>, D>,
+    op: &C,
+) -> Tensor<Candle<F, I>, D>
+where
+    F: FloatCandleElement,
+    I: IntCandleElement,
+    C: CustomOp3,
+{
+    let tensor_1 = tensor_1.into_primitive().tensor;
+    let tensor_2 = tensor_2.into_primitive().tensor;
+    let tensor_3 = tensor_3.into_primitive().tensor;
+    let output = tensor_1.apply_op3_no_bwd(&tensor_2, &tensor_3, op).unwrap();
+
+    Tensor::from_primitive(CandleTensor::new(output))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use burn_tensor::Data;
+    use candle_core::{CpuStorage, Layout, Shape};
+
+    pub type TestBackend = Candle<f32, i64>;
+
+    /// Square the values of a contiguous float tensor.
+    pub struct Square;
+
+    impl CustomOp1 for Square {
+        fn name(&self) -> &'static str {
+            "square"
+        }
+
+        fn cpu_fwd(
+            &self,
+            storage: &CpuStorage,
+            layout: &Layout,
+        ) -> candle_core::Result<(CpuStorage, Shape)> {
+            let (start, end) = layout.contiguous_offsets().unwrap();
+            let values = match storage {
+                CpuStorage::F32(values) => &values[start..end],
+                _ => unimplemented!("Only f32 tensors are supported"),
+            };
+            let output = values.iter().map(|value| value * value).collect();
+
+            Ok((CpuStorage::F32(output), layout.shape().clone()))
+        }
+
+        fn bwd(
+            &self,
+            arg: &candle_core::Tensor,
+            _res: &candle_core::Tensor,
+            grad_res: &candle_core::Tensor,
+        ) -> candle_core::Result<Option<candle_core::Tensor>> {
+            Ok(Some(arg.affine(2.0, 0.0)?.mul(grad_res)?))
+        }
+    }
+
+    #[test]
+    fn should_execute_custom_op() {
+        let tensor = Tensor::<TestBackend, 2>::from_data(
+            Data::from([[1.0, -2.0], [3.0, 0.5]]),
+            &Default::default(),
+        );
+
+        let output = custom_op1(tensor, &Square);
+
+        assert_eq!(output.into_data(), Data::from([[1.0, 4.0], [9.0, 0.25]]));
+    }
+}
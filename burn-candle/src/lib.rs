@@ -7,10 +7,16 @@
 extern crate derive_new;
 
 mod backend;
+mod custom_op;
 mod element;
+mod memory;
 mod ops;
+mod quantization;
 mod tensor;
 pub use backend::*;
+pub use custom_op::*;
+pub use memory::*;
+pub use quantization::*;
 pub use tensor::*;
 
 #[cfg(test)]
@@ -40,15 +46,15 @@ mod tests {
     // test module
     burn_tensor::testgen_module_forward!();
     burn_tensor::testgen_module_conv1d!();
-    // burn_tensor::testgen_module_conv2d!();
-    // burn_tensor::testgen_module_conv_transpose1d!();
-    // burn_tensor::testgen_module_conv_transpose2d!();
-    // burn_tensor::testgen_module_max_pool1d!();
-    // burn_tensor::testgen_module_max_pool2d!();
-    // burn_tensor::testgen_module_avg_pool1d!();
-    // burn_tensor::testgen_module_avg_pool2d!();
-    // burn_tensor::testgen_module_adaptive_avg_pool1d!();
-    // burn_tensor::testgen_module_adaptive_avg_pool2d!();
+    burn_tensor::testgen_module_conv2d!();
+    burn_tensor::testgen_module_conv_transpose1d!();
+    burn_tensor::testgen_module_conv_transpose2d!();
+    burn_tensor::testgen_module_max_pool1d!();
+    burn_tensor::testgen_module_max_pool2d!();
+    burn_tensor::testgen_module_avg_pool1d!();
+    burn_tensor::testgen_module_avg_pool2d!();
+    burn_tensor::testgen_module_adaptive_avg_pool1d!();
+    burn_tensor::testgen_module_adaptive_avg_pool2d!();
 
     // test ops
     burn_tensor::testgen_add!();
@@ -106,12 +112,12 @@ mod tests {
     // burn_autodiff::testgen_ad_conv2d!();
     // burn_autodiff::testgen_ad_conv_transpose1d!();
     // burn_autodiff::testgen_ad_conv_transpose2d!();
-    // burn_autodiff::testgen_ad_max_pool1d!();
-    // burn_autodiff::testgen_ad_max_pool2d!();
-    // burn_autodiff::testgen_ad_avg_pool1d!();
-    // burn_autodiff::testgen_ad_avg_pool2d!();
-    // burn_autodiff::testgen_ad_adaptive_avg_pool1d!();
-    // burn_autodiff::testgen_ad_adaptive_avg_pool2d!();
+    burn_autodiff::testgen_ad_max_pool1d!();
+    burn_autodiff::testgen_ad_max_pool2d!();
+    burn_autodiff::testgen_ad_avg_pool1d!();
+    burn_autodiff::testgen_ad_avg_pool2d!();
+    burn_autodiff::testgen_ad_adaptive_avg_pool1d!();
+    burn_autodiff::testgen_ad_adaptive_avg_pool2d!();
     burn_autodiff::testgen_module_backward!();
 
     // Tensor
@@ -0,0 +1,116 @@
+use std::sync::Mutex;
+
+use burn_tensor::memory::MemoryProfiler;
+
+use crate::{
+    element::{FloatCandleElement, IntCandleElement},
+    Candle, CandleDevice,
+};
+
+/// Memory usage of a device of the [candle backend](Candle), see [Candle::memory_usage].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CandleMemoryUsage {
+    /// Number of bytes held by the tensors of the device.
+    ///
+    /// Candle doesn't pool its allocations, so this is also the memory reserved by the backend.
+    /// It is only recorded while the [memory profiler](MemoryProfiler) is enabled.
+    pub allocated: usize,
+    /// Maximum number of bytes held by the tensors of the device at the same time.
+    pub peak: usize,
+    /// Number of bytes reserved on the device by every process, as reported by the driver.
+    ///
+    /// Only available on Cuda devices.
+    pub reserved: Option<usize>,
+    /// Number of bytes of the device, as reported by the driver.
+    ///
+    /// Only available on Cuda devices.
+    pub total: Option<usize>,
+}
+
+/// The names of the devices used as sources of the memory profiler, which must live forever.
+static SOURCES: Mutex<Vec<(CandleDevice, &'static str)>> = Mutex::new(Vec::new());
+
+/// The source of the allocations of the device in the [memory profiler](MemoryProfiler).
+pub(crate) fn memory_source(device: CandleDevice) -> &'static str {
+    let mut sources = SOURCES.lock().unwrap();
+
+    if let Some((_, source)) = sources.iter().find(|(key, _)| *key == device) {
+        return source;
+    }
+
+    let source: &'static str = Box::leak(format!("candle {device:?}").into_boxed_str());
+    sources.push((device, source));
+
+    source
+}
+
+impl<F: FloatCandleElement, I: IntCandleElement> Candle<F, I> {
+    /// The memory usage of the device.
+    ///
+    /// The memory held by the tensors is recorded by the [memory profiler](MemoryProfiler),
+    /// which must be enabled before the tensors are created.
+    pub fn memory_usage(device: &CandleDevice) -> CandleMemoryUsage {
+        let source = memory_source(*device);
+        let usage = MemoryProfiler::report()
+            .sources
+            .into_iter()
+            .find(|usage| usage.source == source);
+        let (reserved, total) = match driver_memory(device) {
+            Some((reserved, total)) => (Some(reserved), Some(total)),
+            None => (None, None),
+        };
+
+        CandleMemoryUsage {
+            allocated: usage.as_ref().map(|usage| usage.live_bytes).unwrap_or(0),
+            peak: usage.as_ref().map(|usage| usage.peak_bytes).unwrap_or(0),
+            reserved,
+            total,
+        }
+    }
+}
+
+/// The reserved and total memory of the device, as reported by the driver.
+#[cfg(feature = "cuda")]
+pub(crate) fn driver_memory(device: &CandleDevice) -> Option<(usize, usize)> {
+    use candle_core::cuda_backend::cudarc::driver::result::mem_get_info;
+
+    let device: candle_core::Device = (*device).into();
+
+    match device {
+        candle_core::Device::Cuda(device) => {
+            device.bind_to_thread().ok()?;
+            let (free, total) = mem_get_info().ok()?;
+
+            Some((total - free, total))
+        }
+        _ => None,
+    }
+}
+
+/// The reserved and total memory of the device, as reported by the driver.
+#[cfg(not(feature = "cuda"))]
+pub(crate) fn driver_memory(_device: &CandleDevice) -> Option<(usize, usize)> {
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use burn_tensor::Tensor;
+
+    type TestBackend = Candle<f32, i64>;
+
+    #[test]
+    fn should_report_the_memory_of_the_tensors_of_the_device() {
+        MemoryProfiler::enable();
+        let device = CandleDevice::Cpu;
+
+        let tensor = Tensor::<TestBackend, 2>::zeros([16, 16], &device);
+        let usage = TestBackend::memory_usage(&device);
+
+        assert!(usage.allocated >= 16 * 16 * core::mem::size_of::<f32>());
+        assert!(usage.peak >= usage.allocated);
+        assert_eq!(usage.reserved, None);
+        core::mem::drop(tensor);
+    }
+}
@@ -23,3 +23,55 @@ pub(crate) fn fill_like<E: CandleElement, const D: usize>(
         reference_tensor.device(),
     )
 }
+
+/// Insert `factor - 1` zeros between the elements of the tensor along the dimension.
+pub(crate) fn dilate(tensor: Tensor, dim: usize, factor: usize) -> Tensor {
+    if factor == 1 {
+        return tensor;
+    }
+
+    let mut dims = tensor.dims().to_vec();
+    let size = dims[dim];
+    dims[dim] = size * factor;
+
+    tensor
+        .unsqueeze(dim + 1)
+        .unwrap()
+        .pad_with_zeros(dim + 1, 0, factor - 1)
+        .unwrap()
+        .reshape(dims)
+        .unwrap()
+        .narrow(dim, 0, (size - 1) * factor + 1)
+        .unwrap()
+}
+
+/// Keep one element every `step` elements of the tensor along the dimension.
+pub(crate) fn subsample(tensor: Tensor, dim: usize, step: usize) -> Tensor {
+    if step == 1 {
+        return tensor;
+    }
+
+    let indices = (0..tensor.dims()[dim] as u32)
+        .step_by(step)
+        .collect::<Vec<_>>();
+    let indices = Tensor::new(indices.as_slice(), tensor.device()).unwrap();
+
+    tensor.index_select(&indices, dim).unwrap()
+}
+
+/// Remove the padding of a transposed convolution along the dimension, after adding the output
+/// padding at its end.
+pub(crate) fn crop_padding(
+    tensor: Tensor,
+    dim: usize,
+    padding: usize,
+    padding_out: usize,
+) -> Tensor {
+    let size = tensor.dims()[dim];
+
+    tensor
+        .pad_with_zeros(dim, 0, padding_out)
+        .unwrap()
+        .narrow(dim, padding, size + padding_out - 2 * padding)
+        .unwrap()
+}
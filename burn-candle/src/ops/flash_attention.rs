@@ -0,0 +1,33 @@
+use candle_core::{DType, Tensor};
+
+/// The largest head dimension supported by the flash attention kernels.
+const MAX_HEAD_DIM: usize = 256;
+
+/// Compute the attention with the flash attention kernels of candle, returning `None` when they
+/// don't support the inputs.
+///
+/// The kernels only run on Cuda devices with half precision elements, and require the query, the
+/// key and the value to have the same head dimension.
+pub(crate) fn flash_attention(
+    query: &Tensor,
+    key: &Tensor,
+    value: &Tensor,
+    scale: f32,
+) -> Option<Tensor> {
+    if !query.device().is_cuda() || !matches!(query.dtype(), DType::F16 | DType::BF16) {
+        return None;
+    }
+
+    let head_dim = query.dims()[3];
+    if head_dim > MAX_HEAD_DIM || head_dim % 8 != 0 || value.dims()[3] != head_dim {
+        return None;
+    }
+
+    // The kernels expect the heads after the sequence: `[batch_size, seq_length, n_heads, d_k]`.
+    let layout = |tensor: &Tensor| tensor.transpose(1, 2).unwrap().contiguous().unwrap();
+    let output =
+        candle_flash_attn::flash_attn(&layout(query), &layout(key), &layout(value), scale, false)
+            .unwrap();
+
+    Some(output.transpose(1, 2).unwrap().contiguous().unwrap())
+}
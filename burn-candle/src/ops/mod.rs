@@ -2,6 +2,9 @@ mod activation;
 mod base;
 mod bool_tensor;
 mod candle_utils;
+#[cfg(feature = "flash-attn")]
+mod flash_attention;
 mod int_tensor;
 mod module;
+mod pool;
 mod tensor;
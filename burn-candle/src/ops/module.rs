@@ -1,15 +1,19 @@
 use burn_tensor::{
     ops::{
-        ConvOptions, ConvTransposeOptions, FloatTensor, IntTensor, MaxPool2dBackward,
-        MaxPool2dWithIndices, ModuleOps, UnfoldOptions,
+        attention::attention_from_blocks, ConvOptions, ConvTransposeOptions, FloatTensor,
+        IntTensor, MaxPool2dBackward, MaxPool2dWithIndices, ModuleOps, UnfoldOptions,
     },
     Shape,
 };
-use candle_core::ToUsize2;
+use candle_core::{Tensor, ToUsize2};
 
 use crate::{
     element::{CandleElement, FloatCandleElement, IntCandleElement},
-    ops::base::reshape,
+    ops::{
+        base::reshape,
+        candle_utils::{crop_padding, dilate, subsample},
+        pool,
+    },
     Candle, CandleTensor,
 };
 
@@ -44,22 +48,43 @@ impl<F: FloatCandleElement, I: IntCandleElement> ModuleOps<Self> for Candle<F, I
         bias: Option<FloatTensor<Self, 1>>,
         options: ConvOptions<2>,
     ) -> FloatTensor<Self, 4> {
-        assert!(
-            options.dilation[0] == options.dilation[1]
-                && options.padding[0] == options.padding[1]
-                && options.stride[0] == options.stride[1],
-            "Candle does not support per dimension options in convolutions"
-        );
+        // Candle only supports the same options for both dimensions, so the others are applied
+        // by padding the input, dilating the weight and subsampling the output.
+        let (x, padding) = match options.padding[0] == options.padding[1] {
+            true => (x.tensor, options.padding[0]),
+            false => (
+                x.tensor
+                    .pad_with_zeros(2, options.padding[0], options.padding[0])
+                    .unwrap()
+                    .pad_with_zeros(3, options.padding[1], options.padding[1])
+                    .unwrap(),
+                0,
+            ),
+        };
+        let (weight, dilation) = match options.dilation[0] == options.dilation[1] {
+            true => (weight.tensor, options.dilation[0]),
+            false => (
+                dilate(
+                    dilate(weight.tensor, 2, options.dilation[0]),
+                    3,
+                    options.dilation[1],
+                ),
+                1,
+            ),
+        };
+        let stride = match options.stride[0] == options.stride[1] {
+            true => options.stride[0],
+            false => 1,
+        };
+
         let conv = x
-            .tensor
-            .conv2d(
-                &weight.tensor,
-                options.padding[0],
-                options.stride[0],
-                options.dilation[0],
-                options.groups,
-            )
+            .conv2d(&weight, padding, stride, dilation, options.groups)
             .unwrap();
+        let conv = match options.stride[0] == options.stride[1] {
+            true => conv,
+            false => subsample(subsample(conv, 2, options.stride[0]), 3, options.stride[1]),
+        };
+
         CandleTensor::new(match bias {
             Some(bias) => conv
                 .broadcast_add(
@@ -83,20 +108,25 @@ impl<F: FloatCandleElement, I: IntCandleElement> ModuleOps<Self> for Candle<F, I
         bias: Option<FloatTensor<Self, 1>>,
         options: ConvTransposeOptions<1>,
     ) -> FloatTensor<Self, 3> {
-        assert!(
-            options.groups == 1,
-            "Candle does not support groups in transposed convolutions"
+        // Candle doesn't support groups, so each group is convolved separately.
+        let inputs = x.tensor.chunk(options.groups, 1).unwrap();
+        let weights = weight.tensor.chunk(options.groups, 0).unwrap();
+        let outputs = inputs
+            .iter()
+            .zip(weights.iter())
+            .map(|(x, weight)| {
+                x.conv_transpose1d(weight, 0, 0, options.stride[0], options.dilation[0])
+                    .unwrap()
+            })
+            .collect::<Vec<_>>();
+
+        let conv_transpose = crop_padding(
+            Tensor::cat(&outputs, 1).unwrap(),
+            2,
+            options.padding[0],
+            options.padding_out[0],
         );
-        let conv_transpose = x
-            .tensor
-            .conv_transpose1d(
-                &weight.tensor,
-                options.padding[0],
-                options.padding_out[0],
-                options.stride[0],
-                options.dilation[0],
-            )
-            .unwrap();
+
         CandleTensor::new(match bias {
             Some(bias) => conv_transpose
                 .broadcast_add(&bias.tensor.unsqueeze(0).unwrap().unsqueeze(2).unwrap())
@@ -111,27 +141,50 @@ impl<F: FloatCandleElement, I: IntCandleElement> ModuleOps<Self> for Candle<F, I
         bias: Option<FloatTensor<Self, 1>>,
         options: ConvTransposeOptions<2>,
     ) -> FloatTensor<Self, 4> {
-        assert!(
-            options.dilation[0] == options.dilation[1]
-                && options.padding[0] == options.padding[1]
-                && options.padding_out[0] == options.padding_out[1]
-                && options.stride[0] == options.stride[1],
-            "Candle does not support per dimension options in transposed convolutions"
+        // Candle only supports the same options for both dimensions, so the others are applied
+        // by dilating the input and the weight, and the padding by cropping the output.
+        let (x, stride) = match options.stride[0] == options.stride[1] {
+            true => (x.tensor, options.stride[0]),
+            false => (
+                dilate(dilate(x.tensor, 2, options.stride[0]), 3, options.stride[1]),
+                1,
+            ),
+        };
+        let (weight, dilation) = match options.dilation[0] == options.dilation[1] {
+            true => (weight.tensor, options.dilation[0]),
+            false => (
+                dilate(
+                    dilate(weight.tensor, 2, options.dilation[0]),
+                    3,
+                    options.dilation[1],
+                ),
+                1,
+            ),
+        };
+
+        // Candle doesn't support groups, so each group is convolved separately.
+        let inputs = x.chunk(options.groups, 1).unwrap();
+        let weights = weight.chunk(options.groups, 0).unwrap();
+        let outputs = inputs
+            .iter()
+            .zip(weights.iter())
+            .map(|(x, weight)| x.conv_transpose2d(weight, 0, 0, stride, dilation).unwrap())
+            .collect::<Vec<_>>();
+
+        let conv_transpose = Tensor::cat(&outputs, 1).unwrap();
+        let conv_transpose = crop_padding(
+            conv_transpose,
+            2,
+            options.padding[0],
+            options.padding_out[0],
         );
-        assert!(
-            options.groups == 1,
-            "Candle does not support groups in transposed convolutions"
+        let conv_transpose = crop_padding(
+            conv_transpose,
+            3,
+            options.padding[1],
+            options.padding_out[1],
         );
-        let conv_transpose = x
-            .tensor
-            .conv_transpose2d(
-                &weight.tensor,
-                options.padding[0],
-                options.padding_out[0],
-                options.stride[0],
-                options.dilation[0],
-            )
-            .unwrap();
+
         CandleTensor::new(match bias {
             Some(bias) => conv_transpose
                 .broadcast_add(
@@ -149,6 +202,25 @@ impl<F: FloatCandleElement, I: IntCandleElement> ModuleOps<Self> for Candle<F, I
         })
     }
 
+    fn attention(
+        query: FloatTensor<Self, 4>,
+        key: FloatTensor<Self, 4>,
+        value: FloatTensor<Self, 4>,
+        scale: f32,
+    ) -> FloatTensor<Self, 4> {
+        #[cfg(feature = "flash-attn")]
+        if let Some(output) = super::flash_attention::flash_attention(
+            &query.tensor,
+            &key.tensor,
+            &value.tensor,
+            scale,
+        ) {
+            return CandleTensor::new(output);
+        }
+
+        attention_from_blocks::<Self>(query, key, value, scale)
+    }
+
     fn avg_pool2d(
         x: FloatTensor<Self, 4>,
         kernel_size: [usize; 2],
@@ -156,19 +228,13 @@ impl<F: FloatCandleElement, I: IntCandleElement> ModuleOps<Self> for Candle<F, I
         padding: [usize; 2],
         count_include_pad: bool,
     ) -> FloatTensor<Self, 4> {
-        assert!(
-            padding[0] == 0 && padding[1] == 0,
-            "Candle does not support padding in pooling"
-        );
-        assert!(
+        CandleTensor::new(pool::avg_pool2d(
+            &x.tensor,
+            kernel_size,
+            stride,
+            padding,
             count_include_pad,
-            "Candle does not support excluding pad count in pooling"
-        );
-        CandleTensor::new(
-            x.tensor
-                .avg_pool2d_with_stride((kernel_size[0], kernel_size[1]), (stride[0], stride[1]))
-                .unwrap(),
-        )
+        ))
     }
 
     fn avg_pool2d_backward(
@@ -179,7 +245,14 @@ impl<F: FloatCandleElement, I: IntCandleElement> ModuleOps<Self> for Candle<F, I
         padding: [usize; 2],
         count_include_pad: bool,
     ) -> FloatTensor<Self, 4> {
-        panic!("avg_pool2d_backward is not supported by Candle")
+        CandleTensor::new(pool::avg_pool2d_backward(
+            &x.tensor,
+            &grad.tensor,
+            kernel_size,
+            stride,
+            padding,
+            count_include_pad,
+        ))
     }
 
     fn max_pool2d(
@@ -189,19 +262,21 @@ impl<F: FloatCandleElement, I: IntCandleElement> ModuleOps<Self> for Candle<F, I
         padding: [usize; 2],
         dilation: [usize; 2],
     ) -> FloatTensor<Self, 4> {
-        assert!(
-            padding[0] == 0 && padding[1] == 0,
-            "Candle does not support padding in pooling"
-        );
-        assert!(
-            dilation[0] == 1 && dilation[1] == 1,
-            "Candle does not support dilation in pooling"
-        );
-        CandleTensor::new(
-            x.tensor
-                .max_pool2d_with_stride((kernel_size[0], kernel_size[1]), (stride[0], stride[1]))
-                .unwrap(),
-        )
+        if padding == [0, 0] && dilation == [1, 1] {
+            return CandleTensor::new(
+                x.tensor
+                    .max_pool2d_with_stride(
+                        (kernel_size[0], kernel_size[1]),
+                        (stride[0], stride[1]),
+                    )
+                    .unwrap(),
+            );
+        }
+
+        let (output, _indices) =
+            pool::max_pool2d_with_indices(&x.tensor, kernel_size, stride, padding, dilation);
+
+        CandleTensor::new(output)
     }
 
     fn max_pool2d_with_indices(
@@ -211,7 +286,13 @@ impl<F: FloatCandleElement, I: IntCandleElement> ModuleOps<Self> for Candle<F, I
         padding: [usize; 2],
         dilation: [usize; 2],
     ) -> MaxPool2dWithIndices<Candle<F, I>> {
-        panic!("max_pool2d_with_indices is not supported by Candle")
+        let (output, indices) =
+            pool::max_pool2d_with_indices(&x.tensor, kernel_size, stride, padding, dilation);
+
+        MaxPool2dWithIndices::new(
+            CandleTensor::new(output),
+            CandleTensor::new(indices.to_dtype(I::DTYPE).unwrap()),
+        )
     }
 
     fn max_pool2d_with_indices_backward(
@@ -223,20 +304,24 @@ impl<F: FloatCandleElement, I: IntCandleElement> ModuleOps<Self> for Candle<F, I
         output_grad: FloatTensor<Self, 4>,
         indices: IntTensor<Self, 4>,
     ) -> MaxPool2dBackward<Candle<F, I>> {
-        panic!("max_pool2d_with_indices_backward is not supported by Candle")
+        MaxPool2dBackward::new(CandleTensor::new(pool::max_pool2d_with_indices_backward(
+            &x.tensor,
+            &output_grad.tensor,
+            &indices.tensor,
+        )))
     }
 
     fn adaptive_avg_pool2d(
         x: FloatTensor<Self, 4>,
         output_size: [usize; 2],
     ) -> FloatTensor<Self, 4> {
-        panic!("adaptive_avg_pool2 is not supported by Candle")
+        CandleTensor::new(pool::adaptive_avg_pool2d(&x.tensor, output_size))
     }
 
     fn adaptive_avg_pool2d_backward(
         x: FloatTensor<Self, 4>,
         grad: FloatTensor<Self, 4>,
     ) -> FloatTensor<Self, 4> {
-        panic!("adaptive_avg_pool2d_backward is not supported by Candle")
+        CandleTensor::new(pool::adaptive_avg_pool2d_backward(&x.tensor, &grad.tensor))
     }
 }
@@ -0,0 +1,334 @@
+use burn_tensor::ops::conv::calculate_pool_output_size;
+use candle_core::{DType, Tensor};
+
+/// The positions of the input read by the windows of a 2D pooling.
+///
+/// The positions are flattened over the height and width of the input and laid out as
+/// `[kernel_size[0] * kernel_size[1], out_height, out_width]`. The padding is mapped to the
+/// position right after the last element of the input.
+struct PoolWindows {
+    positions: Vec<u32>,
+    /// The number of positions of each window that aren't padding.
+    counts: Vec<u32>,
+    num_positions: usize,
+    output_size: [usize; 2],
+}
+
+impl PoolWindows {
+    fn new(
+        input_size: [usize; 2],
+        kernel_size: [usize; 2],
+        stride: [usize; 2],
+        padding: [usize; 2],
+        dilation: [usize; 2],
+    ) -> Self {
+        let [height, width] = input_size;
+        let output_size = [0, 1].map(|dim| {
+            calculate_pool_output_size(
+                kernel_size[dim],
+                stride[dim],
+                padding[dim],
+                dilation[dim],
+                input_size[dim],
+            )
+        });
+        let [out_height, out_width] = output_size;
+        let pad_position = (height * width) as u32;
+
+        let mut positions =
+            Vec::with_capacity(kernel_size[0] * kernel_size[1] * out_height * out_width);
+        let mut counts = vec![0; out_height * out_width];
+
+        for kh in 0..kernel_size[0] {
+            for kw in 0..kernel_size[1] {
+                for oh in 0..out_height {
+                    for ow in 0..out_width {
+                        let row =
+                            (oh * stride[0] + kh * dilation[0]) as isize - padding[0] as isize;
+                        let col =
+                            (ow * stride[1] + kw * dilation[1]) as isize - padding[1] as isize;
+
+                        if row < 0 || col < 0 || row >= height as isize || col >= width as isize {
+                            positions.push(pad_position);
+                        } else {
+                            positions.push(row as u32 * width as u32 + col as u32);
+                            counts[oh * out_width + ow] += 1;
+                        }
+                    }
+                }
+            }
+        }
+
+        Self {
+            positions,
+            counts,
+            num_positions: height * width,
+            output_size,
+        }
+    }
+
+    fn num_windows(&self) -> usize {
+        self.positions.len() / (self.output_size[0] * self.output_size[1])
+    }
+
+    /// Gather the values of every window, with the given value for the padding, as a tensor of
+    /// shape `[batch_size, channels, num_windows, out_height, out_width]`.
+    fn gather(&self, x: &Tensor, pad_value: f64) -> Tensor {
+        let (batch_size, channels, _, _) = x.dims4().unwrap();
+        let [out_height, out_width] = self.output_size;
+
+        let padding = (Tensor::ones((batch_size, channels, 1), x.dtype(), x.device()).unwrap()
+            * pad_value)
+            .unwrap();
+        let x = Tensor::cat(
+            &[
+                x.reshape((batch_size, channels, self.num_positions))
+                    .unwrap(),
+                padding,
+            ],
+            2,
+        )
+        .unwrap();
+
+        x.index_select(&self.positions(x.device()), 2)
+            .unwrap()
+            .reshape((
+                batch_size,
+                channels,
+                self.num_windows(),
+                out_height,
+                out_width,
+            ))
+            .unwrap()
+    }
+
+    /// Sum the values of every window of the source into the positions they were read from.
+    fn scatter_add(&self, source: &Tensor) -> Tensor {
+        let (batch_size, channels) = (source.dims()[0], source.dims()[1]);
+        let num_values = self.positions.len();
+
+        let indices = self
+            .positions(source.device())
+            .broadcast_as((batch_size, channels, num_values))
+            .unwrap()
+            .contiguous()
+            .unwrap();
+        let source = source.reshape((batch_size, channels, num_values)).unwrap();
+
+        Tensor::zeros(
+            (batch_size, channels, self.num_positions + 1),
+            source.dtype(),
+            source.device(),
+        )
+        .unwrap()
+        .scatter_add(&indices, &source, 2)
+        .unwrap()
+        .narrow(2, 0, self.num_positions)
+        .unwrap()
+    }
+
+    /// The number of elements each output is averaged over.
+    fn divisor(&self, count_include_pad: bool, x: &Tensor) -> Tensor {
+        let [out_height, out_width] = self.output_size;
+        let counts = match count_include_pad {
+            true => vec![self.num_windows() as u32; out_height * out_width],
+            false => self.counts.clone(),
+        };
+
+        Tensor::from_vec(counts, (out_height, out_width), x.device())
+            .unwrap()
+            .to_dtype(x.dtype())
+            .unwrap()
+    }
+
+    fn positions(&self, device: &candle_core::Device) -> Tensor {
+        Tensor::new(self.positions.as_slice(), device).unwrap()
+    }
+}
+
+pub(crate) fn avg_pool2d(
+    x: &Tensor,
+    kernel_size: [usize; 2],
+    stride: [usize; 2],
+    padding: [usize; 2],
+    count_include_pad: bool,
+) -> Tensor {
+    let (_, _, height, width) = x.dims4().unwrap();
+    let windows = PoolWindows::new([height, width], kernel_size, stride, padding, [1, 1]);
+
+    windows
+        .gather(x, 0.0)
+        .sum(2)
+        .unwrap()
+        .broadcast_div(&windows.divisor(count_include_pad, x))
+        .unwrap()
+}
+
+pub(crate) fn avg_pool2d_backward(
+    x: &Tensor,
+    grad: &Tensor,
+    kernel_size: [usize; 2],
+    stride: [usize; 2],
+    padding: [usize; 2],
+    count_include_pad: bool,
+) -> Tensor {
+    let (batch_size, channels, height, width) = x.dims4().unwrap();
+    let windows = PoolWindows::new([height, width], kernel_size, stride, padding, [1, 1]);
+    let [out_height, out_width] = windows.output_size;
+
+    let grad = grad
+        .broadcast_div(&windows.divisor(count_include_pad, x))
+        .unwrap()
+        .unsqueeze(2)
+        .unwrap()
+        .broadcast_as((
+            batch_size,
+            channels,
+            windows.num_windows(),
+            out_height,
+            out_width,
+        ))
+        .unwrap()
+        .contiguous()
+        .unwrap();
+
+    windows
+        .scatter_add(&grad)
+        .reshape((batch_size, channels, height, width))
+        .unwrap()
+}
+
+/// The maximum of each window and its position in the input, flattened over the height and
+/// width.
+pub(crate) fn max_pool2d_with_indices(
+    x: &Tensor,
+    kernel_size: [usize; 2],
+    stride: [usize; 2],
+    padding: [usize; 2],
+    dilation: [usize; 2],
+) -> (Tensor, Tensor) {
+    let (batch_size, channels, height, width) = x.dims4().unwrap();
+    let windows = PoolWindows::new([height, width], kernel_size, stride, padding, dilation);
+    let [out_height, out_width] = windows.output_size;
+
+    let values = windows.gather(x, f64::NEG_INFINITY);
+    let output = values.max(2).unwrap();
+    let argmax = values.argmax_keepdim(2).unwrap();
+
+    let indices = windows
+        .positions(x.device())
+        .reshape((1, 1, windows.num_windows(), out_height, out_width))
+        .unwrap()
+        .broadcast_as((
+            batch_size,
+            channels,
+            windows.num_windows(),
+            out_height,
+            out_width,
+        ))
+        .unwrap()
+        .contiguous()
+        .unwrap()
+        .gather(&argmax, 2)
+        .unwrap()
+        .squeeze(2)
+        .unwrap();
+
+    (output, indices)
+}
+
+pub(crate) fn max_pool2d_with_indices_backward(
+    x: &Tensor,
+    output_grad: &Tensor,
+    indices: &Tensor,
+) -> Tensor {
+    let (batch_size, channels, height, width) = x.dims4().unwrap();
+    let (_, _, out_height, out_width) = output_grad.dims4().unwrap();
+    let num_outputs = out_height * out_width;
+
+    let indices = indices
+        .to_dtype(DType::U32)
+        .unwrap()
+        .reshape((batch_size, channels, num_outputs))
+        .unwrap();
+    let output_grad = output_grad
+        .reshape((batch_size, channels, num_outputs))
+        .unwrap();
+
+    Tensor::zeros(
+        (batch_size, channels, height * width),
+        output_grad.dtype(),
+        output_grad.device(),
+    )
+    .unwrap()
+    .scatter_add(&indices, &output_grad, 2)
+    .unwrap()
+    .reshape((batch_size, channels, height, width))
+    .unwrap()
+}
+
+/// Matrix averaging the inputs of each output of an adaptive pooling along one dimension, with
+/// the shape `[output_size, input_size]`.
+///
+/// Adaptive average pooling is separable, so the 2D pooling is a product with the pooling
+/// matrices of the height and width.
+fn adaptive_pool_matrix(input_size: usize, output_size: usize, x: &Tensor) -> Tensor {
+    let mut values = vec![0f32; output_size * input_size];
+
+    for out in 0..output_size {
+        let start = (out * input_size) / output_size;
+        let end = usize::min(
+            ((out + 1) * input_size + output_size - 1) / output_size,
+            input_size,
+        );
+
+        for index in start..end {
+            values[out * input_size + index] = 1.0 / (end - start) as f32;
+        }
+    }
+
+    Tensor::from_vec(values, (output_size, input_size), x.device())
+        .unwrap()
+        .to_dtype(x.dtype())
+        .unwrap()
+}
+
+/// Multiply each matrix of the tensor on the left and on the right.
+fn matmul_both_sides(lhs: &Tensor, x: &Tensor, rhs: &Tensor) -> Tensor {
+    let (batch_size, channels, _, _) = x.dims4().unwrap();
+    let (lhs_rows, lhs_cols) = lhs.dims2().unwrap();
+    let (rhs_rows, rhs_cols) = rhs.dims2().unwrap();
+
+    let lhs = lhs
+        .broadcast_as((batch_size, channels, lhs_rows, lhs_cols))
+        .unwrap()
+        .contiguous()
+        .unwrap();
+    let rhs = rhs
+        .broadcast_as((batch_size, channels, rhs_rows, rhs_cols))
+        .unwrap()
+        .contiguous()
+        .unwrap();
+
+    lhs.matmul(&x.contiguous().unwrap())
+        .unwrap()
+        .matmul(&rhs)
+        .unwrap()
+}
+
+pub(crate) fn adaptive_avg_pool2d(x: &Tensor, output_size: [usize; 2]) -> Tensor {
+    let (_, _, height, width) = x.dims4().unwrap();
+    let pool_height = adaptive_pool_matrix(height, output_size[0], x);
+    let pool_width = adaptive_pool_matrix(width, output_size[1], x);
+
+    matmul_both_sides(&pool_height, x, &pool_width.t().unwrap())
+}
+
+pub(crate) fn adaptive_avg_pool2d_backward(x: &Tensor, grad: &Tensor) -> Tensor {
+    let (_, _, height, width) = x.dims4().unwrap();
+    let (_, _, out_height, out_width) = grad.dims4().unwrap();
+    let pool_height = adaptive_pool_matrix(height, out_height, x);
+    let pool_width = adaptive_pool_matrix(width, out_width, x);
+
+    matmul_both_sides(&pool_height.t().unwrap(), grad, &pool_width)
+}
@@ -7,6 +7,7 @@ use burn_tensor::{
 use candle_core::{backend::BackendStorage, shape, Tensor};
 
 use crate::{
+    backend::with_seeded_rng,
     element::{CandleElement, FloatCandleElement, IntCandleElement},
     Candle, CandleTensor,
 };
@@ -21,6 +22,12 @@ impl<F: FloatCandleElement, I: IntCandleElement> TensorOps<Self> for Candle<F, I
         distribution: Distribution,
         device: &Device<Self>,
     ) -> FloatTensor<Self, D> {
+        if let Some(data) =
+            with_seeded_rng(device, |rng| Data::random(shape.clone(), distribution, rng))
+        {
+            return Self::from_data(data, device);
+        }
+
         let shape = &shape.dims;
         let device = &(*device).into();
         match distribution {
@@ -0,0 +1,187 @@
+use std::{
+    collections::HashMap,
+    io::{Read, Seek},
+    sync::Arc,
+};
+
+use burn_tensor::Tensor;
+use candle_core::{
+    quantized::{gguf_file, QMatMul, QTensor},
+    Module,
+};
+
+pub use candle_core::quantized::GgmlDType;
+
+use crate::{
+    element::{FloatCandleElement, IntCandleElement},
+    Candle, CandleDevice, CandleTensor,
+};
+
+/// A tensor quantized with one of the [GGML types](GgmlDType) of candle, such as the weights of
+/// the large language models distributed as [GGUF files](GgufFile).
+///
+/// Quantized tensors are kept in host memory and are dequantized when read, either into a
+/// [float tensor](CandleQTensor::dequantize) or directly by the
+/// [quantized matrix multiplication](CandleQTensor::matmul).
+#[derive(Debug, Clone)]
+pub struct CandleQTensor {
+    qtensor: Arc<QTensor>,
+}
+
+impl CandleQTensor {
+    /// The shape of the tensor.
+    pub fn shape(&self) -> Vec<usize> {
+        self.qtensor.shape().dims().to_vec()
+    }
+
+    /// The quantization type of the tensor.
+    pub fn dtype(&self) -> GgmlDType {
+        self.qtensor.dtype()
+    }
+
+    /// Dequantize the tensor into a float tensor of the given device.
+    ///
+    /// # Panics
+    ///
+    /// If the rank of the tensor isn't `D`.
+    pub fn dequantize<F: FloatCandleElement, I: IntCandleElement, const D: usize>(
+        &self,
+        device: &CandleDevice,
+    ) -> Tensor<Candle<F, I>, D> {
+        assert_eq!(
+            self.qtensor.rank(),
+            D,
+            "The quantized tensor has rank {} but {} was expected",
+            self.qtensor.rank(),
+            D
+        );
+
+        let tensor = self
+            .qtensor
+            .dequantize(&(*device).into())
+            .unwrap()
+            .to_dtype(F::DTYPE)
+            .unwrap();
+
+        Tensor::from_primitive(CandleTensor::new(tensor))
+    }
+
+    /// Multiply the input by the transposed quantized matrix, `input @ self^T`, without
+    /// dequantizing the whole matrix.
+    ///
+    /// This is the layout of the weights of linear layers in GGUF files, where the quantized matrix
+    /// has the shape `[d_output, d_input]` and the input the shape `[..., d_input]`.
+    ///
+    /// # Notes
+    ///
+    /// The multiplication is executed on the CPU, the input is moved there and the output is moved
+    /// back to the device of the input.
+    pub fn matmul<F: FloatCandleElement, I: IntCandleElement, const D: usize>(
+        &self,
+        input: Tensor<Candle<F, I>, D>,
+    ) -> Tensor<Candle<F, I>, D> {
+        let input = input.into_primitive().tensor;
+        let device = input.device().clone();
+        let input = input
+            .to_device(&candle_core::Device::Cpu)
+            .unwrap()
+            .to_dtype(candle_core::DType::F32)
+            .unwrap();
+
+        let output = QMatMul::from_arc(self.qtensor.clone())
+            .forward(&input)
+            .unwrap()
+            .to_device(&device)
+            .unwrap()
+            .to_dtype(F::DTYPE)
+            .unwrap();
+
+        Tensor::from_primitive(CandleTensor::new(output))
+    }
+}
+
+impl From<QTensor> for CandleQTensor {
+    fn from(qtensor: QTensor) -> Self {
+        Self {
+            qtensor: Arc::new(qtensor),
+        }
+    }
+}
+
+/// The quantized tensors and metadata of a [GGUF](https://github.com/ggerganov/ggml/blob/master/docs/gguf.md)
+/// file, as used by `llama.cpp` and the quantized models of candle.
+#[derive(Debug)]
+pub struct GgufFile {
+    content: gguf_file::Content,
+    tensors: HashMap<String, CandleQTensor>,
+}
+
+impl GgufFile {
+    /// Read the metadata and every tensor of the file.
+    pub fn read<R: Read + Seek>(reader: &mut R) -> candle_core::Result<Self> {
+        let content = gguf_file::Content::read(reader)?;
+        let mut tensors = HashMap::with_capacity(content.tensor_infos.len());
+
+        for name in content.tensor_infos.keys() {
+            let qtensor = content.tensor(reader, name)?;
+            tensors.insert(name.clone(), CandleQTensor::from(qtensor));
+        }
+
+        Ok(Self { content, tensors })
+    }
+
+    /// The quantized tensor with the given name, if any.
+    pub fn tensor(&self, name: &str) -> Option<&CandleQTensor> {
+        self.tensors.get(name)
+    }
+
+    /// The names of the tensors of the file.
+    pub fn tensor_names(&self) -> impl Iterator<Item = &str> {
+        self.tensors.keys().map(String::as_str)
+    }
+
+    /// The metadata of the file, such as the hyper-parameters of the model and its tokenizer.
+    pub fn metadata(&self) -> &HashMap<String, gguf_file::Value> {
+        &self.content.metadata
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use candle_core::quantized::k_quants::BlockQ8_0;
+
+    type TestBackend = Candle<f32, i64>;
+
+    fn weights() -> candle_core::Tensor {
+        let values = (0..64).map(|i| (i % 8) as f32 - 4.).collect::<Vec<_>>();
+        candle_core::Tensor::from_vec(values, (2, 32), &candle_core::Device::Cpu).unwrap()
+    }
+
+    #[test]
+    fn should_dequantize_on_read() {
+        let qtensor = CandleQTensor::from(QTensor::quantize::<BlockQ8_0>(&weights()).unwrap());
+
+        let tensor: Tensor<TestBackend, 2> = qtensor.dequantize(&CandleDevice::Cpu);
+
+        assert_eq!(qtensor.shape(), vec![2, 32]);
+        assert_eq!(qtensor.dtype(), GgmlDType::Q8_0);
+        let expected = Tensor::<TestBackend, 2>::from_primitive(CandleTensor::new(weights()));
+        tensor
+            .into_data()
+            .assert_approx_eq(&expected.into_data(), 1);
+    }
+
+    #[test]
+    fn should_multiply_by_the_transposed_quantized_matrix() {
+        let qtensor = CandleQTensor::from(QTensor::quantize::<BlockQ8_0>(&weights()).unwrap());
+        let input = Tensor::<TestBackend, 2>::ones([3, 32], &Default::default());
+
+        let output = qtensor.matmul(input.clone());
+        let expected = input.matmul(qtensor.dequantize(&CandleDevice::Cpu).transpose());
+
+        output
+            .into_data()
+            .assert_approx_eq(&expected.into_data(), 1);
+    }
+}
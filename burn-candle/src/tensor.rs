@@ -1,21 +1,29 @@
-use std::marker::PhantomData;
+use std::{marker::PhantomData, sync::Arc};
 
-use burn_tensor::{Data, Element, Shape};
+use burn_tensor::{
+    memory::{MemoryProfiler, TrackedAllocation},
+    Data, Element, Shape,
+};
 
-use crate::{element::CandleElement, CandleDevice};
+use crate::{element::CandleElement, memory::memory_source, CandleDevice};
 
 /// A tensor that uses the candle backend.
 #[derive(Debug, Clone)]
 pub struct CandleTensor<E: CandleElement, const D: usize> {
     pub(crate) tensor: candle_core::Tensor,
+    // Storage recorded by the memory profiler, shared by the clones of the tensor.
+    _allocation: Option<Arc<TrackedAllocation>>,
     phantom: PhantomData<E>,
 }
 
 impl<E: CandleElement, const D: usize> CandleTensor<E, D> {
     /// Create a new tensor.
     pub fn new(tensor: candle_core::Tensor) -> Self {
+        let allocation = track_storage(&tensor).map(Arc::new);
+
         Self {
             tensor,
+            _allocation: allocation,
             phantom: PhantomData,
         }
     }
@@ -42,3 +50,58 @@ impl<E: CandleElement, const D: usize> CandleTensor<E, D> {
         Shape::from(x)
     }
 }
+
+/// Record the storage of the tensor in the memory profiler.
+///
+/// Views share the storage of the tensor they are created from, so the storage is identified by
+/// its address and only counted once.
+fn track_storage(tensor: &candle_core::Tensor) -> Option<TrackedAllocation> {
+    if !MemoryProfiler::is_enabled() {
+        return None;
+    }
+
+    let (storage, _layout) = tensor.storage_and_layout();
+    let key = &*storage as *const candle_core::Storage as u64;
+    let bytes = tensor.elem_count() * tensor.dtype().size_in_bytes();
+    let source = memory_source(tensor.device().clone().into());
+
+    MemoryProfiler::track_shared(source, key, bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Candle;
+    use burn_tensor::Tensor;
+    use half::{bf16, f16};
+
+    #[test]
+    fn should_support_f16_elements() {
+        type TestBackend = Candle<f16, i64>;
+        let data = Data::<f32, 2>::from([[0.5, -1.0], [2.0, 3.25]]);
+
+        let tensor =
+            Tensor::<TestBackend, 2>::from_data(data.convert::<f16>(), &Default::default());
+        let output = (tensor.clone() + tensor).into_data();
+
+        assert_eq!(
+            output.convert::<f32>(),
+            Data::from([[1.0, -2.0], [4.0, 6.5]])
+        );
+    }
+
+    #[test]
+    fn should_support_bf16_elements() {
+        type TestBackend = Candle<bf16, i64>;
+        let data = Data::<f32, 2>::from([[0.5, -1.0], [2.0, 3.25]]);
+
+        let tensor =
+            Tensor::<TestBackend, 2>::from_data(data.convert::<bf16>(), &Default::default());
+        let output = (tensor.clone() + tensor).into_data();
+
+        assert_eq!(
+            output.convert::<f32>(),
+            Data::from([[1.0, -2.0], [4.0, 6.5]])
+        );
+    }
+}
@@ -0,0 +1,57 @@
+/*
+ * C API of the Burn deep learning framework: https://github.com/tracel-ai/burn
+ *
+ * Every handle created by the library must be freed with the matching `burn_*_free` function.
+ * Fallible functions return a `BurnStatus`, the message of the last error of the calling thread
+ * being available with `burn_last_error_message`.
+ */
+
+#ifndef BURN_H
+#define BURN_H
+
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+typedef enum BurnStatus {
+    BURN_STATUS_OK = 0,
+    BURN_STATUS_NULL_POINTER = 1,
+    BURN_STATUS_INVALID_ARGUMENT = 2,
+    BURN_STATUS_MODEL_ERROR = 3,
+    BURN_STATUS_PANIC = 4,
+} BurnStatus;
+
+typedef struct BurnDevice BurnDevice;
+typedef struct BurnTensor BurnTensor;
+typedef struct BurnModel BurnModel;
+
+/* Errors */
+const char *burn_last_error_message(void);
+
+/* Devices */
+BurnDevice *burn_device_default(void);
+void burn_device_free(BurnDevice *device);
+
+/* Tensors */
+BurnStatus burn_tensor_create(const float *data, const size_t *shape, size_t rank,
+                              const BurnDevice *device, BurnTensor **out);
+size_t burn_tensor_rank(const BurnTensor *tensor);
+size_t burn_tensor_num_elements(const BurnTensor *tensor);
+BurnStatus burn_tensor_shape(const BurnTensor *tensor, size_t *shape);
+BurnStatus burn_tensor_read(const BurnTensor *tensor, float *data, size_t len);
+void burn_tensor_free(BurnTensor *tensor);
+
+/* Models, `burn_model_create` is generated by the `export_model!` macro of the model library. */
+BurnStatus burn_model_create(const uint8_t *weights, size_t len, const BurnDevice *device,
+                             BurnModel **out);
+BurnStatus burn_model_forward(const BurnModel *model, const BurnTensor *input, BurnTensor **out);
+void burn_model_free(BurnModel *model);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* BURN_H */
@@ -0,0 +1,34 @@
+use crate::CBackend;
+use burn_core::tensor::backend::Backend;
+
+/// Opaque handle to a device.
+pub struct BurnDevice {
+    pub(crate) device: <CBackend as Backend>::Device,
+}
+
+impl BurnDevice {
+    /// The device of the backend.
+    pub fn device(&self) -> &<CBackend as Backend>::Device {
+        &self.device
+    }
+}
+
+/// Create a handle to the default device, to be freed with [burn_device_free].
+#[no_mangle]
+pub extern "C" fn burn_device_default() -> *mut BurnDevice {
+    Box::into_raw(Box::new(BurnDevice {
+        device: Default::default(),
+    }))
+}
+
+/// Free a device handle.
+///
+/// # Safety
+///
+/// The device must have been created by [burn_device_default] and not already freed.
+#[no_mangle]
+pub unsafe extern "C" fn burn_device_free(device: *mut BurnDevice) {
+    if !device.is_null() {
+        drop(Box::from_raw(device));
+    }
+}
@@ -0,0 +1,111 @@
+use core::fmt::Display;
+use std::cell::RefCell;
+use std::ffi::{c_char, CString};
+use std::panic::{catch_unwind, AssertUnwindSafe};
+
+/// Status returned by the fallible functions of the C API.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BurnStatus {
+    /// The call succeeded.
+    Ok = 0,
+    /// A required pointer is null.
+    NullPointer = 1,
+    /// An argument is invalid, e.g. a shape that doesn't match the data.
+    InvalidArgument = 2,
+    /// The model failed to load or to execute.
+    ModelError = 3,
+    /// The call panicked.
+    Panic = 4,
+}
+
+/// Error that can happen when calling the C API.
+#[derive(Debug)]
+pub enum CapiError {
+    /// A required pointer is null.
+    NullPointer(&'static str),
+    /// An argument is invalid.
+    InvalidArgument(String),
+    /// The model failed to load or to execute.
+    Model(String),
+}
+
+impl Display for CapiError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::NullPointer(name) => write!(f, "The pointer {name} is null"),
+            Self::InvalidArgument(message) => write!(f, "Invalid argument: {message}"),
+            Self::Model(message) => write!(f, "Model error: {message}"),
+        }
+    }
+}
+
+impl std::error::Error for CapiError {}
+
+impl CapiError {
+    fn status(&self) -> BurnStatus {
+        match self {
+            Self::NullPointer(_) => BurnStatus::NullPointer,
+            Self::InvalidArgument(_) => BurnStatus::InvalidArgument,
+            Self::Model(_) => BurnStatus::ModelError,
+        }
+    }
+}
+
+thread_local! {
+    static LAST_ERROR: RefCell<Option<CString>> = const { RefCell::new(None) };
+}
+
+fn set_last_error(message: String) {
+    log::error!("{message}");
+
+    // Interior null bytes can't be represented in a C string.
+    let message = CString::new(message.replace('\0', " ")).unwrap();
+    LAST_ERROR.with(|last| *last.borrow_mut() = Some(message));
+}
+
+/// Execute the body of a C API function, converting errors and panics into a status.
+pub(crate) fn ffi_call<F>(func: F) -> BurnStatus
+where
+    F: FnOnce() -> Result<(), CapiError>,
+{
+    // The handles are never used again by the library after a panic, the state they may leave
+    // behind is only observable by the caller.
+    match catch_unwind(AssertUnwindSafe(func)) {
+        Ok(Ok(())) => BurnStatus::Ok,
+        Ok(Err(err)) => {
+            let status = err.status();
+            set_last_error(err.to_string());
+            status
+        }
+        Err(panic) => {
+            let message = panic
+                .downcast_ref::<&str>()
+                .map(|message| message.to_string())
+                .or_else(|| panic.downcast_ref::<String>().cloned())
+                .unwrap_or_else(|| "Unknown panic".to_string());
+            set_last_error(format!("Panicked: {message}"));
+            BurnStatus::Panic
+        }
+    }
+}
+
+/// Returns the message of the last error that happened on the calling thread, or null if no
+/// error happened.
+///
+/// The message is owned by the library and valid until the next failing call on the same thread.
+#[no_mangle]
+pub extern "C" fn burn_last_error_message() -> *const c_char {
+    LAST_ERROR.with(|last| match last.borrow().as_ref() {
+        Some(message) => message.as_ptr(),
+        None => std::ptr::null(),
+    })
+}
+
+/// Check that the pointer isn't null.
+pub(crate) fn non_null<T>(ptr: *const T, name: &'static str) -> Result<*const T, CapiError> {
+    match ptr.is_null() {
+        true => Err(CapiError::NullPointer(name)),
+        false => Ok(ptr),
+    }
+}
@@ -0,0 +1,36 @@
+#![warn(missing_docs)]
+
+//! C API to embed burn models in applications written in C, C++, Go or any language able to call
+//! C functions.
+//!
+//! Devices, tensors and models are exposed as opaque handles created and freed with the
+//! `burn_*_create` and `burn_*_free` functions, declared in the `include/burn.h` header.
+//! Every fallible function returns a [BurnStatus], the message of the last error of the calling
+//! thread being available with [burn_last_error_message].
+//!
+//! Since a model is defined in Rust, a library exporting it is built by implementing [CModel] and
+//! calling [export_model] in a crate compiled as `cdylib` or `staticlib`, which generates the
+//! `burn_model_create` function. C applications then only use the header.
+//!
+//! ## Feature Flags
+//!
+//! - `ndarray` (default): Executes the models with the NdArray backend.
+//! - `wgpu`: Executes the models with the WGPU backend, takes precedence over `ndarray`.
+
+mod device;
+mod error;
+mod model;
+mod tensor;
+
+pub use device::*;
+pub use error::*;
+pub use model::*;
+pub use tensor::*;
+
+/// The backend used to execute the models.
+#[cfg(feature = "wgpu")]
+pub type CBackend = burn_wgpu::Wgpu<burn_wgpu::AutoGraphicsApi, f32, i32>;
+
+/// The backend used to execute the models.
+#[cfg(all(feature = "ndarray", not(feature = "wgpu")))]
+pub type CBackend = burn_ndarray::NdArray<f32>;
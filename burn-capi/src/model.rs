@@ -0,0 +1,249 @@
+use crate::{ffi_call, non_null, BurnDevice, BurnStatus, BurnTensor, CBackend, CapiError};
+use burn_core::{
+    module::Module,
+    record::{BinBytesRecorder, FullPrecisionSettings, Recorder},
+};
+
+/// A model that can be exported with the C API.
+pub trait CModel: Send + 'static {
+    /// Execute the model on the given input.
+    fn forward(&self, input: BurnTensor) -> Result<BurnTensor, CapiError>;
+}
+
+/// Opaque handle to a model.
+pub struct BurnModel {
+    model: Box<dyn CModel>,
+}
+
+/// Load weights saved with a [BinBytesRecorder] using [FullPrecisionSettings] into the module.
+pub fn load_module<M: Module<CBackend>>(
+    module: M,
+    weights: &[u8],
+    device: &BurnDevice,
+) -> Result<M, CapiError> {
+    let record = BinBytesRecorder::<FullPrecisionSettings>::default()
+        .load(weights.to_vec())
+        .map_err(|err| CapiError::Model(err.to_string()))?;
+
+    Ok(module.load_record(record).to_device(&device.device))
+}
+
+/// Create a model with the given factory, used by the `burn_model_create` function generated by
+/// [export_model](crate::export_model).
+///
+/// # Safety
+///
+/// `weights` must be null or point to `len` bytes, `device` to a valid device and `out` to
+/// writable memory.
+pub unsafe fn create_model<M, F>(
+    weights: *const u8,
+    len: usize,
+    device: *const BurnDevice,
+    out: *mut *mut BurnModel,
+    factory: F,
+) -> BurnStatus
+where
+    M: CModel,
+    F: FnOnce(&[u8], &BurnDevice) -> Result<M, CapiError>,
+{
+    ffi_call(|| {
+        let out = non_null(out, "out")? as *mut *mut BurnModel;
+        let device = &*non_null(device, "device")?;
+        let weights: &[u8] = match len {
+            0 => &[],
+            _ => std::slice::from_raw_parts(non_null(weights, "weights")?, len),
+        };
+
+        let model = factory(weights, device)?;
+        *out = Box::into_raw(Box::new(BurnModel {
+            model: Box::new(model),
+        }));
+
+        Ok(())
+    })
+}
+
+/// Generate the `burn_model_create` function of the C API for a model.
+///
+/// The factory receives the weights given by the caller and the device, and returns the model
+/// implementing [CModel](crate::CModel).
+///
+/// # Example
+///
+/// ```rust, ignore
+/// impl CModel for Mnist<CBackend> {
+///     fn forward(&self, input: BurnTensor) -> Result<BurnTensor, CapiError> {
+///         let images = input.into_tensor::<3>()?;
+///         Ok(BurnTensor::from_tensor(self.forward(images)))
+///     }
+/// }
+///
+/// burn_capi::export_model!(|weights, device| {
+///     burn_capi::load_module(Mnist::new(device.device()), weights, device)
+/// });
+/// ```
+#[macro_export]
+macro_rules! export_model {
+    ($factory:expr) => {
+        /// Create the model from its weights, the model is written to `out` and must be freed
+        /// with `burn_model_free`.
+        ///
+        /// # Safety
+        ///
+        /// `weights` must be null or point to `len` bytes, `device` to a valid device and `out`
+        /// to writable memory.
+        #[no_mangle]
+        pub unsafe extern "C" fn burn_model_create(
+            weights: *const u8,
+            len: usize,
+            device: *const $crate::BurnDevice,
+            out: *mut *mut $crate::BurnModel,
+        ) -> $crate::BurnStatus {
+            $crate::create_model(weights, len, device, out, $factory)
+        }
+    };
+}
+
+/// Execute the model on the input, the output is written to `out` and must be freed with
+/// [burn_tensor_free](crate::burn_tensor_free). The input is left untouched and must still be
+/// freed by the caller.
+///
+/// # Safety
+///
+/// `model` and `input` must be valid handles and `out` must point to writable memory.
+#[no_mangle]
+pub unsafe extern "C" fn burn_model_forward(
+    model: *const BurnModel,
+    input: *const BurnTensor,
+    out: *mut *mut BurnTensor,
+) -> BurnStatus {
+    ffi_call(|| {
+        let model = &*non_null(model, "model")?;
+        let input = &*non_null(input, "input")?;
+        let out = non_null(out, "out")? as *mut *mut BurnTensor;
+
+        let output = model.model.forward(input.clone())?;
+        *out = Box::into_raw(Box::new(output));
+
+        Ok(())
+    })
+}
+
+/// Free a model handle.
+///
+/// # Safety
+///
+/// The model must have been created by `burn_model_create` and not already freed.
+#[no_mangle]
+pub unsafe extern "C" fn burn_model_free(model: *mut BurnModel) {
+    if !model.is_null() {
+        drop(Box::from_raw(model));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        burn_device_default, burn_device_free, burn_tensor_create, burn_tensor_free,
+        burn_tensor_read, burn_tensor_shape,
+    };
+    use burn_core::nn::{Linear, LinearConfig};
+
+    impl CModel for Linear<CBackend> {
+        fn forward(&self, input: BurnTensor) -> Result<BurnTensor, CapiError> {
+            Ok(BurnTensor::from_tensor(
+                self.forward(input.into_tensor::<2>()?),
+            ))
+        }
+    }
+
+    #[test]
+    fn exported_model_loads_weights_and_runs_forward() {
+        let device = BurnDevice {
+            device: Default::default(),
+        };
+        let reference = LinearConfig::new(3, 2).init::<CBackend>(&device.device);
+        let weights = BinBytesRecorder::<FullPrecisionSettings>::default()
+            .record(reference.clone().into_record(), ())
+            .unwrap();
+        let data = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0];
+        let shape = [2, 3];
+        let mut output = [0.0; 4];
+        let mut output_shape = [0; 2];
+
+        unsafe {
+            let device = burn_device_default();
+            let mut model = std::ptr::null_mut();
+            let mut input = std::ptr::null_mut();
+            let mut result = std::ptr::null_mut();
+
+            let status = create_model(
+                weights.as_ptr(),
+                weights.len(),
+                device,
+                &mut model,
+                |weights, device| {
+                    load_module(
+                        LinearConfig::new(3, 2).init(&device.device),
+                        weights,
+                        device,
+                    )
+                },
+            );
+            assert_eq!(status, BurnStatus::Ok);
+
+            burn_tensor_create(data.as_ptr(), shape.as_ptr(), 2, device, &mut input);
+            let status = burn_model_forward(model, input, &mut result);
+            assert_eq!(status, BurnStatus::Ok);
+
+            burn_tensor_shape(result, output_shape.as_mut_ptr());
+            burn_tensor_read(result, output.as_mut_ptr(), 4);
+
+            burn_tensor_free(result);
+            burn_tensor_free(input);
+            burn_model_free(model);
+            burn_device_free(device);
+        }
+
+        let expected = reference
+            .forward(burn_core::tensor::Tensor::from_floats(
+                [[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]],
+                &device.device,
+            ))
+            .into_data();
+
+        assert_eq!(output_shape, [2, 2]);
+        expected.assert_approx_eq(
+            &burn_core::tensor::Data::new(output.to_vec(), expected.shape.clone()),
+            3,
+        );
+    }
+
+    #[test]
+    fn forward_with_wrong_rank_fails() {
+        let data = [1.0, 2.0, 3.0];
+        let shape = [3];
+
+        unsafe {
+            let device = burn_device_default();
+            let mut model = std::ptr::null_mut();
+            let mut input = std::ptr::null_mut();
+            let mut result = std::ptr::null_mut();
+
+            create_model(std::ptr::null(), 0, device, &mut model, |_, device| {
+                Ok(LinearConfig::new(3, 2).init::<CBackend>(&device.device))
+            });
+            burn_tensor_create(data.as_ptr(), shape.as_ptr(), 1, device, &mut input);
+
+            let status = burn_model_forward(model, input, &mut result);
+
+            assert_eq!(status, BurnStatus::InvalidArgument);
+            assert!(result.is_null());
+
+            burn_tensor_free(input);
+            burn_model_free(model);
+            burn_device_free(device);
+        }
+    }
+}
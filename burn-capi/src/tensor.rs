@@ -0,0 +1,258 @@
+use crate::{ffi_call, non_null, BurnDevice, BurnStatus, CBackend, CapiError};
+use burn_core::tensor::{Data, Shape, Tensor};
+
+/// Opaque handle to a float tensor of any rank.
+#[derive(Debug, Clone)]
+pub struct BurnTensor {
+    tensor: Tensor<CBackend, 1>,
+    shape: Vec<usize>,
+}
+
+impl BurnTensor {
+    /// Create a handle from a tensor.
+    pub fn from_tensor<const D: usize>(tensor: Tensor<CBackend, D>) -> Self {
+        let shape = tensor.dims().to_vec();
+        let num_elements = tensor.shape().num_elements();
+
+        Self {
+            tensor: tensor.reshape([num_elements]),
+            shape,
+        }
+    }
+
+    /// Convert into a tensor of rank `D`, failing if the rank doesn't match.
+    pub fn into_tensor<const D: usize>(self) -> Result<Tensor<CBackend, D>, CapiError> {
+        if self.shape.len() != D {
+            return Err(CapiError::InvalidArgument(format!(
+                "Expected a tensor of rank {D}, got shape {:?}",
+                self.shape
+            )));
+        }
+
+        let mut dims = [0; D];
+        dims.copy_from_slice(&self.shape);
+
+        Ok(self.tensor.reshape(dims))
+    }
+
+    /// The shape of the tensor.
+    pub fn shape(&self) -> &[usize] {
+        &self.shape
+    }
+
+    fn num_elements(&self) -> usize {
+        self.shape.iter().product()
+    }
+}
+
+/// Create a tensor by copying `data`, which must contain the product of the `rank` dimensions
+/// of `shape` elements. The tensor is written to `out` and must be freed with [burn_tensor_free].
+///
+/// # Safety
+///
+/// `data` and `shape` must point to valid arrays of the given sizes, `device` to a valid device
+/// and `out` to writable memory.
+#[no_mangle]
+pub unsafe extern "C" fn burn_tensor_create(
+    data: *const f32,
+    shape: *const usize,
+    rank: usize,
+    device: *const BurnDevice,
+    out: *mut *mut BurnTensor,
+) -> BurnStatus {
+    ffi_call(|| {
+        let out = non_null(out, "out")? as *mut *mut BurnTensor;
+        let device = &*non_null(device, "device")?;
+
+        if rank == 0 {
+            return Err(CapiError::InvalidArgument(
+                "The rank must be greater than 0".into(),
+            ));
+        }
+
+        let shape = std::slice::from_raw_parts(non_null(shape, "shape")?, rank).to_vec();
+        let num_elements = shape.iter().product();
+        let data = match num_elements {
+            0 => Vec::new(),
+            _ => std::slice::from_raw_parts(non_null(data, "data")?, num_elements).to_vec(),
+        };
+
+        let data = Data::new(data, Shape::new([num_elements]));
+        let tensor = Tensor::from_data(data.convert(), &device.device);
+
+        *out = Box::into_raw(Box::new(BurnTensor { tensor, shape }));
+
+        Ok(())
+    })
+}
+
+/// Returns the rank of the tensor, or 0 if the tensor is null.
+///
+/// # Safety
+///
+/// `tensor` must be null or a valid tensor.
+#[no_mangle]
+pub unsafe extern "C" fn burn_tensor_rank(tensor: *const BurnTensor) -> usize {
+    match tensor.as_ref() {
+        Some(tensor) => tensor.shape.len(),
+        None => 0,
+    }
+}
+
+/// Returns the number of elements of the tensor, or 0 if the tensor is null.
+///
+/// # Safety
+///
+/// `tensor` must be null or a valid tensor.
+#[no_mangle]
+pub unsafe extern "C" fn burn_tensor_num_elements(tensor: *const BurnTensor) -> usize {
+    match tensor.as_ref() {
+        Some(tensor) => tensor.num_elements(),
+        None => 0,
+    }
+}
+
+/// Write the dimensions of the tensor to `shape`, which must have room for
+/// [rank](burn_tensor_rank) elements.
+///
+/// # Safety
+///
+/// `tensor` must be a valid tensor and `shape` must point to writable memory of the rank size.
+#[no_mangle]
+pub unsafe extern "C" fn burn_tensor_shape(
+    tensor: *const BurnTensor,
+    shape: *mut usize,
+) -> BurnStatus {
+    ffi_call(|| {
+        let tensor = &*non_null(tensor, "tensor")?;
+        let shape = non_null(shape, "shape")? as *mut usize;
+
+        std::slice::from_raw_parts_mut(shape, tensor.shape.len()).copy_from_slice(&tensor.shape);
+
+        Ok(())
+    })
+}
+
+/// Copy the elements of the tensor to `data`, `len` must be the
+/// [number of elements](burn_tensor_num_elements) of the tensor.
+///
+/// # Safety
+///
+/// `tensor` must be a valid tensor and `data` must point to writable memory of `len` elements.
+#[no_mangle]
+pub unsafe extern "C" fn burn_tensor_read(
+    tensor: *const BurnTensor,
+    data: *mut f32,
+    len: usize,
+) -> BurnStatus {
+    ffi_call(|| {
+        let tensor = &*non_null(tensor, "tensor")?;
+
+        if len != tensor.num_elements() {
+            return Err(CapiError::InvalidArgument(format!(
+                "The tensor has {} elements, got a buffer of {len} elements",
+                tensor.num_elements()
+            )));
+        }
+
+        if len == 0 {
+            return Ok(());
+        }
+
+        let data = non_null(data, "data")? as *mut f32;
+        let values = tensor.tensor.clone().into_data().convert::<f32>().value;
+        std::slice::from_raw_parts_mut(data, len).copy_from_slice(&values);
+
+        Ok(())
+    })
+}
+
+/// Free a tensor handle.
+///
+/// # Safety
+///
+/// The tensor must have been created by the library and not already freed.
+#[no_mangle]
+pub unsafe extern "C" fn burn_tensor_free(tensor: *mut BurnTensor) {
+    if !tensor.is_null() {
+        drop(Box::from_raw(tensor));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{burn_device_default, burn_device_free};
+
+    #[test]
+    fn tensor_round_trip() {
+        let data = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0];
+        let shape = [2, 3];
+        let mut output = [0.0; 6];
+        let mut read_shape = [0; 2];
+
+        unsafe {
+            let device = burn_device_default();
+            let mut tensor = std::ptr::null_mut();
+
+            let status = burn_tensor_create(data.as_ptr(), shape.as_ptr(), 2, device, &mut tensor);
+            assert_eq!(status, BurnStatus::Ok);
+            assert_eq!(burn_tensor_rank(tensor), 2);
+            assert_eq!(burn_tensor_num_elements(tensor), 6);
+            assert_eq!(
+                burn_tensor_shape(tensor, read_shape.as_mut_ptr()),
+                BurnStatus::Ok
+            );
+            assert_eq!(
+                burn_tensor_read(tensor, output.as_mut_ptr(), 6),
+                BurnStatus::Ok
+            );
+
+            burn_tensor_free(tensor);
+            burn_device_free(device);
+        }
+
+        assert_eq!(read_shape, shape);
+        assert_eq!(output, data);
+    }
+
+    #[test]
+    fn read_with_wrong_length_fails() {
+        let data = [1.0, 2.0];
+        let shape = [2];
+        let mut output = [0.0; 3];
+
+        unsafe {
+            let device = burn_device_default();
+            let mut tensor = std::ptr::null_mut();
+            burn_tensor_create(data.as_ptr(), shape.as_ptr(), 1, device, &mut tensor);
+
+            let status = burn_tensor_read(tensor, output.as_mut_ptr(), 3);
+
+            assert_eq!(status, BurnStatus::InvalidArgument);
+            assert!(!crate::burn_last_error_message().is_null());
+
+            burn_tensor_free(tensor);
+            burn_device_free(device);
+        }
+    }
+
+    #[test]
+    fn null_device_fails() {
+        let data = [1.0];
+        let shape = [1];
+
+        let status = unsafe {
+            let mut tensor = std::ptr::null_mut();
+            burn_tensor_create(
+                data.as_ptr(),
+                shape.as_ptr(),
+                1,
+                std::ptr::null(),
+                &mut tensor,
+            )
+        };
+
+        assert_eq!(status, BurnStatus::NullPointer);
+    }
+}
@@ -21,6 +21,9 @@ pub mod stub;
 /// Module for benchmarking any executable part
 pub mod benchmark;
 
+/// Memory profiler shared by the backends.
+pub mod memory;
+
 /// Useful when you need to read async data without having to decorate each function with async
 /// notation.
 pub mod reader;
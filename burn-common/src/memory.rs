@@ -0,0 +1,473 @@
+use crate::stub::Mutex;
+use alloc::collections::BTreeMap;
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::cmp::Reverse;
+use core::fmt::Display;
+use core::sync::atomic::{AtomicBool, Ordering};
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+static STATE: Mutex<ProfilerState> = Mutex::new(ProfilerState::new());
+
+#[cfg(feature = "std")]
+std::thread_local! {
+    static SCOPES: core::cell::RefCell<Vec<&'static str>> = const { core::cell::RefCell::new(Vec::new()) };
+}
+
+/// Label of the allocations made outside of any [scope](MemoryProfiler::scope).
+pub const UNSCOPED: &str = "unscoped";
+
+/// Memory profiler shared by every backend.
+///
+/// Backends report their allocations with [track](MemoryProfiler::track), which returns a guard
+/// releasing the allocation when dropped. Each allocation is attributed to a source, such as the
+/// memory pool of a backend, and to a label, which is the innermost
+/// [scope](MemoryProfiler::scope) of the allocating thread unless provided by the backend.
+///
+/// Nothing is recorded while the profiler is disabled, which is the default.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// MemoryProfiler::enable();
+///
+/// let output = {
+///     let _scope = MemoryProfiler::scope("encoder");
+///     model.encoder.forward(input)
+/// };
+///
+/// // What is holding the memory right now.
+/// println!("{}", MemoryProfiler::report());
+/// ```
+pub struct MemoryProfiler;
+
+/// Allocation recorded by the [memory profiler](MemoryProfiler), released when dropped.
+#[derive(Debug)]
+pub struct TrackedAllocation {
+    key: AllocationKey,
+}
+
+/// Label the allocations made by the current thread until dropped, see
+/// [MemoryProfiler::scope].
+#[cfg(feature = "std")]
+#[derive(Debug)]
+pub struct MemoryScope {
+    // The scope modifies a thread local state, so it must be dropped on the same thread.
+    _not_send: core::marker::PhantomData<*const ()>,
+}
+
+impl MemoryProfiler {
+    /// Start recording allocations.
+    pub fn enable() {
+        ENABLED.store(true, Ordering::Relaxed);
+    }
+
+    /// Stop recording new allocations.
+    ///
+    /// Allocations already recorded are still released when their guard is dropped.
+    pub fn disable() {
+        ENABLED.store(false, Ordering::Relaxed);
+    }
+
+    /// If allocations are recorded.
+    pub fn is_enabled() -> bool {
+        ENABLED.load(Ordering::Relaxed)
+    }
+
+    /// Reset the peak usage to the current usage.
+    pub fn reset_peak() {
+        let mut state = STATE.lock().unwrap();
+        state.peak_bytes = state.live_bytes;
+
+        for usage in state.sources.values_mut() {
+            usage.peak_bytes = usage.live_bytes;
+        }
+    }
+
+    /// Label the allocations made by the current thread until the returned scope is dropped.
+    ///
+    /// Scopes can be nested, the innermost one is used.
+    #[cfg(feature = "std")]
+    pub fn scope(label: &'static str) -> MemoryScope {
+        SCOPES.with(|scopes| scopes.borrow_mut().push(label));
+
+        MemoryScope {
+            _not_send: core::marker::PhantomData,
+        }
+    }
+
+    /// Record an allocation of the given source, labeled with the current scope.
+    ///
+    /// Returns `None` when the profiler is disabled.
+    pub fn track(source: &'static str, bytes: usize) -> Option<TrackedAllocation> {
+        Self::track_with_label(source, current_label(), bytes)
+    }
+
+    /// Record an allocation of the given source with an explicit label.
+    ///
+    /// Returns `None` when the profiler is disabled.
+    pub fn track_with_label(
+        source: &'static str,
+        label: &'static str,
+        bytes: usize,
+    ) -> Option<TrackedAllocation> {
+        if !Self::is_enabled() {
+            return None;
+        }
+
+        let mut state = STATE.lock().unwrap();
+        let key = AllocationKey::Unique(state.next_id);
+        state.next_id += 1;
+        state.acquire(key, source, label, bytes);
+
+        Some(TrackedAllocation { key })
+    }
+
+    /// Record an allocation that can be shared by multiple owners, such as a buffer referenced
+    /// by multiple tensor views.
+    ///
+    /// The allocation is identified by the source and the key, it is only counted once and is
+    /// released when the guards of every owner are dropped. The label and the size of the first
+    /// owner are kept.
+    ///
+    /// Returns `None` when the profiler is disabled.
+    pub fn track_shared(source: &'static str, key: u64, bytes: usize) -> Option<TrackedAllocation> {
+        if !Self::is_enabled() {
+            return None;
+        }
+
+        let key = AllocationKey::Shared(source, key);
+        STATE
+            .lock()
+            .unwrap()
+            .acquire(key, source, current_label(), bytes);
+
+        Some(TrackedAllocation { key })
+    }
+
+    /// Report the memory currently held, by source and by label.
+    pub fn report() -> MemoryReport {
+        STATE.lock().unwrap().report()
+    }
+}
+
+impl Drop for TrackedAllocation {
+    fn drop(&mut self) {
+        STATE.lock().unwrap().release(self.key);
+    }
+}
+
+#[cfg(feature = "std")]
+impl Drop for MemoryScope {
+    fn drop(&mut self) {
+        // The thread local might already be destroyed during the thread teardown.
+        let _ = SCOPES.try_with(|scopes| scopes.borrow_mut().pop());
+    }
+}
+
+/// Memory usage of a source, see [MemoryReport].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SourceUsage {
+    /// The source of the allocations, such as the memory pool of a backend.
+    pub source: &'static str,
+    /// Number of bytes currently allocated.
+    pub live_bytes: usize,
+    /// Maximum number of bytes allocated at the same time.
+    pub peak_bytes: usize,
+    /// Number of live allocations.
+    pub num_allocations: usize,
+}
+
+/// Memory currently held by the allocations of a source with the same label, see
+/// [MemoryReport].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LabelUsage {
+    /// The source of the allocations.
+    pub source: &'static str,
+    /// The label of the allocations, either a [scope](MemoryProfiler::scope) or an operation.
+    pub label: &'static str,
+    /// Number of bytes currently allocated.
+    pub live_bytes: usize,
+    /// Number of live allocations.
+    pub num_allocations: usize,
+}
+
+/// Snapshot of the memory recorded by the [memory profiler](MemoryProfiler).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MemoryReport {
+    /// Number of bytes currently allocated by every source.
+    pub live_bytes: usize,
+    /// Maximum number of bytes allocated at the same time by every source.
+    pub peak_bytes: usize,
+    /// Usage of each source, by decreasing live bytes.
+    pub sources: Vec<SourceUsage>,
+    /// Usage of each label, by decreasing live bytes.
+    pub labels: Vec<LabelUsage>,
+}
+
+impl Display for MemoryReport {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        writeln!(
+            f,
+            "Memory: {} live, {} peak",
+            format_bytes(self.live_bytes),
+            format_bytes(self.peak_bytes)
+        )?;
+
+        writeln!(f)?;
+        writeln!(
+            f,
+            "{:<24} {:>12} {:>12} {:>12}",
+            "Source", "Live", "Peak", "Allocations"
+        )?;
+        for usage in self.sources.iter() {
+            writeln!(
+                f,
+                "{:<24} {:>12} {:>12} {:>12}",
+                usage.source,
+                format_bytes(usage.live_bytes),
+                format_bytes(usage.peak_bytes),
+                usage.num_allocations
+            )?;
+        }
+
+        writeln!(f)?;
+        writeln!(
+            f,
+            "{:<24} {:<32} {:>12} {:>12}",
+            "Source", "Label", "Live", "Allocations"
+        )?;
+        for usage in self.labels.iter() {
+            writeln!(
+                f,
+                "{:<24} {:<32} {:>12} {:>12}",
+                usage.source,
+                usage.label,
+                format_bytes(usage.live_bytes),
+                usage.num_allocations
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum AllocationKey {
+    Unique(u64),
+    Shared(&'static str, u64),
+}
+
+struct Allocation {
+    source: &'static str,
+    label: &'static str,
+    bytes: usize,
+    owners: usize,
+}
+
+struct ProfilerState {
+    allocations: BTreeMap<AllocationKey, Allocation>,
+    sources: BTreeMap<&'static str, SourceUsage>,
+    live_bytes: usize,
+    peak_bytes: usize,
+    next_id: u64,
+}
+
+impl ProfilerState {
+    const fn new() -> Self {
+        Self {
+            allocations: BTreeMap::new(),
+            sources: BTreeMap::new(),
+            live_bytes: 0,
+            peak_bytes: 0,
+            next_id: 0,
+        }
+    }
+
+    fn acquire(
+        &mut self,
+        key: AllocationKey,
+        source: &'static str,
+        label: &'static str,
+        bytes: usize,
+    ) {
+        if let Some(allocation) = self.allocations.get_mut(&key) {
+            allocation.owners += 1;
+            return;
+        }
+
+        self.allocations.insert(
+            key,
+            Allocation {
+                source,
+                label,
+                bytes,
+                owners: 1,
+            },
+        );
+
+        let usage = self.sources.entry(source).or_insert(SourceUsage {
+            source,
+            live_bytes: 0,
+            peak_bytes: 0,
+            num_allocations: 0,
+        });
+        usage.live_bytes += bytes;
+        usage.peak_bytes = usize::max(usage.peak_bytes, usage.live_bytes);
+        usage.num_allocations += 1;
+
+        self.live_bytes += bytes;
+        self.peak_bytes = usize::max(self.peak_bytes, self.live_bytes);
+    }
+
+    fn release(&mut self, key: AllocationKey) {
+        let allocation = match self.allocations.get_mut(&key) {
+            Some(allocation) => allocation,
+            None => return,
+        };
+
+        allocation.owners -= 1;
+        if allocation.owners > 0 {
+            return;
+        }
+
+        let allocation = self.allocations.remove(&key).unwrap();
+
+        if let Some(usage) = self.sources.get_mut(allocation.source) {
+            usage.live_bytes -= allocation.bytes;
+            usage.num_allocations -= 1;
+        }
+
+        self.live_bytes -= allocation.bytes;
+    }
+
+    fn report(&self) -> MemoryReport {
+        let mut sources = self.sources.values().cloned().collect::<Vec<_>>();
+        sources.sort_by_key(|source| Reverse(source.live_bytes));
+
+        let mut labels = BTreeMap::<(&'static str, &'static str), LabelUsage>::new();
+        for allocation in self.allocations.values() {
+            let usage = labels
+                .entry((allocation.source, allocation.label))
+                .or_insert(LabelUsage {
+                    source: allocation.source,
+                    label: allocation.label,
+                    live_bytes: 0,
+                    num_allocations: 0,
+                });
+            usage.live_bytes += allocation.bytes;
+            usage.num_allocations += 1;
+        }
+        let mut labels = labels.into_values().collect::<Vec<_>>();
+        labels.sort_by_key(|label| Reverse(label.live_bytes));
+
+        MemoryReport {
+            live_bytes: self.live_bytes,
+            peak_bytes: self.peak_bytes,
+            sources,
+            labels,
+        }
+    }
+}
+
+fn current_label() -> &'static str {
+    #[cfg(feature = "std")]
+    {
+        SCOPES
+            .try_with(|scopes| scopes.borrow().last().copied())
+            .ok()
+            .flatten()
+            .unwrap_or(UNSCOPED)
+    }
+
+    #[cfg(not(feature = "std"))]
+    {
+        UNSCOPED
+    }
+}
+
+fn format_bytes(bytes: usize) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+
+    if bytes < 1024 {
+        return format!("{bytes} B");
+    }
+
+    let mut value = bytes as f64 / 1024.0;
+    let mut unit = UNITS[0];
+
+    for next in UNITS.iter().skip(1) {
+        if value < 1024.0 {
+            break;
+        }
+        value /= 1024.0;
+        unit = next;
+    }
+
+    format!("{value:.2} {unit}")
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+
+    // The profiler is global, so every assertion is made in a single test.
+    #[test]
+    fn allocations_are_reported_by_source_and_label() {
+        MemoryProfiler::enable();
+
+        let source = "test-source";
+        let unscoped = MemoryProfiler::track(source, 100).unwrap();
+        let (scoped, shared_1, shared_2) = {
+            let _scope = MemoryProfiler::scope("encoder");
+            (
+                MemoryProfiler::track(source, 1000).unwrap(),
+                MemoryProfiler::track_shared(source, 42, 10).unwrap(),
+                MemoryProfiler::track_shared(source, 42, 10).unwrap(),
+            )
+        };
+        let usage = |report: &MemoryReport| {
+            report
+                .sources
+                .iter()
+                .find(|usage| usage.source == source)
+                .cloned()
+                .unwrap()
+        };
+
+        let report = MemoryProfiler::report();
+        assert_eq!(usage(&report).live_bytes, 1110);
+        assert_eq!(usage(&report).num_allocations, 3);
+        let labels = report
+            .labels
+            .iter()
+            .filter(|usage| usage.source == source)
+            .map(|usage| (usage.label, usage.live_bytes))
+            .collect::<Vec<_>>();
+        assert_eq!(labels, vec![("encoder", 1010), (UNSCOPED, 100)]);
+
+        core::mem::drop(scoped);
+        core::mem::drop(shared_1);
+        let report = MemoryProfiler::report();
+        assert_eq!(usage(&report).live_bytes, 110);
+        assert_eq!(usage(&report).peak_bytes, 1110);
+
+        core::mem::drop(shared_2);
+        core::mem::drop(unscoped);
+        MemoryProfiler::reset_peak();
+        let report = MemoryProfiler::report();
+        assert_eq!(usage(&report).live_bytes, 0);
+        assert_eq!(usage(&report).peak_bytes, 0);
+
+        MemoryProfiler::disable();
+        assert!(MemoryProfiler::track(source, 100).is_none());
+    }
+
+    #[test]
+    fn bytes_are_formatted_with_binary_units() {
+        assert_eq!(format_bytes(12), "12 B");
+        assert_eq!(format_bytes(1536), "1.50 KiB");
+        assert_eq!(format_bytes(8 * 1024 * 1024 * 1024), "8.00 GiB");
+    }
+}
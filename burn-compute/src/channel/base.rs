@@ -1,4 +1,7 @@
-use crate::server::{ComputeServer, Handle};
+use crate::{
+    memory_management::MemoryUsage,
+    server::{ComputeServer, Handle, ReadFuture},
+};
 use alloc::vec::Vec;
 use burn_common::reader::Reader;
 
@@ -8,6 +11,9 @@ pub trait ComputeChannel<Server: ComputeServer>: Clone + core::fmt::Debug {
     /// Given a handle, returns owned resource as bytes
     fn read(&self, handle: &Handle<Server>) -> Reader<Vec<u8>>;
 
+    /// Given handles, returns a future of the owned resources as bytes
+    fn read_async(&self, handles: &[&Handle<Server>]) -> ReadFuture;
+
     /// Given a resource as bytes, stores it and returns the resource handle
     fn create(&self, data: &[u8]) -> Handle<Server>;
 
@@ -19,4 +25,16 @@ pub trait ComputeChannel<Server: ComputeServer>: Clone + core::fmt::Debug {
 
     /// Wait for the completion of every task in the server.
     fn sync(&self);
+
+    /// Start recording the kernels executed by the server.
+    fn capture_begin(&self);
+
+    /// Stop recording and return the graph of the recorded kernels.
+    fn capture_end(&self) -> Server::Graph;
+
+    /// Execute the kernels of the graph again.
+    fn replay(&self, graph: &Server::Graph);
+
+    /// The memory currently held by the server.
+    fn memory_usage(&self) -> MemoryUsage;
 }
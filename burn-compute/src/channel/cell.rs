@@ -1,5 +1,8 @@
 use super::ComputeChannel;
-use crate::server::{ComputeServer, Handle};
+use crate::{
+    memory_management::MemoryUsage,
+    server::{ComputeServer, Handle, ReadFuture},
+};
 use alloc::sync::Arc;
 use alloc::vec::Vec;
 use burn_common::reader::Reader;
@@ -46,6 +49,10 @@ where
         self.server.borrow_mut().read(handle)
     }
 
+    fn read_async(&self, handles: &[&Handle<Server>]) -> ReadFuture {
+        self.server.borrow_mut().read_async(handles)
+    }
+
     fn create(&self, resource: &[u8]) -> Handle<Server> {
         self.server.borrow_mut().create(resource)
     }
@@ -63,4 +70,20 @@ where
     fn sync(&self) {
         self.server.borrow_mut().sync()
     }
+
+    fn capture_begin(&self) {
+        self.server.borrow_mut().capture_begin()
+    }
+
+    fn capture_end(&self) -> Server::Graph {
+        self.server.borrow_mut().capture_end()
+    }
+
+    fn replay(&self, graph: &Server::Graph) {
+        self.server.borrow_mut().replay(graph)
+    }
+
+    fn memory_usage(&self) -> MemoryUsage {
+        self.server.borrow().memory_usage()
+    }
 }
@@ -6,7 +6,10 @@ use std::{
 use burn_common::reader::Reader;
 
 use super::ComputeChannel;
-use crate::server::{ComputeServer, Handle};
+use crate::{
+    memory_management::MemoryUsage,
+    server::{ComputeServer, Handle, ReadFuture},
+};
 
 /// Create a channel using the [multi-producer, single-consumer channel](mpsc) to communicate with
 /// the compute server spawn on its own thread.
@@ -34,10 +37,15 @@ where
     Server: ComputeServer,
 {
     Read(Handle<Server>, Callback<Reader<Vec<u8>>>),
+    ReadAsync(Vec<Handle<Server>>, Callback<ReadFuture>),
     Create(Vec<u8>, Callback<Handle<Server>>),
     Empty(usize, Callback<Handle<Server>>),
     ExecuteKernel(Server::Kernel, Vec<Handle<Server>>),
     Sync(Callback<()>),
+    CaptureBegin,
+    CaptureEnd(Callback<Server::Graph>),
+    Replay(Server::Graph),
+    MemoryUsage(Callback<MemoryUsage>),
 }
 
 impl<Server> MpscComputeChannel<Server>
@@ -56,6 +64,11 @@ where
                         core::mem::drop(handle);
                         callback.send(data).unwrap();
                     }
+                    Message::ReadAsync(handles, callback) => {
+                        let future = server.read_async(&handles.iter().collect::<Vec<_>>());
+                        core::mem::drop(handles);
+                        callback.send(future).unwrap();
+                    }
                     Message::Create(data, callback) => {
                         let handle = server.create(&data);
                         callback.send(handle).unwrap();
@@ -71,6 +84,19 @@ where
                         server.sync();
                         callback.send(()).unwrap();
                     }
+                    Message::CaptureBegin => {
+                        server.capture_begin();
+                    }
+                    Message::CaptureEnd(callback) => {
+                        let graph = server.capture_end();
+                        callback.send(graph).unwrap();
+                    }
+                    Message::Replay(graph) => {
+                        server.replay(&graph);
+                    }
+                    Message::MemoryUsage(callback) => {
+                        callback.send(server.memory_usage()).unwrap();
+                    }
                 };
             }
         });
@@ -104,6 +130,20 @@ where
         self.response(response)
     }
 
+    fn read_async(&self, handles: &[&Handle<Server>]) -> ReadFuture {
+        let (callback, response) = mpsc::sync_channel(1);
+
+        self.state
+            .sender
+            .send(Message::ReadAsync(
+                handles.iter().map(|handle| (*handle).clone()).collect(),
+                callback,
+            ))
+            .unwrap();
+
+        self.response(response)
+    }
+
     fn create(&self, data: &[u8]) -> Handle<Server> {
         let (callback, response) = mpsc::sync_channel(1);
 
@@ -146,6 +186,39 @@ where
 
         self.response(response)
     }
+
+    fn capture_begin(&self) {
+        self.state.sender.send(Message::CaptureBegin).unwrap()
+    }
+
+    fn capture_end(&self) -> Server::Graph {
+        let (callback, response) = mpsc::sync_channel(1);
+
+        self.state
+            .sender
+            .send(Message::CaptureEnd(callback))
+            .unwrap();
+
+        self.response(response)
+    }
+
+    fn replay(&self, graph: &Server::Graph) {
+        self.state
+            .sender
+            .send(Message::Replay(graph.clone()))
+            .unwrap()
+    }
+
+    fn memory_usage(&self) -> MemoryUsage {
+        let (callback, response) = mpsc::sync_channel(1);
+
+        self.state
+            .sender
+            .send(Message::MemoryUsage(callback))
+            .unwrap();
+
+        self.response(response)
+    }
 }
 
 impl<Server: ComputeServer> MpscComputeChannel<Server> {
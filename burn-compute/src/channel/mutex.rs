@@ -1,5 +1,8 @@
 use super::ComputeChannel;
-use crate::server::{ComputeServer, Handle};
+use crate::{
+    memory_management::MemoryUsage,
+    server::{ComputeServer, Handle, ReadFuture},
+};
 use alloc::sync::Arc;
 use alloc::vec::Vec;
 use burn_common::reader::Reader;
@@ -39,6 +42,10 @@ where
         self.server.lock().read(handle)
     }
 
+    fn read_async(&self, handles: &[&Handle<Server>]) -> ReadFuture {
+        self.server.lock().read_async(handles)
+    }
+
     fn create(&self, data: &[u8]) -> Handle<Server> {
         self.server.lock().create(data)
     }
@@ -54,4 +61,20 @@ where
     fn sync(&self) {
         self.server.lock().sync()
     }
+
+    fn capture_begin(&self) {
+        self.server.lock().capture_begin()
+    }
+
+    fn capture_end(&self) -> Server::Graph {
+        self.server.lock().capture_end()
+    }
+
+    fn replay(&self, graph: &Server::Graph) {
+        self.server.lock().replay(graph)
+    }
+
+    fn memory_usage(&self) -> MemoryUsage {
+        self.server.lock().memory_usage()
+    }
 }
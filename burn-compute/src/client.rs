@@ -1,6 +1,7 @@
 use crate::{
     channel::ComputeChannel,
-    server::{ComputeServer, Handle},
+    memory_management::MemoryUsage,
+    server::{ComputeServer, Handle, ReadFuture},
     tune::{AutotuneOperationSet, Tuner},
 };
 use alloc::vec::Vec;
@@ -51,6 +52,14 @@ where
         self.channel.read(handle)
     }
 
+    /// Given handles, returns a future of the owned resources as bytes, in the same order.
+    ///
+    /// Unlike [read](ComputeClient::read), awaiting the future doesn't block the calling thread
+    /// while the device completes the pending tasks.
+    pub fn read_async(&self, handles: &[&Handle<Server>]) -> ReadFuture {
+        self.channel.read_async(handles)
+    }
+
     /// Given a resource, stores it and returns the resource handle.
     pub fn create(&self, data: &[u8]) -> Handle<Server> {
         self.channel.create(data)
@@ -71,6 +80,29 @@ where
         self.channel.sync()
     }
 
+    /// Start recording the kernels executed by the server.
+    ///
+    /// Every kernel executed through the server is recorded, including the ones of other clients
+    /// of the same device, until [capture_end](ComputeClient::capture_end) is called.
+    pub fn capture_begin(&self) {
+        self.channel.capture_begin()
+    }
+
+    /// Stop recording and return the graph of the recorded kernels.
+    pub fn capture_end(&self) -> Server::Graph {
+        self.channel.capture_end()
+    }
+
+    /// Execute the kernels of the graph again, over the same resources.
+    pub fn replay(&self, graph: &Server::Graph) {
+        self.channel.replay(graph)
+    }
+
+    /// The memory currently held by the server.
+    pub fn memory_usage(&self) -> MemoryUsage {
+        self.channel.memory_usage()
+    }
+
     /// Executes the fastest kernel in the autotune operation, using (cached) runtime benchmarks
     pub fn execute_autotune(
         &self,
@@ -10,6 +10,15 @@ pub trait MemoryHandle: Clone + Send + core::fmt::Debug {
     fn can_mut(&self) -> bool;
 }
 
+/// Memory held by a [memory management](MemoryManagement) strategy.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct MemoryUsage {
+    /// Number of bytes allocated in the storage, whether they are used or kept for reuse.
+    pub bytes_reserved: usize,
+    /// Number of bytes of the allocations referenced by handles.
+    pub bytes_in_use: usize,
+}
+
 /// The MemoryManagement trait encapsulates strategies for (de)allocating memory.
 /// It is bound to the ComputeStorage trait, which does the actual (de)allocations.
 ///
@@ -50,4 +59,7 @@ pub trait MemoryManagement<Storage: ComputeStorage>: Send + core::fmt::Debug {
     /// This is useful if you need to time the deallocations based on async computation, or to
     /// change the mode of storage for different reasons.
     fn storage(&mut self) -> &mut Storage;
+
+    /// The memory currently held in the storage.
+    fn memory_usage(&self) -> MemoryUsage;
 }
@@ -1,9 +1,10 @@
-use super::{MemoryHandle, MemoryManagement};
+use super::{MemoryHandle, MemoryManagement, MemoryUsage};
 use crate::{
     memory_id_type,
     storage::{ComputeStorage, StorageHandle, StorageUtilization},
 };
 use alloc::{sync::Arc, vec::Vec};
+use burn_common::memory::{MemoryProfiler, TrackedAllocation};
 use hashbrown::HashMap;
 
 #[cfg(all(not(target_family = "wasm"), feature = "std"))]
@@ -120,8 +121,11 @@ impl DeallocStrategy {
 pub struct SimpleMemoryManagement<Storage> {
     chunks: HashMap<ChunkId, (StorageHandle, Vec<SliceId>)>,
     slices: HashMap<SliceId, (StorageHandle, ChunkId)>,
+    // Chunks recorded by the memory profiler, released when the chunks are deallocated.
+    tracked: HashMap<ChunkId, TrackedAllocation>,
     dealloc_strategy: DeallocStrategy,
     slice_strategy: SliceStrategy,
+    max_pooled_bytes: Option<usize>,
     storage: Storage,
 }
 
@@ -177,7 +181,7 @@ impl<Storage: ComputeStorage> MemoryManagement<Storage> for SimpleMemoryManageme
 
         let handle = self.reserve_algorithm(size);
 
-        if self.dealloc_strategy.should_dealloc() {
+        if self.dealloc_strategy.should_dealloc() || self.exceeds_max_pooled_bytes() {
             self.cleanup_chunks();
         }
 
@@ -193,6 +197,7 @@ impl<Storage: ComputeStorage> MemoryManagement<Storage> for SimpleMemoryManageme
             SimpleHandle::Chunk(id) => {
                 if let Some((handle, _slices)) = self.chunks.remove(id) {
                     self.storage.dealloc(handle.id);
+                    self.tracked.remove(id);
                 }
             }
             SimpleHandle::Slice(_) => panic!("Can't dealloc slice manually"),
@@ -202,6 +207,21 @@ impl<Storage: ComputeStorage> MemoryManagement<Storage> for SimpleMemoryManageme
     fn storage(&mut self) -> &mut Storage {
         &mut self.storage
     }
+
+    fn memory_usage(&self) -> MemoryUsage {
+        let mut usage = MemoryUsage::default();
+
+        for (chunk_id, (resource, slices)) in self.chunks.iter() {
+            let size = resource.size();
+            usage.bytes_reserved += size;
+
+            if !slices.is_empty() || !chunk_id.is_free() {
+                usage.bytes_in_use += size;
+            }
+        }
+
+        usage
+    }
 }
 
 impl<Storage: ComputeStorage> SimpleMemoryManagement<Storage> {
@@ -214,12 +234,34 @@ impl<Storage: ComputeStorage> SimpleMemoryManagement<Storage> {
         Self {
             chunks: HashMap::new(),
             slices: HashMap::new(),
+            tracked: HashMap::new(),
             dealloc_strategy,
             slice_strategy,
+            max_pooled_bytes: None,
             storage,
         }
     }
 
+    /// Deallocate the unused chunks as soon as they hold more than the given number of bytes,
+    /// instead of waiting for the [deallocation strategy](DeallocStrategy).
+    ///
+    /// This bounds the memory kept for reuse, which otherwise grows with the largest allocations
+    /// of the workload.
+    pub fn with_max_pooled_bytes(mut self, max_pooled_bytes: usize) -> Self {
+        self.max_pooled_bytes = Some(max_pooled_bytes);
+        self
+    }
+
+    fn exceeds_max_pooled_bytes(&self) -> bool {
+        match self.max_pooled_bytes {
+            Some(max_pooled_bytes) => {
+                let usage = self.memory_usage();
+                usage.bytes_reserved - usage.bytes_in_use > max_pooled_bytes
+            }
+            None => false,
+        }
+    }
+
     fn reserve_algorithm(&mut self, size: usize) -> SimpleHandle {
         // Looks for a large enough, existing but unused chunk of memory.
         let chunk = self.find_free_chunk(size);
@@ -302,6 +344,10 @@ impl<Storage: ComputeStorage> SimpleMemoryManagement<Storage> {
         let resource = self.storage.alloc(size);
         let chunk_id = ChunkId::new();
 
+        if let Some(allocation) = MemoryProfiler::track(storage_name::<Storage>(), size) {
+            self.tracked.insert(chunk_id.clone(), allocation);
+        }
+
         self.chunks.insert(chunk_id.clone(), (resource, Vec::new()));
 
         SimpleHandle::Chunk(chunk_id)
@@ -319,9 +365,10 @@ impl<Storage: ComputeStorage> SimpleMemoryManagement<Storage> {
 
         ids_to_remove
             .iter()
-            .map(|chunk_id| self.chunks.remove(chunk_id).unwrap())
-            .for_each(|(resource, _slices)| {
+            .map(|chunk_id| (chunk_id, self.chunks.remove(chunk_id).unwrap()))
+            .for_each(|(chunk_id, (resource, _slices))| {
                 self.storage.dealloc(resource.id);
+                self.tracked.remove(chunk_id);
             });
     }
 
@@ -348,6 +395,15 @@ impl<Storage: ComputeStorage> SimpleMemoryManagement<Storage> {
     }
 }
 
+/// Name of the storage used as the source of its allocations in the memory profiler, without the
+/// module path.
+fn storage_name<Storage>() -> &'static str {
+    let name = core::any::type_name::<Storage>();
+    let name = name.split('<').next().unwrap_or(name);
+
+    name.rsplit("::").next().unwrap_or(name)
+}
+
 #[cfg(test)]
 mod tests {
     use crate::{
@@ -356,6 +412,7 @@ mod tests {
     };
 
     use super::{DeallocStrategy, SimpleMemoryManagement};
+    use burn_common::memory::MemoryProfiler;
 
     #[test]
     fn can_mut_with_single_tensor_reference() {
@@ -441,6 +498,65 @@ mod tests {
         }
     }
 
+    #[test]
+    fn chunks_are_tracked_by_the_memory_profiler_until_deallocated() {
+        let mut memory_management = SimpleMemoryManagement::new(
+            BytesStorage::default(),
+            DeallocStrategy::Never,
+            SliceStrategy::Never,
+        );
+        MemoryProfiler::enable();
+
+        let chunk_handle = memory_management.reserve(16);
+        assert_eq!(memory_management.tracked.len(), 1);
+        assert!(MemoryProfiler::report()
+            .sources
+            .iter()
+            .any(|usage| usage.source == "BytesStorage" && usage.live_bytes >= 16));
+
+        drop(chunk_handle);
+        memory_management.cleanup_chunks();
+        assert_eq!(memory_management.tracked.len(), 0);
+
+        MemoryProfiler::disable();
+    }
+
+    #[test]
+    fn memory_usage_should_count_reserved_and_used_chunks() {
+        let mut memory_management = SimpleMemoryManagement::new(
+            BytesStorage::default(),
+            DeallocStrategy::Never,
+            SliceStrategy::Never,
+        );
+        let handle_1 = memory_management.reserve(16);
+        let _handle_2 = memory_management.reserve(8);
+        drop(handle_1);
+
+        let usage = memory_management.memory_usage();
+
+        assert_eq!(usage.bytes_reserved, 24);
+        assert_eq!(usage.bytes_in_use, 8);
+    }
+
+    #[test]
+    fn max_pooled_bytes_should_dealloc_unused_chunks() {
+        let mut memory_management = SimpleMemoryManagement::new(
+            BytesStorage::default(),
+            DeallocStrategy::Never,
+            SliceStrategy::Never,
+        )
+        .with_max_pooled_bytes(16);
+        let handle_1 = memory_management.reserve(16);
+        let handle_2 = memory_management.reserve(8);
+        drop(handle_1);
+        drop(handle_2);
+
+        let _handle_3 = memory_management.reserve(4);
+
+        assert_eq!(memory_management.chunks.len(), 1);
+        assert_eq!(memory_management.memory_usage().bytes_reserved, 4);
+    }
+
     #[test]
     fn slice_strategy_minimum_bytes() {
         let strategy = SliceStrategy::MinimumSize(100);
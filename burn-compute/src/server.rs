@@ -1,12 +1,16 @@
 use core::fmt::Debug;
 
 use crate::{
-    memory_management::{MemoryHandle, MemoryManagement},
+    memory_management::{MemoryHandle, MemoryManagement, MemoryUsage},
     storage::ComputeStorage,
     tune::AutotuneKey,
 };
-use alloc::vec::Vec;
+use alloc::{boxed::Box, vec::Vec};
 use burn_common::reader::Reader;
+use core::{future::Future, pin::Pin};
+
+/// Bytes of the resources read asynchronously with [read_async](ComputeServer::read_async).
+pub type ReadFuture = Pin<Box<dyn Future<Output = Vec<Vec<u8>>> + Send>>;
 
 /// The compute server is responsible for handling resources and computations over resources.
 ///
@@ -24,10 +28,18 @@ where
     type MemoryManagement: MemoryManagement<Self::Storage>;
     /// The key used to cache operations used on specific inputs in autotune
     type AutotuneKey: AutotuneKey;
+    /// A recording of kernel executions that can be [replayed](ComputeServer::replay).
+    type Graph: Clone + Send;
 
     /// Given a handle, returns the owned resource as bytes.
     fn read(&mut self, handle: &Handle<Self>) -> Reader<Vec<u8>>;
 
+    /// Given handles, returns a future of the owned resources as bytes, in the same order.
+    ///
+    /// The reads are scheduled before returning, but the future doesn't need to be awaited by the
+    /// server, so waiting for the device never blocks the other operations.
+    fn read_async(&mut self, handles: &[&Handle<Self>]) -> ReadFuture;
+
     /// Given a resource as bytes, stores it and returns the memory handle.
     fn create(&mut self, data: &[u8]) -> Handle<Self>;
 
@@ -42,6 +54,24 @@ where
 
     /// Wait for the completion of every task in the server.
     fn sync(&mut self);
+
+    /// Start recording the kernels executed by the server.
+    ///
+    /// Kernels are still executed normally while they are recorded.
+    fn capture_begin(&mut self);
+
+    /// Stop recording and return the graph of the kernels executed since
+    /// [capture_begin](ComputeServer::capture_begin).
+    ///
+    /// The graph keeps the resources of the recorded kernels alive, so they are never reused by
+    /// other allocations.
+    fn capture_end(&mut self) -> Self::Graph;
+
+    /// Execute the kernels of the graph again, in the same order and over the same resources.
+    fn replay(&mut self, graph: &Self::Graph);
+
+    /// The memory currently held by the [memory management](ComputeServer::MemoryManagement).
+    fn memory_usage(&self) -> MemoryUsage;
 }
 
 /// Server handle containing the [memory handle](MemoryManagement::Handle).
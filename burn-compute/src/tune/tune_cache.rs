@@ -18,12 +18,22 @@ use super::AutotuneOperationSet;
 use alloc::boxed::Box;
 use hashbrown::HashMap;
 
+/// Environment variable setting the directory of the persistent autotune cache, which is
+/// `~/.cache/burn/autotune` by default.
+#[cfg(feature = "autotune-persistent-cache")]
+pub const AUTOTUNE_CACHE_DIR_ENV: &str = "BURN_AUTOTUNE_CACHE_DIR";
+
 #[cfg(feature = "autotune-persistent-cache")]
 /// Return the file path for the persistent cache on disk
 /// prefix should be the device id computed at the backend level
 pub fn get_persistent_cache_file_path(prefix: &str) -> PathBuf {
-    let home_dir = dirs::home_dir().expect("An home directory should exist");
-    let path_dir = home_dir.join(".cache").join("burn").join("autotune");
+    let path_dir = match std::env::var_os(AUTOTUNE_CACHE_DIR_ENV) {
+        Some(dir) => PathBuf::from(dir),
+        None => {
+            let home_dir = dirs::home_dir().expect("An home directory should exist");
+            home_dir.join(".cache").join("burn").join("autotune")
+        }
+    };
     let path = Path::new(&path_dir);
     path.join(format!("{}-autotune-cache.json", prefix))
 }
@@ -2,8 +2,8 @@ use std::sync::Arc;
 
 use burn_common::reader::Reader;
 use burn_compute::{
-    memory_management::{MemoryManagement, SimpleMemoryManagement},
-    server::{ComputeServer, Handle},
+    memory_management::{MemoryManagement, MemoryUsage, SimpleMemoryManagement},
+    server::{ComputeServer, Handle, ReadFuture},
     storage::BytesStorage,
 };
 use derive_new::new;
@@ -15,6 +15,24 @@ use super::DummyKernel;
 #[derive(new, Debug)]
 pub struct DummyServer<MM = SimpleMemoryManagement<BytesStorage>> {
     memory_management: MM,
+    #[new(default)]
+    capture: Option<Vec<DummyTask<MM>>>,
+}
+
+/// The kernels recorded by the dummy server, with the handles they are executed on.
+pub type DummyGraph<MM> = Arc<Vec<DummyTask<MM>>>;
+
+pub struct DummyTask<MM: MemoryManagement<BytesStorage>> {
+    kernel: Arc<dyn DummyKernel>,
+    handles: Vec<Handle<DummyServer<MM>>>,
+}
+
+impl<MM: MemoryManagement<BytesStorage>> core::fmt::Debug for DummyTask<MM> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("DummyTask")
+            .field("handles", &self.handles)
+            .finish()
+    }
 }
 
 impl<MM> ComputeServer for DummyServer<MM>
@@ -25,6 +43,7 @@ where
     type Storage = BytesStorage;
     type MemoryManagement = MM;
     type AutotuneKey = String;
+    type Graph = DummyGraph<MM>;
 
     fn read(&mut self, handle: &Handle<Self>) -> Reader<Vec<u8>> {
         let bytes = self.memory_management.get(&handle.memory);
@@ -32,6 +51,15 @@ where
         Reader::Concrete(bytes.read().to_vec())
     }
 
+    fn read_async(&mut self, handles: &[&Handle<Self>]) -> ReadFuture {
+        let data = handles
+            .iter()
+            .map(|handle| self.memory_management.get(&handle.memory).read().to_vec())
+            .collect::<Vec<_>>();
+
+        Box::pin(async move { data })
+    }
+
     fn create(&mut self, data: &[u8]) -> Handle<Self> {
         let handle = self.memory_management.reserve(data.len());
         let resource = self.memory_management.get(&handle);
@@ -50,6 +78,13 @@ where
     }
 
     fn execute(&mut self, kernel: Self::Kernel, handles: &[&Handle<Self>]) {
+        if let Some(capture) = self.capture.as_mut() {
+            capture.push(DummyTask {
+                kernel: kernel.clone(),
+                handles: handles.iter().map(|handle| (*handle).clone()).collect(),
+            });
+        }
+
         let mut resources = handles
             .iter()
             .map(|handle| self.memory_management.get(&handle.memory))
@@ -61,4 +96,28 @@ where
     fn sync(&mut self) {
         // Nothing to do with dummy backend.
     }
+
+    fn capture_begin(&mut self) {
+        self.capture = Some(Vec::new());
+    }
+
+    fn capture_end(&mut self) -> Self::Graph {
+        Arc::new(self.capture.take().unwrap_or_default())
+    }
+
+    fn replay(&mut self, graph: &Self::Graph) {
+        for task in graph.iter() {
+            let mut resources = task
+                .handles
+                .iter()
+                .map(|handle| self.memory_management.get(&handle.memory))
+                .collect::<Vec<_>>();
+
+            task.kernel.compute(&mut resources);
+        }
+    }
+
+    fn memory_usage(&self) -> MemoryUsage {
+        self.memory_management.memory_usage()
+    }
 }
@@ -28,6 +28,17 @@ fn empty_allocates_memory() {
     assert_eq!(empty_resource.read().len(), 4);
 }
 
+#[test]
+fn resources_read_async_are_in_the_same_order() {
+    let client = client(&DummyDevice);
+    let first = client.create(&[0, 1, 2]);
+    let second = client.create(&[3, 4]);
+
+    let obtained_resources = pollster::block_on(client.read_async(&[&second, &first]));
+
+    assert_eq!(obtained_resources, vec![vec![3, 4], vec![0, 1, 2]]);
+}
+
 #[test]
 fn execute_elementwise_addition() {
     let client = client(&DummyDevice);
@@ -42,6 +53,27 @@ fn execute_elementwise_addition() {
     assert_eq!(obtained_resource.read(), Vec::from([4, 5, 6]))
 }
 
+#[test]
+fn replay_captured_kernels_on_updated_resources() {
+    let client = client(&DummyDevice);
+    let lhs = client.create(&[0, 1, 2]);
+    let rhs = client.create(&[4, 4, 4]);
+    let tmp = client.empty(3);
+    let out = client.empty(3);
+
+    client.capture_begin();
+    client.execute(Arc::new(DummyElementwiseAddition), &[&lhs, &rhs, &tmp]);
+    client.execute(Arc::new(DummyElementwiseAddition), &[&tmp, &rhs, &out]);
+    let graph = client.capture_end();
+    assert_eq!(client.read(&out).read(), Vec::from([8, 9, 10]));
+
+    // Update the input in place, then replay the two kernels.
+    client.execute(Arc::new(DummyElementwiseAddition), &[&rhs, &rhs, &lhs]);
+    client.replay(&graph);
+
+    assert_eq!(client.read(&out).read(), Vec::from([16, 16, 16]));
+}
+
 #[test]
 #[serial]
 #[cfg(feature = "std")]
@@ -203,6 +235,37 @@ fn autotune_cache_file_path_creation_works_when_path_does_not_exist_yet() {
     assert!(file_path.exists(), "Cache file should exist");
 }
 
+#[test]
+#[serial]
+#[cfg(feature = "std")]
+fn autotune_cache_directory_can_be_set_with_an_environment_variable() {
+    let cache_dir = std::env::temp_dir().join("burn-autotune-cache-dir-test");
+    std::env::set_var(burn_compute::tune::AUTOTUNE_CACHE_DIR_ENV, &cache_dir);
+    let file_path =
+        burn_compute::tune::get_persistent_cache_file_path(crate::dummy::TUNER_DEVICE_ID);
+    let _ = std::fs::remove_file(&file_path);
+
+    let compute: burn_compute::Compute<DummyDevice, dummy::DummyServer, dummy::DummyChannel> =
+        burn_compute::Compute::new();
+    let client = compute.client(&DummyDevice, dummy::init_client);
+
+    let shapes = vec![vec![1, 3], vec![1, 3], vec![1, 3]];
+    let lhs = client.create(&[0, 1, 2]);
+    let rhs = client.create(&[4, 4, 4]);
+    let out = client.empty(3);
+    let handles = vec![lhs, rhs, out.clone()];
+
+    let cache_test_autotune_kernel =
+        dummy::CacheTestAutotuneOperationSet::new(client.clone(), shapes, handles);
+    client.execute_autotune(Box::new(cache_test_autotune_kernel));
+    let _obtained_resource = client.read(&out);
+    std::env::remove_var(burn_compute::tune::AUTOTUNE_CACHE_DIR_ENV);
+
+    assert!(file_path.starts_with(&cache_dir));
+    assert!(file_path.exists(), "Cache file should exist");
+    let _ = std::fs::remove_dir_all(cache_dir);
+}
+
 #[test]
 #[serial]
 #[cfg(feature = "std")]
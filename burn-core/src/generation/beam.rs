@@ -0,0 +1,306 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
+use super::sampling::{log_softmax, token_sequences};
+use super::NextTokenModel;
+use crate as burn;
+use crate::config::Config;
+use crate::tensor::{backend::Backend, Data, Int, Shape, Tensor};
+
+/// Configuration of the [beam search](beam_search) function.
+#[derive(Config, Debug)]
+pub struct BeamSearchConfig {
+    /// The number of hypotheses kept at each step.
+    pub num_beams: usize,
+    /// The maximum number of tokens generated after the prompt.
+    pub max_new_tokens: usize,
+    /// The token ending a sequence.
+    pub eos_token: Option<usize>,
+    /// The exponent of the length normalization, the score of a hypothesis is its log probability
+    /// divided by `num_generated ^ length_penalty`.
+    ///
+    /// Values greater than zero favor longer sequences, zero disables the normalization.
+    #[config(default = 1.0)]
+    pub length_penalty: f64,
+}
+
+/// A sequence found by [beam search](beam_search).
+#[derive(Debug, Clone, PartialEq)]
+pub struct Hypothesis {
+    /// The tokens of the sequence, including the prompt.
+    pub tokens: Vec<usize>,
+    /// The length normalized log probability of the generated tokens.
+    pub score: f64,
+}
+
+/// Find the most likely continuations of a batch of prompts with beam search.
+///
+/// Each prompt is expanded into [num_beams](BeamSearchConfig::num_beams) sequences using
+/// [select_state](NextTokenModel::select_state), so the model is called with a batch of
+/// `batch_size * num_beams` sequences.
+///
+/// # Returns
+///
+/// The hypotheses of each prompt, sorted from the best score to the worst.
+///
+/// # Shapes
+///
+/// - prompt: `[batch_size, prompt_length]`
+pub fn beam_search<B, M>(
+    model: &M,
+    prompt: Tensor<B, 2, Int>,
+    state: &mut M::State,
+    config: &BeamSearchConfig,
+) -> Vec<Vec<Hypothesis>>
+where
+    B: Backend,
+    M: NextTokenModel<B>,
+{
+    assert!(config.num_beams > 0, "Beam search needs at least one beam");
+
+    let device = prompt.device();
+    let [batch_size, prompt_length] = prompt.dims();
+    let num_beams = config.num_beams;
+
+    let expand = (0..batch_size)
+        .flat_map(|batch| vec![batch as i64; num_beams])
+        .collect::<Vec<_>>();
+    let expand = indices_tensor::<B>(expand, &device);
+    model.select_state(state, expand.clone());
+
+    let mut tokens = prompt.select(0, expand);
+    let mut sequences = token_sequences(&tokens);
+    // Only the first beam is active at the first step, otherwise all beams would be identical.
+    let mut scores = (0..batch_size * num_beams)
+        .map(|beam| match beam % num_beams {
+            0 => 0.0,
+            _ => f64::NEG_INFINITY,
+        })
+        .collect::<Vec<_>>();
+    let mut hypotheses = vec![BeamHypotheses::new(num_beams); batch_size];
+
+    for step in 1..config.max_new_tokens + 1 {
+        let logits = model.next_token_logits(tokens.clone(), state);
+        let [_, vocab_size] = logits.dims();
+        let logits = logits.into_data().convert::<f32>().value;
+
+        let mut selected = Vec::with_capacity(batch_size * num_beams);
+        let mut next = Vec::with_capacity(batch_size * num_beams);
+        let mut next_scores = Vec::with_capacity(batch_size * num_beams);
+
+        for (batch, hypotheses) in hypotheses.iter_mut().enumerate() {
+            let beams = batch * num_beams..(batch + 1) * num_beams;
+
+            if hypotheses.done {
+                // The beams of finished prompts are kept as is until the others are done.
+                for beam in beams {
+                    selected.push(beam);
+                    next.push(config.eos_token.unwrap_or(0));
+                    next_scores.push(f64::NEG_INFINITY);
+                }
+                continue;
+            }
+
+            let mut candidates = Vec::with_capacity(num_beams * vocab_size);
+            for beam in beams {
+                if scores[beam] == f64::NEG_INFINITY {
+                    continue;
+                }
+                let log_probs = log_softmax(&logits[beam * vocab_size..(beam + 1) * vocab_size]);
+                candidates.extend(
+                    log_probs
+                        .into_iter()
+                        .enumerate()
+                        .map(|(token, log_prob)| (scores[beam] + log_prob, beam, token)),
+                );
+            }
+            candidates.sort_by(|a, b| b.0.total_cmp(&a.0));
+
+            let mut num_selected = 0;
+            // Keeping twice as many candidates guarantees enough running beams even if the best
+            // ones all end the sequence.
+            for (score, beam, token) in candidates.into_iter().take(2 * num_beams) {
+                if Some(token) == config.eos_token {
+                    let mut sequence = sequences[beam].clone();
+                    sequence.push(token);
+                    hypotheses.add(sequence, normalize(score, step, config.length_penalty));
+                    continue;
+                }
+
+                selected.push(beam);
+                next.push(token);
+                next_scores.push(score);
+                num_selected += 1;
+
+                if num_selected == num_beams {
+                    break;
+                }
+            }
+
+            // Not enough candidates when the vocabulary is smaller than the number of beams.
+            for _ in num_selected..num_beams {
+                selected.push(batch * num_beams);
+                next.push(config.eos_token.unwrap_or(0));
+                next_scores.push(f64::NEG_INFINITY);
+            }
+
+            let best_running = next_scores[batch * num_beams];
+            hypotheses.done =
+                hypotheses.is_done(normalize(best_running, step, config.length_penalty));
+        }
+
+        sequences = selected
+            .iter()
+            .zip(next.iter())
+            .map(|(beam, token)| {
+                let mut sequence = sequences[*beam].clone();
+                sequence.push(*token);
+                sequence
+            })
+            .collect();
+        scores = next_scores;
+
+        let selected = indices_tensor::<B>(selected.iter().map(|i| *i as i64).collect(), &device);
+        let next = Data::new(
+            next.iter().map(|token| *token as i64).collect(),
+            Shape::new([batch_size * num_beams, 1]),
+        )
+        .convert();
+
+        model.select_state(state, selected.clone());
+        tokens = Tensor::cat(
+            vec![tokens.select(0, selected), Tensor::from_data(next, &device)],
+            1,
+        );
+
+        if hypotheses.iter().all(|hypotheses| hypotheses.done) {
+            break;
+        }
+    }
+
+    let num_generated = sequences[0].len() - prompt_length;
+
+    hypotheses
+        .into_iter()
+        .enumerate()
+        .map(|(batch, mut hypotheses)| {
+            // Prompts reaching the maximum number of tokens use their running beams.
+            if !hypotheses.done {
+                for beam in batch * num_beams..(batch + 1) * num_beams {
+                    if scores[beam] > f64::NEG_INFINITY {
+                        hypotheses.add(
+                            sequences[beam].clone(),
+                            normalize(scores[beam], num_generated, config.length_penalty),
+                        );
+                    }
+                }
+            }
+
+            hypotheses.hypotheses
+        })
+        .collect()
+}
+
+#[derive(Clone, Debug)]
+struct BeamHypotheses {
+    hypotheses: Vec<Hypothesis>,
+    num_beams: usize,
+    done: bool,
+}
+
+impl BeamHypotheses {
+    fn new(num_beams: usize) -> Self {
+        Self {
+            hypotheses: Vec::with_capacity(num_beams + 1),
+            num_beams,
+            done: false,
+        }
+    }
+
+    fn add(&mut self, tokens: Vec<usize>, score: f64) {
+        if self.hypotheses.len() == self.num_beams && score <= self.worst_score() {
+            return;
+        }
+
+        self.hypotheses.push(Hypothesis { tokens, score });
+        self.hypotheses.sort_by(|a, b| b.score.total_cmp(&a.score));
+        self.hypotheses.truncate(self.num_beams);
+    }
+
+    fn worst_score(&self) -> f64 {
+        self.hypotheses
+            .last()
+            .map(|hypothesis| hypothesis.score)
+            .unwrap_or(f64::NEG_INFINITY)
+    }
+
+    /// The search is done when enough hypotheses are found and the best running beam can't
+    /// improve on them.
+    fn is_done(&self, best_running_score: f64) -> bool {
+        self.hypotheses.len() == self.num_beams && best_running_score <= self.worst_score()
+    }
+}
+
+fn normalize(score: f64, num_generated: usize, length_penalty: f64) -> f64 {
+    score / libm::pow(num_generated as f64, length_penalty)
+}
+
+fn indices_tensor<B: Backend>(indices: Vec<i64>, device: &B::Device) -> Tensor<B, 1, Int> {
+    let shape = Shape::new([indices.len()]);
+    Tensor::from_data(Data::new(indices, shape).convert(), device)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generation::sampling::tests::{BigramModel, EOS};
+    use crate::TestBackend;
+
+    fn prompt(batch_size: usize) -> Tensor<TestBackend, 2, Int> {
+        Tensor::zeros([batch_size, 1], &Default::default())
+    }
+
+    #[test]
+    fn beam_search_should_find_more_likely_sequence_than_greedy() {
+        let config = BeamSearchConfig::new(2, 5).with_eos_token(Some(EOS));
+
+        let hypotheses = beam_search(&BigramModel::new(), prompt(1), &mut 1, &config);
+
+        // Greedy search selects [0, 2, 3] with a probability of 0.55 * 0.5.
+        let best = &hypotheses[0][0];
+        assert_eq!(best.tokens, vec![0, 1, 3]);
+        assert!((best.score - libm::log(0.45 * 0.9) / 2.0).abs() < 1e-5);
+    }
+
+    #[test]
+    fn beam_search_should_return_sorted_hypotheses_for_each_prompt() {
+        let config = BeamSearchConfig::new(3, 5).with_eos_token(Some(EOS));
+
+        let hypotheses = beam_search(&BigramModel::new(), prompt(2), &mut 2, &config);
+
+        assert_eq!(hypotheses.len(), 2);
+        assert_eq!(hypotheses[0], hypotheses[1]);
+        assert_eq!(hypotheses[0].len(), 3);
+        for pair in hypotheses[0].windows(2) {
+            assert!(pair[0].score >= pair[1].score);
+        }
+    }
+
+    #[test]
+    fn length_penalty_should_favor_longer_sequences() {
+        let score = libm::log(0.5);
+
+        assert!(normalize(2.0 * score, 2, 1.0) > normalize(2.0 * score, 2, 0.0));
+        assert_eq!(normalize(score, 3, 0.0), score);
+    }
+
+    #[test]
+    fn beam_search_should_use_running_beams_without_eos() {
+        let config = BeamSearchConfig::new(2, 2);
+
+        let hypotheses = beam_search(&BigramModel::new(), prompt(1), &mut 1, &config);
+
+        assert_eq!(hypotheses[0].len(), 2);
+        assert!(hypotheses[0].iter().all(|h| h.tokens.len() == 3));
+    }
+}
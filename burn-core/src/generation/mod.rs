@@ -0,0 +1,7 @@
+mod beam;
+mod model;
+mod sampling;
+
+pub use beam::*;
+pub use model::*;
+pub use sampling::*;
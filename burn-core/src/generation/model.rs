@@ -0,0 +1,29 @@
+use crate::tensor::{backend::Backend, Int, Tensor};
+
+/// A model predicting the logits of the next token of a batch of sequences.
+///
+/// The generation functions call the model once per generated token with every token of the
+/// sequences so far, models using an autoregressive cache (such as the
+/// [transformer decoder](crate::nn::transformer::TransformerDecoder)) can keep it in their state
+/// and only process the last tokens.
+pub trait NextTokenModel<B: Backend> {
+    /// The state kept between generation steps, such as the autoregressive cache.
+    type State;
+
+    /// Compute the logits of the next token.
+    ///
+    /// # Shapes
+    ///
+    /// - tokens: `[batch_size, seq_length]`
+    /// - output: `[batch_size, vocab_size]`
+    fn next_token_logits(&self, tokens: Tensor<B, 2, Int>, state: &mut Self::State)
+        -> Tensor<B, 2>;
+
+    /// Select the sequences of the state at the given indices of the batch dimension.
+    ///
+    /// Indices can be repeated, beam search uses this to expand each sequence into multiple beams
+    /// and to follow the beams kept after each step. With the autoregressive cache of the
+    /// transformer, this is done with
+    /// [select_batch](crate::nn::transformer::TransformerDecoderAutoregressiveCache::select_batch).
+    fn select_state(&self, state: &mut Self::State, indices: Tensor<B, 1, Int>);
+}
@@ -0,0 +1,427 @@
+use alloc::vec;
+use alloc::vec::Vec;
+use burn_common::rand::{get_seeded_rng, Rng, SeedableRng, StdRng};
+
+use super::NextTokenModel;
+use crate as burn;
+use crate::config::Config;
+use crate::tensor::{backend::Backend, Data, Int, Shape, Tensor};
+
+/// Configuration to create a [token sampler](Sampler).
+///
+/// The logits are processed in the following order: repetition penalty, temperature, top-k and
+/// top-p. The default configuration samples from the unmodified distribution of the model.
+#[derive(Config, Debug)]
+pub struct SamplingConfig {
+    /// The temperature dividing the logits, a temperature of zero selects the most likely token.
+    #[config(default = 1.0)]
+    pub temperature: f64,
+    /// Only sample from the `k` most likely tokens.
+    pub top_k: Option<usize>,
+    /// Only sample from the smallest set of most likely tokens with a cumulative probability of at
+    /// least `p` (nucleus sampling).
+    pub top_p: Option<f64>,
+    /// The penalty applied to the logits of the tokens already in the sequence, a penalty of one
+    /// disables it.
+    #[config(default = 1.0)]
+    pub repetition_penalty: f64,
+    /// The seed of the random number generator.
+    pub seed: Option<u64>,
+}
+
+impl SamplingConfig {
+    /// Configuration always selecting the most likely token.
+    pub fn greedy() -> Self {
+        Self::new().with_temperature(0.0)
+    }
+
+    /// Initialize a new [token sampler](Sampler).
+    pub fn init(&self) -> Sampler {
+        if let Some(top_k) = self.top_k {
+            assert!(top_k > 0, "Top-k should keep at least one token");
+        }
+        if let Some(top_p) = self.top_p {
+            assert!(
+                top_p > 0.0 && top_p <= 1.0,
+                "Top-p should be in the range (0, 1], got {top_p}"
+            );
+        }
+
+        let rng = match self.seed {
+            Some(seed) => StdRng::seed_from_u64(seed),
+            None => get_seeded_rng(),
+        };
+
+        Sampler {
+            config: self.clone(),
+            rng,
+        }
+    }
+}
+
+/// Select the next token from the logits of a model.
+#[derive(Debug)]
+pub struct Sampler {
+    config: SamplingConfig,
+    rng: StdRng,
+}
+
+impl Sampler {
+    /// Sample the next token of a sequence from the logits of the model.
+    ///
+    /// The previous tokens of the sequence are used by the repetition penalty.
+    pub fn sample(&mut self, logits: &[f32], previous: &[usize]) -> usize {
+        assert!(!logits.is_empty(), "Can't sample from empty logits");
+
+        let mut logits: Vec<f64> = logits.iter().map(|logit| *logit as f64).collect();
+        apply_repetition_penalty(&mut logits, previous, self.config.repetition_penalty);
+
+        if self.config.temperature <= 0.0 {
+            return argmax(&logits);
+        }
+
+        logits
+            .iter_mut()
+            .for_each(|logit| *logit /= self.config.temperature);
+
+        if let Some(top_k) = self.config.top_k {
+            apply_top_k(&mut logits, top_k);
+        }
+
+        let mut probs = softmax(&logits);
+
+        if let Some(top_p) = self.config.top_p {
+            apply_top_p(&mut probs, top_p);
+        }
+
+        self.sample_index(&probs)
+    }
+
+    fn sample_index(&mut self, probs: &[f64]) -> usize {
+        let total: f64 = probs.iter().sum();
+        let mut threshold = self.rng.gen::<f64>() * total;
+
+        for (index, prob) in probs.iter().enumerate() {
+            if *prob <= 0.0 {
+                continue;
+            }
+            if threshold < *prob {
+                return index;
+            }
+            threshold -= prob;
+        }
+
+        // Floating point errors can leave a tiny remainder, the last candidate is selected then.
+        probs.iter().rposition(|prob| *prob > 0.0).unwrap_or(0)
+    }
+}
+
+/// Configuration of the [generate](generate) function.
+#[derive(Config, Debug)]
+pub struct GenerationConfig {
+    /// The maximum number of tokens generated after the prompt.
+    pub max_new_tokens: usize,
+    /// The token ending a sequence.
+    pub eos_token: Option<usize>,
+    /// The sampling strategy.
+    #[config(default = "SamplingConfig::new()")]
+    pub sampling: SamplingConfig,
+}
+
+/// Generate the continuation of a batch of prompts, one token at a time.
+///
+/// Sequences ending with the [end of sequence token](GenerationConfig::eos_token) before the
+/// others are padded with it.
+///
+/// # Shapes
+///
+/// - prompt: `[batch_size, prompt_length]`
+/// - output: `[batch_size, prompt_length + num_generated]`
+pub fn generate<B, M>(
+    model: &M,
+    prompt: Tensor<B, 2, Int>,
+    state: &mut M::State,
+    config: &GenerationConfig,
+) -> Tensor<B, 2, Int>
+where
+    B: Backend,
+    M: NextTokenModel<B>,
+{
+    let device = prompt.device();
+    let [batch_size, _] = prompt.dims();
+    let mut sampler = config.sampling.init();
+    let mut sequences = token_sequences(&prompt);
+    let mut finished = vec![false; batch_size];
+    let mut tokens = prompt;
+
+    for _ in 0..config.max_new_tokens {
+        let logits = model.next_token_logits(tokens.clone(), state);
+        let [_, vocab_size] = logits.dims();
+        let logits = logits.into_data().convert::<f32>().value;
+
+        let mut next = Vec::with_capacity(batch_size);
+
+        for (batch, sequence) in sequences.iter_mut().enumerate() {
+            let token = match (finished[batch], config.eos_token) {
+                (true, Some(eos_token)) => eos_token,
+                _ => {
+                    let logits = &logits[batch * vocab_size..(batch + 1) * vocab_size];
+                    sampler.sample(logits, sequence)
+                }
+            };
+
+            finished[batch] = Some(token) == config.eos_token;
+            sequence.push(token);
+            next.push(token as i64);
+        }
+
+        let next = Data::new(next, Shape::new([batch_size, 1])).convert();
+        tokens = Tensor::cat(vec![tokens, Tensor::from_data(next, &device)], 1);
+
+        if finished.iter().all(|finished| *finished) {
+            break;
+        }
+    }
+
+    tokens
+}
+
+pub(crate) fn token_sequences<B: Backend>(tokens: &Tensor<B, 2, Int>) -> Vec<Vec<usize>> {
+    let [batch_size, seq_length] = tokens.dims();
+    let tokens = tokens.to_data().convert::<i64>().value;
+
+    (0..batch_size)
+        .map(|batch| {
+            tokens[batch * seq_length..(batch + 1) * seq_length]
+                .iter()
+                .map(|token| *token as usize)
+                .collect()
+        })
+        .collect()
+}
+
+fn apply_repetition_penalty(logits: &mut [f64], previous: &[usize], penalty: f64) {
+    if penalty == 1.0 {
+        return;
+    }
+
+    let mut penalized = vec![false; logits.len()];
+
+    for token in previous {
+        if *token >= logits.len() || penalized[*token] {
+            continue;
+        }
+        penalized[*token] = true;
+
+        // Dividing negative logits would make the token more likely.
+        let logit = &mut logits[*token];
+        *logit = match *logit > 0.0 {
+            true => *logit / penalty,
+            false => *logit * penalty,
+        };
+    }
+}
+
+fn apply_top_k(logits: &mut [f64], top_k: usize) {
+    if top_k >= logits.len() {
+        return;
+    }
+
+    let mut sorted = logits.to_vec();
+    sorted.sort_by(|a, b| b.total_cmp(a));
+    let min = sorted[top_k - 1];
+
+    // Ties with the k-th logit are kept.
+    logits
+        .iter_mut()
+        .filter(|logit| **logit < min)
+        .for_each(|logit| *logit = f64::NEG_INFINITY);
+}
+
+fn apply_top_p(probs: &mut [f64], top_p: f64) {
+    let mut indices: Vec<usize> = (0..probs.len()).collect();
+    indices.sort_by(|a, b| probs[*b].total_cmp(&probs[*a]));
+
+    let mut cumulative = 0.0;
+    let mut num_kept = indices.len();
+
+    for (position, index) in indices.iter().enumerate() {
+        cumulative += probs[*index];
+
+        if cumulative >= top_p {
+            num_kept = position + 1;
+            break;
+        }
+    }
+
+    for index in &indices[num_kept..] {
+        probs[*index] = 0.0;
+    }
+}
+
+fn softmax(logits: &[f64]) -> Vec<f64> {
+    let max = logits[argmax(logits)];
+    let exps: Vec<f64> = logits.iter().map(|logit| libm::exp(logit - max)).collect();
+    let sum: f64 = exps.iter().sum();
+
+    exps.into_iter().map(|exp| exp / sum).collect()
+}
+
+pub(crate) fn log_softmax(logits: &[f32]) -> Vec<f64> {
+    let logits: Vec<f64> = logits.iter().map(|logit| *logit as f64).collect();
+    let max = logits[argmax(&logits)];
+    let sum: f64 = logits.iter().map(|logit| libm::exp(logit - max)).sum();
+    let log_sum = max + libm::log(sum);
+
+    logits.into_iter().map(|logit| logit - log_sum).collect()
+}
+
+fn argmax(values: &[f64]) -> usize {
+    values
+        .iter()
+        .enumerate()
+        .fold(0, |best, (index, value)| match *value > values[best] {
+            true => index,
+            false => best,
+        })
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use crate::TestBackend;
+
+    pub(crate) const EOS: usize = 3;
+
+    /// Toy model where the probabilities of the next token only depend on the last token.
+    pub(crate) struct BigramModel {
+        probs: Vec<[f32; 4]>,
+    }
+
+    impl BigramModel {
+        pub(crate) fn new() -> Self {
+            Self {
+                probs: vec![
+                    [0.0, 0.45, 0.55, 0.0],
+                    [0.0, 0.1, 0.0, 0.9],
+                    [0.0, 0.25, 0.25, 0.5],
+                    [0.0, 0.0, 0.0, 1.0],
+                ],
+            }
+        }
+    }
+
+    impl<B: Backend> NextTokenModel<B> for BigramModel {
+        // The number of sequences, used to check that the state follows the batch.
+        type State = usize;
+
+        fn next_token_logits(
+            &self,
+            tokens: Tensor<B, 2, Int>,
+            state: &mut Self::State,
+        ) -> Tensor<B, 2> {
+            let [batch_size, _] = tokens.dims();
+            assert_eq!(batch_size, *state, "The state should follow the batch");
+
+            let logits: Vec<f32> = token_sequences(&tokens)
+                .into_iter()
+                .flat_map(|sequence| self.probs[*sequence.last().unwrap()])
+                .map(|prob| libm::logf(prob.max(1e-9)))
+                .collect();
+
+            Tensor::from_data(
+                Data::new(logits, Shape::new([batch_size, 4])).convert(),
+                &tokens.device(),
+            )
+        }
+
+        fn select_state(&self, state: &mut Self::State, indices: Tensor<B, 1, Int>) {
+            *state = indices.dims()[0];
+        }
+    }
+
+    fn prompt(batch_size: usize) -> Tensor<TestBackend, 2, Int> {
+        Tensor::zeros([batch_size, 1], &Default::default())
+    }
+
+    #[test]
+    fn greedy_generation_should_select_most_likely_tokens() {
+        let config = GenerationConfig::new(5)
+            .with_eos_token(Some(EOS))
+            .with_sampling(SamplingConfig::greedy());
+
+        let tokens = generate(&BigramModel::new(), prompt(2), &mut 2, &config);
+
+        assert_eq!(
+            tokens.into_data(),
+            Data::<i64, 2>::from([[0, 2, 3], [0, 2, 3]]).convert()
+        );
+    }
+
+    #[test]
+    fn generation_should_stop_after_max_new_tokens() {
+        let config = GenerationConfig::new(4).with_sampling(SamplingConfig::greedy());
+
+        let tokens = generate(&BigramModel::new(), prompt(1), &mut 1, &config);
+
+        assert_eq!(
+            tokens.into_data(),
+            Data::<i64, 2>::from([[0, 2, 3, 3, 3]]).convert()
+        );
+    }
+
+    #[test]
+    fn seeded_sampling_should_be_reproducible() {
+        let config = GenerationConfig::new(8)
+            .with_eos_token(Some(EOS))
+            .with_sampling(SamplingConfig::new().with_seed(Some(42)));
+        let model = BigramModel::new();
+
+        let tokens_1 = generate(&model, prompt(4), &mut 4, &config);
+        let tokens_2 = generate(&model, prompt(4), &mut 4, &config);
+
+        assert_eq!(tokens_1.into_data(), tokens_2.into_data());
+    }
+
+    #[test]
+    fn top_k_one_should_be_greedy() {
+        let mut sampler = SamplingConfig::new()
+            .with_top_k(Some(1))
+            .with_seed(Some(0))
+            .init();
+
+        for _ in 0..10 {
+            assert_eq!(sampler.sample(&[0.1, 2.0, 1.9, -1.0], &[]), 1);
+        }
+    }
+
+    #[test]
+    fn top_p_should_only_sample_from_the_nucleus() {
+        let mut sampler = SamplingConfig::new()
+            .with_top_p(Some(0.7))
+            .with_seed(Some(0))
+            .init();
+        let logits = [0.5f32, 0.3, 0.15, 0.05].map(libm::logf);
+
+        for _ in 0..100 {
+            assert!(sampler.sample(&logits, &[]) < 2);
+        }
+    }
+
+    #[test]
+    fn repetition_penalty_should_discourage_previous_tokens() {
+        let mut sampler = SamplingConfig::greedy().with_repetition_penalty(2.0).init();
+
+        assert_eq!(sampler.sample(&[2.0, 1.5, -1.0], &[]), 0);
+        assert_eq!(sampler.sample(&[2.0, 1.5, -1.0], &[0, 0]), 1);
+    }
+
+    #[test]
+    fn repetition_penalty_should_decrease_negative_logits() {
+        let mut logits = [-1.0, 2.0];
+
+        apply_repetition_penalty(&mut logits, &[0, 1], 2.0);
+
+        assert_eq!(logits, [-2.0, 1.0]);
+    }
+}
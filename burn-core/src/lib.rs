@@ -9,6 +9,10 @@ extern crate derive_new;
 /// Re-export serde for proc macros.
 pub use serde;
 
+pub use burn_tensor::backend::set_default_device;
+#[cfg(feature = "std")]
+pub use burn_tensor::backend::DeviceGuard;
+
 /// The configuration module.
 pub mod config;
 
@@ -24,6 +28,10 @@ pub mod optim;
 #[cfg(feature = "std")]
 pub mod lr_scheduler;
 
+/// Text generation module.
+#[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+pub mod generation;
+
 /// Gradient clipping module.
 pub mod grad_clipping;
 
@@ -33,6 +41,12 @@ pub mod module;
 /// Neural network module.
 pub mod nn;
 
+/// Pruning module.
+pub mod pruning;
+
+/// Quantization module.
+pub mod quantization;
+
 /// Module for the recorder.
 pub mod record;
 
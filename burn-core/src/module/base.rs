@@ -235,4 +235,13 @@ pub trait AutodiffModule<B: AutodiffBackend>: Module<B> + Send + Sync + core::fm
 
     /// Get the same module, but on the inner backend without auto-differentiation.
     fn valid(&self) -> Self::InnerModule;
+
+    /// Execute the function with the module without building the autodiff graph.
+    ///
+    /// Unlike [valid](AutodiffModule::valid), the module stays on the autodiff backend, so
+    /// the same forward functions can be used, but no graph node is created for the operations
+    /// executed inside the function.
+    fn inference<R, F: FnOnce(&Self) -> R>(&self, func: F) -> R {
+        B::no_grad(|| func(self))
+    }
 }
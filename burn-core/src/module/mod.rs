@@ -1,5 +1,9 @@
 mod base;
 mod param;
+mod placement;
+mod teacher;
 
 pub use base::*;
 pub use param::*;
+pub use placement::*;
+pub use teacher::*;
@@ -0,0 +1,92 @@
+use super::Module;
+use crate::tensor::backend::Backend;
+use alloc::vec::Vec;
+
+/// Spread the modules across the devices, for simple model sharding.
+///
+/// The modules are split into contiguous groups with a similar number of parameters, the first
+/// group being moved to the first device and so on. This is meant for stacks of modules, like the
+/// layers of a transformer, that don't fit on a single device: the activations only have to be
+/// moved to the device of the next module when it's on another device.
+///
+/// # Example
+///
+/// ```rust, ignore
+/// let layers = spread_across_devices(layers, &[CandleDevice::Cuda(0), CandleDevice::Cuda(1)]);
+///
+/// for layer in layers.iter() {
+///     let device = layer.devices()[0].clone();
+///     x = layer.forward(x.to_device(&device));
+/// }
+/// ```
+pub fn spread_across_devices<B, M>(modules: Vec<M>, devices: &[B::Device]) -> Vec<M>
+where
+    B: Backend,
+    M: Module<B>,
+{
+    assert!(!devices.is_empty(), "At least one device is required");
+
+    let sizes = modules
+        .iter()
+        .map(|module| module.num_params())
+        .collect::<Vec<_>>();
+    let placement = partition(&sizes, devices.len());
+
+    modules
+        .into_iter()
+        .zip(placement)
+        .map(|(module, index)| module.to_device(&devices[index]))
+        .collect()
+}
+
+/// Assign each item to one of the partitions, keeping the items in order and the total size of
+/// each partition close to the average.
+fn partition(sizes: &[usize], num_partitions: usize) -> Vec<usize> {
+    let total: usize = sizes.iter().sum();
+    let mut placement = Vec::with_capacity(sizes.len());
+    let mut cumulative = 0;
+
+    for size in sizes {
+        // The partition containing the middle of the item.
+        let middle = cumulative + size / 2;
+        let index = match total {
+            0 => 0,
+            _ => usize::min(middle * num_partitions / total, num_partitions - 1),
+        };
+
+        placement.push(index);
+        cumulative += size;
+    }
+
+    placement
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{nn::LinearConfig, TestBackend};
+
+    #[test]
+    fn should_partition_items_of_equal_sizes() {
+        assert_eq!(partition(&[10; 6], 3), [0, 0, 1, 1, 2, 2]);
+        assert_eq!(partition(&[10; 3], 4), [0, 2, 3]);
+    }
+
+    #[test]
+    fn should_partition_items_by_size() {
+        assert_eq!(partition(&[30, 5, 5, 10, 10], 2), [0, 1, 1, 1, 1]);
+        assert_eq!(partition(&[0, 0], 2), [0, 0]);
+    }
+
+    #[test]
+    fn should_keep_every_module() {
+        let device = Default::default();
+        let layers = (0..4)
+            .map(|_| LinearConfig::new(4, 4).init::<TestBackend>(&device))
+            .collect::<Vec<_>>();
+
+        let layers = spread_across_devices(layers, &[device, Default::default()]);
+
+        assert_eq!(layers.len(), 4);
+    }
+}
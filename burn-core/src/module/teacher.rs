@@ -0,0 +1,115 @@
+use super::{AutodiffModule, ConstantRecord, Devices, Module, ModuleMapper, ModuleVisitor};
+use crate::tensor::backend::{AutodiffBackend, Backend};
+
+/// Frozen model used as a teacher, e.g. to train a student model with
+/// [knowledge distillation](crate::nn::loss::DistillationLoss).
+///
+/// The parameters of the teacher aren't visited by the optimizers and aren't saved in the record
+/// of the module containing it, so a teacher can be a field of the student model without being
+/// trained or checkpointed with it. Its forward pass is executed without building the autodiff
+/// graph.
+///
+/// # Example
+///
+/// ```rust, ignore
+/// #[derive(Module, Debug)]
+/// struct Student<B: Backend> {
+///     model: Model<B>,
+///     teacher: Teacher<Model<B>>,
+/// }
+///
+/// let teacher_output = student.teacher.forward(|teacher| teacher.forward(input.clone()));
+/// ```
+#[derive(Clone, Debug)]
+pub struct Teacher<M> {
+    model: M,
+}
+
+impl<M> Teacher<M> {
+    /// Freeze the model to use it as a teacher.
+    pub fn new(model: M) -> Self {
+        Self { model }
+    }
+
+    /// The frozen model.
+    pub fn model(&self) -> &M {
+        &self.model
+    }
+
+    /// Execute the function with the frozen model, without building the autodiff graph.
+    pub fn forward<B, R, F>(&self, func: F) -> R
+    where
+        B: AutodiffBackend,
+        M: AutodiffModule<B>,
+        F: FnOnce(&M) -> R,
+    {
+        self.model.inference(func)
+    }
+}
+
+impl<B: Backend, M: Module<B>> Module<B> for Teacher<M> {
+    type Record = ConstantRecord;
+
+    fn visit<V: ModuleVisitor<B>>(&self, _visitor: &mut V) {}
+
+    fn map<MA: ModuleMapper<B>>(self, _mapper: &mut MA) -> Self {
+        self
+    }
+
+    fn load_record(self, _record: Self::Record) -> Self {
+        self
+    }
+
+    fn into_record(self) -> Self::Record {
+        ConstantRecord::new()
+    }
+
+    fn to_device(self, device: &B::Device) -> Self {
+        Self::new(self.model.to_device(device))
+    }
+
+    fn fork(self, device: &B::Device) -> Self {
+        Self::new(self.model.fork(device))
+    }
+
+    fn collect_devices(&self, devices: Devices<B>) -> Devices<B> {
+        self.model.collect_devices(devices)
+    }
+}
+
+impl<B: AutodiffBackend, M: AutodiffModule<B>> AutodiffModule<B> for Teacher<M> {
+    type InnerModule = Teacher<M::InnerModule>;
+
+    fn valid(&self) -> Self::InnerModule {
+        Teacher::new(self.model.valid())
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::nn::{Linear, LinearConfig};
+    use crate::TestAutodiffBackend;
+    use burn_tensor::Tensor;
+
+    #[test]
+    fn teacher_is_frozen() {
+        let device = Default::default();
+        let linear: Linear<TestAutodiffBackend> = LinearConfig::new(2, 2).init(&device);
+        let teacher = Teacher::new(linear.clone());
+        let input = Tensor::<TestAutodiffBackend, 2>::ones([1, 2], &device).require_grad();
+
+        let student = Tensor::<TestAutodiffBackend, 2>::ones([1, 2], &device).require_grad();
+
+        let output = teacher.forward(|model| model.forward(input.clone()));
+        assert!(!output.is_require_grad());
+
+        // The teacher output is combined with a tracked tensor, otherwise nothing is tracked.
+        let grads = output.add(student.clone()).sum().backward();
+
+        assert_eq!(teacher.num_params(), 0);
+        assert!(student.grad(&grads).is_some());
+        assert!(linear.weight.grad(&grads).is_none());
+        assert!(input.grad(&grads).is_none());
+    }
+}
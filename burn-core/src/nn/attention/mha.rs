@@ -6,7 +6,7 @@ use crate::{
     config::Config,
     module::Module,
     nn,
-    tensor::{activation, backend::Backend, Bool, Tensor},
+    tensor::{activation, backend::Backend, module, Bool, Int, Tensor},
 };
 use libm::sqrtf;
 
@@ -194,6 +194,41 @@ impl<B: Backend> MultiHeadAttention<B> {
         MhaOutput { weights, context }
     }
 
+    /// Applies the forward pass with the fused [attention](module::attention) of the backend,
+    /// which never materializes the attention weights and can use dedicated kernels, such as the
+    /// flash attention kernels of the candle backend.
+    ///
+    /// Only the context is returned. Masks, quiet softmax and dropout aren't supported by the fused
+    /// attention, so the regular [forward](Self::forward) pass is used when any of them is active.
+    ///
+    /// # Shapes
+    ///
+    /// - query: `[batch_size, seq_length_1, d_model]`
+    /// - key: `[batch_size, seq_length_2, d_model]`
+    /// - value: `[batch_size, seq_length_2, d_model]`
+    /// - output: `[batch_size, seq_length_1, d_model]`
+    pub fn forward_fused(&self, input: MhaInput<B>) -> Tensor<B, 3> {
+        let dropout = B::ad_enabled() && self.dropout.prob > 0.0;
+        let masked = input.mask_pad.is_some() || input.mask_attn.is_some();
+
+        if dropout || masked || self.quiet_softmax {
+            return self.forward(input).context;
+        }
+
+        let [batch_size, seq_length_1, d_model] = input.query.dims();
+
+        let query = self.attention_linear(input.query, &self.query);
+        let key = self.attention_linear(input.key, &self.key);
+        let value = self.attention_linear(input.value, &self.value);
+
+        let context = module::attention(query, key, value, 1.0 / sqrtf(self.d_k as f32));
+        let context = context
+            .swap_dims(1, 2)
+            .reshape([batch_size, seq_length_1, d_model]);
+
+        self.output.forward(context)
+    }
+
     /// Applies the forward pass using a cache.
     ///
     /// # Shapes
@@ -312,6 +347,16 @@ impl<B: Backend> MhaCache<B> {
             output: MhaLinearCache::Autoregressive(TensorCache::empty(), 1),
         }
     }
+
+    /// Select the cached sequences at the given indices of the batch dimension.
+    ///
+    /// This is used by beam search to follow the hypotheses kept after each step.
+    pub fn select_batch(&mut self, indices: Tensor<B, 1, Int>) {
+        self.query.select_batch(indices.clone());
+        self.key.select_batch(indices.clone());
+        self.value.select_batch(indices.clone());
+        self.output.select_batch(indices);
+    }
 }
 
 impl<B: Backend, const D: usize> MhaLinearCache<B, D> {
@@ -327,6 +372,13 @@ impl<B: Backend, const D: usize> MhaLinearCache<B, D> {
             MhaLinearCache::Full(cache) => cache.forward_full(tensor, func),
         }
     }
+
+    fn select_batch(&mut self, indices: Tensor<B, 1, Int>) {
+        match self {
+            MhaLinearCache::Autoregressive(cache, _) => cache.select_batch(indices),
+            MhaLinearCache::Full(cache) => cache.select_batch(indices),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -362,6 +414,37 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_fused_attention_same_context() {
+        let [batch_size, seq_length_1, seq_length_2, d_model, n_heads] = [3, 5, 7, 32, 4];
+        let device = Default::default();
+        let mha = MultiHeadAttentionConfig::new(d_model, n_heads).init::<TestBackend>(&device);
+        let input = MhaInput::new(
+            Tensor::random(
+                [batch_size, seq_length_1, d_model],
+                Distribution::Default,
+                &device,
+            ),
+            Tensor::random(
+                [batch_size, seq_length_2, d_model],
+                Distribution::Default,
+                &device,
+            ),
+            Tensor::random(
+                [batch_size, seq_length_2, d_model],
+                Distribution::Default,
+                &device,
+            ),
+        );
+
+        let context = mha.forward(input.clone()).context;
+        let context_fused = mha.forward_fused(input);
+
+        context
+            .into_data()
+            .assert_approx_eq(&context_fused.into_data(), 3);
+    }
+
     #[test]
     fn test_generic_mha_shapes() {
         let [batch_size, seq_length_1, seq_length_2, d_model, n_heads] = [7, 13, 15, 32, 4];
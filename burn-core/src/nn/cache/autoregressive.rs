@@ -2,7 +2,7 @@ use alloc::vec;
 
 use super::{CacheState, TensorCache};
 use crate::tensor::backend::Backend;
-use crate::tensor::Tensor;
+use crate::tensor::{Int, Tensor};
 
 impl<B: Backend, const D: usize> TensorCache<B, D> {
     pub(crate) fn forward_autoregressive<F>(
@@ -48,4 +48,15 @@ impl<B: Backend, const D: usize> TensorCache<B, D> {
         self.state = CacheState::Value(tensor_new.clone());
         tensor_new
     }
+
+    /// Select the cached sequences at the given indices of the batch dimension, which can be
+    /// used to reorder, drop or duplicate sequences.
+    pub(crate) fn select_batch(&mut self, indices: Tensor<B, 1, Int>) {
+        let mut tensor_old = CacheState::Empty;
+        core::mem::swap(&mut self.state, &mut tensor_old);
+
+        if let CacheState::Value(tensor_old) = tensor_old {
+            self.state = CacheState::Value(tensor_old.select(0, indices));
+        }
+    }
 }
@@ -5,8 +5,10 @@ use crate::module::Module;
 use crate::module::Param;
 use crate::nn::Initializer;
 use crate::nn::PaddingConfig2d;
+use crate::pruning::{kept_indices, select_param};
+use crate::quantization::{MinMaxObserver, QuantizationParams, QuantizationScheme};
 use crate::tensor::backend::Backend;
-use crate::tensor::Tensor;
+use crate::tensor::{Int, Tensor};
 use burn_tensor::module::conv2d;
 use burn_tensor::ops::ConvOptions;
 use libm::sqrt;
@@ -132,6 +134,198 @@ impl<B: Backend> Conv2d<B> {
             ConvOptions::new(self.stride, padding, self.dilation, self.groups),
         )
     }
+
+    /// Expand the parameters of the layer for each sample of a batch.
+    ///
+    /// The returned layer computes the same output as the original one, but since each sample
+    /// uses its own copy of the parameters, the gradients of the
+    /// [expanded parameters](ExpandedConv2d) are the per-sample gradients.
+    pub fn expand(&self, batch_size: usize) -> ExpandedConv2d<B> {
+        let weight = self
+            .weight
+            .val()
+            .unsqueeze::<5>()
+            .repeat(0, batch_size)
+            .detach()
+            .require_grad();
+        let bias = self.bias.as_ref().map(|bias| {
+            bias.val()
+                .unsqueeze::<2>()
+                .repeat(0, batch_size)
+                .detach()
+                .require_grad()
+        });
+
+        ExpandedConv2d {
+            weight,
+            bias,
+            stride: self.stride,
+            kernel_size: self.kernel_size,
+            dilation: self.dilation,
+            groups: self.groups,
+            padding: self.padding.clone(),
+        }
+    }
+
+    /// Remove the output channels not in `indices`, e.g. the ones pruned with
+    /// [structured pruning](crate::pruning::PruningMethod::L1Structured).
+    pub fn select_out_channels(&self, indices: &[usize]) -> Self {
+        assert_eq!(
+            self.groups, 1,
+            "Can't remove channels of a grouped convolution."
+        );
+        let indices = kept_indices(indices, &self.weight.device());
+
+        Self {
+            weight: select_param(&self.weight, 0, indices.clone()),
+            bias: self
+                .bias
+                .as_ref()
+                .map(|bias| select_param(bias, 0, indices)),
+            padding: self.padding.clone(),
+            ..*self
+        }
+    }
+
+    /// Remove the input channels not in `indices`, e.g. the outputs removed from the previous
+    /// layer with [select_out_channels](Conv2d::select_out_channels).
+    pub fn select_in_channels(&self, indices: &[usize]) -> Self {
+        assert_eq!(
+            self.groups, 1,
+            "Can't remove channels of a grouped convolution."
+        );
+        let indices = kept_indices(indices, &self.weight.device());
+
+        Self {
+            weight: select_param(&self.weight, 1, indices),
+            bias: self.bias.clone(),
+            padding: self.padding.clone(),
+            ..*self
+        }
+    }
+
+    /// Quantize the layer to int8, using the input range collected by the observer on
+    /// calibration data.
+    ///
+    /// The weights are quantized symmetrically, with a scale for each output channel when
+    /// `per_channel` is true.
+    pub fn quantize(&self, input: &MinMaxObserver<B>, per_channel: bool) -> QuantizedConv2d<B> {
+        let weight = self.weight.val().detach();
+        let scheme = match per_channel {
+            true => QuantizationScheme::PerChannel(0),
+            false => QuantizationScheme::PerTensor,
+        };
+        let weight_params = QuantizationParams::symmetric(&weight, &scheme);
+
+        QuantizedConv2d {
+            weight: Param::from(weight_params.quantize(weight)),
+            weight_params,
+            bias: self.bias.clone(),
+            input_params: input.params(),
+            stride: self.stride,
+            kernel_size: self.kernel_size,
+            dilation: self.dilation,
+            groups: self.groups,
+            padding: self.padding.clone(),
+        }
+    }
+}
+
+/// [2D convolution](Conv2d) layer with int8 weights and inputs, created with [Conv2d::quantize].
+///
+/// The quantized values are stored with the int element type of the backend, and the
+/// convolution is executed on the dequantized values, since backends don't expose int8 kernels.
+#[derive(Module, Debug)]
+pub struct QuantizedConv2d<B: Backend> {
+    /// Quantized weights of shape `[channels_out, channels_in / groups, kernel_size_1, kernel_size_2]`.
+    pub weight: Param<Tensor<B, 4, Int>>,
+    /// Quantization parameters of the weights.
+    pub weight_params: QuantizationParams<B>,
+    /// Bias of shape `[channels_out]`, kept in float.
+    pub bias: Option<Param<Tensor<B, 1>>>,
+    /// Quantization parameters of the inputs.
+    pub input_params: QuantizationParams<B>,
+    stride: [usize; 2],
+    kernel_size: [usize; 2],
+    dilation: [usize; 2],
+    groups: usize,
+    padding: PaddingConfig2d,
+}
+
+impl<B: Backend> QuantizedConv2d<B> {
+    /// Applies the forward pass on the input tensor.
+    ///
+    /// # Shapes
+    ///
+    /// - input: [batch_size, channels_in, height_in, width_in],
+    /// - output: [batch_size, channels_out, height_out, width_out],
+    pub fn forward(&self, input: Tensor<B, 4>) -> Tensor<B, 4> {
+        let [_batch_size, _channels_in, height_in, width_in] = input.dims();
+        let padding =
+            self.padding
+                .calculate_padding_2d(height_in, width_in, &self.kernel_size, &self.stride);
+        conv2d(
+            self.input_params.fake_quantize(input),
+            self.weight_params.dequantize(self.weight.val()),
+            self.bias.as_ref().map(|bias| bias.val()),
+            ConvOptions::new(self.stride, padding, self.dilation, self.groups),
+        )
+    }
+}
+
+/// [2D convolution](Conv2d) layer with its parameters expanded for each sample of a batch, used
+/// to compute per-sample gradients in a single backward pass.
+///
+/// The forward pass is executed as a single grouped convolution where each sample is a group.
+#[derive(Debug, Clone)]
+pub struct ExpandedConv2d<B: Backend> {
+    /// Weights of shape `[batch_size, channels_out, channels_in / groups, kernel_size_1, kernel_size_2]`.
+    pub weight: Tensor<B, 5>,
+    /// Bias of shape `[batch_size, channels_out]`.
+    pub bias: Option<Tensor<B, 2>>,
+    stride: [usize; 2],
+    kernel_size: [usize; 2],
+    dilation: [usize; 2],
+    groups: usize,
+    padding: PaddingConfig2d,
+}
+
+impl<B: Backend> ExpandedConv2d<B> {
+    /// Applies the forward pass on the input tensor.
+    ///
+    /// # Shapes
+    ///
+    /// - input: [batch_size, channels_in, height_in, width_in],
+    /// - output: [batch_size, channels_out, height_out, width_out],
+    pub fn forward(&self, input: Tensor<B, 4>) -> Tensor<B, 4> {
+        let [batch_size, channels_in, height_in, width_in] = input.dims();
+        let [_, channels_out, channels_per_group, kernel_1, kernel_2] = self.weight.dims();
+        let padding =
+            self.padding
+                .calculate_padding_2d(height_in, width_in, &self.kernel_size, &self.stride);
+
+        let output = conv2d(
+            input.reshape([1, batch_size * channels_in, height_in, width_in]),
+            self.weight.clone().reshape([
+                batch_size * channels_out,
+                channels_per_group,
+                kernel_1,
+                kernel_2,
+            ]),
+            self.bias
+                .as_ref()
+                .map(|bias| bias.clone().reshape([batch_size * channels_out])),
+            ConvOptions::new(
+                self.stride,
+                padding,
+                self.dilation,
+                self.groups * batch_size,
+            ),
+        );
+        let [_, _, height_out, width_out] = output.dims();
+
+        output.reshape([batch_size, channels_out, height_out, width_out])
+    }
 }
 
 #[cfg(test)]
@@ -20,7 +20,7 @@ pub struct DropoutConfig {
 /// The input is also scaled during training to `1 / (1 - prob_keep)`.
 #[derive(Module, Clone, Debug)]
 pub struct Dropout {
-    prob: f64,
+    pub(crate) prob: f64,
 }
 
 impl DropoutConfig {
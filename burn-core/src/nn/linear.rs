@@ -3,7 +3,9 @@ use crate as burn;
 use crate::config::Config;
 use crate::module::Module;
 use crate::module::Param;
-use crate::tensor::{backend::Backend, Tensor};
+use crate::pruning::{kept_indices, select_param};
+use crate::quantization::{Int4Weight, MinMaxObserver, QuantizationParams, QuantizationScheme};
+use crate::tensor::{backend::Backend, Int, Tensor};
 use libm::sqrt;
 
 use super::Initializer;
@@ -84,6 +86,210 @@ impl<B: Backend> Linear<B> {
             None => output,
         }
     }
+
+    /// Expand the parameters of the layer for each sample of a batch.
+    ///
+    /// The returned layer computes the same output as the original one, but since each sample
+    /// uses its own copy of the parameters, the gradients of the
+    /// [expanded parameters](ExpandedLinear) are the per-sample gradients.
+    pub fn expand(&self, batch_size: usize) -> ExpandedLinear<B> {
+        let weight = self
+            .weight
+            .val()
+            .unsqueeze::<3>()
+            .repeat(0, batch_size)
+            .detach()
+            .require_grad();
+        let bias = self.bias.as_ref().map(|bias| {
+            bias.val()
+                .unsqueeze::<2>()
+                .repeat(0, batch_size)
+                .detach()
+                .require_grad()
+        });
+
+        ExpandedLinear { weight, bias }
+    }
+
+    /// Remove the output features not in `indices`, e.g. the ones pruned with
+    /// [structured pruning](crate::pruning::PruningMethod::L1Structured).
+    pub fn select_outputs(&self, indices: &[usize]) -> Self {
+        let indices = kept_indices(indices, &self.weight.device());
+
+        Self {
+            weight: select_param(&self.weight, 1, indices.clone()),
+            bias: self
+                .bias
+                .as_ref()
+                .map(|bias| select_param(bias, 0, indices)),
+        }
+    }
+
+    /// Remove the input features not in `indices`, e.g. the outputs removed from the previous
+    /// layer with [select_outputs](Linear::select_outputs).
+    pub fn select_inputs(&self, indices: &[usize]) -> Self {
+        let indices = kept_indices(indices, &self.weight.device());
+
+        Self {
+            weight: select_param(&self.weight, 0, indices),
+            bias: self.bias.clone(),
+        }
+    }
+
+    /// Quantize the layer to int8, using the input range collected by the observer on
+    /// calibration data.
+    ///
+    /// The weights are quantized symmetrically, with a scale for each output feature when
+    /// `per_channel` is true.
+    pub fn quantize(&self, input: &MinMaxObserver<B>, per_channel: bool) -> QuantizedLinear<B> {
+        QuantizedLinear {
+            input_params: Some(input.params()),
+            ..self.quantize_weight(per_channel)
+        }
+    }
+
+    /// Quantize the weights of the layer to int8, the inputs being quantized dynamically with
+    /// the range of each batch, so no calibration data is required.
+    ///
+    /// The weights are quantized symmetrically, with a scale for each output feature when
+    /// `per_channel` is true.
+    pub fn quantize_dynamic(&self, per_channel: bool) -> QuantizedLinear<B> {
+        self.quantize_weight(per_channel)
+    }
+
+    /// Quantize the weights of the layer to 4 bits by blocks of `block_size` input features, the
+    /// inputs being kept in float, which reduces the memory used by large models.
+    pub fn quantize_int4(&self, block_size: usize) -> Int4Linear<B> {
+        Int4Linear {
+            weight: Int4Weight::quantize(self.weight.val(), block_size),
+            bias: self.bias.clone(),
+        }
+    }
+
+    fn quantize_weight(&self, per_channel: bool) -> QuantizedLinear<B> {
+        let weight = self.weight.val().detach();
+        let scheme = match per_channel {
+            true => QuantizationScheme::PerChannel(1),
+            false => QuantizationScheme::PerTensor,
+        };
+        let weight_params = QuantizationParams::symmetric(&weight, &scheme);
+
+        QuantizedLinear {
+            weight: Param::from(weight_params.quantize(weight)),
+            weight_params,
+            bias: self.bias.clone(),
+            input_params: None,
+        }
+    }
+}
+
+/// [Linear](Linear) layer with int8 weights and inputs, created with [Linear::quantize] or
+/// [Linear::quantize_dynamic].
+///
+/// The quantized values are stored with the int element type of the backend, and the matrix
+/// multiplication is executed on the dequantized values, since backends don't expose int8
+/// kernels. The output is the same as the one of an int8 kernel with float accumulation.
+#[derive(Module, Debug)]
+pub struct QuantizedLinear<B: Backend> {
+    /// Quantized weights of shape `[d_input, d_output]`.
+    pub weight: Param<Tensor<B, 2, Int>>,
+    /// Quantization parameters of the weights.
+    pub weight_params: QuantizationParams<B>,
+    /// Vector of size `d_output`, kept in float.
+    pub bias: Option<Param<Tensor<B, 1>>>,
+    /// Quantization parameters of the inputs, computed for each batch when `None`.
+    pub input_params: Option<QuantizationParams<B>>,
+}
+
+impl<B: Backend> QuantizedLinear<B> {
+    /// Applies the forward pass on the input tensor.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[..., any, d_input]`
+    /// - output: `[..., any, d_output]`
+    pub fn forward<const D: usize>(&self, input: Tensor<B, D>) -> Tensor<B, D> {
+        let input = match &self.input_params {
+            Some(params) => params.fake_quantize(input),
+            None => QuantizationParams::dynamic(&input).fake_quantize(input),
+        };
+        let weight = self.weight_params.dequantize(self.weight.val());
+        let output = input.matmul(weight.unsqueeze());
+
+        match &self.bias {
+            Some(bias) => output + bias.val().unsqueeze(),
+            None => output,
+        }
+    }
+}
+
+/// [Linear](Linear) layer with 4-bit weights, created with [Linear::quantize_int4].
+#[derive(Module, Debug)]
+pub struct Int4Linear<B: Backend> {
+    /// Quantized weights of shape `[d_input, d_output]`.
+    pub weight: Int4Weight<B>,
+    /// Vector of size `d_output`, kept in float.
+    pub bias: Option<Param<Tensor<B, 1>>>,
+}
+
+impl<B: Backend> Int4Linear<B> {
+    /// Applies the forward pass on the input tensor.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[..., any, d_input]`
+    /// - output: `[..., any, d_output]`
+    pub fn forward<const D: usize>(&self, input: Tensor<B, D>) -> Tensor<B, D> {
+        let mut dims = input.dims();
+        let d_input = dims[D - 1];
+        let num_rows = input.shape().num_elements() / d_input;
+
+        let output = self.weight.matmul(input.reshape([num_rows, d_input]));
+        dims[D - 1] = output.dims()[1];
+        let output = output.reshape(dims);
+
+        match &self.bias {
+            Some(bias) => output + bias.val().unsqueeze(),
+            None => output,
+        }
+    }
+}
+
+/// [Linear](Linear) layer with its parameters expanded for each sample of a batch, used to compute
+/// per-sample gradients in a single backward pass.
+#[derive(Debug, Clone)]
+pub struct ExpandedLinear<B: Backend> {
+    /// Weights of shape `[batch_size, d_input, d_output]`.
+    pub weight: Tensor<B, 3>,
+    /// Bias of shape `[batch_size, d_output]`.
+    pub bias: Option<Tensor<B, 2>>,
+}
+
+impl<B: Backend> ExpandedLinear<B> {
+    /// Applies the forward pass on the input tensor.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[batch_size, ..., d_input]`
+    /// - output: `[batch_size, ..., d_output]`
+    pub fn forward<const D: usize>(&self, input: Tensor<B, D>) -> Tensor<B, D> {
+        let dims = input.dims();
+        let [batch_size, d_input, d_output] = self.weight.dims();
+        let num_rows = dims[1..D - 1].iter().product::<usize>();
+
+        let output = input
+            .reshape([batch_size, num_rows, d_input])
+            .matmul(self.weight.clone());
+        let output = match &self.bias {
+            Some(bias) => output + bias.clone().unsqueeze_dim::<3>(1),
+            None => output,
+        };
+
+        let mut dims_output = dims;
+        dims_output[D - 1] = d_output;
+
+        output.reshape(dims_output)
+    }
 }
 
 #[cfg(test)]
@@ -93,6 +299,9 @@ mod tests {
     use burn_tensor::{Data, Shape};
     use libm::sqrt;
 
+    #[cfg(feature = "std")]
+    use crate::TestAutodiffBackend;
+
     #[test]
     fn initializer_default() {
         TestBackend::seed(0);
@@ -161,4 +370,141 @@ mod tests {
 
         assert_eq!(result.into_data(), expected_result.into_data());
     }
+
+    #[test]
+    fn quantized_linear_close_to_float() {
+        TestBackend::seed(0);
+
+        let device = Default::default();
+        let linear = LinearConfig::new(8, 4).init::<TestBackend>(&device);
+        let input = Tensor::<TestBackend, 2>::random(
+            [16, 8],
+            burn_tensor::Distribution::Uniform(-1.0, 1.0),
+            &device,
+        );
+
+        let mut observer = MinMaxObserver::new(QuantizationScheme::PerTensor);
+        observer.observe(&input);
+        let quantized = linear.quantize(&observer, true);
+
+        quantized.weight.to_data().assert_within_range(-128..128);
+        quantized
+            .forward(input.clone())
+            .into_data()
+            .assert_approx_eq(&linear.forward(input).into_data(), 1);
+    }
+
+    #[test]
+    fn select_outputs_and_inputs() {
+        let device = Default::default();
+        let linear = LinearConfig::new(3, 4).init::<TestBackend>(&device);
+        let input = Tensor::<TestBackend, 2>::from_floats([[1.0, 0.0, 2.0]], &device);
+
+        let selected = linear.select_outputs(&[0, 2]).select_inputs(&[0, 2]);
+        let output = selected.forward(Tensor::from_floats([[1.0, 2.0]], &device));
+
+        let expected = linear.forward(input).into_data().value;
+        assert_eq!(selected.weight.dims(), [2, 2]);
+        output
+            .into_data()
+            .assert_approx_eq(&Data::from([[expected[0], expected[2]]]), 3);
+    }
+
+    #[test]
+    fn int4_linear_close_to_float() {
+        TestBackend::seed(0);
+
+        let device = Default::default();
+        let linear = LinearConfig::new(32, 4).init::<TestBackend>(&device);
+        let input = Tensor::<TestBackend, 3>::random(
+            [2, 3, 32],
+            burn_tensor::Distribution::Uniform(-1.0, 1.0),
+            &device,
+        );
+
+        let quantized = linear.quantize_int4(16);
+
+        quantized
+            .forward(input.clone())
+            .into_data()
+            .assert_approx_eq(&linear.forward(input).into_data(), 1);
+    }
+
+    #[test]
+    fn dynamic_quantized_linear_close_to_float() {
+        TestBackend::seed(0);
+
+        let device = Default::default();
+        let linear = LinearConfig::new(8, 4).init::<TestBackend>(&device);
+        let input = Tensor::<TestBackend, 3>::random(
+            [2, 16, 8],
+            burn_tensor::Distribution::Uniform(-4.0, 4.0),
+            &device,
+        );
+
+        let quantized = linear.quantize_dynamic(false);
+
+        assert!(quantized.input_params.is_none());
+        quantized
+            .forward(input.clone())
+            .into_data()
+            .assert_approx_eq(&linear.forward(input).into_data(), 1);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    #[allow(clippy::single_range_in_vec_init)]
+    fn expanded_linear_per_sample_grads() {
+        TestBackend::seed(0);
+
+        let device = Default::default();
+        let linear = LinearConfig::new(3, 2).init::<TestAutodiffBackend>(&device);
+        let input = Tensor::<TestAutodiffBackend, 3>::random(
+            [4, 5, 3],
+            burn_tensor::Distribution::Default,
+            &device,
+        );
+
+        let expanded = linear.expand(4);
+        let output = expanded.forward(input.clone());
+        let output_expected = linear.forward(input.clone());
+        output
+            .to_data()
+            .assert_approx_eq(&output_expected.to_data(), 3);
+
+        let grads = output.sum().backward();
+        let weight_grads = expanded.weight.grad(&grads).unwrap();
+        let bias_grads = expanded.bias.as_ref().unwrap().grad(&grads).unwrap();
+        assert_eq!(weight_grads.shape(), Shape::new([4, 3, 2]));
+        assert_eq!(bias_grads.shape(), Shape::new([4, 2]));
+
+        // The gradients of each sample are computed independently.
+        for i in 0..4 {
+            let linear = linear.clone().fork(&device);
+            let sample = input.clone().slice([i..i + 1]);
+            let grads = linear.forward(sample).sum().backward();
+
+            weight_grads
+                .clone()
+                .slice([i..i + 1])
+                .squeeze::<2>(0)
+                .to_data()
+                .assert_approx_eq(&linear.weight.grad(&grads).unwrap().to_data(), 3);
+            bias_grads
+                .clone()
+                .slice([i..i + 1])
+                .squeeze::<1>(0)
+                .to_data()
+                .assert_approx_eq(
+                    &linear
+                        .bias
+                        .as_ref()
+                        .unwrap()
+                        .grad(&grads)
+                        .unwrap()
+                        .to_data(),
+                    3,
+                );
+        }
+    }
 }
@@ -0,0 +1,136 @@
+use crate as burn;
+
+use crate::{config::Config, module::Module};
+use burn_tensor::activation::{log_softmax, softmax};
+use burn_tensor::{backend::Backend, Int, Tensor};
+
+use super::{CrossEntropyLoss, CrossEntropyLossConfig};
+
+/// Configuration to create a [Distillation loss](DistillationLoss).
+#[derive(Config, Debug)]
+pub struct DistillationLossConfig {
+    /// Temperature used to soften the distributions of the student and the teacher.
+    #[config(default = 2.0)]
+    pub temperature: f32,
+    /// Weight of the distillation term, the cross-entropy with the hard labels being weighted
+    /// by `1 - alpha`.
+    #[config(default = 0.5)]
+    pub alpha: f32,
+}
+
+impl DistillationLossConfig {
+    /// Initialize [Distillation loss](DistillationLoss).
+    pub fn init<B: Backend>(&self, device: &B::Device) -> DistillationLoss<B> {
+        assert!(
+            self.temperature > 0.0,
+            "Temperature of distillation loss should be positive. Got {}",
+            self.temperature
+        );
+        assert!(
+            (0.0..=1.).contains(&self.alpha),
+            "Alpha of distillation loss should be in interval [0, 1]. Got {}",
+            self.alpha
+        );
+
+        DistillationLoss {
+            temperature: self.temperature,
+            alpha: self.alpha,
+            cross_entropy: CrossEntropyLossConfig::new().init(device),
+        }
+    }
+}
+
+/// Knowledge distillation loss, mixing the KL divergence between the softened distributions of
+/// the teacher and the student with the cross-entropy of the student with the hard labels:
+///
+/// `alpha * T² * KL(softmax(teacher / T) || softmax(student / T)) + (1 - alpha) * CE(student, targets)`
+///
+/// The distillation term is scaled by `T²` so that its gradients keep the same magnitude when
+/// the temperature changes.
+#[derive(Module, Debug)]
+pub struct DistillationLoss<B: Backend> {
+    temperature: f32,
+    alpha: f32,
+    cross_entropy: CrossEntropyLoss<B>,
+}
+
+impl<B: Backend> DistillationLoss<B> {
+    /// Compute the criterion on the logits of the student and of the teacher.
+    ///
+    /// The logits of the teacher are detached, so no gradient flows to the teacher.
+    ///
+    /// # Shapes
+    ///
+    /// - logits: `[batch_size, num_targets]`
+    /// - teacher_logits: `[batch_size, num_targets]`
+    /// - targets: `[batch_size]`
+    pub fn forward(
+        &self,
+        logits: Tensor<B, 2>,
+        teacher_logits: Tensor<B, 2>,
+        targets: Tensor<B, 1, Int>,
+    ) -> Tensor<B, 1> {
+        let soft = self.forward_soft(logits.clone(), teacher_logits);
+        let hard = self.cross_entropy.forward(logits, targets);
+
+        soft.mul_scalar(self.alpha)
+            .add(hard.mul_scalar(1.0 - self.alpha))
+    }
+
+    /// Compute only the distillation term, without the hard labels.
+    pub fn forward_soft(&self, logits: Tensor<B, 2>, teacher_logits: Tensor<B, 2>) -> Tensor<B, 1> {
+        let [batch_size, _] = logits.dims();
+        let log_probs = log_softmax(logits.div_scalar(self.temperature), 1);
+        let teacher_logits = teacher_logits.detach().div_scalar(self.temperature);
+        let teacher_probs = softmax(teacher_logits.clone(), 1);
+        let teacher_log_probs = log_softmax(teacher_logits, 1);
+
+        teacher_probs
+            .mul(teacher_log_probs.sub(log_probs))
+            .sum()
+            .div_scalar(batch_size as f32)
+            .mul_scalar(self.temperature * self.temperature)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+    use burn_tensor::Data;
+
+    #[test]
+    fn distillation_loss_is_zero_for_identical_distributions() {
+        let device = Default::default();
+        let loss = DistillationLossConfig::new()
+            .with_alpha(1.0)
+            .init::<TestBackend>(&device);
+        let logits = Tensor::<TestBackend, 2>::from_floats([[1.0, 2.0, 3.0]], &device);
+        let targets = Tensor::<TestBackend, 1, Int>::from_ints([0], &device);
+
+        let output = loss.forward(logits.clone(), logits, targets);
+
+        output.into_data().assert_approx_eq(&Data::from([0.0]), 5);
+    }
+
+    #[test]
+    fn distillation_loss_mixes_soft_and_hard_terms() {
+        let device = Default::default();
+        let loss = DistillationLossConfig::new()
+            .with_temperature(1.0)
+            .with_alpha(0.25)
+            .init::<TestBackend>(&device);
+        let logits = Tensor::<TestBackend, 2>::from_floats([[0.0, 0.0]], &device);
+        let teacher_logits = Tensor::<TestBackend, 2>::from_floats([[0.0, f32::ln(3.0)]], &device);
+        let targets = Tensor::<TestBackend, 1, Int>::from_ints([1], &device);
+
+        let output = loss.forward(logits, teacher_logits, targets);
+
+        // KL([0.25, 0.75] || [0.5, 0.5]) = 0.25 ln(0.5) + 0.75 ln(1.5) and CE = ln(2).
+        let kl = 0.25 * f32::ln(0.5) + 0.75 * f32::ln(1.5);
+        let expected = 0.25 * kl + 0.75 * f32::ln(2.0);
+        output
+            .into_data()
+            .assert_approx_eq(&Data::from([expected]), 4);
+    }
+}
@@ -1,9 +1,11 @@
 mod binary_cross_entropy;
 mod cross_entropy;
+mod distillation;
 mod mse;
 mod reduction;
 
 pub use binary_cross_entropy::*;
 pub use cross_entropy::*;
+pub use distillation::*;
 pub use mse::*;
 pub use reduction::*;
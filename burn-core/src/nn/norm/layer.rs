@@ -65,6 +65,62 @@ impl<B: Backend> LayerNorm<B> {
             .mul(self.gamma.val().unsqueeze())
             .add(self.beta.val().unsqueeze())
     }
+
+    /// Expand the parameters of the layer for each sample of a batch.
+    ///
+    /// The returned layer computes the same output as the original one, but since each sample
+    /// uses its own copy of the parameters, the gradients of the
+    /// [expanded parameters](ExpandedLayerNorm) are the per-sample gradients.
+    pub fn expand(&self, batch_size: usize) -> ExpandedLayerNorm<B> {
+        let expand = |param: &Param<Tensor<B, 1>>| {
+            param
+                .val()
+                .unsqueeze::<2>()
+                .repeat(0, batch_size)
+                .detach()
+                .require_grad()
+        };
+
+        ExpandedLayerNorm {
+            gamma: expand(&self.gamma),
+            beta: expand(&self.beta),
+            epsilon: self.epsilon,
+        }
+    }
+}
+
+/// [Layer Normalization](LayerNorm) with its parameters expanded for each sample of a batch,
+/// used to compute per-sample gradients in a single backward pass.
+#[derive(Debug, Clone)]
+pub struct ExpandedLayerNorm<B: Backend> {
+    /// Scale of shape `[batch_size, d_model]`.
+    pub gamma: Tensor<B, 2>,
+    /// Offset of shape `[batch_size, d_model]`.
+    pub beta: Tensor<B, 2>,
+    epsilon: f64,
+}
+
+impl<B: Backend> ExpandedLayerNorm<B> {
+    /// Applies the forward pass on the input tensor.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[batch_size, ..., d_model]`
+    /// - output: `[batch_size, ..., d_model]`
+    pub fn forward<const D: usize>(&self, input: Tensor<B, D>) -> Tensor<B, D> {
+        let [batch_size, d_model] = self.gamma.dims();
+        let mut shape = [1; D];
+        shape[0] = batch_size;
+        shape[D - 1] = d_model;
+
+        let (var, mean) = input.clone().var_mean_bias(D - 1);
+
+        let input_normalized = input.sub(mean).div(var.sqrt().add_scalar(self.epsilon));
+
+        input_normalized
+            .mul(self.gamma.clone().reshape(shape))
+            .add(self.beta.clone().reshape(shape))
+    }
 }
 
 #[cfg(test)]
@@ -138,4 +194,32 @@ mod tests {
             .to_data()
             .assert_approx_eq(&Data::zeros(tensor_2_grad.shape()), 3);
     }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn expanded_layer_norm_per_sample_grads() {
+        let device = Default::default();
+        let module = LayerNormConfig::new(2).init::<TestAutodiffBackend>(&device);
+        let input = Tensor::<TestAutodiffBackend, 2>::from_data(
+            Data::from([[0.0, 1.0], [4.0, 3.0]]),
+            &device,
+        );
+
+        let expanded = module.expand(2);
+        let output = expanded.forward(input.clone());
+        output
+            .to_data()
+            .assert_approx_eq(&module.forward(input).to_data(), 3);
+
+        let grads = output.mul_scalar(2.0).sum().backward();
+        let gamma_grad = expanded.gamma.grad(&grads).unwrap();
+        let beta_grad = expanded.beta.grad(&grads).unwrap();
+
+        gamma_grad
+            .to_data()
+            .assert_approx_eq(&Data::from([[-2.0, 2.0], [2.0, -2.0]]), 3);
+        beta_grad
+            .to_data()
+            .assert_approx_eq(&Data::from([[2.0, 2.0], [2.0, 2.0]]), 3);
+    }
 }
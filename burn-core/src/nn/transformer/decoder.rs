@@ -1,5 +1,5 @@
 use alloc::vec::Vec;
-use burn_tensor::Bool;
+use burn_tensor::{Bool, Int};
 
 use crate::{
     self as burn,
@@ -170,6 +170,15 @@ impl<B: Backend> TransformerDecoderLayerAutoregressiveCache<B> {
             norm_3: TensorCache::empty(),
         }
     }
+
+    fn select_batch(&mut self, indices: Tensor<B, 1, Int>) {
+        self.cross_attn.select_batch(indices.clone());
+        self.self_attn.select_batch(indices.clone());
+        self.pwff.select_batch(indices.clone());
+        self.norm_1.select_batch(indices.clone());
+        self.norm_2.select_batch(indices.clone());
+        self.norm_3.select_batch(indices);
+    }
 }
 
 /// Autoregressive cache for the [Transformer Decoder](TransformerDecoder) layer.
@@ -187,6 +196,16 @@ impl<B: Backend> TransformerDecoderAutoregressiveCache<B> {
                 .collect(),
         }
     }
+
+    /// Select the cached sequences at the given indices of the batch dimension.
+    ///
+    /// This is used by beam search to follow the hypotheses kept after each step, indices can be
+    /// repeated to expand a single sequence into multiple beams.
+    pub fn select_batch(&mut self, indices: Tensor<B, 1, Int>) {
+        for layer in self.layers.iter_mut() {
+            layer.select_batch(indices.clone());
+        }
+    }
 }
 
 impl<B: Backend> TransformerDecoderLayer<B> {
@@ -1,5 +1,5 @@
 use alloc::vec::Vec;
-use burn_tensor::Bool;
+use burn_tensor::{Bool, Int};
 
 use crate::{
     self as burn,
@@ -356,6 +356,13 @@ impl<B: Backend> TransformerEncoderLayerAutoregressiveCache<B> {
             norm_2: TensorCache::empty(),
         }
     }
+
+    fn select_batch(&mut self, indices: Tensor<B, 1, Int>) {
+        self.mha.select_batch(indices.clone());
+        self.pwff.select_batch(indices.clone());
+        self.norm_1.select_batch(indices.clone());
+        self.norm_2.select_batch(indices);
+    }
 }
 
 /// Autoregressive cache for the [Transformer Encoder](TransformerEncoder) layer.
@@ -373,6 +380,16 @@ impl<B: Backend> TransformerEncoderAutoregressiveCache<B> {
                 .collect(),
         }
     }
+
+    /// Select the cached sequences at the given indices of the batch dimension.
+    ///
+    /// This is used by beam search to follow the hypotheses kept after each step, indices can be
+    /// repeated to expand a single sequence into multiple beams.
+    pub fn select_batch(&mut self, indices: Tensor<B, 1, Int>) {
+        for layer in self.layers.iter_mut() {
+            layer.select_batch(indices.clone());
+        }
+    }
 }
 
 #[cfg(test)]
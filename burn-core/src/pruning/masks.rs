@@ -0,0 +1,328 @@
+use crate as burn;
+
+use crate::config::Config;
+use crate::module::{Module, ModuleMapper, ModuleVisitor, Param, ParamId};
+use crate::quantization::reduce_channels;
+use crate::tensor::{backend::Backend, container::TensorContainer, Data, Int, Shape, Tensor};
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// Criterion used to select the weights to prune.
+#[derive(Config, Debug, PartialEq)]
+pub enum PruningMethod {
+    /// Prune the individual weights with the smallest magnitude.
+    Magnitude,
+    /// Prune the groups of channels with the smallest L1 norm, e.g. the output channels of a
+    /// convolution, or the heads of an attention layer when the group size is the size of a head.
+    L1Structured {
+        /// Axis of the channels.
+        axis: usize,
+        /// Number of consecutive channels pruned together.
+        group_size: usize,
+    },
+}
+
+/// Configuration to compute [pruning masks](PruningMasks).
+#[derive(Config, Debug)]
+pub struct PruningConfig {
+    /// Fraction of the weights, or of the groups of channels for structured pruning, to prune
+    /// in each parameter.
+    pub sparsity: f64,
+    /// Criterion used to select the weights to prune.
+    #[config(default = "PruningMethod::Magnitude")]
+    pub method: PruningMethod,
+}
+
+/// Masks of the pruned weights of a module, zero for the pruned weights and one for the others.
+///
+/// Only the parameters with at least two dimensions are pruned, the biases and the
+/// normalization parameters are left untouched. Applying the masks zeroes the pruned weights,
+/// so the forward pass of the module uses the pruned weights; the masks should be applied again
+/// after each optimization step when fine-tuning, so the pruned weights stay zero.
+///
+/// # Example
+///
+/// ```rust, ignore
+/// let masks = PruningMasks::compute(&model, &PruningConfig::new(0.5));
+/// let model = masks.apply(model);
+/// ```
+#[derive(Debug, Default)]
+pub struct PruningMasks<B: Backend> {
+    masks: TensorContainer<ParamId>,
+    phantom: core::marker::PhantomData<B>,
+}
+
+impl<B: Backend> PruningMasks<B> {
+    /// Create an empty set of masks, which prunes nothing.
+    pub fn new() -> Self {
+        Self {
+            masks: TensorContainer::new(),
+            phantom: core::marker::PhantomData,
+        }
+    }
+
+    /// Compute the masks of all the parameters of the module.
+    pub fn compute<M: Module<B>>(module: &M, config: &PruningConfig) -> Self {
+        Self::new().with_module(module, config)
+    }
+
+    /// Add the masks of the parameters of the module, replacing the existing masks of the same
+    /// parameters.
+    ///
+    /// This can be used to prune different parts of a model with different configurations,
+    /// e.g. the output channels of the convolutions and the heads of the attention layers.
+    pub fn with_module<M: Module<B>>(mut self, module: &M, config: &PruningConfig) -> Self {
+        let mut visitor = PruningMasksVisitor {
+            masks: &mut self.masks,
+            config,
+            phantom: core::marker::PhantomData,
+        };
+        module.visit(&mut visitor);
+
+        self
+    }
+
+    /// Zero the pruned weights of the module.
+    pub fn apply<M: Module<B>>(&self, module: M) -> M {
+        let mut mapper = PruningMasksMapper::<B> {
+            masks: &self.masks,
+            phantom: core::marker::PhantomData,
+        };
+
+        module.map(&mut mapper)
+    }
+
+    /// Get the mask of the parameter.
+    pub fn get<const D: usize>(&self, id: &ParamId) -> Option<Tensor<B, D>> {
+        self.masks.get::<B, D>(id)
+    }
+
+    /// Indices of the channels along the axis with at least one weight not pruned, which can be
+    /// used to physically remove the pruned channels of a layer, e.g. with
+    /// [Linear::select_outputs](crate::nn::Linear::select_outputs).
+    pub fn kept_channels<const D: usize>(&self, id: &ParamId, axis: usize) -> Option<Vec<usize>> {
+        let mask = self.get::<D>(id)?;
+        let kept = reduce_channels(mask, axis, |tensor| tensor.sum_dim(0))
+            .into_data()
+            .convert::<f32>()
+            .value
+            .into_iter()
+            .enumerate()
+            .filter(|(_, value)| *value > 0.0)
+            .map(|(index, _)| index)
+            .collect();
+
+        Some(kept)
+    }
+
+    /// The number of masks.
+    pub fn len(&self) -> usize {
+        self.masks.len()
+    }
+
+    /// If no parameter is masked.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+struct PruningMasksVisitor<'a, B: Backend> {
+    masks: &'a mut TensorContainer<ParamId>,
+    config: &'a PruningConfig,
+    phantom: core::marker::PhantomData<B>,
+}
+
+impl<'a, B: Backend> ModuleVisitor<B> for PruningMasksVisitor<'a, B> {
+    fn visit_float<const D: usize>(&mut self, id: &ParamId, tensor: &Tensor<B, D>) {
+        if D < 2 {
+            return;
+        }
+
+        let mask = match &self.config.method {
+            PruningMethod::Magnitude => magnitude_mask(tensor, self.config.sparsity),
+            PruningMethod::L1Structured { axis, group_size } => {
+                structured_mask(tensor, *axis, *group_size, self.config.sparsity)
+            }
+        };
+
+        self.masks.register(id.clone(), mask);
+    }
+}
+
+struct PruningMasksMapper<'a, B: Backend> {
+    masks: &'a TensorContainer<ParamId>,
+    phantom: core::marker::PhantomData<B>,
+}
+
+impl<'a, B: Backend> ModuleMapper<B> for PruningMasksMapper<'a, B> {
+    fn map_float<const D: usize>(&mut self, id: &ParamId, tensor: Tensor<B, D>) -> Tensor<B, D> {
+        match self.masks.get::<B, D>(id) {
+            Some(mask) => {
+                let require_grad = tensor.is_require_grad();
+                let device = tensor.device();
+                let tensor = tensor.mul(mask.to_device(&device)).detach();
+
+                tensor.set_require_grad(require_grad)
+            }
+            None => tensor,
+        }
+    }
+}
+
+fn magnitude_mask<B: Backend, const D: usize>(
+    tensor: &Tensor<B, D>,
+    sparsity: f64,
+) -> Tensor<B, D> {
+    let shape = tensor.shape();
+    let values = tensor
+        .clone()
+        .detach()
+        .abs()
+        .into_data()
+        .convert::<f32>()
+        .value;
+    let kept = kept_smallest_pruned(&values, sparsity);
+
+    Tensor::from_data(Data::new(kept, shape).convert(), &tensor.device())
+}
+
+fn structured_mask<B: Backend, const D: usize>(
+    tensor: &Tensor<B, D>,
+    axis: usize,
+    group_size: usize,
+    sparsity: f64,
+) -> Tensor<B, D> {
+    let channels = tensor.dims()[axis];
+    assert!(
+        channels % group_size == 0,
+        "The number of channels {channels} must be a multiple of the group size {group_size}."
+    );
+
+    let norms = reduce_channels(tensor.clone().detach().abs(), axis, |tensor| {
+        tensor.sum_dim(0)
+    })
+    .reshape([channels / group_size, group_size])
+    .sum_dim(1)
+    .into_data()
+    .convert::<f32>()
+    .value;
+    let kept = kept_smallest_pruned(&norms, sparsity)
+        .into_iter()
+        .flat_map(|value| vec![value; group_size])
+        .collect::<Vec<_>>();
+
+    let mut shape = [1; D];
+    shape[axis] = channels;
+    let mask = Tensor::<B, 1>::from_data(Data::from(kept.as_slice()).convert(), &tensor.device())
+        .reshape(shape);
+
+    mask.mul(tensor.ones_like())
+}
+
+/// Create a tensor of indices to select the channels kept by a pruned layer.
+pub(crate) fn kept_indices<B: Backend>(indices: &[usize], device: &B::Device) -> Tensor<B, 1, Int> {
+    let indices = indices
+        .iter()
+        .map(|index| *index as i64)
+        .collect::<Vec<_>>();
+    let shape = Shape::new([indices.len()]);
+
+    Tensor::from_data(Data::new(indices, shape).convert(), device)
+}
+
+/// Select the channels kept by a pruned layer in a parameter, creating a new parameter.
+pub(crate) fn select_param<B: Backend, const D: usize>(
+    param: &Param<Tensor<B, D>>,
+    dim: usize,
+    indices: Tensor<B, 1, Int>,
+) -> Param<Tensor<B, D>> {
+    let require_grad = param.is_require_grad();
+    let tensor = param.val().select(dim, indices).detach();
+
+    Param::from(tensor.set_require_grad(require_grad))
+}
+
+/// Mask keeping all the values except the `sparsity` fraction of smallest ones.
+fn kept_smallest_pruned(values: &[f32], sparsity: f64) -> Vec<f32> {
+    let num_pruned = (values.len() as f64 * sparsity) as usize;
+    let mut indices = (0..values.len()).collect::<Vec<_>>();
+    indices.sort_by(|a, b| values[*a].total_cmp(&values[*b]));
+
+    let mut kept = vec![1.0; values.len()];
+    for index in indices.into_iter().take(num_pruned) {
+        kept[index] = 0.0;
+    }
+
+    kept
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nn::LinearConfig;
+    use crate::TestBackend;
+
+    #[test]
+    fn magnitude_pruning_zeroes_smallest_weights() {
+        let device = Default::default();
+        let tensor =
+            Tensor::<TestBackend, 2>::from_floats([[0.1, -4.0], [-0.2, 3.0], [1.0, 0.3]], &device);
+
+        let mask = magnitude_mask(&tensor, 0.5);
+
+        assert_eq!(
+            mask.into_data(),
+            Data::from([[0.0, 1.0], [0.0, 1.0], [1.0, 0.0]])
+        );
+    }
+
+    #[test]
+    fn structured_pruning_zeroes_groups_of_channels() {
+        let device = Default::default();
+        let tensor = Tensor::<TestBackend, 2>::from_floats(
+            [[1.0, 1.0, 0.1, 0.1], [-1.0, 1.0, 0.2, -0.1]],
+            &device,
+        );
+
+        let mask = structured_mask(&tensor, 1, 2, 0.5);
+
+        assert_eq!(
+            mask.into_data(),
+            Data::from([[1.0, 1.0, 0.0, 0.0], [1.0, 1.0, 0.0, 0.0]])
+        );
+    }
+
+    #[test]
+    fn apply_masks_skips_biases() {
+        let device = Default::default();
+        let linear = LinearConfig::new(4, 6).init::<TestBackend>(&device);
+        let config = PruningConfig::new(0.5).with_method(PruningMethod::L1Structured {
+            axis: 1,
+            group_size: 1,
+        });
+
+        let masks = PruningMasks::compute(&linear, &config);
+        let pruned = masks.apply(linear.clone());
+
+        assert_eq!(masks.len(), 1);
+        assert_eq!(
+            masks
+                .kept_channels::<2>(&linear.weight.id, 1)
+                .unwrap()
+                .len(),
+            3
+        );
+        assert_eq!(
+            pruned.bias.unwrap().val().into_data(),
+            linear.bias.unwrap().val().into_data()
+        );
+        let zeros = pruned
+            .weight
+            .val()
+            .equal_elem(0.0)
+            .int()
+            .sum()
+            .into_scalar();
+        assert_eq!(zeros, 12);
+    }
+}
@@ -0,0 +1,3 @@
+mod masks;
+
+pub use masks::*;
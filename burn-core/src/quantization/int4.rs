@@ -0,0 +1,165 @@
+use crate as burn;
+
+use crate::module::{Module, Param};
+use crate::tensor::{backend::Backend, Int, Tensor};
+use burn_tensor::module::{int4_dequantize, int4_matmul};
+use burn_tensor::ops::quantization::INT4_PER_INT;
+
+use super::round;
+
+/// Largest 4-bit value.
+const INT4_MAX: i32 = 15;
+
+/// Weight matrix quantized to 4 bits by blocks of rows, each block having its own scale and
+/// zero point per column.
+///
+/// The 4-bit values are [packed](burn_tensor::ops::ModuleOps::int4_dequantize) 8 per int, so
+/// backends using 32 bits ints store the weights with 8 times less memory than with `f32`.
+#[derive(Module, Debug)]
+pub struct Int4Weight<B: Backend> {
+    /// Packed values of shape `[d_input / 8, d_output]`.
+    pub weight: Param<Tensor<B, 2, Int>>,
+    /// Scales of shape `[d_input / block_size, d_output]`.
+    pub scales: Param<Tensor<B, 2>>,
+    /// Zero points of shape `[d_input / block_size, d_output]`.
+    pub zero_points: Param<Tensor<B, 2>>,
+    /// Number of rows sharing the same scale and zero point.
+    pub block_size: usize,
+}
+
+impl<B: Backend> Int4Weight<B> {
+    /// Quantize the weight matrix of shape `[d_input, d_output]`, the range of each block of
+    /// `block_size` rows being mapped to the 16 values of 4 bits.
+    ///
+    /// # Panics
+    ///
+    /// If `d_input` isn't a multiple of both the block size and 8.
+    pub fn quantize(weight: Tensor<B, 2>, block_size: usize) -> Self {
+        let [d_input, d_output] = weight.dims();
+        assert!(
+            d_input % block_size == 0 && d_input % INT4_PER_INT == 0,
+            "The input size {d_input} must be a multiple of the block size {block_size} and of \
+             {INT4_PER_INT}."
+        );
+
+        let num_blocks = d_input / block_size;
+        let blocks = weight.detach().reshape([num_blocks, block_size, d_output]);
+        let min = blocks.clone().min_dim(1);
+        let max = blocks.clone().max_dim(1);
+        let scales = max
+            .sub(min.clone())
+            .div_scalar(INT4_MAX as f32)
+            .clamp_min(f32::EPSILON);
+        let zero_points = min.div(scales.clone()).neg();
+
+        let values = round(blocks.div(scales.clone()).add(zero_points.clone()))
+            .clamp(0, INT4_MAX)
+            .reshape([d_input / INT4_PER_INT, INT4_PER_INT, d_output]);
+
+        Self {
+            weight: Param::from(pack(values)),
+            scales: Param::from(scales.reshape([num_blocks, d_output])),
+            zero_points: Param::from(zero_points.reshape([num_blocks, d_output])),
+            block_size,
+        }
+    }
+
+    /// Convert the weights back to a float matrix of shape `[d_input, d_output]`.
+    pub fn dequantize(&self) -> Tensor<B, 2> {
+        int4_dequantize(
+            self.weight.val(),
+            self.scales.val(),
+            self.zero_points.val(),
+            self.block_size,
+        )
+    }
+
+    /// Multiply the input of shape `[batch_size, d_input]` by the weights, using the fused
+    /// kernel of the backend when available.
+    pub fn matmul(&self, lhs: Tensor<B, 2>) -> Tensor<B, 2> {
+        int4_matmul(
+            lhs,
+            self.weight.val(),
+            self.scales.val(),
+            self.zero_points.val(),
+            self.block_size,
+        )
+    }
+}
+
+/// Pack the values of shape `[d_input / 8, 8, d_output]` in a tensor of shape
+/// `[d_input / 8, d_output]`, using a two's complement 32 bits representation.
+fn pack<B: Backend>(values: Tensor<B, 3, Int>) -> Tensor<B, 2, Int> {
+    let [num_packed, _, d_output] = values.dims();
+
+    let packed = (0..INT4_PER_INT)
+        .map(|j| {
+            let value = values.clone().slice([0..num_packed, j..j + 1, 0..d_output]);
+
+            match j == INT4_PER_INT - 1 {
+                // The last value holds the sign bit.
+                true => {
+                    let signed = value.clone().sub_scalar(INT4_MAX + 1);
+                    value
+                        .clone()
+                        .mask_where(value.greater_equal_elem(8), signed)
+                        .mul_scalar(1 << (4 * j))
+                }
+                false => value.mul_scalar(1 << (4 * j)),
+            }
+        })
+        .reduce(|acc, value| acc.add(value))
+        .unwrap();
+
+    packed.reshape([num_packed, d_output])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+    use burn_tensor::{Data, Distribution};
+
+    #[test]
+    fn pack_values_with_sign_bit() {
+        let device = Default::default();
+        let values = Tensor::<TestBackend, 3, Int>::from_ints(
+            [[
+                [0, 15],
+                [1, 15],
+                [2, 15],
+                [3, 15],
+                [4, 15],
+                [5, 15],
+                [6, 15],
+                [7, 15],
+            ]],
+            &device,
+        );
+
+        let packed = pack(values);
+
+        assert_eq!(
+            packed.into_data(),
+            Data::<i32, 2>::from([[0x76543210, -1]]).convert()
+        );
+    }
+
+    #[test]
+    fn quantize_dequantize_round_trip() {
+        TestBackend::seed(0);
+
+        let device = Default::default();
+        let weight = Tensor::<TestBackend, 2>::random([32, 4], Distribution::Default, &device);
+
+        let quantized = Int4Weight::quantize(weight.clone(), 16);
+
+        assert_eq!(quantized.weight.dims(), [4, 4]);
+        assert_eq!(quantized.scales.dims(), [2, 4]);
+        // The error is at most half a quantization step, the range being at most 1.
+        quantized
+            .dequantize()
+            .into_data()
+            .assert_approx_eq_diff(&weight.into_data(), 0.5 / INT4_MAX as f64 + 1e-4);
+    }
+}
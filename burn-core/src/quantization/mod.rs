@@ -0,0 +1,7 @@
+mod int4;
+mod observer;
+mod params;
+
+pub use int4::*;
+pub use observer::*;
+pub use params::*;
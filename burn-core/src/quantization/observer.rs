@@ -0,0 +1,74 @@
+use crate::tensor::{backend::Backend, Tensor};
+
+use super::{reduce_channels, QuantizationParams, QuantizationScheme};
+
+/// Observer collecting the range of the values of a tensor, e.g. the activations of a layer on
+/// calibration data, to compute [static quantization parameters](QuantizationParams).
+///
+/// # Example
+///
+/// ```rust, ignore
+/// let mut observer = MinMaxObserver::new(QuantizationScheme::PerTensor);
+/// for batch in calibration_data {
+///     observer.observe(&batch.inputs);
+/// }
+/// let quantized = linear.quantize(&observer, true);
+/// ```
+#[derive(Clone, Debug)]
+pub struct MinMaxObserver<B: Backend> {
+    scheme: QuantizationScheme,
+    min: Option<Tensor<B, 1>>,
+    max: Option<Tensor<B, 1>>,
+}
+
+impl<B: Backend> MinMaxObserver<B> {
+    /// Create a new observer collecting the range with the given granularity.
+    pub fn new(scheme: QuantizationScheme) -> Self {
+        Self {
+            scheme,
+            min: None,
+            max: None,
+        }
+    }
+
+    /// Update the observed range with the values of the tensor.
+    pub fn observe<const D: usize>(&mut self, tensor: &Tensor<B, D>) {
+        let tensor = tensor.clone().detach();
+        let (min, max) = match &self.scheme {
+            QuantizationScheme::PerTensor => (tensor.clone().min(), tensor.max()),
+            QuantizationScheme::PerChannel(axis) => (
+                reduce_channels(tensor.clone(), *axis, |tensor| tensor.min_dim(0)),
+                reduce_channels(tensor, *axis, |tensor| tensor.max_dim(0)),
+            ),
+        };
+
+        self.min = Some(match self.min.take() {
+            Some(current) => current.clone().mask_where(min.clone().lower(current), min),
+            None => min,
+        });
+        self.max = Some(match self.max.take() {
+            Some(current) => current
+                .clone()
+                .mask_where(max.clone().greater(current), max),
+            None => max,
+        });
+    }
+
+    /// Compute the quantization parameters covering the observed range.
+    ///
+    /// # Panics
+    ///
+    /// If no tensor was observed.
+    pub fn params(&self) -> QuantizationParams<B> {
+        let (min, max) = match (&self.min, &self.max) {
+            (Some(min), Some(max)) => (min.clone(), max.clone()),
+            _ => panic!("Can't compute the quantization parameters before observing a tensor."),
+        };
+        let axis = match self.scheme {
+            QuantizationScheme::PerTensor => None,
+            QuantizationScheme::PerChannel(axis) => Some(axis),
+        };
+
+        QuantizationParams::from_range(min, max, axis)
+    }
+}
@@ -0,0 +1,214 @@
+use crate as burn;
+
+use crate::config::Config;
+use crate::module::{Module, Param};
+use crate::tensor::{backend::Backend, Int, Tensor};
+
+/// Smallest value of the int8 range.
+pub const QUANTIZATION_MIN: i32 = -128;
+/// Largest value of the int8 range.
+pub const QUANTIZATION_MAX: i32 = 127;
+
+/// Granularity of the quantization parameters.
+#[derive(Config, Debug, PartialEq)]
+pub enum QuantizationScheme {
+    /// A single scale and zero point for the whole tensor.
+    PerTensor,
+    /// A scale and zero point for each channel of the tensor, along the given axis.
+    PerChannel(usize),
+}
+
+/// Parameters of an affine int8 quantization, mapping a float value `x` to
+/// `q = clamp(round(x / scale) + zero_point, -128, 127)`.
+#[derive(Module, Debug)]
+pub struct QuantizationParams<B: Backend> {
+    /// Scale of shape `[1]` for per-tensor quantization, or `[channels]` for per-channel
+    /// quantization.
+    pub scale: Param<Tensor<B, 1>>,
+    /// Zero point with the same shape as the scale.
+    pub zero_point: Param<Tensor<B, 1, Int>>,
+    /// Axis of the channels for per-channel quantization.
+    pub axis: Option<usize>,
+}
+
+impl<B: Backend> QuantizationParams<B> {
+    /// Compute the parameters of an affine quantization covering the range `[min, max]`.
+    ///
+    /// The range is extended to include zero, so that zero is exactly representable.
+    pub fn from_range(min: Tensor<B, 1>, max: Tensor<B, 1>, axis: Option<usize>) -> Self {
+        let min = min.clamp_max(0.0);
+        let max = max.clamp_min(0.0);
+        let scale = max
+            .sub(min.clone())
+            .div_scalar((QUANTIZATION_MAX - QUANTIZATION_MIN) as f32)
+            .clamp_min(f32::EPSILON);
+        let zero_point = round(min.div(scale.clone()).neg().add_scalar(QUANTIZATION_MIN))
+            .clamp(QUANTIZATION_MIN, QUANTIZATION_MAX);
+
+        Self {
+            scale: Param::from(scale),
+            zero_point: Param::from(zero_point),
+            axis,
+        }
+    }
+
+    /// Compute the parameters of a per-tensor affine quantization covering the range of the
+    /// tensor, used to quantize activations dynamically without calibration data.
+    pub fn dynamic<const D: usize>(tensor: &Tensor<B, D>) -> Self {
+        let tensor = tensor.clone().detach();
+
+        Self::from_range(tensor.clone().min(), tensor.max(), None)
+    }
+
+    /// Compute the parameters of a symmetric quantization of the tensor, where the zero point is
+    /// always zero, which is usually used for weights.
+    pub fn symmetric<const D: usize>(tensor: &Tensor<B, D>, scheme: &QuantizationScheme) -> Self {
+        let (abs_max, axis) = match scheme {
+            QuantizationScheme::PerTensor => (tensor.clone().abs().max(), None),
+            QuantizationScheme::PerChannel(axis) => (
+                reduce_channels(tensor.clone().abs(), *axis, |tensor| tensor.max_dim(0)),
+                Some(*axis),
+            ),
+        };
+        let scale = abs_max
+            .div_scalar(QUANTIZATION_MAX as f32)
+            .clamp_min(f32::EPSILON);
+        let zero_point = scale.zeros_like().int();
+
+        Self {
+            scale: Param::from(scale),
+            zero_point: Param::from(zero_point),
+            axis,
+        }
+    }
+
+    /// Quantize the tensor to int8 values, stored with the int element type of the backend.
+    pub fn quantize<const D: usize>(&self, tensor: Tensor<B, D>) -> Tensor<B, D, Int> {
+        let scale = self.broadcast::<D>(self.scale.val());
+        let zero_point = self.broadcast::<D>(self.zero_point.val().float());
+
+        round(tensor.div(scale).add(zero_point)).clamp(QUANTIZATION_MIN, QUANTIZATION_MAX)
+    }
+
+    /// Convert quantized values back to float values.
+    pub fn dequantize<const D: usize>(&self, tensor: Tensor<B, D, Int>) -> Tensor<B, D> {
+        let scale = self.broadcast::<D>(self.scale.val());
+        let zero_point = self.broadcast::<D>(self.zero_point.val().float());
+
+        tensor.float().sub(zero_point).mul(scale)
+    }
+
+    /// Quantize and dequantize the tensor, simulating the quantization error.
+    pub fn fake_quantize<const D: usize>(&self, tensor: Tensor<B, D>) -> Tensor<B, D> {
+        self.dequantize(self.quantize(tensor))
+    }
+
+    fn broadcast<const D: usize>(&self, tensor: Tensor<B, 1>) -> Tensor<B, D> {
+        let mut shape = [1; D];
+        if let Some(axis) = self.axis {
+            shape[axis] = tensor.dims()[0];
+        }
+
+        tensor.reshape(shape)
+    }
+}
+
+/// Round to the nearest integer, with ties rounded away from zero.
+pub(crate) fn round<B: Backend, const D: usize>(tensor: Tensor<B, D>) -> Tensor<B, D, Int> {
+    // The conversion to int truncates toward zero.
+    let offset = tensor
+        .ones_like()
+        .mul_scalar(0.5)
+        .mask_fill(tensor.clone().lower_elem(0.0), -0.5);
+
+    tensor.add(offset).int()
+}
+
+/// Reduce all the dimensions except the channel axis, returning a tensor of shape `[channels]`.
+pub(crate) fn reduce_channels<B: Backend, const D: usize, F>(
+    tensor: Tensor<B, D>,
+    axis: usize,
+    reduce: F,
+) -> Tensor<B, 1>
+where
+    F: FnOnce(Tensor<B, 2>) -> Tensor<B, 2>,
+{
+    let channels = tensor.dims()[axis];
+    let num_elements = tensor.shape().num_elements();
+    let tensor = tensor
+        .swap_dims(0, axis)
+        .reshape([channels, num_elements / channels])
+        .transpose();
+
+    reduce(tensor).reshape([channels])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+    use burn_tensor::Data;
+
+    #[test]
+    fn quantize_dequantize_round_trip() {
+        let device = Default::default();
+        let tensor = Tensor::<TestBackend, 1>::from_floats([-1.0, -0.5, 0.0, 0.5, 2.0], &device);
+        let params =
+            QuantizationParams::from_range(tensor.clone().min(), tensor.clone().max(), None);
+
+        let quantized = params.quantize(tensor.clone());
+        quantized.to_data().assert_within_range(-128..128);
+
+        params
+            .dequantize(quantized)
+            .into_data()
+            .assert_approx_eq(&tensor.into_data(), 1);
+    }
+
+    #[test]
+    fn zero_is_exactly_representable() {
+        let device = Default::default();
+        let tensor = Tensor::<TestBackend, 1>::from_floats([0.3, 0.0, 1.7], &device);
+        let params =
+            QuantizationParams::from_range(tensor.clone().min(), tensor.clone().max(), None);
+
+        let output = params.fake_quantize(tensor).into_data();
+
+        assert_eq!(output.value[1], 0.0);
+    }
+
+    #[test]
+    fn dynamic_covers_tensor_range() {
+        let device = Default::default();
+        let tensor = Tensor::<TestBackend, 2>::from_floats([[-3.0, 0.2], [1.0, 5.0]], &device);
+
+        let params = QuantizationParams::dynamic(&tensor);
+
+        assert_eq!(params.axis, None);
+        params
+            .fake_quantize(tensor.clone())
+            .into_data()
+            .assert_approx_eq(&tensor.into_data(), 1);
+    }
+
+    #[test]
+    fn symmetric_per_channel_scales() {
+        let device = Default::default();
+        let tensor = Tensor::<TestBackend, 2>::from_floats([[1.0, -4.0], [-2.0, 0.5]], &device);
+
+        let params = QuantizationParams::symmetric(&tensor, &QuantizationScheme::PerChannel(1));
+
+        params.scale.to_data().assert_approx_eq(
+            &Data::from([2.0 / QUANTIZATION_MAX as f32, 4.0 / QUANTIZATION_MAX as f32]),
+            3,
+        );
+        assert_eq!(
+            params.zero_point.to_data(),
+            Data::<i64, 1>::from([0, 0]).convert()
+        );
+        params
+            .fake_quantize(tensor.clone())
+            .into_data()
+            .assert_approx_eq(&tensor.into_data(), 1);
+    }
+}
@@ -1,5 +1,5 @@
 use super::{bin_config, PrecisionSettings, Recorder, RecorderError};
-use alloc::vec::Vec;
+use alloc::{format, vec::Vec};
 use serde::{de::DeserializeOwned, Serialize};
 
 /// Recorder trait specialized to save and load data to and from bytes.
@@ -40,6 +40,40 @@ impl<S: PrecisionSettings> Recorder for BinBytesRecorder<S> {
     }
 }
 
+/// In memory recorder using the [bincode format](bincode), loading records from a static slice.
+///
+/// # Notes
+///
+/// Unlike [BinBytesRecorder], the bytes aren't copied into a vector before being decoded, which
+/// avoids keeping the weights twice in memory on targets with limited RAM, such as
+/// microcontrollers where the weights are embedded with `include_bytes!`. Records are saved in
+/// the same format as [BinBytesRecorder].
+#[derive(new, Debug, Default, Clone)]
+pub struct BinSliceRecorder<S: PrecisionSettings> {
+    _settings: core::marker::PhantomData<S>,
+}
+
+impl<S: PrecisionSettings> Recorder for BinSliceRecorder<S> {
+    type Settings = S;
+    type RecordArgs = ();
+    type RecordOutput = Vec<u8>;
+    type LoadArgs = &'static [u8];
+
+    fn save_item<I: Serialize>(
+        &self,
+        item: I,
+        _args: Self::RecordArgs,
+    ) -> Result<Self::RecordOutput, RecorderError> {
+        bincode::serde::encode_to_vec(item, bin_config())
+            .map_err(|err| RecorderError::Unknown(format!("{err:?}")))
+    }
+
+    fn load_item<I: DeserializeOwned>(&self, args: Self::LoadArgs) -> Result<I, RecorderError> {
+        bincode::serde::decode_borrowed_from_slice(args, bin_config())
+            .map_err(|err| RecorderError::Unknown(format!("{err:?}")))
+    }
+}
+
 #[cfg(feature = "std")]
 /// In memory recorder using the [Named MessagePack](rmp_serde).
 #[derive(new, Debug, Default, Clone)]
@@ -81,6 +115,35 @@ mod tests {
         test_can_save_and_load(BinBytesRecorder::<FullPrecisionSettings>::default())
     }
 
+    #[test]
+    fn test_can_load_bin_bytes_from_slice() {
+        let device = Default::default();
+        let model1 = create_model::<TestBackend>(&device);
+        let model2 = create_model::<TestBackend>(&device);
+        let bytes = BinBytesRecorder::<FullPrecisionSettings>::default()
+            .record(model1.clone().into_record(), ())
+            .unwrap();
+        let recorder = BinSliceRecorder::<FullPrecisionSettings>::default();
+
+        // The recorders are saved in the metadata, so the loaded parameters are compared instead.
+        let model2 = model2.load_record(recorder.load(Vec::leak(bytes)).unwrap());
+
+        assert_eq!(model1.weight.to_data(), model2.weight.to_data());
+        assert_eq!(
+            model1.bias.unwrap().val().into_data(),
+            model2.bias.unwrap().val().into_data()
+        );
+    }
+
+    #[test]
+    fn test_slice_recorder_fails_on_invalid_bytes() {
+        let recorder = BinSliceRecorder::<FullPrecisionSettings>::default();
+
+        let result: Result<nn::LinearRecord<TestBackend>, _> = recorder.load(&[1, 2, 3]);
+
+        assert!(result.is_err());
+    }
+
     #[cfg(feature = "std")]
     #[test]
     fn test_can_save_and_load_named_mpk_format() {
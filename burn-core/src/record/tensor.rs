@@ -1,5 +1,6 @@
 use super::{PrecisionSettings, Record};
-use burn_tensor::{backend::Backend, Bool, DataSerialize, Int, Tensor};
+use burn_tensor::backend::{default_device, Backend};
+use burn_tensor::{Bool, DataSerialize, Int, Tensor};
 use serde::{Deserialize, Serialize};
 
 /// This struct implements serde to lazily serialize and deserialize a float tensor
@@ -97,7 +98,10 @@ impl<B: Backend, const D: usize> Record for Tensor<B, D> {
     }
 
     fn from_item<S: PrecisionSettings>(item: Self::Item<S>) -> Self {
-        Tensor::from_data(item.data.convert::<B::FloatElem>(), &B::Device::default())
+        Tensor::from_data(
+            item.data.convert::<B::FloatElem>(),
+            &default_device::<B::Device>(),
+        )
     }
 }
 
@@ -113,7 +117,7 @@ impl<B: Backend, const D: usize> Record for Tensor<B, D, Int> {
     }
 
     fn from_item<S: PrecisionSettings>(item: Self::Item<S>) -> Self {
-        Tensor::from_data(item.data.convert(), &B::Device::default())
+        Tensor::from_data(item.data.convert(), &default_device::<B::Device>())
     }
 }
 
@@ -129,6 +133,6 @@ impl<B: Backend, const D: usize> Record for Tensor<B, D, Bool> {
     }
 
     fn from_item<S: PrecisionSettings>(item: Self::Item<S>) -> Self {
-        Tensor::from_data(item.data, &B::Device::default())
+        Tensor::from_data(item.data, &default_device::<B::Device>())
     }
 }
@@ -1,11 +1,15 @@
 use crate::{
     client::FusionClient,
     stream::{Context, TensorOpsDescription},
-    FusionClientLocator, FusionTensor,
+    DrainFuture, FusionCheckpoint, FusionClientLocator, FusionConfig, FusionMetrics, FusionTensor,
 };
 use burn_tensor::{backend::Backend, Device, Shape};
 use serde::{de::DeserializeOwned, Serialize};
-use std::marker::PhantomData;
+use std::{
+    io,
+    marker::PhantomData,
+    path::{Path, PathBuf},
+};
 
 pub(crate) static CLIENTS: FusionClientLocator = FusionClientLocator::new();
 
@@ -14,6 +18,10 @@ pub(crate) fn get_client<B: FusionBackend>(device: &B::FusionDevice) -> B::Fusio
 }
 
 /// Enable dynamic operation fusion on a backend that implements [fusion backend](crate::FusionBackend).
+///
+/// Set the `BURN_FUSION_DOT` [environment variable](crate::stream::DOT_DIRECTORY_ENV) to a
+/// directory to write each executed stream of operations as a DOT graph, showing which operations
+/// were fused.
 #[derive(Clone, Debug, Default)]
 pub struct Fusion<B> {
     _backend: PhantomData<B>,
@@ -49,6 +57,195 @@ impl<B: FusionBackend> Backend for Fusion<B> {
         client.drain();
         B::sync(device)
     }
+
+    fn non_deterministic_ops(device: &Self::Device) -> &'static [&'static str] {
+        B::non_deterministic_ops(device)
+    }
+}
+
+impl<B: FusionBackend> Fusion<B> {
+    /// Set the [configuration](FusionConfig) of the fusion on the device.
+    ///
+    /// The lazy operations of the device are executed first.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// Fusion::<Wgpu>::configure(
+    ///     &device,
+    ///     FusionConfig {
+    ///         eviction: Some(EvictionPolicy::new(256, EvictionStrategy::LeastRecentlyUsed)),
+    ///         ..Default::default()
+    ///     },
+    /// );
+    /// ```
+    pub fn configure(device: &B::Device, config: FusionConfig) {
+        let client = CLIENTS.client::<B::FusionClient>(&device.clone().into());
+        client.configure(config)
+    }
+
+    /// Set the [mode](FusionMode) used to execute the operations registered on the device.
+    ///
+    /// The lazy operations of the device are executed first.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// Fusion::<Wgpu>::set_execution_mode(&device, FusionMode::Eager);
+    /// ```
+    pub fn set_execution_mode(device: &B::Device, mode: FusionMode) {
+        let client = CLIENTS.client::<B::FusionClient>(&device.clone().into());
+        client.set_execution_mode(mode)
+    }
+
+    /// Execute the lazy operations of the device without blocking the current thread, returning a
+    /// [future](DrainFuture) resolved when they are completed.
+    ///
+    /// This is the async equivalent of [sync](Backend::sync), meant for servers using an async
+    /// runtime where blocking a worker thread during sync points would stall other requests.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// Fusion::<Wgpu>::drain_async(&device).await;
+    /// ```
+    pub fn drain_async(device: &B::Device) -> DrainFuture
+    where
+        B::FusionClient: 'static,
+        B::Device: 'static,
+    {
+        let client = CLIENTS.client::<B::FusionClient>(&device.clone().into());
+        DrainFuture::new::<B>(client, device.clone())
+    }
+
+    /// Register an [optimization factory](OptimizationFactory) on the device, so that custom
+    /// optimizations are explored with the ones of the backend.
+    ///
+    /// The lazy operations of the device are executed first.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// Fusion::<Wgpu>::register_optimization(
+    ///     &device,
+    ///     Box::new(|device: &WgpuDevice| {
+    ///         Box::new(FusedAttentionBuilder::new(device.clone())) as Box<_>
+    ///     }),
+    /// );
+    /// ```
+    pub fn register_optimization(device: &B::Device, factory: Box<dyn OptimizationFactory<B>>) {
+        let client = CLIENTS.client::<B::FusionClient>(&device.clone().into());
+        client.register_optimization(factory)
+    }
+
+    /// The [metrics](FusionMetrics) of the fusion on the device.
+    ///
+    /// Only the executed operations are counted, the lazy operations still pending on the device
+    /// aren't included.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let metrics = Fusion::<Wgpu>::metrics(&device);
+    /// println!("{:.1}% of the operations are fused", metrics.fusion_rate() * 100.0);
+    /// ```
+    pub fn metrics(device: &B::Device) -> FusionMetrics {
+        let client = CLIENTS.client::<B::FusionClient>(&device.clone().into());
+        client.metrics()
+    }
+
+    /// Save the optimizations found on the device to a file.
+    ///
+    /// Optimizations are saved using their [serializable state](Optimization::to_state), so they
+    /// can be [loaded](Fusion::load_optimizations) by another process to skip the exploration of
+    /// the same streams of operations.
+    pub fn save_optimizations<P: AsRef<Path>>(
+        device: &B::Device,
+        path: P,
+    ) -> Result<(), io::Error> {
+        let client = CLIENTS.client::<B::FusionClient>(&device.clone().into());
+        client.save_optimizations(path.as_ref())
+    }
+
+    /// Load the optimizations saved in a file on the device, returning the number of new
+    /// optimizations.
+    ///
+    /// The lazy operations of the device are executed first.
+    pub fn load_optimizations<P: AsRef<Path>>(
+        device: &B::Device,
+        path: P,
+    ) -> Result<usize, io::Error> {
+        let client = CLIENTS.client::<B::FusionClient>(&device.clone().into());
+        client.load_optimizations(path.as_ref())
+    }
+
+    /// Enable the persistence of the optimizations of the device.
+    ///
+    /// The optimizations saved in the file are loaded if it exists, then the file is updated each
+    /// time a new optimization is found, which reduces the warmup time of repeated workloads.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// Fusion::<Wgpu>::persist_optimizations(&device, "fusion-cache.json")?;
+    /// ```
+    pub fn persist_optimizations<P: Into<PathBuf>>(
+        device: &B::Device,
+        path: P,
+    ) -> Result<(), io::Error> {
+        let client = CLIENTS.client::<B::FusionClient>(&device.clone().into());
+        client.persist_optimizations(path.into())
+    }
+
+    /// Snapshot the fusion state of the device in a [checkpoint](FusionCheckpoint), so that a
+    /// process can be suspended and resumed without compiling its optimizations again.
+    ///
+    /// The device is synchronized first, so no lazy operation is in flight when the checkpoint is
+    /// taken.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// Fusion::<Wgpu>::checkpoint(&device).save("fusion-checkpoint.json")?;
+    /// ```
+    pub fn checkpoint(device: &B::Device) -> FusionCheckpoint {
+        let client = CLIENTS.client::<B::FusionClient>(&device.clone().into());
+        let checkpoint = client.checkpoint();
+        B::sync(device);
+
+        checkpoint
+    }
+
+    /// Restore a [checkpoint](FusionCheckpoint) on the device, returning the number of new
+    /// optimizations.
+    ///
+    /// The lazy operations of the device are executed first. An error is returned when the
+    /// checkpoint was created on another backend.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let checkpoint = FusionCheckpoint::load("fusion-checkpoint.json")?;
+    /// Fusion::<Wgpu>::restore(&device, &checkpoint)?;
+    /// ```
+    pub fn restore(device: &B::Device, checkpoint: &FusionCheckpoint) -> Result<usize, io::Error> {
+        let client = CLIENTS.client::<B::FusionClient>(&device.clone().into());
+        client.restore(checkpoint)
+    }
+}
+
+/// How the operations registered on a device are executed, set with
+/// [set_execution_mode](Fusion::set_execution_mode).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum FusionMode {
+    /// The operations are kept pending to be fused with the next ones.
+    #[default]
+    Lazy,
+    /// Each operation is executed as soon as it is registered, without any optimization.
+    ///
+    /// Useful to find out if the fusion causes a numerical difference, since the model code
+    /// doesn't change.
+    Eager,
 }
 
 /// The status of a [builder](OptimizationBuilder).
@@ -94,6 +291,44 @@ pub trait OptimizationBuilder<B: FusionBackend>: Send {
     fn properties(&self) -> OptimizationProperties;
 }
 
+/// Create [builders](OptimizationBuilder) of custom optimizations, registered with
+/// [register_optimization](Fusion::register_optimization).
+///
+/// A builder is created for each stream of the device, and its optimizations are selected with the
+/// ones of the backend based on their [score](OptimizationProperties::score). The optimizations must
+/// be of the [backend type](FusionBackend::Optimization), backends supporting custom
+/// optimizations wrap a [custom optimization](CustomOptimization) in one of their variants.
+pub trait OptimizationFactory<B: FusionBackend>: Send {
+    /// Create a new builder for the device.
+    fn create(&self, device: &B::Device) -> Box<dyn OptimizationBuilder<B>>;
+}
+
+impl<B, F> OptimizationFactory<B> for F
+where
+    B: FusionBackend,
+    F: Fn(&B::Device) -> Box<dyn OptimizationBuilder<B>> + Send,
+{
+    fn create(&self, device: &B::Device) -> Box<dyn OptimizationBuilder<B>> {
+        self(device)
+    }
+}
+
+/// An optimization provided by users, which can be wrapped by the
+/// [optimization](FusionBackend::Optimization) of a backend.
+///
+/// Custom optimizations can't be [saved](Fusion::save_optimizations), since the backend can't
+/// create them from a serialized state.
+pub trait CustomOptimization<B: FusionBackend>: Send {
+    /// Execute the operation.
+    fn execute(&mut self, context: &mut Context<'_, B>);
+    /// The number of registered operations in this optimization.
+    fn len(&self) -> usize;
+    /// If the current optimization is empty.
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
 /// The operation created from the [builder](OptimizationBuilder).
 pub trait Optimization<B: FusionBackend>: Send {
     /// Execute the operation.
@@ -104,6 +339,11 @@ pub trait Optimization<B: FusionBackend>: Send {
     fn is_empty(&self) -> bool {
         self.len() == 0
     }
+    /// If the optimization can be [saved](Fusion::save_optimizations) using its
+    /// [state](Optimization::to_state).
+    fn is_persistent(&self) -> bool {
+        true
+    }
     /// Returns the state that can be serialized.
     fn to_state(&self) -> B::OptimizationState;
     /// Create the optimization from the state.
@@ -167,4 +407,23 @@ pub trait FusionBackend: Backend {
     fn int_tensor_handle<const D: usize>(tensor: Self::IntTensorPrimitive<D>) -> Self::Handle;
     /// Convert a [bool tensor](Backend::BoolTensorPrimitive) to a [handle](FusionBackend::Handle).
     fn bool_tensor_handle<const D: usize>(tensor: Self::BoolTensorPrimitive<D>) -> Self::Handle;
+
+    /// The size in bytes of the elements of a freed handle of the given shape, when its memory can
+    /// be [reused](crate::HandleContainer::take_pooled) to write a new contiguous tensor of the same shape.
+    ///
+    /// Returns `None` when the memory is shared with another tensor or can't hold the new tensor,
+    /// which is the default: handles are only pooled by backends implementing this method.
+    fn reusable_elem_size(_handle: &Self::Handle, _shape: &[usize]) -> Option<usize> {
+        None
+    }
+
+    /// The number of operations the execution of a found optimization is deferred when a longer
+    /// optimization might still match the stream, unless [configured](FusionConfig::lookahead)
+    /// on the device.
+    ///
+    /// Backends with expensive launches can increase it to favor longer fusions, at the cost of a
+    /// higher latency. No operation is deferred by default.
+    fn lookahead() -> usize {
+        0
+    }
 }
@@ -0,0 +1,89 @@
+use serde::{Deserialize, Serialize};
+use std::{
+    fs::{self, File},
+    io::{self, BufWriter},
+    path::Path,
+};
+
+/// Snapshot of the fusion state of a device, created with
+/// [checkpoint](crate::Fusion::checkpoint) and [restored](crate::Fusion::restore) on a device of
+/// the same backend, possibly in another process.
+///
+/// The lazy operations of the device are executed before the snapshot is taken, so no stream is
+/// in flight and every tensor handle is materialized on the device. The checkpoint then only has
+/// to keep the optimizations of the device, which lets a suspended process resume without
+/// exploring and compiling its streams of operations again.
+///
+/// # Notes
+///
+/// The values of the tensors aren't part of the checkpoint, they should be saved with the
+/// records of the modules and optimizers like any other training state.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct FusionCheckpoint {
+    pub(crate) backend: String,
+    pub(crate) optimizations: Vec<String>,
+}
+
+impl FusionCheckpoint {
+    pub(crate) fn new(backend: String, optimizations: Vec<String>) -> Self {
+        Self {
+            backend,
+            optimizations,
+        }
+    }
+
+    /// The name of the backend on which the checkpoint was created.
+    pub fn backend(&self) -> &str {
+        &self.backend
+    }
+
+    /// The number of optimizations kept by the checkpoint.
+    pub fn num_optimizations(&self) -> usize {
+        self.optimizations.len()
+    }
+
+    /// Save the checkpoint to a file.
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<(), io::Error> {
+        let path = path.as_ref();
+
+        if let Some(parent_dir) = path.parent() {
+            fs::create_dir_all(parent_dir)?;
+        }
+
+        let file = BufWriter::new(File::create(path)?);
+        serde_json::to_writer(file, self)?;
+
+        Ok(())
+    }
+
+    /// Load a checkpoint saved in a file.
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, io::Error> {
+        let data = fs::read_to_string(path)?;
+
+        Ok(serde_json::from_str(&data)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn checkpoints_are_loaded_as_saved() {
+        let path = std::env::temp_dir().join(format!(
+            "burn-fusion-checkpoint-{}.json",
+            std::process::id()
+        ));
+        let checkpoint = FusionCheckpoint::new(
+            "fusion<test>".to_string(),
+            vec!["{\"stream\":[]}".to_string(), "{}".to_string()],
+        );
+
+        checkpoint.save(&path).unwrap();
+        let loaded = FusionCheckpoint::load(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(loaded, checkpoint);
+        assert_eq!(loaded.num_optimizations(), 2);
+    }
+}
@@ -1,11 +1,16 @@
 use crate::{
     stream::{Ops, TensorOpsDescription},
-    FusionBackend, FusionTensor, Handle, TensorDescription, TensorId,
+    FusionBackend, FusionCheckpoint, FusionConfig, FusionMetrics, FusionMode, FusionTensor, Handle,
+    OptimizationFactory, TensorDescription, TensorId,
 };
 use burn_tensor::{
     ops::{FloatElem, IntElem},
     Data, Reader,
 };
+use std::{
+    io,
+    path::{Path, PathBuf},
+};
 
 /// Define how to interact with the fusion server.
 pub trait FusionClient: Send + Sync + Clone {
@@ -22,6 +27,27 @@ pub trait FusionClient: Send + Sync + Clone {
     );
     /// Register all lazy computation.
     fn drain(&self);
+    /// Set the [configuration](FusionConfig) of the server.
+    fn configure(&self, config: FusionConfig);
+    /// Set the [mode](FusionMode) used to execute the operations registered on the server.
+    fn set_execution_mode(&self, mode: FusionMode);
+    /// Register an [optimization factory](OptimizationFactory) on the server.
+    fn register_optimization(&self, factory: Box<dyn OptimizationFactory<Self::FusionBackend>>);
+    /// Get the [metrics](FusionMetrics) of the server.
+    fn metrics(&self) -> FusionMetrics;
+    /// Save the optimizations found by the server to a file.
+    fn save_optimizations(&self, path: &Path) -> Result<(), io::Error>;
+    /// Load the optimizations saved in a file, returning the number of new optimizations.
+    fn load_optimizations(&self, path: &Path) -> Result<usize, io::Error>;
+    /// Load the optimizations saved in a file if it exists, then save every new optimization
+    /// found by the server to that file.
+    fn persist_optimizations(&self, path: PathBuf) -> Result<(), io::Error>;
+    /// Execute the lazy operations, then snapshot the optimizations found by the server in a
+    /// [checkpoint](FusionCheckpoint).
+    fn checkpoint(&self) -> FusionCheckpoint;
+    /// Add the optimizations of a [checkpoint](FusionCheckpoint) to the server, returning the
+    /// number of new optimizations.
+    fn restore(&self, checkpoint: &FusionCheckpoint) -> Result<usize, io::Error>;
     /// Get the current device used by all operations handled by this client.
     fn device(&self) -> &<Self::FusionBackend as FusionBackend>::FusionDevice;
     /// Create a new [fusion tensor](FusionTensor), but with no resources allocated to it.
@@ -1,8 +1,15 @@
 use super::FusionClient;
-use crate::{stream::TensorOpsDescription, FusionBackend, FusionServer, FusionTensor, Handle};
+use crate::{
+    stream::TensorOpsDescription, FusionBackend, FusionCheckpoint, FusionConfig, FusionMetrics,
+    FusionMode, FusionServer, FusionTensor, Handle, OptimizationFactory,
+};
 use burn_tensor::ops::FloatElem;
 use spin::Mutex;
-use std::sync::Arc;
+use std::{
+    io,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
 
 /// Use a mutex to communicate with the fusion server.
 pub struct MutexFusionClient<B>
@@ -50,6 +57,42 @@ where
         self.server.lock().drain_streams();
     }
 
+    fn configure(&self, config: FusionConfig) {
+        self.server.lock().configure(config);
+    }
+
+    fn set_execution_mode(&self, mode: FusionMode) {
+        self.server.lock().set_execution_mode(mode);
+    }
+
+    fn register_optimization(&self, factory: Box<dyn OptimizationFactory<B>>) {
+        self.server.lock().register_optimization(factory);
+    }
+
+    fn metrics(&self) -> FusionMetrics {
+        self.server.lock().metrics()
+    }
+
+    fn save_optimizations(&self, path: &Path) -> Result<(), io::Error> {
+        self.server.lock().save_optimizations(path)
+    }
+
+    fn load_optimizations(&self, path: &Path) -> Result<usize, io::Error> {
+        self.server.lock().load_optimizations(path)
+    }
+
+    fn persist_optimizations(&self, path: PathBuf) -> Result<(), io::Error> {
+        self.server.lock().persist_optimizations(path)
+    }
+
+    fn checkpoint(&self) -> FusionCheckpoint {
+        self.server.lock().checkpoint()
+    }
+
+    fn restore(&self, checkpoint: &FusionCheckpoint) -> Result<usize, io::Error> {
+        self.server.lock().restore(checkpoint)
+    }
+
     fn tensor_uninitialized(&self, shape: Vec<usize>) -> FusionTensor<Self> {
         let id = self.server.lock().create_empty_handle();
 
@@ -99,16 +142,13 @@ where
         tensor: crate::TensorDescription,
         client: Self,
     ) -> FusionTensor<Self> {
-        let device = client.device.clone().into();
-
-        let mut server_other = client.server.lock();
-        let mut server_current = self.server.lock();
-        server_current.drain_streams();
-
-        let id = server_current.change_server_float::<D>(&tensor, &device, &mut server_other);
-
-        core::mem::drop(server_other);
-        core::mem::drop(server_current);
+        // The servers are never locked together, so transfers in both directions can't
+        // deadlock.
+        let primitive = self.server.lock().take_float_tensor::<D>(&tensor);
+        let id = client
+            .server
+            .lock()
+            .register_float_transfer::<D>(primitive, tensor.shape.clone());
 
         FusionTensor::new(id, tensor.shape, client)
     }
@@ -117,16 +157,11 @@ where
         tensor: crate::TensorDescription,
         client: Self,
     ) -> FusionTensor<Self> {
-        let device = client.device.clone().into();
-
-        let mut server_other = client.server.lock();
-        let mut server_current = self.server.lock();
-        server_current.drain_streams();
-
-        let id = server_current.change_server_int::<D>(&tensor, &device, &mut server_other);
-
-        core::mem::drop(server_other);
-        core::mem::drop(server_current);
+        let primitive = self.server.lock().take_int_tensor::<D>(&tensor);
+        let id = client
+            .server
+            .lock()
+            .register_int_transfer::<D>(primitive, tensor.shape.clone());
 
         FusionTensor::new(id, tensor.shape, client)
     }
@@ -136,16 +171,11 @@ where
         tensor: crate::TensorDescription,
         client: Self,
     ) -> FusionTensor<Self> {
-        let device = client.device.clone().into();
-
-        let mut server_other = client.server.lock();
-        let mut server_current = self.server.lock();
-        server_current.drain_streams();
-
-        let id = server_current.change_server_bool::<D>(&tensor, &device, &mut server_other);
-
-        core::mem::drop(server_other);
-        core::mem::drop(server_current);
+        let primitive = self.server.lock().take_bool_tensor::<D>(&tensor);
+        let id = client
+            .server
+            .lock()
+            .register_bool_transfer::<D>(primitive, tensor.shape.clone());
 
         FusionTensor::new(id, tensor.shape, client)
     }
@@ -0,0 +1,89 @@
+use std::{path::PathBuf, time::Duration};
+
+/// Configuration of the fusion on a device, set with [configure](crate::Fusion::configure).
+#[derive(Clone, Debug, Default)]
+pub struct FusionConfig {
+    /// When to execute the lazy operations before an optimization is found.
+    pub flush: FlushPolicy,
+    /// The policy used to evict optimizations, no optimization is evicted when `None`.
+    pub eviction: Option<EvictionPolicy>,
+    /// Log the [metrics](crate::FusionMetrics) with the `info` level each time the device is
+    /// synchronized.
+    pub log_metrics: bool,
+    /// Keep an approximation of the scalar values in the relative streams instead of zeroing
+    /// them, so that optimizations can be specialized on their values.
+    ///
+    /// Small integers are kept as is, the other values are [bucketed](crate::stream::bucket_scalar)
+    /// to limit the number of optimizations created for streams only differing by their scalars.
+    pub bucket_scalars: bool,
+    /// Append each executed stream of operations to the file, so that it can be
+    /// [replayed](crate::stream::replay) to debug the fused execution.
+    ///
+    /// Only meant for debugging, since the file is written each time operations are executed.
+    pub dump_streams: Option<PathBuf>,
+    /// Share the optimizations found on the device with the other devices of the same backend.
+    ///
+    /// Each device still keeps its own optimizations, but imports the ones found on the other
+    /// devices sharing their optimizations instead of exploring the same streams again.
+    pub share_optimizations: bool,
+    /// The maximum number of freed tensor handles kept to write new tensors of the same shape
+    /// without allocating, no handle is pooled when zero.
+    ///
+    /// Only backends [reusing handles](crate::FusionBackend::reusable_elem_size) benefit from it.
+    pub max_pooled_handles: usize,
+    /// The number of operations the execution of a found optimization is deferred when a longer
+    /// optimization might still match the stream, the [backend](crate::FusionBackend::lookahead)
+    /// decides when `None`.
+    ///
+    /// A larger window enables longer fusions, but delays the execution of the operations.
+    pub lookahead: Option<usize>,
+}
+
+/// Limits on the lazy operations pending on a device.
+///
+/// Operations are kept pending as long as they might be fused with the next ones, which improves
+/// the throughput but delays their execution. When one of the limits is exceeded, all pending
+/// operations are executed with the optimizations found so far.
+///
+/// The limits are checked each time an operation is registered, no limit is set by default.
+#[derive(Clone, Debug, Default)]
+pub struct FlushPolicy {
+    /// The maximum number of operations pending in a stream.
+    pub max_stream_length: Option<usize>,
+    /// The maximum time elapsed since the oldest pending operation of a stream was registered.
+    pub max_latency: Option<Duration>,
+    /// The maximum number of bytes of the tensors created by the pending operations of a stream.
+    ///
+    /// The size is estimated from the shapes of the tensors and the size of the float element of
+    /// the backend.
+    pub max_memory: Option<usize>,
+}
+
+/// Limit the number of optimizations kept on a device.
+///
+/// Long-running processes can encounter many different streams of operations, each one creating a
+/// new optimization. When the maximum number of optimizations is exceeded, the ones selected by
+/// the [strategy](EvictionStrategy) are removed and will be explored again if their streams are
+/// encountered later.
+///
+/// # Notes
+///
+/// Optimizations are only evicted when no operation is pending on the device, so the number of
+/// optimizations can temporarily exceed the maximum.
+#[derive(new, Clone, Debug)]
+pub struct EvictionPolicy {
+    /// The maximum number of optimizations kept on the device.
+    pub max_optimizations: usize,
+    /// How to select the optimizations to evict.
+    pub strategy: EvictionStrategy,
+}
+
+/// How to select the optimizations to evict.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EvictionStrategy {
+    /// Evict the optimizations that haven't been executed for the longest time.
+    LeastRecentlyUsed,
+    /// Evict the optimizations that have been executed the fewest times, the least recently used
+    /// are evicted first between optimizations executed the same number of times.
+    LeastFrequentlyUsed,
+}
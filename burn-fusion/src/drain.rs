@@ -0,0 +1,64 @@
+use crate::{client::FusionClient, FusionBackend};
+use core::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll, Waker},
+};
+use spin::Mutex;
+use std::sync::Arc;
+
+/// Future resolved when the lazy operations of a device are executed and completed, created with
+/// [drain_async](crate::Fusion::drain_async).
+///
+/// The streams are drained on a separate thread, so waiting for the future doesn't block the
+/// thread polling it, which lets async runtimes keep serving other tasks during sync points.
+pub struct DrainFuture {
+    state: Arc<Mutex<DrainState>>,
+}
+
+#[derive(Default)]
+struct DrainState {
+    completed: bool,
+    waker: Option<Waker>,
+}
+
+impl DrainFuture {
+    pub(crate) fn new<B>(client: B::FusionClient, device: B::Device) -> Self
+    where
+        B: FusionBackend,
+        B::FusionClient: 'static,
+        B::Device: 'static,
+    {
+        let state = Arc::new(Mutex::new(DrainState::default()));
+        let state_thread = state.clone();
+
+        std::thread::spawn(move || {
+            client.drain();
+            B::sync(&device);
+
+            let mut state = state_thread.lock();
+            state.completed = true;
+
+            if let Some(waker) = state.waker.take() {
+                waker.wake();
+            }
+        });
+
+        Self { state }
+    }
+}
+
+impl Future for DrainFuture {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut state = self.state.lock();
+
+        if state.completed {
+            return Poll::Ready(());
+        }
+
+        state.waker = Some(cx.waker().clone());
+        Poll::Pending
+    }
+}
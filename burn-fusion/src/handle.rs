@@ -11,6 +11,10 @@ pub struct HandleContainer<B: FusionBackend> {
     pub(crate) handles_orphan: Vec<TensorId>,
     /// The device on which all tensors are held.
     pub device: B::Device,
+    // Freed handles that can be reused for new tensors, keyed by shape and element size.
+    pool: HashMap<(Vec<usize>, usize), Vec<B::Handle>>,
+    num_pooled: usize,
+    max_pooled: usize,
 }
 
 enum Handle<B: FusionBackend> {
@@ -19,12 +23,16 @@ enum Handle<B: FusionBackend> {
 }
 
 impl<B: FusionBackend> HandleContainer<B> {
-    pub(crate) fn new(device_handle: B::FusionDevice) -> Self {
+    /// Create an empty handle container for the device.
+    pub fn new(device_handle: B::FusionDevice) -> Self {
         Self {
             handles: HashMap::new(),
             handles_orphan: Vec::new(),
             counter: 0,
             device: device_handle.clone().into(),
+            pool: HashMap::new(),
+            num_pooled: 0,
+            max_pooled: 0,
         }
     }
 
@@ -134,12 +142,55 @@ impl<B: FusionBackend> HandleContainer<B> {
         Arc::new(id)
     }
 
+    /// Take a freed handle that can be reused to write a new contiguous tensor of the given shape
+    /// and element size, avoiding a new allocation.
+    pub fn take_pooled(&mut self, shape: &[usize], elem_size: usize) -> Option<B::Handle> {
+        let handle = self.pool.get_mut(&(shape.to_vec(), elem_size))?.pop()?;
+        self.num_pooled -= 1;
+
+        Some(handle)
+    }
+
+    /// Give back a handle that isn't used anymore, keeping it for a new tensor of the same shape
+    /// when the [backend](FusionBackend::reusable_elem_size) allows it and the pool isn't full.
+    pub fn recycle(&mut self, shape: &[usize], handle: B::Handle) {
+        if self.num_pooled >= self.max_pooled {
+            return;
+        }
+
+        if let Some(elem_size) = B::reusable_elem_size(&handle, shape) {
+            self.pool
+                .entry((shape.to_vec(), elem_size))
+                .or_default()
+                .push(handle);
+            self.num_pooled += 1;
+        }
+    }
+
+    /// Set the maximum number of freed handles kept for reuse, releasing the pooled handles when
+    /// the new maximum is lower.
+    pub fn set_max_pooled(&mut self, max_pooled: usize) {
+        self.max_pooled = max_pooled;
+
+        if self.num_pooled > max_pooled {
+            self.pool.clear();
+            self.num_pooled = 0;
+        }
+    }
+
+    /// The number of tensors with a handle, initialized or not.
+    pub(crate) fn num_handles(&self) -> usize {
+        self.handles.len()
+    }
+
     pub(crate) fn free(&mut self, tensor: &TensorDescription) {
         match tensor.status {
             TensorStatus::ReadOnly => (),
             TensorStatus::NotInit => (),
             TensorStatus::ReadWrite => {
-                self.handles.remove(&tensor.id);
+                if let Some(Handle::Existing(handle)) = self.handles.remove(&tensor.id) {
+                    self.recycle(&tensor.shape, handle);
+                }
             }
         }
     }
@@ -14,8 +14,12 @@ pub mod client;
 pub mod stream;
 
 mod backend;
+mod checkpoint;
+mod config;
+mod drain;
 mod fusion;
 mod handle;
+mod metrics;
 mod ops;
 mod server;
 mod tensor;
@@ -23,6 +27,10 @@ mod tensor;
 pub(crate) use server::*;
 
 pub use backend::*;
+pub use checkpoint::*;
+pub use config::*;
+pub use drain::*;
 pub use fusion::*;
 pub use handle::*;
+pub use metrics::*;
 pub use tensor::*;
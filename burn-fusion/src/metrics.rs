@@ -0,0 +1,114 @@
+use core::{fmt::Display, ops::AddAssign};
+
+/// Metrics of the fusion on a device, returned by [metrics](crate::Fusion::metrics).
+///
+/// The counters are accumulated since the creation of the device, use the difference between two
+/// snapshots to measure a specific workload.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct FusionMetrics {
+    /// The number of operations executed as part of an optimization.
+    pub fused_operations: u64,
+    /// The number of operations executed one by one, without optimization.
+    pub unfused_operations: u64,
+    /// The number of times an existing optimization was found for a stream.
+    pub optimization_hits: u64,
+    /// The number of optimizations created after exploring a stream.
+    pub optimization_misses: u64,
+    /// The number of times operations were executed from a stream.
+    pub executions: u64,
+    /// The sum of the number of pending operations in the stream at each execution.
+    pub total_stream_length: u64,
+    /// The number of tensor handles held by the device when the metrics are taken, which isn't
+    /// accumulated like the other counters.
+    pub handles: u64,
+}
+
+impl FusionMetrics {
+    /// The ratio of operations executed as part of an optimization.
+    pub fn fusion_rate(&self) -> f64 {
+        ratio(
+            self.fused_operations,
+            self.fused_operations + self.unfused_operations,
+        )
+    }
+
+    /// The ratio of optimizations found in the store instead of being created.
+    pub fn hit_rate(&self) -> f64 {
+        ratio(
+            self.optimization_hits,
+            self.optimization_hits + self.optimization_misses,
+        )
+    }
+
+    /// The average number of pending operations in the stream when executed.
+    pub fn average_stream_length(&self) -> f64 {
+        ratio(self.total_stream_length, self.executions)
+    }
+}
+
+impl AddAssign for FusionMetrics {
+    fn add_assign(&mut self, rhs: Self) {
+        self.fused_operations += rhs.fused_operations;
+        self.unfused_operations += rhs.unfused_operations;
+        self.optimization_hits += rhs.optimization_hits;
+        self.optimization_misses += rhs.optimization_misses;
+        self.executions += rhs.executions;
+        self.total_stream_length += rhs.total_stream_length;
+        self.handles += rhs.handles;
+    }
+}
+
+impl Display for FusionMetrics {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "Fusion - fused ops: {} ({:.1}%), unfused ops: {}, optimization hits: {} ({:.1}%), \
+             misses: {}, average stream length: {:.2}",
+            self.fused_operations,
+            self.fusion_rate() * 100.0,
+            self.unfused_operations,
+            self.optimization_hits,
+            self.hit_rate() * 100.0,
+            self.optimization_misses,
+            self.average_stream_length(),
+        )
+    }
+}
+
+fn ratio(value: u64, total: u64) -> f64 {
+    match total {
+        0 => 0.0,
+        _ => value as f64 / total as f64,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_compute_rates_from_counters() {
+        let metrics = FusionMetrics {
+            fused_operations: 6,
+            unfused_operations: 2,
+            optimization_hits: 3,
+            optimization_misses: 1,
+            executions: 4,
+            total_stream_length: 10,
+            handles: 0,
+        };
+
+        assert_eq!(metrics.fusion_rate(), 0.75);
+        assert_eq!(metrics.hit_rate(), 0.75);
+        assert_eq!(metrics.average_stream_length(), 2.5);
+    }
+
+    #[test]
+    fn should_not_divide_by_zero_without_executions() {
+        let metrics = FusionMetrics::default();
+
+        assert_eq!(metrics.fusion_rate(), 0.0);
+        assert_eq!(metrics.hit_rate(), 0.0);
+        assert_eq!(metrics.average_stream_length(), 0.0);
+    }
+}
@@ -0,0 +1,59 @@
+use crate::{client::FusionClient, FusionTensor};
+use burn_tensor::{Element, ElementConversion, Precision};
+
+/// Fold a scalar operation when its input is a [constant](FusionTensor::constant) tensor.
+///
+/// The folded operation is replaced by a new constant tensor at registration time, so it's never
+/// added to the stream and the creation of the input can be skipped when it isn't used elsewhere.
+///
+/// The operation is computed in `f64`, the result must then be checked with [float_constant] or
+/// [int_constant] to make sure it's the value the backend would have computed.
+pub(crate) fn fold_scalar<C: FusionClient>(
+    lhs: &FusionTensor<C>,
+    rhs: f64,
+    func: fn(f64, f64) -> f64,
+) -> Option<f64> {
+    Some(func(lhs.constant?, rhs))
+}
+
+/// Fold a binary operation when both of its inputs are [constant](FusionTensor::constant) tensors.
+///
+/// See [fold_scalar] for how the result must be checked.
+pub(crate) fn fold_binary<C: FusionClient>(
+    lhs: &FusionTensor<C>,
+    rhs: &FusionTensor<C>,
+    func: fn(f64, f64) -> f64,
+) -> Option<f64> {
+    Some(func(lhs.constant?, rhs.constant?))
+}
+
+/// Round a float constant to the element type `E`, returning `None` when it can't be stored
+/// exactly by a full tensor, which is described with an `f32` value.
+///
+/// `f64` has more than twice the precision of the lower precision elements, so rounding the `f64`
+/// result of an addition, subtraction, multiplication or division of elements gives the result
+/// computed by the backend in `E`.
+pub(crate) fn float_constant<E: Element>(value: f64) -> Option<f64> {
+    let value = value.elem::<E>().elem::<f64>();
+    let stored = value as f32;
+
+    match stored as f64 == value {
+        true => Some(value),
+        false => None,
+    }
+}
+
+/// Check an int constant, returning `None` when it overflows the element type `E` or the `i32`
+/// value describing a full tensor, since the backend would wrap around instead.
+pub(crate) fn int_constant<E: Element>(value: f64) -> Option<f64> {
+    let max = match E::precision() {
+        Precision::Double | Precision::Full => i32::MAX as f64,
+        Precision::Half => i16::MAX as f64,
+        Precision::Other => i8::MAX as f64,
+    };
+
+    match value.abs() <= max {
+        true => Some(value),
+        false => None,
+    }
+}
@@ -3,6 +3,7 @@ use crate::{
     client::FusionClient,
     get_client,
     ops::binary::binary_ops_shape,
+    ops::constant::{float_constant, fold_binary, fold_scalar},
     scalar_float2int_ops, scalar_float_cmp_ops, scalar_float_ops,
     stream::{
         BaseOpsDescription, BinaryOpsDescription, CatOpsDescription, ClampOpsDescription,
@@ -83,7 +84,8 @@ impl<B: FusionBackend> TensorOps<Self> for Fusion<B> {
 
         let shape: Vec<usize> = shape.dims.into();
         let client = get_client::<B>(&device.clone().into());
-        let out = client.tensor_uninitialized(shape);
+        let mut out = client.tensor_uninitialized(shape);
+        out.constant = Some(0.0);
 
         let desc = out.to_description_out();
         client.register(
@@ -110,7 +112,8 @@ impl<B: FusionBackend> TensorOps<Self> for Fusion<B> {
 
         let shape: Vec<usize> = shape.dims.into();
         let client = get_client::<B>(&device.clone().into());
-        let out = client.tensor_uninitialized(shape);
+        let mut out = client.tensor_uninitialized(shape);
+        out.constant = Some(1.0);
 
         let desc = out.to_description_out();
         client.register(
@@ -143,7 +146,8 @@ impl<B: FusionBackend> TensorOps<Self> for Fusion<B> {
 
         let shape: Vec<usize> = shape.dims.into();
         let client = get_client::<B>(&device.clone().into());
-        let out = client.tensor_uninitialized(shape);
+        let mut out = client.tensor_uninitialized(shape);
+        out.constant = float_constant::<FloatElem<Self>>(fill_value.elem());
 
         let desc = (out.to_description_out(), fill_value.elem::<f32>());
         client.register(
@@ -227,6 +231,13 @@ impl<B: FusionBackend> TensorOps<Self> for Fusion<B> {
     ) -> FloatTensor<Self, D> {
         binary_float_ops!(AddOps, B::add);
 
+        if let Some(value) = fold_binary(&lhs, &rhs, |lhs, rhs| lhs + rhs)
+            .and_then(float_constant::<FloatElem<Self>>)
+        {
+            let shape = binary_ops_shape(&lhs.shape, &rhs.shape);
+            return Self::full::<D>(Shape::from(shape), value.elem(), &Self::device::<D>(&lhs));
+        }
+
         let out = lhs
             .client
             .tensor_uninitialized(binary_ops_shape(&lhs.shape, &rhs.shape));
@@ -250,6 +261,12 @@ impl<B: FusionBackend> TensorOps<Self> for Fusion<B> {
     ) -> FloatTensor<Self, D> {
         scalar_float_ops!(AddOps, B::add_scalar);
 
+        if let Some(value) = fold_scalar(&lhs, rhs.elem(), |lhs, rhs| lhs + rhs)
+            .and_then(float_constant::<FloatElem<Self>>)
+        {
+            return Self::full::<D>(lhs.shape::<D>(), value.elem(), &Self::device::<D>(&lhs));
+        }
+
         let out = lhs.client.tensor_uninitialized(lhs.shape.clone());
 
         let desc = ScalarOpsDescription {
@@ -306,6 +323,13 @@ impl<B: FusionBackend> TensorOps<Self> for Fusion<B> {
     ) -> FloatTensor<Self, D> {
         binary_float_ops!(SubOps, B::sub);
 
+        if let Some(value) = fold_binary(&lhs, &rhs, |lhs, rhs| lhs - rhs)
+            .and_then(float_constant::<FloatElem<Self>>)
+        {
+            let shape = binary_ops_shape(&lhs.shape, &rhs.shape);
+            return Self::full::<D>(Shape::from(shape), value.elem(), &Self::device::<D>(&lhs));
+        }
+
         let out = lhs
             .client
             .tensor_uninitialized(binary_ops_shape(&lhs.shape, &rhs.shape));
@@ -329,6 +353,12 @@ impl<B: FusionBackend> TensorOps<Self> for Fusion<B> {
     ) -> FloatTensor<Self, D> {
         scalar_float_ops!(SubOps, B::sub_scalar);
 
+        if let Some(value) = fold_scalar(&lhs, rhs.elem(), |lhs, rhs| lhs - rhs)
+            .and_then(float_constant::<FloatElem<Self>>)
+        {
+            return Self::full::<D>(lhs.shape::<D>(), value.elem(), &Self::device::<D>(&lhs));
+        }
+
         let out = lhs.client.tensor_uninitialized(lhs.shape.clone());
         let desc = ScalarOpsDescription {
             lhs: lhs.into_description(),
@@ -350,6 +380,13 @@ impl<B: FusionBackend> TensorOps<Self> for Fusion<B> {
     ) -> FloatTensor<Self, D> {
         binary_float_ops!(MulOps, B::mul);
 
+        if let Some(value) = fold_binary(&lhs, &rhs, |lhs, rhs| lhs * rhs)
+            .and_then(float_constant::<FloatElem<Self>>)
+        {
+            let shape = binary_ops_shape(&lhs.shape, &rhs.shape);
+            return Self::full::<D>(Shape::from(shape), value.elem(), &Self::device::<D>(&lhs));
+        }
+
         let out = lhs
             .client
             .tensor_uninitialized(binary_ops_shape(&lhs.shape, &rhs.shape));
@@ -373,6 +410,12 @@ impl<B: FusionBackend> TensorOps<Self> for Fusion<B> {
     ) -> FloatTensor<Self, D> {
         scalar_float_ops!(MulOps, B::mul_scalar);
 
+        if let Some(value) = fold_scalar(&lhs, rhs.elem(), |lhs, rhs| lhs * rhs)
+            .and_then(float_constant::<FloatElem<Self>>)
+        {
+            return Self::full::<D>(lhs.shape::<D>(), value.elem(), &Self::device::<D>(&lhs));
+        }
+
         let out = lhs.client.tensor_uninitialized(lhs.shape.clone());
 
         let desc = ScalarOpsDescription {
@@ -394,6 +437,13 @@ impl<B: FusionBackend> TensorOps<Self> for Fusion<B> {
     ) -> FloatTensor<Self, D> {
         binary_float_ops!(DivOps, B::div);
 
+        if let Some(value) = fold_binary(&lhs, &rhs, |lhs, rhs| lhs / rhs)
+            .and_then(float_constant::<FloatElem<Self>>)
+        {
+            let shape = binary_ops_shape(&lhs.shape, &rhs.shape);
+            return Self::full::<D>(Shape::from(shape), value.elem(), &Self::device::<D>(&lhs));
+        }
+
         let out = lhs
             .client
             .tensor_uninitialized(binary_ops_shape(&lhs.shape, &rhs.shape));
@@ -417,6 +467,12 @@ impl<B: FusionBackend> TensorOps<Self> for Fusion<B> {
     ) -> FloatTensor<Self, D> {
         scalar_float_ops!(DivOps, B::div_scalar);
 
+        if let Some(value) = fold_scalar(&lhs, rhs.elem(), |lhs, rhs| lhs / rhs)
+            .and_then(float_constant::<FloatElem<Self>>)
+        {
+            return Self::full::<D>(lhs.shape::<D>(), value.elem(), &Self::device::<D>(&lhs));
+        }
+
         let out = lhs.client.tensor_uninitialized(lhs.shape.clone());
 
         let desc = ScalarOpsDescription {
@@ -3,6 +3,7 @@ use crate::{
     client::FusionClient,
     get_client,
     ops::binary::binary_ops_shape,
+    ops::constant::{fold_binary, fold_scalar, int_constant},
     scalar_int_cmp_ops, scalar_int_ops,
     stream::{
         self, BaseOpsDescription, BinaryOpsDescription, CatOpsDescription, ClampOpsDescription,
@@ -691,6 +692,17 @@ impl<B: FusionBackend> IntTensorOps<Self> for Fusion<B> {
     ) -> IntTensor<Self, D> {
         binary_int_ops!(AddOps, B::int_add);
 
+        if let Some(value) =
+            fold_binary(&lhs, &rhs, |lhs, rhs| lhs + rhs).and_then(int_constant::<IntElem<Self>>)
+        {
+            let shape = binary_ops_shape(&lhs.shape, &rhs.shape);
+            return Self::int_full::<D>(
+                Shape::from(shape),
+                value.elem(),
+                &Self::int_device::<D>(&lhs),
+            );
+        }
+
         let out = lhs
             .client
             .tensor_uninitialized(binary_ops_shape(&lhs.shape, &rhs.shape));
@@ -714,6 +726,16 @@ impl<B: FusionBackend> IntTensorOps<Self> for Fusion<B> {
     ) -> IntTensor<Self, D> {
         scalar_int_ops!(AddOps, B::int_add_scalar);
 
+        if let Some(value) = fold_scalar(&lhs, rhs.elem(), |lhs, rhs| lhs + rhs)
+            .and_then(int_constant::<IntElem<Self>>)
+        {
+            return Self::int_full::<D>(
+                lhs.shape::<D>(),
+                value.elem(),
+                &Self::int_device::<D>(&lhs),
+            );
+        }
+
         let out = lhs.client.tensor_uninitialized(lhs.shape.clone());
 
         let desc = ScalarOpsDescription {
@@ -737,6 +759,17 @@ impl<B: FusionBackend> IntTensorOps<Self> for Fusion<B> {
     ) -> IntTensor<Self, D> {
         binary_int_ops!(SubOps, B::int_sub);
 
+        if let Some(value) =
+            fold_binary(&lhs, &rhs, |lhs, rhs| lhs - rhs).and_then(int_constant::<IntElem<Self>>)
+        {
+            let shape = binary_ops_shape(&lhs.shape, &rhs.shape);
+            return Self::int_full::<D>(
+                Shape::from(shape),
+                value.elem(),
+                &Self::int_device::<D>(&lhs),
+            );
+        }
+
         let out = lhs
             .client
             .tensor_uninitialized(binary_ops_shape(&lhs.shape, &rhs.shape));
@@ -760,6 +793,16 @@ impl<B: FusionBackend> IntTensorOps<Self> for Fusion<B> {
     ) -> IntTensor<Self, D> {
         scalar_int_ops!(SubOps, B::int_sub_scalar);
 
+        if let Some(value) = fold_scalar(&lhs, rhs.elem(), |lhs, rhs| lhs - rhs)
+            .and_then(int_constant::<IntElem<Self>>)
+        {
+            return Self::int_full::<D>(
+                lhs.shape::<D>(),
+                value.elem(),
+                &Self::int_device::<D>(&lhs),
+            );
+        }
+
         let out = lhs.client.tensor_uninitialized(lhs.shape.clone());
 
         let desc = ScalarOpsDescription {
@@ -783,6 +826,17 @@ impl<B: FusionBackend> IntTensorOps<Self> for Fusion<B> {
     ) -> IntTensor<Self, D> {
         binary_int_ops!(MulOps, B::int_mul);
 
+        if let Some(value) =
+            fold_binary(&lhs, &rhs, |lhs, rhs| lhs * rhs).and_then(int_constant::<IntElem<Self>>)
+        {
+            let shape = binary_ops_shape(&lhs.shape, &rhs.shape);
+            return Self::int_full::<D>(
+                Shape::from(shape),
+                value.elem(),
+                &Self::int_device::<D>(&lhs),
+            );
+        }
+
         let out = lhs
             .client
             .tensor_uninitialized(binary_ops_shape(&lhs.shape, &rhs.shape));
@@ -806,6 +860,16 @@ impl<B: FusionBackend> IntTensorOps<Self> for Fusion<B> {
     ) -> IntTensor<Self, D> {
         scalar_int_ops!(MulOps, B::int_mul_scalar);
 
+        if let Some(value) = fold_scalar(&lhs, rhs.elem(), |lhs, rhs| lhs * rhs)
+            .and_then(int_constant::<IntElem<Self>>)
+        {
+            return Self::int_full::<D>(
+                lhs.shape::<D>(),
+                value.elem(),
+                &Self::int_device::<D>(&lhs),
+            );
+        }
+
         let out = lhs.client.tensor_uninitialized(lhs.shape.clone());
 
         let desc = ScalarOpsDescription {
@@ -885,7 +949,8 @@ impl<B: FusionBackend> IntTensorOps<Self> for Fusion<B> {
 
         let shape: Vec<usize> = shape.dims.into();
         let client = get_client::<B>(&device.clone().into());
-        let out = client.tensor_uninitialized(shape);
+        let mut out = client.tensor_uninitialized(shape);
+        out.constant = Some(0.0);
         let desc = out.to_description_out();
         client.register(
             TensorOpsDescription::NumericOpsInt(NumericOpsDescription::Zeros(desc.clone())),
@@ -911,7 +976,8 @@ impl<B: FusionBackend> IntTensorOps<Self> for Fusion<B> {
 
         let shape: Vec<usize> = shape.dims.into();
         let client = get_client::<B>(&device.clone().into());
-        let out = client.tensor_uninitialized(shape);
+        let mut out = client.tensor_uninitialized(shape);
+        out.constant = Some(1.0);
 
         let desc = out.to_description_out();
         client.register(
@@ -922,6 +988,40 @@ impl<B: FusionBackend> IntTensorOps<Self> for Fusion<B> {
         out
     }
 
+    fn int_full<const D: usize>(
+        shape: Shape<D>,
+        fill_value: IntElem<Self>,
+        device: &Device<Self>,
+    ) -> IntTensor<Self, D> {
+        #[derive(new)]
+        struct FullOps<const D: usize> {
+            out: TensorDescription,
+            elem: i32,
+        }
+
+        impl<const D: usize, B: FusionBackend> Ops<B> for FullOps<D> {
+            fn execute(self: Box<Self>, handles: &mut crate::HandleContainer<B>) {
+                let shape = Shape::from(self.out.shape.clone());
+                let output: B::IntTensorPrimitive<D> =
+                    B::int_full(shape, self.elem.elem(), &handles.device);
+                handles.register_int_tensor(&self.out.id, output);
+            }
+        }
+
+        let shape: Vec<usize> = shape.dims.into();
+        let client = get_client::<B>(&device.clone().into());
+        let mut out = client.tensor_uninitialized(shape);
+        out.constant = int_constant::<IntElem<Self>>(fill_value.elem());
+
+        let desc = (out.to_description_out(), fill_value.elem::<i32>());
+        client.register(
+            TensorOpsDescription::NumericOpsInt(NumericOpsDescription::Full(desc.clone())),
+            FullOps::<D>::new(desc.0, desc.1),
+        );
+
+        out
+    }
+
     fn int_sum<const D: usize>(tensor: IntTensor<Self, D>) -> IntTensor<Self, 1> {
         unary_int_ops!(SumOps, B::int_sum);
 
@@ -1,6 +1,7 @@
 mod activation;
 mod binary;
 mod boolean;
+mod constant;
 mod float;
 mod int;
 mod module;
@@ -1,9 +1,14 @@
 use crate::{
-    stream::{MultiStream, Ops, TensorOpsDescription},
-    FusionBackend, HandleContainer, TensorId,
+    stream::{BaseOpsDescription, MultiStream, Ops, TensorOpsDescription},
+    FusionBackend, FusionCheckpoint, FusionConfig, FusionMetrics, FusionMode, HandleContainer,
+    OptimizationFactory, TensorDescription, TensorId, TensorStatus,
 };
 use burn_tensor::ops::{FloatElem, IntElem};
-use std::sync::Arc;
+use std::{
+    io,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
 
 pub struct FusionServer<B>
 where
@@ -34,6 +39,46 @@ where
         self.streams.drain(&mut self.handles)
     }
 
+    pub fn configure(&mut self, config: FusionConfig) {
+        self.streams.configure(config, &mut self.handles)
+    }
+
+    pub fn set_execution_mode(&mut self, mode: FusionMode) {
+        self.streams.set_execution_mode(mode, &mut self.handles)
+    }
+
+    pub fn register_optimization(&mut self, factory: Box<dyn OptimizationFactory<B>>) {
+        self.streams
+            .register_optimization(factory, &mut self.handles)
+    }
+
+    pub fn metrics(&self) -> FusionMetrics {
+        FusionMetrics {
+            handles: self.handles.num_handles() as u64,
+            ..self.streams.metrics()
+        }
+    }
+
+    pub fn save_optimizations(&self, path: &Path) -> Result<(), io::Error> {
+        self.streams.save_optimizations(path)
+    }
+
+    pub fn load_optimizations(&mut self, path: &Path) -> Result<usize, io::Error> {
+        self.streams.load_optimizations(path, &mut self.handles)
+    }
+
+    pub fn persist_optimizations(&mut self, path: PathBuf) -> Result<(), io::Error> {
+        self.streams.persist_optimizations(path, &mut self.handles)
+    }
+
+    pub fn checkpoint(&mut self) -> FusionCheckpoint {
+        self.streams.checkpoint(&mut self.handles)
+    }
+
+    pub fn restore(&mut self, checkpoint: &FusionCheckpoint) -> Result<usize, io::Error> {
+        self.streams.restore(checkpoint, &mut self.handles)
+    }
+
     pub fn create_empty_handle(&mut self) -> Arc<TensorId> {
         self.handles.create_tensor_uninit()
     }
@@ -74,56 +119,156 @@ where
         B::bool_into_data(tensor)
     }
 
-    pub fn change_server_float<const D: usize>(
+    /// Take a float tensor to move it to another device.
+    ///
+    /// Only the streams using the tensor are executed, the other ones stay lazy so their
+    /// computation can overlap with the transfer.
+    pub fn take_float_tensor<const D: usize>(
+        &mut self,
+        tensor: &TensorDescription,
+    ) -> B::TensorPrimitive<D> {
+        self.streams
+            .drain_dependencies(&tensor.id, &mut self.handles);
+        self.handles.get_float_tensor::<D>(tensor)
+    }
+
+    /// Take an int tensor to move it to another device.
+    ///
+    /// Only the streams using the tensor are executed, the other ones stay lazy so their
+    /// computation can overlap with the transfer.
+    pub fn take_int_tensor<const D: usize>(
         &mut self,
-        tensor: &crate::TensorDescription,
-        device: &B::Device,
-        server_device: &mut Self,
+        tensor: &TensorDescription,
+    ) -> B::IntTensorPrimitive<D> {
+        self.streams
+            .drain_dependencies(&tensor.id, &mut self.handles);
+        self.handles.get_int_tensor::<D>(tensor)
+    }
+
+    /// Take a bool tensor to move it to another device.
+    ///
+    /// Only the streams using the tensor are executed, the other ones stay lazy so their
+    /// computation can overlap with the transfer.
+    pub fn take_bool_tensor<const D: usize>(
+        &mut self,
+        tensor: &TensorDescription,
+    ) -> B::BoolTensorPrimitive<D> {
+        self.streams
+            .drain_dependencies(&tensor.id, &mut self.handles);
+        self.handles.get_bool_tensor::<D>(tensor)
+    }
+
+    /// Register the transfer of a float tensor of another device as a lazy operation, executed
+    /// with the operations reading it.
+    pub fn register_float_transfer<const D: usize>(
+        &mut self,
+        tensor: B::TensorPrimitive<D>,
+        shape: Vec<usize>,
     ) -> Arc<TensorId> {
-        let tensor = self.handles.get_float_tensor::<D>(tensor);
-        let tensor = B::to_device(tensor, device);
-        let id = server_device.create_empty_handle();
+        let (id, out) = self.transfer_output(shape);
+        let ops = ToDeviceFloatOps::<B, D>::new(out.clone(), tensor, self.device.clone().into());
 
-        server_device
-            .handles
-            .register_float_tensor(&id, tensor.clone());
+        self.register(
+            TensorOpsDescription::BaseOpsFloat(BaseOpsDescription::ToDevice(out)),
+            Box::new(ops),
+        );
 
         id
     }
-    pub fn change_server_int<const D: usize>(
+
+    /// Register the transfer of an int tensor of another device as a lazy operation, executed
+    /// with the operations reading it.
+    pub fn register_int_transfer<const D: usize>(
         &mut self,
-        tensor: &crate::TensorDescription,
-        device: &B::Device,
-        server_device: &mut Self,
+        tensor: B::IntTensorPrimitive<D>,
+        shape: Vec<usize>,
     ) -> Arc<TensorId> {
-        let tensor = self.handles.get_int_tensor::<D>(tensor);
-        let tensor = B::int_to_device(tensor, device);
-        let id = server_device.create_empty_handle();
+        let (id, out) = self.transfer_output(shape);
+        let ops = ToDeviceIntOps::<B, D>::new(out.clone(), tensor, self.device.clone().into());
 
-        server_device
-            .handles
-            .register_int_tensor(&id, tensor.clone());
+        self.register(
+            TensorOpsDescription::BaseOpsInt(BaseOpsDescription::ToDevice(out)),
+            Box::new(ops),
+        );
 
         id
     }
-    pub fn change_server_bool<const D: usize>(
+
+    /// Register the transfer of a bool tensor of another device as a lazy operation, executed
+    /// with the operations reading it.
+    pub fn register_bool_transfer<const D: usize>(
         &mut self,
-        tensor: &crate::TensorDescription,
-        device: &B::Device,
-        server_device: &mut Self,
+        tensor: B::BoolTensorPrimitive<D>,
+        shape: Vec<usize>,
     ) -> Arc<TensorId> {
-        let tensor = self.handles.get_bool_tensor::<D>(tensor);
-        let tensor = B::bool_to_device(tensor, device);
-        let id = server_device.create_empty_handle();
+        let (id, out) = self.transfer_output(shape);
+        let ops = ToDeviceBoolOps::<B, D>::new(out.clone(), tensor, self.device.clone().into());
 
-        server_device
-            .handles
-            .register_bool_tensor(&id, tensor.clone());
+        self.register(
+            TensorOpsDescription::BaseOpsBool(BaseOpsDescription::ToDevice(out)),
+            Box::new(ops),
+        );
 
         id
     }
 
+    fn transfer_output(&mut self, shape: Vec<usize>) -> (Arc<TensorId>, TensorDescription) {
+        let id = self.create_empty_handle();
+        let out = TensorDescription {
+            id: id.as_ref().clone(),
+            shape,
+            status: TensorStatus::NotInit,
+        };
+
+        (id, out)
+    }
+
     pub fn drop_tensor_handle(&mut self, id: TensorId) {
         self.handles.handles_orphan.push(id);
     }
 }
+
+/// Move a float tensor of another device to the device of the stream.
+#[derive(new)]
+struct ToDeviceFloatOps<B: FusionBackend, const D: usize> {
+    out: TensorDescription,
+    tensor: B::TensorPrimitive<D>,
+    device: B::Device,
+}
+
+impl<B: FusionBackend, const D: usize> Ops<B> for ToDeviceFloatOps<B, D> {
+    fn execute(self: Box<Self>, handles: &mut HandleContainer<B>) {
+        let output = B::to_device(self.tensor, &self.device);
+        handles.register_float_tensor(&self.out.id, output);
+    }
+}
+
+/// Move an int tensor of another device to the device of the stream.
+#[derive(new)]
+struct ToDeviceIntOps<B: FusionBackend, const D: usize> {
+    out: TensorDescription,
+    tensor: B::IntTensorPrimitive<D>,
+    device: B::Device,
+}
+
+impl<B: FusionBackend, const D: usize> Ops<B> for ToDeviceIntOps<B, D> {
+    fn execute(self: Box<Self>, handles: &mut HandleContainer<B>) {
+        let output = B::int_to_device(self.tensor, &self.device);
+        handles.register_int_tensor(&self.out.id, output);
+    }
+}
+
+/// Move a bool tensor of another device to the device of the stream.
+#[derive(new)]
+struct ToDeviceBoolOps<B: FusionBackend, const D: usize> {
+    out: TensorDescription,
+    tensor: B::BoolTensorPrimitive<D>,
+    device: B::Device,
+}
+
+impl<B: FusionBackend, const D: usize> Ops<B> for ToDeviceBoolOps<B, D> {
+    fn execute(self: Box<Self>, handles: &mut HandleContainer<B>) {
+        let output = B::bool_to_device(self.tensor, &self.device);
+        handles.register_bool_tensor(&self.out.id, output);
+    }
+}
@@ -1,7 +1,11 @@
+use super::dot_directory;
+use super::store::SizeKey;
 use super::Ops;
 use super::RelativeStreamConverter;
+use super::Signature;
 use super::TensorOpsDescription;
-use crate::FusionBackend;
+use crate::{FusionBackend, FusionConfig, TensorId, TensorStatus};
+use std::path::PathBuf;
 
 /// A growing list of [tensor operation descriptions](TensorOpsDescription).
 pub struct Stream<B: FusionBackend> {
@@ -9,6 +13,12 @@ pub struct Stream<B: FusionBackend> {
     pub(crate) relative: Vec<TensorOpsDescription>,
     pub(crate) converter: RelativeStreamConverter,
     pub(crate) ops: Vec<Box<dyn Ops<B>>>,
+    /// The signature of each operation, used to eliminate common subexpressions.
+    pub(crate) signatures: Vec<Option<Signature>>,
+    /// The file where the executed operations are [dumped](super::StreamDump), if any.
+    pub(crate) dump_path: Option<PathBuf>,
+    /// The directory where the executed operations are written as DOT graphs, if any.
+    pub(crate) dot_directory: Option<PathBuf>,
 }
 
 impl<B: FusionBackend> Stream<B> {
@@ -18,9 +28,19 @@ impl<B: FusionBackend> Stream<B> {
             relative: Vec::new(),
             converter: RelativeStreamConverter::default(),
             ops: Vec::new(),
+            signatures: Vec::new(),
+            dump_path: None,
+            dot_directory: dot_directory(),
         }
     }
 
+    /// Apply the [configuration](FusionConfig) of the device, only when the stream is empty since
+    /// it changes how the operations are converted.
+    pub(crate) fn configure(&mut self, config: &FusionConfig) {
+        self.converter.bucket_scalars = config.bucket_scalars;
+        self.dump_path = config.dump_streams.clone();
+    }
+
     pub(crate) fn split_relative_stream(
         &self,
     ) -> (&[TensorOpsDescription], Option<&TensorOpsDescription>) {
@@ -32,11 +52,35 @@ impl<B: FusionBackend> Stream<B> {
         (&self.relative[0..len - 1], self.relative.last())
     }
 
+    /// Add an operation to the stream.
+    ///
+    /// When a pending operation computes the same values, the operation is replaced by an
+    /// [alias](super::BaseOpsDescription::Alias) of its outputs before being converted to the
+    /// relative stream, so that the duplicated work is never executed nor explored.
     pub(crate) fn add(&mut self, global: TensorOpsDescription, ops: Box<dyn Ops<B>>) {
+        let signature = Signature::new(&global);
+
+        if let Some(signature) = &signature {
+            if let Some((alias, ops)) = self.eliminate_common_subexpression(&global, signature) {
+                self.push(alias, ops, None);
+                return;
+            }
+        }
+
+        self.push(global, ops, signature);
+    }
+
+    fn push(
+        &mut self,
+        global: TensorOpsDescription,
+        ops: Box<dyn Ops<B>>,
+        signature: Option<Signature>,
+    ) {
         let relative = global.to_relative(&mut self.converter);
         self.relative.push(relative);
         self.global.push(global);
         self.ops.push(ops);
+        self.signatures.push(signature);
     }
 
     /// The size of the stream.
@@ -44,6 +88,36 @@ impl<B: FusionBackend> Stream<B> {
         self.global.len()
     }
 
+    /// The number of elements of the tensors created by the pending operations of the stream.
+    pub(crate) fn num_created_elements(&self) -> usize {
+        self.num_elements_created_by(self.len())
+    }
+
+    /// The [size key](SizeKey) of the first operations of the stream, used to compare the
+    /// execution times of the optimizations executing them.
+    pub(crate) fn size_key(&self, num_ops: usize) -> SizeKey {
+        self.num_elements_created_by(num_ops)
+            .next_power_of_two()
+            .trailing_zeros()
+    }
+
+    fn num_elements_created_by(&self, num_ops: usize) -> usize {
+        self.global[0..num_ops]
+            .iter()
+            .flat_map(|desc| desc.nodes())
+            .filter(|tensor| tensor.status == TensorStatus::NotInit)
+            .map(|tensor| tensor.shape.iter().product::<usize>())
+            .sum()
+    }
+
+    /// If any of the pending operations of the stream uses one of the given tensors.
+    pub(crate) fn uses_any(&self, ids: &[&TensorId]) -> bool {
+        self.global
+            .iter()
+            .flat_map(|desc| desc.nodes())
+            .any(|tensor| ids.contains(&&tensor.id))
+    }
+
     /// If the stream is empty.
     pub(crate) fn is_empty(&self) -> bool {
         self.len() == 0
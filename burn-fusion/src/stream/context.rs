@@ -1,6 +1,6 @@
 use super::{
     AdaptiveAvgPool1dBackwardDescription, AdaptiveAvgPool1dDescription,
-    AdaptiveAvgPool2dBackwardDescription, AdaptiveAvgPool2dDescription,
+    AdaptiveAvgPool2dBackwardDescription, AdaptiveAvgPool2dDescription, AliasDescription,
     AvgPool2dBackwardDescription, AvgPool2dDescription, BaseOpsDescription, BinaryOpsDescription,
     BoolOpsDescription, ClampOpsDescription, Conv1dDescription, Conv2dDescription,
     ConvTranspose1dDescription, ConvTranspose2dDescription, EmbeddingBackwardDescription,
@@ -13,7 +13,7 @@ use super::{
     SelectOpsDescription, SliceOpsDescription, SwapDimsDescription, TensorOpsDescription,
     UnaryOpsDescription,
 };
-use crate::{FusionBackend, HandleContainer, TensorDescription, TensorId};
+use crate::{FusionBackend, HandleContainer, TensorDescription, TensorId, TensorStatus};
 use burn_tensor::{Element, ElementConversion};
 use hashbrown::HashMap;
 
@@ -37,13 +37,49 @@ pub struct Context<'a, B: FusionBackend> {
 
 #[derive(Default)]
 pub(crate) struct RelativeStreamConverter {
-    tensors_relative2global: HashMap<TensorId, TensorDescription>,
+    pub(crate) tensors_relative2global: HashMap<TensorId, TensorDescription>,
     tensors_global2relative: HashMap<TensorId, TensorDescription>,
     /// Only useful to create new shape ID.
     /// You should use tensor descriptions to retrieve the proper shape.
     shapes_global2relative: HashMap<usize, usize>,
-    scalar_floats: Vec<f32>,
-    scalar_ints: Vec<i32>,
+    pub(crate) scalar_floats: Vec<f32>,
+    pub(crate) scalar_ints: Vec<i32>,
+    /// Set the status of all relative tensors to read only, so that the same operation on the same
+    /// tensors has the same relative description no matter when the tensors are last used.
+    ignore_status: bool,
+    /// Keep the [bucket](bucket_scalar) of the scalars in the relative descriptions instead of
+    /// zero.
+    pub(crate) bucket_scalars: bool,
+}
+
+/// The bound of the integers kept exactly in the relative streams when the scalars are
+/// [bucketed](crate::FusionConfig::bucket_scalars).
+pub const MAX_EXACT_SCALAR: f64 = 16.0;
+
+/// The value representing a scalar in the relative streams when the scalars are
+/// [bucketed](crate::FusionConfig::bucket_scalars).
+///
+/// - Integers lower than [MAX_EXACT_SCALAR] in absolute value are kept as is.
+/// - Other values lower than [MAX_EXACT_SCALAR] in absolute value are bucketed by unit interval,
+///   represented by its middle so they are never mistaken for an exact integer.
+/// - Larger values are bucketed by power of two, represented by the lower bound so it can be
+///   converted back to the type of the scalar.
+/// - NaN values are bucketed with the positive infinity.
+pub fn bucket_scalar(value: f64) -> f64 {
+    if value.is_nan() {
+        return f64::INFINITY;
+    }
+
+    let magnitude = value.abs();
+
+    if magnitude < MAX_EXACT_SCALAR {
+        return match value.fract() == 0.0 {
+            true => value,
+            false => value.floor() + 0.5,
+        };
+    }
+
+    value.signum() * f64::powf(2.0, magnitude.log2().floor())
 }
 
 impl RelativeStreamConverter {
@@ -59,6 +95,14 @@ impl RelativeStreamConverter {
         }
     }
 
+    /// Create a converter [ignoring](RelativeStreamConverter::ignore_status) the tensor statuses.
+    pub(crate) fn ignoring_status() -> Self {
+        Self {
+            ignore_status: true,
+            ..Default::default()
+        }
+    }
+
     pub(crate) fn clear(&mut self) {
         self.tensors_relative2global.clear();
         self.tensors_global2relative.clear();
@@ -69,16 +113,21 @@ impl RelativeStreamConverter {
 
     pub(crate) fn relative_float<E: Element>(&mut self, elem: &E) -> E {
         self.scalar_floats.push(elem.elem());
-        // We return 0 so that the id from a scalar operation is the same no matter its scalar
-        // value.
-        0.elem()
+        self.relative_scalar(elem)
     }
 
     pub(crate) fn relative_int<E: Element>(&mut self, elem: &E) -> E {
         self.scalar_ints.push(elem.elem());
-        // We return 0 so that the id from a scalar operation is the same no matter its scalar
-        // value.
-        0.elem()
+        self.relative_scalar(elem)
+    }
+
+    fn relative_scalar<E: Element>(&self, elem: &E) -> E {
+        match self.bucket_scalars {
+            true => bucket_scalar(elem.elem()).elem(),
+            // We return 0 so that the id from a scalar operation is the same no matter its scalar
+            // value.
+            false => 0.elem(),
+        }
     }
 }
 
@@ -741,10 +790,25 @@ impl BaseOpsDescription {
                 dim: desc.dim,
                 out: desc.out.to_relative(converter),
             }),
+            BaseOpsDescription::Alias(desc) => BaseOpsDescription::Alias(AliasDescription {
+                inputs: to_relative_all(&desc.inputs, converter),
+                outs: to_relative_all(&desc.outs, converter),
+                released: to_relative_all(&desc.released, converter),
+            }),
         }
     }
 }
 
+fn to_relative_all(
+    tensors: &[TensorDescription],
+    converter: &mut RelativeStreamConverter,
+) -> Vec<TensorDescription> {
+    tensors
+        .iter()
+        .map(|tensor| tensor.to_relative(converter))
+        .collect()
+}
+
 impl TensorDescription {
     pub(crate) fn to_relative(&self, converter: &mut RelativeStreamConverter) -> Self {
         let relative_id = if let Some(value) = converter.tensors_global2relative.get(&self.id) {
@@ -770,11 +834,16 @@ impl TensorDescription {
             }
         }
 
+        let status = match converter.ignore_status {
+            true => TensorStatus::ReadOnly,
+            false => self.status.clone(),
+        };
+
         // We create the relative tensor.
         let relative_tensor = TensorDescription {
             id: relative_id.clone(),
             shape: relative_shape,
-            status: self.status.clone(),
+            status,
         };
 
         // We update both mappings.
@@ -828,4 +897,39 @@ mod tests {
             }
         );
     }
+
+    #[test]
+    fn scalars_are_zeroed_by_default() {
+        let mut converter = RelativeStreamConverter::default();
+
+        assert_eq!(converter.relative_float(&2.0f32), 0.0);
+        assert_eq!(converter.relative_int(&3i32), 0);
+        assert_eq!(converter.scalar_floats, vec![2.0]);
+        assert_eq!(converter.scalar_ints, vec![3]);
+    }
+
+    #[test]
+    fn bucketed_scalars_keep_small_integers() {
+        let mut converter = RelativeStreamConverter {
+            bucket_scalars: true,
+            ..Default::default()
+        };
+
+        assert_eq!(converter.relative_float(&2.0f32), 2.0);
+        assert_eq!(converter.relative_float(&-15.0f32), -15.0);
+        assert_eq!(converter.relative_int(&3i32), 3);
+        assert_eq!(converter.scalar_floats, vec![2.0, -15.0]);
+    }
+
+    #[test]
+    fn bucketed_scalars_group_other_values() {
+        assert_eq!(bucket_scalar(0.1), 0.5);
+        assert_eq!(bucket_scalar(0.9), 0.5);
+        assert_eq!(bucket_scalar(-0.1), -0.5);
+        assert_eq!(bucket_scalar(2.7), 2.5);
+        assert_eq!(bucket_scalar(17.0), 16.0);
+        assert_eq!(bucket_scalar(-1000.0), -512.0);
+        assert_eq!(bucket_scalar(i32::MIN as f64), i32::MIN as f64);
+        assert_eq!(bucket_scalar(f64::NAN), f64::INFINITY);
+    }
 }
@@ -0,0 +1,252 @@
+use super::{
+    AliasDescription, BaseOpsDescription, Ops, RelativeStreamConverter, Stream,
+    TensorOpsDescription,
+};
+use crate::{FusionBackend, HandleContainer, TensorDescription, TensorId, TensorStatus};
+
+/// The values computed by an operation, used to find common subexpressions in a stream.
+///
+/// Two operations have the same signature when they are of the same kind, with the same
+/// parameters and scalars, and are applied on the same tensors.
+#[derive(Debug, PartialEq)]
+pub(crate) struct Signature {
+    inputs: Vec<TensorId>,
+    shapes: Vec<Vec<usize>>,
+    relative: TensorOpsDescription,
+    scalar_floats: Vec<f32>,
+    scalar_ints: Vec<i32>,
+}
+
+impl Signature {
+    /// Create the signature of an operation, `None` if the operation can't be deduplicated.
+    ///
+    /// Operations without inputs, such as random, or without outputs are never deduplicated.
+    pub(crate) fn new(desc: &TensorOpsDescription) -> Option<Self> {
+        let mut converter = RelativeStreamConverter::ignoring_status();
+        let relative = desc.to_relative(&mut converter);
+        let tensors = global_tensors(&converter);
+
+        let inputs = tensors
+            .iter()
+            .filter(|tensor| tensor.status != TensorStatus::NotInit)
+            .map(|tensor| tensor.id.clone())
+            .collect::<Vec<_>>();
+
+        if inputs.is_empty() || inputs.len() == tensors.len() {
+            return None;
+        }
+
+        Some(Self {
+            inputs,
+            shapes: tensors.iter().map(|tensor| tensor.shape.clone()).collect(),
+            relative,
+            scalar_floats: converter.scalar_floats,
+            scalar_ints: converter.scalar_ints,
+        })
+    }
+}
+
+/// Set the handles of the outputs of a duplicated operation to the ones of the pending operation.
+struct AliasOps {
+    desc: AliasDescription,
+}
+
+impl<B: FusionBackend> Ops<B> for AliasOps {
+    fn execute(self: Box<Self>, handles: &mut HandleContainer<B>) {
+        for (input, out) in self.desc.inputs.iter().zip(self.desc.outs.iter()) {
+            let handle = handles.get_handle(&input.id, &input.status);
+            handles.register_handle(out.id.clone(), handle);
+        }
+
+        // The tensors used for the last time by the duplicated operation are never consumed.
+        for tensor in self.desc.released.iter() {
+            handles.free(tensor);
+        }
+    }
+}
+
+impl<B: FusionBackend> Stream<B> {
+    /// Replace the operation by an [alias](BaseOpsDescription::Alias) if a pending operation
+    /// computes the same values and its outputs are still available.
+    pub(crate) fn eliminate_common_subexpression(
+        &self,
+        desc: &TensorOpsDescription,
+        signature: &Signature,
+    ) -> Option<(TensorOpsDescription, Box<dyn Ops<B>>)> {
+        let index = self
+            .signatures
+            .iter()
+            .enumerate()
+            .filter(|(_, other)| other.as_ref() == Some(signature))
+            .map(|(index, _)| index)
+            .find(|index| !self.outputs_consumed(*index))?;
+
+        let desc = AliasDescription {
+            inputs: outputs(&self.global[index])
+                .into_iter()
+                .map(|tensor| TensorDescription {
+                    status: TensorStatus::ReadOnly,
+                    ..tensor
+                })
+                .collect(),
+            outs: outputs(desc),
+            released: desc
+                .nodes()
+                .into_iter()
+                .filter(|tensor| tensor.status == TensorStatus::ReadWrite)
+                .cloned()
+                .collect(),
+        };
+        let ops = Box::new(AliasOps { desc: desc.clone() });
+
+        Some((with_kind(desc_kind(&self.global[index]), desc), ops))
+    }
+
+    /// If an output of the operation at the given index is used for the last time by a following
+    /// pending operation, in which case its handle might not exist anymore.
+    fn outputs_consumed(&self, index: usize) -> bool {
+        let outputs = outputs(&self.global[index]);
+
+        self.global[index + 1..]
+            .iter()
+            .flat_map(|desc| desc.nodes())
+            .filter(|tensor| tensor.status == TensorStatus::ReadWrite)
+            .any(|tensor| outputs.iter().any(|output| output.id == tensor.id))
+    }
+}
+
+/// The global tensors of the converted operations, sorted by relative id.
+fn global_tensors(converter: &RelativeStreamConverter) -> Vec<TensorDescription> {
+    let mut tensors = converter.tensors_relative2global.iter().collect::<Vec<_>>();
+    tensors.sort_by_key(|(id, _)| *id);
+
+    tensors
+        .into_iter()
+        .map(|(_, tensor)| tensor.clone())
+        .collect()
+}
+
+/// The outputs of the operation, in the same order for operations with the same signature.
+fn outputs(desc: &TensorOpsDescription) -> Vec<TensorDescription> {
+    let mut converter = RelativeStreamConverter::default();
+    desc.to_relative(&mut converter);
+
+    global_tensors(&converter)
+        .into_iter()
+        .filter(|tensor| tensor.status == TensorStatus::NotInit)
+        .collect()
+}
+
+enum Kind {
+    Float,
+    Int,
+    Bool,
+}
+
+fn desc_kind(desc: &TensorOpsDescription) -> Kind {
+    match desc {
+        TensorOpsDescription::BaseOpsInt(_)
+        | TensorOpsDescription::NumericOpsInt(_)
+        | TensorOpsDescription::IntOps(_) => Kind::Int,
+        TensorOpsDescription::BaseOpsBool(_) | TensorOpsDescription::BoolOps(_) => Kind::Bool,
+        TensorOpsDescription::BaseOpsFloat(_)
+        | TensorOpsDescription::NumericOpsFloat(_)
+        | TensorOpsDescription::FloatOps(_)
+        | TensorOpsDescription::ModuleOps(_) => Kind::Float,
+    }
+}
+
+fn with_kind(kind: Kind, desc: AliasDescription) -> TensorOpsDescription {
+    let desc = BaseOpsDescription::Alias(desc);
+
+    match kind {
+        Kind::Float => TensorOpsDescription::BaseOpsFloat(desc),
+        Kind::Int => TensorOpsDescription::BaseOpsInt(desc),
+        Kind::Bool => TensorOpsDescription::BaseOpsBool(desc),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stream::{
+        FloatOpsDescription, NumericOpsDescription, ScalarOpsDescription, UnaryOpsDescription,
+    };
+
+    #[test]
+    fn same_operation_on_same_input_has_same_signature() {
+        let exp1 = exp(
+            tensor(0, TensorStatus::ReadOnly),
+            tensor(1, TensorStatus::NotInit),
+        );
+        let exp2 = exp(
+            tensor(0, TensorStatus::ReadWrite),
+            tensor(2, TensorStatus::NotInit),
+        );
+
+        assert!(Signature::new(&exp1).is_some());
+        assert_eq!(Signature::new(&exp1), Signature::new(&exp2));
+    }
+
+    #[test]
+    fn same_operation_on_different_inputs_has_different_signatures() {
+        let exp1 = exp(
+            tensor(0, TensorStatus::ReadOnly),
+            tensor(1, TensorStatus::NotInit),
+        );
+        let exp2 = exp(
+            tensor(3, TensorStatus::ReadOnly),
+            tensor(2, TensorStatus::NotInit),
+        );
+
+        assert_ne!(Signature::new(&exp1), Signature::new(&exp2));
+    }
+
+    #[test]
+    fn same_operation_with_different_scalars_has_different_signatures() {
+        let add1 = add_scalar(
+            tensor(0, TensorStatus::ReadOnly),
+            1.0,
+            tensor(1, TensorStatus::NotInit),
+        );
+        let add2 = add_scalar(
+            tensor(0, TensorStatus::ReadOnly),
+            2.0,
+            tensor(2, TensorStatus::NotInit),
+        );
+
+        assert_ne!(Signature::new(&add1), Signature::new(&add2));
+    }
+
+    #[test]
+    fn outputs_follow_the_signature_order() {
+        let exp = exp(
+            tensor(0, TensorStatus::ReadOnly),
+            tensor(1, TensorStatus::NotInit),
+        );
+
+        assert_eq!(outputs(&exp), vec![tensor(1, TensorStatus::NotInit)]);
+    }
+
+    fn exp(input: TensorDescription, out: TensorDescription) -> TensorOpsDescription {
+        TensorOpsDescription::FloatOps(FloatOpsDescription::Exp(UnaryOpsDescription { input, out }))
+    }
+
+    fn add_scalar(
+        lhs: TensorDescription,
+        rhs: f32,
+        out: TensorDescription,
+    ) -> TensorOpsDescription {
+        TensorOpsDescription::NumericOpsFloat(NumericOpsDescription::AddScalar(
+            ScalarOpsDescription { lhs, rhs, out },
+        ))
+    }
+
+    fn tensor(id: u64, status: TensorStatus) -> TensorDescription {
+        TensorDescription {
+            id: TensorId::new(id),
+            shape: vec![32, 32],
+            status,
+        }
+    }
+}
@@ -0,0 +1,135 @@
+use super::{Stream, TensorOpsDescription};
+use crate::{FusionBackend, TensorStatus};
+use std::{
+    fmt::Write,
+    path::{Path, PathBuf},
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+/// Environment variable with the directory where each executed stream is written as a DOT graph.
+pub const DOT_DIRECTORY_ENV: &str = "BURN_FUSION_DOT";
+
+static NUM_DOT_FILES: AtomicUsize = AtomicUsize::new(0);
+
+impl<B: FusionBackend> Stream<B> {
+    /// Render the pending operations of the stream as a [DOT](https://graphviz.org/doc/info/lang.html)
+    /// graph, where the operations are linked by the tensors they use.
+    pub fn to_dot(&self) -> String {
+        dot(&self.global, None)
+    }
+}
+
+/// The directory set with the [environment variable](DOT_DIRECTORY_ENV), if any.
+pub(crate) fn dot_directory() -> Option<PathBuf> {
+    std::env::var_os(DOT_DIRECTORY_ENV).map(PathBuf::from)
+}
+
+/// Write the graph of executed operations in a new file of the directory.
+///
+/// The first `num_fused` operations were executed with an optimization, they are grouped in a
+/// cluster so the fusion boundary is visible.
+pub(crate) fn write_dot(
+    directory: &Path,
+    operations: &[TensorOpsDescription],
+    num_fused: Option<usize>,
+) {
+    let index = NUM_DOT_FILES.fetch_add(1, Ordering::Relaxed);
+    let path = directory.join(format!("stream-{index}.dot"));
+
+    if let Err(err) = std::fs::write(&path, dot(operations, num_fused)) {
+        log::warn!("Unable to write the fusion stream graph to {path:?}: {err}");
+    }
+}
+
+fn dot(operations: &[TensorOpsDescription], num_fused: Option<usize>) -> String {
+    let mut graph = String::from("digraph stream {\n");
+    let mut tensors = Vec::new();
+
+    if let Some(num_fused) = num_fused {
+        graph.push_str("  subgraph cluster_fused {\n    label=\"fused\";\n    style=dashed;\n");
+        for index in 0..num_fused {
+            writeln!(graph, "    ops_{index};").unwrap();
+        }
+        graph.push_str("  }\n");
+    }
+
+    for (index, desc) in operations.iter().enumerate() {
+        writeln!(
+            graph,
+            "  ops_{index} [shape=box, label=\"{index}: {}\"];",
+            name(desc)
+        )
+        .unwrap();
+
+        for tensor in desc.nodes() {
+            if !tensors.contains(&&tensor.id) {
+                tensors.push(&tensor.id);
+                writeln!(
+                    graph,
+                    "  tensor_{id} [label=\"#{id}\\n{shape:?}\"];",
+                    id = tensor.id,
+                    shape = tensor.shape
+                )
+                .unwrap();
+            }
+
+            match tensor.status {
+                TensorStatus::NotInit => writeln!(graph, "  ops_{index} -> tensor_{};", tensor.id),
+                TensorStatus::ReadOnly => writeln!(graph, "  tensor_{} -> ops_{index};", tensor.id),
+                TensorStatus::ReadWrite => writeln!(
+                    graph,
+                    "  tensor_{} -> ops_{index} [style=bold, label=\"last use\"];",
+                    tensor.id
+                ),
+            }
+            .unwrap();
+        }
+    }
+
+    graph.push_str("}\n");
+    graph
+}
+
+/// The name of the operation, such as `FloatOps::Exp`, from its debug representation.
+fn name(desc: &TensorOpsDescription) -> String {
+    format!("{desc:?}")
+        .split(['(', ' ', '{'])
+        .take(2)
+        .collect::<Vec<_>>()
+        .join("::")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        stream::{FloatOpsDescription, UnaryOpsDescription},
+        TensorDescription, TensorId,
+    };
+
+    #[test]
+    fn operations_are_linked_by_their_tensors() {
+        let graph = dot(&[exp(0, 1), exp(1, 2)], Some(1));
+
+        assert!(graph.contains("ops_0 [shape=box, label=\"0: FloatOps::Exp\"];"));
+        assert!(graph.contains("tensor_1 [label=\"#1\\n[32, 32]\"];"));
+        assert!(graph.contains("ops_0 -> tensor_1;"));
+        assert!(graph.contains("tensor_1 -> ops_1;"));
+        assert!(graph.contains("subgraph cluster_fused"));
+    }
+
+    fn exp(input: u64, out: u64) -> TensorOpsDescription {
+        TensorOpsDescription::FloatOps(FloatOpsDescription::Exp(UnaryOpsDescription {
+            input: tensor(input, TensorStatus::ReadOnly),
+            out: tensor(out, TensorStatus::NotInit),
+        }))
+    }
+
+    fn tensor(id: u64, status: TensorStatus) -> TensorDescription {
+        TensorDescription {
+            id: TensorId::new(id),
+            shape: vec![32, 32],
+            status,
+        }
+    }
+}
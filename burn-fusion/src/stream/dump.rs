@@ -0,0 +1,189 @@
+use super::{RelativeStreamConverter, TensorOpsDescription};
+use crate::{
+    FusionBackend, HandleContainer, Optimization, OptimizationBuilder, TensorDescription,
+    TensorStatus,
+};
+use serde::{Deserialize, Serialize};
+use std::{
+    fs::{File, OpenOptions},
+    io::{self, BufRead, BufReader, Write},
+    path::Path,
+};
+
+/// A stream of operations executed on a device, written to the file set with
+/// [dump_streams](crate::FusionConfig::dump_streams).
+///
+/// The dumped streams can be [replayed](replay) to reproduce the fused execution of a backend
+/// without running the program that registered the operations.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct StreamDump {
+    /// The executed operations, with the ids and shapes of their tensors.
+    pub operations: Vec<TensorOpsDescription>,
+    /// If the operations were executed with an optimization, otherwise they were executed one by
+    /// one.
+    pub fused: bool,
+}
+
+/// The reason why a [dumped stream](StreamDump) can't be [replayed](replay).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReplayError {
+    /// The operations were executed one by one, which can't be replayed since only their
+    /// descriptions are dumped.
+    NotFused,
+    /// None of the optimizations of the backend can fuse all the operations.
+    NoOptimization,
+}
+
+impl StreamDump {
+    /// The tensors read by the operations that aren't created by them, which must be registered in
+    /// the [handle container](HandleContainer) before [replaying](replay) the stream.
+    pub fn inputs(&self) -> Vec<&TensorDescription> {
+        let mut inputs: Vec<&TensorDescription> = Vec::new();
+        let mut created = Vec::new();
+
+        for tensor in self.operations.iter().flat_map(|desc| desc.nodes()) {
+            match tensor.status {
+                TensorStatus::NotInit => created.push(&tensor.id),
+                TensorStatus::ReadOnly | TensorStatus::ReadWrite => {
+                    let known = created.contains(&&tensor.id)
+                        || inputs.iter().any(|input| input.id == tensor.id);
+
+                    if !known {
+                        inputs.push(tensor);
+                    }
+                }
+            }
+        }
+
+        inputs
+    }
+
+    /// Append the stream to a file, one stream per line.
+    pub(crate) fn append(&self, path: &Path) -> Result<(), io::Error> {
+        let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+        let mut line = serde_json::to_vec(self)?;
+        line.push(b'\n');
+
+        file.write_all(&line)
+    }
+
+    /// Load the streams dumped in a file, in their execution order.
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Vec<Self>, io::Error> {
+        let file = BufReader::new(File::open(path)?);
+        let mut streams = Vec::new();
+
+        for line in file.lines() {
+            let line = line?;
+            if !line.is_empty() {
+                streams.push(serde_json::from_str(&line)?);
+            }
+        }
+
+        Ok(streams)
+    }
+}
+
+/// Replay a [dumped stream](StreamDump) with the optimizations of a backend.
+///
+/// The optimization is found again using the [builders](FusionBackend::optimizations) of the
+/// backend, so the stream can be replayed on another backend or after changing the backend to
+/// reproduce a bug. The [inputs](StreamDump::inputs) must be registered in the handles, which
+/// contain the outputs of the stream once it is replayed.
+pub fn replay<B: FusionBackend>(
+    stream: &StreamDump,
+    device: &B::Device,
+    handles: &mut HandleContainer<B>,
+) -> Result<(), ReplayError> {
+    if !stream.fused {
+        return Err(ReplayError::NotFused);
+    }
+
+    let mut converter = RelativeStreamConverter::default();
+    let relative = stream
+        .operations
+        .iter()
+        .map(|desc| desc.to_relative(&mut converter))
+        .collect::<Vec<_>>();
+
+    let mut optimization = B::optimizations(device.clone())
+        .into_iter()
+        .map(|mut builder| {
+            relative.iter().for_each(|desc| builder.register(desc));
+            builder
+        })
+        .filter(|builder| builder.properties().ready)
+        .max_by_key(|builder| builder.properties().score)
+        .map(|builder: Box<dyn OptimizationBuilder<B>>| builder.build())
+        .filter(|optimization| optimization.len() == relative.len())
+        .ok_or(ReplayError::NoOptimization)?;
+
+    let mut context = converter.context(handles);
+    optimization.execute(&mut context);
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        stream::UnaryOpsDescription,
+        stream::{BinaryOpsDescription, FloatOpsDescription, NumericOpsDescription},
+        TensorId,
+    };
+
+    #[test]
+    fn inputs_are_the_tensors_not_created_by_the_stream() {
+        let stream = StreamDump {
+            operations: vec![
+                TensorOpsDescription::FloatOps(FloatOpsDescription::Exp(UnaryOpsDescription {
+                    input: tensor(0, TensorStatus::ReadOnly),
+                    out: tensor(1, TensorStatus::NotInit),
+                })),
+                TensorOpsDescription::NumericOpsFloat(NumericOpsDescription::Add(
+                    BinaryOpsDescription {
+                        lhs: tensor(1, TensorStatus::ReadWrite),
+                        rhs: tensor(0, TensorStatus::ReadWrite),
+                        out: tensor(2, TensorStatus::NotInit),
+                    },
+                )),
+            ],
+            fused: true,
+        };
+
+        assert_eq!(stream.inputs(), vec![&tensor(0, TensorStatus::ReadOnly)]);
+    }
+
+    #[test]
+    fn dumped_streams_are_loaded_in_order() {
+        let path =
+            std::env::temp_dir().join(format!("burn-fusion-dump-{}.jsonl", std::process::id()));
+        let streams = (0..3)
+            .map(|id| StreamDump {
+                operations: vec![TensorOpsDescription::FloatOps(FloatOpsDescription::Exp(
+                    UnaryOpsDescription {
+                        input: tensor(id, TensorStatus::ReadOnly),
+                        out: tensor(id + 1, TensorStatus::NotInit),
+                    },
+                ))],
+                fused: false,
+            })
+            .collect::<Vec<_>>();
+
+        streams
+            .iter()
+            .for_each(|stream| stream.append(&path).unwrap());
+        let loaded = StreamDump::load(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(loaded, streams);
+    }
+
+    fn tensor(id: u64, status: TensorStatus) -> TensorDescription {
+        TensorDescription {
+            id: TensorId::new(id),
+            shape: vec![32, 32],
+            status,
+        }
+    }
+}
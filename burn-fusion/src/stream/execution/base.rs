@@ -1,10 +1,12 @@
 use crate::{
     stream::{
+        dead_operations,
         store::{OptimizationId, OptimizationStore},
-        Stream,
+        write_dot, Stream, StreamDump,
     },
     FusionBackend, HandleContainer, Optimization,
 };
+use std::time::Instant;
 
 #[derive(Clone, Copy, Debug)]
 pub(crate) enum ExecutionMode {
@@ -23,8 +25,39 @@ impl<B: FusionBackend> Stream<B> {
         handles: &mut HandleContainer<B>,
         store: &mut OptimizationStore<B::Optimization>,
     ) {
+        let num_executed = match id {
+            Some(id) => store.get_unchecked(id).value.len(),
+            None => self.ops.len(),
+        };
+
+        if let Some(path) = &self.dump_path {
+            let dump = StreamDump {
+                operations: self.global[0..num_executed].to_vec(),
+                fused: id.is_some(),
+            };
+
+            if let Err(err) = dump.append(path) {
+                log::warn!("Unable to dump the fusion stream to {path:?}: {err}");
+            }
+        }
+
+        if let Some(directory) = &self.dot_directory {
+            // The whole pending stream is rendered to show the operations following the boundary.
+            let num_fused = id.map(|_| num_executed);
+            write_dot(directory, &self.global, num_fused);
+        }
+
         match id {
-            Some(id) => self.execute_optimization(handles, &mut store.get_mut_unchecked(id).value),
+            Some(id) => {
+                let key = self.size_key(num_executed);
+                store.record_hit(id);
+
+                // Backends executing asynchronously only measure the time to launch the
+                // optimization, which is still enough to compare candidates cheaply.
+                let start = Instant::now();
+                self.execute_optimization(handles, &mut store.get_mut_unchecked(id).value);
+                store.record_duration(id, key, start.elapsed());
+            }
             None => self.execute_operations(handles),
         }
     }
@@ -43,11 +76,25 @@ impl<B: FusionBackend> Stream<B> {
         self.ops.drain(0..num_drained);
     }
 
+    /// Execute the operations one by one, skipping the ones only computing dropped tensors.
+    ///
+    /// Dead operations are only eliminated here, the operations of an
+    /// [optimization](crate::Optimization) are always executed.
     fn execute_operations(&mut self, handles: &mut HandleContainer<B>) {
         let num_drained = self.ops.len();
+        // The handles of the dropped tensors are kept until the operations using them are drained.
+        let dead = dead_operations(&self.global, &handles.handles_orphan);
+        let operations = self.ops.drain(0..num_drained).zip(self.global.iter());
 
-        for ops in self.ops.drain(0..num_drained) {
-            ops.execute(handles);
+        for ((ops, desc), dead) in operations.zip(dead) {
+            match dead {
+                // The inputs used for the last time by a skipped operation are never consumed.
+                true => desc
+                    .nodes()
+                    .into_iter()
+                    .for_each(|tensor| handles.free(tensor)),
+                false => ops.execute(handles),
+            }
         }
 
         self.drain_stream(num_drained, handles);
@@ -60,15 +107,9 @@ impl<B: FusionBackend> Stream<B> {
             .for_each(|tensor| handles.free(tensor));
 
         self.global.drain(0..num_drained);
+        self.signatures.drain(0..num_drained);
 
-        handles.free_orphans(
-            &self
-                .global
-                .iter()
-                .flat_map(|desc| desc.nodes())
-                .map(|tensor| &tensor.id)
-                .collect::<Vec<_>>(),
-        );
+        // Orphan handles are freed by the multi stream, since they might be used by other streams.
         self.reset_relative();
     }
 
@@ -23,6 +23,11 @@ impl<B: FusionBackend> Explorer<B> {
         }
     }
 
+    /// Add a builder, the stream must be empty since the builder didn't see its operations.
+    pub(crate) fn add_builder(&mut self, builder: Box<dyn OptimizationBuilder<B>>) {
+        self.builders.push(builder);
+    }
+
     pub(crate) fn defer(&mut self) {
         self.num_deferred += 1;
     }
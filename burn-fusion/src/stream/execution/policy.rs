@@ -1,6 +1,6 @@
 use super::ExecutionMode;
 use crate::stream::{
-    store::{OptimizationId, OptimizationStore, SearchQuery},
+    store::{IndexNodeId, OptimizationId, OptimizationStore, SizeKey},
     TensorOpsDescription,
 };
 use std::marker::PhantomData;
@@ -9,43 +9,60 @@ use std::marker::PhantomData;
 ///
 /// # Details
 ///
-/// We keep track of each new operation added to the stream and invalidate potential optimizations
-/// when we see a different operation is added while keeping track of the current stream.
+/// We keep track of each new operation added to the stream by walking the
+/// [optimization index](crate::stream::store::OptimizationIndex), which is a prefix tree over the
+/// streams of the optimizations.
 ///
-/// Therefore, the overhead is very minimal, since the time-complexity of checking for existing
-/// optimizations scales with the number of concurrent potential optimizations for the current stream,
-/// which isn't supposed to be big at any time.
+/// Therefore, the overhead is very minimal, since each new operation only moves the position in the
+/// index by one node, whatever the number of potential optimizations for the current stream.
 pub(crate) struct Policy<O> {
-    // The potential optimizations that we could apply to the current stream, but their streams
-    // still exceed the size of the current stream.
-    candidates: Vec<OptimizationId>,
+    // The index node matching the current stream, the potential optimizations that we could apply
+    // are the ones going through that node. `None` when no optimization starts with the current
+    // stream.
+    node: Option<IndexNodeId>,
     // Optimizations that we find during the `updates`, but none of their `end_conditions` matches the
     // current stream.
     availables: Vec<(OptimizationId, usize)>,
-    // Optimization that we find during the `updates` where one of its `end_condition` matches the
-    // current stream.
-    found: Option<(OptimizationId, usize)>,
+    // Optimizations that we find during the `updates` where one of their `end_conditions` matches
+    // the current stream, the fastest one is executed.
+    found: Vec<OptimizationId>,
     // The size of the stream currently analyzed.
     stream_size: usize,
+    // The size of the stream when the current `found` optimizations were found.
+    found_at: usize,
+    // The number of operations the execution of the `found` optimizations is deferred when a
+    // longer optimization might still match the stream.
+    lookahead: usize,
     _item_type: PhantomData<O>,
 }
 
 impl<O> Policy<O> {
-    pub(crate) fn new() -> Self {
+    pub(crate) fn new(lookahead: usize) -> Self {
         Self {
-            candidates: Vec::new(),
+            node: None,
             availables: Vec::new(),
-            found: None,
+            found: Vec::new(),
             stream_size: 0,
+            found_at: 0,
+            lookahead,
             _item_type: PhantomData,
         }
     }
 
+    /// Set the number of operations the execution of a found optimization is deferred.
+    pub(crate) fn set_lookahead(&mut self, lookahead: usize) {
+        self.lookahead = lookahead;
+    }
+
     /// Returns the [action](Action) that should be taken given the state of the policy.
+    ///
+    /// When multiple optimizations can be executed, the [fastest](OptimizationStore::fastest) one
+    /// on streams of the given [size](SizeKey) is selected.
     pub fn action(
         &self,
         optimizations: &OptimizationStore<O>,
         stream: &[TensorOpsDescription],
+        key: SizeKey,
         mode: ExecutionMode,
     ) -> Action {
         let num_minimum_analyzed = match mode {
@@ -57,13 +74,21 @@ impl<O> Policy<O> {
             panic!("Internal Error: Can't retrieve the policy action when the number of operations analyzed is lower than the stream itself.");
         }
 
-        if let Some((id, _length)) = self.found {
+        if let Some(id) = optimizations.fastest(&self.found, key) {
+            if let ExecutionMode::Lazy = mode {
+                // A longer optimization still going through the current node might end within
+                // the lookahead window, in which case it is found and executed instead.
+                if self.node.is_some() && self.stream_size - self.found_at < self.lookahead {
+                    return Action::Defer;
+                }
+            }
+
             return Action::Execute(id);
         }
 
         match mode {
             ExecutionMode::Lazy => {
-                if self.candidates.is_empty() {
+                if self.node.is_none() {
                     // Even if there are optimizations available, we aren't sure if they are the best ones
                     // we can use. Exploring more optimizations might find a new `end_condition` or
                     // even find a better optimization.
@@ -75,19 +100,25 @@ impl<O> Policy<O> {
             ExecutionMode::Sync => {
                 // If an optimization covers the _whole_ stream, we return it, else we explore new
                 // optimizations.
-                for (id, length) in self.availables.iter() {
-                    if *length == stream.len() {
-                        return Action::Execute(*id);
-                    }
+                let candidates = self
+                    .availables
+                    .iter()
+                    .filter(|(_, length)| *length == stream.len())
+                    .map(|(id, _)| *id)
+                    .collect::<Vec<_>>();
+
+                if let Some(id) = optimizations.fastest(&candidates, key) {
+                    return Action::Execute(id);
                 }
 
-                for candidate in self.candidates.iter() {
-                    let item = optimizations.get_unchecked(*candidate);
-
-                    // The candidate can actually be executed, since the stream is of the same
-                    // size.
-                    if item.stream.len() == stream.len() {
-                        return Action::Execute(*candidate);
+                // The candidates ending at the current node can actually be executed, since
+                // their stream is of the same size.
+                if let Some(node) = self.node {
+                    if self.stream_size == stream.len() {
+                        if let Some(id) = optimizations.fastest(optimizations.ending_at(node), key)
+                        {
+                            return Action::Execute(id);
+                        }
                     }
                 }
 
@@ -98,72 +129,51 @@ impl<O> Policy<O> {
 
     /// Update the policy state.
     pub fn update(&mut self, store: &OptimizationStore<O>, ops: &TensorOpsDescription) {
-        if self.stream_size == 0 {
-            self.candidates = store.find(SearchQuery::OptimizationsStartingWith(ops));
-        } else {
-            self.analyze_candidates(store, ops, self.stream_size);
+        if let Some(node) = self.node {
+            self.analyze_ending_optimizations(store, node, ops);
         }
 
+        self.node = match self.stream_size {
+            0 => store.next_node(None, ops),
+            _ => self.node.and_then(|node| store.next_node(Some(node), ops)),
+        };
         self.stream_size += 1;
     }
 
     // Reset the state of the policy.
     pub fn reset(&mut self) {
-        self.candidates.clear();
+        self.node = None;
         self.availables.clear();
         self.stream_size = 0;
-        self.found = None;
+        self.found_at = 0;
+        self.found.clear();
     }
 
-    fn analyze_candidates(
+    fn analyze_ending_optimizations(
         &mut self,
         optimizations: &OptimizationStore<O>,
+        node: IndexNodeId,
         next_ops: &TensorOpsDescription,
-        stream_size: usize,
     ) {
-        // The index starts at zero.
-        let mut invalidated_candidates = Vec::new();
+        let mut found = Vec::new();
 
-        for id in self.candidates.iter() {
+        for id in optimizations.ending_at(node) {
             let item = optimizations.get_unchecked(*id);
 
-            if item.stream.len() == stream_size {
-                if item.end_conditions.contains(next_ops) {
-                    self.found = Some((*id, item.stream.len()));
-                    break;
-                } else {
-                    // The optimization is available, but the current operation isn't an existing
-                    // end_condition for this optimization, so we may find a better optimization by
-                    // still growing the stream.
-                    self.availables.push((*id, item.stream.len()));
-                    invalidated_candidates.push(*id);
-                    continue;
-                }
-            };
-
-            let next_ops_candidate = match item.stream.get(stream_size) {
-                Some(val) => val,
-                None => {
-                    // Stream of different size, invalidated.
-                    invalidated_candidates.push(*id);
-                    continue;
-                }
-            };
-
-            if next_ops_candidate != next_ops {
-                // Stream with different node at the current position, invalidated.
-                invalidated_candidates.push(*id);
-                continue;
+            if item.end_conditions.contains(next_ops) {
+                found.push(*id);
+            } else {
+                // The optimization is available, but the current operation isn't an existing
+                // end_condition for this optimization, so we may find a better optimization by
+                // still growing the stream.
+                self.availables.push((*id, item.stream.len()));
             }
         }
 
-        let mut updated_candidates = Vec::new();
-        core::mem::swap(&mut updated_candidates, &mut self.candidates);
-
-        self.candidates = updated_candidates
-            .into_iter()
-            .filter(|candidate| !invalidated_candidates.contains(candidate))
-            .collect();
+        if !found.is_empty() {
+            self.found = found;
+            self.found_at = self.stream_size + 1;
+        }
     }
 }
 
@@ -190,12 +200,12 @@ mod tests {
         stream::{store::OptimizationItem, FloatOpsDescription, UnaryOpsDescription},
         TensorDescription, TensorId, TensorStatus,
     };
-    use std::ops::Range;
+    use std::{ops::Range, time::Duration};
 
     #[test]
     fn given_no_optimization_should_explore() {
         let store = OptimizationStore::default();
-        let mut policy = Policy::new();
+        let mut policy = Policy::new(0);
         let stream = TestStream::new(3);
 
         stream.assert_updates(
@@ -209,7 +219,7 @@ mod tests {
     #[test]
     fn given_existing_optimization_when_sync_should_execute_optim() {
         let mut store = OptimizationStore::default();
-        let mut policy = Policy::new();
+        let mut policy = Policy::new(0);
         let stream = TestStream::new(2);
 
         let id = store.add(OptimizationItem {
@@ -225,14 +235,14 @@ mod tests {
             Action::Defer,
         );
 
-        let action = policy.action(&store, &stream.operations, ExecutionMode::Sync);
+        let action = policy.action(&store, &stream.operations, 0, ExecutionMode::Sync);
         assert_eq!(action, Action::Execute(id));
     }
 
     #[test]
     fn given_existing_optimization_when_found_end_condition_should_execute_optim() {
         let mut store = OptimizationStore::default();
-        let mut policy = Policy::new();
+        let mut policy = Policy::new(0);
 
         let stream = TestStream::new(3);
         let id = store.add(OptimizationItem {
@@ -255,11 +265,103 @@ mod tests {
         );
     }
 
+    #[test]
+    fn given_multiple_candidates_should_execute_the_fastest() {
+        let mut store = OptimizationStore::default();
+        let mut policy = Policy::new(0);
+
+        let stream = TestStream::new(3);
+        let item = || OptimizationItem {
+            stream: stream.operations[0..2].to_vec(),
+            end_conditions: stream.operations[2..3].to_vec(),
+            value: (),
+        };
+        let id_1 = store.add(item());
+        let id_2 = store.add(item());
+        store.record_duration(id_1, 0, Duration::from_millis(4));
+        store.record_duration(id_2, 0, Duration::from_millis(2));
+
+        stream.assert_updates(
+            &store,
+            &mut policy,
+            AssertUpdatesOptions::OperationsIndex(0..2),
+            Action::Defer,
+        );
+        stream.assert_updates(
+            &store,
+            &mut policy,
+            AssertUpdatesOptions::OperationsIndex(2..3),
+            Action::Execute(id_2),
+        );
+    }
+
+    #[test]
+    fn given_lookahead_should_defer_for_a_longer_optimization() {
+        let mut store = OptimizationStore::default();
+        let mut policy = Policy::new(2);
+
+        let stream = TestStream::new(5);
+        store.add(OptimizationItem {
+            stream: stream.operations[0..2].to_vec(),
+            end_conditions: stream.operations[2..3].to_vec(),
+            value: (),
+        });
+        let id_long = store.add(OptimizationItem {
+            stream: stream.operations[0..4].to_vec(),
+            end_conditions: stream.operations[4..5].to_vec(),
+            value: (),
+        });
+
+        stream.assert_updates(
+            &store,
+            &mut policy,
+            AssertUpdatesOptions::OperationsIndex(0..4),
+            Action::Defer,
+        );
+        stream.assert_updates(
+            &store,
+            &mut policy,
+            AssertUpdatesOptions::OperationsIndex(4..5),
+            Action::Execute(id_long),
+        );
+    }
+
+    #[test]
+    fn given_lookahead_exceeded_should_execute_the_found_optimization() {
+        let mut store = OptimizationStore::default();
+        let mut policy = Policy::new(1);
+
+        let stream = TestStream::new(5);
+        let id_short = store.add(OptimizationItem {
+            stream: stream.operations[0..2].to_vec(),
+            end_conditions: stream.operations[2..3].to_vec(),
+            value: (),
+        });
+        store.add(OptimizationItem {
+            stream: stream.operations[0..4].to_vec(),
+            end_conditions: stream.operations[4..5].to_vec(),
+            value: (),
+        });
+
+        stream.assert_updates(
+            &store,
+            &mut policy,
+            AssertUpdatesOptions::OperationsIndex(0..3),
+            Action::Defer,
+        );
+        stream.assert_updates(
+            &store,
+            &mut policy,
+            AssertUpdatesOptions::OperationsIndex(3..4),
+            Action::Execute(id_short),
+        );
+    }
+
     #[test]
     fn should_support_multiple_end_conditions() {
         let mut store = OptimizationStore::default();
-        let mut policy_1 = Policy::new();
-        let mut policy_2 = Policy::new();
+        let mut policy_1 = Policy::new(0);
+        let mut policy_2 = Policy::new(0);
 
         let mut stream_1 = TestStream::new(2);
         let mut stream_2 = TestStream::new(2);
@@ -309,8 +411,8 @@ mod tests {
     #[test]
     fn should_select_right_optimization() {
         let mut store = OptimizationStore::default();
-        let mut policy_1 = Policy::new();
-        let mut policy_2 = Policy::new();
+        let mut policy_1 = Policy::new(0);
+        let mut policy_2 = Policy::new(0);
 
         let mut stream_1 = TestStream::new(2);
         let mut stream_2 = TestStream::new(2);
@@ -375,7 +477,7 @@ mod tests {
             value: (),
         });
 
-        let mut policy = Policy::new();
+        let mut policy = Policy::new(0);
         // Same path as stream 1
         stream_2.assert_updates(
             &store,
@@ -429,7 +531,7 @@ mod tests {
                         let stream = &self.operations[0..i];
                         let next_ops = &self.operations[i];
                         policy.update(optimizations, next_ops);
-                        let result = policy.action(optimizations, stream, ExecutionMode::Lazy);
+                        let result = policy.action(optimizations, stream, 0, ExecutionMode::Lazy);
 
                         assert_eq!(result, action);
                     }
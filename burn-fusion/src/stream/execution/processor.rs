@@ -2,7 +2,9 @@ use super::{ExecutionMode, Exploration, Explorer};
 use crate::stream::execution::{Action, Policy};
 use crate::stream::store::{OptimizationId, OptimizationItem, OptimizationStore};
 use crate::stream::{Stream, TensorOpsDescription};
-use crate::{FusionBackend, HandleContainer, OptimizationBuilder};
+use crate::{
+    FusionBackend, FusionConfig, FusionMetrics, HandleContainer, Optimization, OptimizationBuilder,
+};
 
 /// Process the [stream](Stream) following a [policy](Policy).
 ///
@@ -10,17 +12,35 @@ use crate::{FusionBackend, HandleContainer, OptimizationBuilder};
 pub(crate) struct Processor<B: FusionBackend> {
     policy: Policy<B::Optimization>,
     explorer: Explorer<B>,
+    metrics: FusionMetrics,
 }
 
 impl<B: FusionBackend> Processor<B> {
     /// Create a new stream processor.
     pub fn new(optimizations: Vec<Box<dyn OptimizationBuilder<B>>>) -> Self {
         Self {
-            policy: Policy::new(),
+            policy: Policy::new(B::lookahead()),
             explorer: Explorer::new(optimizations),
+            metrics: FusionMetrics::default(),
         }
     }
 
+    /// Add an [optimization builder](OptimizationBuilder) used to explore the next streams.
+    pub fn add_builder(&mut self, builder: Box<dyn OptimizationBuilder<B>>) {
+        self.explorer.add_builder(builder);
+    }
+
+    /// Apply the [lookahead](FusionConfig::lookahead) of the device to the policy.
+    pub fn configure(&mut self, config: &FusionConfig) {
+        self.policy
+            .set_lookahead(config.lookahead.unwrap_or_else(B::lookahead));
+    }
+
+    /// The [metrics](FusionMetrics) of the streams processed so far.
+    pub fn metrics(&self) -> FusionMetrics {
+        self.metrics
+    }
+
     /// Process the [stream](Stream) with the provided mode.
     pub fn process(
         &mut self,
@@ -51,7 +71,8 @@ impl<B: FusionBackend> Processor<B> {
                     };
                 }
                 Action::Execute(id) => {
-                    stream.execute(Some(id), handles, optimizations);
+                    self.metrics.optimization_hits += 1;
+                    self.execute(stream, Some(id), handles, optimizations);
                     self.reset(optimizations, stream);
                 }
             };
@@ -62,6 +83,17 @@ impl<B: FusionBackend> Processor<B> {
         }
     }
 
+    /// Execute the operations of the [stream](Stream) one by one, without any optimization.
+    pub fn execute_unfused(
+        &mut self,
+        stream: &mut Stream<B>,
+        optimizations: &mut OptimizationStore<B::Optimization>,
+        handles: &mut HandleContainer<B>,
+    ) {
+        self.execute(stream, None, handles, optimizations);
+        self.reset(optimizations, stream);
+    }
+
     fn explore(
         &mut self,
         stream: &mut Stream<B>,
@@ -71,11 +103,19 @@ impl<B: FusionBackend> Processor<B> {
     ) {
         match self.explorer.explore(stream, mode) {
             Exploration::OptimizationFound(optim) => {
+                let num_optimizations = optimizations.len();
                 let id = optim.map(|optim| {
                     Self::on_new_optimization(&self.policy, stream, optimizations, optim, mode)
                 });
 
-                stream.execute(id, handles, optimizations);
+                // An existing optimization can be selected instead of adding a new one.
+                if id.is_some() && optimizations.len() == num_optimizations {
+                    self.metrics.optimization_hits += 1;
+                } else if id.is_some() {
+                    self.metrics.optimization_misses += 1;
+                }
+
+                self.execute(stream, id, handles, optimizations);
                 self.reset(optimizations, stream);
             }
             Exploration::Continue => {
@@ -86,6 +126,28 @@ impl<B: FusionBackend> Processor<B> {
         }
     }
 
+    fn execute(
+        &mut self,
+        stream: &mut Stream<B>,
+        id: Option<OptimizationId>,
+        handles: &mut HandleContainer<B>,
+        optimizations: &mut OptimizationStore<B::Optimization>,
+    ) {
+        let num_operations = stream.len() as u64;
+        let num_fused = id
+            .map(|id| optimizations.get_unchecked(id).value.len() as u64)
+            .unwrap_or(0);
+
+        self.metrics.executions += 1;
+        self.metrics.total_stream_length += num_operations;
+        match id {
+            Some(_) => self.metrics.fused_operations += num_fused,
+            None => self.metrics.unfused_operations += num_operations,
+        }
+
+        stream.execute(id, handles, optimizations);
+    }
+
     fn reset(&mut self, store: &mut OptimizationStore<B::Optimization>, stream: &Stream<B>) {
         self.explorer.reset(stream);
         self.policy.reset();
@@ -102,13 +164,14 @@ impl<B: FusionBackend> Processor<B> {
         stream: &Stream<B>,
         mode: ExecutionMode,
     ) -> Action {
-        let (stream, next_ops) = Self::split_stream_ref(stream, mode);
+        let (relative, next_ops) = Self::split_stream_ref(stream, mode);
 
         if let Some(next_ops) = next_ops {
             self.policy.update(cache, next_ops)
         }
 
-        self.policy.action(cache, stream, mode)
+        let key = stream.size_key(relative.len());
+        self.policy.action(cache, relative, key, mode)
     }
 
     fn split_stream_owned(
@@ -147,7 +210,9 @@ impl<B: FusionBackend> Processor<B> {
         //
         // Specify a sync execution mode signaling that we want to know if an optimization is
         // available right now even if it isn't the best one.
-        match policy.action(store, &stream_relative, ExecutionMode::Sync) {
+        let key = stream.size_key(stream_relative.len());
+
+        match policy.action(store, &stream_relative, key, ExecutionMode::Sync) {
             Action::Execute(id) => {
                 // When we are in lazy mode, a next operation will be available.
                 //
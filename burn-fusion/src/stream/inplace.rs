@@ -0,0 +1,93 @@
+use crate::{TensorDescription, TensorStatus};
+
+/// An output of fused operations that can be written in the memory of one of their inputs.
+#[derive(new, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct InplaceCandidate {
+    /// The position of the input.
+    pub input: usize,
+    /// The position of the output.
+    pub output: usize,
+}
+
+/// Plan which outputs of fused operations can be written in place of their inputs.
+///
+/// An input can be reused when the fused operations read it for the last time, which is the case
+/// when its [status](TensorStatus) is [read write](TensorStatus::ReadWrite) in the relative
+/// stream, and when it has the same shape and element type as the output. Each input is reused by
+/// at most one output, the first one matching it.
+///
+/// The plan only relies on the descriptions of the tensors, so the backend must still verify
+/// before executing in place that the memory of the input isn't shared with another tensor.
+pub fn plan_inplace<E: PartialEq>(
+    inputs: &[(TensorDescription, E)],
+    outputs: &[(TensorDescription, E)],
+) -> Vec<InplaceCandidate> {
+    let mut available = inputs
+        .iter()
+        .enumerate()
+        .filter(|(_, (tensor, _))| tensor.status == TensorStatus::ReadWrite)
+        .collect::<Vec<_>>();
+
+    outputs
+        .iter()
+        .enumerate()
+        .filter_map(|(output, (tensor, elem))| {
+            let index = available.iter().position(|(_, (input, input_elem))| {
+                input.shape == tensor.shape && input_elem == elem
+            })?;
+            let (input, _) = available.remove(index);
+
+            Some(InplaceCandidate::new(input, output))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TensorId;
+
+    #[test]
+    fn should_reuse_inputs_read_for_the_last_time() {
+        let inputs = [
+            (tensor(0, [2, 3], TensorStatus::ReadOnly), 'f'),
+            (tensor(1, [2, 3], TensorStatus::ReadWrite), 'f'),
+        ];
+        let outputs = [(tensor(2, [2, 3], TensorStatus::NotInit), 'f')];
+
+        assert_eq!(
+            plan_inplace(&inputs, &outputs),
+            vec![InplaceCandidate::new(1, 0)]
+        );
+    }
+
+    #[test]
+    fn should_only_reuse_inputs_with_the_same_shape_and_element() {
+        let inputs = [
+            (tensor(0, [1, 3], TensorStatus::ReadWrite), 'f'),
+            (tensor(1, [2, 3], TensorStatus::ReadWrite), 'i'),
+            (tensor(2, [2, 3], TensorStatus::ReadWrite), 'f'),
+        ];
+        let outputs = [
+            (tensor(3, [2, 3], TensorStatus::NotInit), 'f'),
+            (tensor(4, [2, 3], TensorStatus::NotInit), 'f'),
+        ];
+
+        assert_eq!(
+            plan_inplace(&inputs, &outputs),
+            vec![InplaceCandidate::new(2, 0)]
+        );
+    }
+
+    fn tensor<const D: usize>(
+        id: u64,
+        shape: [usize; D],
+        status: TensorStatus,
+    ) -> TensorDescription {
+        TensorDescription {
+            id: TensorId::new(id),
+            shape: shape.to_vec(),
+            status,
+        }
+    }
+}
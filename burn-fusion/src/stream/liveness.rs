@@ -0,0 +1,99 @@
+use super::TensorOpsDescription;
+use crate::{TensorId, TensorStatus};
+use std::collections::HashSet;
+
+/// Find the operations that don't need to be executed, since all of their outputs have been
+/// dropped without being read by another operation that needs to be executed.
+///
+/// The operations are traversed in reverse order, so that a chain of operations only computing a
+/// dropped tensor is entirely skipped. Only the operations executed one by one are skipped.
+pub(crate) fn dead_operations(descs: &[TensorOpsDescription], dropped: &[TensorId]) -> Vec<bool> {
+    let mut read = HashSet::new();
+    let mut dead = vec![false; descs.len()];
+
+    for (index, desc) in descs.iter().enumerate().rev() {
+        let nodes = desc.nodes();
+        let mut outputs = nodes
+            .iter()
+            .filter(|tensor| tensor.status == TensorStatus::NotInit)
+            .peekable();
+
+        let is_dead = outputs.peek().is_some()
+            && outputs.all(|tensor| dropped.contains(&tensor.id) && !read.contains(&tensor.id));
+
+        if is_dead {
+            dead[index] = true;
+            continue;
+        }
+
+        read.extend(
+            nodes
+                .iter()
+                .filter(|tensor| tensor.status != TensorStatus::NotInit)
+                .map(|tensor| &tensor.id),
+        );
+    }
+
+    dead
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        stream::{FloatOpsDescription, UnaryOpsDescription},
+        TensorDescription,
+    };
+
+    #[test]
+    fn operation_with_dropped_output_is_dead() {
+        let descs = vec![exp(0, 1), exp(0, 2)];
+
+        assert_eq!(
+            dead_operations(&descs, &[TensorId::new(2)]),
+            vec![false, true]
+        );
+    }
+
+    #[test]
+    fn operation_with_output_read_by_live_operation_is_alive() {
+        let descs = vec![exp(0, 1), exp(1, 2)];
+
+        assert_eq!(
+            dead_operations(&descs, &[TensorId::new(1)]),
+            vec![false, false]
+        );
+    }
+
+    #[test]
+    fn operations_only_computing_dropped_tensors_are_dead() {
+        let descs = vec![exp(0, 1), exp(1, 2), exp(0, 3)];
+
+        assert_eq!(
+            dead_operations(&descs, &[TensorId::new(1), TensorId::new(2)]),
+            vec![true, true, false]
+        );
+    }
+
+    #[test]
+    fn no_operation_is_dead_without_dropped_tensors() {
+        let descs = vec![exp(0, 1), exp(1, 2)];
+
+        assert_eq!(dead_operations(&descs, &[]), vec![false, false]);
+    }
+
+    fn exp(input: u64, out: u64) -> TensorOpsDescription {
+        TensorOpsDescription::FloatOps(FloatOpsDescription::Exp(UnaryOpsDescription {
+            input: tensor(input, TensorStatus::ReadOnly),
+            out: tensor(out, TensorStatus::NotInit),
+        }))
+    }
+
+    fn tensor(id: u64, status: TensorStatus) -> TensorDescription {
+        TensorDescription {
+            id: TensorId::new(id),
+            shape: vec![32, 32],
+            status,
+        }
+    }
+}
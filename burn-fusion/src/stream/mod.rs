@@ -3,10 +3,20 @@ pub(crate) mod store;
 
 mod base;
 mod context;
+mod cse;
+mod dot;
+mod dump;
+mod inplace;
+mod liveness;
 mod multi;
 mod ops;
 
 pub use base::*;
 pub use context::*;
+pub(crate) use cse::*;
+pub use dot::*;
+pub use dump::*;
+pub use inplace::*;
+pub(crate) use liveness::*;
 pub use multi::*;
 pub use ops::*;
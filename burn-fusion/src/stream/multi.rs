@@ -1,28 +1,60 @@
 use super::{
     execution::{ExecutionMode, Processor},
-    store::OptimizationStore,
+    store::{OptimizationStore, SHARED_OPTIMIZATIONS},
     Ops, Stream, TensorOpsDescription,
 };
-use crate::{FusionBackend, HandleContainer};
+use crate::{
+    FlushPolicy, FusionBackend, FusionCheckpoint, FusionConfig, FusionMetrics, FusionMode,
+    HandleContainer, OptimizationFactory, TensorId,
+};
+use burn_tensor::ops::FloatElem;
+use std::{
+    io,
+    path::{Path, PathBuf},
+    time::Instant,
+};
 
 /// Keep track of multiple concurrent streams of operations.
 ///
-/// TODO: Actually support multiple streams.
+/// Operations are grouped into streams by the tensors they involve: an operation is added to the
+/// stream that already uses one of its tensors, or to a new stream when it doesn't depend on any
+/// pending operation. Independent chains of operations are therefore optimized separately and
+/// can't prevent each other from being fused.
+///
+/// When an operation involves tensors of multiple streams, the streams it depends on are executed
+/// before the operation is added to the remaining one. This synchronizes the streams when a tensor
+/// written by a pending operation of a stream is read by an operation of another stream, and
+/// ensures a tensor is never mutated in place by a stream while another one still reads it.
 pub struct MultiStream<B: FusionBackend> {
     items: Vec<Item<B>>,
     optimizations: OptimizationStore<B::Optimization>,
+    device: B::FusionDevice,
+    persistent_path: Option<PathBuf>,
+    config: FusionConfig,
+    mode: FusionMode,
+    factories: Vec<Box<dyn OptimizationFactory<B>>>,
+    // The number of shared optimizations already imported.
+    num_shared_imported: usize,
 }
 
 struct Item<B: FusionBackend> {
     stream: Stream<B>,
     executor: Processor<B>,
+    // Only tracked when the flush policy has a maximum latency.
+    pending_since: Option<Instant>,
 }
 
 impl<B: FusionBackend> MultiStream<B> {
     pub(crate) fn new(device: B::FusionDevice) -> Self {
         Self {
-            items: vec![Item::new(device)],
+            items: vec![Item::new(device.clone())],
             optimizations: OptimizationStore::new(),
+            device,
+            persistent_path: None,
+            config: FusionConfig::default(),
+            mode: FusionMode::default(),
+            factories: Vec::new(),
+            num_shared_imported: 0,
         }
     }
 
@@ -33,20 +65,52 @@ impl<B: FusionBackend> MultiStream<B> {
         ops: Box<dyn Ops<B>>,
         handles: &mut HandleContainer<B>,
     ) {
-        // TODO: Support more than only one stream.
-        if let Some(item) = self.items.first_mut() {
-            item.stream.add(ops_desc, ops);
+        let num_optimizations = self.optimizations.len();
+        let index = self.select_stream(&ops_desc, handles);
+        let item = &mut self.items[index];
+
+        if item.stream.is_empty() && self.config.flush.max_latency.is_some() {
+            item.pending_since = Some(Instant::now());
+        }
+
+        item.stream.add(ops_desc, ops);
+
+        if let FusionMode::Eager = self.mode {
+            item.executor
+                .execute_unfused(&mut item.stream, &mut self.optimizations, handles);
+            item.pending_since = None;
+            self.free_orphans(handles);
+            return;
+        }
+
+        item.executor.process(
+            &mut item.stream,
+            &mut self.optimizations,
+            handles,
+            ExecutionMode::Lazy,
+        );
+
+        if item.should_flush(&self.config.flush) {
             item.executor.process(
                 &mut item.stream,
                 &mut self.optimizations,
                 handles,
-                ExecutionMode::Lazy,
+                ExecutionMode::Sync,
             );
-        };
+        }
+
+        if item.stream.is_empty() {
+            item.pending_since = None;
+        }
+
+        self.free_orphans(handles);
+        self.update_optimizations(num_optimizations);
     }
 
     /// Drain the streams.
     pub fn drain(&mut self, handles: &mut HandleContainer<B>) {
+        let num_optimizations = self.optimizations.len();
+
         self.items.iter_mut().for_each(|item| {
             item.executor.process(
                 &mut item.stream,
@@ -54,7 +118,291 @@ impl<B: FusionBackend> MultiStream<B> {
                 handles,
                 ExecutionMode::Sync,
             );
+            item.pending_since = None;
         });
+
+        self.free_orphans(handles);
+        self.update_optimizations(num_optimizations);
+
+        if self.config.log_metrics {
+            log::info!("{}", self.metrics());
+        }
+    }
+
+    /// Drain the streams using the given tensor, the other streams stay lazy.
+    pub fn drain_dependencies(&mut self, id: &TensorId, handles: &mut HandleContainer<B>) {
+        let num_optimizations = self.optimizations.len();
+
+        for item in self.items.iter_mut() {
+            if !item.stream.uses_any(&[id]) {
+                continue;
+            }
+
+            item.executor.process(
+                &mut item.stream,
+                &mut self.optimizations,
+                handles,
+                ExecutionMode::Sync,
+            );
+            item.pending_since = None;
+        }
+
+        self.free_orphans(handles);
+        self.update_optimizations(num_optimizations);
+    }
+
+    /// The [metrics](FusionMetrics) of all the streams.
+    pub fn metrics(&self) -> FusionMetrics {
+        let mut metrics = FusionMetrics::default();
+
+        for item in self.items.iter() {
+            metrics += item.executor.metrics();
+        }
+
+        metrics
+    }
+
+    /// Set the [configuration](FusionConfig) of the streams.
+    pub fn configure(&mut self, config: FusionConfig, handles: &mut HandleContainer<B>) {
+        self.config = config;
+        // Draining evicts the optimizations exceeding the new maximum right away.
+        self.drain(handles);
+        handles.set_max_pooled(self.config.max_pooled_handles);
+
+        for item in self.items.iter_mut() {
+            item.stream.configure(&self.config);
+            item.executor.configure(&self.config);
+        }
+    }
+
+    /// Set the [mode](FusionMode) used to execute the registered operations.
+    ///
+    /// The streams are drained first, so the operations registered before are executed with the
+    /// previous mode.
+    pub fn set_execution_mode(&mut self, mode: FusionMode, handles: &mut HandleContainer<B>) {
+        self.drain(handles);
+        self.mode = mode;
+    }
+
+    /// Register an [optimization factory](OptimizationFactory), creating a builder for each
+    /// stream.
+    ///
+    /// The streams are drained first, since the new builders didn't see their operations.
+    pub fn register_optimization(
+        &mut self,
+        factory: Box<dyn OptimizationFactory<B>>,
+        handles: &mut HandleContainer<B>,
+    ) {
+        self.drain(handles);
+
+        let device = self.device.clone().into();
+        for item in self.items.iter_mut() {
+            item.executor.add_builder(factory.create(&device));
+        }
+
+        self.factories.push(factory);
+    }
+
+    /// Save the optimizations found so far to a file.
+    pub fn save_optimizations(&self, path: &Path) -> Result<(), io::Error> {
+        self.optimizations.save::<B>(path)
+    }
+
+    /// Load the optimizations saved in a file, returning the number of new optimizations.
+    ///
+    /// The streams are drained first, since the optimizations are only loaded between streams.
+    pub fn load_optimizations(
+        &mut self,
+        path: &Path,
+        handles: &mut HandleContainer<B>,
+    ) -> Result<usize, io::Error> {
+        self.drain(handles);
+
+        self.optimizations
+            .load::<B>(&self.device.clone().into(), path)
+    }
+
+    /// Load the optimizations saved in a file if it exists, then save every new optimization to
+    /// that file.
+    pub fn persist_optimizations(
+        &mut self,
+        path: PathBuf,
+        handles: &mut HandleContainer<B>,
+    ) -> Result<(), io::Error> {
+        match self.load_optimizations(&path, handles) {
+            Ok(_) => {}
+            Err(err) if err.kind() == io::ErrorKind::NotFound => {}
+            Err(err) => return Err(err),
+        }
+
+        self.persistent_path = Some(path);
+        Ok(())
+    }
+
+    /// Drain the streams, then snapshot the optimizations found so far in a
+    /// [checkpoint](FusionCheckpoint).
+    pub fn checkpoint(&mut self, handles: &mut HandleContainer<B>) -> FusionCheckpoint {
+        self.drain(handles);
+
+        FusionCheckpoint::new(B::name(), self.optimizations.serialize_from::<B>(0))
+    }
+
+    /// Add the optimizations of a [checkpoint](FusionCheckpoint), returning the number of new
+    /// optimizations.
+    ///
+    /// The streams are drained first, since the optimizations are only added between streams.
+    pub fn restore(
+        &mut self,
+        checkpoint: &FusionCheckpoint,
+        handles: &mut HandleContainer<B>,
+    ) -> Result<usize, io::Error> {
+        if checkpoint.backend != B::name() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!(
+                    "The checkpoint was created on the backend {}, not {}",
+                    checkpoint.backend,
+                    B::name()
+                ),
+            ));
+        }
+
+        self.drain(handles);
+
+        Ok(self
+            .optimizations
+            .deserialize::<B>(&self.device.clone().into(), &checkpoint.optimizations))
+    }
+
+    /// Share, evict and persist the optimizations after processing the streams.
+    fn update_optimizations(&mut self, num_optimizations: usize) {
+        self.share_optimizations(num_optimizations);
+        let num_evicted = self.evict_optimizations();
+
+        if num_evicted > 0 || self.optimizations.len() != num_optimizations {
+            self.persist();
+        }
+    }
+
+    /// Publish the optimizations added since the given number of optimizations, then import the
+    /// ones published by the other devices.
+    fn share_optimizations(&mut self, num_optimizations: usize) {
+        if !self.config.share_optimizations {
+            return;
+        }
+
+        if self.optimizations.len() > num_optimizations {
+            SHARED_OPTIMIZATIONS
+                .publish::<B>(self.optimizations.serialize_from::<B>(num_optimizations));
+        }
+
+        // Like loading optimizations from a file, importing is only done between streams.
+        if !self.items.iter().all(|item| item.stream.is_empty()) {
+            return;
+        }
+
+        // The optimizations published by this device are skipped since they are already in the
+        // store.
+        let items = SHARED_OPTIMIZATIONS.read::<B>(self.num_shared_imported);
+        self.num_shared_imported += items.len();
+        self.optimizations
+            .deserialize::<B>(&self.device.clone().into(), &items);
+    }
+
+    fn evict_optimizations(&mut self) -> usize {
+        let policy = match &self.config.eviction {
+            Some(policy) => policy,
+            None => return 0,
+        };
+
+        // Evicting changes the ids of the optimizations, which is only safe when no stream is
+        // pending, since the policies of the processors refer to them.
+        if !self.items.iter().all(|item| item.stream.is_empty()) {
+            return 0;
+        }
+
+        self.optimizations.evict(policy)
+    }
+
+    fn persist(&self) {
+        let path = match &self.persistent_path {
+            Some(path) => path,
+            None => return,
+        };
+
+        if let Err(err) = self.save_optimizations(path) {
+            log::warn!("Unable to save the fusion optimizations to {path:?}: {err}");
+        }
+    }
+
+    /// Find the stream where the operation should be added, executing the other streams it depends
+    /// on.
+    fn select_stream(
+        &mut self,
+        ops_desc: &TensorOpsDescription,
+        handles: &mut HandleContainer<B>,
+    ) -> usize {
+        let ids = ops_desc
+            .nodes()
+            .into_iter()
+            .map(|tensor| &tensor.id)
+            .collect::<Vec<_>>();
+
+        let dependencies = self
+            .items
+            .iter()
+            .enumerate()
+            .filter(|(_, item)| item.stream.uses_any(&ids))
+            .map(|(index, _)| index)
+            .collect::<Vec<_>>();
+
+        let (selected, others) = match dependencies.split_first() {
+            Some(split) => split,
+            None => return self.empty_stream(),
+        };
+
+        for index in others {
+            let item = &mut self.items[*index];
+            item.executor.process(
+                &mut item.stream,
+                &mut self.optimizations,
+                handles,
+                ExecutionMode::Sync,
+            );
+            item.pending_since = None;
+        }
+
+        *selected
+    }
+
+    fn empty_stream(&mut self) -> usize {
+        if let Some(index) = self.items.iter().position(|item| item.stream.is_empty()) {
+            return index;
+        }
+
+        let mut item = Item::new(self.device.clone());
+        item.stream.configure(&self.config);
+        item.executor.configure(&self.config);
+        let device = self.device.clone().into();
+        for factory in self.factories.iter() {
+            item.executor.add_builder(factory.create(&device));
+        }
+
+        self.items.push(item);
+        self.items.len() - 1
+    }
+
+    /// Free the handles of the dropped tensors that aren't used by any stream anymore.
+    fn free_orphans(&self, handles: &mut HandleContainer<B>) {
+        let remaining = self
+            .items
+            .iter()
+            .flat_map(|item| item.stream.global.iter())
+            .flat_map(|desc| desc.nodes())
+            .map(|tensor| &tensor.id)
+            .collect::<Vec<&TensorId>>();
+
+        handles.free_orphans(&remaining);
     }
 }
 
@@ -63,6 +411,35 @@ impl<B: FusionBackend> Item<B> {
         Self {
             executor: Processor::new(B::optimizations(device.into())),
             stream: Stream::new(),
+            pending_since: None,
+        }
+    }
+
+    /// If the pending operations exceed one of the limits of the [flush policy](FlushPolicy).
+    fn should_flush(&self, policy: &FlushPolicy) -> bool {
+        if self.stream.is_empty() {
+            return false;
+        }
+
+        if let Some(max_stream_length) = policy.max_stream_length {
+            if self.stream.len() > max_stream_length {
+                return true;
+            }
+        }
+
+        if let (Some(max_latency), Some(pending_since)) = (policy.max_latency, self.pending_since) {
+            if pending_since.elapsed() > max_latency {
+                return true;
+            }
+        }
+
+        if let Some(max_memory) = policy.max_memory {
+            let elem_size = core::mem::size_of::<FloatElem<B>>();
+            if self.stream.num_created_elements() * elem_size > max_memory {
+                return true;
+            }
         }
+
+        false
     }
 }
@@ -173,6 +173,9 @@ pub enum BaseOpsDescription {
     /// Int => [cat](burn_tensor::ops::IntTensorOps::int_cat).
     /// Bool => [cat](burn_tensor::ops::BoolTensorOps::bool_cat).
     Cat(CatOpsDescription),
+    /// Reuse the outputs of a pending operation computing the same values, added to the stream
+    /// instead of a duplicated operation.
+    Alias(AliasDescription),
 }
 
 /// Numeric operations on int and float tensors.
@@ -547,6 +550,17 @@ pub struct CatOpsDescription {
     pub out: TensorDescription,
 }
 
+/// Description of the [alias](BaseOpsDescription::Alias) operation.
+#[derive(Clone, Debug, Hash, PartialEq, Serialize, Deserialize)]
+pub struct AliasDescription {
+    /// The outputs of the pending operation, read only.
+    pub inputs: Vec<TensorDescription>,
+    /// The outputs of the duplicated operation, each one using the handle of its input.
+    pub outs: Vec<TensorDescription>,
+    /// The tensors used for the last time by the duplicated operation.
+    pub released: Vec<TensorDescription>,
+}
+
 #[derive(Clone, Debug, Hash, PartialEq, Serialize, Deserialize)]
 #[allow(missing_docs)]
 pub struct ReduceDimWithIndicesDescription {
@@ -933,6 +947,12 @@ impl BaseOpsDescription {
                 vec![&desc.tensor, &desc.out]
             }
             BaseOpsDescription::Cat(desc) => desc.tensors.iter().collect(),
+            BaseOpsDescription::Alias(desc) => desc
+                .inputs
+                .iter()
+                .chain(desc.outs.iter())
+                .chain(desc.released.iter())
+                .collect(),
         }
     }
 }
@@ -0,0 +1,146 @@
+use core::hash::Hasher;
+
+/// Hasher of the operations of the streams kept by the [optimization index](super::OptimizationIndex).
+///
+/// The hashes are part of the serialized indices, so implementations must return the same hashes
+/// across Rust releases and platforms, which isn't guaranteed by the
+/// [default hasher](std::collections::hash_map::DefaultHasher) of the standard library.
+pub trait StreamHasher: Hasher + Default {}
+
+/// [SipHash-1-3](https://www.aumasson.jp/siphash/siphash.pdf) with fixed keys.
+///
+/// Integers are always hashed as little-endian bytes, and sizes as 64-bit integers, so the hashes
+/// don't depend on the platform.
+#[derive(Clone, Debug)]
+pub struct SipHasher13 {
+    v0: u64,
+    v1: u64,
+    v2: u64,
+    v3: u64,
+    // The bytes not compressed yet, fewer than eight.
+    tail: u64,
+    num_tail: usize,
+    length: usize,
+}
+
+impl SipHasher13 {
+    /// Create a new hasher with the given keys.
+    pub fn new_with_keys(key_0: u64, key_1: u64) -> Self {
+        Self {
+            v0: key_0 ^ 0x736f6d6570736575,
+            v1: key_1 ^ 0x646f72616e646f6d,
+            v2: key_0 ^ 0x6c7967656e657261,
+            v3: key_1 ^ 0x7465646279746573,
+            tail: 0,
+            num_tail: 0,
+            length: 0,
+        }
+    }
+
+    fn round(&mut self) {
+        self.v0 = self.v0.wrapping_add(self.v1);
+        self.v1 = self.v1.rotate_left(13);
+        self.v1 ^= self.v0;
+        self.v0 = self.v0.rotate_left(32);
+        self.v2 = self.v2.wrapping_add(self.v3);
+        self.v3 = self.v3.rotate_left(16);
+        self.v3 ^= self.v2;
+        self.v0 = self.v0.wrapping_add(self.v3);
+        self.v3 = self.v3.rotate_left(21);
+        self.v3 ^= self.v0;
+        self.v2 = self.v2.wrapping_add(self.v1);
+        self.v1 = self.v1.rotate_left(17);
+        self.v1 ^= self.v2;
+        self.v2 = self.v2.rotate_left(32);
+    }
+
+    fn compress(&mut self, word: u64) {
+        self.v3 ^= word;
+        self.round();
+        self.v0 ^= word;
+    }
+}
+
+impl Default for SipHasher13 {
+    fn default() -> Self {
+        Self::new_with_keys(0, 0)
+    }
+}
+
+impl StreamHasher for SipHasher13 {}
+
+impl Hasher for SipHasher13 {
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.tail |= (*byte as u64) << (8 * self.num_tail);
+            self.num_tail += 1;
+
+            if self.num_tail == 8 {
+                self.compress(self.tail);
+                self.tail = 0;
+                self.num_tail = 0;
+            }
+        }
+
+        self.length += bytes.len();
+    }
+
+    fn write_u16(&mut self, i: u16) {
+        self.write(&i.to_le_bytes());
+    }
+
+    fn write_u32(&mut self, i: u32) {
+        self.write(&i.to_le_bytes());
+    }
+
+    fn write_u64(&mut self, i: u64) {
+        self.write(&i.to_le_bytes());
+    }
+
+    fn write_u128(&mut self, i: u128) {
+        self.write(&i.to_le_bytes());
+    }
+
+    fn write_usize(&mut self, i: usize) {
+        self.write_u64(i as u64);
+    }
+
+    fn finish(&self) -> u64 {
+        let mut state = self.clone();
+        let last = ((self.length as u64 & 0xff) << 56) | self.tail;
+
+        state.compress(last);
+        state.v2 ^= 0xff;
+        state.round();
+        state.round();
+        state.round();
+
+        state.v0 ^ state.v1 ^ state.v2 ^ state.v3
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_match_the_reference_hashes() {
+        let mut hasher = SipHasher13::default();
+        assert_eq!(hasher.finish(), 0xd1fba762150c532c);
+
+        hasher.write(&(0..15).collect::<Vec<u8>>());
+        assert_eq!(hasher.finish(), 0xf30eb725bb91c9ea);
+    }
+
+    #[test]
+    fn should_hash_integers_independently_of_the_platform() {
+        let mut hasher_usize = SipHasher13::default();
+        let mut hasher_bytes = SipHasher13::default();
+
+        hasher_usize.write_usize(42);
+        hasher_bytes.write(&42u64.to_le_bytes());
+
+        assert_eq!(hasher_usize.finish(), 0x7b3e724b36ebdf51);
+        assert_eq!(hasher_usize.finish(), hasher_bytes.finish());
+    }
+}
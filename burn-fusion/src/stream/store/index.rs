@@ -1,27 +1,51 @@
+use super::{SipHasher13, StreamHasher};
 use crate::stream::{store::OptimizationId, TensorOpsDescription};
 use serde::{Deserialize, Serialize};
-use std::{
-    collections::{hash_map::DefaultHasher, HashMap},
-    hash::{Hash, Hasher},
-};
+use std::{collections::HashMap, hash::Hash, marker::PhantomData};
 
 /// Index used to search optimizations.
+///
+/// The index is a prefix tree over the streams of the optimizations: each node corresponds to a
+/// stream of operations, and its children extend that stream with one more operation. Searching
+/// for the optimizations matching a stream therefore only walks the tree once, whatever the number
+/// of optimizations sharing the same prefix.
+///
+/// The children of a node are keyed by the hash of their operation, computed with a
+/// [stable hasher](StreamHasher) so that serialized indices stay valid.
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(bound = "")]
+pub struct OptimizationIndex<H: StreamHasher = SipHasher13> {
+    nodes: Vec<IndexNode>,
+    _hasher: PhantomData<H>,
+}
+
+/// Identifier of a node of the [optimization index](OptimizationIndex).
+pub type IndexNodeId = usize;
+
 #[derive(Default, Serialize, Deserialize, Clone)]
-pub struct OptimizationIndex {
-    /// We can't use `HashMap<TensorOpsDescription, Vec<OptimizationId>>` since `TensorOpsDescription`
+struct IndexNode {
+    /// We can't use `HashMap<TensorOpsDescription, IndexNodeId>` since `TensorOpsDescription`
     /// doesn't implement [`Eq`](core::cmp::Eq).
     ///
     /// `TensorOpsDescription` can't implement `Eq` since float types don't implement it.
     ///
     /// We rely instead on [`PartialEq`](core::cmp::PartialEq) to manually handle hash collisions.
-    /// This is OK because we use `relative` streams where any scalar values are set to zeros,
-    /// see [`RelativeStreamConverter`](crate::stream::RelativeStreamConverter).
-    mapping: HashMap<u64, Vec<(TensorOpsDescription, usize)>>,
-    starters: Vec<Vec<OptimizationId>>,
+    /// This is OK because we use `relative` streams where any scalar values are set to zeros or
+    /// to their [bucket](crate::stream::bucket_scalar), see
+    /// [`RelativeStreamConverter`](crate::stream::RelativeStreamConverter).
+    children: HashMap<u64, Vec<(TensorOpsDescription, IndexNodeId)>>,
+    /// The optimizations with a stream going through this node, in insertion order.
+    optimizations: Vec<OptimizationId>,
+    /// The optimizations with a stream ending at this node.
+    ends: Vec<OptimizationId>,
 }
 
 pub enum SearchQuery<'a> {
+    /// The optimizations with a stream starting with the given operation.
+    #[allow(dead_code)] // The policy walks the index one operation at a time instead.
     OptimizationsStartingWith(&'a TensorOpsDescription),
+    /// The optimizations with the longest stream that is a prefix of the given stream.
+    LongestPrefixOf(&'a [TensorOpsDescription]),
 }
 
 pub enum InsertQuery<'a> {
@@ -31,84 +55,108 @@ pub enum InsertQuery<'a> {
     },
 }
 
-impl OptimizationIndex {
+impl<H: StreamHasher> Default for OptimizationIndex<H> {
+    fn default() -> Self {
+        Self {
+            nodes: vec![IndexNode::default()],
+            _hasher: PhantomData,
+        }
+    }
+}
+
+impl<H: StreamHasher> OptimizationIndex<H> {
+    /// The node of the empty stream.
+    pub const ROOT: IndexNodeId = 0;
+
     /// Search optimizations with the given [query](SearchQuery).
     pub fn find(&self, query: SearchQuery<'_>) -> Vec<OptimizationId> {
         match query {
-            SearchQuery::OptimizationsStartingWith(ops) => self.find_starting_with(ops),
+            SearchQuery::OptimizationsStartingWith(ops) => self
+                .child(Self::ROOT, ops)
+                .map(|node| self.nodes[node].optimizations.clone())
+                .unwrap_or_default(),
+            SearchQuery::LongestPrefixOf(stream) => self.find_longest_prefix(stream),
         }
     }
 
     /// Register a new optimization with the given [query](InsertQuery).
     pub fn insert(&mut self, query: InsertQuery<'_>) {
         match query {
-            InsertQuery::NewOptimization { stream, id } => self.insert_new_ops(
-                stream
-                    .first()
-                    .expect("An optimization should never have an empty stream."),
-                id,
-            ),
+            InsertQuery::NewOptimization { stream, id } => {
+                assert!(
+                    !stream.is_empty(),
+                    "An optimization should never have an empty stream."
+                );
+                self.insert_new_ops(stream, id)
+            }
         }
     }
 
-    fn find_starting_with(&self, ops: &TensorOpsDescription) -> Vec<OptimizationId> {
+    /// The node of the stream extended with the given operation, if an optimization starts with
+    /// that stream.
+    pub fn child(&self, node: IndexNodeId, ops: &TensorOpsDescription) -> Option<IndexNodeId> {
         let key = self.stream_key(ops);
-        let values = match self.mapping.get(&key) {
-            Some(val) => val,
-            None => return Vec::new(),
-        };
 
-        if values.is_empty() {
-            return Vec::new();
-        }
+        self.nodes[node]
+            .children
+            .get(&key)?
+            .iter()
+            .find(|(child_ops, _)| child_ops == ops)
+            .map(|(_, child)| *child)
+    }
 
-        let (_, index) = match values.iter().find(|value| &value.0 == ops) {
-            Some(val) => val,
-            None => return Vec::new(),
-        };
+    /// The optimizations with a stream ending at the given node.
+    pub fn ending_at(&self, node: IndexNodeId) -> &[OptimizationId] {
+        &self.nodes[node].ends
+    }
 
-        let val = match self.starters.get(*index) {
-            Some(value) => value.clone(),
-            None => Vec::new(),
-        };
+    fn find_longest_prefix(&self, stream: &[TensorOpsDescription]) -> Vec<OptimizationId> {
+        let mut node = Self::ROOT;
+        let mut longest = Self::ROOT;
 
-        val
-    }
+        for ops in stream {
+            node = match self.child(node, ops) {
+                Some(child) => child,
+                None => break,
+            };
 
-    fn insert_new_ops(&mut self, ops: &TensorOpsDescription, new_id: OptimizationId) {
-        let key = self.stream_key(ops);
-        let values = match self.mapping.get_mut(&key) {
-            Some(val) => val,
-            None => {
-                // New starter ops.
-                let index = self.starters.len();
-                self.starters.push(vec![new_id]);
-                self.mapping.insert(key, vec![(ops.clone(), index)]);
-
-                return;
+            if !self.nodes[node].ends.is_empty() {
+                longest = node;
             }
-        };
-        let (_, index) = match values.iter_mut().find(|value| &value.0 == ops) {
-            Some(val) => val,
-            None => {
-                // New with hash collision.
-                let index = self.starters.len();
-                self.starters.push(vec![new_id]);
-                values.push((ops.clone(), index));
-                return;
-            }
-        };
+        }
 
-        // New optimization for an existing starter.
-        self.starters
-            .get_mut(*index)
-            .expect("Should exist")
-            .push(new_id);
+        self.nodes[longest].ends.clone()
     }
 
-    // Hash the value of the first operation in a stream.
+    fn insert_new_ops(&mut self, stream: &[TensorOpsDescription], new_id: OptimizationId) {
+        let mut node = Self::ROOT;
+
+        for ops in stream {
+            node = match self.child(node, ops) {
+                Some(child) => child,
+                None => {
+                    // New operation for this prefix, possibly with a hash collision.
+                    let child = self.nodes.len();
+                    let key = self.stream_key(ops);
+                    self.nodes.push(IndexNode::default());
+                    self.nodes[node]
+                        .children
+                        .entry(key)
+                        .or_default()
+                        .push((ops.clone(), child));
+                    child
+                }
+            };
+
+            self.nodes[node].optimizations.push(new_id);
+        }
+
+        self.nodes[node].ends.push(new_id);
+    }
+
+    // Hash the value of an operation in a stream.
     fn stream_key(&self, ops: &TensorOpsDescription) -> u64 {
-        let mut hasher = DefaultHasher::new();
+        let mut hasher = H::default();
         ops.hash(&mut hasher);
         hasher.finish()
     }
@@ -124,7 +172,7 @@ mod tests {
 
     #[test]
     fn should_find_optimization_id_based_on_tensor_ops() {
-        let mut index = OptimizationIndex::default();
+        let mut index = <OptimizationIndex>::default();
         let stream_1 = [ops_1()];
         let optimization_id_1 = 0;
 
@@ -140,7 +188,7 @@ mod tests {
 
     #[test]
     fn should_support_multiple_optimization_ids_with_same_starting_ops() {
-        let mut index = OptimizationIndex::default();
+        let mut index = <OptimizationIndex>::default();
         let stream_1 = [ops_1(), ops_2(), ops_1()];
         let stream_2 = [ops_1(), ops_1(), ops_2()];
         let optimization_id_1 = 0;
@@ -162,7 +210,7 @@ mod tests {
 
     #[test]
     fn should_only_find_optimization_with_correct_starting_ops() {
-        let mut index = OptimizationIndex::default();
+        let mut index = <OptimizationIndex>::default();
         let stream_1 = [ops_1(), ops_1()];
         let stream_2 = [ops_2(), ops_1()];
         let optimization_id_1 = 0;
@@ -184,7 +232,7 @@ mod tests {
 
     #[test]
     fn should_handle_hash_collisions() {
-        let mut index = OptimizationIndex::default();
+        let mut index = <OptimizationIndex>::default();
         let stream_1 = [ops_1(), ops_1()];
         let stream_2 = [ops_3(), ops_1()];
         let optimization_id_1 = 0;
@@ -213,6 +261,87 @@ mod tests {
         assert_eq!(found, vec![optimization_id_1]);
     }
 
+    #[test]
+    fn should_find_longest_prefix_of_stream() {
+        let mut index = <OptimizationIndex>::default();
+        let stream_1 = [ops_1(), ops_2()];
+        let stream_2 = [ops_1(), ops_2(), ops_1(), ops_2()];
+        let stream_3 = [ops_1(), ops_2(), ops_1(), ops_1()];
+
+        for (id, stream) in [&stream_1[..], &stream_2, &stream_3].iter().enumerate() {
+            index.insert(InsertQuery::NewOptimization { stream, id });
+        }
+
+        let found = index.find(SearchQuery::LongestPrefixOf(&[
+            ops_1(),
+            ops_2(),
+            ops_1(),
+            ops_2(),
+            ops_2(),
+        ]));
+        assert_eq!(found, vec![1]);
+
+        let found = index.find(SearchQuery::LongestPrefixOf(&[ops_1(), ops_2(), ops_1()]));
+        assert_eq!(found, vec![0]);
+
+        let found = index.find(SearchQuery::LongestPrefixOf(&[ops_2(), ops_1()]));
+        assert!(found.is_empty());
+    }
+
+    #[test]
+    fn should_walk_the_index_one_operation_at_a_time() {
+        let mut index = <OptimizationIndex>::default();
+        let stream_1 = [ops_1(), ops_2()];
+
+        index.insert(InsertQuery::NewOptimization {
+            stream: &stream_1,
+            id: 0,
+        });
+
+        let node = index.child(<OptimizationIndex>::ROOT, &ops_1()).unwrap();
+        assert!(index.ending_at(node).is_empty());
+        assert_eq!(index.child(node, &ops_1()), None);
+
+        let node = index.child(node, &ops_2()).unwrap();
+        assert_eq!(index.ending_at(node), &[0]);
+    }
+
+    #[test]
+    fn should_support_hash_collisions() {
+        let mut index = OptimizationIndex::<CollidingHasher>::default();
+        let stream_1 = [ops_1(), ops_2()];
+        let stream_2 = [ops_1(), ops_3()];
+
+        index.insert(InsertQuery::NewOptimization {
+            stream: &stream_1,
+            id: 0,
+        });
+        index.insert(InsertQuery::NewOptimization {
+            stream: &stream_2,
+            id: 1,
+        });
+
+        let found = index.find(SearchQuery::LongestPrefixOf(&stream_1));
+        assert_eq!(found, vec![0]);
+
+        let found = index.find(SearchQuery::LongestPrefixOf(&stream_2));
+        assert_eq!(found, vec![1]);
+    }
+
+    /// Hash every operation to the same value.
+    #[derive(Default)]
+    struct CollidingHasher;
+
+    impl std::hash::Hasher for CollidingHasher {
+        fn write(&mut self, _bytes: &[u8]) {}
+
+        fn finish(&self) -> u64 {
+            0
+        }
+    }
+
+    impl StreamHasher for CollidingHasher {}
+
     fn ops_1() -> TensorOpsDescription {
         TensorOpsDescription::NumericOpsFloat(NumericOpsDescription::Add(BinaryOpsDescription {
             lhs: TensorDescription {
@@ -1,5 +1,9 @@
+mod hasher;
 mod index;
 mod optimization;
+mod shared;
 
+pub(super) use hasher::*;
 pub(super) use index::*;
 pub(crate) use optimization::*;
+pub(crate) use shared::*;
@@ -1,15 +1,43 @@
-use super::{InsertQuery, OptimizationIndex, SearchQuery};
-use crate::stream::TensorOpsDescription;
+use super::{IndexNodeId, InsertQuery, OptimizationIndex, SearchQuery};
+use crate::{
+    stream::TensorOpsDescription, EvictionPolicy, EvictionStrategy, FusionBackend, Optimization,
+};
+use burn_tensor::Device;
 use serde::{Deserialize, Serialize};
+use std::{
+    collections::HashMap,
+    fs::{self, File},
+    io::{self, BufWriter},
+    path::Path,
+    time::Duration,
+};
 
 #[derive(Default, Serialize, Deserialize)]
 pub(crate) struct OptimizationStore<O> {
     pub(super) optimizations: Vec<OptimizationItem<O>>,
     pub(super) index: OptimizationIndex,
+    #[serde(skip)]
+    usages: Vec<OptimizationUsage>,
+    // Logical time incremented each time an optimization is added or executed.
+    #[serde(skip)]
+    clock: u64,
 }
 
 pub(crate) type OptimizationId = usize;
 
+/// The number of elements created by the operations of a stream rounded up to a power of two, used
+/// to compare the execution times of optimizations on streams of similar sizes.
+pub(crate) type SizeKey = u32;
+
+/// How an optimization has been used, to select the ones to evict and the fastest candidates.
+#[derive(Default, Clone, Debug)]
+struct OptimizationUsage {
+    hits: u64,
+    last_used: u64,
+    // The total execution time and the number of timed executions for each size key.
+    timings: HashMap<SizeKey, (Duration, u32)>,
+}
+
 #[derive(Serialize, Deserialize)]
 pub(crate) struct OptimizationItem<O> {
     pub(crate) stream: Vec<TensorOpsDescription>,
@@ -22,6 +50,8 @@ impl<O> OptimizationStore<O> {
         Self {
             optimizations: Vec::new(),
             index: OptimizationIndex::default(),
+            usages: Vec::new(),
+            clock: 0,
         }
     }
 
@@ -29,6 +59,22 @@ impl<O> OptimizationStore<O> {
         self.index.find(query)
     }
 
+    /// The [index node](IndexNodeId) of the stream extended with the given operation, starting
+    /// from the empty stream when no node is given.
+    pub fn next_node(
+        &self,
+        node: Option<IndexNodeId>,
+        ops: &TensorOpsDescription,
+    ) -> Option<IndexNodeId> {
+        self.index
+            .child(node.unwrap_or(<OptimizationIndex>::ROOT), ops)
+    }
+
+    /// The optimizations with a stream ending at the given [index node](IndexNodeId).
+    pub fn ending_at(&self, node: IndexNodeId) -> &[OptimizationId] {
+        self.index.ending_at(node)
+    }
+
     pub fn add(&mut self, optimization: OptimizationItem<O>) -> OptimizationId {
         let id = self.optimizations.len();
 
@@ -37,11 +83,90 @@ impl<O> OptimizationStore<O> {
             id,
         });
 
+        let last_used = self.tick();
+
         self.optimizations.push(optimization);
+        self.usages.push(OptimizationUsage {
+            hits: 0,
+            last_used,
+            timings: HashMap::new(),
+        });
 
         id
     }
 
+    /// Record that an optimization is executed.
+    pub fn record_hit(&mut self, id: OptimizationId) {
+        let last_used = self.tick();
+        let usage = &mut self.usages[id];
+
+        usage.hits += 1;
+        usage.last_used = last_used;
+    }
+
+    /// Record the time taken to execute an optimization on a stream of the given
+    /// [size](SizeKey).
+    pub fn record_duration(&mut self, id: OptimizationId, key: SizeKey, duration: Duration) {
+        let (total, count) = self.usages[id].timings.entry(key).or_default();
+
+        *total += duration;
+        *count += 1;
+    }
+
+    /// The candidate with the lowest mean execution time on streams of the given [size](SizeKey).
+    ///
+    /// Candidates never executed on streams of that size are selected first, in order, so that
+    /// each candidate is measured before relying on the timings.
+    pub fn fastest(&self, candidates: &[OptimizationId], key: SizeKey) -> Option<OptimizationId> {
+        candidates.iter().copied().min_by_key(|id| {
+            self.usages[*id]
+                .timings
+                .get(&key)
+                .map(|(total, count)| *total / *count)
+        })
+    }
+
+    /// Remove the optimizations selected by the [eviction policy](EvictionPolicy) until the
+    /// maximum number of optimizations is respected, returning the number of evicted
+    /// optimizations.
+    ///
+    /// The ids of the remaining optimizations change, so it must only be called when no
+    /// [policy](crate::stream::execution::Policy) refers to an optimization.
+    pub fn evict(&mut self, policy: &EvictionPolicy) -> usize {
+        let num_evicted = self.len().saturating_sub(policy.max_optimizations);
+
+        if num_evicted == 0 {
+            return 0;
+        }
+
+        let mut ids = (0..self.len()).collect::<Vec<_>>();
+        match policy.strategy {
+            EvictionStrategy::LeastRecentlyUsed => ids.sort_by_key(|id| self.usages[*id].last_used),
+            EvictionStrategy::LeastFrequentlyUsed => {
+                ids.sort_by_key(|id| (self.usages[*id].hits, self.usages[*id].last_used))
+            }
+        }
+
+        let mut evicted = vec![false; self.len()];
+        ids.into_iter()
+            .take(num_evicted)
+            .for_each(|id| evicted[id] = true);
+
+        let optimizations = core::mem::take(&mut self.optimizations);
+        let usages = core::mem::take(&mut self.usages);
+        self.index = OptimizationIndex::default();
+
+        // The remaining optimizations are added back in the same order with their usage.
+        for ((optimization, usage), evicted) in optimizations.into_iter().zip(usages).zip(evicted) {
+            if !evicted {
+                let id = self.add(optimization);
+                self.usages[id] = usage;
+            }
+        }
+
+        num_evicted
+    }
+
     pub fn get_mut_unchecked(&mut self, id: OptimizationId) -> &mut OptimizationItem<O> {
         &mut self.optimizations[id]
     }
@@ -54,4 +179,275 @@ impl<O> OptimizationStore<O> {
     pub fn add_end_condition(&mut self, id: OptimizationId, end_condition: TensorOpsDescription) {
         self.optimizations[id].end_conditions.push(end_condition)
     }
+
+    /// The number of optimizations in the store.
+    pub fn len(&self) -> usize {
+        self.optimizations.len()
+    }
+
+    /// Save the optimizations to a file, using their [serializable state](Optimization::to_state).
+    pub fn save<B>(&self, path: &Path) -> Result<(), io::Error>
+    where
+        B: FusionBackend<Optimization = O>,
+        O: Optimization<B>,
+    {
+        let items = self.states::<B>(0);
+
+        if let Some(parent_dir) = path.parent() {
+            fs::create_dir_all(parent_dir)?;
+        }
+
+        let file = BufWriter::new(File::create(path)?);
+        serde_json::to_writer(file, &items)?;
+
+        Ok(())
+    }
+
+    /// Load the optimizations saved in a file, skipping the ones already in the store.
+    ///
+    /// Returns the number of loaded optimizations.
+    pub fn load<B>(&mut self, device: &Device<B>, path: &Path) -> Result<usize, io::Error>
+    where
+        B: FusionBackend<Optimization = O>,
+        O: Optimization<B>,
+    {
+        // Reading the whole file is faster than deserializing from a buffered reader, see
+        // https://github.com/serde-rs/json/issues/160.
+        let data = fs::read_to_string(path)?;
+        let items: Vec<OptimizationItem<B::OptimizationState>> = serde_json::from_str(&data)?;
+
+        let mut num_loaded = 0;
+
+        for item in items {
+            if self.add_state(device, item) {
+                num_loaded += 1;
+            }
+        }
+
+        Ok(num_loaded)
+    }
+
+    /// Serialize each persistent optimization added since the given id, to
+    /// [share](super::SharedOptimizations) them with other devices.
+    pub fn serialize_from<B>(&self, start: OptimizationId) -> Vec<String>
+    where
+        B: FusionBackend<Optimization = O>,
+        O: Optimization<B>,
+    {
+        let items = self.states::<B>(start);
+
+        items
+            .iter()
+            .filter_map(|item| match serde_json::to_string(item) {
+                Ok(item) => Some(item),
+                Err(err) => {
+                    log::warn!("Unable to serialize a fusion optimization: {err}");
+                    None
+                }
+            })
+            .collect()
+    }
+
+    /// Add the optimizations [serialized](Self::serialize_from) on another device, skipping the
+    /// ones already in the store.
+    ///
+    /// Returns the number of added optimizations.
+    pub fn deserialize<B>(&mut self, device: &Device<B>, items: &[String]) -> usize
+    where
+        B: FusionBackend<Optimization = O>,
+        O: Optimization<B>,
+    {
+        let mut num_added = 0;
+
+        for item in items {
+            match serde_json::from_str::<OptimizationItem<B::OptimizationState>>(item) {
+                Ok(item) => {
+                    if self.add_state(device, item) {
+                        num_added += 1;
+                    }
+                }
+                Err(err) => log::warn!("Unable to deserialize a fusion optimization: {err}"),
+            }
+        }
+
+        num_added
+    }
+
+    fn states<B>(&self, start: OptimizationId) -> Vec<OptimizationItem<B::OptimizationState>>
+    where
+        B: FusionBackend<Optimization = O>,
+        O: Optimization<B>,
+    {
+        self.optimizations[start..]
+            .iter()
+            .filter(|item| item.value.is_persistent())
+            .map(|item| OptimizationItem {
+                stream: item.stream.clone(),
+                end_conditions: item.end_conditions.clone(),
+                value: item.value.to_state(),
+            })
+            .collect()
+    }
+
+    /// Add an optimization from its state, unless its stream is already in the store.
+    fn add_state<B>(
+        &mut self,
+        device: &Device<B>,
+        item: OptimizationItem<B::OptimizationState>,
+    ) -> bool
+    where
+        B: FusionBackend<Optimization = O>,
+        O: Optimization<B>,
+    {
+        if item.stream.is_empty() || self.contains(&item.stream) {
+            return false;
+        }
+
+        self.add(OptimizationItem {
+            stream: item.stream,
+            end_conditions: item.end_conditions,
+            value: O::from_state(device, item.value),
+        });
+
+        true
+    }
+
+    fn tick(&mut self) -> u64 {
+        self.clock += 1;
+        self.clock
+    }
+
+    fn contains(&self, stream: &[TensorOpsDescription]) -> bool {
+        self.find(SearchQuery::LongestPrefixOf(stream))
+            .into_iter()
+            .any(|id| self.optimizations[id].stream == stream)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        stream::{FloatOpsDescription, UnaryOpsDescription},
+        TensorDescription, TensorId, TensorStatus,
+    };
+
+    #[test]
+    fn lru_should_evict_least_recently_executed_optimizations() {
+        let mut store = store_with_optimizations(3);
+        store.record_hit(0);
+        store.record_hit(2);
+        store.record_hit(0);
+
+        let num_evicted = store.evict(&EvictionPolicy::new(2, EvictionStrategy::LeastRecentlyUsed));
+
+        assert_eq!(num_evicted, 1);
+        assert_eq!(values(&store), vec![0, 2]);
+    }
+
+    #[test]
+    fn lfu_should_evict_least_executed_optimizations() {
+        let mut store = store_with_optimizations(3);
+        store.record_hit(0);
+        store.record_hit(0);
+        store.record_hit(1);
+        store.record_hit(2);
+        store.record_hit(2);
+
+        let num_evicted = store.evict(&EvictionPolicy::new(
+            2,
+            EvictionStrategy::LeastFrequentlyUsed,
+        ));
+
+        assert_eq!(num_evicted, 1);
+        assert_eq!(values(&store), vec![0, 2]);
+    }
+
+    #[test]
+    fn eviction_should_update_the_index() {
+        let mut store = store_with_optimizations(3);
+        store.record_hit(2);
+
+        store.evict(&EvictionPolicy::new(1, EvictionStrategy::LeastRecentlyUsed));
+
+        assert!(store
+            .find(SearchQuery::LongestPrefixOf(&stream(0)))
+            .is_empty());
+        assert_eq!(
+            store.find(SearchQuery::LongestPrefixOf(&stream(2))),
+            vec![0]
+        );
+        assert_eq!(store.get_unchecked(0).value, 2);
+    }
+
+    #[test]
+    fn should_not_evict_when_under_the_maximum() {
+        let mut store = store_with_optimizations(3);
+
+        let num_evicted = store.evict(&EvictionPolicy::new(
+            3,
+            EvictionStrategy::LeastFrequentlyUsed,
+        ));
+
+        assert_eq!(num_evicted, 0);
+        assert_eq!(values(&store), vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn fastest_should_select_unmeasured_candidates_first() {
+        let mut store = store_with_optimizations(3);
+        store.record_duration(0, 10, Duration::from_millis(5));
+        store.record_duration(2, 12, Duration::from_millis(5));
+
+        assert_eq!(store.fastest(&[0, 1, 2], 10), Some(1));
+        assert_eq!(store.fastest(&[0, 2], 10), Some(2));
+    }
+
+    #[test]
+    fn fastest_should_select_lowest_mean_duration_for_the_size() {
+        let mut store = store_with_optimizations(2);
+        store.record_duration(0, 10, Duration::from_millis(2));
+        store.record_duration(0, 10, Duration::from_millis(6));
+        store.record_duration(1, 10, Duration::from_millis(3));
+        store.record_duration(0, 12, Duration::from_millis(1));
+        store.record_duration(1, 12, Duration::from_millis(8));
+
+        assert_eq!(store.fastest(&[0, 1], 10), Some(1));
+        assert_eq!(store.fastest(&[0, 1], 12), Some(0));
+        assert_eq!(store.fastest(&[], 10), None);
+    }
+
+    fn store_with_optimizations(num_optimizations: usize) -> OptimizationStore<usize> {
+        let mut store = OptimizationStore::new();
+
+        for value in 0..num_optimizations {
+            store.add(OptimizationItem {
+                stream: stream(value),
+                end_conditions: Vec::new(),
+                value,
+            });
+        }
+
+        store
+    }
+
+    fn values(store: &OptimizationStore<usize>) -> Vec<usize> {
+        store.optimizations.iter().map(|item| item.value).collect()
+    }
+
+    /// A stream with a single operation, different for each value.
+    fn stream(value: usize) -> Vec<TensorOpsDescription> {
+        let tensor = |id: u64| TensorDescription {
+            id: TensorId::new(id),
+            shape: vec![32, 32, value + 1],
+            status: TensorStatus::NotInit,
+        };
+
+        vec![TensorOpsDescription::FloatOps(FloatOpsDescription::Log(
+            UnaryOpsDescription {
+                input: tensor(0),
+                out: tensor(1),
+            },
+        ))]
+    }
 }
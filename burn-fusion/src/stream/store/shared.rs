@@ -0,0 +1,136 @@
+use spin::RwLock;
+use std::{
+    any::{Any, TypeId},
+    collections::HashMap,
+    sync::Arc,
+};
+
+/// The optimizations shared between all the devices of the process.
+pub(crate) static SHARED_OPTIMIZATIONS: SharedOptimizations = SharedOptimizations::new();
+
+/// Optimizations shared between the devices of the same backend.
+///
+/// Each device keeps its own [store](super::OptimizationStore), since optimizations are created
+/// for a device and different devices can benefit from different fusions. When sharing is
+/// [enabled](crate::FusionConfig::share_optimizations), a device publishes the serialized state of
+/// the optimizations it finds here, and imports the ones published by the other devices instead of
+/// exploring the same streams again. The shared layer is append-only: devices never evict or
+/// modify the optimizations published by others.
+///
+/// The optimizations are sharded by backend, each shard having its own lock, so that devices of
+/// different backends never wait for each other and concurrent readers of a shard don't block.
+pub(crate) struct SharedOptimizations {
+    shards: RwLock<Option<HashMap<TypeId, Arc<Shard>>>>,
+}
+
+type Shard = RwLock<Vec<String>>;
+
+impl SharedOptimizations {
+    pub(crate) const fn new() -> Self {
+        Self {
+            shards: RwLock::new(None),
+        }
+    }
+
+    /// Publish serialized optimizations for the backend `B`.
+    pub(crate) fn publish<B: Any>(&self, items: Vec<String>) {
+        if items.is_empty() {
+            return;
+        }
+
+        self.shard::<B>().write().extend(items);
+    }
+
+    /// The optimizations published for the backend `B`, starting at the given position.
+    ///
+    /// Each device keeps the position of the next optimization it hasn't imported yet, which is
+    /// valid forever since optimizations are only appended.
+    pub(crate) fn read<B: Any>(&self, start: usize) -> Vec<String> {
+        let shard = self.shard::<B>();
+        let items = shard.read();
+
+        match items.len() > start {
+            true => items[start..].to_vec(),
+            false => Vec::new(),
+        }
+    }
+
+    /// The shard of the backend `B`, created the first time it is accessed.
+    fn shard<B: Any>(&self) -> Arc<Shard> {
+        let key = TypeId::of::<B>();
+
+        if let Some(shard) = self
+            .shards
+            .read()
+            .as_ref()
+            .and_then(|shards| shards.get(&key))
+        {
+            return shard.clone();
+        }
+
+        self.shards
+            .write()
+            .get_or_insert_with(HashMap::new)
+            .entry(key)
+            .or_default()
+            .clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct BackendA;
+    struct BackendB;
+
+    #[test]
+    fn should_only_read_the_optimizations_of_the_same_backend() {
+        let shared = SharedOptimizations::new();
+        shared.publish::<BackendA>(vec!["a0".into(), "a1".into()]);
+        shared.publish::<BackendB>(vec!["b0".into()]);
+
+        assert_eq!(shared.read::<BackendA>(0), vec!["a0", "a1"]);
+        assert_eq!(shared.read::<BackendB>(0), vec!["b0"]);
+    }
+
+    #[test]
+    fn should_read_from_the_given_position() {
+        let shared = SharedOptimizations::new();
+        shared.publish::<BackendA>(vec!["a0".into()]);
+        shared.publish::<BackendA>(vec!["a1".into(), "a2".into()]);
+
+        assert_eq!(shared.read::<BackendA>(1), vec!["a1", "a2"]);
+        assert!(shared.read::<BackendA>(3).is_empty());
+        assert!(shared.read::<BackendB>(0).is_empty());
+    }
+
+    #[test]
+    fn should_keep_every_optimization_published_concurrently() {
+        let shared = Arc::new(SharedOptimizations::new());
+
+        let threads = (0..8)
+            .map(|thread| {
+                let shared = shared.clone();
+
+                std::thread::spawn(move || {
+                    let mut num_read = 0;
+
+                    for i in 0..100 {
+                        shared.publish::<BackendA>(vec![format!("{thread}-{i}")]);
+                        num_read += shared.read::<BackendA>(num_read).len();
+                    }
+                })
+            })
+            .collect::<Vec<_>>();
+
+        for thread in threads {
+            thread.join().unwrap();
+        }
+
+        let mut items = shared.read::<BackendA>(0);
+        items.sort();
+        items.dedup();
+        assert_eq!(items.len(), 800);
+    }
+}
@@ -21,6 +21,9 @@ pub struct FusionTensor<C: FusionClient> {
     // When a tensor is dropped and is still an orphan, we need to register it as such to avoid
     // memory leak. Otherwise, the cleanup is going to happen during a graph execution.
     pub(crate) is_orphan: bool,
+    /// The value of all elements when the tensor is filled with a value known at registration time,
+    /// used to fold the operations on constant tensors.
+    pub(crate) constant: Option<f64>,
 }
 
 impl<C: FusionClient> core::fmt::Debug for FusionTensor<C> {
@@ -46,6 +49,7 @@ impl<C: FusionClient> FusionTensor<C> {
             shape,
             client,
             is_orphan: true,
+            constant: None,
         }
     }
     pub(crate) fn shape<const D: usize>(&self) -> Shape<D> {
@@ -158,6 +162,12 @@ pub struct TensorDescription {
     pub status: TensorStatus,
 }
 
+impl core::fmt::Display for TensorId {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.value)
+    }
+}
+
 impl TensorId {
     /// Create a new tensor id.
     pub fn new(value: u64) -> Self {
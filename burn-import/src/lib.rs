@@ -16,6 +16,10 @@ extern crate derive_new;
 #[cfg(feature = "onnx")]
 pub mod onnx;
 
+/// The safetensors module.
+#[cfg(feature = "safetensors")]
+pub mod safetensors;
+
 /// The module for generating the burn code.
 pub mod burn;
 
@@ -0,0 +1,173 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    path::Path,
+};
+
+use burn::{module::ParamId, record::RecorderError};
+use candle_core::{DType, Device, Tensor};
+use regex::Regex;
+use serde_json::{json, Map, Value};
+
+/// Read the tensors of a safetensors file as the nested item of a record.
+pub(crate) fn read_file(
+    path: &Path,
+    key_remap: &[(Regex, String)],
+    transposed: &[Regex],
+) -> Result<Value, RecorderError> {
+    if !path.exists() {
+        return Err(RecorderError::FileNotFound(path.display().to_string()));
+    }
+
+    let tensors = candle_core::safetensors::load(path, &Device::Cpu).map_err(candle_error)?;
+
+    read_item(tensors, key_remap, transposed)
+}
+
+/// Read the tensors of safetensors bytes as the nested item of a record.
+pub(crate) fn read_bytes(
+    bytes: &[u8],
+    key_remap: &[(Regex, String)],
+    transposed: &[Regex],
+) -> Result<Value, RecorderError> {
+    let tensors =
+        candle_core::safetensors::load_buffer(bytes, &Device::Cpu).map_err(candle_error)?;
+
+    read_item(tensors, key_remap, transposed)
+}
+
+/// Convert the tensors into the nested item of a record.
+///
+/// The keys of the tensors are split on dots, so `layers.0.attention.weight` becomes the field
+/// `weight` of the field `attention` of the first element of the field `layers`.
+fn read_item(
+    tensors: HashMap<String, Tensor>,
+    key_remap: &[(Regex, String)],
+    transposed: &[Regex],
+) -> Result<Value, RecorderError> {
+    let mut item = Node::Fields(BTreeMap::new());
+
+    for (key, tensor) in tensors {
+        let tensor = match transposed.iter().any(|pattern| pattern.is_match(&key)) {
+            true => tensor.t().map_err(candle_error)?,
+            false => tensor,
+        };
+        let key = key_remap.iter().fold(key, |key, (pattern, replacement)| {
+            pattern.replace_all(&key, replacement.as_str()).to_string()
+        });
+
+        item.insert(&key, param(tensor)?)?;
+    }
+
+    Ok(item.into_value())
+}
+
+/// Serialize the tensor the same way as the record of a parameter.
+fn param(tensor: Tensor) -> Result<Value, RecorderError> {
+    let shape = tensor.dims().to_vec();
+    let tensor = tensor.flatten_all().map_err(candle_error)?;
+
+    let value: Vec<Value> = match tensor.dtype() {
+        DType::U8 | DType::U32 | DType::I64 => tensor
+            .to_dtype(DType::I64)
+            .and_then(|tensor| tensor.to_vec1::<i64>())
+            .map_err(candle_error)?
+            .into_iter()
+            .map(Value::from)
+            .collect(),
+        _ => tensor
+            .to_dtype(DType::F64)
+            .and_then(|tensor| tensor.to_vec1::<f64>())
+            .map_err(candle_error)?
+            .into_iter()
+            .map(Value::from)
+            .collect(),
+    };
+
+    Ok(json!({
+        "id": ParamId::new().into_string(),
+        "param": {
+            "value": value,
+            "shape": shape,
+        },
+    }))
+}
+
+/// Node of the tree of fields formed by the keys of the tensors.
+enum Node {
+    Param(Value),
+    Fields(BTreeMap<String, Node>),
+}
+
+impl Node {
+    fn insert(&mut self, key: &str, param: Value) -> Result<(), RecorderError> {
+        let mut node = self;
+
+        for field in key.split('.') {
+            node = match node {
+                Node::Fields(fields) => fields
+                    .entry(field.to_string())
+                    .or_insert_with(|| Node::Fields(BTreeMap::new())),
+                Node::Param(_) => {
+                    return Err(RecorderError::Unknown(format!(
+                        "The tensor {key} is nested in another tensor"
+                    )))
+                }
+            };
+        }
+
+        match node {
+            Node::Fields(fields) if fields.is_empty() => {
+                *node = Node::Param(param);
+                Ok(())
+            }
+            _ => Err(RecorderError::Unknown(format!(
+                "The tensor {key} has nested tensors or is mapped more than once"
+            ))),
+        }
+    }
+
+    /// Convert the node into the value of the item, where the fields named after the indices
+    /// `0..n` become sequences, which is how the records of vectors of modules are deserialized.
+    fn into_value(self) -> Value {
+        let fields = match self {
+            Node::Param(value) => return value,
+            Node::Fields(fields) => fields,
+        };
+
+        let indices = fields
+            .keys()
+            .map(|field| field.parse::<usize>().ok())
+            .collect::<Option<Vec<_>>>()
+            .unwrap_or_default();
+        let mut indices_sorted = indices.clone();
+        indices_sorted.sort();
+
+        let is_sequence = !indices_sorted.is_empty()
+            && indices_sorted
+                .iter()
+                .enumerate()
+                .all(|(pos, index)| pos == *index);
+
+        if !is_sequence {
+            return Value::Object(
+                fields
+                    .into_iter()
+                    .map(|(field, node)| (field, node.into_value()))
+                    .collect::<Map<_, _>>(),
+            );
+        }
+
+        let mut elements = fields
+            .into_values()
+            .zip(indices)
+            .map(|(node, index)| (index, node.into_value()))
+            .collect::<Vec<_>>();
+        elements.sort_by_key(|(index, _)| *index);
+
+        Value::Array(elements.into_iter().map(|(_, value)| value).collect())
+    }
+}
+
+fn candle_error(err: candle_core::Error) -> RecorderError {
+    RecorderError::Unknown(err.to_string())
+}
@@ -0,0 +1,275 @@
+use core::any::type_name;
+use std::path::PathBuf;
+
+use burn::record::{
+    BurnMetadata, FullPrecisionSettings, PrecisionSettings, Recorder, RecorderError,
+};
+use regex::Regex;
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::{json, Value};
+
+use super::reader::{read_bytes, read_file};
+
+/// Recorder loading the weights of a [safetensors](https://github.com/huggingface/safetensors)
+/// file, such as the pretrained models of [candle](https://github.com/huggingface/candle), into
+/// the records of burn modules.
+///
+/// The keys of the tensors are mapped to the fields of the record by splitting them on dots, so
+/// `encoder.layers.0.weight` is loaded into the `weight` parameter of the first module of the
+/// `layers` vector of the `encoder` module. The keys can be renamed with
+/// [key remaps](LoadArgs::with_key_remap) when the modules are named differently.
+///
+/// # Notes
+///
+/// The weights are loaded with full precision and saving records isn't supported.
+#[derive(new, Debug, Default, Clone)]
+pub struct SafetensorsFileRecorder;
+
+/// Arguments of the [safetensors recorder](SafetensorsFileRecorder).
+#[derive(Debug, Clone)]
+pub struct LoadArgs {
+    /// The safetensors file.
+    pub file: PathBuf,
+    /// The patterns of the keys to rename and their replacements, applied in order.
+    pub key_remap: Vec<(Regex, String)>,
+    /// The patterns of the keys of the matrices to transpose.
+    pub transposed: Vec<Regex>,
+}
+
+impl LoadArgs {
+    /// Create the arguments to load the given file.
+    pub fn new(file: PathBuf) -> Self {
+        Self {
+            file,
+            key_remap: Vec::new(),
+            transposed: Vec::new(),
+        }
+    }
+
+    /// Rename the keys matching the regular expression, where the replacement can refer to the
+    /// groups of the pattern with `$1`, `$2` and so on.
+    ///
+    /// # Panics
+    ///
+    /// If the pattern isn't a valid regular expression.
+    pub fn with_key_remap(mut self, pattern: &str, replacement: &str) -> Self {
+        let pattern = Regex::new(pattern).expect("Valid regular expression");
+        self.key_remap.push((pattern, replacement.to_string()));
+        self
+    }
+
+    /// Transpose the matrices whose original keys match the regular expression.
+    ///
+    /// This is needed for the weights of linear layers, which candle and PyTorch store with the
+    /// shape `[d_output, d_input]` while burn uses `[d_input, d_output]`.
+    ///
+    /// # Panics
+    ///
+    /// If the pattern isn't a valid regular expression.
+    pub fn with_transposed(mut self, pattern: &str) -> Self {
+        let pattern = Regex::new(pattern).expect("Valid regular expression");
+        self.transposed.push(pattern);
+        self
+    }
+}
+
+/// Recorder loading the weights of safetensors bytes into the records of burn modules, the same
+/// way as the [file recorder](SafetensorsFileRecorder).
+///
+/// This is useful when there is no file system, such as in the browser, where the weights are
+/// fetched or embedded in the binary with `include_bytes!`.
+#[derive(new, Debug, Default, Clone)]
+pub struct SafetensorsBytesRecorder;
+
+/// Arguments of the [safetensors bytes recorder](SafetensorsBytesRecorder).
+#[derive(Debug, Clone)]
+pub struct BytesLoadArgs {
+    /// The content of a safetensors file.
+    pub bytes: Vec<u8>,
+    /// The patterns of the keys to rename and their replacements, applied in order.
+    pub key_remap: Vec<(Regex, String)>,
+    /// The patterns of the keys of the matrices to transpose.
+    pub transposed: Vec<Regex>,
+}
+
+impl BytesLoadArgs {
+    /// Create the arguments to load the given bytes.
+    pub fn new(bytes: Vec<u8>) -> Self {
+        Self {
+            bytes,
+            key_remap: Vec::new(),
+            transposed: Vec::new(),
+        }
+    }
+
+    /// Rename the keys matching the regular expression, see [LoadArgs::with_key_remap].
+    ///
+    /// # Panics
+    ///
+    /// If the pattern isn't a valid regular expression.
+    pub fn with_key_remap(mut self, pattern: &str, replacement: &str) -> Self {
+        let pattern = Regex::new(pattern).expect("Valid regular expression");
+        self.key_remap.push((pattern, replacement.to_string()));
+        self
+    }
+
+    /// Transpose the matrices whose original keys match the regular expression, see
+    /// [LoadArgs::with_transposed].
+    ///
+    /// # Panics
+    ///
+    /// If the pattern isn't a valid regular expression.
+    pub fn with_transposed(mut self, pattern: &str) -> Self {
+        let pattern = Regex::new(pattern).expect("Valid regular expression");
+        self.transposed.push(pattern);
+        self
+    }
+}
+
+impl From<Vec<u8>> for BytesLoadArgs {
+    fn from(bytes: Vec<u8>) -> Self {
+        Self::new(bytes)
+    }
+}
+
+impl From<PathBuf> for LoadArgs {
+    fn from(file: PathBuf) -> Self {
+        Self::new(file)
+    }
+}
+
+impl From<&str> for LoadArgs {
+    fn from(file: &str) -> Self {
+        Self::new(file.into())
+    }
+}
+
+impl Recorder for SafetensorsFileRecorder {
+    type Settings = FullPrecisionSettings;
+    type RecordArgs = PathBuf;
+    type RecordOutput = ();
+    type LoadArgs = LoadArgs;
+
+    fn save_item<I: Serialize>(
+        &self,
+        _item: I,
+        _file: Self::RecordArgs,
+    ) -> Result<(), RecorderError> {
+        Err(RecorderError::Unknown(
+            "Saving records to safetensors files isn't supported".to_string(),
+        ))
+    }
+
+    fn load_item<I: DeserializeOwned>(&self, args: Self::LoadArgs) -> Result<I, RecorderError> {
+        let item = read_file(&args.file, &args.key_remap, &args.transposed)?;
+
+        deserialize_item::<Self, I>(item)
+    }
+}
+
+impl Recorder for SafetensorsBytesRecorder {
+    type Settings = FullPrecisionSettings;
+    type RecordArgs = ();
+    type RecordOutput = Vec<u8>;
+    type LoadArgs = BytesLoadArgs;
+
+    fn save_item<I: Serialize>(
+        &self,
+        _item: I,
+        _args: Self::RecordArgs,
+    ) -> Result<Vec<u8>, RecorderError> {
+        Err(RecorderError::Unknown(
+            "Saving records to safetensors bytes isn't supported".to_string(),
+        ))
+    }
+
+    fn load_item<I: DeserializeOwned>(&self, args: Self::LoadArgs) -> Result<I, RecorderError> {
+        let item = read_bytes(&args.bytes, &args.key_remap, &args.transposed)?;
+
+        deserialize_item::<Self, I>(item)
+    }
+}
+
+/// Deserialize the item read by the recorder along with the metadata of its settings.
+fn deserialize_item<R: Recorder, I: DeserializeOwned>(item: Value) -> Result<I, RecorderError> {
+    let metadata = BurnMetadata::new(
+        type_name::<<R::Settings as PrecisionSettings>::FloatElem>().to_string(),
+        type_name::<<R::Settings as PrecisionSettings>::IntElem>().to_string(),
+        type_name::<R>().to_string(),
+        env!("CARGO_PKG_VERSION").to_string(),
+        format!("{:?}", R::Settings::default()),
+    );
+
+    serde_json::from_value(json!({ "metadata": metadata, "item": item }))
+        .map_err(|err| RecorderError::Unknown(err.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use burn::{
+        module::Module,
+        nn::{Linear, LinearConfig, LinearRecord},
+        tensor::Data,
+    };
+    use candle_core::{Device, Tensor};
+    use std::collections::HashMap;
+
+    type TestBackend = burn_ndarray::NdArray<f32>;
+
+    #[test]
+    fn should_load_candle_weights_into_modules() {
+        let path = std::env::temp_dir().join(format!(
+            "burn-import-safetensors-{}.safetensors",
+            std::process::id()
+        ));
+        let weight = Tensor::new(&[[1f32, 2., 3.], [4., 5., 6.]], &Device::Cpu).unwrap();
+        let bias = Tensor::new(&[7f32, 8.], &Device::Cpu).unwrap();
+        let tensors = HashMap::from([
+            ("model.fc.weight".to_string(), weight),
+            ("model.fc.bias".to_string(), bias),
+        ]);
+        candle_core::safetensors::save(&tensors, &path).unwrap();
+
+        let args = LoadArgs::new(path.clone())
+            .with_key_remap(r"^model\.fc\.(.*)$", "$1")
+            .with_transposed(r"fc\.weight$");
+        let record: LinearRecord<TestBackend> = SafetensorsFileRecorder::new().load(args).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        let linear: Linear<TestBackend> = LinearConfig::new(3, 2)
+            .init(&Default::default())
+            .load_record(record);
+
+        assert_eq!(
+            linear.weight.val().into_data(),
+            Data::from([[1., 4.], [2., 5.], [3., 6.]])
+        );
+        assert_eq!(linear.bias.unwrap().val().into_data(), Data::from([7., 8.]));
+    }
+
+    #[test]
+    fn should_load_candle_weights_from_bytes() {
+        let path = std::env::temp_dir().join(format!(
+            "burn-import-safetensors-bytes-{}.safetensors",
+            std::process::id()
+        ));
+        let weight = Tensor::new(&[[1f32, 2.], [3., 4.]], &Device::Cpu).unwrap();
+        let tensors = HashMap::from([("weight".to_string(), weight)]);
+        candle_core::safetensors::save(&tensors, &path).unwrap();
+        let bytes = std::fs::read(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        let args = BytesLoadArgs::new(bytes).with_transposed("^weight$");
+        let record: LinearRecord<TestBackend> = SafetensorsBytesRecorder::new().load(args).unwrap();
+        let linear: Linear<TestBackend> = LinearConfig::new(2, 2)
+            .with_bias(false)
+            .init(&Default::default())
+            .load_record(record);
+
+        assert_eq!(
+            linear.weight.val().into_data(),
+            Data::from([[1., 3.], [2., 4.]])
+        );
+    }
+}
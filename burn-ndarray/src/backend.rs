@@ -2,8 +2,10 @@ use crate::element::FloatNdArrayElement;
 use crate::NdArrayTensor;
 use alloc::string::String;
 use burn_common::stub::Mutex;
-use burn_tensor::backend::Backend;
+use burn_tensor::backend::{Backend, ComplexBackend};
+use burn_tensor::Element;
 use core::marker::PhantomData;
+use num_complex::Complex;
 use rand::{rngs::StdRng, SeedableRng};
 
 pub(crate) static SEED: Mutex<Option<StdRng>> = Mutex::new(None);
@@ -57,3 +59,12 @@ impl<E: FloatNdArrayElement> Backend for NdArray<E> {
         *seed = Some(rng);
     }
 }
+
+impl<E> ComplexBackend for NdArray<E>
+where
+    E: FloatNdArrayElement + num_traits::Float,
+    Complex<E>: Element,
+{
+    type ComplexTensorPrimitive<const D: usize> = NdArrayTensor<Complex<E>, D>;
+    type ComplexElem = Complex<E>;
+}
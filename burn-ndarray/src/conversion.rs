@@ -0,0 +1,124 @@
+use crate::{element::FloatNdArrayElement, NdArray, NdArrayTensor};
+use burn_tensor::{Float, Int, Tensor};
+use ndarray::{ArcArray, ArrayD, IxDyn};
+
+/// Conversion between tensors of the [ndarray backend](NdArray) and arrays of the
+/// [ndarray](ndarray) crate.
+///
+/// The storage is shared whenever possible: an array is only copied when it isn't contiguous in
+/// memory, and a tensor is only copied when its storage is shared with other tensors.
+pub trait NdArrayTensorExt<E>: Sized {
+    /// Create a tensor from an array.
+    ///
+    /// # Panics
+    ///
+    /// If the number of dimensions of the array isn't the rank of the tensor.
+    fn from_ndarray(array: ArrayD<E>) -> Self;
+
+    /// Convert the tensor into an array.
+    fn into_ndarray(self) -> ArrayD<E>;
+}
+
+impl<E: FloatNdArrayElement, const D: usize> NdArrayTensorExt<E> for Tensor<NdArray<E>, D, Float> {
+    fn from_ndarray(array: ArrayD<E>) -> Self {
+        Tensor::from_primitive(into_tensor(array))
+    }
+
+    fn into_ndarray(self) -> ArrayD<E> {
+        self.into_primitive().array.into_owned()
+    }
+}
+
+impl<E: FloatNdArrayElement, const D: usize> NdArrayTensorExt<i64> for Tensor<NdArray<E>, D, Int> {
+    fn from_ndarray(array: ArrayD<i64>) -> Self {
+        Tensor::from_primitive(into_tensor(array))
+    }
+
+    fn into_ndarray(self) -> ArrayD<i64> {
+        self.into_primitive().array.into_owned()
+    }
+}
+
+fn into_tensor<E: Clone, const D: usize>(array: ArrayD<E>) -> NdArrayTensor<E, D> {
+    assert_eq!(
+        array.ndim(),
+        D,
+        "The array has {} dimensions, expected {D}",
+        array.ndim()
+    );
+
+    let contiguous = array.as_slice_memory_order().is_some()
+        && array.strides().iter().all(|stride| *stride >= 0);
+
+    let array: ArcArray<E, IxDyn> = match contiguous {
+        true => array.into_shared(),
+        false => array.as_standard_layout().into_owned().into_shared(),
+    };
+
+    NdArrayTensor::new(array)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+    use burn_tensor::Data;
+    use ndarray::{s, Array};
+
+    type TestBackend = NdArray<f32>;
+
+    #[test]
+    fn from_ndarray_shares_contiguous_storage() {
+        let array = Array::from_shape_vec(vec![2, 3], vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]).unwrap();
+        let ptr = array.as_ptr();
+
+        let tensor = Tensor::<TestBackend, 2>::from_ndarray(array);
+
+        assert_eq!(tensor.clone().into_primitive().array.as_ptr(), ptr);
+        tensor
+            .into_data()
+            .assert_approx_eq(&Data::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]), 3);
+    }
+
+    #[test]
+    fn from_ndarray_copies_non_contiguous_array() {
+        let mut array =
+            Array::from_shape_vec(vec![2, 3], vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]).unwrap();
+        array.slice_collapse(s![.., ..;2]);
+
+        let tensor = Tensor::<TestBackend, 2>::from_ndarray(array);
+
+        tensor
+            .into_data()
+            .assert_approx_eq(&Data::from([[1.0, 3.0], [4.0, 6.0]]), 3);
+    }
+
+    #[test]
+    fn into_ndarray_reuses_unique_storage() {
+        let tensor =
+            Tensor::<TestBackend, 2>::from_floats([[1.0, 2.0], [3.0, 4.0]], &Default::default());
+        let ptr = tensor.clone().into_primitive().array.as_ptr();
+
+        let array = tensor.into_ndarray();
+
+        assert_eq!(array.as_ptr(), ptr);
+        assert_eq!(array.shape(), &[2, 2]);
+    }
+
+    #[test]
+    fn int_round_trip() {
+        let array = Array::from_shape_vec(vec![3], vec![1i64, 2, 3]).unwrap();
+
+        let tensor = Tensor::<TestBackend, 1, Int>::from_ndarray(array.clone());
+
+        assert_eq!(tensor.into_ndarray(), array);
+    }
+
+    #[test]
+    #[should_panic]
+    fn from_ndarray_panics_on_rank_mismatch() {
+        let array = Array::from_shape_vec(vec![3], vec![1.0, 2.0, 3.0]).unwrap();
+
+        let _tensor = Tensor::<TestBackend, 2>::from_ndarray(array);
+    }
+}
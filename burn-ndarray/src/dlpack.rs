@@ -0,0 +1,79 @@
+use crate::{element::FloatNdArrayElement, NdArray, NdArrayDevice, NdArrayTensor};
+use alloc::vec::Vec;
+use burn_tensor::dlpack::{DLDataType, DLDevice, DLPackBackend, DLPackError, DLPackTensor};
+
+impl<E: FloatNdArrayElement> DLPackBackend for NdArray<E> {
+    /// The exported tensor shares the memory of the array, whatever its layout.
+    fn float_to_dlpack<const D: usize>(tensor: NdArrayTensor<E, D>) -> DLPackTensor {
+        let array = tensor.array;
+        let dtype = DLDataType::of::<E>().expect("Float elements should be supported by DLPack");
+        let shape = array
+            .shape()
+            .iter()
+            .map(|dim| *dim as i64)
+            .collect::<Vec<_>>();
+        let strides = array
+            .strides()
+            .iter()
+            .map(|stride| *stride as i64)
+            .collect::<Vec<_>>();
+        let data = array.as_ptr() as *mut core::ffi::c_void;
+
+        // SAFETY: The array owns the memory and is kept alive by the managed tensor.
+        unsafe { DLPackTensor::new(array, data, DLDevice::cpu(), dtype, shape, strides) }
+    }
+
+    /// The array must own its memory, so the elements are always copied.
+    fn float_from_dlpack<const D: usize>(
+        tensor: DLPackTensor,
+        _device: &NdArrayDevice,
+    ) -> Result<NdArrayTensor<E, D>, DLPackError> {
+        Ok(NdArrayTensor::from_data(tensor.to_data()?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::NdArray;
+    use burn_tensor::{dlpack::DLDataType, Data, Tensor};
+
+    type TestBackend = NdArray<f32>;
+
+    #[test]
+    fn export_shares_memory() {
+        let tensor = Tensor::<TestBackend, 2>::from_floats(
+            [[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]],
+            &Default::default(),
+        );
+        let data = tensor.clone().into_primitive().array.as_ptr();
+
+        let exported = tensor.to_dlpack();
+
+        assert_eq!(exported.data_ptr() as *const f32, data);
+        assert_eq!(exported.shape(), &[2, 3]);
+        assert_eq!(exported.strides(), alloc::vec![3, 1]);
+        assert_eq!(exported.dtype(), DLDataType::new(DLDataType::FLOAT, 32));
+    }
+
+    #[test]
+    fn round_trip_with_strides() {
+        let device = Default::default();
+        let tensor =
+            Tensor::<TestBackend, 2>::from_floats([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]], &device);
+
+        let exported = tensor.clone().transpose().to_dlpack();
+        let imported = Tensor::<TestBackend, 2>::from_dlpack(exported, &device).unwrap();
+
+        imported
+            .into_data()
+            .assert_approx_eq(&Data::from([[1.0, 4.0], [2.0, 5.0], [3.0, 6.0]]), 3);
+    }
+
+    #[test]
+    fn import_fails_on_rank_mismatch() {
+        let device = Default::default();
+        let exported = Tensor::<TestBackend, 1>::from_floats([1.0, 2.0], &device).to_dlpack();
+
+        assert!(Tensor::<TestBackend, 2>::from_dlpack(exported, &device).is_err());
+    }
+}
@@ -14,6 +14,8 @@ extern crate derive_new;
 extern crate blas_src;
 
 mod backend;
+mod conversion;
+mod dlpack;
 mod element;
 mod ops;
 mod parallel;
@@ -21,10 +23,17 @@ mod sharing;
 mod tensor;
 
 pub use backend::*;
+pub use conversion::NdArrayTensorExt;
 pub use element::FloatNdArrayElement;
+#[cfg(feature = "std")]
+pub use parallel::{num_threads, set_parallel_config, ParallelConfig, ThreadStartHandler};
+pub use parallel::{parallel_threshold, ParallelOp};
 pub(crate) use sharing::*;
 pub use tensor::*;
 
+/// Re-export of the [ndarray](ndarray) crate used by the backend.
+pub use ndarray;
+
 extern crate alloc;
 
 #[cfg(test)]
@@ -38,6 +47,10 @@ mod tests {
     use alloc::vec;
 
     burn_tensor::testgen_all!();
+    burn_tensor::testgen_complex!();
+
+    #[cfg(feature = "std")]
+    burn_tensor::testgen_fft!();
 
     #[cfg(feature = "std")]
     burn_autodiff::testgen_all!();
@@ -16,10 +16,11 @@ pub(crate) fn adaptive_avg_pool2d<E: FloatNdArrayElement>(
         (batch_size, channels, output_size[0], output_size[1]),
         0.elem(),
     );
+    let num_elements = output.len();
     let unsafe_shared_out = UnsafeSharedRef::new(&mut output);
 
     run_par!(|| {
-        iter_range_par!(0, batch_size * channels).for_each(|k| unsafe {
+        iter_range_par!(0, batch_size * channels; Pool, num_elements).for_each(|k| unsafe {
             let b = k / channels;
             let c = k % channels;
 
@@ -58,10 +59,11 @@ pub(crate) fn adaptive_avg_pool2d_backward<E: FloatNdArrayElement>(
 
     let mut output_grad =
         Array4::from_elem((batch_size, channels, input_height, input_width), 0.elem());
+    let num_elements = output_grad.len();
     let unsafe_shared_out = UnsafeSharedRef::new(&mut output_grad);
 
     run_par!(|| {
-        iter_range_par!(0, batch_size * channels).for_each(|k| unsafe {
+        iter_range_par!(0, batch_size * channels; Pool, num_elements).for_each(|k| unsafe {
             let b = k / channels;
             let c = k % channels;
 
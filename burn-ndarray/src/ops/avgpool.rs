@@ -24,10 +24,11 @@ pub(crate) fn avg_pool2d<E: FloatNdArrayElement>(
     let x = x.array;
 
     let mut output = Array4::from_elem((batch_size, channels, out_height, out_width), 0.elem());
+    let num_elements = output.len();
     let unsafe_shared_out = UnsafeSharedRef::new(&mut output);
 
     run_par!(|| {
-        iter_range_par!(0, batch_size * channels).for_each(|k| unsafe {
+        iter_range_par!(0, batch_size * channels; Pool, num_elements).for_each(|k| unsafe {
             let b = k / channels;
             let c = k % channels;
 
@@ -89,10 +90,11 @@ pub(crate) fn avg_pool2d_backward<E: FloatNdArrayElement>(
     let grad = grad.array;
 
     let mut output_grad = Array4::from_elem((batch_size, channels, x_height, x_width), 0.elem());
+    let num_elements = output_grad.len();
     let unsafe_shared_grad = UnsafeSharedRef::new(&mut output_grad);
 
     run_par!(|| {
-        iter_range_par!(0, batch_size * channels).for_each(|k| unsafe {
+        iter_range_par!(0, batch_size * channels; Pool, num_elements).for_each(|k| unsafe {
             let b = k / channels;
             let c = k % channels;
 
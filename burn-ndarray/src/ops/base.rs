@@ -3,7 +3,9 @@ use burn_tensor::Data;
 use burn_tensor::ElementConversion;
 use core::{marker::PhantomData, ops::Range};
 use ndarray::s;
+use ndarray::ArcArray;
 use ndarray::Array2;
+use ndarray::ArrayView1;
 
 use burn_tensor::Shape;
 use ndarray::Axis;
@@ -12,7 +14,13 @@ use ndarray::IxDyn;
 use ndarray::SliceInfoElem;
 
 use crate::element::NdArrayElement;
+#[cfg(feature = "std")]
+use crate::ops::macros::reduce_lanes_par;
 use crate::ops::macros::{keepdim, mean_dim, sum_dim};
+#[cfg(feature = "std")]
+use crate::parallel::is_parallel;
+#[cfg(feature = "std")]
+use crate::ParallelOp;
 use crate::{reshape, tensor::NdArrayTensor};
 
 pub struct NdArrayOps<E> {
@@ -191,12 +199,17 @@ where
     }
 
     pub fn mean<const D: usize>(tensor: NdArrayTensor<E, D>) -> NdArrayTensor<E, 1> {
-        let data = Data::from([tensor.array.mean().unwrap()]);
+        let num_elements = tensor.array.len();
+        let mean = match num_elements {
+            0 => tensor.array.mean().unwrap(),
+            _ => sum_all(&tensor.array) / E::from_elem(num_elements),
+        };
+        let data = Data::from([mean]);
         NdArrayTensor::from_data(data)
     }
 
     pub fn sum<const D: usize>(tensor: NdArrayTensor<E, D>) -> NdArrayTensor<E, 1> {
-        let data = Data::from([tensor.array.sum()]);
+        let data = Data::from([sum_all(&tensor.array)]);
         NdArrayTensor::from_data(data)
     }
 
@@ -227,6 +240,24 @@ where
         }
     }
 
+    pub fn cumsum<const D: usize>(tensor: NdArrayTensor<E, D>, dim: usize) -> NdArrayTensor<E, D> {
+        let mut array = tensor.array.into_owned();
+        array.accumulate_axis_inplace(Axis(dim), |&previous, current| {
+            *current = previous + *current
+        });
+
+        NdArrayTensor::new(array.into_shared())
+    }
+
+    pub fn cumprod<const D: usize>(tensor: NdArrayTensor<E, D>, dim: usize) -> NdArrayTensor<E, D> {
+        let mut array = tensor.array.into_owned();
+        array.accumulate_axis_inplace(Axis(dim), |&previous, current| {
+            *current = previous * *current
+        });
+
+        NdArrayTensor::new(array.into_shared())
+    }
+
     pub fn gather<const D: usize>(
         dim: usize,
         mut tensor: NdArrayTensor<E, D>,
@@ -265,10 +296,49 @@ where
     }
 
     pub fn scatter<const D: usize>(
+        dim: usize,
+        tensor: NdArrayTensor<E, D>,
+        indices: NdArrayTensor<i64, D>,
+        value: NdArrayTensor<E, D>,
+    ) -> NdArrayTensor<E, D> {
+        Self::scatter_reduce(dim, tensor, indices, value, |current, value| {
+            *current += value
+        })
+    }
+
+    pub fn scatter_max<const D: usize>(
+        dim: usize,
+        tensor: NdArrayTensor<E, D>,
+        indices: NdArrayTensor<i64, D>,
+        value: NdArrayTensor<E, D>,
+    ) -> NdArrayTensor<E, D> {
+        Self::scatter_reduce(dim, tensor, indices, value, |current, value| {
+            if value > *current {
+                *current = value;
+            }
+        })
+    }
+
+    pub fn scatter_min<const D: usize>(
+        dim: usize,
+        tensor: NdArrayTensor<E, D>,
+        indices: NdArrayTensor<i64, D>,
+        value: NdArrayTensor<E, D>,
+    ) -> NdArrayTensor<E, D> {
+        Self::scatter_reduce(dim, tensor, indices, value, |current, value| {
+            if value < *current {
+                *current = value;
+            }
+        })
+    }
+
+    /// Combine each value with the element at its index along the dimension.
+    fn scatter_reduce<const D: usize>(
         dim: usize,
         mut tensor: NdArrayTensor<E, D>,
         mut indices: NdArrayTensor<i64, D>,
         mut value: NdArrayTensor<E, D>,
+        reduce: fn(&mut E, E),
     ) -> NdArrayTensor<E, D> {
         if dim != D - 1 {
             tensor.array.swap_axes(D - 1, dim);
@@ -302,7 +372,7 @@ where
 
             for (i, index) in indices.iter().enumerate() {
                 let index = *index as usize;
-                tensor[[b, index]] += value[[b, i]];
+                reduce(&mut tensor[[b, index]], value[[b, i]]);
             }
         }
 
@@ -474,7 +544,7 @@ fn arg<E: NdArrayElement, const D: usize>(
     let mut reshape = tensor.array.shape().to_vec();
     reshape[dim] = 1;
 
-    let output = tensor.array.map_axis(Axis(dim), |arr| {
+    let reduce = |arr: ArrayView1<E>| {
         // Find the min/max value in the array, and return its index.
         let (_e, idx) = arr.indexed_iter().fold((arr[0], 0usize), |acc, (idx, e)| {
             let cmp = match cmp {
@@ -490,7 +560,16 @@ fn arg<E: NdArrayElement, const D: usize>(
         });
 
         idx as i64
-    });
+    };
+
+    #[cfg(feature = "std")]
+    let output = match is_parallel(ParallelOp::Reduce, tensor.array.len()) {
+        true => reduce_lanes_par(&tensor.array, dim, reduce),
+        false => tensor.array.map_axis(Axis(dim), reduce),
+    };
+
+    #[cfg(not(feature = "std"))]
+    let output = tensor.array.map_axis(Axis(dim), reduce);
 
     let output = output.into_shape(Dim(reshape.as_slice())).unwrap();
 
@@ -499,6 +578,34 @@ fn arg<E: NdArrayElement, const D: usize>(
     }
 }
 
+/// Sum every element of the array.
+///
+/// Large contiguous arrays are summed in parallel by chunks of a fixed size, the partial sums are
+/// then added in order, so the result doesn't depend on the number of threads.
+fn sum_all<E: NdArrayElement>(array: &ArcArray<E, IxDyn>) -> E {
+    #[cfg(feature = "std")]
+    if is_parallel(ParallelOp::Reduce, array.len()) {
+        if let Some(slice) = array.as_slice_memory_order() {
+            use rayon::prelude::*;
+
+            const CHUNK_SIZE: usize = 4096;
+
+            let partial_sums: Vec<E> = crate::parallel::install(|| {
+                slice
+                    .par_chunks(CHUNK_SIZE)
+                    .map(|chunk| ArrayView1::from(chunk).sum())
+                    .collect()
+            });
+
+            return partial_sums
+                .into_iter()
+                .fold(num_traits::Zero::zero(), |acc: E, sum| acc + sum);
+        }
+    }
+
+    array.sum()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -536,4 +643,27 @@ mod tests {
             expected_array.array.into_iter().collect::<Vec<_>>(),
         );
     }
+
+    #[test]
+    fn should_reduce_large_tensors_in_parallel() {
+        let [rows, cols] = [256, 300];
+        let values = (0..rows * cols).map(|i| (i % 7) as i64).collect::<Vec<_>>();
+        let tensor =
+            NdArrayTensor::<i64, 2>::from_data(Data::new(values, Shape::new([rows, cols])));
+        let expected = tensor.array.sum_axis(Axis(0));
+
+        let sum_dim = NdArrayMathOps::sum_dim(tensor.clone(), 0);
+        let sum = NdArrayMathOps::sum(tensor.clone());
+        let argmax = NdArrayMathOps::argmax(tensor.clone(), 1);
+
+        assert_eq!(sum_dim.shape(), Shape::new([1, cols]));
+        assert_eq!(
+            sum_dim.array.into_iter().collect::<Vec<_>>(),
+            expected.into_iter().collect::<Vec<_>>()
+        );
+        assert_eq!(sum.array[[0]], tensor.array.sum());
+        for (row, index) in argmax.array.iter().enumerate() {
+            assert_eq!(tensor.array[[row, *index as usize]], 6);
+        }
+    }
 }
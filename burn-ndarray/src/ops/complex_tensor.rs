@@ -0,0 +1,119 @@
+// Current crate
+use crate::element::FloatNdArrayElement;
+use crate::NdArrayDevice;
+use crate::{tensor::NdArrayTensor, NdArray};
+
+// Workspace crates
+use burn_tensor::ops::ComplexTensorOps;
+use burn_tensor::{Data, Element, Reader, Shape};
+
+// External crates
+use ndarray::Zip;
+use num_complex::Complex;
+use num_traits::Float;
+
+use super::NdArrayOps;
+
+impl<E> ComplexTensorOps<Self> for NdArray<E>
+where
+    E: FloatNdArrayElement + Float,
+    Complex<E>: Element,
+{
+    fn complex_from_data<const D: usize>(
+        data: Data<Complex<E>, D>,
+        _device: &NdArrayDevice,
+    ) -> NdArrayTensor<Complex<E>, D> {
+        NdArrayTensor::from_data(data)
+    }
+
+    fn complex_into_data<const D: usize>(
+        tensor: NdArrayTensor<Complex<E>, D>,
+    ) -> Reader<Data<Complex<E>, D>> {
+        let shape = tensor.shape();
+        let values = tensor.array.into_iter().collect();
+
+        Reader::Concrete(Data::new(values, shape))
+    }
+
+    fn complex_shape<const D: usize>(tensor: &NdArrayTensor<Complex<E>, D>) -> Shape<D> {
+        tensor.shape()
+    }
+
+    fn complex_device<const D: usize>(_tensor: &NdArrayTensor<Complex<E>, D>) -> NdArrayDevice {
+        NdArrayDevice::Cpu
+    }
+
+    fn complex_reshape<const D1: usize, const D2: usize>(
+        tensor: NdArrayTensor<Complex<E>, D1>,
+        shape: Shape<D2>,
+    ) -> NdArrayTensor<Complex<E>, D2> {
+        NdArrayOps::reshape(tensor, shape)
+    }
+
+    fn complex_from_parts<const D: usize>(
+        real: NdArrayTensor<E, D>,
+        imag: NdArrayTensor<E, D>,
+    ) -> NdArrayTensor<Complex<E>, D> {
+        let array = Zip::from(&real.array)
+            .and(&imag.array)
+            .map_collect(|re, im| Complex::new(*re, *im));
+
+        NdArrayTensor::new(array.into_shared())
+    }
+
+    fn complex_real<const D: usize>(tensor: NdArrayTensor<Complex<E>, D>) -> NdArrayTensor<E, D> {
+        NdArrayTensor::new(tensor.array.mapv(|value| value.re).into_shared())
+    }
+
+    fn complex_imag<const D: usize>(tensor: NdArrayTensor<Complex<E>, D>) -> NdArrayTensor<E, D> {
+        NdArrayTensor::new(tensor.array.mapv(|value| value.im).into_shared())
+    }
+
+    fn complex_add<const D: usize>(
+        lhs: NdArrayTensor<Complex<E>, D>,
+        rhs: NdArrayTensor<Complex<E>, D>,
+    ) -> NdArrayTensor<Complex<E>, D> {
+        NdArrayTensor::new((&lhs.array + &rhs.array).into_shared())
+    }
+
+    fn complex_sub<const D: usize>(
+        lhs: NdArrayTensor<Complex<E>, D>,
+        rhs: NdArrayTensor<Complex<E>, D>,
+    ) -> NdArrayTensor<Complex<E>, D> {
+        NdArrayTensor::new((&lhs.array - &rhs.array).into_shared())
+    }
+
+    fn complex_mul<const D: usize>(
+        lhs: NdArrayTensor<Complex<E>, D>,
+        rhs: NdArrayTensor<Complex<E>, D>,
+    ) -> NdArrayTensor<Complex<E>, D> {
+        NdArrayTensor::new((&lhs.array * &rhs.array).into_shared())
+    }
+
+    fn complex_div<const D: usize>(
+        lhs: NdArrayTensor<Complex<E>, D>,
+        rhs: NdArrayTensor<Complex<E>, D>,
+    ) -> NdArrayTensor<Complex<E>, D> {
+        NdArrayTensor::new((&lhs.array / &rhs.array).into_shared())
+    }
+
+    fn complex_neg<const D: usize>(
+        tensor: NdArrayTensor<Complex<E>, D>,
+    ) -> NdArrayTensor<Complex<E>, D> {
+        NdArrayTensor::new(tensor.array.mapv(|value| -value).into_shared())
+    }
+
+    fn complex_conj<const D: usize>(
+        tensor: NdArrayTensor<Complex<E>, D>,
+    ) -> NdArrayTensor<Complex<E>, D> {
+        NdArrayTensor::new(tensor.array.mapv(|value| value.conj()).into_shared())
+    }
+
+    fn complex_abs<const D: usize>(tensor: NdArrayTensor<Complex<E>, D>) -> NdArrayTensor<E, D> {
+        NdArrayTensor::new(tensor.array.mapv(|value| value.norm()).into_shared())
+    }
+
+    fn complex_angle<const D: usize>(tensor: NdArrayTensor<Complex<E>, D>) -> NdArrayTensor<E, D> {
+        NdArrayTensor::new(tensor.array.mapv(|value| value.arg()).into_shared())
+    }
+}
@@ -81,9 +81,10 @@ pub(crate) fn conv2d<E: FloatNdArrayElement>(
     let weights = weight.array.into_dimensionality::<ndarray::Ix4>().unwrap();
 
     let mut output = Array3::zeros(Dim([batch_size * out_channels, out_height, out_width]));
+    let num_elements = output.len();
 
     run_par!(|| {
-        iter_par!(output.axis_iter_mut(Axis(0)))
+        iter_par!(output.axis_iter_mut(Axis(0)); Conv, num_elements)
             .enumerate()
             .for_each(
                 #[inline(never)]
@@ -196,56 +197,58 @@ pub(crate) fn conv_transpose2d<E: FloatNdArrayElement>(
         out_height,
         out_width,
     ]));
+    let num_elements = output.len();
 
     let unsafe_shared_out = UnsafeSharedRef::new(&mut output);
 
     run_par!(|| {
-        iter_range_par!(0, batch_size * out_channels * options.groups).for_each(|k| unsafe {
-            let b = k / (out_channels * options.groups);
-            let oc = k % out_channels;
-            let g = k % options.groups;
-
-            let output = unsafe_shared_out.get();
-
-            let oc_out = oc + (out_channels * g);
-            let ic_start = g * (in_channels / options.groups);
-            let ic_end = ic_start + in_channels / options.groups;
-
-            for ic in ic_start..ic_end {
-                for ih in 0..in_height {
-                    for iw in 0..in_width {
-                        for kh in 0..kernel_height {
-                            for kw in 0..kernel_width {
-                                let oh = ih * stride_height + kh * dilation_height;
-                                let ow = iw * stride_width + kw * dilation_width;
-
-                                if oh >= out_height + padding_height
-                                    || ow >= out_width + padding_width
-                                    || oh < padding_height
-                                    || ow < padding_width
-                                {
-                                    continue;
+        iter_range_par!(0, batch_size * out_channels * options.groups; Conv, num_elements)
+            .for_each(|k| unsafe {
+                let b = k / (out_channels * options.groups);
+                let oc = k % out_channels;
+                let g = k % options.groups;
+
+                let output = unsafe_shared_out.get();
+
+                let oc_out = oc + (out_channels * g);
+                let ic_start = g * (in_channels / options.groups);
+                let ic_end = ic_start + in_channels / options.groups;
+
+                for ic in ic_start..ic_end {
+                    for ih in 0..in_height {
+                        for iw in 0..in_width {
+                            for kh in 0..kernel_height {
+                                for kw in 0..kernel_width {
+                                    let oh = ih * stride_height + kh * dilation_height;
+                                    let ow = iw * stride_width + kw * dilation_width;
+
+                                    if oh >= out_height + padding_height
+                                        || ow >= out_width + padding_width
+                                        || oh < padding_height
+                                        || ow < padding_width
+                                    {
+                                        continue;
+                                    }
+
+                                    let oh = oh - padding_height;
+                                    let ow = ow - padding_width;
+
+                                    output[[b, oc_out, oh, ow]] +=
+                                        x[[b, ic, ih, iw]] * weight.array[[ic, oc, kh, kw]];
                                 }
-
-                                let oh = oh - padding_height;
-                                let ow = ow - padding_width;
-
-                                output[[b, oc_out, oh, ow]] +=
-                                    x[[b, ic, ih, iw]] * weight.array[[ic, oc, kh, kw]];
                             }
                         }
                     }
                 }
-            }
 
-            if let Some(bias) = &bias {
-                for oh in 0..out_height {
-                    for ow in 0..out_width {
-                        output[[b, oc_out, oh, ow]] += bias.array[oc_out];
+                if let Some(bias) = &bias {
+                    for oh in 0..out_height {
+                        for ow in 0..out_width {
+                            output[[b, oc_out, oh, ow]] += bias.array[oc_out];
+                        }
                     }
                 }
-            }
-        });
+            });
     });
 
     NdArrayTensor::new(output.into_dyn().into_shared())
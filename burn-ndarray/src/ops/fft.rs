@@ -0,0 +1,53 @@
+use crate::{element::FloatNdArrayElement, tensor::NdArrayTensor};
+use alloc::vec::Vec;
+use burn_tensor::ElementConversion;
+use ndarray::{Axis, Zip};
+use rustfft::{num_complex::Complex, FftDirection, FftPlanner};
+
+// The transforms are computed by rustfft in double precision, one lane along the dimension at a
+// time.
+
+pub(crate) fn fft<E: FloatNdArrayElement, const D: usize>(
+    real: NdArrayTensor<E, D>,
+    imag: NdArrayTensor<E, D>,
+    dim: usize,
+    inverse: bool,
+) -> (NdArrayTensor<E, D>, NdArrayTensor<E, D>) {
+    let mut signal = Zip::from(&real.array)
+        .and(&imag.array)
+        .map_collect(|re, im| Complex::new(re.elem::<f64>(), im.elem::<f64>()));
+
+    let n = signal.shape()[dim];
+    let direction = match inverse {
+        true => FftDirection::Inverse,
+        false => FftDirection::Forward,
+    };
+    // The inverse transform of rustfft isn't normalized.
+    let scale = match inverse {
+        true => 1.0 / usize::max(n, 1) as f64,
+        false => 1.0,
+    };
+
+    if n > 0 {
+        let plan = FftPlanner::new().plan_fft(n, direction);
+        let mut buffer = Vec::with_capacity(n);
+
+        for mut lane in signal.lanes_mut(Axis(dim)) {
+            buffer.clear();
+            buffer.extend(lane.iter().copied());
+            plan.process(&mut buffer);
+
+            for (value, transformed) in lane.iter_mut().zip(buffer.iter()) {
+                *value = transformed * scale;
+            }
+        }
+    }
+
+    let real = signal.mapv(|value| value.re.elem::<E>());
+    let imag = signal.mapv(|value| value.im.elem::<E>());
+
+    (
+        NdArrayTensor::new(real.into_shared()),
+        NdArrayTensor::new(imag.into_shared()),
+    )
+}
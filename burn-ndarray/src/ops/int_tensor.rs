@@ -277,6 +277,20 @@ impl<E: FloatNdArrayElement> IntTensorOps<Self> for NdArray<E> {
         NdArrayMathOps::sum_dim(tensor, dim)
     }
 
+    fn int_cumsum<const D: usize>(
+        tensor: NdArrayTensor<i64, D>,
+        dim: usize,
+    ) -> NdArrayTensor<i64, D> {
+        NdArrayMathOps::cumsum(tensor, dim)
+    }
+
+    fn int_cumprod<const D: usize>(
+        tensor: NdArrayTensor<i64, D>,
+        dim: usize,
+    ) -> NdArrayTensor<i64, D> {
+        NdArrayMathOps::cumprod(tensor, dim)
+    }
+
     fn int_mean<const D: usize>(tensor: NdArrayTensor<i64, D>) -> NdArrayTensor<i64, 1> {
         NdArrayMathOps::mean(tensor)
     }
@@ -305,6 +319,24 @@ impl<E: FloatNdArrayElement> IntTensorOps<Self> for NdArray<E> {
         NdArrayMathOps::scatter(dim, tensor, indices, value)
     }
 
+    fn int_scatter_max<const D: usize>(
+        dim: usize,
+        tensor: NdArrayTensor<i64, D>,
+        indices: NdArrayTensor<i64, D>,
+        value: NdArrayTensor<i64, D>,
+    ) -> NdArrayTensor<i64, D> {
+        NdArrayMathOps::scatter_max(dim, tensor, indices, value)
+    }
+
+    fn int_scatter_min<const D: usize>(
+        dim: usize,
+        tensor: NdArrayTensor<i64, D>,
+        indices: NdArrayTensor<i64, D>,
+        value: NdArrayTensor<i64, D>,
+    ) -> NdArrayTensor<i64, D> {
+        NdArrayMathOps::scatter_min(dim, tensor, indices, value)
+    }
+
     fn int_select<const D: usize>(
         tensor: NdArrayTensor<i64, D>,
         dim: usize,
@@ -0,0 +1,145 @@
+use crate::{element::FloatNdArrayElement, tensor::NdArrayTensor};
+use alloc::vec::Vec;
+use burn_tensor::{ElementConversion, Shape};
+use ndarray::{s, Array, Array2, Array3, IxDyn};
+use ndarray_linalg::{Cholesky, Factorize, Inverse, Solve, QR, SVD, UPLO};
+
+// The decompositions are computed by LAPACK in double precision, one matrix of the batch at a
+// time.
+
+pub(crate) fn solve<E: FloatNdArrayElement, const D: usize>(
+    lhs: NdArrayTensor<E, D>,
+    rhs: NdArrayTensor<E, D>,
+) -> NdArrayTensor<E, D> {
+    let shape = rhs.shape();
+    let lhs = matrices(lhs);
+    let rhs = matrices(rhs);
+
+    let output = lhs.outer_iter().zip(rhs.outer_iter()).map(|(a, b)| {
+        let lu = a
+            .factorize()
+            .expect("Can't solve a linear system with a singular matrix");
+        let mut x = Array2::zeros(b.raw_dim());
+
+        for (j, column) in b.columns().into_iter().enumerate() {
+            x.column_mut(j).assign(&lu.solve(&column).unwrap());
+        }
+
+        x
+    });
+
+    tensor(output, shape)
+}
+
+pub(crate) fn inverse<E: FloatNdArrayElement, const D: usize>(
+    tensor: NdArrayTensor<E, D>,
+) -> NdArrayTensor<E, D> {
+    let shape = tensor.shape();
+
+    let output = matrices(tensor)
+        .outer_iter()
+        .map(|a| a.inv().expect("Can't invert a singular matrix"))
+        .collect::<Vec<_>>();
+
+    self::tensor(output, shape)
+}
+
+pub(crate) fn cholesky<E: FloatNdArrayElement, const D: usize>(
+    tensor: NdArrayTensor<E, D>,
+) -> NdArrayTensor<E, D> {
+    let shape = tensor.shape();
+
+    let output = matrices(tensor)
+        .outer_iter()
+        .map(|a| {
+            a.cholesky(UPLO::Lower).expect(
+                "Can't compute the Cholesky decomposition of a matrix that isn't positive-definite",
+            )
+        })
+        .collect::<Vec<_>>();
+
+    self::tensor(output, shape)
+}
+
+pub(crate) fn qr<E: FloatNdArrayElement, const D: usize>(
+    tensor: NdArrayTensor<E, D>,
+) -> (NdArrayTensor<E, D>, NdArrayTensor<E, D>) {
+    let shape = tensor.shape();
+    let [m, n] = [shape.dims[D - 2], shape.dims[D - 1]];
+    let k = usize::min(m, n);
+
+    let (q, r): (Vec<_>, Vec<_>) = matrices(tensor)
+        .outer_iter()
+        .map(|a| a.qr().expect("Can't compute the QR decomposition"))
+        .unzip();
+
+    (
+        self::tensor(q, matrix_shape(&shape, m, k)),
+        self::tensor(r, matrix_shape(&shape, k, n)),
+    )
+}
+
+pub(crate) fn svd<E: FloatNdArrayElement, const D: usize>(
+    tensor: NdArrayTensor<E, D>,
+) -> (
+    NdArrayTensor<E, D>,
+    NdArrayTensor<E, D>,
+    NdArrayTensor<E, D>,
+) {
+    let shape = tensor.shape();
+    let [m, n] = [shape.dims[D - 2], shape.dims[D - 1]];
+    let k = usize::min(m, n);
+
+    let mut output_u = Vec::new();
+    let mut output_s = Vec::new();
+    let mut output_vt = Vec::new();
+
+    for a in matrices(tensor).outer_iter() {
+        let (u, s, vt) = a
+            .svd(true, true)
+            .expect("Can't compute the singular value decomposition");
+
+        // LAPACK computes the full matrices, only the first `k` singular vectors are kept.
+        output_u.push(u.unwrap().slice(s![.., ..k]).to_owned());
+        output_s.push(s.into_shape((1, k)).unwrap());
+        output_vt.push(vt.unwrap().slice(s![..k, ..]).to_owned());
+    }
+
+    (
+        self::tensor(output_u, matrix_shape(&shape, m, k)),
+        self::tensor(output_s, matrix_shape(&shape, 1, k)),
+        self::tensor(output_vt, matrix_shape(&shape, k, n)),
+    )
+}
+
+/// Copy the matrices of the tensor in double precision, with the batch dimensions flattened.
+fn matrices<E: FloatNdArrayElement, const D: usize>(tensor: NdArrayTensor<E, D>) -> Array3<f64> {
+    let shape = tensor.shape();
+    let [m, n] = [shape.dims[D - 2], shape.dims[D - 1]];
+    let batch_size = shape.num_elements() / usize::max(m * n, 1);
+    let values = tensor.array.iter().map(|value| value.elem()).collect();
+
+    Array3::from_shape_vec((batch_size, m, n), values).unwrap()
+}
+
+fn tensor<E: FloatNdArrayElement, const D: usize, I: IntoIterator<Item = Array2<f64>>>(
+    matrices: I,
+    shape: Shape<D>,
+) -> NdArrayTensor<E, D> {
+    let values = matrices
+        .into_iter()
+        .flat_map(|matrix| matrix.into_iter())
+        .map(|value| value.elem())
+        .collect();
+    let array = Array::from_shape_vec(IxDyn(&shape.dims), values).unwrap();
+
+    NdArrayTensor::new(array.into_shared())
+}
+
+fn matrix_shape<const D: usize>(shape: &Shape<D>, rows: usize, cols: usize) -> Shape<D> {
+    let mut dims = shape.dims;
+    dims[D - 2] = rows;
+    dims[D - 1] = cols;
+
+    Shape::new(dims)
+}
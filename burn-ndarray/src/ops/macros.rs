@@ -32,6 +32,14 @@ pub(crate) fn mean_dim<E: NdArrayElement, const D1: usize, const D2: usize>(
     tensor: NdArrayTensor<E, D1>,
     dim: usize,
 ) -> NdArrayTensor<E, D2> {
+    #[cfg(feature = "std")]
+    if crate::parallel::is_parallel(crate::ParallelOp::Reduce, tensor.array.len()) {
+        let array = reduce_lanes_par(&tensor.array, dim, |lane| lane.mean().unwrap());
+        return NdArrayTensor {
+            array: array.into_shared(),
+        };
+    }
+
     let array = tensor.array.mean_axis(Axis(dim)).unwrap().into_shared();
 
     NdArrayTensor { array }
@@ -41,7 +49,32 @@ pub(crate) fn sum_dim<E: NdArrayElement, const D1: usize, const D2: usize>(
     tensor: NdArrayTensor<E, D1>,
     dim: usize,
 ) -> NdArrayTensor<E, D2> {
+    #[cfg(feature = "std")]
+    if crate::parallel::is_parallel(crate::ParallelOp::Reduce, tensor.array.len()) {
+        let array = reduce_lanes_par(&tensor.array, dim, |lane| lane.sum());
+        return NdArrayTensor {
+            array: array.into_shared(),
+        };
+    }
+
     let array = tensor.array.sum_axis(Axis(dim)).into_shared();
 
     NdArrayTensor { array }
 }
+
+/// Reduce every lane along the given dimension in parallel, removing the dimension.
+///
+/// Each lane is reduced by a single thread, so the result doesn't depend on the scheduling.
+#[cfg(feature = "std")]
+pub(crate) fn reduce_lanes_par<E, O, F>(
+    array: &ndarray::ArcArray<E, ndarray::IxDyn>,
+    dim: usize,
+    func: F,
+) -> ndarray::Array<O, ndarray::IxDyn>
+where
+    E: NdArrayElement,
+    O: Send,
+    F: Fn(ndarray::ArrayView1<E>) -> O + Send + Sync,
+{
+    crate::parallel::install(|| ndarray::Zip::from(array.lanes(Axis(dim))).par_map_collect(func))
+}
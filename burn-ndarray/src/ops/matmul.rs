@@ -53,12 +53,13 @@ fn general_matmul<E: FloatNdArrayElement>(
         let beta: E = 0.0.elem();
 
         let mut out_array = ndarray::Array3::<E>::zeros((batch_size, m, n));
+        let num_elements = out_array.len();
         let unsafe_shared_out_array = UnsafeSharedRef::new(&mut out_array);
 
         let lhs_array = lhs.array.into_shape((batch_size_lhs, m, k)).unwrap();
         let rhs_array = rhs.array.into_shape((batch_size_rhs, k, n)).unwrap();
 
-        iter_range_par!(0, batch_size).for_each(|b| {
+        iter_range_par!(0, batch_size; Matmul, num_elements).for_each(|b| {
             let lhs_slice = match batch_size_lhs == 1 {
                 true => lhs_array.slice(s!(0, .., ..)),
                 false => lhs_array.slice(s!(b, .., ..)),
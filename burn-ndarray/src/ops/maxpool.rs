@@ -30,10 +30,11 @@ pub(crate) fn max_pool2d<E: FloatNdArrayElement>(
     let x = apply_padding_4d(x, padding, inf).array;
 
     let mut output = Array4::from_elem((batch_size, channels, out_height, out_width), inf);
+    let num_elements = output.len();
     let unsafe_shared_out = UnsafeSharedRef::new(&mut output);
 
     run_par!(|| {
-        iter_range_par!(0, batch_size * channels).for_each(|k| unsafe {
+        iter_range_par!(0, batch_size * channels; Pool, num_elements).for_each(|k| unsafe {
             let b = k / channels;
             let c = k % channels;
 
@@ -92,11 +93,13 @@ pub(crate) fn max_pool2d_with_indices<E: FloatNdArrayElement>(
     let mut output = Array4::from_elem((batch_size, channels, out_height, out_width), inf);
     let mut indices = Array4::<i64>::zeros((batch_size, channels, out_height, out_width));
 
+    let num_elements = output.len();
+
     let unsafe_shared_out = UnsafeSharedRef::new(&mut output);
     let unsafe_shared_indices = UnsafeSharedRef::new(&mut indices);
 
     run_par!(|| {
-        iter_range_par!(0, batch_size * channels).for_each(|k| unsafe {
+        iter_range_par!(0, batch_size * channels; Pool, num_elements).for_each(|k| unsafe {
             let b = k / channels;
             let c = k % channels;
 
@@ -156,10 +159,12 @@ pub(crate) fn max_pool2d_backward<E: FloatNdArrayElement>(
 
     let mut output = Array4::zeros((batch_size, channels, height_x, width_x));
 
+    let num_elements = output.len();
+
     let unsafe_shared_out = UnsafeSharedRef::new(&mut output);
 
     run_par!(|| {
-        iter_range_par!(0, batch_size * channels).for_each(|k| unsafe {
+        iter_range_par!(0, batch_size * channels; Pool, num_elements).for_each(|k| unsafe {
             let b = k / channels;
             let c = k % channels;
 
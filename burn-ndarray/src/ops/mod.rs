@@ -1,6 +1,7 @@
 mod activations;
 mod base;
 mod bool_tensor;
+mod complex_tensor;
 mod int_tensor;
 mod module;
 mod tensor;
@@ -8,9 +9,15 @@ mod tensor;
 pub(crate) mod adaptive_avgpool;
 pub(crate) mod avgpool;
 pub(crate) mod conv;
+#[cfg(feature = "std")]
+pub(crate) mod fft;
+#[cfg(feature = "ndarray-linalg")]
+pub(crate) mod linalg;
 pub(crate) mod macros;
 pub(crate) mod matmul;
 pub(crate) mod maxpool;
 pub(crate) mod padding;
+pub(crate) mod quantization;
+pub(crate) mod sparse;
 
 pub(crate) use base::*;
@@ -3,6 +3,7 @@ use super::{
     avgpool::{avg_pool2d, avg_pool2d_backward},
     conv::{conv2d, conv_transpose2d},
     maxpool::{max_pool2d, max_pool2d_backward, max_pool2d_with_indices},
+    quantization::int4_matmul,
 };
 use crate::{element::FloatNdArrayElement, tensor::NdArrayTensor, NdArray};
 use burn_tensor::ops::*;
@@ -99,4 +100,14 @@ impl<E: FloatNdArrayElement> ModuleOps<Self> for NdArray<E> {
     ) -> NdArrayTensor<E, 4> {
         adaptive_avg_pool2d_backward(x, grad)
     }
+
+    fn int4_matmul(
+        lhs: NdArrayTensor<E, 2>,
+        weight: NdArrayTensor<i64, 2>,
+        scales: NdArrayTensor<E, 2>,
+        zero_points: NdArrayTensor<E, 2>,
+        block_size: usize,
+    ) -> NdArrayTensor<E, 2> {
+        int4_matmul(lhs, weight, scales, zero_points, block_size)
+    }
 }
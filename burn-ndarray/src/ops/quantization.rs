@@ -0,0 +1,65 @@
+use crate::{
+    element::FloatNdArrayElement, iter_range_par, run_par, sharing::UnsafeSharedRef,
+    tensor::NdArrayTensor,
+};
+
+use alloc::vec;
+use burn_tensor::{ops::quantization::INT4_PER_INT, ElementConversion};
+use ndarray::Array2;
+
+/// Fused dequantization and matrix multiplication, the weights being dequantized one column at
+/// a time so the float weight matrix is never materialized.
+pub(crate) fn int4_matmul<E: FloatNdArrayElement>(
+    lhs: NdArrayTensor<E, 2>,
+    weight: NdArrayTensor<i64, 2>,
+    scales: NdArrayTensor<E, 2>,
+    zero_points: NdArrayTensor<E, 2>,
+    block_size: usize,
+) -> NdArrayTensor<E, 2> {
+    let [batch_size, d_input] = lhs.shape().dims;
+    let [num_packed, d_output] = weight.shape().dims;
+    assert_eq!(
+        num_packed * INT4_PER_INT,
+        d_input,
+        "The packed weights don't match the input size."
+    );
+
+    let lhs = lhs.array;
+    let weight = weight.array;
+    let scales = scales.array;
+    let zero_points = zero_points.array;
+
+    let mut output = Array2::from_elem((batch_size, d_output), 0.elem::<E>());
+    let num_elements = output.len();
+    let unsafe_shared_out = UnsafeSharedRef::new(&mut output);
+
+    run_par!(|| {
+        iter_range_par!(0, d_output; Matmul, num_elements).for_each(|col| unsafe {
+            let output = unsafe_shared_out.get();
+            let mut column = vec![0.elem::<E>(); d_input];
+
+            for i in 0..num_packed {
+                let packed = weight[[i, col]];
+
+                for j in 0..INT4_PER_INT {
+                    let row = i * INT4_PER_INT + j;
+                    let block = row / block_size;
+                    let value = ((packed >> (4 * j)) & 0xF).elem::<E>();
+
+                    column[row] = (value - zero_points[[block, col]]) * scales[[block, col]];
+                }
+            }
+
+            for b in 0..batch_size {
+                let mut sum = 0.elem::<E>();
+                for (row, value) in column.iter().enumerate() {
+                    sum += lhs[[b, row]] * *value;
+                }
+
+                output[[b, col]] = sum;
+            }
+        })
+    });
+
+    NdArrayTensor::new(output.into_dyn().into_shared())
+}
@@ -0,0 +1,33 @@
+use crate::{element::FloatNdArrayElement, tensor::NdArrayTensor};
+use burn_tensor::Shape;
+use ndarray::{Array2, Ix2};
+
+// Each stored value scales a row of the dense matrix, which is accumulated in the row of the
+// output, so the cost is linear in the number of stored values.
+
+pub(crate) fn spmm<E: FloatNdArrayElement>(
+    indices: NdArrayTensor<i64, 2>,
+    values: NdArrayTensor<E, 1>,
+    shape: Shape<2>,
+    dense: NdArrayTensor<E, 2>,
+) -> NdArrayTensor<E, 2> {
+    let [rows, _] = shape.dims;
+    let dense = dense.array.into_dimensionality::<Ix2>().unwrap();
+    let mut output = Array2::zeros((rows, dense.ncols()));
+
+    let indices = indices.array.into_dimensionality::<Ix2>().unwrap();
+    let row_indices = indices.row(0);
+    let col_indices = indices.row(1);
+
+    for ((row, col), value) in row_indices
+        .iter()
+        .zip(col_indices.iter())
+        .zip(values.array.iter())
+    {
+        output
+            .row_mut(*row as usize)
+            .scaled_add(*value, &dense.row(*col as usize));
+    }
+
+    NdArrayTensor::new(output.into_dyn().into_shared())
+}
@@ -3,11 +3,16 @@ use alloc::vec::Vec;
 use core::ops::Range;
 
 // Current crate
-use super::{matmul::matmul, NdArrayMathOps, NdArrayOps};
+use super::{matmul::matmul, sparse, NdArrayMathOps, NdArrayOps};
 use crate::element::FloatNdArrayElement;
 use crate::{tensor::NdArrayTensor, NdArray};
 use crate::{NdArrayDevice, SEED};
 
+#[cfg(feature = "std")]
+use super::fft;
+#[cfg(feature = "ndarray-linalg")]
+use super::linalg;
+
 // Workspace crates
 use burn_common::rand::get_seeded_rng;
 use burn_tensor::{backend::Backend, ops::TensorOps, Data, ElementConversion, Shape};
@@ -163,6 +168,24 @@ impl<E: FloatNdArrayElement> TensorOps<Self> for NdArray<E> {
         NdArrayMathOps::scatter(dim, tensor, indices, value)
     }
 
+    fn scatter_max<const D: usize>(
+        dim: usize,
+        tensor: NdArrayTensor<E, D>,
+        indices: NdArrayTensor<i64, D>,
+        value: NdArrayTensor<E, D>,
+    ) -> NdArrayTensor<E, D> {
+        NdArrayMathOps::scatter_max(dim, tensor, indices, value)
+    }
+
+    fn scatter_min<const D: usize>(
+        dim: usize,
+        tensor: NdArrayTensor<E, D>,
+        indices: NdArrayTensor<i64, D>,
+        value: NdArrayTensor<E, D>,
+    ) -> NdArrayTensor<E, D> {
+        NdArrayMathOps::scatter_min(dim, tensor, indices, value)
+    }
+
     fn select<const D: usize>(
         tensor: NdArrayTensor<E, D>,
         dim: usize,
@@ -313,6 +336,14 @@ impl<E: FloatNdArrayElement> TensorOps<Self> for NdArray<E> {
         NdArrayMathOps::sum_dim(tensor, dim)
     }
 
+    fn cumsum<const D: usize>(tensor: NdArrayTensor<E, D>, dim: usize) -> NdArrayTensor<E, D> {
+        NdArrayMathOps::cumsum(tensor, dim)
+    }
+
+    fn cumprod<const D: usize>(tensor: NdArrayTensor<E, D>, dim: usize) -> NdArrayTensor<E, D> {
+        NdArrayMathOps::cumprod(tensor, dim)
+    }
+
     fn to_full_precision<const D: usize>(tensor: &NdArrayTensor<E, D>) -> NdArrayTensor<f32, D> {
         let array = tensor.array.mapv(|a| a.elem()).into_shared();
 
@@ -433,6 +464,61 @@ impl<E: FloatNdArrayElement> TensorOps<Self> for NdArray<E> {
         NdArrayMathOps::clamp(tensor, min, max)
     }
 
+    #[cfg(feature = "ndarray-linalg")]
+    fn solve<const D: usize>(
+        lhs: NdArrayTensor<E, D>,
+        rhs: NdArrayTensor<E, D>,
+    ) -> NdArrayTensor<E, D> {
+        linalg::solve(lhs, rhs)
+    }
+
+    #[cfg(feature = "ndarray-linalg")]
+    fn inverse<const D: usize>(tensor: NdArrayTensor<E, D>) -> NdArrayTensor<E, D> {
+        linalg::inverse(tensor)
+    }
+
+    #[cfg(feature = "ndarray-linalg")]
+    fn cholesky<const D: usize>(tensor: NdArrayTensor<E, D>) -> NdArrayTensor<E, D> {
+        linalg::cholesky(tensor)
+    }
+
+    #[cfg(feature = "ndarray-linalg")]
+    fn qr<const D: usize>(
+        tensor: NdArrayTensor<E, D>,
+    ) -> (NdArrayTensor<E, D>, NdArrayTensor<E, D>) {
+        linalg::qr(tensor)
+    }
+
+    #[cfg(feature = "ndarray-linalg")]
+    fn svd<const D: usize>(
+        tensor: NdArrayTensor<E, D>,
+    ) -> (
+        NdArrayTensor<E, D>,
+        NdArrayTensor<E, D>,
+        NdArrayTensor<E, D>,
+    ) {
+        linalg::svd(tensor)
+    }
+
+    #[cfg(feature = "std")]
+    fn fft<const D: usize>(
+        real: NdArrayTensor<E, D>,
+        imag: NdArrayTensor<E, D>,
+        dim: usize,
+        inverse: bool,
+    ) -> Result<(NdArrayTensor<E, D>, NdArrayTensor<E, D>), burn_tensor::fft::FftError> {
+        Ok(fft::fft(real, imag, dim, inverse))
+    }
+
+    fn spmm(
+        indices: NdArrayTensor<i64, 2>,
+        values: NdArrayTensor<E, 1>,
+        shape: Shape<2>,
+        dense: NdArrayTensor<E, 2>,
+    ) -> NdArrayTensor<E, 2> {
+        sparse::spmm(indices, values, shape, dense)
+    }
+
     fn into_int<const D: usize>(
         tensor: <NdArray<E> as Backend>::TensorPrimitive<D>,
     ) -> <NdArray<E> as Backend>::IntTensorPrimitive<D> {
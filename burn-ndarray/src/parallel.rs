@@ -1,3 +1,207 @@
+use core::sync::atomic::{AtomicUsize, Ordering};
+
+#[cfg(feature = "std")]
+use std::sync::{Arc, RwLock};
+
+/// Group of operations sharing the same parallelism threshold.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ParallelOp {
+    /// Matrix multiplications.
+    Matmul,
+    /// Convolutions and transposed convolutions, forward and backward.
+    Conv,
+    /// Pooling operations, forward and backward.
+    Pool,
+    /// Reductions such as sum, mean, argmax and argmin.
+    Reduce,
+}
+
+impl ParallelOp {
+    #[cfg(feature = "std")]
+    const ALL: [ParallelOp; 4] = [Self::Matmul, Self::Conv, Self::Pool, Self::Reduce];
+
+    fn index(&self) -> usize {
+        match self {
+            Self::Matmul => 0,
+            Self::Conv => 1,
+            Self::Pool => 2,
+            Self::Reduce => 3,
+        }
+    }
+
+    fn default_threshold(&self) -> usize {
+        match self {
+            Self::Matmul | Self::Conv | Self::Pool => 0,
+            // Parallel reductions are slower than vectorized sequential ones on small tensors.
+            Self::Reduce => 32 * 1024,
+        }
+    }
+}
+
+#[allow(clippy::declare_interior_mutable_const)]
+const THRESHOLD_UNSET: AtomicUsize = AtomicUsize::new(usize::MAX);
+static THRESHOLDS: [AtomicUsize; 4] = [THRESHOLD_UNSET; 4];
+
+#[cfg(feature = "std")]
+static THREAD_POOL: RwLock<Option<Arc<rayon::ThreadPool>>> = RwLock::new(None);
+
+/// Handler called when a thread of the pool starts, with the index of the thread.
+#[cfg(feature = "std")]
+pub type ThreadStartHandler = Arc<dyn Fn(usize) + Send + Sync>;
+
+/// Parallelism configuration of the ndarray backend.
+///
+/// The configuration is global and applied with [set_parallel_config].
+///
+/// # Notes
+///
+/// The matrix multiplication kernel of each batch item may use its own threads, see the
+/// `MATMUL_NUM_THREADS` environment variable of the
+/// [matrixmultiply](https://docs.rs/matrixmultiply) crate.
+#[cfg(feature = "std")]
+#[derive(Clone, Default)]
+pub struct ParallelConfig {
+    num_threads: Option<usize>,
+    thresholds: [Option<usize>; 4],
+    start_handler: Option<ThreadStartHandler>,
+}
+
+#[cfg(feature = "std")]
+impl ParallelConfig {
+    /// Create a new configuration using the default values.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the number of threads used by the backend.
+    ///
+    /// When not set, the global [rayon](rayon) thread pool is used.
+    pub fn with_num_threads(mut self, num_threads: usize) -> Self {
+        self.num_threads = Some(num_threads);
+        self
+    }
+
+    /// Set the minimum number of output elements for an operation of the given group to be
+    /// executed in parallel, smaller operations are executed on a single thread.
+    pub fn with_threshold(mut self, op: ParallelOp, num_elements: usize) -> Self {
+        self.thresholds[op.index()] = Some(num_elements);
+        self
+    }
+
+    /// Set a handler called by each thread of the pool when it starts.
+    ///
+    /// This is the place to apply affinity hints, such as pinning each thread to a core, which
+    /// only takes effect when [the number of threads](ParallelConfig::with_num_threads) is set.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let cores = core_affinity::get_core_ids().unwrap();
+    /// let config = ParallelConfig::new()
+    ///     .with_num_threads(cores.len())
+    ///     .with_start_handler(Arc::new(move |index| {
+    ///         core_affinity::set_for_current(cores[index]);
+    ///     }));
+    /// ```
+    pub fn with_start_handler(mut self, handler: ThreadStartHandler) -> Self {
+        self.start_handler = Some(handler);
+        self
+    }
+}
+
+#[cfg(feature = "std")]
+impl core::fmt::Debug for ParallelConfig {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("ParallelConfig")
+            .field("num_threads", &self.num_threads)
+            .field("thresholds", &self.thresholds)
+            .field("start_handler", &self.start_handler.is_some())
+            .finish()
+    }
+}
+
+/// Apply the parallelism configuration of the ndarray backend.
+///
+/// Operations already running keep using the previous thread pool.
+///
+/// # Panics
+///
+/// If the thread pool can't be created.
+#[cfg(feature = "std")]
+pub fn set_parallel_config(config: ParallelConfig) {
+    for op in ParallelOp::ALL {
+        // The maximum value is reserved to mark the thresholds that aren't set.
+        let threshold = match config.thresholds[op.index()] {
+            Some(threshold) => threshold.min(usize::MAX - 1),
+            None => usize::MAX,
+        };
+        THRESHOLDS[op.index()].store(threshold, Ordering::Relaxed);
+    }
+
+    let pool = config.num_threads.map(|num_threads| {
+        let mut builder = rayon::ThreadPoolBuilder::new()
+            .num_threads(num_threads)
+            .thread_name(|index| format!("burn-ndarray-{index}"));
+
+        if let Some(handler) = config.start_handler {
+            builder = builder.start_handler(move |index| handler(index));
+        }
+
+        Arc::new(builder.build().expect("Should create the thread pool"))
+    });
+
+    *THREAD_POOL.write().unwrap() = pool;
+}
+
+/// The number of threads used by the backend.
+#[cfg(feature = "std")]
+pub fn num_threads() -> usize {
+    match THREAD_POOL.read().unwrap().as_ref() {
+        Some(pool) => pool.current_num_threads(),
+        None => rayon::current_num_threads(),
+    }
+}
+
+/// The minimum number of output elements for an operation of the given group to be executed in
+/// parallel.
+pub fn parallel_threshold(op: ParallelOp) -> usize {
+    match THRESHOLDS[op.index()].load(Ordering::Relaxed) {
+        usize::MAX => op.default_threshold(),
+        threshold => threshold,
+    }
+}
+
+/// If an operation of the given group producing `num_elements` should be executed in parallel.
+#[cfg(feature = "std")]
+pub(crate) fn is_parallel(op: ParallelOp, num_elements: usize) -> bool {
+    num_elements >= parallel_threshold(op)
+}
+
+/// The minimum number of items processed by each job of a parallel iterator, which prevents
+/// splitting the work when the operation is under its parallelism threshold.
+#[cfg(feature = "std")]
+pub(crate) fn min_len(op: ParallelOp, num_elements: usize) -> usize {
+    match is_parallel(op, num_elements) {
+        true => 1,
+        false => usize::MAX,
+    }
+}
+
+/// Execute the function on the thread pool of the backend.
+#[cfg(feature = "std")]
+pub(crate) fn install<R, F>(func: F) -> R
+where
+    R: Send,
+    F: FnOnce() -> R + Send,
+{
+    let pool = THREAD_POOL.read().unwrap().clone();
+
+    match pool {
+        Some(pool) => pool.install(func),
+        None => rayon::scope(|_| func()),
+    }
+}
+
 /// Macro for running a function in parallel.
 #[macro_export(local_inner_macros)]
 macro_rules! run_par {
@@ -9,7 +213,7 @@ macro_rules! run_par {
 
         #[cfg(feature = "std")]
         #[allow(clippy::redundant_closure_call)]
-        let output = rayon::scope(|_| $func());
+        let output = $crate::parallel::install(|| $func());
 
         #[cfg(not(feature = "std"))]
         let output = $func();
@@ -19,6 +223,9 @@ macro_rules! run_par {
 }
 
 /// Macro for iterating in parallel.
+///
+/// The parallelism threshold of an [operation group](crate::ParallelOp) can be applied by
+/// providing the group and the number of output elements of the operation.
 #[macro_export(local_inner_macros)]
 macro_rules! iter_par {
     (
@@ -30,11 +237,33 @@ macro_rules! iter_par {
         #[cfg(not(feature = "std"))]
         let output = $iter;
 
+        output
+    }};
+    (
+        $iter:expr; $op:ident, $num_elements:expr
+    ) => {{
+        #[cfg(feature = "std")]
+        let output = $iter
+            .into_par_iter()
+            .with_min_len($crate::parallel::min_len(
+                $crate::ParallelOp::$op,
+                $num_elements,
+            ));
+
+        #[cfg(not(feature = "std"))]
+        let output = {
+            let _ = $num_elements;
+            $iter
+        };
+
         output
     }};
 }
 
 /// Macro for iterating over a range in parallel.
+///
+/// The parallelism threshold of an [operation group](crate::ParallelOp) can be applied by
+/// providing the group and the number of output elements of the operation.
 #[macro_export(local_inner_macros)]
 macro_rules! iter_range_par {
     (
@@ -48,4 +277,56 @@ macro_rules! iter_range_par {
 
         output
     }};
+    (
+        $start:expr, $end:expr; $op:ident, $num_elements:expr
+    ) => {{
+        #[cfg(feature = "std")]
+        let output = ($start..$end)
+            .into_par_iter()
+            .with_min_len($crate::parallel::min_len(
+                $crate::ParallelOp::$op,
+                $num_elements,
+            ));
+
+        #[cfg(not(feature = "std"))]
+        let output = {
+            let _ = $num_elements;
+            ($start..$end)
+        };
+
+        output
+    }};
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn config_sets_thresholds_and_thread_pool() {
+        let started = Arc::new(AtomicUsize::new(0));
+        let counter = started.clone();
+
+        set_parallel_config(
+            ParallelConfig::new()
+                .with_num_threads(2)
+                .with_threshold(ParallelOp::Conv, 100)
+                .with_start_handler(Arc::new(move |_| {
+                    counter.fetch_add(1, Ordering::Relaxed);
+                })),
+        );
+
+        assert_eq!(num_threads(), 2);
+        assert_eq!(install(rayon::current_num_threads), 2);
+        assert!(!is_parallel(ParallelOp::Conv, 99));
+        assert!(is_parallel(ParallelOp::Conv, 100));
+        assert_eq!(
+            parallel_threshold(ParallelOp::Reduce),
+            ParallelOp::Reduce.default_threshold()
+        );
+        assert!(started.load(Ordering::Relaxed) > 0);
+
+        set_parallel_config(ParallelConfig::new());
+        assert_eq!(parallel_threshold(ParallelOp::Conv), 0);
+    }
 }
@@ -3,9 +3,15 @@
 use burn_no_std_tests::mlp::*;
 use burn_no_std_tests::model::*;
 
-use burn::tensor::{backend::Backend, Distribution, Tensor};
+use burn::{
+    module::Module,
+    record::{BinBytesRecorder, BinSliceRecorder, FullPrecisionSettings, Recorder},
+    tensor::{backend::Backend, Distribution, Tensor},
+};
 use burn_ndarray::NdArray;
 
+extern crate alloc;
+
 #[test]
 fn test_mnist_model_with_random_input() {
     type Backend = NdArray<f32>;
@@ -29,3 +35,30 @@ fn test_mnist_model_with_random_input() {
     assert_eq!(output.shape().dims, [1, 10]);
     assert!(output.to_data().value.into_iter().all(|x| x <= 1.0));
 }
+
+#[test]
+fn test_mnist_model_loaded_from_embedded_weights() {
+    type Backend = NdArray<f32>;
+
+    let device = Default::default();
+    let mnist_config = MnistConfig::new(MlpConfig::new());
+    let trained: Model<Backend> = Model::new(&mnist_config, &device);
+
+    // On a microcontroller, the weights would be embedded with `include_bytes!`.
+    let weights = BinBytesRecorder::<FullPrecisionSettings>::default()
+        .record(trained.clone().into_record(), ())
+        .unwrap();
+    let weights: &'static [u8] = alloc::vec::Vec::leak(weights);
+
+    let record = BinSliceRecorder::<FullPrecisionSettings>::default()
+        .load(weights)
+        .unwrap();
+    let loaded: Model<Backend> = Model::new(&mnist_config, &device).load_record(record);
+
+    let input = Tensor::<Backend, 3>::random([2, 28, 28], Distribution::Default, &device);
+
+    trained
+        .forward(input.clone())
+        .into_data()
+        .assert_approx_eq(&loaded.forward(input).into_data(), 3);
+}
@@ -0,0 +1,226 @@
+use crate::{BatchModel, BatchingConfig, OutputSink, ServeError};
+use std::sync::mpsc::{
+    channel, sync_channel, Receiver, RecvTimeoutError, Sender, SyncSender, TrySendError,
+};
+use std::thread::JoinHandle;
+use std::time::{Duration, Instant};
+
+#[derive(new)]
+struct Request<I, O> {
+    input: I,
+    sender: Sender<O>,
+    received: Instant,
+}
+
+/// Serve a [model](BatchModel) with dynamic batching.
+///
+/// The model is moved to a dedicated thread, which executes the batches formed from the requests
+/// submitted with the [handles](ServerHandle) of the server.
+pub struct ModelServer<M: BatchModel> {
+    handle: ServerHandle<M::Input, M::Output>,
+    worker: JoinHandle<()>,
+}
+
+/// Submit requests to a [model server](ModelServer).
+///
+/// Handles are cheap to clone and can be shared between threads.
+pub struct ServerHandle<I, O> {
+    sender: SyncSender<Request<I, O>>,
+}
+
+impl<I, O> Clone for ServerHandle<I, O> {
+    fn clone(&self) -> Self {
+        Self {
+            sender: self.sender.clone(),
+        }
+    }
+}
+
+impl<M: BatchModel> ModelServer<M> {
+    /// Start serving the given model.
+    pub fn new(model: M, config: BatchingConfig) -> Self {
+        assert!(
+            config.max_batch_size > 0,
+            "The maximum batch size must be greater than 0"
+        );
+
+        let (sender, receiver) = sync_channel(config.queue_capacity);
+        let worker = std::thread::spawn(move || run_batcher(model, receiver, config));
+
+        Self {
+            handle: ServerHandle { sender },
+            worker,
+        }
+    }
+
+    /// Get a new handle to submit requests.
+    pub fn handle(&self) -> ServerHandle<M::Input, M::Output> {
+        self.handle.clone()
+    }
+
+    /// Stop accepting requests from this server and wait for the batching thread to finish.
+    ///
+    /// The batching thread processes the pending requests and stops once every
+    /// [handle](ServerHandle) is dropped.
+    pub fn join(self) {
+        core::mem::drop(self.handle);
+
+        if self.worker.join().is_err() {
+            log::error!("The batching thread panicked");
+        }
+    }
+}
+
+impl<I, O> ServerHandle<I, O> {
+    /// Submit a request, returning the stream of its outputs.
+    ///
+    /// The stream is closed once the model is done with the request.
+    pub fn infer(&self, input: I) -> Result<Receiver<O>, ServeError> {
+        let (sender, receiver) = channel();
+        let request = Request::new(input, sender, Instant::now());
+
+        match self.sender.try_send(request) {
+            Ok(()) => Ok(receiver),
+            Err(TrySendError::Full(_)) => Err(ServeError::QueueFull),
+            Err(TrySendError::Disconnected(_)) => Err(ServeError::Stopped),
+        }
+    }
+}
+
+fn run_batcher<M: BatchModel>(
+    mut model: M,
+    receiver: Receiver<Request<M::Input, M::Output>>,
+    config: BatchingConfig,
+) {
+    let latency = Duration::from_millis(config.max_latency_ms);
+
+    // Blocks until a request is available, stops when every handle is dropped.
+    while let Ok(first) = receiver.recv() {
+        let deadline = first.received + latency;
+        let mut batch = vec![first];
+
+        while batch.len() < config.max_batch_size {
+            let now = Instant::now();
+            if now >= deadline {
+                break;
+            }
+
+            match receiver.recv_timeout(deadline - now) {
+                Ok(request) => batch.push(request),
+                Err(RecvTimeoutError::Timeout) | Err(RecvTimeoutError::Disconnected) => break,
+            }
+        }
+
+        log::debug!("Executing a batch of {} requests", batch.len());
+
+        let (inputs, senders) = batch
+            .into_iter()
+            .map(|request| (request.input, request.sender))
+            .unzip();
+        let mut sink = OutputSink::new(senders);
+
+        model.forward(inputs, &mut sink);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::{Arc, Mutex};
+
+    struct Echo {
+        batch_sizes: Arc<Mutex<Vec<usize>>>,
+        repeat: usize,
+    }
+
+    impl BatchModel for Echo {
+        type Input = u32;
+        type Output = u32;
+
+        fn forward(&mut self, inputs: Vec<u32>, sink: &mut OutputSink<u32>) {
+            self.batch_sizes.lock().unwrap().push(inputs.len());
+
+            for _ in 0..self.repeat {
+                for (index, input) in inputs.iter().enumerate() {
+                    sink.send(index, input * 2);
+                }
+            }
+        }
+    }
+
+    fn server(
+        repeat: usize,
+        config: BatchingConfig,
+    ) -> (ModelServer<Echo>, Arc<Mutex<Vec<usize>>>) {
+        let batch_sizes = Arc::new(Mutex::new(Vec::new()));
+        let model = Echo {
+            batch_sizes: batch_sizes.clone(),
+            repeat,
+        };
+
+        (ModelServer::new(model, config), batch_sizes)
+    }
+
+    #[test]
+    fn requests_are_batched_up_to_max_batch_size() {
+        let config = BatchingConfig::default()
+            .with_max_batch_size(4)
+            .with_max_latency_ms(60_000);
+        let (server, batch_sizes) = server(1, config);
+        let handle = server.handle();
+
+        let receivers = (0..8)
+            .map(|input| handle.infer(input).unwrap())
+            .collect::<Vec<_>>();
+        let outputs = receivers
+            .into_iter()
+            .map(|receiver| receiver.iter().collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+
+        assert_eq!(outputs, (0..8).map(|i| vec![i * 2]).collect::<Vec<_>>());
+        assert_eq!(*batch_sizes.lock().unwrap(), vec![4, 4]);
+    }
+
+    #[test]
+    fn partial_batch_is_executed_after_latency_budget() {
+        let config = BatchingConfig::default()
+            .with_max_batch_size(8)
+            .with_max_latency_ms(20);
+        let (server, batch_sizes) = server(1, config);
+        let handle = server.handle();
+
+        let outputs = handle.infer(3).unwrap().iter().collect::<Vec<_>>();
+
+        assert_eq!(outputs, vec![6]);
+        assert_eq!(*batch_sizes.lock().unwrap(), vec![1]);
+    }
+
+    #[test]
+    fn outputs_are_streamed() {
+        let (server, _) = server(3, BatchingConfig::default());
+        let handle = server.handle();
+
+        let outputs = handle.infer(5).unwrap().iter().collect::<Vec<_>>();
+
+        assert_eq!(outputs, vec![10, 10, 10]);
+    }
+
+    #[test]
+    fn stopped_server_rejects_requests() {
+        let (sender, receiver) = sync_channel::<Request<u32, u32>>(1);
+        let handle = ServerHandle { sender };
+        core::mem::drop(receiver);
+
+        assert!(matches!(handle.infer(1), Err(ServeError::Stopped)));
+    }
+
+    #[test]
+    fn full_queue_rejects_requests() {
+        let (sender, _receiver) = sync_channel::<Request<u32, u32>>(1);
+        let handle = ServerHandle { sender };
+
+        handle.infer(1).unwrap();
+
+        assert!(matches!(handle.infer(2), Err(ServeError::QueueFull)));
+    }
+}
@@ -0,0 +1,43 @@
+use serde::{Deserialize, Serialize};
+
+/// Configuration of the dynamic batching.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BatchingConfig {
+    /// The maximum number of requests executed in a single forward pass.
+    pub max_batch_size: usize,
+    /// The maximum time in milliseconds a request waits in the queue for other requests
+    /// to join its batch.
+    pub max_latency_ms: u64,
+    /// The maximum number of pending requests, new requests are rejected when the queue is full.
+    pub queue_capacity: usize,
+}
+
+impl Default for BatchingConfig {
+    fn default() -> Self {
+        Self {
+            max_batch_size: 32,
+            max_latency_ms: 10,
+            queue_capacity: 1024,
+        }
+    }
+}
+
+impl BatchingConfig {
+    /// Set the maximum batch size.
+    pub fn with_max_batch_size(mut self, max_batch_size: usize) -> Self {
+        self.max_batch_size = max_batch_size;
+        self
+    }
+
+    /// Set the latency budget in milliseconds.
+    pub fn with_max_latency_ms(mut self, max_latency_ms: u64) -> Self {
+        self.max_latency_ms = max_latency_ms;
+        self
+    }
+
+    /// Set the queue capacity.
+    pub fn with_queue_capacity(mut self, queue_capacity: usize) -> Self {
+        self.queue_capacity = queue_capacity;
+        self
+    }
+}
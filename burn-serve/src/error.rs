@@ -0,0 +1,33 @@
+use core::fmt::Display;
+
+/// Error that can happen when serving a model.
+#[derive(Debug)]
+pub enum ServeError {
+    /// The request queue is full, the request should be retried later.
+    QueueFull,
+    /// The batching thread is stopped, no more requests can be processed.
+    Stopped,
+    /// The input of a request can't be decoded.
+    InvalidInput(String),
+    /// An IO error happened while communicating with a client.
+    Io(std::io::Error),
+}
+
+impl Display for ServeError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::QueueFull => f.write_str("The request queue is full"),
+            Self::Stopped => f.write_str("The model server is stopped"),
+            Self::InvalidInput(message) => write!(f, "Invalid input: {message}"),
+            Self::Io(err) => write!(f, "IO error: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for ServeError {}
+
+impl From<std::io::Error> for ServeError {
+    fn from(err: std::io::Error) -> Self {
+        Self::Io(err)
+    }
+}
@@ -0,0 +1,244 @@
+use crate::{ServeError, ServerHandle};
+use serde::{de::DeserializeOwned, Serialize};
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{SocketAddr, TcpListener, TcpStream, ToSocketAddrs};
+
+/// Minimal HTTP/1.1 server exposing a [model server](crate::ModelServer).
+///
+/// Each connection is handled by its own thread and serves a single request.
+pub struct HttpServer<I, O> {
+    listener: TcpListener,
+    handle: ServerHandle<I, O>,
+}
+
+struct HttpRequest {
+    method: String,
+    path: String,
+    body: Vec<u8>,
+}
+
+impl<I, O> HttpServer<I, O>
+where
+    I: DeserializeOwned + Send + 'static,
+    O: Serialize + Send + 'static,
+{
+    /// Bind the server to the given address.
+    pub fn bind<A: ToSocketAddrs>(addr: A, handle: ServerHandle<I, O>) -> Result<Self, ServeError> {
+        let listener = TcpListener::bind(addr)?;
+
+        Ok(Self { listener, handle })
+    }
+
+    /// The address the server is bound to.
+    pub fn local_addr(&self) -> Result<SocketAddr, ServeError> {
+        Ok(self.listener.local_addr()?)
+    }
+
+    /// Accept connections until the listener fails.
+    pub fn run(self) -> Result<(), ServeError> {
+        log::info!("Serving on {}", self.local_addr()?);
+
+        for stream in self.listener.incoming() {
+            let stream = match stream {
+                Ok(stream) => stream,
+                Err(err) => {
+                    log::warn!("Failed to accept connection: {err}");
+                    continue;
+                }
+            };
+            let handle = self.handle.clone();
+
+            std::thread::spawn(move || {
+                if let Err(err) = handle_connection(stream, handle) {
+                    log::warn!("Failed to handle connection: {err}");
+                }
+            });
+        }
+
+        Ok(())
+    }
+}
+
+fn handle_connection<I, O>(
+    mut stream: TcpStream,
+    handle: ServerHandle<I, O>,
+) -> Result<(), ServeError>
+where
+    I: DeserializeOwned,
+    O: Serialize,
+{
+    let request = read_request(&mut stream)?;
+
+    match (request.method.as_str(), request.path.as_str()) {
+        ("GET", "/health") => write_response(&mut stream, "200 OK", "ok"),
+        ("POST", "/predict") => predict(&mut stream, handle, &request.body),
+        _ => write_response(&mut stream, "404 Not Found", "not found"),
+    }
+}
+
+fn predict<I, O>(
+    stream: &mut TcpStream,
+    handle: ServerHandle<I, O>,
+    body: &[u8],
+) -> Result<(), ServeError>
+where
+    I: DeserializeOwned,
+    O: Serialize,
+{
+    let input = match serde_json::from_slice(body) {
+        Ok(input) => input,
+        Err(err) => return write_response(stream, "400 Bad Request", &err.to_string()),
+    };
+
+    let outputs = match handle.infer(input) {
+        Ok(outputs) => outputs,
+        Err(err) => return write_response(stream, "503 Service Unavailable", &err.to_string()),
+    };
+
+    stream.write_all(
+        b"HTTP/1.1 200 OK\r\n\
+          Content-Type: application/x-ndjson\r\n\
+          Transfer-Encoding: chunked\r\n\
+          Connection: close\r\n\r\n",
+    )?;
+
+    for output in outputs.iter() {
+        let mut line = serde_json::to_vec(&output).map_err(|err| ServeError::Io(err.into()))?;
+        line.push(b'\n');
+
+        write!(stream, "{:x}\r\n", line.len())?;
+        stream.write_all(&line)?;
+        stream.write_all(b"\r\n")?;
+        stream.flush()?;
+    }
+
+    stream.write_all(b"0\r\n\r\n")?;
+    stream.flush()?;
+
+    Ok(())
+}
+
+fn read_request(stream: &mut TcpStream) -> Result<HttpRequest, ServeError> {
+    let mut reader = BufReader::new(stream);
+    let mut line = String::new();
+    reader.read_line(&mut line)?;
+
+    let mut parts = line.split_whitespace();
+    let method = parts.next().unwrap_or_default().to_string();
+    let path = parts.next().unwrap_or_default().to_string();
+    let mut content_length = 0;
+
+    loop {
+        line.clear();
+        if reader.read_line(&mut line)? == 0 {
+            break;
+        }
+
+        let header = line.trim_end();
+        if header.is_empty() {
+            break;
+        }
+
+        if let Some((name, value)) = header.split_once(':') {
+            if name.trim().eq_ignore_ascii_case("content-length") {
+                content_length = value
+                    .trim()
+                    .parse()
+                    .map_err(|_| ServeError::InvalidInput("Invalid Content-Length".into()))?;
+            }
+        }
+    }
+
+    let mut body = vec![0; content_length];
+    reader.read_exact(&mut body)?;
+
+    Ok(HttpRequest { method, path, body })
+}
+
+fn write_response(stream: &mut TcpStream, status: &str, body: &str) -> Result<(), ServeError> {
+    write!(
+        stream,
+        "HTTP/1.1 {status}\r\nContent-Type: text/plain\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+        body.len()
+    )?;
+    stream.flush()?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{BatchModel, BatchingConfig, ModelServer, OutputSink};
+
+    struct Tokens;
+
+    impl BatchModel for Tokens {
+        type Input = Vec<u32>;
+        type Output = u32;
+
+        fn forward(&mut self, inputs: Vec<Vec<u32>>, sink: &mut OutputSink<u32>) {
+            for (index, tokens) in inputs.into_iter().enumerate() {
+                for token in tokens {
+                    sink.send(index, token + 1);
+                }
+            }
+        }
+    }
+
+    fn call(addr: SocketAddr, request: &str) -> String {
+        let mut stream = TcpStream::connect(addr).unwrap();
+        stream.write_all(request.as_bytes()).unwrap();
+
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+        response
+    }
+
+    fn start() -> SocketAddr {
+        let server = ModelServer::new(Tokens, BatchingConfig::default());
+        let http = HttpServer::bind("127.0.0.1:0", server.handle()).unwrap();
+        let addr = http.local_addr().unwrap();
+        std::thread::spawn(move || http.run());
+
+        addr
+    }
+
+    #[test]
+    fn predict_streams_outputs_as_chunked_ndjson() {
+        let addr = start();
+        let body = "[1, 2, 3]";
+
+        let response = call(
+            addr,
+            &format!(
+                "POST /predict HTTP/1.1\r\nContent-Length: {}\r\n\r\n{body}",
+                body.len()
+            ),
+        );
+
+        assert!(response.starts_with("HTTP/1.1 200 OK"));
+        assert!(response.ends_with("2\r\n2\n\r\n2\r\n3\n\r\n2\r\n4\n\r\n0\r\n\r\n"));
+    }
+
+    #[test]
+    fn predict_rejects_invalid_input() {
+        let addr = start();
+
+        let response = call(
+            addr,
+            "POST /predict HTTP/1.1\r\nContent-Length: 3\r\n\r\nabc",
+        );
+
+        assert!(response.starts_with("HTTP/1.1 400 Bad Request"));
+    }
+
+    #[test]
+    fn health() {
+        let addr = start();
+
+        let response = call(addr, "GET /health HTTP/1.1\r\n\r\n");
+
+        assert!(response.starts_with("HTTP/1.1 200 OK"));
+    }
+}
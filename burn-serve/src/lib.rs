@@ -0,0 +1,33 @@
+#![warn(missing_docs)]
+
+//! A library for serving burn models with dynamic batching.
+//!
+//! Requests are pushed into a bounded queue consumed by a single batching thread owning the model.
+//! The batching thread waits for the first request, then keeps collecting requests until either
+//! the maximum batch size is reached or the latency budget of the first request is exhausted.
+//! The whole batch is executed with a single forward pass, and each request receives its outputs
+//! through its own stream, which allows models to return partial results (e.g. generated tokens)
+//! as soon as they are available.
+//!
+//! The [HTTP server](HttpServer) exposes the batcher over HTTP/1.1:
+//!
+//! - `POST /predict`: The body is the JSON encoded input, the response is a chunked stream of
+//!   newline-delimited JSON outputs.
+//! - `GET /health`: Returns `200 OK` while the server is running.
+//!
+//! Other transports, such as gRPC, can be built on top of [ServerHandle::infer].
+
+#[macro_use]
+extern crate derive_new;
+
+mod batcher;
+mod config;
+mod error;
+mod http;
+mod model;
+
+pub use batcher::*;
+pub use config::*;
+pub use error::*;
+pub use http::*;
+pub use model::*;
@@ -0,0 +1,91 @@
+use std::sync::mpsc::Sender;
+
+/// A model that can be served with dynamic batching.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// impl<B: Backend> BatchModel for Classifier<B> {
+///     type Input = Vec<f32>;
+///     type Output = usize;
+///
+///     fn forward(&mut self, inputs: Vec<Vec<f32>>, sink: &mut OutputSink<usize>) {
+///         let batch_size = inputs.len();
+///         let data = Data::new(inputs.concat(), Shape::new([batch_size * 784]));
+///         let images = Tensor::from_data(data.convert(), &self.device).reshape([batch_size, 784]);
+///         let classes = self.model.forward(images).argmax(1).into_data().convert::<i64>();
+///
+///         for (index, class) in classes.value.into_iter().enumerate() {
+///             sink.send(index, class as usize);
+///         }
+///     }
+/// }
+/// ```
+pub trait BatchModel: Send + 'static {
+    /// The input of a single request.
+    type Input: Send + 'static;
+    /// An output of a single request.
+    type Output: Send + 'static;
+
+    /// Execute the model on a batch of inputs.
+    ///
+    /// The outputs of the input at position `index` are sent with
+    /// [sink.send(index, output)](OutputSink::send). Multiple outputs can be sent for the same
+    /// input to stream partial results, the stream of every request is closed when this method
+    /// returns.
+    fn forward(&mut self, inputs: Vec<Self::Input>, sink: &mut OutputSink<Self::Output>);
+}
+
+/// Sends the outputs of a batch back to the requests that produced it.
+pub struct OutputSink<O> {
+    senders: Vec<Option<Sender<O>>>,
+}
+
+impl<O> OutputSink<O> {
+    pub(crate) fn new(senders: Vec<Sender<O>>) -> Self {
+        Self {
+            senders: senders.into_iter().map(Some).collect(),
+        }
+    }
+
+    /// Send an output to the request at position `index` in the batch.
+    ///
+    /// Returns false when the request doesn't wait for outputs anymore, either because its
+    /// stream was [closed](OutputSink::close) or because the client disconnected.
+    pub fn send(&mut self, index: usize, output: O) -> bool {
+        let sender = match self.senders.get(index) {
+            Some(Some(sender)) => sender,
+            _ => return false,
+        };
+
+        if sender.send(output).is_err() {
+            self.senders[index] = None;
+            return false;
+        }
+
+        true
+    }
+
+    /// Close the stream of the request at position `index` in the batch before the end of the
+    /// forward pass.
+    pub fn close(&mut self, index: usize) {
+        if let Some(sender) = self.senders.get_mut(index) {
+            *sender = None;
+        }
+    }
+
+    /// If the request at position `index` in the batch is still waiting for outputs.
+    pub fn is_open(&self, index: usize) -> bool {
+        matches!(self.senders.get(index), Some(Some(_)))
+    }
+
+    /// The number of requests in the batch.
+    pub fn len(&self) -> usize {
+        self.senders.len()
+    }
+
+    /// If the batch is empty.
+    pub fn is_empty(&self) -> bool {
+        self.senders.is_empty()
+    }
+}
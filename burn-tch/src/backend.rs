@@ -1,6 +1,8 @@
 use super::element::TchElement;
 use super::TchTensor;
-use burn_tensor::backend::Backend;
+use burn_tensor::backend::{Backend, ComplexBackend};
+use burn_tensor::Element;
+use num_complex::Complex;
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 /// The device struct when using the `tch` backend.
@@ -73,6 +75,41 @@ pub struct LibTorch<E = f32> {
     _e: E,
 }
 
+impl<E: TchElement> LibTorch<E> {
+    /// Execute the closure with the [autocast](https://pytorch.org/docs/stable/amp.html) of
+    /// LibTorch enabled on the given device, so that eligible operations such as matrix
+    /// multiplications and convolutions run in half precision.
+    ///
+    /// The outputs of the operations executed in half precision keep their precision, but their
+    /// data is converted to `E` when read. Autocast is only supported on Cuda devices, the
+    /// closure is executed normally on the other devices.
+    ///
+    /// The [Learner](https://docs.rs/burn-train) has no mixed precision setting, autocast can be
+    /// enabled in the forward pass of the training step instead:
+    ///
+    /// ```rust, ignore
+    /// impl TrainStep<MnistBatch<B>, ClassificationOutput<B>> for Model<Autodiff<LibTorch>> {
+    ///     fn step(&self, batch: MnistBatch<B>) -> TrainOutput<ClassificationOutput<B>> {
+    ///         let device = batch.images.device();
+    ///         let item = LibTorch::<f32>::with_autocast(&device, || {
+    ///             self.forward_classification(batch.images, batch.targets)
+    ///         });
+    ///
+    ///         TrainOutput::new(self, item.loss.backward(), item)
+    ///     }
+    /// }
+    /// ```
+    pub fn with_autocast<T, F>(device: &LibTorchDevice, func: F) -> T
+    where
+        F: FnOnce() -> T,
+    {
+        match device {
+            LibTorchDevice::Cuda(_) => tch::autocast(true, func),
+            _ => func(),
+        }
+    }
+}
+
 impl<E: TchElement> Backend for LibTorch<E> {
     type Device = LibTorchDevice;
     type FullPrecisionElem = f32;
@@ -102,7 +139,109 @@ impl<E: TchElement> Backend for LibTorch<E> {
         if let LibTorchDevice::Cuda(index) = device {
             tch::Cuda::synchronize(*index as i64);
         } else if let LibTorchDevice::Mps = device {
-            panic!("Can't sync MPS device")
+            // LibTorch has `torch::mps::synchronize`, but tch doesn't expose it like it does for
+            // Cuda. Copying a tensor to the host is blocking and only happens once the kernels
+            // queued on the MPS stream are executed, so it's used to wait for them instead.
+            let _ =
+                tch::Tensor::zeros([1], (tch::Kind::Float, tch::Device::Mps)).to(tch::Device::Cpu);
+        }
+    }
+
+    fn non_deterministic_ops(device: &Self::Device) -> &'static [&'static str] {
+        match device {
+            // The CUDA kernels of LibTorch accumulate with atomics for these operations.
+            LibTorchDevice::Cuda(_) => &[
+                "scatter",
+                "select_assign",
+                "index_select backward",
+                "embedding backward",
+                "max_pool2d backward",
+                "avg_pool2d backward",
+                "adaptive_avg_pool2d backward",
+                "interpolate backward",
+            ],
+            _ => &[],
+        }
+    }
+}
+
+/// The complex tensors hold LibTorch complex tensors, whose parts have the float element type.
+impl<E: TchElement> ComplexBackend for LibTorch<E>
+where
+    Complex<E>: Element,
+{
+    type ComplexTensorPrimitive<const D: usize> = TchTensor<E, D>;
+    type ComplexElem = Complex<E>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, Int, Tensor};
+
+    type TestBackend = LibTorch<f32>;
+
+    /// The accelerated device available on the system, the CPU is used otherwise.
+    fn device() -> LibTorchDevice {
+        if tch::utils::has_mps() {
+            LibTorchDevice::Mps
+        } else if tch::Cuda::is_available() {
+            LibTorchDevice::Cuda(0)
+        } else {
+            LibTorchDevice::Cpu
         }
     }
+
+    fn float_ops(device: &LibTorchDevice) -> Data<f32, 1> {
+        let tensor = Tensor::<TestBackend, 2>::from_floats([[1.0, -2.0], [3.0, 0.5]], device);
+        let output = tensor
+            .clone()
+            .matmul(tensor.clone().transpose())
+            .add(tensor.clone().relu())
+            .sub(tensor.clone().powf(2.0))
+            .div(tensor.abs().add_scalar(1.0))
+            .exp()
+            .sum_dim(1)
+            .reshape([2]);
+        TestBackend::sync(device);
+
+        output.into_data()
+    }
+
+    fn int_ops(device: &LibTorchDevice) -> Data<i64, 1> {
+        let tensor = Tensor::<TestBackend, 1, Int>::arange(0..6, device);
+        let output = tensor
+            .clone()
+            .mul(tensor.clone())
+            .sub_scalar(3)
+            .reshape([2, 3])
+            .sum_dim(1)
+            .reshape([2]);
+        TestBackend::sync(device);
+
+        output.into_data()
+    }
+
+    #[test]
+    fn should_sync_float_ops() {
+        let device = device();
+
+        float_ops(&device).assert_approx_eq(&float_ops(&LibTorchDevice::Cpu), 3);
+    }
+
+    #[test]
+    fn should_sync_int_ops() {
+        let device = device();
+
+        assert_eq!(int_ops(&device), int_ops(&LibTorchDevice::Cpu));
+        assert_eq!(int_ops(&device), Data::from([-4, 41]));
+    }
+
+    #[test]
+    fn should_sync_without_queued_ops() {
+        let device = device();
+
+        TestBackend::sync(&device);
+        TestBackend::sync(&device);
+    }
 }
@@ -0,0 +1,126 @@
+use crate::{element::TchElement, LibTorch, LibTorchDevice, TchTensor};
+use burn_tensor::dlpack::{
+    device_type, DLDataType, DLDevice, DLPackBackend, DLPackError, DLPackTensor,
+};
+
+impl<E: TchElement> DLPackBackend for LibTorch<E> {
+    /// The exported tensor shares the memory of the tensor, on any device.
+    fn float_to_dlpack<const D: usize>(tensor: TchTensor<E, D>) -> DLPackTensor {
+        let device = match tensor.tensor.device() {
+            tch::Device::Cpu => DLDevice::cpu(),
+            tch::Device::Cuda(index) => DLDevice {
+                device_type: device_type::CUDA,
+                device_id: index as i32,
+            },
+            tch::Device::Mps => DLDevice {
+                device_type: device_type::METAL,
+                device_id: 0,
+            },
+            tch::Device::Vulkan => DLDevice {
+                device_type: device_type::VULKAN,
+                device_id: 0,
+            },
+        };
+        let dtype = DLDataType::of::<E>().expect("Float elements should be supported by DLPack");
+        let shape = tensor.tensor.size();
+        let strides = tensor.tensor.stride();
+        let data = tensor.tensor.data_ptr();
+
+        // SAFETY: The tensor keeps its storage alive until the managed tensor is deleted.
+        unsafe { DLPackTensor::new(tensor, data, device, dtype, shape, strides) }
+    }
+
+    /// The memory of the DLPack tensor is viewed by LibTorch and copied on its device, so the
+    /// elements never transit through the host for GPU tensors.
+    fn float_from_dlpack<const D: usize>(
+        tensor: DLPackTensor,
+        device: &LibTorchDevice,
+    ) -> Result<TchTensor<E, D>, DLPackError> {
+        let dl_device = tensor.device();
+        let source = match dl_device.device_type {
+            device_type::CPU | device_type::CUDA_HOST => tch::Device::Cpu,
+            device_type::CUDA => tch::Device::Cuda(dl_device.device_id as usize),
+            _ => return Err(DLPackError::UnsupportedDevice(dl_device)),
+        };
+
+        let shape = tensor.shape().to_vec();
+        if shape.len() != D {
+            return Err(DLPackError::RankMismatch {
+                expected: D,
+                got: shape.len(),
+            });
+        }
+
+        let dtype = tensor.dtype();
+        let kind = match (dtype.code, dtype.bits, dtype.lanes) {
+            (DLDataType::FLOAT, 64, 1) => tch::Kind::Double,
+            (DLDataType::FLOAT, 32, 1) => tch::Kind::Float,
+            (DLDataType::FLOAT, 16, 1) => tch::Kind::Half,
+            (DLDataType::BFLOAT, 16, 1) => tch::Kind::BFloat16,
+            (DLDataType::INT, 64, 1) => tch::Kind::Int64,
+            (DLDataType::INT, 32, 1) => tch::Kind::Int,
+            (DLDataType::INT, 16, 1) => tch::Kind::Int16,
+            (DLDataType::INT, 8, 1) => tch::Kind::Int8,
+            (DLDataType::UINT, 8, 1) => tch::Kind::Uint8,
+            _ => return Err(DLPackError::UnsupportedDType(dtype)),
+        };
+
+        // SAFETY: The view is only used to copy the elements while the DLPack tensor is alive.
+        let view = unsafe {
+            tch::Tensor::from_blob(
+                tensor.data_ptr() as *const u8,
+                &shape,
+                &tensor.strides(),
+                kind,
+                source,
+            )
+        };
+        let copy = view.to_device(tch::Device::from(*device)).to_kind(E::KIND);
+        // Materialize the copy before releasing the memory of the DLPack tensor.
+        let copy = match copy.data_ptr() == view.data_ptr() {
+            true => copy.copy(),
+            false => copy,
+        };
+
+        core::mem::drop(view);
+        core::mem::drop(tensor);
+
+        Ok(TchTensor::new(copy))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::LibTorch;
+    use burn_tensor::{Data, Tensor};
+
+    type TestBackend = LibTorch<f32>;
+
+    #[test]
+    fn export_shares_memory() {
+        let tensor = Tensor::<TestBackend, 2>::from_floats(
+            [[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]],
+            &Default::default(),
+        );
+        let data = tensor.clone().into_primitive().tensor.data_ptr();
+
+        let exported = tensor.to_dlpack();
+
+        assert_eq!(exported.data_ptr(), data);
+        assert_eq!(exported.shape(), &[2, 3]);
+    }
+
+    #[test]
+    fn round_trip_with_strides() {
+        let device = Default::default();
+        let tensor =
+            Tensor::<TestBackend, 2>::from_floats([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]], &device);
+
+        let exported = tensor.transpose().to_dlpack();
+        let imported = Tensor::<TestBackend, 2>::from_dlpack(exported, &device).unwrap();
+
+        imported
+            .into_data()
+            .assert_approx_eq(&Data::from([[1.0, 4.0], [2.0, 5.0], [3.0, 6.0]]), 3);
+    }
+}
@@ -4,13 +4,18 @@
 //! Burn Tch Backend
 
 mod backend;
+mod dlpack;
 mod element;
 mod ops;
 mod tensor;
+mod torchscript;
+mod transfer;
 
 pub use backend::*;
 pub use element::*;
 pub use tensor::*;
+pub use torchscript::*;
+pub use transfer::*;
 
 #[cfg(test)]
 mod tests {
@@ -22,5 +27,7 @@ mod tests {
     type TestTensorBool<const D: usize> = burn_tensor::Tensor<TestBackend, D, burn_tensor::Bool>;
 
     burn_tensor::testgen_all!();
+    burn_tensor::testgen_complex!();
+    burn_tensor::testgen_fft!();
     burn_autodiff::testgen_all!();
 }
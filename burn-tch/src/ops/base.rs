@@ -96,6 +96,22 @@ impl<E: tch::kind::Element + Copy + Default> TchOps<E> {
         TchTensor::from_existing(tensor, storage)
     }
 
+    pub fn scatter_reduce<const D: usize>(
+        dim: usize,
+        tensor: TchTensor<E, D>,
+        indices: TchTensor<i64, D>,
+        value: TchTensor<E, D>,
+        reduce: &str,
+    ) -> TchTensor<E, D> {
+        let storage = tensor.storage.clone();
+        let tensor =
+            tensor
+                .tensor
+                .scatter_reduce(dim as i64, &indices.tensor, &value.tensor, reduce, true);
+
+        TchTensor::from_existing(tensor, storage)
+    }
+
     pub fn index_select_dim<const D: usize>(
         tensor: TchTensor<E, D>,
         dim: usize,
@@ -374,6 +390,67 @@ impl<E: tch::kind::Element + Copy + Default> TchOps<E> {
         (tensor, indices)
     }
 
+    pub fn sort<const D: usize>(
+        tensor: TchTensor<E, D>,
+        dim: usize,
+        descending: bool,
+    ) -> TchTensor<E, D> {
+        Self::sort_with_indices(tensor, dim, descending).0
+    }
+
+    pub fn sort_with_indices<const D: usize>(
+        tensor: TchTensor<E, D>,
+        dim: usize,
+        descending: bool,
+    ) -> (TchTensor<E, D>, TchTensor<i64, D>) {
+        let (tensor, indices) = tensor.tensor.sort_stable(true, dim as i64, descending);
+
+        (TchTensor::new(tensor), TchTensor::new(indices))
+    }
+
+    pub fn argsort<const D: usize>(
+        tensor: TchTensor<E, D>,
+        dim: usize,
+        descending: bool,
+    ) -> TchTensor<i64, D> {
+        Self::sort_with_indices(tensor, dim, descending).1
+    }
+
+    pub fn cumsum<const D: usize>(tensor: TchTensor<E, D>, dim: usize) -> TchTensor<E, D> {
+        TchTensor::new(tensor.tensor.cumsum(dim as i64, E::KIND))
+    }
+
+    pub fn cumprod<const D: usize>(tensor: TchTensor<E, D>, dim: usize) -> TchTensor<E, D> {
+        TchTensor::new(tensor.tensor.cumprod(dim as i64, E::KIND))
+    }
+
+    pub fn masked_select<const D: usize>(
+        tensor: TchTensor<E, D>,
+        mask: TchTensor<bool, D>,
+    ) -> TchTensor<E, 1> {
+        TchTensor::new(tensor.tensor.masked_select(&mask.tensor))
+    }
+
+    pub fn searchsorted<const D: usize>(
+        sorted_sequence: TchTensor<E, D>,
+        values: TchTensor<E, D>,
+        right: bool,
+    ) -> TchTensor<i64, D> {
+        let side = match right {
+            true => "right",
+            false => "left",
+        };
+
+        TchTensor::new(tch::Tensor::searchsorted(
+            &sorted_sequence.tensor,
+            &values.tensor,
+            false,
+            right,
+            side,
+            None::<tch::Tensor>,
+        ))
+    }
+
     pub fn clamp_min<const D: usize, S: Into<tch::Scalar> + Clone + Copy>(
         tensor: TchTensor<E, D>,
         min: S,
@@ -35,7 +35,7 @@ impl<E: TchElement> BoolTensorOps<Self> for LibTorch<E> {
         tensor: TchTensor<bool, D>,
         device: &LibTorchDevice,
     ) -> TchTensor<bool, D> {
-        TchTensor::new(tensor.tensor.to((*device).into()))
+        TchTensor::new(crate::transfer::to_device(&tensor.tensor, *device))
     }
 
     fn bool_reshape<const D1: usize, const D2: usize>(
@@ -131,4 +131,13 @@ impl<E: TchElement> BoolTensorOps<Self> for LibTorch<E> {
     ) -> Vec<TchTensor<bool, D>> {
         TchOps::chunk(tensor, chunks, dim)
     }
+
+    fn bool_nonzero<const D: usize>(tensor: TchTensor<bool, D>) -> Vec<TchTensor<i64, 1>> {
+        tensor
+            .tensor
+            .nonzero_numpy()
+            .into_iter()
+            .map(TchTensor::new)
+            .collect()
+    }
 }
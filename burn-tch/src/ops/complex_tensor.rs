@@ -0,0 +1,102 @@
+use super::TchOps;
+use crate::{element::TchElement, LibTorch, LibTorchDevice, TchTensor};
+use burn_tensor::{ops::ComplexTensorOps, Data, Element, Reader, Shape};
+use num_complex::Complex;
+
+// The complex tensors are stored as LibTorch complex tensors, with the parts of the float
+// element type, e.g. `ComplexFloat` for `f32`.
+
+impl<E: TchElement> ComplexTensorOps<Self> for LibTorch<E>
+where
+    Complex<E>: Element,
+{
+    fn complex_from_data<const D: usize>(
+        data: Data<Complex<E>, D>,
+        device: &LibTorchDevice,
+    ) -> TchTensor<E, D> {
+        let (real, imag): (Vec<E>, Vec<E>) =
+            data.value.iter().map(|value| (value.re, value.im)).unzip();
+
+        let real = TchTensor::from_data(Data::new(real, data.shape.clone()), (*device).into());
+        let imag = TchTensor::from_data(Data::new(imag, data.shape), (*device).into());
+
+        Self::complex_from_parts(real, imag)
+    }
+
+    fn complex_into_data<const D: usize>(tensor: TchTensor<E, D>) -> Reader<Data<Complex<E>, D>> {
+        let shape = tensor.shape();
+        let real: Result<Vec<E>, tch::TchError> = tensor.tensor.real().reshape([-1]).try_into();
+        let imag: Result<Vec<E>, tch::TchError> = tensor.tensor.imag().reshape([-1]).try_into();
+
+        let values = real
+            .unwrap()
+            .into_iter()
+            .zip(imag.unwrap())
+            .map(|(re, im)| Complex::new(re, im))
+            .collect();
+
+        Reader::Concrete(Data::new(values, shape))
+    }
+
+    fn complex_shape<const D: usize>(tensor: &TchTensor<E, D>) -> Shape<D> {
+        tensor.shape()
+    }
+
+    fn complex_device<const D: usize>(tensor: &TchTensor<E, D>) -> LibTorchDevice {
+        tensor.tensor.device().into()
+    }
+
+    fn complex_reshape<const D1: usize, const D2: usize>(
+        tensor: TchTensor<E, D1>,
+        shape: Shape<D2>,
+    ) -> TchTensor<E, D2> {
+        TchOps::reshape(tensor, shape)
+    }
+
+    fn complex_from_parts<const D: usize>(
+        real: TchTensor<E, D>,
+        imag: TchTensor<E, D>,
+    ) -> TchTensor<E, D> {
+        TchTensor::new(tch::Tensor::complex(&real.tensor, &imag.tensor))
+    }
+
+    fn complex_real<const D: usize>(tensor: TchTensor<E, D>) -> TchTensor<E, D> {
+        TchTensor::partial(tensor.tensor.real(), tensor.storage)
+    }
+
+    fn complex_imag<const D: usize>(tensor: TchTensor<E, D>) -> TchTensor<E, D> {
+        TchTensor::partial(tensor.tensor.imag(), tensor.storage)
+    }
+
+    fn complex_add<const D: usize>(lhs: TchTensor<E, D>, rhs: TchTensor<E, D>) -> TchTensor<E, D> {
+        TchTensor::new(&lhs.tensor + &rhs.tensor)
+    }
+
+    fn complex_sub<const D: usize>(lhs: TchTensor<E, D>, rhs: TchTensor<E, D>) -> TchTensor<E, D> {
+        TchTensor::new(&lhs.tensor - &rhs.tensor)
+    }
+
+    fn complex_mul<const D: usize>(lhs: TchTensor<E, D>, rhs: TchTensor<E, D>) -> TchTensor<E, D> {
+        TchTensor::new(&lhs.tensor * &rhs.tensor)
+    }
+
+    fn complex_div<const D: usize>(lhs: TchTensor<E, D>, rhs: TchTensor<E, D>) -> TchTensor<E, D> {
+        TchTensor::new(&lhs.tensor / &rhs.tensor)
+    }
+
+    fn complex_neg<const D: usize>(tensor: TchTensor<E, D>) -> TchTensor<E, D> {
+        TchTensor::new(tensor.tensor.neg())
+    }
+
+    fn complex_conj<const D: usize>(tensor: TchTensor<E, D>) -> TchTensor<E, D> {
+        TchTensor::new(tensor.tensor.conj_physical())
+    }
+
+    fn complex_abs<const D: usize>(tensor: TchTensor<E, D>) -> TchTensor<E, D> {
+        TchTensor::new(tensor.tensor.abs())
+    }
+
+    fn complex_angle<const D: usize>(tensor: TchTensor<E, D>) -> TchTensor<E, D> {
+        TchTensor::new(tensor.tensor.angle())
+    }
+}
@@ -38,7 +38,7 @@ impl<E: TchElement> IntTensorOps<Self> for LibTorch<E> {
         tensor: TchTensor<i64, D>,
         device: &LibTorchDevice,
     ) -> TchTensor<i64, D> {
-        TchTensor::new(tensor.tensor.to((*device).into()))
+        TchTensor::new(crate::transfer::to_device(&tensor.tensor, *device))
     }
 
     fn int_reshape<const D1: usize, const D2: usize>(
@@ -297,6 +297,24 @@ impl<E: TchElement> IntTensorOps<Self> for LibTorch<E> {
         TchOps::scatter(dim, tensor, indices, value)
     }
 
+    fn int_scatter_max<const D: usize>(
+        dim: usize,
+        tensor: TchTensor<i64, D>,
+        indices: TchTensor<i64, D>,
+        value: TchTensor<i64, D>,
+    ) -> TchTensor<i64, D> {
+        TchOps::scatter_reduce(dim, tensor, indices, value, "amax")
+    }
+
+    fn int_scatter_min<const D: usize>(
+        dim: usize,
+        tensor: TchTensor<i64, D>,
+        indices: TchTensor<i64, D>,
+        value: TchTensor<i64, D>,
+    ) -> TchTensor<i64, D> {
+        TchOps::scatter_reduce(dim, tensor, indices, value, "amin")
+    }
+
     fn int_select<const D: usize>(
         tensor: TchTensor<i64, D>,
         dim: usize,
@@ -418,4 +436,72 @@ impl<E: TchElement> IntTensorOps<Self> for LibTorch<E> {
     ) -> Vec<TchTensor<i64, D>> {
         TchOps::chunk(tensor, chunks, dim)
     }
+
+    fn int_sort<const D: usize>(
+        tensor: TchTensor<i64, D>,
+        dim: usize,
+        descending: bool,
+    ) -> TchTensor<i64, D> {
+        TchOps::sort(tensor, dim, descending)
+    }
+
+    fn int_sort_with_indices<const D: usize>(
+        tensor: TchTensor<i64, D>,
+        dim: usize,
+        descending: bool,
+    ) -> (TchTensor<i64, D>, TchTensor<i64, D>) {
+        TchOps::sort_with_indices(tensor, dim, descending)
+    }
+
+    fn int_argsort<const D: usize>(
+        tensor: TchTensor<i64, D>,
+        dim: usize,
+        descending: bool,
+    ) -> TchTensor<i64, D> {
+        TchOps::argsort(tensor, dim, descending)
+    }
+
+    fn int_cumsum<const D: usize>(tensor: TchTensor<i64, D>, dim: usize) -> TchTensor<i64, D> {
+        TchOps::cumsum(tensor, dim)
+    }
+
+    fn int_cumprod<const D: usize>(tensor: TchTensor<i64, D>, dim: usize) -> TchTensor<i64, D> {
+        TchOps::cumprod(tensor, dim)
+    }
+
+    fn int_masked_select<const D: usize>(
+        tensor: TchTensor<i64, D>,
+        mask: TchTensor<bool, D>,
+    ) -> TchTensor<i64, 1> {
+        TchOps::masked_select(tensor, mask)
+    }
+
+    fn int_searchsorted<const D: usize>(
+        sorted_sequence: TchTensor<i64, D>,
+        values: TchTensor<i64, D>,
+        right: bool,
+    ) -> TchTensor<i64, D> {
+        TchOps::searchsorted(sorted_sequence, values, right)
+    }
+
+    fn int_unique<const D: usize>(
+        tensor: TchTensor<i64, D>,
+    ) -> (TchTensor<i64, 1>, TchTensor<i64, D>, TchTensor<i64, 1>) {
+        let shape = tensor.tensor.size();
+        let (values, inverse, counts) = tensor.tensor.reshape([-1]).unique_dim(0, true, true, true);
+
+        (
+            TchTensor::new(values),
+            TchTensor::new(inverse.reshape(shape.as_slice())),
+            TchTensor::new(counts),
+        )
+    }
+
+    fn int_bincount(tensor: TchTensor<i64, 1>, min_length: usize) -> TchTensor<i64, 1> {
+        TchTensor::new(
+            tensor
+                .tensor
+                .bincount(None::<tch::Tensor>, min_length as i64),
+        )
+    }
 }
@@ -1,6 +1,7 @@
 mod activation;
 mod base;
 mod bool_tensor;
+mod complex_tensor;
 mod int_tensor;
 mod module;
 mod tensor;
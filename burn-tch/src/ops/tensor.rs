@@ -1,7 +1,8 @@
 use super::TchOps;
 use crate::{element::TchElement, LibTorch, LibTorchDevice, TchShape, TchTensor};
 use burn_tensor::{
-    backend::Backend, ops::TensorOps, Data, Distribution, ElementConversion, Reader, Shape,
+    backend::Backend, fft::FftError, ops::TensorOps, Data, Distribution, ElementConversion, Reader,
+    Shape,
 };
 use std::ops::Range;
 
@@ -97,7 +98,7 @@ impl<E: TchElement> TensorOps<Self> for LibTorch<E> {
         tensor: TchTensor<E, D>,
         device: &LibTorchDevice,
     ) -> TchTensor<E, D> {
-        TchTensor::new(tensor.tensor.to((*device).into()))
+        TchTensor::new(crate::transfer::to_device(&tensor.tensor, *device))
     }
 
     fn empty<const D: usize>(
@@ -206,6 +207,24 @@ impl<E: TchElement> TensorOps<Self> for LibTorch<E> {
         TchOps::scatter(dim, tensor, indices, value)
     }
 
+    fn scatter_max<const D: usize>(
+        dim: usize,
+        tensor: TchTensor<E, D>,
+        indices: TchTensor<i64, D>,
+        value: TchTensor<E, D>,
+    ) -> TchTensor<E, D> {
+        TchOps::scatter_reduce(dim, tensor, indices, value, "amax")
+    }
+
+    fn scatter_min<const D: usize>(
+        dim: usize,
+        tensor: TchTensor<E, D>,
+        indices: TchTensor<i64, D>,
+        value: TchTensor<E, D>,
+    ) -> TchTensor<E, D> {
+        TchOps::scatter_reduce(dim, tensor, indices, value, "amin")
+    }
+
     fn select<const D: usize>(
         tensor: TchTensor<E, D>,
         dim: usize,
@@ -457,4 +476,143 @@ impl<E: TchElement> TensorOps<Self> for LibTorch<E> {
     ) -> Vec<TchTensor<E, D>> {
         TchOps::chunk(tensor, chunks, dim)
     }
+
+    fn sort<const D: usize>(
+        tensor: TchTensor<E, D>,
+        dim: usize,
+        descending: bool,
+    ) -> TchTensor<E, D> {
+        TchOps::sort(tensor, dim, descending)
+    }
+
+    fn sort_with_indices<const D: usize>(
+        tensor: TchTensor<E, D>,
+        dim: usize,
+        descending: bool,
+    ) -> (TchTensor<E, D>, TchTensor<i64, D>) {
+        TchOps::sort_with_indices(tensor, dim, descending)
+    }
+
+    fn argsort<const D: usize>(
+        tensor: TchTensor<E, D>,
+        dim: usize,
+        descending: bool,
+    ) -> TchTensor<i64, D> {
+        TchOps::argsort(tensor, dim, descending)
+    }
+
+    fn cumsum<const D: usize>(tensor: TchTensor<E, D>, dim: usize) -> TchTensor<E, D> {
+        TchOps::cumsum(tensor, dim)
+    }
+
+    fn cumprod<const D: usize>(tensor: TchTensor<E, D>, dim: usize) -> TchTensor<E, D> {
+        TchOps::cumprod(tensor, dim)
+    }
+
+    fn masked_select<const D: usize>(
+        tensor: TchTensor<E, D>,
+        mask: TchTensor<bool, D>,
+    ) -> TchTensor<E, 1> {
+        TchOps::masked_select(tensor, mask)
+    }
+
+    fn searchsorted<const D: usize>(
+        sorted_sequence: TchTensor<E, D>,
+        values: TchTensor<E, D>,
+        right: bool,
+    ) -> TchTensor<i64, D> {
+        TchOps::searchsorted(sorted_sequence, values, right)
+    }
+
+    fn solve<const D: usize>(lhs: TchTensor<E, D>, rhs: TchTensor<E, D>) -> TchTensor<E, D> {
+        TchTensor::new(tch::Tensor::linalg_solve(&lhs.tensor, &rhs.tensor, true))
+    }
+
+    fn inverse<const D: usize>(tensor: TchTensor<E, D>) -> TchTensor<E, D> {
+        TchTensor::new(tch::Tensor::linalg_inv(&tensor.tensor))
+    }
+
+    fn cholesky<const D: usize>(tensor: TchTensor<E, D>) -> TchTensor<E, D> {
+        TchTensor::new(tensor.tensor.linalg_cholesky(false))
+    }
+
+    fn qr<const D: usize>(tensor: TchTensor<E, D>) -> (TchTensor<E, D>, TchTensor<E, D>) {
+        let (q, r) = tch::Tensor::linalg_qr(&tensor.tensor, "reduced");
+
+        (TchTensor::new(q), TchTensor::new(r))
+    }
+
+    fn svd<const D: usize>(
+        tensor: TchTensor<E, D>,
+    ) -> (TchTensor<E, D>, TchTensor<E, D>, TchTensor<E, D>) {
+        let (u, s, v) = tensor.tensor.svd(true, true);
+
+        (
+            TchTensor::new(u),
+            TchTensor::new(s.unsqueeze(-2)),
+            TchTensor::new(v.transpose(-2, -1)),
+        )
+    }
+
+    fn fft<const D: usize>(
+        real: TchTensor<E, D>,
+        imag: TchTensor<E, D>,
+        dim: usize,
+        inverse: bool,
+    ) -> Result<(TchTensor<E, D>, TchTensor<E, D>), FftError> {
+        let signal = tch::Tensor::complex(&real.tensor, &imag.tensor);
+        let spectrum = match inverse {
+            true => signal.fft_ifft(None::<i64>, dim as i64, "backward"),
+            false => signal.fft_fft(None::<i64>, dim as i64, "backward"),
+        };
+
+        Ok(complex_parts(spectrum))
+    }
+
+    fn rfft<const D: usize>(
+        signal: TchTensor<E, D>,
+        dim: usize,
+    ) -> Result<(TchTensor<E, D>, TchTensor<E, D>), FftError> {
+        let spectrum = signal.tensor.fft_rfft(None::<i64>, dim as i64, "backward");
+
+        Ok(complex_parts(spectrum))
+    }
+
+    fn irfft<const D: usize>(
+        real: TchTensor<E, D>,
+        imag: TchTensor<E, D>,
+        dim: usize,
+        n: usize,
+    ) -> Result<TchTensor<E, D>, FftError> {
+        let spectrum = tch::Tensor::complex(&real.tensor, &imag.tensor);
+        let signal = spectrum.fft_irfft(n as i64, dim as i64, "backward");
+
+        Ok(TchTensor::new(signal))
+    }
+
+    fn spmm(
+        indices: TchTensor<i64, 2>,
+        values: TchTensor<E, 1>,
+        shape: Shape<2>,
+        dense: TchTensor<E, 2>,
+    ) -> TchTensor<E, 2> {
+        let row_indices = indices.tensor.select(0, 0);
+        let col_indices = indices.tensor.select(0, 1);
+        let products = dense.tensor.index_select(0, &col_indices) * values.tensor.unsqueeze(1);
+
+        let size = [shape.dims[0] as i64, products.size()[1]];
+        let output = tch::Tensor::zeros(size, (products.kind(), products.device()));
+
+        TchTensor::new(output.index_add(0, &row_indices, &products))
+    }
+}
+
+/// Split a complex tensor into its real and imaginary parts, each with its own storage.
+fn complex_parts<E: TchElement, const D: usize>(
+    tensor: tch::Tensor,
+) -> (TchTensor<E, D>, TchTensor<E, D>) {
+    (
+        TchTensor::new(tensor.real().contiguous()),
+        TchTensor::new(tensor.imag().contiguous()),
+    )
 }
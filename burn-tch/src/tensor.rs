@@ -203,7 +203,10 @@ impl<E: tch::kind::Element + Default, const D: usize> TchTensor<E, D> {
     ///
     /// A new tensor.
     pub fn from_data(data: Data<E, D>, device: tch::Device) -> Self {
-        let tensor = tch::Tensor::from_slice(data.value.as_slice()).to(device);
+        let tensor = crate::transfer::to_device(
+            &tch::Tensor::from_slice(data.value.as_slice()),
+            device.into(),
+        );
         let shape_tch = TchShape::from(data.shape);
         let tensor = tensor.reshape(shape_tch.dims).to_kind(E::KIND);
 
@@ -0,0 +1,154 @@
+use std::{
+    path::Path,
+    sync::{Arc, Mutex},
+};
+
+use burn_tensor::Tensor;
+
+use crate::{element::TchElement, LibTorch, LibTorchDevice, TchTensor};
+
+/// A [TorchScript](https://pytorch.org/docs/stable/jit.html) module executed as an opaque
+/// operation on the tensors of the tch backend.
+///
+/// This allows models partially ported to Burn to keep some of their components in TorchScript,
+/// the tensors being passed to LibTorch without any copy.
+///
+/// # Notes
+///
+/// The module is executed in evaluation mode, and its operations aren't tracked by the
+/// [Autodiff](https://docs.rs/burn-autodiff) backend decorator, so no gradient flows through it.
+///
+/// # Example
+///
+/// ```rust, ignore
+/// let encoder = TorchScriptModule::load("encoder.pt", &device)?;
+/// let features: Tensor<LibTorch, 2> = encoder.forward(vec![images]);
+/// ```
+#[derive(Clone, Debug)]
+pub struct TorchScriptModule {
+    module: Arc<Mutex<tch::CModule>>,
+}
+
+impl TorchScriptModule {
+    /// Load the module saved at the given path, with its parameters on the given device.
+    pub fn load<P: AsRef<Path>>(path: P, device: &LibTorchDevice) -> Result<Self, tch::TchError> {
+        let module = tch::CModule::load_on_device(path, (*device).into())?;
+
+        Ok(Self::from(module))
+    }
+
+    /// Execute the `forward` method of the module, which should return a single tensor.
+    ///
+    /// # Panics
+    ///
+    /// If the module fails, or if the rank of its output isn't `D_OUT`.
+    pub fn forward<E: TchElement, const D_IN: usize, const D_OUT: usize>(
+        &self,
+        inputs: Vec<Tensor<LibTorch<E>, D_IN>>,
+    ) -> Tensor<LibTorch<E>, D_OUT> {
+        let inputs = inputs
+            .into_iter()
+            .map(|input| input.into_primitive().tensor)
+            .collect::<Vec<_>>();
+        let output = self
+            .module
+            .lock()
+            .unwrap()
+            .forward_ts(&inputs)
+            .expect("The TorchScript module should be executed");
+
+        Tensor::from_primitive(into_primitive(output))
+    }
+
+    /// Execute the `forward` method of the module, which should return a tuple of tensors.
+    ///
+    /// # Panics
+    ///
+    /// If the module fails, if it doesn't return a tuple of tensors, or if the rank of an output
+    /// isn't `D_OUT`.
+    pub fn forward_many<E: TchElement, const D_IN: usize, const D_OUT: usize>(
+        &self,
+        inputs: Vec<Tensor<LibTorch<E>, D_IN>>,
+    ) -> Vec<Tensor<LibTorch<E>, D_OUT>> {
+        let inputs = inputs
+            .into_iter()
+            .map(|input| tch::IValue::Tensor(input.into_primitive().tensor))
+            .collect::<Vec<_>>();
+        let output = self
+            .module
+            .lock()
+            .unwrap()
+            .forward_is(&inputs)
+            .expect("The TorchScript module should be executed");
+
+        match output {
+            tch::IValue::Tuple(outputs) | tch::IValue::GenericList(outputs) => outputs
+                .into_iter()
+                .map(|output| match output {
+                    tch::IValue::Tensor(tensor) => Tensor::from_primitive(into_primitive(tensor)),
+                    output => panic!("Expected a tensor output, got {output:?}"),
+                })
+                .collect(),
+            tch::IValue::TensorList(outputs) => outputs
+                .into_iter()
+                .map(|tensor| Tensor::from_primitive(into_primitive(tensor)))
+                .collect(),
+            output => panic!("Expected a tuple of tensors, got {output:?}"),
+        }
+    }
+}
+
+impl From<tch::CModule> for TorchScriptModule {
+    fn from(mut module: tch::CModule) -> Self {
+        module.set_eval();
+
+        Self {
+            module: Arc::new(Mutex::new(module)),
+        }
+    }
+}
+
+fn into_primitive<E: TchElement, const D: usize>(tensor: tch::Tensor) -> TchTensor<E, D> {
+    assert_eq!(
+        tensor.dim(),
+        D,
+        "The TorchScript module returned a tensor of rank {} but {} was expected",
+        tensor.dim(),
+        D
+    );
+
+    TchTensor::new(tensor.to_kind(E::KIND))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use burn_tensor::Data;
+
+    type TestBackend = LibTorch<f32>;
+
+    fn traced_module() -> TorchScriptModule {
+        let input = tch::Tensor::ones([2, 3], (tch::Kind::Float, tch::Device::Cpu));
+        let module =
+            tch::CModule::create_by_tracing("Double", "forward", &[input], &mut |inputs| {
+                vec![&inputs[0] * 2.0]
+            })
+            .unwrap();
+
+        TorchScriptModule::from(module)
+    }
+
+    #[test]
+    fn should_execute_traced_module() {
+        let input = Tensor::<TestBackend, 2>::from_floats(
+            [[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]],
+            &Default::default(),
+        );
+
+        let output: Tensor<TestBackend, 2> = traced_module().forward(vec![input]);
+
+        output
+            .into_data()
+            .assert_approx_eq(&Data::from([[2.0, 4.0, 6.0], [8.0, 10.0, 12.0]]), 3);
+    }
+}
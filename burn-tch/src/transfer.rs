@@ -0,0 +1,91 @@
+use core::sync::atomic::{AtomicBool, Ordering};
+
+use crate::LibTorchDevice;
+
+static PIN_MEMORY: AtomicBool = AtomicBool::new(false);
+static NON_BLOCKING: AtomicBool = AtomicBool::new(false);
+
+/// How tensors are copied from the host to Cuda devices, such as the batches of a dataloader.
+///
+/// # Example
+///
+/// ```no_run
+/// use burn_tch::TransferOptions;
+///
+/// TransferOptions {
+///     pin_memory: true,
+///     non_blocking: true,
+/// }
+/// .set();
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct TransferOptions {
+    /// Stage the data in page-locked host memory before copying it to the device, which is
+    /// faster and required for the copies to be asynchronous.
+    pub pin_memory: bool,
+    /// Return before the copy is completed, so it overlaps with the computations already queued
+    /// on the device. The operations on the copied tensor are ordered after the copy.
+    pub non_blocking: bool,
+}
+
+impl TransferOptions {
+    /// Use the options for every following transfer.
+    pub fn set(self) {
+        PIN_MEMORY.store(self.pin_memory, Ordering::Relaxed);
+        NON_BLOCKING.store(self.non_blocking, Ordering::Relaxed);
+    }
+
+    /// The options currently used.
+    pub fn current() -> Self {
+        Self {
+            pin_memory: PIN_MEMORY.load(Ordering::Relaxed),
+            non_blocking: NON_BLOCKING.load(Ordering::Relaxed),
+        }
+    }
+}
+
+/// Move the tensor to the device, following the [transfer options](TransferOptions) when it is
+/// copied from the host to a Cuda device.
+pub(crate) fn to_device(tensor: &tch::Tensor, device: LibTorchDevice) -> tch::Tensor {
+    let device = tch::Device::from(device);
+
+    if tensor.device() != tch::Device::Cpu || !device.is_cuda() {
+        return tensor.to(device);
+    }
+
+    let options = TransferOptions::current();
+    let tensor = match options.pin_memory {
+        true => tensor.pin_memory(device),
+        false => tensor.shallow_clone(),
+    };
+
+    tensor.to_device_(device, tensor.kind(), options.non_blocking, false)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_keep_the_options_set() {
+        let options = TransferOptions {
+            pin_memory: true,
+            non_blocking: false,
+        };
+
+        options.set();
+        let current = TransferOptions::current();
+        TransferOptions::default().set();
+
+        assert_eq!(current, options);
+    }
+
+    #[test]
+    fn should_move_to_the_cpu_without_copy() {
+        let tensor = tch::Tensor::from_slice(&[1.0f32, 2.0]);
+
+        let moved = to_device(&tensor, LibTorchDevice::Cpu);
+
+        assert_eq!(moved.data_ptr(), tensor.data_ptr());
+    }
+}
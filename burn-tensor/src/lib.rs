@@ -19,3 +19,6 @@ pub use half::{bf16, f16};
 pub use tensor::*;
 
 pub use burn_common::reader::Reader; // Useful so that backends don't have to add `burn_common` as
+
+/// Memory profiler shared by every backend, see [MemoryProfiler](memory::MemoryProfiler).
+pub use burn_common::memory;
@@ -1,3 +1,5 @@
+use alloc::boxed::Box;
+
 use crate::{backend::AutodiffBackend, BasicOps, Bool, Float, Int, Tensor, TensorKind};
 
 impl<const D: usize, B: AutodiffBackend> Tensor<B, D> {
@@ -6,6 +8,15 @@ impl<const D: usize, B: AutodiffBackend> Tensor<B, D> {
         B::backward::<D>(self.primitive.clone())
     }
 
+    /// Backward pass of the tensor keeping the graph alive.
+    ///
+    /// This is useful when multiple losses share a part of the graph, since each one can call
+    /// backward without recomputing the forward pass. The memory used by the graph is only freed
+    /// once a regular [backward](Tensor::backward) is called or once all the tensors are dropped.
+    pub fn backward_retain(&self) -> B::Gradients {
+        B::backward_retain::<D>(&self.primitive)
+    }
+
     /// Get the gradients of a tensor if it exist.
     ///
     /// Returns a new reference to the same tensor. Therefore the same grad tensor can
@@ -25,6 +36,26 @@ impl<const D: usize, B: AutodiffBackend> Tensor<B, D> {
     pub fn grad_replace(&self, grads: &mut B::Gradients, grad: Tensor<B::InnerBackend, D>) {
         B::grad_replace(&self.primitive, grads, grad.primitive);
     }
+
+    /// Register a hook executed on the gradient of the tensor during the backward pass.
+    ///
+    /// The hook can be used to observe the gradient, or to modify it before it is propagated
+    /// to the rest of the graph. Hooks are kept when the tensor is detached, moved to another
+    /// device or marked as requiring gradients, so they are preserved across module mapping.
+    ///
+    /// # Notes
+    ///
+    /// Hooks are executed in the order they are registered, and should be registered before the
+    /// tensor is used in other operations.
+    pub fn register_grad_hook<F>(self, hook: F) -> Self
+    where
+        F: Fn(Tensor<B::InnerBackend, D>) -> Tensor<B::InnerBackend, D> + Send + Sync + 'static,
+    {
+        Self::new(B::register_grad_hook(
+            self.primitive,
+            Box::new(move |grad| hook(Tensor::new(grad)).primitive),
+        ))
+    }
 }
 
 impl<const D: usize, B: AutodiffBackend, K: BasicAutodiffOps<B>> Tensor<B, D, K> {
@@ -1509,7 +1509,7 @@ where
     fn deserialize<De: Deserializer<'de>>(deserializer: De) -> Result<Self, De::Error> {
         let data_res: Result<DataSerialize<K::Elem>, De::Error> =
             DataSerialize::deserialize(deserializer);
-        let tensor = Tensor::from_data(data_res?, &<B::Device as Default>::default());
+        let tensor = Tensor::from_data(data_res?, &crate::backend::default_device::<B::Device>());
         Ok(tensor)
     }
 }
@@ -1,4 +1,5 @@
 use crate::{backend::Backend, Bool, Data, Int, Tensor};
+use alloc::vec::Vec;
 
 impl<B, const D: usize> Tensor<B, D, Bool>
 where
@@ -23,4 +24,34 @@ where
     pub fn bool_not(self) -> Self {
         Tensor::new(B::bool_not(self.primitive))
     }
+
+    /// Returns the indices of the true elements, as a 1-D tensor for each dimension, in row-major
+    /// order.
+    ///
+    /// The length of the indices depends on the values of the tensor, so the tensor is read back
+    /// to the host on most backends. This is a synchronization point: on lazy backends like
+    /// fusion, every pending operation is executed before the tensor is read.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Bool, Data, Tensor};
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = Default::default();
+    ///     let data = Data::from([[true, false], [false, true]]);
+    ///     let tensor = Tensor::<B, 2, Bool>::from_bool(data, &device);
+    ///
+    ///     let indices = tensor.nonzero();
+    ///     println!("{} {}", indices[0], indices[1]);
+    ///     // [0, 1] [0, 1]
+    /// }
+    /// ```
+    pub fn nonzero(self) -> Vec<Tensor<B, 1, Int>> {
+        B::bool_nonzero(self.primitive)
+            .into_iter()
+            .map(Tensor::new)
+            .collect()
+    }
 }
@@ -1,4 +1,5 @@
-use crate::{backend::Backend, BasicOps, Shape, Tensor};
+use super::einsum::EinsumEquation;
+use crate::{backend::Backend, BasicOps, PadMode, Shape, Tensor};
 use alloc::format;
 use alloc::string::{String, ToString};
 use alloc::vec;
@@ -231,6 +232,83 @@ impl TensorCheck {
         check
     }
 
+    pub(crate) fn einsum<const D1: usize, const D2: usize, const D: usize>(
+        equation: &str,
+        lhs: &Shape<D1>,
+        rhs: &Shape<D2>,
+    ) -> Self {
+        let mut check = Self::Ok;
+
+        let parsed = match EinsumEquation::parse(equation) {
+            Ok(parsed) => parsed,
+            Err(error) => {
+                return check.register(
+                    "Einsum",
+                    TensorError::new(error).details(format!("Equation: '{equation}'.")),
+                )
+            }
+        };
+
+        if parsed.lhs.len() != D1 {
+            check = check.register(
+                "Einsum",
+                TensorError::new("The labels of the first operand don't match its rank.").details(
+                    format!(
+                        "Equation: '{equation}', labels: {}, rank: {D1}.",
+                        parsed.lhs.len()
+                    ),
+                ),
+            );
+        }
+
+        if parsed.rhs.len() != D2 {
+            check = check.register(
+                "Einsum",
+                TensorError::new("The labels of the second operand don't match its rank.").details(
+                    format!(
+                        "Equation: '{equation}', labels: {}, rank: {D2}.",
+                        parsed.rhs.len()
+                    ),
+                ),
+            );
+        }
+
+        if parsed.output.len() != D && !(parsed.output.is_empty() && D == 1) {
+            check = check.register(
+                "Einsum",
+                TensorError::new("The labels of the output don't match its rank.").details(
+                    format!(
+                        "Equation: '{equation}', labels: {}, rank: {D}.",
+                        parsed.output.len()
+                    ),
+                ),
+            );
+        }
+
+        if let Self::Failed(_) = check {
+            return check;
+        }
+
+        for (dim_lhs, label) in parsed.lhs.iter().enumerate() {
+            if let Some(dim_rhs) = parsed.rhs.iter().position(|l| l == label) {
+                if lhs.dims[dim_lhs] != rhs.dims[dim_rhs] {
+                    check = check.register(
+                        "Einsum",
+                        TensorError::new(
+                            "The dimensions with the same label should have the same size.",
+                        )
+                        .details(format!(
+                            "Label: '{label}', lhs size: {}, rhs size: {}.",
+                            lhs.dims[dim_lhs], rhs.dims[dim_rhs]
+                        )),
+                    );
+                }
+            }
+        }
+
+        check
+    }
+
     pub(crate) fn squeeze<const D2: usize>(dim: usize, tensor_dims: &[usize]) -> Self {
         let mut check = Self::Ok;
         // This should actually be to check that the dimension to squeeze
@@ -342,6 +420,339 @@ impl TensorCheck {
         check
     }
 
+    pub(crate) fn linalg_matrix<const D: usize>(ops: &str) -> Self {
+        let mut check = Self::Ok;
+
+        if D < 2 {
+            check = check.register(
+                ops,
+                TensorError::new(format!(
+                    "The tensor should be a matrix or a batch of matrices, but got a rank of {D}."
+                )),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn linalg_square<const D: usize>(ops: &str, shape: &Shape<D>) -> Self {
+        let check = Self::linalg_matrix::<D>(ops);
+
+        if D < 2 {
+            return check;
+        }
+
+        let rows = shape.dims[D - 2];
+        let cols = shape.dims[D - 1];
+
+        if rows != cols {
+            return check.register(
+                ops,
+                TensorError::new(format!(
+                    "The matrices should be square, but got {rows} rows and {cols} columns."
+                ))
+                .details(format!("Tensor shape {:?}.", shape.dims)),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn solve<B: Backend, const D: usize>(
+        lhs: &Tensor<B, D>,
+        rhs: &Tensor<B, D>,
+    ) -> Self {
+        let shape_lhs = lhs.shape();
+        let shape_rhs = rhs.shape();
+        let mut check = Self::linalg_square::<D>("Solve", &shape_lhs);
+
+        check = check.binary_ops_device("Solve", &lhs.device(), &rhs.device());
+
+        if D < 2 {
+            return check;
+        }
+
+        if shape_lhs.dims[..D - 1] != shape_rhs.dims[..D - 1] {
+            check = check.register(
+                "Solve",
+                TensorError::new(
+                    "The right hand side should have the same batch dimensions and as many rows \
+                     as the matrices of the left hand side."
+                        .to_string(),
+                )
+                .details(format!(
+                    "Lhs shape {:?}, rhs shape {:?}.",
+                    shape_lhs.dims, shape_rhs.dims
+                )),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn complex_parts<const D: usize>(
+        ops: &str,
+        shape_real: &Shape<D>,
+        shape_imag: &Shape<D>,
+    ) -> Self {
+        let mut check = Self::Ok;
+
+        if shape_real != shape_imag {
+            check = check.register(
+                ops,
+                TensorError::new(
+                    "The real and imaginary parts should have the same shape.".to_string(),
+                )
+                .details(format!(
+                    "Real shape {:?}, imaginary shape {:?}.",
+                    shape_real.dims, shape_imag.dims
+                )),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn irfft<const D: usize>(dim: usize, shape: &Shape<D>, n: usize) -> Self {
+        let mut check = Self::dim_ops::<D>("Irfft", dim);
+
+        if dim < D && shape.dims[dim] != n / 2 + 1 {
+            check = check.register(
+                "Irfft",
+                TensorError::new(format!(
+                    "A signal of length {n} should have {} frequencies, but got {}.",
+                    n / 2 + 1,
+                    shape.dims[dim]
+                ))
+                .details(format!("Shape {:?}, dimension {dim}.", shape.dims)),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn masked_select<const D: usize>(shape: &Shape<D>, shape_mask: &Shape<D>) -> Self {
+        let mut check = Self::Ok;
+
+        if shape != shape_mask {
+            check = check.register(
+                "MaskedSelect",
+                TensorError::new("The mask should have the same shape as the tensor.".to_string())
+                    .details(format!(
+                        "Tensor shape {:?}, mask shape {:?}.",
+                        shape.dims, shape_mask.dims
+                    )),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn searchsorted<const D: usize>(
+        shape_sorted_sequence: &Shape<D>,
+        shape_values: &Shape<D>,
+    ) -> Self {
+        let mut check = Self::Ok;
+
+        if shape_sorted_sequence.dims[..D - 1] != shape_values.dims[..D - 1] {
+            check = check.register(
+                "Searchsorted",
+                TensorError::new(
+                    "The values should have the same leading dimensions as the sorted sequences."
+                        .to_string(),
+                )
+                .details(format!(
+                    "Sorted sequences shape {:?}, values shape {:?}.",
+                    shape_sorted_sequence.dims, shape_values.dims
+                )),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn histc(bins: usize, min: f64, max: f64) -> Self {
+        let mut check = Self::Ok;
+
+        if bins == 0 {
+            check = check.register(
+                "Histc",
+                TensorError::new("The number of bins should be greater than 0.".to_string()),
+            );
+        }
+
+        if min >= max {
+            check = check.register(
+                "Histc",
+                TensorError::new("The minimum should be lower than the maximum.".to_string())
+                    .details(format!("Minimum {min}, maximum {max}.")),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn quantile<const D: usize>(
+        ops: &str,
+        dim: usize,
+        shape: &Shape<D>,
+        q: f64,
+    ) -> Self {
+        let mut check = Self::dim_ops::<D>(ops, dim);
+
+        if !(0.0..=1.0).contains(&q) {
+            check = check.register(
+                ops,
+                TensorError::new("The quantile should be between 0 and 1.".to_string())
+                    .details(format!("Quantile {q}.")),
+            );
+        }
+
+        if dim < D && shape.dims[dim] == 0 {
+            check = check.register(
+                ops,
+                TensorError::new("Can't reduce an empty dimension.".to_string())
+                    .details(format!("Shape {:?}, dimension {dim}.", shape.dims)),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn pad<const D: usize>(
+        shape: &Shape<D>,
+        padding: &[(usize, usize); D],
+        mode: PadMode,
+    ) -> Self {
+        let mut check = Self::Ok;
+
+        for (dim, (before, after)) in padding.iter().enumerate() {
+            let size = shape.dims[dim];
+            let padded = *before > 0 || *after > 0;
+
+            let invalid = match mode {
+                PadMode::Constant(_) => false,
+                PadMode::Reflect => padded && (*before >= size || *after >= size),
+                PadMode::Replicate => padded && size == 0,
+            };
+
+            if invalid {
+                check = check.register(
+                    "Pad",
+                    TensorError::new(format!(
+                        "Can't pad dimension {dim} of size {size} by ({before}, {after}) with \
+                         mode {mode:?}."
+                    ))
+                    .details(
+                        "Reflection padding should be lower than the size of the dimension and \
+                         replication padding needs a non-empty dimension."
+                            .to_string(),
+                    ),
+                );
+            }
+        }
+
+        check
+    }
+
+    pub(crate) fn sparse_coo(
+        shape_indices: &Shape<2>,
+        shape_values: &Shape<1>,
+        shape: &Shape<2>,
+    ) -> Self {
+        let mut check = Self::Ok;
+
+        if shape_indices.dims[0] != 2 || shape_indices.dims[1] != shape_values.dims[0] {
+            check = check.register(
+                "SparseCoo",
+                TensorError::new(
+                    "The indices should have the shape [2, nnz], with a row and a column index \
+                     for each of the nnz values."
+                        .to_string(),
+                )
+                .details(format!(
+                    "Indices shape {:?}, values shape {:?}.",
+                    shape_indices.dims, shape_values.dims
+                )),
+            );
+        }
+
+        if shape.num_elements() == 0 {
+            check = check.register(
+                "SparseCoo",
+                TensorError::new("The sparse matrix should have at least one element.".to_string())
+                    .details(format!("Shape {:?}.", shape.dims)),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn sparse_csr(
+        shape_row_offsets: &Shape<1>,
+        shape_col_indices: &Shape<1>,
+        shape_values: &Shape<1>,
+        shape: &Shape<2>,
+    ) -> Self {
+        let mut check = Self::Ok;
+
+        if shape_row_offsets.dims[0] != shape.dims[0] + 1 {
+            check = check.register(
+                "SparseCsr",
+                TensorError::new(format!(
+                    "A matrix with {} rows should have {} row offsets, but got {}.",
+                    shape.dims[0],
+                    shape.dims[0] + 1,
+                    shape_row_offsets.dims[0]
+                ))
+                .details(format!("Shape {:?}.", shape.dims)),
+            );
+        }
+
+        if shape_col_indices.dims[0] != shape_values.dims[0] {
+            check = check.register(
+                "SparseCsr",
+                TensorError::new(
+                    "The column indices and the values should have the same length.".to_string(),
+                )
+                .details(format!(
+                    "Column indices shape {:?}, values shape {:?}.",
+                    shape_col_indices.dims, shape_values.dims
+                )),
+            );
+        }
+
+        if shape.num_elements() == 0 {
+            check = check.register(
+                "SparseCsr",
+                TensorError::new("The sparse matrix should have at least one element.".to_string())
+                    .details(format!("Shape {:?}.", shape.dims)),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn spmm(shape_lhs: &Shape<2>, shape_rhs: &Shape<2>) -> Self {
+        let mut check = Self::Ok;
+
+        if shape_lhs.dims[1] != shape_rhs.dims[0] {
+            check = check.register(
+                "Spmm",
+                TensorError::new(format!(
+                    "The inner dimension of matmul should be the same, but got {} and {}.",
+                    shape_lhs.dims[1], shape_rhs.dims[0]
+                ))
+                .details(format!(
+                    "Lhs shape {:?}, rhs shape {:?}.",
+                    shape_lhs.dims, shape_rhs.dims
+                )),
+            );
+        }
+
+        check
+    }
+
     pub(crate) fn stack<B: Backend, const D: usize, K: BasicOps<B>>(
         tensors: &[Tensor<B, D, K>],
         dim: usize,
@@ -597,9 +1008,65 @@ impl TensorCheck {
         shape_indices: &Shape<D>,
         shape_value: &Shape<D>,
     ) -> Self {
-        let ops = "Scatter";
-        let mut check =
-            Self::check_gather_scatter_indices(Self::Ok, ops, dim, shape, shape_indices);
+        Self::check_scatter(Self::Ok, "Scatter", dim, shape, shape_indices, shape_value)
+    }
+
+    pub(crate) fn scatter_max<const D: usize>(
+        dim: usize,
+        shape: &Shape<D>,
+        shape_indices: &Shape<D>,
+        shape_value: &Shape<D>,
+    ) -> Self {
+        Self::check_scatter(
+            Self::Ok,
+            "ScatterMax",
+            dim,
+            shape,
+            shape_indices,
+            shape_value,
+        )
+    }
+
+    pub(crate) fn scatter_min<const D: usize>(
+        dim: usize,
+        shape: &Shape<D>,
+        shape_indices: &Shape<D>,
+        shape_value: &Shape<D>,
+    ) -> Self {
+        Self::check_scatter(
+            Self::Ok,
+            "ScatterMin",
+            dim,
+            shape,
+            shape_indices,
+            shape_value,
+        )
+    }
+
+    pub(crate) fn scatter_index(ops: &str, dim: usize, index: i64, size: usize) -> Self {
+        let mut check = Self::Ok;
+
+        if index < 0 || index as usize >= size {
+            check = check.register(
+                ops,
+                TensorError::new(format!(
+                    "Can't scatter at index ({index}) of dimension ({dim}) with a size of ({size})."
+                )),
+            );
+        }
+
+        check
+    }
+
+    fn check_scatter<const D: usize>(
+        check: Self,
+        ops: &str,
+        dim: usize,
+        shape: &Shape<D>,
+        shape_indices: &Shape<D>,
+        shape_value: &Shape<D>,
+    ) -> Self {
+        let mut check = Self::check_gather_scatter_indices(check, ops, dim, shape, shape_indices);
 
         if shape_indices != shape_value {
             check = check.register(
@@ -618,6 +1085,41 @@ impl TensorCheck {
         check
     }
 
+    pub(crate) fn index_put<const D: usize>(
+        shape_indices: &Shape<2>,
+        shape_values: &Shape<1>,
+    ) -> Self {
+        let ops = "IndexPut";
+        let mut check = Self::Ok;
+
+        if shape_indices.dims[1] != D {
+            check = check.register(
+                ops,
+                TensorError::new(
+                    "Each row of the indices tensor should have a coordinate per dimension."
+                        .to_string(),
+                )
+                .details(format!(
+                    "Tensor rank: '{D}', indices shape: {:?}.",
+                    shape_indices.dims
+                )),
+            );
+        }
+
+        if shape_indices.dims[0] != shape_values.dims[0] {
+            check = check.register(
+                ops,
+                TensorError::new("The indices tensor should have a row per value.".to_string())
+                    .details(format!(
+                        "Indices shape: {:?}, values shape: {:?}.",
+                        shape_indices.dims, shape_values.dims
+                    )),
+            );
+        }
+
+        check
+    }
+
     pub(crate) fn select<const D: usize>(dim: usize) -> Self {
         Self::check_select_basic::<D>(Self::Ok, "select", dim)
     }
@@ -897,4 +1399,27 @@ mod tests {
             &8
         ));
     }
+
+    #[test]
+    #[should_panic]
+    fn scatter_max_indices_values_shapes() {
+        check!(TensorCheck::scatter_max(
+            0,
+            &Shape::new([3, 2]),
+            &Shape::new([4, 2]),
+            &Shape::new([4, 1])
+        ));
+    }
+
+    #[test]
+    #[should_panic]
+    fn scatter_index_out_of_range() {
+        check!(TensorCheck::scatter_index("ScatterMin", 1, 3, 3));
+    }
+
+    #[test]
+    #[should_panic]
+    fn scatter_index_negative() {
+        check!(TensorCheck::scatter_index("ScatterMax", 0, -1, 3));
+    }
 }
@@ -0,0 +1,248 @@
+use alloc::vec::Vec;
+use core::ops::Range;
+
+use crate::{
+    backend::{Backend, ComplexBackend},
+    check,
+    check::TensorCheck,
+    ops::{ComplexTensor, FloatTensor},
+    Arithmetic, BasicOps, Bool, Complex, Data, ElementConversion, Float, Shape, Tensor, TensorKind,
+};
+use burn_common::reader::Reader;
+
+impl<B, const D: usize> Tensor<B, D, Complex>
+where
+    B: ComplexBackend,
+{
+    /// Creates a complex tensor from its real and imaginary parts.
+    ///
+    /// # Panics
+    ///
+    /// If the real and imaginary parts don't have the same shape.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::ComplexBackend;
+    /// use burn_tensor::{Complex, Tensor};
+    ///
+    /// fn example<B: ComplexBackend>() {
+    ///     let device = Default::default();
+    ///     let real = Tensor::<B, 1>::from_floats([3.0, 0.0], &device);
+    ///     let imag = Tensor::<B, 1>::from_floats([4.0, -1.0], &device);
+    ///
+    ///     let tensor = Tensor::<B, 1, Complex>::from_parts(real, imag);
+    ///     println!("{}", tensor.magnitude());
+    ///     // [5.0, 1.0]
+    /// }
+    /// ```
+    pub fn from_parts(real: Tensor<B, D>, imag: Tensor<B, D>) -> Self {
+        check!(TensorCheck::complex_parts::<D>(
+            "FromParts",
+            &real.shape(),
+            &imag.shape()
+        ));
+
+        Self::new(B::complex_from_parts(real.primitive, imag.primitive))
+    }
+
+    /// Returns the real part of each element as a float tensor.
+    pub fn real(self) -> Tensor<B, D> {
+        Tensor::new(B::complex_real(self.primitive))
+    }
+
+    /// Returns the imaginary part of each element as a float tensor.
+    pub fn imag(self) -> Tensor<B, D> {
+        Tensor::new(B::complex_imag(self.primitive))
+    }
+
+    /// Returns the complex conjugate of each element.
+    ///
+    /// `y = re(x) - i * im(x)`
+    pub fn conj(self) -> Self {
+        Self::new(B::complex_conj(self.primitive))
+    }
+
+    /// Returns the magnitude of each element as a float tensor, the complex counterpart of
+    /// [abs](Tensor::abs).
+    ///
+    /// `y = sqrt(re(x)^2 + im(x)^2)`
+    pub fn magnitude(self) -> Tensor<B, D> {
+        Tensor::new(B::complex_abs(self.primitive))
+    }
+
+    /// Returns the angle of each element as a float tensor, in radians between `-pi` and `pi`.
+    ///
+    /// `y = atan2(im(x), re(x))`
+    pub fn angle(self) -> Tensor<B, D> {
+        Tensor::new(B::complex_angle(self.primitive))
+    }
+}
+
+impl<B, const D: usize> Tensor<B, D, Float>
+where
+    B: ComplexBackend,
+{
+    /// Converts the float tensor to a complex tensor with a zero imaginary part.
+    pub fn complex(self) -> Tensor<B, D, Complex> {
+        let imag = Tensor::zeros_like(&self);
+
+        Tensor::from_parts(self, imag)
+    }
+}
+
+// The layout operations are applied on the real and imaginary parts, so that backends only have
+// to provide the arithmetic of complex tensors.
+
+impl<B: ComplexBackend> BasicOps<B> for Complex {
+    type Elem = B::ComplexElem;
+
+    fn empty<const D: usize>(shape: Shape<D>, device: &B::Device) -> Self::Primitive<D> {
+        B::complex_from_parts(B::empty(shape.clone(), device), B::empty(shape, device))
+    }
+
+    fn shape<const D: usize>(tensor: &Self::Primitive<D>) -> Shape<D> {
+        B::complex_shape(tensor)
+    }
+
+    fn reshape<const D1: usize, const D2: usize>(
+        tensor: Self::Primitive<D1>,
+        shape: Shape<D2>,
+    ) -> Self::Primitive<D2> {
+        B::complex_reshape(tensor, shape)
+    }
+
+    fn transpose<const D: usize>(tensor: Self::Primitive<D>) -> Self::Primitive<D> {
+        map_parts::<B, D, _>(tensor, B::transpose)
+    }
+
+    fn swap_dims<const D: usize>(
+        tensor: Self::Primitive<D>,
+        dim1: usize,
+        dim2: usize,
+    ) -> Self::Primitive<D> {
+        check!(TensorCheck::swap_dims::<D>(dim1, dim2));
+        map_parts::<B, D, _>(tensor, |part| B::swap_dims(part, dim1, dim2))
+    }
+
+    fn slice<const D1: usize, const D2: usize>(
+        tensor: Self::Primitive<D1>,
+        ranges: [Range<usize>; D2],
+    ) -> Self::Primitive<D1> {
+        map_parts::<B, D1, _>(tensor, |part| B::slice(part, ranges.clone()))
+    }
+
+    fn slice_assign<const D1: usize, const D2: usize>(
+        tensor: Self::Primitive<D1>,
+        ranges: [Range<usize>; D2],
+        value: Self::Primitive<D1>,
+    ) -> Self::Primitive<D1> {
+        let (real, imag) = parts::<B, D1>(tensor);
+        let (value_real, value_imag) = parts::<B, D1>(value);
+
+        B::complex_from_parts(
+            B::slice_assign(real, ranges.clone(), value_real),
+            B::slice_assign(imag, ranges, value_imag),
+        )
+    }
+
+    fn device<const D: usize>(tensor: &Self::Primitive<D>) -> <B as Backend>::Device {
+        B::complex_device(tensor)
+    }
+
+    fn to_device<const D: usize>(
+        tensor: Self::Primitive<D>,
+        device: &<B as Backend>::Device,
+    ) -> Self::Primitive<D> {
+        map_parts::<B, D, _>(tensor, |part| B::to_device(part, device))
+    }
+
+    fn into_data<const D: usize>(tensor: Self::Primitive<D>) -> Reader<Data<Self::Elem, D>> {
+        B::complex_into_data(tensor)
+    }
+
+    fn from_data<const D: usize>(
+        data: Data<Self::Elem, D>,
+        device: &B::Device,
+    ) -> Self::Primitive<D> {
+        B::complex_from_data(data, device)
+    }
+
+    fn repeat<const D: usize>(
+        tensor: Self::Primitive<D>,
+        dim: usize,
+        times: usize,
+    ) -> Self::Primitive<D> {
+        map_parts::<B, D, _>(tensor, |part| B::repeat(part, dim, times))
+    }
+
+    fn equal<const D: usize>(
+        lhs: Self::Primitive<D>,
+        rhs: Self::Primitive<D>,
+    ) -> Tensor<B, D, Bool> {
+        let (lhs_real, lhs_imag) = parts::<B, D>(lhs);
+        let (rhs_real, rhs_imag) = parts::<B, D>(rhs);
+
+        // Both parts are equal when the sum of their comparisons is two.
+        let count = B::int_add(
+            B::bool_into_int(B::equal(lhs_real, rhs_real)),
+            B::bool_into_int(B::equal(lhs_imag, rhs_imag)),
+        );
+
+        Tensor::new(B::int_equal_elem(count, 2i32.elem()))
+    }
+
+    fn cat<const D: usize>(vectors: Vec<Self::Primitive<D>>, dim: usize) -> Self::Primitive<D> {
+        let (real, imag): (Vec<_>, Vec<_>) = vectors.into_iter().map(parts::<B, D>).unzip();
+
+        B::complex_from_parts(B::cat(real, dim), B::cat(imag, dim))
+    }
+}
+
+impl<B: ComplexBackend> Arithmetic<B> for Complex {
+    fn add<const D: usize>(
+        lhs: Self::Primitive<D>,
+        rhs: Self::Primitive<D>,
+    ) -> <Complex as TensorKind<B>>::Primitive<D> {
+        B::complex_add(lhs, rhs)
+    }
+    fn sub<const D: usize>(
+        lhs: Self::Primitive<D>,
+        rhs: Self::Primitive<D>,
+    ) -> <Complex as TensorKind<B>>::Primitive<D> {
+        B::complex_sub(lhs, rhs)
+    }
+    fn div<const D: usize>(
+        lhs: Self::Primitive<D>,
+        rhs: Self::Primitive<D>,
+    ) -> <Complex as TensorKind<B>>::Primitive<D> {
+        B::complex_div(lhs, rhs)
+    }
+    fn mul<const D: usize>(
+        lhs: Self::Primitive<D>,
+        rhs: Self::Primitive<D>,
+    ) -> <Complex as TensorKind<B>>::Primitive<D> {
+        B::complex_mul(lhs, rhs)
+    }
+    fn neg<const D: usize>(tensor: Self::Primitive<D>) -> Self::Primitive<D> {
+        B::complex_neg(tensor)
+    }
+}
+
+fn parts<B: ComplexBackend, const D: usize>(
+    tensor: ComplexTensor<B, D>,
+) -> (FloatTensor<B, D>, FloatTensor<B, D>) {
+    (B::complex_real(tensor.clone()), B::complex_imag(tensor))
+}
+
+fn map_parts<B: ComplexBackend, const D: usize, F>(
+    tensor: ComplexTensor<B, D>,
+    func: F,
+) -> ComplexTensor<B, D>
+where
+    F: Fn(FloatTensor<B, D>) -> FloatTensor<B, D>,
+{
+    let (real, imag) = parts::<B, D>(tensor);
+
+    B::complex_from_parts(func(real), func(imag))
+}
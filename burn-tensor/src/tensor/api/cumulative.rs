@@ -0,0 +1,94 @@
+use super::narrow::narrow;
+use crate::{backend::Backend, Element, Numeric, Shape, TensorKind};
+use alloc::vec;
+
+/// Computes the cumulative sum of the elements along the given dimension.
+///
+/// # Arguments
+///
+/// * `tensor` - The tensor.
+/// * `dim` - The dimension along which the elements are summed.
+///
+/// # Returns
+///
+/// A tensor of the same shape, where each element is the sum of the previous elements along
+/// `dim`, itself included.
+///
+/// # Remarks
+///
+/// Default implementation of the float and int `cumsum` ops. The sum is computed on the device
+/// with `log2(n)` additions of shifted copies of the tensor, which allocates a new tensor at each
+/// step, so backends with a native cumulative sum should use it instead.
+pub fn cumsum<B: Backend, const D: usize, K: TensorKind<B> + Numeric<B>>(
+    tensor: K::Primitive<D>,
+    dim: usize,
+) -> K::Primitive<D>
+where
+    K::Elem: Element,
+{
+    scan::<B, D, K>(tensor, dim, K::zeros, K::add)
+}
+
+/// Computes the cumulative product of the elements along the given dimension.
+///
+/// # Arguments
+///
+/// * `tensor` - The tensor.
+/// * `dim` - The dimension along which the elements are multiplied.
+///
+/// # Returns
+///
+/// A tensor of the same shape, where each element is the product of the previous elements along
+/// `dim`, itself included.
+///
+/// # Remarks
+///
+/// Default implementation of the float and int `cumprod` ops. Like [cumsum], it's computed on the
+/// device with `log2(n)` multiplications of shifted copies padded with ones. The factors are
+/// grouped differently than in a sequential product, so float results can differ in the last bits.
+pub fn cumprod<B: Backend, const D: usize, K: TensorKind<B> + Numeric<B>>(
+    tensor: K::Primitive<D>,
+    dim: usize,
+) -> K::Primitive<D>
+where
+    K::Elem: Element,
+{
+    scan::<B, D, K>(tensor, dim, K::ones, K::mul)
+}
+
+/// Inclusive scan of the elements along a dimension with the Hillis-Steele algorithm: each step
+/// combines every element with the one `offset` positions before it, doubling the offset, so only
+/// `log2(n)` tensor operations are needed for a dimension of size `n`.
+fn scan<B: Backend, const D: usize, K: TensorKind<B> + Numeric<B>>(
+    tensor: K::Primitive<D>,
+    dim: usize,
+    identity: fn(Shape<D>, &B::Device) -> K::Primitive<D>,
+    combine: fn(K::Primitive<D>, K::Primitive<D>) -> K::Primitive<D>,
+) -> K::Primitive<D>
+where
+    K::Elem: Element,
+{
+    let shape = K::shape(&tensor);
+    let device = K::device(&tensor);
+    let size = shape.dims[dim];
+
+    let mut output = tensor;
+    let mut offset = 1;
+
+    while offset < size {
+        let mut shape_identity = shape.clone();
+        shape_identity.dims[dim] = offset;
+
+        let shifted = K::cat(
+            vec![
+                identity(shape_identity, &device),
+                narrow::<B, D, K>(output.clone(), dim, 0, size - offset),
+            ],
+            dim,
+        );
+        output = combine(output, shifted);
+        offset *= 2;
+    }
+
+    output
+}
@@ -0,0 +1,298 @@
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::check;
+use crate::check::TensorCheck;
+use crate::tensor::backend::Backend;
+use crate::Tensor;
+
+/// The labels of the operands and of the output of an einsum equation with two operands.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct EinsumEquation {
+    pub(crate) lhs: Vec<char>,
+    pub(crate) rhs: Vec<char>,
+    pub(crate) output: Vec<char>,
+}
+
+impl EinsumEquation {
+    /// Parse an equation such as `bij,bjk->bik`.
+    ///
+    /// Without `->`, the output has the labels appearing in a single operand, in alphabetical
+    /// order, like numpy.
+    pub(crate) fn parse(equation: &str) -> Result<Self, String> {
+        let equation = equation
+            .chars()
+            .filter(|c| !c.is_whitespace())
+            .collect::<String>();
+
+        let (inputs, output) = match equation.split_once("->") {
+            Some((inputs, output)) => (inputs, Some(output)),
+            None => (equation.as_str(), None),
+        };
+
+        let operands = inputs.split(',').collect::<Vec<_>>();
+        if operands.len() != 2 {
+            return Err(format!(
+                "Only equations with two operands are supported, got {}",
+                operands.len()
+            ));
+        }
+
+        let lhs = parse_labels(operands[0])?;
+        let rhs = parse_labels(operands[1])?;
+
+        let output = match output {
+            Some(output) => {
+                let output = parse_labels(output)?;
+
+                if let Some(label) = output
+                    .iter()
+                    .find(|label| !lhs.contains(label) && !rhs.contains(label))
+                {
+                    return Err(format!(
+                        "The output label '{label}' isn't a label of the operands"
+                    ));
+                }
+
+                output
+            }
+            None => {
+                let mut output = lhs
+                    .iter()
+                    .chain(rhs.iter())
+                    .filter(|label| lhs.contains(label) != rhs.contains(label))
+                    .copied()
+                    .collect::<Vec<_>>();
+                output.sort();
+                output
+            }
+        };
+
+        Ok(Self { lhs, rhs, output })
+    }
+}
+
+fn parse_labels(labels: &str) -> Result<Vec<char>, String> {
+    let mut parsed = Vec::with_capacity(labels.len());
+
+    for label in labels.chars() {
+        if !label.is_ascii_alphabetic() {
+            return Err(format!(
+                "Labels should be ascii letters, got '{label}' in '{labels}'"
+            ));
+        }
+
+        if parsed.contains(&label) {
+            return Err(format!(
+                "Repeated labels in an operand aren't supported, got '{labels}'"
+            ));
+        }
+
+        parsed.push(label);
+    }
+
+    Ok(parsed)
+}
+
+impl<const D: usize, B> Tensor<B, D>
+where
+    B: Backend,
+{
+    /// Evaluates the Einstein summation of two tensors, described by an equation such as
+    /// `bij,bjk->bik`.
+    ///
+    /// Each letter labels a dimension of an operand. The labels of the output after `->` are kept,
+    /// in the given order, and the other ones are summed over. Without `->`, the output has the
+    /// labels appearing in a single operand, in alphabetical order.
+    ///
+    /// The summation is decomposed into a single batched [matmul](Tensor::matmul) with the
+    /// permutations and reshapes needed around it, so common contractions such as batched matrix
+    /// multiplications use the matmul kernels of the backend directly.
+    ///
+    /// # Panics
+    ///
+    /// - If the equation doesn't have exactly two operands.
+    /// - If a label is repeated in an operand, such as the diagonal `ii`.
+    /// - If the number of labels of an operand or of the output doesn't match its rank. An
+    ///   output without label, such as a dot product, has the shape `[1]`.
+    /// - If the dimensions with the same label don't have the same size.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = Default::default();
+    ///     let queries = Tensor::<B, 3>::ones([2, 4, 8], &device);
+    ///     let keys = Tensor::<B, 3>::ones([2, 6, 8], &device);
+    ///
+    ///     let scores: Tensor<B, 3> = Tensor::einsum("bqd,bkd->bqk", queries, keys);
+    ///     assert_eq!(scores.dims(), [2, 4, 6]);
+    /// }
+    /// ```
+    pub fn einsum<const D1: usize, const D2: usize>(
+        equation: &str,
+        lhs: Tensor<B, D1>,
+        rhs: Tensor<B, D2>,
+    ) -> Self {
+        check!(TensorCheck::einsum::<D1, D2, D>(
+            equation,
+            &lhs.shape(),
+            &rhs.shape()
+        ));
+        let equation = EinsumEquation::parse(equation).unwrap();
+        let EinsumEquation {
+            lhs: lhs_labels,
+            rhs: rhs_labels,
+            output,
+        } = equation;
+
+        // Labels of a single operand that aren't in the output are summed right away.
+        let mut lhs = lhs;
+        for (dim, label) in lhs_labels.iter().enumerate() {
+            if !rhs_labels.contains(label) && !output.contains(label) {
+                lhs = lhs.sum_dim(dim);
+            }
+        }
+        let mut rhs = rhs;
+        for (dim, label) in rhs_labels.iter().enumerate() {
+            if !lhs_labels.contains(label) && !output.contains(label) {
+                rhs = rhs.sum_dim(dim);
+            }
+        }
+
+        let in_lhs = |label: &&char| lhs_labels.contains(label);
+        let in_rhs = |label: &&char| rhs_labels.contains(label);
+        let in_output = |label: &&char| output.contains(label);
+
+        let batch = output
+            .iter()
+            .filter(|label| in_lhs(label) && in_rhs(label))
+            .copied()
+            .collect::<Vec<_>>();
+        let lhs_free = output
+            .iter()
+            .filter(|label| in_lhs(label) && !in_rhs(label))
+            .copied()
+            .collect::<Vec<_>>();
+        let rhs_free = output
+            .iter()
+            .filter(|label| in_rhs(label) && !in_lhs(label))
+            .copied()
+            .collect::<Vec<_>>();
+        let contracted = lhs_labels
+            .iter()
+            .filter(|label| in_rhs(label) && !in_output(label))
+            .copied()
+            .collect::<Vec<_>>();
+        let lhs_summed = lhs_labels
+            .iter()
+            .filter(|label| !in_rhs(label) && !in_output(label))
+            .copied()
+            .collect::<Vec<_>>();
+        let rhs_summed = rhs_labels
+            .iter()
+            .filter(|label| !in_lhs(label) && !in_output(label))
+            .copied()
+            .collect::<Vec<_>>();
+
+        let lhs_dims = lhs.dims();
+        let rhs_dims = rhs.dims();
+        let size = |label: &char| match lhs_labels.iter().position(|l| l == label) {
+            Some(dim) => lhs_dims[dim],
+            None => rhs_dims[rhs_labels.iter().position(|l| l == label).unwrap()],
+        };
+        let product = |labels: &[char]| labels.iter().map(size).product::<usize>();
+
+        let lhs_order = [
+            batch.as_slice(),
+            lhs_free.as_slice(),
+            lhs_summed.as_slice(),
+            contracted.as_slice(),
+        ]
+        .concat();
+        let rhs_order = [
+            batch.as_slice(),
+            contracted.as_slice(),
+            rhs_summed.as_slice(),
+            rhs_free.as_slice(),
+        ]
+        .concat();
+
+        let lhs = permute(lhs, &lhs_labels, &lhs_order).reshape([
+            product(&batch),
+            product(&lhs_free),
+            product(&contracted),
+        ]);
+        let rhs = permute(rhs, &rhs_labels, &rhs_order).reshape([
+            product(&batch),
+            product(&contracted),
+            product(&rhs_free),
+        ]);
+
+        let result_labels = [batch.as_slice(), lhs_free.as_slice(), rhs_free.as_slice()].concat();
+        let mut result_shape = [1; D];
+        result_labels
+            .iter()
+            .enumerate()
+            .for_each(|(dim, label)| result_shape[dim] = size(label));
+
+        let result = lhs.matmul(rhs).reshape(result_shape);
+
+        permute(result, &result_labels, &output)
+    }
+}
+
+/// Reorder the dimensions of the tensor, labeled by `labels`, following the `order` of the labels.
+fn permute<B: Backend, const D: usize>(
+    tensor: Tensor<B, D>,
+    labels: &[char],
+    order: &[char],
+) -> Tensor<B, D> {
+    let mut tensor = tensor;
+    let mut current = labels.to_vec();
+
+    for (dim, label) in order.iter().enumerate() {
+        let position = current.iter().position(|l| l == label).unwrap();
+
+        if position != dim {
+            tensor = tensor.swap_dims(dim, position);
+            current.swap(dim, position);
+        }
+    }
+
+    tensor
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    #[test]
+    fn should_parse_explicit_output() {
+        let equation = EinsumEquation::parse("bij, bjk -> bik").unwrap();
+
+        assert_eq!(equation.lhs, vec!['b', 'i', 'j']);
+        assert_eq!(equation.rhs, vec!['b', 'j', 'k']);
+        assert_eq!(equation.output, vec!['b', 'i', 'k']);
+    }
+
+    #[test]
+    fn should_parse_implicit_output_in_alphabetical_order() {
+        let equation = EinsumEquation::parse("kj,ji").unwrap();
+
+        assert_eq!(equation.output, vec!['i', 'k']);
+    }
+
+    #[test]
+    fn should_reject_invalid_equations() {
+        assert!(EinsumEquation::parse("ij->ji").is_err());
+        assert!(EinsumEquation::parse("ii,ij->j").is_err());
+        assert!(EinsumEquation::parse("ij,jk->il").is_err());
+        assert!(EinsumEquation::parse("i1,1k->ik").is_err());
+    }
+}
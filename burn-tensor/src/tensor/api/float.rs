@@ -6,8 +6,8 @@ use crate::check::TensorCheck;
 use crate::tensor::backend::Backend;
 use crate::tensor::stats;
 use crate::tensor::{Data, Distribution, Shape};
-use crate::Int;
 use crate::Tensor;
+use crate::{ElementConversion, Int};
 
 impl<const D: usize, B> Tensor<B, D>
 where
@@ -209,6 +209,76 @@ where
         (var, mean)
     }
 
+    /// Computes the histogram of the elements, with `bins` bins of equal width between `min` and
+    /// `max`.
+    ///
+    /// The elements outside of the range are ignored, and the elements equal to `max` are counted
+    /// in the last bin. The counts are computed on the device, without reading the tensor back to
+    /// the host.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = B::Device::default();
+    ///     let tensor = Tensor::<B, 1>::from_floats([0.5, 1.0, 1.5, 2.5, 4.0, -1.0], &device);
+    ///     let histogram = tensor.histc(4, 0.0, 4.0);
+    ///     println!("{}", histogram);
+    ///     // [1.0, 2.0, 1.0, 1.0]
+    /// }
+    /// ```
+    pub fn histc<E: ElementConversion>(self, bins: usize, min: E, max: E) -> Tensor<B, 1> {
+        let (min, max) = (min.elem::<f64>(), max.elem::<f64>());
+        check!(TensorCheck::histc(bins, min, max));
+
+        stats::histc(self, bins, min, max)
+    }
+
+    /// Computes the `q`-th quantile of the elements along the given dimension, interpolating
+    /// linearly between the two nearest elements when the quantile falls between them.
+    ///
+    /// # Arguments
+    ///
+    /// * `q` - The quantile, between 0 and 1.
+    /// * `dim` - The dimension to reduce.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the quantile, where the size of `dim` is 1.
+    pub fn quantile(self, q: f64, dim: usize) -> Self {
+        check!(TensorCheck::quantile::<D>(
+            "Quantile",
+            dim,
+            &self.shape(),
+            q
+        ));
+
+        stats::quantile(self, q, dim)
+    }
+
+    /// Computes the median of the elements along the given dimension.
+    ///
+    /// For an even number of elements, the lower of the two middle elements is returned, so the
+    /// median is always an element of the tensor. Use [quantile](Tensor::quantile) with `0.5` to
+    /// get their mean instead.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the median, where the size of `dim` is 1.
+    pub fn median(self, dim: usize) -> Self {
+        check!(TensorCheck::quantile::<D>(
+            "Median",
+            dim,
+            &self.shape(),
+            0.5
+        ));
+
+        stats::median(self, dim)
+    }
+
     /// Create a random tensor of the given shape on the given device where each element is
     /// sampled from the given distribution.
     pub fn random<S: Into<Shape<D>>>(
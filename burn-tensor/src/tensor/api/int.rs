@@ -24,6 +24,30 @@ where
     pub fn arange_step(range: Range<usize>, step: usize, device: &B::Device) -> Self {
         Tensor::new(B::arange_step(range, step, device))
     }
+
+    /// Counts the number of occurrences of each value of a tensor of non-negative integers.
+    ///
+    /// The output has a length of `max(tensor.max() + 1, min_length)`, which depends on the values
+    /// of the tensor, so the maximum is read back to the host on most backends.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Int, Tensor};
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = Default::default();
+    ///     let tensor = Tensor::<B, 1, Int>::from_ints([1, 3, 1, 0], &device);
+    ///
+    ///     let counts = tensor.bincount(0);
+    ///     println!("{}", counts);
+    ///     // [1, 2, 0, 1]
+    /// }
+    /// ```
+    pub fn bincount(self, min_length: usize) -> Self {
+        Tensor::new(B::int_bincount(self.primitive, min_length))
+    }
 }
 
 impl<const D: usize, B> Tensor<B, D, Int>
@@ -66,4 +90,46 @@ where
     pub fn float(self) -> Tensor<B, D, Float> {
         Tensor::new(B::int_into_float(self.primitive))
     }
+
+    /// Returns the unique elements of the tensor in ascending order, as a 1-D tensor.
+    ///
+    /// The number of unique elements depends on the values of the tensor, so it is read back to
+    /// the host on most backends.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Int, Tensor};
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = Default::default();
+    ///     let tensor = Tensor::<B, 2, Int>::from_ints([[3, 1], [1, 2]], &device);
+    ///
+    ///     let (values, counts) = tensor.unique_with_counts();
+    ///     println!("{} {}", values, counts);
+    ///     // [1, 2, 3] [2, 1, 1]
+    /// }
+    /// ```
+    pub fn unique(self) -> Tensor<B, 1, Int> {
+        Tensor::new(B::int_unique(self.primitive).0)
+    }
+
+    /// Returns the unique elements of the tensor in ascending order, with the number of
+    /// occurrences of each of them.
+    pub fn unique_with_counts(self) -> (Tensor<B, 1, Int>, Tensor<B, 1, Int>) {
+        let (values, _, counts) = B::int_unique(self.primitive);
+
+        (Tensor::new(values), Tensor::new(counts))
+    }
+
+    /// Returns the unique elements of the tensor in ascending order, with the index of each
+    /// element of the tensor in the unique elements.
+    ///
+    /// The unique elements selected by the inverse indices give back the tensor.
+    pub fn unique_with_inverse(self) -> (Tensor<B, 1, Int>, Tensor<B, D, Int>) {
+        let (values, inverse, _) = B::int_unique(self.primitive);
+
+        (Tensor::new(values), Tensor::new(inverse))
+    }
 }
@@ -1,4 +1,4 @@
-use crate::backend::Backend;
+use crate::backend::{Backend, ComplexBackend};
 
 /// A type-level representation of the kind of a float tensor
 #[derive(Clone, Debug)]
@@ -12,6 +12,11 @@ pub struct Int;
 #[derive(Clone, Debug)]
 pub struct Bool;
 
+/// A type-level representation of the kind of a complex tensor, only available on backends
+/// implementing [ComplexBackend].
+#[derive(Clone, Debug)]
+pub struct Complex;
+
 /// A type-level representation of the kind of a tensor.
 pub trait TensorKind<B: Backend>: Clone + core::fmt::Debug {
     /// The primitive type of the tensor.
@@ -41,3 +46,10 @@ impl<B: Backend> TensorKind<B> for Bool {
         "Bool"
     }
 }
+
+impl<B: ComplexBackend> TensorKind<B> for Complex {
+    type Primitive<const D: usize> = B::ComplexTensorPrimitive<D>;
+    fn name() -> &'static str {
+        "Complex"
+    }
+}
@@ -4,15 +4,31 @@ mod autodiff;
 mod base;
 mod bool;
 mod chunk;
+mod complex;
+mod cumulative;
+mod einsum;
 mod float;
 mod int;
 mod kind;
 mod narrow;
+mod nonzero;
 mod numeric;
+mod pad;
+mod scatter;
+mod search;
+mod sort;
+mod unique;
 
 pub use autodiff::*;
 pub use base::*;
 pub use chunk::chunk;
+pub use cumulative::{cumprod, cumsum};
 pub use kind::*;
 pub use narrow::narrow;
+pub use nonzero::{masked_select, nonzero};
 pub use numeric::*;
+pub use pad::{pad, PadMode};
+pub use scatter::{scatter_max, scatter_min};
+pub use search::searchsorted;
+pub use sort::sort_with_indices;
+pub use unique::{bincount, unique};
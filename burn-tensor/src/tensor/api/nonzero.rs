@@ -0,0 +1,96 @@
+use crate::{
+    backend::Backend,
+    ops::{BoolTensor, IntTensor},
+    Data, Element, ElementConversion, Int, Numeric, Shape, Tensor, TensorKind,
+};
+use alloc::vec::Vec;
+
+/// Gets the indices of the `true` elements of the tensor, in row-major order.
+///
+/// # Arguments
+///
+/// * `tensor` - The boolean tensor.
+///
+/// # Returns
+///
+/// A vector with a 1-D tensor of indices for each dimension, all with the length of the number of
+/// `true` elements.
+///
+/// # Remarks
+///
+/// The length of the output depends on the values of the tensor, so the tensor is read back to the
+/// host, which waits for every pending operation on lazy backends. Backends with a native
+/// implementation should override the default implementation calling this function.
+pub fn nonzero<B: Backend, const D: usize>(tensor: BoolTensor<B, D>) -> Vec<IntTensor<B, 1>> {
+    let device = B::bool_device(&tensor);
+    let data = B::bool_into_data(tensor)
+        .read_sync()
+        .expect("Can't get the nonzero indices of a tensor that can only be read asynchronously");
+    let shape = data.shape;
+
+    let positions = data
+        .value
+        .iter()
+        .enumerate()
+        .filter(|(_, value)| **value)
+        .map(|(position, _)| position)
+        .collect::<Vec<_>>();
+    let num_elements = positions.len();
+
+    let mut stride = shape.num_elements();
+
+    (0..D)
+        .map(|dim| {
+            let size = shape.dims[dim];
+            stride /= usize::max(size, 1);
+
+            let indices = positions
+                .iter()
+                .map(|position| ((position / stride % size) as i64).elem())
+                .collect();
+
+            B::int_from_data(Data::new(indices, Shape::new([num_elements])), &device)
+        })
+        .collect()
+}
+
+/// Gets the elements of the tensor where the mask is `true`, in row-major order.
+///
+/// # Arguments
+///
+/// * `tensor` - The tensor.
+/// * `mask` - The boolean mask, with the same shape as the tensor.
+///
+/// # Returns
+///
+/// A 1-D tensor with the selected elements.
+///
+/// # Remarks
+///
+/// The positions of the selected elements are computed with
+/// [bool_nonzero](crate::ops::BoolTensorOps::bool_nonzero), then the elements are gathered from the
+/// flattened tensor, so the default implementations calling this function are differentiable.
+pub fn masked_select<B: Backend, const D: usize, K: TensorKind<B> + Numeric<B>>(
+    tensor: K::Primitive<D>,
+    mask: BoolTensor<B, D>,
+) -> K::Primitive<1>
+where
+    K::Elem: Element,
+{
+    let shape = K::shape(&tensor);
+    let mut stride = shape.num_elements();
+
+    let positions = B::bool_nonzero(mask)
+        .into_iter()
+        .enumerate()
+        .map(|(dim, indices)| {
+            stride /= usize::max(shape.dims[dim], 1);
+            Tensor::<B, 1, Int>::from_primitive(indices).mul_scalar(stride as i64)
+        })
+        .reduce(|lhs, rhs| lhs + rhs)
+        .expect("The tensor should have at least one dimension");
+
+    let tensor = K::reshape(tensor, Shape::new([shape.num_elements()]));
+
+    K::select(tensor, 0, positions)
+}
@@ -1,8 +1,61 @@
+use alloc::vec::Vec;
+
 use crate::{
-    backend::Backend, check, check::TensorCheck, BasicOps, Bool, Element, ElementConversion, Float,
-    Int, Shape, Tensor, TensorKind,
+    backend::Backend, check, check::TensorCheck, BasicOps, Bool, Data, Element, ElementConversion,
+    Float, Int, PadMode, Shape, Tensor, TensorKind,
 };
 
+impl<B, const D: usize, K> Tensor<B, D, K>
+where
+    B: Backend,
+    K: Arithmetic<B>,
+    K::Elem: Element,
+{
+    /// Applies element wise addition operation.
+    ///
+    /// `y = x2 + x1`
+    #[allow(clippy::should_implement_trait)]
+    pub fn add(self, other: Self) -> Self {
+        check!(TensorCheck::binary_ops_ew("Add", &self, &other));
+        Self::new(K::add(self.primitive, other.primitive))
+    }
+
+    /// Applies element wise subtraction operation.
+    ///
+    /// `y = x2 - x1`
+    #[allow(clippy::should_implement_trait)]
+    pub fn sub(self, other: Self) -> Self {
+        check!(TensorCheck::binary_ops_ew("Sub", &self, &other));
+        Self::new(K::sub(self.primitive, other.primitive))
+    }
+
+    /// Applies element wise division operation.
+    ///
+    /// `y = x2 / x1`
+    #[allow(clippy::should_implement_trait)]
+    pub fn div(self, other: Self) -> Self {
+        check!(TensorCheck::binary_ops_ew("Div", &self, &other));
+        Self::new(K::div(self.primitive, other.primitive))
+    }
+
+    /// Applies element wise multiplication operation.
+    ///
+    /// `y = x2 * x1`
+    #[allow(clippy::should_implement_trait)]
+    pub fn mul(self, other: Self) -> Self {
+        check!(TensorCheck::binary_ops_ew("Mul", &self, &other));
+        Self::new(K::mul(self.primitive, other.primitive))
+    }
+
+    /// Switch sign of each element in the tensor.
+    ///
+    /// `y = -x`
+    #[allow(clippy::should_implement_trait)]
+    pub fn neg(self) -> Self {
+        Self::new(K::neg(self.primitive))
+    }
+}
+
 impl<B, const D: usize, K> Tensor<B, D, K>
 where
     B: Backend,
@@ -33,15 +86,6 @@ where
         data.value[0]
     }
 
-    /// Applies element wise addition operation.
-    ///
-    /// `y = x2 + x1`
-    #[allow(clippy::should_implement_trait)]
-    pub fn add(self, other: Self) -> Self {
-        check!(TensorCheck::binary_ops_ew("Add", &self, &other));
-        Self::new(K::add(self.primitive, other.primitive))
-    }
-
     /// Applies element wise addition operation with a scalar.
     ///
     /// `y = x + s`
@@ -49,15 +93,6 @@ where
         Self::new(K::add_scalar(self.primitive, other))
     }
 
-    /// Applies element wise subtraction operation.
-    ///
-    /// `y = x2 - x1`
-    #[allow(clippy::should_implement_trait)]
-    pub fn sub(self, other: Self) -> Self {
-        check!(TensorCheck::binary_ops_ew("Sub", &self, &other));
-        Self::new(K::sub(self.primitive, other.primitive))
-    }
-
     /// Applies element wise subtraction operation with a scalar.
     ///
     /// `y = x - s`
@@ -65,30 +100,12 @@ where
         Self::new(K::sub_scalar(self.primitive, other))
     }
 
-    /// Applies element wise division operation.
-    ///
-    /// `y = x2 / x1`
-    #[allow(clippy::should_implement_trait)]
-    pub fn div(self, other: Self) -> Self {
-        check!(TensorCheck::binary_ops_ew("Div", &self, &other));
-        Self::new(K::div(self.primitive, other.primitive))
-    }
-
     /// Applies element wise division operation with a scalar.
     ///
     /// `y = x / s`
     pub fn div_scalar<E: ElementConversion>(self, other: E) -> Self {
         Self::new(K::div_scalar(self.primitive, other))
     }
-    ///
-    /// Applies element wise multiplication operation.
-    ///
-    /// `y = x2 * x1`
-    #[allow(clippy::should_implement_trait)]
-    pub fn mul(self, other: Self) -> Self {
-        check!(TensorCheck::binary_ops_ew("Mul", &self, &other));
-        Self::new(K::mul(self.primitive, other.primitive))
-    }
 
     /// Applies element wise multiplication operation with a scalar.
     ///
@@ -97,14 +114,6 @@ where
         Self::new(K::mul_scalar(self.primitive, other))
     }
 
-    /// Switch sign of each element in the tensor.
-    ///
-    /// `y = -x`
-    #[allow(clippy::should_implement_trait)]
-    pub fn neg(self) -> Self {
-        Self::new(K::neg(self.primitive))
-    }
-
     /// Create a tensor of the given shape where each element is zero.
     pub fn zeros<S: Into<Shape<D>>>(shape: S, device: &B::Device) -> Self {
         Self::new(K::zeros(shape.into(), device))
@@ -227,6 +236,20 @@ where
         Self::new(K::mask_fill(self.primitive, mask, value.elem()))
     }
 
+    /// Returns a 1-D tensor with the elements where the mask is true, in row-major order.
+    ///
+    /// The length of the output depends on the values of the mask, so the mask is read back to
+    /// the host on most backends. This is a synchronization point: on lazy backends like fusion,
+    /// every pending operation is executed before the mask is read.
+    pub fn masked_select(self, mask: Tensor<B, D, Bool>) -> Tensor<B, 1, K> {
+        check!(TensorCheck::masked_select::<D>(
+            &self.shape(),
+            &mask.shape()
+        ));
+
+        Tensor::new(K::masked_select(self.primitive, mask))
+    }
+
     /// Gather tensor elements corresponding to the given indices from the specified dim.
     ///
     /// Example using a 3D tensor:
@@ -275,6 +298,127 @@ where
         Self::new(K::scatter(dim, self.primitive, indices, values.primitive))
     }
 
+    /// Add the values scattered at the given indices along the specified dimension to the
+    /// elements of the tensor.
+    ///
+    /// This is the same operation as [scatter](Tensor::scatter), named after its reduction like
+    /// [scatter_max](Tensor::scatter_max) and [scatter_min](Tensor::scatter_min).
+    pub fn scatter_add(self, dim: usize, indices: Tensor<B, D, Int>, values: Self) -> Self {
+        self.scatter(dim, indices, values)
+    }
+
+    /// Assign the maximum of each element and of the values scattered into it at the given
+    /// indices along the specified dimension.
+    ///
+    /// Example using a 3D tensor:
+    ///
+    /// `input[indices[i, j, k], j, k] = max(input[indices[i, j, k], j, k], values[i, j, k]); // dim = 0`
+    ///
+    /// # Notes
+    ///
+    /// The index tensor should have the same shape as the original tensor except for the specified
+    /// dimension. The value and index tensors should have the same shape.
+    ///
+    /// Scattering into a tensor filled with the lowest value computes the maximum of each segment
+    /// of values, such as the max aggregation of the messages of a graph neural network.
+    pub fn scatter_max(self, dim: usize, indices: Tensor<B, D, Int>, values: Self) -> Self {
+        check!(TensorCheck::scatter_max::<D>(
+            dim,
+            &self.shape(),
+            &indices.shape(),
+            &values.shape()
+        ));
+
+        Self::new(K::scatter_max(
+            dim,
+            self.primitive,
+            indices,
+            values.primitive,
+        ))
+    }
+
+    /// Assign the minimum of each element and of the values scattered into it at the given
+    /// indices along the specified dimension.
+    ///
+    /// Example using a 3D tensor:
+    ///
+    /// `input[indices[i, j, k], j, k] = min(input[indices[i, j, k], j, k], values[i, j, k]); // dim = 0`
+    ///
+    /// # Notes
+    ///
+    /// The index tensor should have the same shape as the original tensor except for the specified
+    /// dimension. The value and index tensors should have the same shape.
+    pub fn scatter_min(self, dim: usize, indices: Tensor<B, D, Int>, values: Self) -> Self {
+        check!(TensorCheck::scatter_min::<D>(
+            dim,
+            &self.shape(),
+            &indices.shape(),
+            &values.shape()
+        ));
+
+        Self::new(K::scatter_min(
+            dim,
+            self.primitive,
+            indices,
+            values.primitive,
+        ))
+    }
+
+    /// Put the values at the given coordinates of the tensor.
+    ///
+    /// Each row of the `[N, D]` index tensor holds the coordinates of an element, which is
+    /// replaced by the corresponding value of the `[N]` value tensor. When `accumulate` is true,
+    /// the values are added to the elements instead.
+    ///
+    /// Example using a 2D tensor:
+    ///
+    /// `input[indices[n, 0], indices[n, 1]] = values[n]; // accumulate = false`
+    /// `input[indices[n, 0], indices[n, 1]] += values[n]; // accumulate = true`
+    ///
+    /// # Notes
+    ///
+    /// When the same coordinates appear more than once, the values are summed if `accumulate` is
+    /// true, and the result is unspecified otherwise.
+    pub fn index_put(
+        self,
+        indices: Tensor<B, 2, Int>,
+        values: Tensor<B, 1, K>,
+        accumulate: bool,
+    ) -> Self {
+        check!(TensorCheck::index_put::<D>(
+            &indices.shape(),
+            &values.shape()
+        ));
+
+        let shape = self.shape();
+        let device = self.device();
+        let num_elements = shape.num_elements();
+
+        let strides = (0..D)
+            .map(|i| shape.dims[i + 1..].iter().product::<usize>() as i64)
+            .collect::<Vec<_>>();
+        let strides = Tensor::<B, 2, Int>::from_data(
+            Data::new(strides, Shape::new([1, D])).convert(),
+            &device,
+        );
+        let num_values = values.dims()[0];
+        let positions = indices.mul(strides).sum_dim(1).reshape([num_values]);
+
+        let tensor = self.reshape([num_elements]);
+        let tensor = match accumulate {
+            true => tensor,
+            false => {
+                let mask = Tensor::<B, 1, K>::zeros([num_elements], &device)
+                    .select_assign(0, positions.clone(), Tensor::ones([num_values], &device))
+                    .greater_elem(0);
+
+                tensor.mask_fill(mask, 0)
+            }
+        };
+
+        tensor.select_assign(0, positions, values).reshape(shape)
+    }
+
     /// Select the tensor elements along the given dimension corresponding to the given indices.
     ///
     /// Example using a 3D tensor:
@@ -402,6 +546,198 @@ where
         (tensor, index)
     }
 
+    /// Sort the elements along the given dimension.
+    ///
+    /// The sort is stable, equal elements keep their relative order. NaN values are greater than
+    /// every other value, so they are placed last in ascending order and first in descending order.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = B::Device::default();
+    ///     let tensor = Tensor::<B, 2>::from_floats([[3.0, 1.0, 2.0], [0.0, 5.0, 4.0]], &device);
+    ///     let tensor = tensor.sort(1, true);
+    ///     println!("{}", tensor);
+    ///     // [[3.0, 2.0, 1.0], [5.0, 4.0, 0.0]]
+    /// }
+    /// ```
+    pub fn sort(self, dim: usize, descending: bool) -> Tensor<B, D, K> {
+        check!(TensorCheck::dim_ops::<D>("Sort", dim));
+
+        Tensor::new(K::sort(self.primitive, dim, descending))
+    }
+
+    /// Sort the elements along the given dimension.
+    ///
+    /// Also returns the indices of the sorted elements in the input tensor.
+    pub fn sort_with_indices(
+        self,
+        dim: usize,
+        descending: bool,
+    ) -> (Tensor<B, D, K>, Tensor<B, D, Int>) {
+        check!(TensorCheck::dim_ops::<D>("Sort", dim));
+
+        let (tensor, indices) = K::sort_with_indices(self.primitive, dim, descending);
+
+        (Tensor::new(tensor), Tensor::new(indices))
+    }
+
+    /// Returns the indices that sort the elements along the given dimension in ascending order.
+    ///
+    /// Gathering the tensor with the indices along the same dimension returns the sorted tensor.
+    pub fn argsort(self, dim: usize) -> Tensor<B, D, Int> {
+        check!(TensorCheck::dim_ops::<D>("Argsort", dim));
+
+        Tensor::new(K::argsort(self.primitive, dim, false))
+    }
+
+    /// Computes the cumulative sum of the elements along the given dimension.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = B::Device::default();
+    ///     let tensor = Tensor::<B, 2>::from_floats([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]], &device);
+    ///     let tensor = tensor.cumsum(1);
+    ///     println!("{}", tensor);
+    ///     // [[1.0, 3.0, 6.0], [4.0, 9.0, 15.0]]
+    /// }
+    /// ```
+    pub fn cumsum(self, dim: usize) -> Self {
+        check!(TensorCheck::dim_ops::<D>("Cumsum", dim));
+
+        Self::new(K::cumsum(self.primitive, dim))
+    }
+
+    /// Computes the cumulative product of the elements along the given dimension.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = B::Device::default();
+    ///     let tensor = Tensor::<B, 2>::from_floats([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]], &device);
+    ///     let tensor = tensor.cumprod(1);
+    ///     println!("{}", tensor);
+    ///     // [[1.0, 2.0, 6.0], [4.0, 20.0, 120.0]]
+    /// }
+    /// ```
+    pub fn cumprod(self, dim: usize) -> Self {
+        check!(TensorCheck::dim_ops::<D>("Cumprod", dim));
+
+        Self::new(K::cumprod(self.primitive, dim))
+    }
+
+    /// Finds the indices where the values should be inserted in the sorted sequences to keep them
+    /// sorted.
+    ///
+    /// The tensor holds the sequences, sorted in ascending order along the last dimension, and the
+    /// values are searched in the sequence with the same leading indices.
+    ///
+    /// # Arguments
+    ///
+    /// * `values` - The values to search, with the same leading dimensions as the tensor.
+    /// * `right` - If `false`, the index of the first element greater than or equal to the value
+    ///   is returned, otherwise the index of the first element strictly greater than the value.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = B::Device::default();
+    ///     let sorted = Tensor::<B, 1>::from_floats([1.0, 3.0, 5.0, 7.0], &device);
+    ///     let values = Tensor::<B, 1>::from_floats([0.0, 3.0, 6.0, 9.0], &device);
+    ///     let indices = sorted.searchsorted(values, false);
+    ///     println!("{}", indices);
+    ///     // [0, 1, 3, 4]
+    /// }
+    /// ```
+    pub fn searchsorted(self, values: Self, right: bool) -> Tensor<B, D, Int> {
+        check!(TensorCheck::searchsorted::<D>(
+            &self.shape(),
+            &values.shape()
+        ));
+
+        Tensor::new(K::searchsorted(self.primitive, values.primitive, right))
+    }
+
+    /// Gets the index of the bucket of each element, where the buckets are delimited by the
+    /// boundaries sorted in ascending order.
+    ///
+    /// With `right` set to `false`, an element `x` is in the bucket `i` when
+    /// `boundaries[i - 1] < x <= boundaries[i]`, otherwise when
+    /// `boundaries[i - 1] <= x < boundaries[i]`.
+    /// The elements lower than the first boundary are in the bucket `0` and the elements greater
+    /// than the last boundary are in the bucket `boundaries.len()`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = B::Device::default();
+    ///     let tensor = Tensor::<B, 2>::from_floats([[0.5, 1.0], [2.5, 4.0]], &device);
+    ///     let boundaries = Tensor::<B, 1>::from_floats([1.0, 2.0, 3.0], &device);
+    ///     let buckets = tensor.bucketize(boundaries, false);
+    ///     println!("{}", buckets);
+    ///     // [[0, 0], [2, 3]]
+    /// }
+    /// ```
+    pub fn bucketize(self, boundaries: Tensor<B, 1, K>, right: bool) -> Tensor<B, D, Int> {
+        let shape = self.shape();
+        let num_elements = shape.num_elements();
+        let num_boundaries = boundaries.dims()[0];
+
+        let values = self.reshape([1, num_elements]);
+        let boundaries = boundaries.reshape([1, num_boundaries]);
+
+        boundaries.searchsorted(values, right).reshape(shape)
+    }
+
+    /// Pads the tensor along each dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `padding` - The number of elements added before and after the tensor along each
+    ///   dimension.
+    /// * `mode` - How the padded elements are filled.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{PadMode, Tensor};
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = B::Device::default();
+    ///     let tensor = Tensor::<B, 2>::from_floats([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]], &device);
+    ///     let padded = tensor.pad([(0, 0), (2, 1)], PadMode::Reflect);
+    ///     println!("{}", padded);
+    ///     // [[3.0, 2.0, 1.0, 2.0, 3.0, 2.0], [6.0, 5.0, 4.0, 5.0, 6.0, 5.0]]
+    /// }
+    /// ```
+    pub fn pad(self, padding: [(usize, usize); D], mode: PadMode) -> Self {
+        check!(TensorCheck::pad::<D>(&self.shape(), &padding, mode));
+
+        Self::new(K::pad(self.primitive, padding, mode))
+    }
+
     /// Clamp the tensor between the given min and max values.
     ///
     /// # Arguments
@@ -569,12 +905,13 @@ where
     }
 }
 
-/// Trait that list all operations that can be applied on all numerical tensors.
+/// Trait that list the element-wise arithmetic operations shared by the numerical and the complex
+/// tensors.
 ///
 /// # Warnings
 ///
 /// This is an internal trait, use the public API provided by [tensor struct](Tensor).
-pub trait Numeric<B: Backend>: BasicOps<B>
+pub trait Arithmetic<B: Backend>: BasicOps<B>
 where
     Self::Elem: Element,
 {
@@ -599,16 +936,16 @@ where
     /// which is more high-level and designed for public use.
     fn add<const D: usize>(lhs: Self::Primitive<D>, rhs: Self::Primitive<D>) -> Self::Primitive<D>;
 
-    /// Adds a scalar to a tensor element-wise.
+    /// Subtracts two tensors.
     ///
     /// # Arguments
     ///
     /// * `lhs` - The left hand side tensor.
-    /// * `rhs` - The right hand side scalar.
+    /// * `rhs` - The right hand side tensor.
     ///
     /// # Returns
     ///
-    /// The sum of the tensor and the scalar.
+    /// The difference of the two tensors.
     ///
     /// # Remarks
     ///
@@ -616,14 +953,11 @@ where
     /// with static dispatch. It is not designed for direct usage by users, and not recommended to import
     /// or use this function directly.
     ///
-    /// For adding a scalar to a tensor, users should prefer the [Tensor::add_scalar](Tensor::add_scalar) function,
+    /// For subtracting tensors, users should prefer the [Tensor::sub](Tensor::sub) function,
     /// which is more high-level and designed for public use.
-    fn add_scalar<const D: usize, E: ElementConversion>(
-        lhs: Self::Primitive<D>,
-        rhs: E,
-    ) -> Self::Primitive<D>;
+    fn sub<const D: usize>(lhs: Self::Primitive<D>, rhs: Self::Primitive<D>) -> Self::Primitive<D>;
 
-    /// Subtracts two tensors.
+    /// Divides two tensors.
     ///
     /// # Arguments
     ///
@@ -632,7 +966,7 @@ where
     ///
     /// # Returns
     ///
-    /// The difference of the two tensors.
+    /// The quotient of the two tensors.
     ///
     /// # Remarks
     ///
@@ -640,20 +974,20 @@ where
     /// with static dispatch. It is not designed for direct usage by users, and not recommended to import
     /// or use this function directly.
     ///
-    /// For subtracting tensors, users should prefer the [Tensor::sub](Tensor::sub) function,
+    /// For dividing tensors, users should prefer the [Tensor::div](Tensor::div) function,
     /// which is more high-level and designed for public use.
-    fn sub<const D: usize>(lhs: Self::Primitive<D>, rhs: Self::Primitive<D>) -> Self::Primitive<D>;
+    fn div<const D: usize>(lhs: Self::Primitive<D>, rhs: Self::Primitive<D>) -> Self::Primitive<D>;
 
-    /// Subtracts a scalar from a tensor element-wise.
+    /// Multiplies two tensors.
     ///
     /// # Arguments
     ///
     /// * `lhs` - The left hand side tensor.
-    /// * `rhs` - The right hand side scalar.
+    /// * `rhs` - The right hand side tensor.
     ///
     /// # Returns
     ///
-    /// The difference of the tensor and the scalar.
+    /// The product of the two tensors.
     ///
     /// # Remarks
     ///
@@ -661,23 +995,19 @@ where
     /// with static dispatch. It is not designed for direct usage by users, and not recommended to import
     /// or use this function directly.
     ///
-    /// For subtracting a scalar from a tensor, users should prefer the [Tensor::sub_scalar](Tensor::sub_scalar) function,
+    /// For multiplying tensors, users should prefer the [Tensor::mul](Tensor::mul) function,
     /// which is more high-level and designed for public use.
-    fn sub_scalar<const D: usize, E: ElementConversion>(
-        lhs: Self::Primitive<D>,
-        rhs: E,
-    ) -> Self::Primitive<D>;
+    fn mul<const D: usize>(lhs: Self::Primitive<D>, rhs: Self::Primitive<D>) -> Self::Primitive<D>;
 
-    /// Divides two tensors.
+    /// Negates a tensor.
     ///
     /// # Arguments
     ///
-    /// * `lhs` - The left hand side tensor.
-    /// * `rhs` - The right hand side tensor.
+    /// * `tensor` - The tensor to negate.
     ///
     /// # Returns
     ///
-    /// The quotient of the two tensors.
+    /// The negated tensor.
     ///
     /// # Remarks
     ///
@@ -685,11 +1015,81 @@ where
     /// with static dispatch. It is not designed for direct usage by users, and not recommended to import
     /// or use this function directly.
     ///
-    /// For dividing tensors, users should prefer the [Tensor::div](Tensor::div) function,
+    /// For negating a tensor, users should prefer the [Tensor::neg](Tensor::neg) function,
     /// which is more high-level and designed for public use.
-    fn div<const D: usize>(lhs: Self::Primitive<D>, rhs: Self::Primitive<D>) -> Self::Primitive<D>;
+    fn neg<const D: usize>(tensor: Self::Primitive<D>) -> Self::Primitive<D>;
+}
 
-    /// Divides a tensor by a scalar element-wise.
+impl<B: Backend> Arithmetic<B> for Int {
+    fn add<const D: usize>(
+        lhs: Self::Primitive<D>,
+        rhs: Self::Primitive<D>,
+    ) -> <Int as TensorKind<B>>::Primitive<D> {
+        B::int_add(lhs, rhs)
+    }
+    fn sub<const D: usize>(
+        lhs: Self::Primitive<D>,
+        rhs: Self::Primitive<D>,
+    ) -> <Int as TensorKind<B>>::Primitive<D> {
+        B::int_sub(lhs, rhs)
+    }
+    fn div<const D: usize>(
+        lhs: Self::Primitive<D>,
+        rhs: Self::Primitive<D>,
+    ) -> <Int as TensorKind<B>>::Primitive<D> {
+        B::int_div(lhs, rhs)
+    }
+    fn mul<const D: usize>(
+        lhs: Self::Primitive<D>,
+        rhs: Self::Primitive<D>,
+    ) -> <Int as TensorKind<B>>::Primitive<D> {
+        B::int_mul(lhs, rhs)
+    }
+    fn neg<const D: usize>(tensor: Self::Primitive<D>) -> Self::Primitive<D> {
+        B::int_neg(tensor)
+    }
+}
+
+impl<B: Backend> Arithmetic<B> for Float {
+    fn add<const D: usize>(
+        lhs: Self::Primitive<D>,
+        rhs: Self::Primitive<D>,
+    ) -> <Float as TensorKind<B>>::Primitive<D> {
+        B::add(lhs, rhs)
+    }
+    fn sub<const D: usize>(
+        lhs: Self::Primitive<D>,
+        rhs: Self::Primitive<D>,
+    ) -> <Float as TensorKind<B>>::Primitive<D> {
+        B::sub(lhs, rhs)
+    }
+    fn div<const D: usize>(
+        lhs: Self::Primitive<D>,
+        rhs: Self::Primitive<D>,
+    ) -> <Float as TensorKind<B>>::Primitive<D> {
+        B::div(lhs, rhs)
+    }
+    fn mul<const D: usize>(
+        lhs: Self::Primitive<D>,
+        rhs: Self::Primitive<D>,
+    ) -> <Float as TensorKind<B>>::Primitive<D> {
+        B::mul(lhs, rhs)
+    }
+    fn neg<const D: usize>(tensor: Self::Primitive<D>) -> Self::Primitive<D> {
+        B::neg(tensor)
+    }
+}
+
+/// Trait that list all operations that can be applied on all numerical tensors.
+///
+/// # Warnings
+///
+/// This is an internal trait, use the public API provided by [tensor struct](Tensor).
+pub trait Numeric<B: Backend>: Arithmetic<B>
+where
+    Self::Elem: Element,
+{
+    /// Adds a scalar to a tensor element-wise.
     ///
     /// # Arguments
     ///
@@ -698,7 +1098,7 @@ where
     ///
     /// # Returns
     ///
-    /// The quotient of the tensor and the scalar.
+    /// The sum of the tensor and the scalar.
     ///
     /// # Remarks
     ///
@@ -706,23 +1106,23 @@ where
     /// with static dispatch. It is not designed for direct usage by users, and not recommended to import
     /// or use this function directly.
     ///
-    /// For dividing a tensor by a scalar, users should prefer the [Tensor::div_scalar](Tensor::div_scalar) function,
+    /// For adding a scalar to a tensor, users should prefer the [Tensor::add_scalar](Tensor::add_scalar) function,
     /// which is more high-level and designed for public use.
-    fn div_scalar<const D: usize, E: ElementConversion>(
+    fn add_scalar<const D: usize, E: ElementConversion>(
         lhs: Self::Primitive<D>,
         rhs: E,
     ) -> Self::Primitive<D>;
 
-    /// Multiplies two tensors.
+    /// Subtracts a scalar from a tensor element-wise.
     ///
     /// # Arguments
     ///
     /// * `lhs` - The left hand side tensor.
-    /// * `rhs` - The right hand side tensor.
+    /// * `rhs` - The right hand side scalar.
     ///
     /// # Returns
     ///
-    /// The product of the two tensors.
+    /// The difference of the tensor and the scalar.
     ///
     /// # Remarks
     ///
@@ -730,11 +1130,14 @@ where
     /// with static dispatch. It is not designed for direct usage by users, and not recommended to import
     /// or use this function directly.
     ///
-    /// For multiplying tensors, users should prefer the [Tensor::mul](Tensor::mul) function,
+    /// For subtracting a scalar from a tensor, users should prefer the [Tensor::sub_scalar](Tensor::sub_scalar) function,
     /// which is more high-level and designed for public use.
-    fn mul<const D: usize>(lhs: Self::Primitive<D>, rhs: Self::Primitive<D>) -> Self::Primitive<D>;
+    fn sub_scalar<const D: usize, E: ElementConversion>(
+        lhs: Self::Primitive<D>,
+        rhs: E,
+    ) -> Self::Primitive<D>;
 
-    /// Multiplies a tensor by a scalar element-wise.
+    /// Divides a tensor by a scalar element-wise.
     ///
     /// # Arguments
     ///
@@ -743,7 +1146,7 @@ where
     ///
     /// # Returns
     ///
-    /// The product of the tensor and the scalar.
+    /// The quotient of the tensor and the scalar.
     ///
     /// # Remarks
     ///
@@ -751,22 +1154,23 @@ where
     /// with static dispatch. It is not designed for direct usage by users, and not recommended to import
     /// or use this function directly.
     ///
-    /// For multiplying a tensor by a scalar, users should prefer the [Tensor::mul_scalar](Tensor::mul_scalar) function,
+    /// For dividing a tensor by a scalar, users should prefer the [Tensor::div_scalar](Tensor::div_scalar) function,
     /// which is more high-level and designed for public use.
-    fn mul_scalar<const D: usize, E: ElementConversion>(
+    fn div_scalar<const D: usize, E: ElementConversion>(
         lhs: Self::Primitive<D>,
         rhs: E,
     ) -> Self::Primitive<D>;
 
-    /// Negates a tensor.
+    /// Multiplies a tensor by a scalar element-wise.
     ///
     /// # Arguments
     ///
-    /// * `tensor` - The tensor to negate.
+    /// * `lhs` - The left hand side tensor.
+    /// * `rhs` - The right hand side scalar.
     ///
     /// # Returns
     ///
-    /// The negated tensor.
+    /// The product of the tensor and the scalar.
     ///
     /// # Remarks
     ///
@@ -774,9 +1178,12 @@ where
     /// with static dispatch. It is not designed for direct usage by users, and not recommended to import
     /// or use this function directly.
     ///
-    /// For negating a tensor, users should prefer the [Tensor::neg](Tensor::neg) function,
+    /// For multiplying a tensor by a scalar, users should prefer the [Tensor::mul_scalar](Tensor::mul_scalar) function,
     /// which is more high-level and designed for public use.
-    fn neg<const D: usize>(tensor: Self::Primitive<D>) -> Self::Primitive<D>;
+    fn mul_scalar<const D: usize, E: ElementConversion>(
+        lhs: Self::Primitive<D>,
+        rhs: E,
+    ) -> Self::Primitive<D>;
 
     /// Creates a tensor filled with zeros.
     ///
@@ -1267,6 +1674,66 @@ where
         values: Self::Primitive<D>,
     ) -> Self::Primitive<D>;
 
+    /// Scatters elements into a tensor, keeping the maximum of each element and of the values
+    /// scattered into it.
+    ///
+    /// # Arguments
+    ///
+    /// * `dim` - The axis along which to scatter elements.
+    /// * `tensor` - The tensor to scatter elements into.
+    /// * `indices` - The indices of the elements to scatter.
+    /// * `values` - The values to scatter into the tensor.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as the input tensor, where the elements at the specified
+    /// indices are the maximum of the element and of the values scattered into it.
+    ///
+    /// # Remarks
+    ///
+    /// This is a low-level function used internally by the library to call different backend functions
+    /// with static dispatch. It is not designed for direct usage by users, and not recommended to import
+    /// or use this function directly.
+    ///
+    /// For scattering elements into a tensor along an axis, users should prefer the
+    /// [Tensor::scatter_max](Tensor::scatter_max) function, which is more high-level and designed for public use.
+    fn scatter_max<const D: usize>(
+        dim: usize,
+        tensor: Self::Primitive<D>,
+        indices: Tensor<B, D, Int>,
+        values: Self::Primitive<D>,
+    ) -> Self::Primitive<D>;
+
+    /// Scatters elements into a tensor, keeping the minimum of each element and of the values
+    /// scattered into it.
+    ///
+    /// # Arguments
+    ///
+    /// * `dim` - The axis along which to scatter elements.
+    /// * `tensor` - The tensor to scatter elements into.
+    /// * `indices` - The indices of the elements to scatter.
+    /// * `values` - The values to scatter into the tensor.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as the input tensor, where the elements at the specified
+    /// indices are the minimum of the element and of the values scattered into it.
+    ///
+    /// # Remarks
+    ///
+    /// This is a low-level function used internally by the library to call different backend functions
+    /// with static dispatch. It is not designed for direct usage by users, and not recommended to import
+    /// or use this function directly.
+    ///
+    /// For scattering elements into a tensor along an axis, users should prefer the
+    /// [Tensor::scatter_min](Tensor::scatter_min) function, which is more high-level and designed for public use.
+    fn scatter_min<const D: usize>(
+        dim: usize,
+        tensor: Self::Primitive<D>,
+        indices: Tensor<B, D, Int>,
+        values: Self::Primitive<D>,
+    ) -> Self::Primitive<D>;
+
     /// Select tensor elements along the given dimension corresponding for the given indices.
     ///
     /// # Arguments
@@ -1294,22 +1761,202 @@ where
         indices: Tensor<B, 1, Int>,
     ) -> Self::Primitive<D>;
 
-    /// Assign the selected elements along the given dimension corresponding to the given indices
-    /// from the value tensor.
+    /// Assign the selected elements along the given dimension corresponding to the given indices
+    /// from the value tensor.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to assign elements to.
+    /// * `dim` - The axis along which to assign elements.
+    /// * `indices` - The indices of the elements to assign.
+    /// * `values` - The values to assign to the tensor.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as the input tensor, where each element is taken from the
+    /// corresponding element of the input tensor at the corresponding index along the specified axis,
+    /// except for the elements at the specified indices, which are taken from the corresponding
+    /// element of the values tensor.
+    ///
+    /// # Remarks
+    ///
+    /// This is a low-level function used internally by the library to call different backend functions
+    /// with static dispatch. It is not designed for direct usage by users, and not recommended to import
+    /// or use this function directly.
+    ///
+    /// For assigning elements to a tensor along an axis, users should prefer the
+    /// [Tensor::select_assign](Tensor::select_assign) function, which is more high-level and designed for public use.
+    fn select_assign<const D: usize>(
+        tensor: Self::Primitive<D>,
+        dim: usize,
+        indices: Tensor<B, 1, Int>,
+        values: Self::Primitive<D>,
+    ) -> Self::Primitive<D>;
+
+    /// Gets the indices of the maximum elements of a tensor along an axis.
+    ///
+    /// # Arguments
+    ///
+    /// * `dim` - The axis along which to get the indices of the maximum elements.
+    /// * `tensor` - The tensor to get the indices of the maximum elements from.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as the input tensor, where each element is the index of the
+    /// maximum element of the input tensor at the corresponding index along the specified axis.
+    ///
+    /// # Remarks
+    ///
+    /// This is a low-level function used internally by the library to call different backend functions
+    /// with static dispatch. It is not designed for direct usage by users, and not recommended to import
+    /// or use this function directly.
+    ///
+    /// For getting the indices of the maximum elements of a tensor along an axis, users should prefer the
+    /// [Tensor::argmax](Tensor::argmax) function, which is more high-level and designed for public use.
+    fn argmax<const D: usize>(tensor: Self::Primitive<D>, dim: usize) -> B::IntTensorPrimitive<D>;
+
+    /// Gets the indices of the minimum elements of a tensor along an axis.
+    ///
+    /// # Arguments
+    ///
+    /// * `dim` - The axis along which to get the indices of the minimum elements.
+    /// * `tensor` - The tensor to get the indices of the minimum elements from.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as the input tensor, where each element is the index of the
+    /// minimum element of the input tensor at the corresponding index along the specified axis.
+    ///
+    /// # Remarks
+    ///
+    /// This is a low-level function used internally by the library to call different backend functions
+    /// with static dispatch. It is not designed for direct usage by users, and not recommended to import
+    /// or use this function directly.
+    ///
+    /// For getting the indices of the minimum elements of a tensor along an axis, users should prefer the
+    /// [Tensor::argmin](Tensor::argmin) function, which is more high-level and designed for public use.
+    fn argmin<const D: usize>(tensor: Self::Primitive<D>, dim: usize) -> B::IntTensorPrimitive<D>;
+
+    /// Gets the maximum elements of a tensor along an axis.
+    ///
+    /// # Arguments
+    ///
+    /// * `dim` - The axis along which to get the maximum elements.
+    ///
+    /// # Returns
+    ///
+    /// A single-element tensor containing the maximum element of the input tensor.
+    ///
+    /// # Remarks
+    ///
+    /// This is a low-level function used internally by the library to call different backend functions
+    /// with static dispatch. It is not designed for direct usage by users, and not recommended to import
+    /// or use this function directly.
+    ///
+    /// For getting the maximum elements of a tensor along an axis, users should prefer the
+    /// [Tensor::max](Tensor::max) function, which is more high-level and designed for public use.
+    fn max<const D: usize>(tensor: Self::Primitive<D>) -> Self::Primitive<1>;
+
+    /// Gets the maximum elements of a tensor along an axis.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to get the maximum elements from.
+    /// * `dim` - The axis along which to get the maximum elements.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as the input tensor, where each element is the maximum element
+    ///
+    /// # Remarks
+    ///
+    /// This is a low-level function used internally by the library to call different backend functions
+    /// with static dispatch. It is not designed for direct usage by users, and not recommended to import
+    /// or use this function directly.
+    ///
+    /// For getting the maximum elements of a tensor along an axis, users should prefer the
+    /// [Tensor::max_dim](Tensor::max_dim) function, which is more high-level and designed for public use.
+    fn max_dim<const D: usize>(tensor: Self::Primitive<D>, dim: usize) -> Self::Primitive<D>;
+
+    /// Gets the maximum elements of a tensor along an axis.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to get the maximum elements from.
+    /// * `dim` - The axis along which to get the maximum elements.
+    ///
+    /// # Returns
+    ///
+    /// A tuple containing the maximum element of the input tensor, and a tensor with the same shape
+    /// as the input tensor, where each element is the index of the maximum element of the input tensor
+    /// at the corresponding index along the specified axis.
+    ///
+    /// # Remarks
+    ///
+    /// This is a low-level function used internally by the library to call different backend functions
+    /// with static dispatch. It is not designed for direct usage by users, and not recommended to import
+    /// or use this function directly.
+    ///
+    /// For getting the maximum elements of a tensor along an axis, users should prefer the
+    /// [Tensor::max_dim_with_indices](Tensor::max_dim_with_indices) function, which is more high-level and designed for public use.
+    fn max_dim_with_indices<const D: usize>(
+        tensor: Self::Primitive<D>,
+        dim: usize,
+    ) -> (Self::Primitive<D>, B::IntTensorPrimitive<D>);
+
+    /// Gets the minimum elements of a tensor along an axis.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to get the minimum elements from.
+    ///
+    /// # Returns
+    ///
+    /// A single-element tensor containing the minimum element of the input tensor.
+    ///
+    /// # Remarks
+    ///
+    /// This is a low-level function used internally by the library to call different backend functions
+    /// with static dispatch. It is not designed for direct usage by users, and not recommended to import
+    /// or use this function directly.
+    ///
+    /// For getting the minimum elements of a tensor along an axis, users should prefer the
+    /// [Tensor::min](Tensor::min) function, which is more high-level and designed for public use.
+    fn min<const D: usize>(tensor: Self::Primitive<D>) -> Self::Primitive<1>;
+
+    /// Gets the minimum elements of a tensor along an axis.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to get the minimum elements from.
+    /// * `dim` - The axis along which to get the minimum elements.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as the input tensor, where each element is the minimum element
+    /// of the input tensor at the corresponding index along the specified axis.
+    ///
+    /// # Remarks
+    ///
+    /// This is a low-level function used internally by the library to call different backend functions
+    /// with static dispatch. It is not designed for direct usage by users, and not recommended to import
+    /// or use this function directly.
+    ///
+    /// For getting the minimum elements of a tensor along an axis, users should prefer the
+    /// [Tensor::min_dim](Tensor::min_dim) function, which is more high-level and designed for public use.
+    fn min_dim<const D: usize>(tensor: Self::Primitive<D>, dim: usize) -> Self::Primitive<D>;
+
+    /// Gets the minimum elements and indices of a tensor along an axis.
     ///
     /// # Arguments
     ///
-    /// * `tensor` - The tensor to assign elements to.
-    /// * `dim` - The axis along which to assign elements.
-    /// * `indices` - The indices of the elements to assign.
-    /// * `values` - The values to assign to the tensor.
+    /// * `tensor` - The tensor to get the minimum elements from.
     ///
     /// # Returns
     ///
-    /// A tensor with the same shape as the input tensor, where each element is taken from the
-    /// corresponding element of the input tensor at the corresponding index along the specified axis,
-    /// except for the elements at the specified indices, which are taken from the corresponding
-    /// element of the values tensor.
+    /// A tensor with the same shape as the input tensor and corresponding indices, where
+    /// each element is the minimum element of the input tensor at the corresponding index
+    /// along the specified axis.
     ///
     /// # Remarks
     ///
@@ -1317,26 +1964,24 @@ where
     /// with static dispatch. It is not designed for direct usage by users, and not recommended to import
     /// or use this function directly.
     ///
-    /// For assigning elements to a tensor along an axis, users should prefer the
-    /// [Tensor::select_assign](Tensor::select_assign) function, which is more high-level and designed for public use.
-    fn select_assign<const D: usize>(
+    /// For getting the minimum elements of a tensor along an axis, users should prefer the
+    /// [Tensor::min_dim_with_indices](Tensor::min_dim_with_indices) function, which is more high-level and designed for public use.
+    fn min_dim_with_indices<const D: usize>(
         tensor: Self::Primitive<D>,
         dim: usize,
-        indices: Tensor<B, 1, Int>,
-        values: Self::Primitive<D>,
-    ) -> Self::Primitive<D>;
+    ) -> (Self::Primitive<D>, B::IntTensorPrimitive<D>);
 
-    /// Gets the indices of the maximum elements of a tensor along an axis.
+    /// Sort the elements of the tensor along the given dimension.
     ///
     /// # Arguments
     ///
-    /// * `dim` - The axis along which to get the indices of the maximum elements.
-    /// * `tensor` - The tensor to get the indices of the maximum elements from.
+    /// * `tensor` - The tensor to sort.
+    /// * `dim` - The dimension along which to sort.
+    /// * `descending` - If the elements are sorted in descending order.
     ///
     /// # Returns
     ///
-    /// A tensor with the same shape as the input tensor, where each element is the index of the
-    /// maximum element of the input tensor at the corresponding index along the specified axis.
+    /// A tensor with the elements of the input tensor sorted along the given dimension.
     ///
     /// # Remarks
     ///
@@ -1344,21 +1989,25 @@ where
     /// with static dispatch. It is not designed for direct usage by users, and not recommended to import
     /// or use this function directly.
     ///
-    /// For getting the indices of the maximum elements of a tensor along an axis, users should prefer the
-    /// [Tensor::argmax](Tensor::argmax) function, which is more high-level and designed for public use.
-    fn argmax<const D: usize>(tensor: Self::Primitive<D>, dim: usize) -> B::IntTensorPrimitive<D>;
+    /// For sorting a tensor, users should prefer the [Tensor::sort](Tensor::sort) function,
+    /// which is more high-level and designed for public use.
+    fn sort<const D: usize>(
+        tensor: Self::Primitive<D>,
+        dim: usize,
+        descending: bool,
+    ) -> Self::Primitive<D>;
 
-    /// Gets the indices of the minimum elements of a tensor along an axis.
+    /// Sort the elements of the tensor along the given dimension and returns their indices.
     ///
     /// # Arguments
     ///
-    /// * `dim` - The axis along which to get the indices of the minimum elements.
-    /// * `tensor` - The tensor to get the indices of the minimum elements from.
+    /// * `tensor` - The tensor to sort.
+    /// * `dim` - The dimension along which to sort.
+    /// * `descending` - If the elements are sorted in descending order.
     ///
     /// # Returns
     ///
-    /// A tensor with the same shape as the input tensor, where each element is the index of the
-    /// minimum element of the input tensor at the corresponding index along the specified axis.
+    /// A tuple with the sorted tensor and the indices of the sorted elements in the input tensor.
     ///
     /// # Remarks
     ///
@@ -1366,19 +2015,26 @@ where
     /// with static dispatch. It is not designed for direct usage by users, and not recommended to import
     /// or use this function directly.
     ///
-    /// For getting the indices of the minimum elements of a tensor along an axis, users should prefer the
-    /// [Tensor::argmin](Tensor::argmin) function, which is more high-level and designed for public use.
-    fn argmin<const D: usize>(tensor: Self::Primitive<D>, dim: usize) -> B::IntTensorPrimitive<D>;
+    /// For sorting a tensor, users should prefer the
+    /// [Tensor::sort_with_indices](Tensor::sort_with_indices) function, which is more high-level and
+    /// designed for public use.
+    fn sort_with_indices<const D: usize>(
+        tensor: Self::Primitive<D>,
+        dim: usize,
+        descending: bool,
+    ) -> (Self::Primitive<D>, B::IntTensorPrimitive<D>);
 
-    /// Gets the maximum elements of a tensor along an axis.
+    /// Gets the indices that sort the elements of the tensor along the given dimension.
     ///
     /// # Arguments
     ///
-    /// * `dim` - The axis along which to get the maximum elements.
+    /// * `tensor` - The tensor to sort.
+    /// * `dim` - The dimension along which to sort.
+    /// * `descending` - If the elements are sorted in descending order.
     ///
     /// # Returns
     ///
-    /// A single-element tensor containing the maximum element of the input tensor.
+    /// A tensor with the indices of the sorted elements in the input tensor.
     ///
     /// # Remarks
     ///
@@ -1386,20 +2042,25 @@ where
     /// with static dispatch. It is not designed for direct usage by users, and not recommended to import
     /// or use this function directly.
     ///
-    /// For getting the maximum elements of a tensor along an axis, users should prefer the
-    /// [Tensor::max](Tensor::max) function, which is more high-level and designed for public use.
-    fn max<const D: usize>(tensor: Self::Primitive<D>) -> Self::Primitive<1>;
+    /// For getting the indices that sort a tensor, users should prefer the
+    /// [Tensor::argsort](Tensor::argsort) function, which is more high-level and designed for public use.
+    fn argsort<const D: usize>(
+        tensor: Self::Primitive<D>,
+        dim: usize,
+        descending: bool,
+    ) -> B::IntTensorPrimitive<D>;
 
-    /// Gets the maximum elements of a tensor along an axis.
+    /// Computes the cumulative sum of the elements along the given dimension.
     ///
     /// # Arguments
     ///
-    /// * `tensor` - The tensor to get the maximum elements from.
-    /// * `dim` - The axis along which to get the maximum elements.
+    /// * `tensor` - The tensor.
+    /// * `dim` - The dimension along which the elements are summed.
     ///
     /// # Returns
     ///
-    /// A tensor with the same shape as the input tensor, where each element is the maximum element
+    /// A tensor with the same shape as the input tensor, where each element is the sum of the
+    /// elements before it along the given dimension, itself included.
     ///
     /// # Remarks
     ///
@@ -1407,22 +2068,21 @@ where
     /// with static dispatch. It is not designed for direct usage by users, and not recommended to import
     /// or use this function directly.
     ///
-    /// For getting the maximum elements of a tensor along an axis, users should prefer the
-    /// [Tensor::max_dim](Tensor::max_dim) function, which is more high-level and designed for public use.
-    fn max_dim<const D: usize>(tensor: Self::Primitive<D>, dim: usize) -> Self::Primitive<D>;
+    /// For computing the cumulative sum of a tensor, users should prefer the
+    /// [Tensor::cumsum](Tensor::cumsum) function, which is more high-level and designed for public use.
+    fn cumsum<const D: usize>(tensor: Self::Primitive<D>, dim: usize) -> Self::Primitive<D>;
 
-    /// Gets the maximum elements of a tensor along an axis.
+    /// Computes the cumulative product of the elements along the given dimension.
     ///
     /// # Arguments
     ///
-    /// * `tensor` - The tensor to get the maximum elements from.
-    /// * `dim` - The axis along which to get the maximum elements.
+    /// * `tensor` - The tensor.
+    /// * `dim` - The dimension along which the elements are multiplied.
     ///
     /// # Returns
     ///
-    /// A tuple containing the maximum element of the input tensor, and a tensor with the same shape
-    /// as the input tensor, where each element is the index of the maximum element of the input tensor
-    /// at the corresponding index along the specified axis.
+    /// A tensor with the same shape as the input tensor, where each element is the product of the
+    /// elements before it along the given dimension, itself included.
     ///
     /// # Remarks
     ///
@@ -1430,22 +2090,20 @@ where
     /// with static dispatch. It is not designed for direct usage by users, and not recommended to import
     /// or use this function directly.
     ///
-    /// For getting the maximum elements of a tensor along an axis, users should prefer the
-    /// [Tensor::max_dim_with_indices](Tensor::max_dim_with_indices) function, which is more high-level and designed for public use.
-    fn max_dim_with_indices<const D: usize>(
-        tensor: Self::Primitive<D>,
-        dim: usize,
-    ) -> (Self::Primitive<D>, B::IntTensorPrimitive<D>);
+    /// For computing the cumulative product of a tensor, users should prefer the
+    /// [Tensor::cumprod](Tensor::cumprod) function, which is more high-level and designed for public use.
+    fn cumprod<const D: usize>(tensor: Self::Primitive<D>, dim: usize) -> Self::Primitive<D>;
 
-    /// Gets the minimum elements of a tensor along an axis.
+    /// Gets the elements of the tensor where the mask is `true`.
     ///
     /// # Arguments
     ///
-    /// * `tensor` - The tensor to get the minimum elements from.
+    /// * `tensor` - The tensor.
+    /// * `mask` - The boolean mask, with the same shape as the tensor.
     ///
     /// # Returns
     ///
-    /// A single-element tensor containing the minimum element of the input tensor.
+    /// A 1-D tensor with the selected elements, in row-major order.
     ///
     /// # Remarks
     ///
@@ -1453,21 +2111,27 @@ where
     /// with static dispatch. It is not designed for direct usage by users, and not recommended to import
     /// or use this function directly.
     ///
-    /// For getting the minimum elements of a tensor along an axis, users should prefer the
-    /// [Tensor::min](Tensor::min) function, which is more high-level and designed for public use.
-    fn min<const D: usize>(tensor: Self::Primitive<D>) -> Self::Primitive<1>;
+    /// For selecting the elements of a tensor with a mask, users should prefer the
+    /// [Tensor::masked_select](Tensor::masked_select) function, which is more high-level and designed
+    /// for public use.
+    fn masked_select<const D: usize>(
+        tensor: Self::Primitive<D>,
+        mask: Tensor<B, D, Bool>,
+    ) -> Self::Primitive<1>;
 
-    /// Gets the minimum elements of a tensor along an axis.
+    /// Finds the indices where the values should be inserted in the sorted sequences to keep them
+    /// sorted.
     ///
     /// # Arguments
     ///
-    /// * `tensor` - The tensor to get the minimum elements from.
-    /// * `dim` - The axis along which to get the minimum elements.
+    /// * `sorted_sequence` - The sequences sorted in ascending order along the last dimension.
+    /// * `values` - The values to search, with the same leading dimensions as `sorted_sequence`.
+    /// * `right` - If `false`, the index of the first element greater than or equal to the value
+    ///   is returned, otherwise the index of the first element strictly greater than the value.
     ///
     /// # Returns
     ///
-    /// A tensor with the same shape as the input tensor, where each element is the minimum element
-    /// of the input tensor at the corresponding index along the specified axis.
+    /// A tensor with the same shape as `values` with the insertion indices.
     ///
     /// # Remarks
     ///
@@ -1475,21 +2139,27 @@ where
     /// with static dispatch. It is not designed for direct usage by users, and not recommended to import
     /// or use this function directly.
     ///
-    /// For getting the minimum elements of a tensor along an axis, users should prefer the
-    /// [Tensor::min_dim](Tensor::min_dim) function, which is more high-level and designed for public use.
-    fn min_dim<const D: usize>(tensor: Self::Primitive<D>, dim: usize) -> Self::Primitive<D>;
+    /// For searching values in sorted sequences, users should prefer the
+    /// [Tensor::searchsorted](Tensor::searchsorted) function, which is more high-level and designed
+    /// for public use.
+    fn searchsorted<const D: usize>(
+        sorted_sequence: Self::Primitive<D>,
+        values: Self::Primitive<D>,
+        right: bool,
+    ) -> <B as Backend>::IntTensorPrimitive<D>;
 
-    /// Gets the minimum elements and indices of a tensor along an axis.
+    /// Pads the tensor along each dimension.
     ///
     /// # Arguments
     ///
-    /// * `tensor` - The tensor to get the minimum elements from.
+    /// * `tensor` - The tensor.
+    /// * `padding` - The number of elements added before and after the tensor along each
+    ///   dimension.
+    /// * `mode` - How the padded elements are filled.
     ///
     /// # Returns
     ///
-    /// A tensor with the same shape as the input tensor and corresponding indices, where
-    /// each element is the minimum element of the input tensor at the corresponding index
-    /// along the specified axis.
+    /// The padded tensor, where the size of each dimension is increased by its padding.
     ///
     /// # Remarks
     ///
@@ -1497,12 +2167,13 @@ where
     /// with static dispatch. It is not designed for direct usage by users, and not recommended to import
     /// or use this function directly.
     ///
-    /// For getting the minimum elements of a tensor along an axis, users should prefer the
-    /// [Tensor::min_dim_with_indices](Tensor::min_dim_with_indices) function, which is more high-level and designed for public use.
-    fn min_dim_with_indices<const D: usize>(
+    /// For padding a tensor, users should prefer the [Tensor::pad](Tensor::pad) function, which is
+    /// more high-level and designed for public use.
+    fn pad<const D: usize>(
         tensor: Self::Primitive<D>,
-        dim: usize,
-    ) -> (Self::Primitive<D>, B::IntTensorPrimitive<D>);
+        padding: [(usize, usize); D],
+        mode: PadMode,
+    ) -> Self::Primitive<D>;
 
     /// Clamp the tensor between the given min and max values.
     ///
@@ -1592,57 +2263,30 @@ where
 }
 
 impl<B: Backend> Numeric<B> for Int {
-    fn add<const D: usize>(
-        lhs: Self::Primitive<D>,
-        rhs: Self::Primitive<D>,
-    ) -> <Int as TensorKind<B>>::Primitive<D> {
-        B::int_add(lhs, rhs)
-    }
     fn add_scalar<const D: usize, E: ElementConversion>(
         lhs: Self::Primitive<D>,
         rhs: E,
     ) -> Self::Primitive<D> {
         B::int_add_scalar(lhs, rhs.elem())
     }
-    fn sub<const D: usize>(
-        lhs: Self::Primitive<D>,
-        rhs: Self::Primitive<D>,
-    ) -> <Int as TensorKind<B>>::Primitive<D> {
-        B::int_sub(lhs, rhs)
-    }
     fn sub_scalar<const D: usize, E: ElementConversion>(
         lhs: Self::Primitive<D>,
         rhs: E,
     ) -> Self::Primitive<D> {
         B::int_sub_scalar(lhs, rhs.elem())
     }
-    fn div<const D: usize>(
-        lhs: Self::Primitive<D>,
-        rhs: Self::Primitive<D>,
-    ) -> <Int as TensorKind<B>>::Primitive<D> {
-        B::int_div(lhs, rhs)
-    }
     fn div_scalar<const D: usize, E: ElementConversion>(
         lhs: Self::Primitive<D>,
         rhs: E,
     ) -> Self::Primitive<D> {
         B::int_div_scalar(lhs, rhs.elem())
     }
-    fn mul<const D: usize>(
-        lhs: Self::Primitive<D>,
-        rhs: Self::Primitive<D>,
-    ) -> <Int as TensorKind<B>>::Primitive<D> {
-        B::int_mul(lhs, rhs)
-    }
     fn mul_scalar<const D: usize, E: ElementConversion>(
         lhs: Self::Primitive<D>,
         rhs: E,
     ) -> Self::Primitive<D> {
         B::int_mul_scalar(lhs, rhs.elem())
     }
-    fn neg<const D: usize>(tensor: Self::Primitive<D>) -> Self::Primitive<D> {
-        B::int_neg(tensor)
-    }
     fn zeros<const D: usize>(shape: Shape<D>, device: &B::Device) -> Self::Primitive<D> {
         B::int_zeros(shape, device)
     }
@@ -1774,6 +2418,24 @@ impl<B: Backend> Numeric<B> for Int {
         B::int_scatter(dim, tensor, indices.primitive, values)
     }
 
+    fn scatter_max<const D: usize>(
+        dim: usize,
+        tensor: Self::Primitive<D>,
+        indices: Tensor<B, D, Int>,
+        values: Self::Primitive<D>,
+    ) -> Self::Primitive<D> {
+        B::int_scatter_max(dim, tensor, indices.primitive, values)
+    }
+
+    fn scatter_min<const D: usize>(
+        dim: usize,
+        tensor: Self::Primitive<D>,
+        indices: Tensor<B, D, Int>,
+        values: Self::Primitive<D>,
+    ) -> Self::Primitive<D> {
+        B::int_scatter_min(dim, tensor, indices.primitive, values)
+    }
+
     fn argmax<const D: usize>(
         tensor: Self::Primitive<D>,
         dim: usize,
@@ -1818,6 +2480,61 @@ impl<B: Backend> Numeric<B> for Int {
         B::int_min_dim_with_indices(tensor, dim)
     }
 
+    fn sort<const D: usize>(
+        tensor: Self::Primitive<D>,
+        dim: usize,
+        descending: bool,
+    ) -> Self::Primitive<D> {
+        B::int_sort(tensor, dim, descending)
+    }
+
+    fn sort_with_indices<const D: usize>(
+        tensor: Self::Primitive<D>,
+        dim: usize,
+        descending: bool,
+    ) -> (Self::Primitive<D>, <B as Backend>::IntTensorPrimitive<D>) {
+        B::int_sort_with_indices(tensor, dim, descending)
+    }
+
+    fn argsort<const D: usize>(
+        tensor: Self::Primitive<D>,
+        dim: usize,
+        descending: bool,
+    ) -> <B as Backend>::IntTensorPrimitive<D> {
+        B::int_argsort(tensor, dim, descending)
+    }
+
+    fn cumsum<const D: usize>(tensor: Self::Primitive<D>, dim: usize) -> Self::Primitive<D> {
+        B::int_cumsum(tensor, dim)
+    }
+
+    fn cumprod<const D: usize>(tensor: Self::Primitive<D>, dim: usize) -> Self::Primitive<D> {
+        B::int_cumprod(tensor, dim)
+    }
+
+    fn masked_select<const D: usize>(
+        tensor: Self::Primitive<D>,
+        mask: Tensor<B, D, Bool>,
+    ) -> Self::Primitive<1> {
+        B::int_masked_select(tensor, mask.primitive)
+    }
+
+    fn searchsorted<const D: usize>(
+        sorted_sequence: Self::Primitive<D>,
+        values: Self::Primitive<D>,
+        right: bool,
+    ) -> <B as Backend>::IntTensorPrimitive<D> {
+        B::int_searchsorted(sorted_sequence, values, right)
+    }
+
+    fn pad<const D: usize>(
+        tensor: Self::Primitive<D>,
+        padding: [(usize, usize); D],
+        mode: PadMode,
+    ) -> Self::Primitive<D> {
+        B::int_pad(tensor, padding, mode)
+    }
+
     fn clamp<const D: usize>(
         tensor: Self::Primitive<D>,
         min: B::IntElem,
@@ -1846,57 +2563,30 @@ impl<B: Backend> Numeric<B> for Int {
 }
 
 impl<B: Backend> Numeric<B> for Float {
-    fn add<const D: usize>(
-        lhs: Self::Primitive<D>,
-        rhs: Self::Primitive<D>,
-    ) -> <Float as TensorKind<B>>::Primitive<D> {
-        B::add(lhs, rhs)
-    }
     fn add_scalar<const D: usize, E: ElementConversion>(
         lhs: Self::Primitive<D>,
         rhs: E,
     ) -> Self::Primitive<D> {
         B::add_scalar(lhs, rhs.elem())
     }
-    fn sub<const D: usize>(
-        lhs: Self::Primitive<D>,
-        rhs: Self::Primitive<D>,
-    ) -> <Float as TensorKind<B>>::Primitive<D> {
-        B::sub(lhs, rhs)
-    }
     fn sub_scalar<const D: usize, E: ElementConversion>(
         lhs: Self::Primitive<D>,
         rhs: E,
     ) -> Self::Primitive<D> {
         B::sub_scalar(lhs, rhs.elem())
     }
-    fn div<const D: usize>(
-        lhs: Self::Primitive<D>,
-        rhs: Self::Primitive<D>,
-    ) -> <Float as TensorKind<B>>::Primitive<D> {
-        B::div(lhs, rhs)
-    }
     fn div_scalar<const D: usize, E: ElementConversion>(
         lhs: Self::Primitive<D>,
         rhs: E,
     ) -> Self::Primitive<D> {
         B::div_scalar(lhs, rhs.elem())
     }
-    fn mul<const D: usize>(
-        lhs: Self::Primitive<D>,
-        rhs: Self::Primitive<D>,
-    ) -> <Float as TensorKind<B>>::Primitive<D> {
-        B::mul(lhs, rhs)
-    }
     fn mul_scalar<const D: usize, E: ElementConversion>(
         lhs: Self::Primitive<D>,
         rhs: E,
     ) -> Self::Primitive<D> {
         B::mul_scalar(lhs, rhs.elem())
     }
-    fn neg<const D: usize>(tensor: Self::Primitive<D>) -> Self::Primitive<D> {
-        B::neg(tensor)
-    }
     fn zeros<const D: usize>(shape: Shape<D>, device: &B::Device) -> Self::Primitive<D> {
         B::zeros(shape, device)
     }
@@ -2029,6 +2719,24 @@ impl<B: Backend> Numeric<B> for Float {
         B::scatter(dim, tensor, indices.primitive, values)
     }
 
+    fn scatter_max<const D: usize>(
+        dim: usize,
+        tensor: Self::Primitive<D>,
+        indices: Tensor<B, D, Int>,
+        values: Self::Primitive<D>,
+    ) -> Self::Primitive<D> {
+        B::scatter_max(dim, tensor, indices.primitive, values)
+    }
+
+    fn scatter_min<const D: usize>(
+        dim: usize,
+        tensor: Self::Primitive<D>,
+        indices: Tensor<B, D, Int>,
+        values: Self::Primitive<D>,
+    ) -> Self::Primitive<D> {
+        B::scatter_min(dim, tensor, indices.primitive, values)
+    }
+
     fn argmax<const D: usize>(
         tensor: Self::Primitive<D>,
         dim: usize,
@@ -2073,6 +2781,61 @@ impl<B: Backend> Numeric<B> for Float {
         B::min_dim_with_indices(tensor, dim)
     }
 
+    fn sort<const D: usize>(
+        tensor: Self::Primitive<D>,
+        dim: usize,
+        descending: bool,
+    ) -> Self::Primitive<D> {
+        B::sort(tensor, dim, descending)
+    }
+
+    fn sort_with_indices<const D: usize>(
+        tensor: Self::Primitive<D>,
+        dim: usize,
+        descending: bool,
+    ) -> (Self::Primitive<D>, <B as Backend>::IntTensorPrimitive<D>) {
+        B::sort_with_indices(tensor, dim, descending)
+    }
+
+    fn argsort<const D: usize>(
+        tensor: Self::Primitive<D>,
+        dim: usize,
+        descending: bool,
+    ) -> <B as Backend>::IntTensorPrimitive<D> {
+        B::argsort(tensor, dim, descending)
+    }
+
+    fn cumsum<const D: usize>(tensor: Self::Primitive<D>, dim: usize) -> Self::Primitive<D> {
+        B::cumsum(tensor, dim)
+    }
+
+    fn cumprod<const D: usize>(tensor: Self::Primitive<D>, dim: usize) -> Self::Primitive<D> {
+        B::cumprod(tensor, dim)
+    }
+
+    fn masked_select<const D: usize>(
+        tensor: Self::Primitive<D>,
+        mask: Tensor<B, D, Bool>,
+    ) -> Self::Primitive<1> {
+        B::masked_select(tensor, mask.primitive)
+    }
+
+    fn searchsorted<const D: usize>(
+        sorted_sequence: Self::Primitive<D>,
+        values: Self::Primitive<D>,
+        right: bool,
+    ) -> <B as Backend>::IntTensorPrimitive<D> {
+        B::searchsorted(sorted_sequence, values, right)
+    }
+
+    fn pad<const D: usize>(
+        tensor: Self::Primitive<D>,
+        padding: [(usize, usize); D],
+        mode: PadMode,
+    ) -> Self::Primitive<D> {
+        B::pad(tensor, padding, mode)
+    }
+
     fn clamp<const D: usize>(
         tensor: Self::Primitive<D>,
         min: B::FloatElem,
@@ -2103,7 +2866,7 @@ impl<B: Backend> Numeric<B> for Float {
 impl<B, const D: usize, K> core::ops::Add<Self> for Tensor<B, D, K>
 where
     B: Backend,
-    K: Numeric<B>,
+    K: Arithmetic<B>,
     K::Elem: Element,
 {
     type Output = Self;
@@ -2130,7 +2893,7 @@ where
 impl<B, const D: usize, K> core::ops::Sub<Tensor<B, D, K>> for Tensor<B, D, K>
 where
     B: Backend,
-    K: Numeric<B>,
+    K: Arithmetic<B>,
     K::Elem: Element,
 {
     type Output = Self;
@@ -2157,7 +2920,7 @@ where
 impl<B, const D: usize, K> core::ops::Div<Tensor<B, D, K>> for Tensor<B, D, K>
 where
     B: Backend,
-    K: Numeric<B>,
+    K: Arithmetic<B>,
     K::Elem: Element,
 {
     type Output = Self;
@@ -2184,7 +2947,7 @@ where
 impl<B, const D: usize, K> core::ops::Mul<Tensor<B, D, K>> for Tensor<B, D, K>
 where
     B: Backend,
-    K: Numeric<B>,
+    K: Arithmetic<B>,
     K::Elem: Element,
 {
     type Output = Self;
@@ -2211,7 +2974,7 @@ where
 impl<B, const D: usize, K> core::ops::Neg for Tensor<B, D, K>
 where
     B: Backend,
-    K: Numeric<B>,
+    K: Arithmetic<B>,
     K::Elem: Element,
 {
     type Output = Self;
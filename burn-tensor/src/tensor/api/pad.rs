@@ -0,0 +1,111 @@
+use crate::{backend::Backend, Element, Int, Numeric, Tensor, TensorKind};
+use alloc::vec::Vec;
+
+/// How the padded elements of a tensor are filled.
+#[derive(Debug, Clone, Copy, PartialEq, serde::Serialize, serde::Deserialize)]
+pub enum PadMode {
+    /// Fill the padding with the given value.
+    Constant(f64),
+
+    /// Mirror the elements next to the border, without repeating the border itself, so the
+    /// padding of a dimension should be lower than its size.
+    Reflect,
+
+    /// Repeat the element at the border.
+    Replicate,
+}
+
+/// Pads the tensor along each dimension.
+///
+/// # Arguments
+///
+/// * `tensor` - The tensor.
+/// * `padding` - The number of elements added before and after the tensor along each dimension.
+/// * `mode` - How the padded elements are filled.
+///
+/// # Returns
+///
+/// The padded tensor, where the size of each dimension is increased by its padding.
+///
+/// # Remarks
+///
+/// The padding is built from slices, selections and fills of the tensor, concatenated along each
+/// padded dimension, so the default implementations calling this function are differentiable.
+/// Backends with a native implementation should override them.
+pub fn pad<B: Backend, const D: usize, K: TensorKind<B> + Numeric<B>>(
+    tensor: K::Primitive<D>,
+    padding: [(usize, usize); D],
+    mode: PadMode,
+) -> K::Primitive<D>
+where
+    K::Elem: Element,
+{
+    let mut tensor = Tensor::<B, D, K>::from_primitive(tensor);
+
+    for (dim, (before, after)) in padding.into_iter().enumerate() {
+        if before == 0 && after == 0 {
+            continue;
+        }
+
+        let size = tensor.dims()[dim];
+        let mut parts = Vec::with_capacity(3);
+
+        if before > 0 {
+            parts.push(pad_border(&tensor, dim, before, size, mode, false));
+        }
+
+        let padded_after = match after > 0 {
+            true => Some(pad_border(&tensor, dim, after, size, mode, true)),
+            false => None,
+        };
+
+        parts.push(tensor);
+        parts.extend(padded_after);
+
+        tensor = Tensor::cat(parts, dim);
+    }
+
+    tensor.into_primitive()
+}
+
+/// Creates the padding of a single border of a dimension.
+fn pad_border<B: Backend, const D: usize, K: TensorKind<B> + Numeric<B>>(
+    tensor: &Tensor<B, D, K>,
+    dim: usize,
+    length: usize,
+    size: usize,
+    mode: PadMode,
+    end: bool,
+) -> Tensor<B, D, K>
+where
+    K::Elem: Element,
+{
+    let device = tensor.device();
+
+    match mode {
+        PadMode::Constant(value) => {
+            let mut shape = tensor.shape();
+            shape.dims[dim] = length;
+
+            Tensor::full(shape, value, &device)
+        }
+        PadMode::Reflect => {
+            // The elements are selected from the border inward, skipping the border itself.
+            let indices = Tensor::<B, 1, Int>::arange(0..length, &device).neg();
+            let indices = match end {
+                true => indices.add_scalar(size as i64 - 2),
+                false => indices.add_scalar(length as i64),
+            };
+
+            tensor.clone().select(dim, indices)
+        }
+        PadMode::Replicate => {
+            let start = match end {
+                true => size - 1,
+                false => 0,
+            };
+
+            tensor.clone().narrow(dim, start, 1).repeat(dim, length)
+        }
+    }
+}
@@ -0,0 +1,136 @@
+use crate::check::TensorCheck;
+use crate::{backend::Backend, ops::IntTensor, BasicOps, Data, Element, ElementConversion, Int};
+use crate::{check, ElementComparison, Shape, TensorKind};
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+
+/// Assign the maximum of the elements of the tensor and of the values scattered at the given
+/// indices along the dimension.
+///
+/// # Arguments
+///
+/// * `dim` - The dimension to scatter into.
+/// * `tensor` - The tensor to scatter into.
+/// * `indices` - The indices to scatter into.
+/// * `values` - The values to scatter.
+///
+/// # Returns
+///
+/// The tensor with the maximum of its elements and of the values scattered into them.
+///
+/// # Remarks
+///
+/// The tensor, the indices and the values are read back to the host and the result is uploaded
+/// to the device again, which waits for every pending operation on lazy backends. This is only
+/// meant as a reference for backends without a native implementation, which should override the
+/// default implementation calling this function.
+pub fn scatter_max<B: Backend, const D: usize, K: TensorKind<B> + BasicOps<B>>(
+    dim: usize,
+    tensor: K::Primitive<D>,
+    indices: IntTensor<B, D>,
+    values: K::Primitive<D>,
+) -> K::Primitive<D>
+where
+    K::Elem: Element,
+{
+    scatter_reduce::<B, D, K>(
+        "ScatterMax",
+        dim,
+        tensor,
+        indices,
+        values,
+        Ordering::Greater,
+    )
+}
+
+/// Assign the minimum of the elements of the tensor and of the values scattered at the given
+/// indices along the dimension.
+///
+/// # Arguments
+///
+/// * `dim` - The dimension to scatter into.
+/// * `tensor` - The tensor to scatter into.
+/// * `indices` - The indices to scatter into.
+/// * `values` - The values to scatter.
+///
+/// # Returns
+///
+/// The tensor with the minimum of its elements and of the values scattered into them.
+///
+/// # Remarks
+///
+/// The tensor, the indices and the values are read back to the host and the result is uploaded
+/// to the device again, which waits for every pending operation on lazy backends. This is only
+/// meant as a reference for backends without a native implementation, which should override the
+/// default implementation calling this function.
+pub fn scatter_min<B: Backend, const D: usize, K: TensorKind<B> + BasicOps<B>>(
+    dim: usize,
+    tensor: K::Primitive<D>,
+    indices: IntTensor<B, D>,
+    values: K::Primitive<D>,
+) -> K::Primitive<D>
+where
+    K::Elem: Element,
+{
+    scatter_reduce::<B, D, K>("ScatterMin", dim, tensor, indices, values, Ordering::Less)
+}
+
+/// Scatter the values on the host, replacing the current element when the value compares to it
+/// with the `replace` ordering.
+fn scatter_reduce<B: Backend, const D: usize, K: TensorKind<B> + BasicOps<B>>(
+    ops: &str,
+    dim: usize,
+    tensor: K::Primitive<D>,
+    indices: IntTensor<B, D>,
+    values: K::Primitive<D>,
+    replace: Ordering,
+) -> K::Primitive<D>
+where
+    K::Elem: Element,
+{
+    let device = K::device(&tensor);
+    let mut data = read::<B, D, K>(tensor);
+    let indices = read::<B, D, Int>(indices);
+    let values = read::<B, D, K>(values);
+
+    let data_strides = strides(&data.shape);
+    let indices_strides = strides(&indices.shape);
+
+    for (position, value) in values.value.iter().enumerate() {
+        let index = indices.value[position].elem::<i64>();
+        check!(TensorCheck::scatter_index(
+            ops,
+            dim,
+            index,
+            data.shape.dims[dim]
+        ));
+
+        let index = index as usize;
+        let offset = (0..D)
+            .map(|i| match i == dim {
+                true => index * data_strides[i],
+                false => position / indices_strides[i] % indices.shape.dims[i] * data_strides[i],
+            })
+            .sum::<usize>();
+
+        if value.elem_cmp(&data.value[offset]) == replace {
+            data.value[offset] = *value;
+        }
+    }
+
+    K::from_data(data, &device)
+}
+
+fn read<B: Backend, const D: usize, K: TensorKind<B> + BasicOps<B>>(
+    tensor: K::Primitive<D>,
+) -> Data<K::Elem, D> {
+    K::into_data(tensor)
+        .read_sync()
+        .expect("Can't scatter into a tensor that can only be read asynchronously")
+}
+
+fn strides<const D: usize>(shape: &Shape<D>) -> Vec<usize> {
+    (0..D)
+        .map(|i| shape.dims[i + 1..].iter().product())
+        .collect()
+}
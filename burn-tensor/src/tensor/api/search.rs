@@ -0,0 +1,55 @@
+use crate::{backend::Backend, ops::IntTensor, Element, Int, Numeric, Tensor, TensorKind};
+
+/// Finds the indices where the values should be inserted in the sorted sequences to keep them
+/// sorted.
+///
+/// # Arguments
+///
+/// * `sorted_sequence` - The sequences sorted in ascending order along the last dimension.
+/// * `values` - The values to search, with the same leading dimensions as `sorted_sequence`.
+/// * `right` - If `false`, the index of the first element greater than or equal to the value is
+///   returned, otherwise the index of the first element strictly greater than the value.
+///
+/// # Returns
+///
+/// A tensor with the same shape as `values` with the insertion indices.
+///
+/// # Remarks
+///
+/// Each value is compared with every element of its sequence and the insertion index is the number
+/// of elements lower than the value (or lower than or equal to with `right`), so the intermediate
+/// comparisons use `O(n * m)` memory for `n` values and sequences of length `m`. Backends with a
+/// native binary search should override the default implementation calling this function.
+pub fn searchsorted<B: Backend, const D: usize, K: TensorKind<B> + Numeric<B>>(
+    sorted_sequence: K::Primitive<D>,
+    values: K::Primitive<D>,
+    right: bool,
+) -> IntTensor<B, D>
+where
+    K::Elem: Element,
+{
+    let sorted_sequence = Tensor::<B, D, K>::from_primitive(sorted_sequence);
+    let values = Tensor::<B, D, K>::from_primitive(values);
+    let shape = values.shape();
+    let device = values.device();
+
+    let length = sorted_sequence.dims()[D - 1];
+    let num_values = shape.dims[D - 1];
+    let num_lanes = shape.num_elements() / usize::max(num_values, 1);
+
+    if length == 0 || num_values == 0 {
+        return Tensor::<B, D, Int>::zeros(shape, &device).into_primitive();
+    }
+
+    let sorted_sequence = sorted_sequence
+        .reshape([num_lanes, 1, length])
+        .repeat(1, num_values);
+    let values = values.reshape([num_lanes, num_values, 1]).repeat(2, length);
+
+    let before = match right {
+        true => values.greater_equal(sorted_sequence),
+        false => values.greater(sorted_sequence),
+    };
+
+    before.int().sum_dim(2).reshape(shape).into_primitive()
+}
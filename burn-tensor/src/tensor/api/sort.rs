@@ -0,0 +1,84 @@
+use crate::{
+    backend::Backend, ops::IntTensor, BasicOps, Data, Element, ElementConversion, TensorKind,
+};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+
+/// Sort the elements of the tensor along the given dimension, returning the sorted elements and
+/// their indices in the input tensor.
+///
+/// # Arguments
+///
+/// * `tensor` - The tensor.
+/// * `dim` - The dimension along which the tensor will be sorted.
+/// * `descending` - If the elements are sorted in descending order.
+///
+/// # Returns
+///
+/// A tuple with the sorted tensor and the indices of the sorted elements.
+///
+/// # Remarks
+///
+/// The sort is stable, equal elements keep their relative order, and NaN values are greater than
+/// every other value whatever their sign, so they are placed last in ascending order and first in
+/// descending order. The tensor is read back to the host and sorted there, so backends with a
+/// native sort should override the default implementations calling this function.
+pub fn sort_with_indices<B: Backend, const D: usize, K: TensorKind<B> + BasicOps<B>>(
+    tensor: K::Primitive<D>,
+    dim: usize,
+    descending: bool,
+) -> (K::Primitive<D>, IntTensor<B, D>)
+where
+    K::Elem: Element,
+{
+    let device = K::device(&tensor);
+    let data = K::into_data(tensor)
+        .read_sync()
+        .expect("Can't sort a tensor that can only be read asynchronously");
+    let shape = data.shape.clone();
+
+    let size = shape.dims[dim];
+    let stride = shape.dims[dim + 1..].iter().product::<usize>();
+    let num_lanes = shape.num_elements() / usize::max(size, 1);
+
+    let mut values = data.value.clone();
+    let mut indices = vec![0.elem::<B::IntElem>(); data.value.len()];
+    let mut order = Vec::with_capacity(size);
+
+    for lane in 0..num_lanes {
+        let offset = (lane / stride) * stride * size + lane % stride;
+
+        order.clear();
+        order.extend(0..size);
+        order.sort_by(|&a, &b| {
+            let a = data.value[offset + a * stride].elem::<f64>();
+            let b = data.value[offset + b * stride].elem::<f64>();
+
+            match descending {
+                true => compare(b, a),
+                false => compare(a, b),
+            }
+        });
+
+        for (position, &index) in order.iter().enumerate() {
+            values[offset + position * stride] = data.value[offset + index * stride];
+            indices[offset + position * stride] = (index as i64).elem();
+        }
+    }
+
+    let values = K::from_data(Data::new(values, shape.clone()), &device);
+    let indices = B::int_from_data(Data::new(indices, shape), &device);
+
+    (values, indices)
+}
+
+/// Compare two elements, NaN values being greater than every other value.
+fn compare(a: f64, b: f64) -> Ordering {
+    match (a.is_nan(), b.is_nan()) {
+        (true, true) => Ordering::Equal,
+        (true, false) => Ordering::Greater,
+        (false, true) => Ordering::Less,
+        (false, false) => a.partial_cmp(&b).unwrap(),
+    }
+}
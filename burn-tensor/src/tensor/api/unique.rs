@@ -0,0 +1,101 @@
+#![allow(clippy::single_range_in_vec_init)]
+
+use crate::{backend::Backend, ops::IntTensor, Bool, Data, ElementConversion, Int, Tensor};
+use alloc::vec;
+
+/// Gets the unique elements of the tensor in ascending order, with the index of each element in
+/// the unique elements and the number of occurrences of each unique element.
+///
+/// # Arguments
+///
+/// * `tensor` - The tensor.
+///
+/// # Returns
+///
+/// A tuple with the sorted unique elements, the inverse indices, of the same shape as the tensor,
+/// and the counts, of the same length as the unique elements.
+///
+/// # Remarks
+///
+/// The elements are sorted and compared with their predecessor on the device. Only the mask of the
+/// first occurrences is read back to the host to get the number of unique elements, which waits
+/// for every pending operation on lazy backends. Backends with a native implementation should
+/// override the default implementation calling this function.
+pub fn unique<B: Backend, const D: usize>(
+    tensor: IntTensor<B, D>,
+) -> (IntTensor<B, 1>, IntTensor<B, D>, IntTensor<B, 1>) {
+    let tensor = Tensor::<B, D, Int>::from_primitive(tensor);
+    let shape = tensor.shape();
+    let device = tensor.device();
+    let n = shape.num_elements();
+
+    let (sorted, order) = tensor.reshape([n]).sort_with_indices(0, false);
+
+    // The first element of each run of equal sorted elements starts a new unique element.
+    let first = Tensor::<B, 1, Bool>::from_bool(Data::from([true]), &device);
+    let is_first = match n {
+        1 => first,
+        _ => {
+            let changes = sorted
+                .clone()
+                .slice([1..n])
+                .equal(sorted.clone().slice([0..n - 1]))
+                .bool_not();
+
+            Tensor::cat(vec![first, changes], 0)
+        }
+    };
+
+    let groups = is_first.clone().int().cumsum(0).sub_scalar(1);
+    let values = sorted.masked_select(is_first);
+    let num_unique = values.dims()[0];
+
+    let inverse = Tensor::<B, 1, Int>::zeros([n], &device)
+        .scatter(0, order, groups.clone())
+        .reshape(shape);
+    let counts = Tensor::<B, 1, Int>::zeros([num_unique], &device).scatter(
+        0,
+        groups,
+        Tensor::ones([n], &device),
+    );
+
+    (
+        values.into_primitive(),
+        inverse.into_primitive(),
+        counts.into_primitive(),
+    )
+}
+
+/// Counts the number of occurrences of each value of a tensor of non-negative integers.
+///
+/// # Arguments
+///
+/// * `tensor` - The tensor of non-negative integers.
+/// * `min_length` - The minimum number of bins.
+///
+/// # Returns
+///
+/// The counts of the values from zero to the maximum of the tensor and `min_length - 1`.
+///
+/// # Remarks
+///
+/// The maximum of the tensor is read back to the host to get the number of bins, which waits for
+/// every pending operation on lazy backends. Backends with a native implementation should override
+/// the default implementation calling this function.
+pub fn bincount<B: Backend>(tensor: IntTensor<B, 1>, min_length: usize) -> IntTensor<B, 1> {
+    let tensor = Tensor::<B, 1, Int>::from_primitive(tensor);
+    let device = tensor.device();
+    let n = tensor.dims()[0];
+
+    let num_bins = match n {
+        0 => min_length,
+        _ => {
+            let max = tensor.clone().max().into_scalar().elem::<i64>();
+            usize::max(max as usize + 1, min_length)
+        }
+    };
+
+    Tensor::<B, 1, Int>::zeros([num_bins], &device)
+        .scatter(0, tensor, Tensor::ones([n], &device))
+        .into_primitive()
+}
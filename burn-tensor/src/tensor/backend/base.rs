@@ -1,3 +1,4 @@
+use alloc::boxed::Box;
 use alloc::string::String;
 
 use crate::ops::*;
@@ -64,7 +65,7 @@ pub trait Backend:
     + 'static
 {
     /// Device type.
-    type Device: Clone + Default + PartialEq + core::fmt::Debug + Send + Sync;
+    type Device: Clone + Default + PartialEq + core::fmt::Debug + Send + Sync + 'static;
 
     /// Pointer to another backend that have a full precision float element type
     type FullPrecisionBackend: Backend<FloatElem = Self::FullPrecisionElem, Device = Self::Device>;
@@ -97,8 +98,29 @@ pub trait Backend:
 
     /// Sync the backend, ensure that all computation are finished.
     fn sync(_device: &Self::Device) {}
+
+    /// Operations that don't have a deterministic implementation on the given device, even when
+    /// [deterministic algorithms](crate::backend::set_deterministic) are selected.
+    fn non_deterministic_ops(_device: &Self::Device) -> &'static [&'static str] {
+        &[]
+    }
+}
+
+/// Trait that allows a backend to support complex tensors.
+///
+/// Complex tensors are created from and decomposed into float tensors of the same backend, see
+/// [Complex](crate::Complex).
+pub trait ComplexBackend: Backend + ComplexTensorOps<Self> {
+    /// Tensor primitive to be used for all complex operations.
+    type ComplexTensorPrimitive<const D: usize>: Clone + Send + Sync + 'static + core::fmt::Debug;
+    /// Complex element type.
+    type ComplexElem: Element;
 }
 
+/// Function applied on the gradient of a tensor during the backward pass.
+pub type GradHook<B, const D: usize> =
+    Box<dyn Fn(FloatTensor<B, D>) -> FloatTensor<B, D> + Send + Sync>;
+
 /// Trait that allows a backend to support autodiff.
 pub trait AutodiffBackend: Backend {
     /// The inner backend type.
@@ -123,6 +145,27 @@ pub trait AutodiffBackend: Backend {
     /// The gradients.
     fn backward<const D: usize>(tensor: FloatTensor<Self, D>) -> Self::Gradients;
 
+    /// Backward pass keeping the graph alive.
+    ///
+    /// The graph can be used for other backward passes, from the same tensor or from other
+    /// tensors sharing a part of the graph, without recomputing the forward pass.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor is the last node of computational graph where the gradients are computed.
+    ///
+    /// # Returns
+    ///
+    /// The gradients.
+    ///
+    /// # Notes
+    ///
+    /// The default implementation runs a regular backward pass on a copy of the tensor, backends
+    /// that free the graph during the backward pass should override it.
+    fn backward_retain<const D: usize>(tensor: &FloatTensor<Self, D>) -> Self::Gradients {
+        Self::backward(tensor.clone())
+    }
+
     /// Returns the gradients of a tensor.
     ///
     /// # Arguments
@@ -167,6 +210,45 @@ pub trait AutodiffBackend: Backend {
         grad: FloatTensor<Self::InnerBackend, D>,
     );
 
+    /// Execute the function without building the autodiff graph.
+    ///
+    /// Operations executed inside the function are computed on the inner backend, and their
+    /// outputs don't require gradients.
+    ///
+    /// # Arguments
+    ///
+    /// * `func` - The function to execute.
+    ///
+    /// # Returns
+    ///
+    /// The output of the function.
+    ///
+    /// # Notes
+    ///
+    /// The default implementation only executes the function, backends that can disable the
+    /// graph construction should override it.
+    fn no_grad<R, F: FnOnce() -> R>(func: F) -> R {
+        func()
+    }
+
+    /// Register a hook executed on the gradient of the tensor during the backward pass.
+    ///
+    /// The hook receives the gradient once it is fully accumulated and returns the gradient
+    /// that is propagated to the parents of the tensor and stored in the gradients container.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to register the hook on.
+    /// * `hook` - The function applied on the gradient.
+    ///
+    /// # Returns
+    ///
+    /// The tensor with the hook registered.
+    fn register_grad_hook<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        hook: GradHook<Self::InnerBackend, D>,
+    ) -> FloatTensor<Self, D>;
+
     /// Returns the tensor with inner backend type.
     ///
     /// # Arguments
@@ -0,0 +1,18 @@
+use core::sync::atomic::{AtomicBool, Ordering};
+
+static DETERMINISTIC: AtomicBool = AtomicBool::new(false);
+
+/// Select deterministic algorithm variants in every backend.
+///
+/// When enabled, backends avoid algorithms whose results can change between runs on the same
+/// inputs, such as kernels selected by autotuning or reductions with an unspecified order, at the
+/// cost of performance. Some operations can't be made deterministic on some devices, they are
+/// reported by [Backend::non_deterministic_ops](crate::backend::Backend::non_deterministic_ops).
+pub fn set_deterministic(enabled: bool) {
+    DETERMINISTIC.store(enabled, Ordering::Relaxed);
+}
+
+/// If deterministic algorithms are selected, see [set_deterministic].
+pub fn is_deterministic() -> bool {
+    DETERMINISTIC.load(Ordering::Relaxed)
+}
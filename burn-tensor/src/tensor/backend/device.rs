@@ -0,0 +1,170 @@
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use burn_common::stub::Mutex;
+use core::any::{Any, TypeId};
+
+type DeviceEntry = (TypeId, Box<dyn Any + Send + Sync>);
+
+static DEFAULT_DEVICES: Mutex<Vec<DeviceEntry>> = Mutex::new(Vec::new());
+
+#[cfg(feature = "std")]
+std::thread_local! {
+    static SCOPED_DEVICES: core::cell::RefCell<Vec<DeviceEntry>> =
+        core::cell::RefCell::new(Vec::new());
+}
+
+/// Set the device used when tensors are created without an explicit device.
+///
+/// The default device is registered per device type, so it applies to every backend using that
+/// device, including decorator backends such as autodiff. It is used, for instance, when loading
+/// records or deserializing tensors.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// burn::set_default_device(WgpuDevice::DiscreteGpu(1));
+///
+/// // Loaded on the second discrete GPU.
+/// let model = config.init_with::<Wgpu>(record);
+/// ```
+pub fn set_default_device<D>(device: D)
+where
+    D: Clone + Send + Sync + 'static,
+{
+    let mut devices = DEFAULT_DEVICES.lock().unwrap();
+    set_entry(&mut devices, device);
+}
+
+/// The device used when tensors are created without an explicit device.
+///
+/// The device of the innermost [guard](DeviceGuard) of the current thread is returned first, then
+/// the device registered with [set_default_device], falling back on the
+/// [default](Default::default) of the device type.
+pub fn default_device<D>() -> D
+where
+    D: Clone + Default + Send + Sync + 'static,
+{
+    #[cfg(feature = "std")]
+    if let Some(device) = SCOPED_DEVICES.with(|devices| get_entry::<D>(&devices.borrow())) {
+        return device;
+    }
+
+    let devices = DEFAULT_DEVICES.lock().unwrap();
+    get_entry(&devices).unwrap_or_default()
+}
+
+/// Overwrite the default device of the current thread until the guard is dropped.
+///
+/// Guards can be nested, dropping a guard restores the device that was active when it was
+/// created.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// for (index, device) in devices.into_iter().enumerate() {
+///     std::thread::spawn(move || {
+///         let _guard = DeviceGuard::new(device);
+///         // Every tensor created without an explicit device in this thread uses `device`.
+///         train(index);
+///     });
+/// }
+/// ```
+#[cfg(feature = "std")]
+pub struct DeviceGuard<D: Clone + Send + Sync + 'static> {
+    previous: Option<D>,
+    // The guard modifies a thread local state, so it must be dropped on the same thread.
+    _not_send: core::marker::PhantomData<*const ()>,
+}
+
+#[cfg(feature = "std")]
+impl<D: Clone + Send + Sync + 'static> DeviceGuard<D> {
+    /// Use the given device as the default device of the current thread.
+    pub fn new(device: D) -> Self {
+        let previous = SCOPED_DEVICES.with(|devices| {
+            let mut devices = devices.borrow_mut();
+            let previous = get_entry::<D>(&devices);
+            set_entry(&mut devices, device);
+            previous
+        });
+
+        Self {
+            previous,
+            _not_send: core::marker::PhantomData,
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<D: Clone + Send + Sync + 'static> Drop for DeviceGuard<D> {
+    fn drop(&mut self) {
+        let previous = self.previous.take();
+
+        // The thread local might already be destroyed when the guard is dropped during the
+        // thread teardown, there is nothing to restore in that case.
+        let _ = SCOPED_DEVICES.try_with(|devices| {
+            let mut devices = devices.borrow_mut();
+            match previous {
+                Some(device) => set_entry(&mut devices, device),
+                None => devices.retain(|(id, _)| *id != TypeId::of::<D>()),
+            }
+        });
+    }
+}
+
+fn get_entry<D: Clone + 'static>(devices: &[DeviceEntry]) -> Option<D> {
+    devices
+        .iter()
+        .find(|(id, _)| *id == TypeId::of::<D>())
+        .and_then(|(_, device)| device.downcast_ref::<D>())
+        .cloned()
+}
+
+fn set_entry<D: Send + Sync + 'static>(devices: &mut Vec<DeviceEntry>, device: D) {
+    let device = Box::new(device);
+
+    match devices.iter_mut().find(|(id, _)| *id == TypeId::of::<D>()) {
+        Some((_, entry)) => *entry = device,
+        None => devices.push((TypeId::of::<D>(), device)),
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+
+    #[derive(Clone, Default, Debug, PartialEq)]
+    struct TestDevice(usize);
+
+    #[derive(Clone, Default, Debug, PartialEq)]
+    struct OtherDevice(usize);
+
+    #[test]
+    fn default_device_falls_back_on_device_default() {
+        assert_eq!(default_device::<OtherDevice>(), OtherDevice(0));
+    }
+
+    #[test]
+    fn guards_overwrite_the_default_device_until_dropped() {
+        set_default_device(TestDevice(1));
+        assert_eq!(default_device::<TestDevice>(), TestDevice(1));
+
+        {
+            let _guard = DeviceGuard::new(TestDevice(2));
+            assert_eq!(default_device::<TestDevice>(), TestDevice(2));
+
+            {
+                let _guard = DeviceGuard::new(TestDevice(3));
+                assert_eq!(default_device::<TestDevice>(), TestDevice(3));
+            }
+
+            assert_eq!(default_device::<TestDevice>(), TestDevice(2));
+            // Other threads aren't affected by the guard.
+            let device = std::thread::spawn(default_device::<TestDevice>)
+                .join()
+                .unwrap();
+            assert_eq!(device, TestDevice(1));
+        }
+
+        assert_eq!(default_device::<TestDevice>(), TestDevice(1));
+    }
+}
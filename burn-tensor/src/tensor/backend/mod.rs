@@ -1,5 +1,10 @@
 mod base;
+mod determinism;
+mod device;
+
 pub use base::*;
+pub use determinism::*;
+pub use device::*;
 
 // Not needed for now, useful for different tensor memory layout
 // pub mod conversion;
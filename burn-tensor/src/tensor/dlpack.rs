@@ -0,0 +1,461 @@
+//! [DLPack](https://dmlc.github.io/dlpack/latest/) protocol to exchange tensors with other
+//! frameworks, such as PyTorch, JAX and CuPy, in the same process.
+//!
+//! A [DLPackTensor] owns a `DLManagedTensor`: exporting it with [DLPackTensor::into_raw] gives
+//! the ownership to the consumer, which calls the deleter once done with the memory. Backends
+//! supporting the protocol implement [DLPackBackend].
+
+use crate::{
+    backend::Backend, bf16, f16, ops::FloatTensor, Data, Element, ElementConversion, Float, Shape,
+    Tensor,
+};
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use core::any::TypeId;
+use core::ffi::c_void;
+use core::fmt::Display;
+use core::ptr::NonNull;
+
+/// Type of the device holding the memory of a DLPack tensor (`DLDeviceType`).
+pub mod device_type {
+    /// CPU device.
+    pub const CPU: i32 = 1;
+    /// CUDA GPU device.
+    pub const CUDA: i32 = 2;
+    /// Pinned CUDA CPU memory.
+    pub const CUDA_HOST: i32 = 3;
+    /// Vulkan buffer.
+    pub const VULKAN: i32 = 7;
+    /// Metal buffer.
+    pub const METAL: i32 = 8;
+    /// ROCm GPU device.
+    pub const ROCM: i32 = 10;
+}
+
+/// Device holding the memory of a DLPack tensor (`DLDevice`).
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DLDevice {
+    /// The [type](device_type) of the device.
+    pub device_type: i32,
+    /// The index of the device.
+    pub device_id: i32,
+}
+
+impl DLDevice {
+    /// The CPU device.
+    pub const fn cpu() -> Self {
+        Self {
+            device_type: device_type::CPU,
+            device_id: 0,
+        }
+    }
+}
+
+/// Element type of a DLPack tensor (`DLDataType`).
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DLDataType {
+    /// Type code: 0 for signed integers, 1 for unsigned integers, 2 for floats and 4 for bfloats.
+    pub code: u8,
+    /// Number of bits of an element.
+    pub bits: u8,
+    /// Number of lanes, 1 for non vectorized types.
+    pub lanes: u16,
+}
+
+impl DLDataType {
+    /// Signed integer type code.
+    pub const INT: u8 = 0;
+    /// Unsigned integer type code.
+    pub const UINT: u8 = 1;
+    /// Float type code.
+    pub const FLOAT: u8 = 2;
+    /// Brain float type code.
+    pub const BFLOAT: u8 = 4;
+
+    /// Create a new data type with a single lane.
+    pub const fn new(code: u8, bits: u8) -> Self {
+        Self {
+            code,
+            bits,
+            lanes: 1,
+        }
+    }
+
+    /// The data type of the element `E`, if supported by the protocol.
+    pub fn of<E: Element>() -> Option<Self> {
+        let id = TypeId::of::<E>();
+        let dtype = if id == TypeId::of::<f64>() {
+            Self::new(Self::FLOAT, 64)
+        } else if id == TypeId::of::<f32>() {
+            Self::new(Self::FLOAT, 32)
+        } else if id == TypeId::of::<f16>() {
+            Self::new(Self::FLOAT, 16)
+        } else if id == TypeId::of::<bf16>() {
+            Self::new(Self::BFLOAT, 16)
+        } else if id == TypeId::of::<i64>() {
+            Self::new(Self::INT, 64)
+        } else if id == TypeId::of::<i32>() {
+            Self::new(Self::INT, 32)
+        } else if id == TypeId::of::<i16>() {
+            Self::new(Self::INT, 16)
+        } else if id == TypeId::of::<i8>() {
+            Self::new(Self::INT, 8)
+        } else if id == TypeId::of::<u32>() {
+            Self::new(Self::UINT, 32)
+        } else if id == TypeId::of::<u8>() {
+            Self::new(Self::UINT, 8)
+        } else {
+            return None;
+        };
+
+        Some(dtype)
+    }
+
+    /// The number of bytes of an element.
+    pub fn size(&self) -> usize {
+        (self.bits as usize * self.lanes as usize).div_ceil(8)
+    }
+}
+
+/// Tensor description of the DLPack protocol (`DLTensor`).
+#[repr(C)]
+#[derive(Debug)]
+pub struct DLTensor {
+    /// Pointer to the allocated memory, which may be a device pointer.
+    pub data: *mut c_void,
+    /// The device of the memory.
+    pub device: DLDevice,
+    /// The number of dimensions.
+    pub ndim: i32,
+    /// The type of the elements.
+    pub dtype: DLDataType,
+    /// The size of each dimension.
+    pub shape: *mut i64,
+    /// The stride of each dimension in number of elements, null for compact row-major tensors.
+    pub strides: *mut i64,
+    /// The offset in bytes of the first element from the data pointer.
+    pub byte_offset: u64,
+}
+
+/// Tensor owned by a manager, the unit of exchange of the DLPack protocol (`DLManagedTensor`).
+#[repr(C)]
+#[derive(Debug)]
+pub struct DLManagedTensor {
+    /// The tensor description.
+    pub dl_tensor: DLTensor,
+    /// Context of the framework owning the memory.
+    pub manager_ctx: *mut c_void,
+    /// Function called by the consumer to release the memory.
+    pub deleter: Option<unsafe extern "C" fn(*mut DLManagedTensor)>,
+}
+
+/// Error that can happen when importing a DLPack tensor.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DLPackError {
+    /// The element type isn't supported.
+    UnsupportedDType(DLDataType),
+    /// The device isn't supported by the backend.
+    UnsupportedDevice(DLDevice),
+    /// The number of dimensions doesn't match the rank of the tensor.
+    RankMismatch {
+        /// The expected rank.
+        expected: usize,
+        /// The number of dimensions of the DLPack tensor.
+        got: usize,
+    },
+}
+
+impl Display for DLPackError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::UnsupportedDType(dtype) => write!(f, "Unsupported data type {dtype:?}"),
+            Self::UnsupportedDevice(device) => write!(f, "Unsupported device {device:?}"),
+            Self::RankMismatch { expected, got } => {
+                write!(
+                    f,
+                    "Expected a tensor of rank {expected}, got {got} dimensions"
+                )
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for DLPackError {}
+
+/// Owned DLPack tensor, calling the deleter of the managed tensor when dropped.
+#[derive(Debug)]
+pub struct DLPackTensor {
+    managed: NonNull<DLManagedTensor>,
+}
+
+// The producer of a DLPack tensor can't assume it will be released on the thread that created it.
+unsafe impl Send for DLPackTensor {}
+
+#[repr(C)]
+struct ManagerContext<T> {
+    // Must be the first field, the managed tensor pointer being the context pointer.
+    managed: DLManagedTensor,
+    shape: Vec<i64>,
+    strides: Vec<i64>,
+    owner: T,
+}
+
+unsafe extern "C" fn delete_context<T>(managed: *mut DLManagedTensor) {
+    if !managed.is_null() {
+        drop(Box::from_raw(
+            (*managed).manager_ctx as *mut ManagerContext<T>,
+        ));
+    }
+}
+
+impl DLPackTensor {
+    /// Create a DLPack tensor viewing the memory kept alive by `owner`.
+    ///
+    /// # Safety
+    ///
+    /// `data` must point to memory on `device` owned by `owner`, valid for the given shape,
+    /// strides and data type until `owner` is dropped.
+    pub unsafe fn new<T: Send + 'static>(
+        owner: T,
+        data: *mut c_void,
+        device: DLDevice,
+        dtype: DLDataType,
+        shape: Vec<i64>,
+        strides: Vec<i64>,
+    ) -> Self {
+        assert_eq!(
+            shape.len(),
+            strides.len(),
+            "Each dimension must have a stride"
+        );
+
+        let mut context = Box::new(ManagerContext {
+            managed: DLManagedTensor {
+                dl_tensor: DLTensor {
+                    data,
+                    device,
+                    ndim: shape.len() as i32,
+                    dtype,
+                    shape: core::ptr::null_mut(),
+                    strides: core::ptr::null_mut(),
+                    byte_offset: 0,
+                },
+                manager_ctx: core::ptr::null_mut(),
+                deleter: Some(delete_context::<T>),
+            },
+            shape,
+            strides,
+            owner,
+        });
+
+        // The vectors are never resized, their buffers stay valid when the box is moved.
+        context.managed.dl_tensor.shape = context.shape.as_mut_ptr();
+        context.managed.dl_tensor.strides = context.strides.as_mut_ptr();
+
+        let context = Box::into_raw(context);
+        (*context).managed.manager_ctx = context as *mut c_void;
+
+        Self {
+            managed: NonNull::new_unchecked(context as *mut DLManagedTensor),
+        }
+    }
+
+    /// Take the ownership of a managed tensor received from another framework.
+    ///
+    /// # Safety
+    ///
+    /// `managed` must be a valid managed tensor not owned by anything else.
+    pub unsafe fn from_raw(managed: *mut DLManagedTensor) -> Option<Self> {
+        NonNull::new(managed).map(|managed| Self { managed })
+    }
+
+    /// Give the ownership of the managed tensor to the caller, which becomes responsible for
+    /// calling its deleter.
+    pub fn into_raw(self) -> *mut DLManagedTensor {
+        let managed = self.managed.as_ptr();
+        core::mem::forget(self);
+        managed
+    }
+
+    /// The tensor description.
+    pub fn dl_tensor(&self) -> &DLTensor {
+        // SAFETY: The managed tensor is valid until dropped.
+        unsafe { &self.managed.as_ref().dl_tensor }
+    }
+
+    /// The device of the memory.
+    pub fn device(&self) -> DLDevice {
+        self.dl_tensor().device
+    }
+
+    /// The type of the elements.
+    pub fn dtype(&self) -> DLDataType {
+        self.dl_tensor().dtype
+    }
+
+    /// The size of each dimension.
+    pub fn shape(&self) -> &[i64] {
+        let tensor = self.dl_tensor();
+
+        match tensor.ndim {
+            0 => &[],
+            // SAFETY: The shape has ndim elements.
+            ndim => unsafe { core::slice::from_raw_parts(tensor.shape, ndim as usize) },
+        }
+    }
+
+    /// The stride of each dimension in number of elements, computing the row-major strides when
+    /// the producer didn't provide them.
+    pub fn strides(&self) -> Vec<i64> {
+        let tensor = self.dl_tensor();
+
+        if !tensor.strides.is_null() && tensor.ndim > 0 {
+            // SAFETY: The strides have ndim elements when not null.
+            let strides =
+                unsafe { core::slice::from_raw_parts(tensor.strides, tensor.ndim as usize) };
+            return strides.to_vec();
+        }
+
+        let shape = self.shape();
+        let mut strides = alloc::vec![1; shape.len()];
+        for i in (0..shape.len().saturating_sub(1)).rev() {
+            strides[i] = strides[i + 1] * shape[i + 1];
+        }
+
+        strides
+    }
+
+    /// The number of elements.
+    pub fn num_elements(&self) -> usize {
+        self.shape().iter().product::<i64>() as usize
+    }
+
+    /// Pointer to the first element, which may be a device pointer.
+    pub fn data_ptr(&self) -> *mut c_void {
+        let tensor = self.dl_tensor();
+
+        // SAFETY: The byte offset stays within the allocation described by the tensor.
+        unsafe { (tensor.data as *mut u8).add(tensor.byte_offset as usize) as *mut c_void }
+    }
+
+    /// Copy the elements of a CPU tensor in row-major order, converting them to `E`.
+    pub fn to_data<E: Element, const D: usize>(&self) -> Result<Data<E, D>, DLPackError> {
+        let device = self.device();
+        if device.device_type != device_type::CPU && device.device_type != device_type::CUDA_HOST {
+            return Err(DLPackError::UnsupportedDevice(device));
+        }
+
+        let shape = self.shape();
+        if shape.len() != D {
+            return Err(DLPackError::RankMismatch {
+                expected: D,
+                got: shape.len(),
+            });
+        }
+
+        let dtype = self.dtype();
+        if dtype.lanes != 1 {
+            return Err(DLPackError::UnsupportedDType(dtype));
+        }
+
+        let strides = self.strides();
+        let data = self.data_ptr();
+        let num_elements = self.num_elements();
+        let mut value = Vec::with_capacity(num_elements);
+        let mut index = [0i64; D];
+
+        for _ in 0..num_elements {
+            let offset = index
+                .iter()
+                .zip(strides.iter())
+                .map(|(index, stride)| index * stride)
+                .sum::<i64>() as isize;
+
+            // SAFETY: The offset is within the tensor described by the shape and strides.
+            value.push(unsafe { read_element::<E>(data, offset, dtype)? });
+
+            // Increment the row-major index.
+            for dim in (0..D).rev() {
+                index[dim] += 1;
+                if index[dim] < shape[dim] {
+                    break;
+                }
+                index[dim] = 0;
+            }
+        }
+
+        let mut dims = [0; D];
+        for (dim, size) in dims.iter_mut().zip(shape) {
+            *dim = *size as usize;
+        }
+
+        Ok(Data::new(value, Shape::new(dims)))
+    }
+}
+
+unsafe fn read_element<E: Element>(
+    data: *mut c_void,
+    offset: isize,
+    dtype: DLDataType,
+) -> Result<E, DLPackError> {
+    unsafe fn read<T: ElementConversion + Copy, E: Element>(data: *mut c_void, offset: isize) -> E {
+        (data as *const T).offset(offset).read_unaligned().elem()
+    }
+
+    let value = match (dtype.code, dtype.bits) {
+        (DLDataType::FLOAT, 64) => read::<f64, E>(data, offset),
+        (DLDataType::FLOAT, 32) => read::<f32, E>(data, offset),
+        (DLDataType::FLOAT, 16) => read::<f16, E>(data, offset),
+        (DLDataType::BFLOAT, 16) => read::<bf16, E>(data, offset),
+        (DLDataType::INT, 64) => read::<i64, E>(data, offset),
+        (DLDataType::INT, 32) => read::<i32, E>(data, offset),
+        (DLDataType::INT, 16) => read::<i16, E>(data, offset),
+        (DLDataType::INT, 8) => read::<i8, E>(data, offset),
+        (DLDataType::UINT, 32) => read::<u32, E>(data, offset),
+        (DLDataType::UINT, 8) => read::<u8, E>(data, offset),
+        _ => return Err(DLPackError::UnsupportedDType(dtype)),
+    };
+
+    Ok(value)
+}
+
+impl Drop for DLPackTensor {
+    fn drop(&mut self) {
+        // SAFETY: The managed tensor is owned by this struct.
+        unsafe {
+            if let Some(deleter) = self.managed.as_ref().deleter {
+                deleter(self.managed.as_ptr());
+            }
+        }
+    }
+}
+
+/// Backend able to exchange float tensors with the DLPack protocol.
+pub trait DLPackBackend: Backend {
+    /// Export a float tensor, sharing its memory when possible.
+    fn float_to_dlpack<const D: usize>(tensor: FloatTensor<Self, D>) -> DLPackTensor;
+
+    /// Import a float tensor on the given device.
+    fn float_from_dlpack<const D: usize>(
+        tensor: DLPackTensor,
+        device: &Self::Device,
+    ) -> Result<FloatTensor<Self, D>, DLPackError>;
+}
+
+impl<B: DLPackBackend, const D: usize> Tensor<B, D, Float> {
+    /// Export the tensor with the DLPack protocol.
+    ///
+    /// The returned tensor can be given to another framework with [DLPackTensor::into_raw],
+    /// e.g. wrapped in a `dltensor` Python capsule for `torch.from_dlpack`.
+    pub fn to_dlpack(self) -> DLPackTensor {
+        B::float_to_dlpack(self.into_primitive())
+    }
+
+    /// Import a tensor exported with the DLPack protocol by another framework.
+    pub fn from_dlpack(tensor: DLPackTensor, device: &B::Device) -> Result<Self, DLPackError> {
+        B::float_from_dlpack(tensor, device).map(Self::from_primitive)
+    }
+}
@@ -1,5 +1,7 @@
 use crate::Distribution;
+use core::cmp::Ordering;
 use half::{bf16, f16};
+use num_complex::Complex;
 use num_traits::ToPrimitive;
 use rand::RngCore;
 
@@ -9,6 +11,7 @@ pub trait Element:
     + ElementRandom
     + ElementConversion
     + ElementPrecision
+    + ElementComparison
     + core::fmt::Debug
     + core::fmt::Display
     + Default
@@ -75,6 +78,15 @@ pub trait ElementPrecision {
     fn precision() -> Precision;
 }
 
+/// Element ordering trait.
+pub trait ElementComparison {
+    /// Returns the ordering of the element compared to the other element.
+    ///
+    /// NaN values are greater than every other value whatever their sign, and complex numbers are
+    /// ordered by their real part, then by their imaginary part.
+    fn elem_cmp(&self, other: &Self) -> Ordering;
+}
+
 /// Macro to implement the element trait for a type.
 #[macro_export]
 macro_rules! make_element {
@@ -175,3 +187,74 @@ make_element!(
         bf16::from_elem(sample)
     }
 );
+
+/// Complex number with single precision parts.
+#[allow(non_camel_case_types)]
+pub type c32 = Complex<f32>;
+
+/// Complex number with double precision parts.
+#[allow(non_camel_case_types)]
+pub type c64 = Complex<f64>;
+
+// Real values are converted to complex numbers with a zero imaginary part, while complex numbers
+// can only be converted to real values when their imaginary part is zero.
+
+make_element!(
+    ty c64 Precision::Double,
+    convert |elem: &dyn ToPrimitive| c64::new(elem.to_f64().unwrap(), 0.0),
+    random |distribution: Distribution, rng: &mut R| {
+        let re: f64 = distribution.sampler(rng).sample();
+        let im: f64 = distribution.sampler(rng).sample();
+        c64::new(re, im)
+    }
+);
+
+make_element!(
+    ty c32 Precision::Full,
+    convert |elem: &dyn ToPrimitive| c32::new(elem.to_f32().unwrap(), 0.0),
+    random |distribution: Distribution, rng: &mut R| {
+        let re: f32 = distribution.sampler(rng).sample();
+        let im: f32 = distribution.sampler(rng).sample();
+        c32::new(re, im)
+    }
+);
+
+macro_rules! make_int_comparison {
+    ($($type:ident),*) => {
+        $(
+            impl ElementComparison for $type {
+                fn elem_cmp(&self, other: &Self) -> Ordering {
+                    Ord::cmp(self, other)
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! make_float_comparison {
+    ($($type:ident),*) => {
+        $(
+            impl ElementComparison for $type {
+                fn elem_cmp(&self, other: &Self) -> Ordering {
+                    match (self.is_nan(), other.is_nan()) {
+                        (true, true) => Ordering::Equal,
+                        (true, false) => Ordering::Greater,
+                        (false, true) => Ordering::Less,
+                        (false, false) => self.partial_cmp(other).unwrap(),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+make_int_comparison!(i64, i32, u32, i16, i8, u8);
+make_float_comparison!(f64, f32, f16, bf16);
+
+impl<E: ElementComparison> ElementComparison for Complex<E> {
+    fn elem_cmp(&self, other: &Self) -> Ordering {
+        self.re
+            .elem_cmp(&other.re)
+            .then_with(|| self.im.elem_cmp(&other.im))
+    }
+}
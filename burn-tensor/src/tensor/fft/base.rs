@@ -0,0 +1,239 @@
+use core::fmt::Display;
+
+use crate::backend::Backend;
+use crate::check::TensorCheck;
+use crate::ops::FloatTensor;
+use crate::{check, Data, Int, Shape, Tensor};
+use alloc::vec::Vec;
+
+// Complex tensors are represented by their real and imaginary parts, two float tensors of the
+// same shape.
+
+/// Error returned by the FFT operations.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FftError {
+    /// The backend doesn't implement the FFT, such as the wgpu and candle backends.
+    Unsupported,
+}
+
+impl Display for FftError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Unsupported => write!(f, "The backend doesn't support the FFT"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for FftError {}
+
+/// Computes the discrete Fourier transform of a complex signal along the given dimension.
+///
+/// The complex signal and the result are given by their real and imaginary parts. The transform
+/// isn't normalized, `X[k] = sum_t x[t] * exp(-2 * pi * i * t * k / n)`.
+///
+/// # Errors
+///
+/// [FftError::Unsupported] if the backend doesn't implement the FFT. The outputs aren't tracked
+/// by the autodiff backend.
+pub fn fft<B: Backend, const D: usize>(
+    real: Tensor<B, D>,
+    imag: Tensor<B, D>,
+    dim: usize,
+) -> Result<(Tensor<B, D>, Tensor<B, D>), FftError> {
+    check!(TensorCheck::dim_ops::<D>("Fft", dim));
+    check!(TensorCheck::complex_parts::<D>(
+        "Fft",
+        &real.shape(),
+        &imag.shape()
+    ));
+
+    let (real, imag) = B::fft(real.into_primitive(), imag.into_primitive(), dim, false)?;
+
+    Ok((Tensor::from_primitive(real), Tensor::from_primitive(imag)))
+}
+
+/// Computes the inverse discrete Fourier transform of a complex spectrum along the given
+/// dimension.
+///
+/// The inverse transform is normalized by the length `n` of the signal,
+/// `x[t] = sum_k X[k] * exp(2 * pi * i * t * k / n) / n`, so that `ifft(fft(x)) = x`.
+///
+/// # Errors
+///
+/// [FftError::Unsupported] if the backend doesn't implement the FFT.
+pub fn ifft<B: Backend, const D: usize>(
+    real: Tensor<B, D>,
+    imag: Tensor<B, D>,
+    dim: usize,
+) -> Result<(Tensor<B, D>, Tensor<B, D>), FftError> {
+    check!(TensorCheck::dim_ops::<D>("Ifft", dim));
+    check!(TensorCheck::complex_parts::<D>(
+        "Ifft",
+        &real.shape(),
+        &imag.shape()
+    ));
+
+    let (real, imag) = B::fft(real.into_primitive(), imag.into_primitive(), dim, true)?;
+
+    Ok((Tensor::from_primitive(real), Tensor::from_primitive(imag)))
+}
+
+/// Computes the discrete Fourier transform of a real signal along the given dimension.
+///
+/// The spectrum of a real signal is Hermitian symmetric, so only the `n / 2 + 1` non-negative
+/// frequencies are returned, as the real and imaginary parts.
+///
+/// # Errors
+///
+/// [FftError::Unsupported] if the backend doesn't implement the FFT.
+///
+/// # Example
+///
+/// ```rust
+/// use burn_tensor::backend::Backend;
+/// use burn_tensor::{fft, Tensor};
+///
+/// fn example<B: Backend>() -> Result<(), fft::FftError> {
+///     let device = Default::default();
+///     let signal = Tensor::<B, 2>::ones([4, 400], &device);
+///
+///     let (real, imag) = fft::rfft(signal, 1)?;
+///     let power = real.powf(2.0) + imag.powf(2.0);
+///     assert_eq!(power.dims(), [4, 201]);
+///
+///     Ok(())
+/// }
+/// ```
+pub fn rfft<B: Backend, const D: usize>(
+    signal: Tensor<B, D>,
+    dim: usize,
+) -> Result<(Tensor<B, D>, Tensor<B, D>), FftError> {
+    check!(TensorCheck::dim_ops::<D>("Rfft", dim));
+
+    let (real, imag) = B::rfft(signal.into_primitive(), dim)?;
+
+    Ok((Tensor::from_primitive(real), Tensor::from_primitive(imag)))
+}
+
+/// Computes the inverse of [rfft], the real signal of length `n` with the given non-negative
+/// frequencies along the dimension.
+///
+/// The length is needed since a spectrum of `n / 2 + 1` frequencies can come from a signal of
+/// an even or an odd length.
+///
+/// # Panics
+///
+/// If the number of frequencies along the dimension isn't `n / 2 + 1`.
+///
+/// # Errors
+///
+/// [FftError::Unsupported] if the backend doesn't implement the FFT.
+pub fn irfft<B: Backend, const D: usize>(
+    real: Tensor<B, D>,
+    imag: Tensor<B, D>,
+    dim: usize,
+    n: usize,
+) -> Result<Tensor<B, D>, FftError> {
+    check!(TensorCheck::complex_parts::<D>(
+        "Irfft",
+        &real.shape(),
+        &imag.shape()
+    ));
+    check!(TensorCheck::irfft::<D>(dim, &real.shape(), n));
+
+    let signal = B::irfft(real.into_primitive(), imag.into_primitive(), dim, n)?;
+
+    Ok(Tensor::from_primitive(signal))
+}
+
+/// Computes the two-dimensional discrete Fourier transform of a complex signal over its last
+/// two dimensions, such as images.
+///
+/// # Errors
+///
+/// [FftError::Unsupported] if the backend doesn't implement the FFT.
+pub fn fft2<B: Backend, const D: usize>(
+    real: Tensor<B, D>,
+    imag: Tensor<B, D>,
+) -> Result<(Tensor<B, D>, Tensor<B, D>), FftError> {
+    check!(TensorCheck::linalg_matrix::<D>("Fft2"));
+
+    let (real, imag) = fft(real, imag, D - 1)?;
+
+    fft(real, imag, D - 2)
+}
+
+/// Computes the inverse of [fft2] over the last two dimensions.
+///
+/// # Errors
+///
+/// [FftError::Unsupported] if the backend doesn't implement the FFT.
+pub fn ifft2<B: Backend, const D: usize>(
+    real: Tensor<B, D>,
+    imag: Tensor<B, D>,
+) -> Result<(Tensor<B, D>, Tensor<B, D>), FftError> {
+    check!(TensorCheck::linalg_matrix::<D>("Ifft2"));
+
+    let (real, imag) = ifft(real, imag, D - 1)?;
+
+    ifft(real, imag, D - 2)
+}
+
+/// Computes the transform of a real signal with the complex FFT of the backend, keeping the
+/// non-negative frequencies.
+pub(crate) fn rfft_with_fft<B: Backend, const D: usize>(
+    signal: FloatTensor<B, D>,
+    dim: usize,
+) -> Result<(FloatTensor<B, D>, FloatTensor<B, D>), FftError> {
+    let n = B::shape(&signal).dims[dim];
+    let imag = B::zeros(B::shape(&signal), &B::device(&signal));
+    let (real, imag) = B::fft(signal, imag, dim, false)?;
+
+    Ok((
+        B::narrow(real, dim, 0, n / 2 + 1),
+        B::narrow(imag, dim, 0, n / 2 + 1),
+    ))
+}
+
+/// Computes the inverse transform of the non-negative frequencies of a real signal with the
+/// complex FFT of the backend, restoring the negative frequencies from the Hermitian symmetry
+/// `X[n - k] = conj(X[k])`.
+pub(crate) fn irfft_with_fft<B: Backend, const D: usize>(
+    real: FloatTensor<B, D>,
+    imag: FloatTensor<B, D>,
+    dim: usize,
+    n: usize,
+) -> Result<FloatTensor<B, D>, FftError> {
+    let device = B::device(&real);
+    let num_frequencies = n / 2 + 1;
+
+    let indices = (0..n)
+        .map(|k| match k < num_frequencies {
+            true => k as i64,
+            false => (n - k) as i64,
+        })
+        .collect::<Vec<_>>();
+    let signs = (0..n)
+        .map(|k| match k < num_frequencies {
+            true => 1.0,
+            false => -1.0,
+        })
+        .collect::<Vec<f32>>();
+
+    let indices =
+        Tensor::<B, 1, Int>::from_data(Data::new(indices, Shape::new([n])).convert(), &device);
+    let mut shape_signs = [1; D];
+    shape_signs[dim] = n;
+    let signs = Tensor::<B, 1>::from_data(Data::new(signs, Shape::new([n])).convert(), &device)
+        .reshape(shape_signs);
+
+    let real = Tensor::<B, D>::from_primitive(real).select(dim, indices.clone());
+    let imag = Tensor::<B, D>::from_primitive(imag)
+        .select(dim, indices)
+        .mul(signs);
+
+    let (signal, _) = B::fft(real.into_primitive(), imag.into_primitive(), dim, true)?;
+
+    Ok(signal)
+}
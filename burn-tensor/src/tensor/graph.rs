@@ -0,0 +1,135 @@
+//! Capture of fixed-shape computations into graphs that can be replayed without the overhead of
+//! launching each operation.
+//!
+//! A [StaticGraph] records the kernels executed by a function on a set of input tensors. Replaying
+//! the graph copies new inputs into the memory of the captured inputs and executes the recorded
+//! kernels again, which is useful for low-latency inference with fixed shapes. Backends supporting
+//! capture implement [GraphBackend].
+
+use alloc::vec::Vec;
+
+use crate::{backend::Backend, ops::FloatTensor, Float, Tensor};
+
+/// Backend able to capture the kernels executed on a device and to replay them.
+pub trait GraphBackend: Backend {
+    /// The recorded kernels.
+    type Graph: Clone + Send;
+
+    /// Start recording the kernels executed on the device.
+    fn graph_capture_begin(device: &Self::Device);
+
+    /// Stop recording and return the kernels executed on the device since
+    /// [graph_capture_begin](GraphBackend::graph_capture_begin).
+    fn graph_capture_end(device: &Self::Device) -> Self::Graph;
+
+    /// Execute the recorded kernels again, over the memory used during the capture.
+    fn graph_replay(device: &Self::Device, graph: &Self::Graph);
+
+    /// Copy the values of a tensor into the memory of a captured tensor.
+    fn graph_write<const D: usize>(target: &FloatTensor<Self, D>, value: FloatTensor<Self, D>);
+
+    /// Copy a captured tensor into new memory, which isn't modified by the next replays.
+    fn graph_read<const D: usize>(tensor: &FloatTensor<Self, D>) -> FloatTensor<Self, D>;
+}
+
+/// Computation captured on a device, replayed with new inputs of the same shapes.
+///
+/// # Notes
+///
+/// - Only the kernels are recorded, so the computation must not depend on the values of the
+///   inputs: data read back from the device, such as conditions on the values of a tensor, are
+///   fixed at capture time.
+/// - Every kernel executed on the device during the capture is recorded, including the ones from
+///   other threads and the benchmarks of autotune. Run the function once before capturing it so
+///   that the kernels are compiled and tuned.
+/// - The memory used by the captured computation is reserved until the graph is dropped.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// // Warmup, so that autotune is done before the capture.
+/// model.forward(input.clone());
+///
+/// let graph = StaticGraph::capture(vec![input], |inputs| {
+///     vec![model.forward(inputs[0].clone())]
+/// });
+///
+/// for input in inputs {
+///     let output = graph.replay(vec![input]).remove(0);
+/// }
+/// ```
+pub struct StaticGraph<B: GraphBackend, const DI: usize, const DO: usize> {
+    graph: B::Graph,
+    device: B::Device,
+    inputs: Vec<Tensor<B, DI, Float>>,
+    outputs: Vec<Tensor<B, DO, Float>>,
+}
+
+impl<B: GraphBackend, const DI: usize, const DO: usize> StaticGraph<B, DI, DO> {
+    /// Capture the kernels executed by the function on the given inputs.
+    ///
+    /// # Panics
+    ///
+    /// If no input is given.
+    pub fn capture<F>(inputs: Vec<Tensor<B, DI, Float>>, func: F) -> Self
+    where
+        F: FnOnce(Vec<Tensor<B, DI, Float>>) -> Vec<Tensor<B, DO, Float>>,
+    {
+        let device = inputs
+            .first()
+            .expect("Should have at least one input to capture a graph")
+            .device();
+
+        // The captured inputs are overwritten by each replay, so they can't share their memory
+        // with tensors of the caller.
+        let inputs: Vec<_> = inputs
+            .iter()
+            .map(|input| Tensor::from_primitive(B::graph_read(&input.primitive)))
+            .collect();
+
+        B::graph_capture_begin(&device);
+        let outputs = func(inputs.clone());
+        let graph = B::graph_capture_end(&device);
+
+        Self {
+            graph,
+            device,
+            inputs,
+            outputs,
+        }
+    }
+
+    /// Replay the captured kernels with new inputs.
+    ///
+    /// # Panics
+    ///
+    /// If the number of inputs or their shapes differ from the captured inputs.
+    pub fn replay(&self, inputs: Vec<Tensor<B, DI, Float>>) -> Vec<Tensor<B, DO, Float>> {
+        assert_eq!(
+            inputs.len(),
+            self.inputs.len(),
+            "Should replay the graph with the same number of inputs as captured"
+        );
+
+        for (target, value) in self.inputs.iter().zip(inputs) {
+            assert_eq!(
+                target.shape(),
+                value.shape(),
+                "Should replay the graph with inputs of the same shapes as captured"
+            );
+            B::graph_write(&target.primitive, value.into_primitive());
+        }
+
+        B::graph_replay(&self.device, &self.graph);
+
+        self.outputs
+            .iter()
+            .map(|output| Tensor::from_primitive(B::graph_read(&output.primitive)))
+            .collect()
+    }
+
+    /// The device where the graph was captured.
+    pub fn device(&self) -> &B::Device {
+        &self.device
+    }
+}
@@ -0,0 +1,117 @@
+use crate::backend::Backend;
+use crate::check::TensorCheck;
+use crate::{check, Tensor};
+
+/// Solves the linear systems `lhs @ x = rhs` for `x`.
+///
+/// The left hand side is a square matrix or a batch of square matrices of shape `[..., n, n]`
+/// and the right hand side has the shape `[..., n, k]`, with the same batch dimensions.
+///
+/// # Panics
+///
+/// If a matrix of the left hand side is singular, on the backends without a native solver.
+///
+/// # Example
+///
+/// ```rust
+/// use burn_tensor::backend::Backend;
+/// use burn_tensor::{linalg, Tensor};
+///
+/// fn example<B: Backend>() {
+///     let device = Default::default();
+///     let a = Tensor::<B, 2>::from_floats([[3.0, 1.0], [1.0, 2.0]], &device);
+///     let b = Tensor::<B, 2>::from_floats([[9.0], [8.0]], &device);
+///
+///     let x = linalg::solve(a, b);
+///     println!("{}", x);
+///     // [[2.0], [3.0]]
+/// }
+/// ```
+pub fn solve<B: Backend, const D: usize>(lhs: Tensor<B, D>, rhs: Tensor<B, D>) -> Tensor<B, D> {
+    check!(TensorCheck::solve(&lhs, &rhs));
+
+    Tensor::from_primitive(B::solve(lhs.into_primitive(), rhs.into_primitive()))
+}
+
+/// Computes the inverse of a square matrix or of a batch of square matrices.
+///
+/// Solving a linear system with [solve] is faster and more accurate than multiplying by the
+/// inverse, which should only be computed when it is needed by itself.
+///
+/// # Panics
+///
+/// If a matrix is singular, on the backends without a native inversion.
+pub fn inverse<B: Backend, const D: usize>(tensor: Tensor<B, D>) -> Tensor<B, D> {
+    check!(TensorCheck::linalg_square::<D>("Inverse", &tensor.shape()));
+
+    Tensor::from_primitive(B::inverse(tensor.into_primitive()))
+}
+
+/// Computes the Cholesky decomposition `tensor = l @ l^T` of a symmetric positive-definite
+/// matrix or of a batch of such matrices, returning the lower triangular factor `l`.
+///
+/// Only the lower triangular part of the matrices is read.
+///
+/// # Panics
+///
+/// If a matrix isn't positive-definite, on the backends without a native decomposition.
+pub fn cholesky<B: Backend, const D: usize>(tensor: Tensor<B, D>) -> Tensor<B, D> {
+    check!(TensorCheck::linalg_square::<D>("Cholesky", &tensor.shape()));
+
+    Tensor::from_primitive(B::cholesky(tensor.into_primitive()))
+}
+
+/// Computes the reduced QR decomposition `tensor = q @ r` of a matrix or of a batch of
+/// matrices of shape `[..., m, n]`.
+///
+/// With `k = min(m, n)`, `q` has the shape `[..., m, k]` with orthonormal columns and `r` is
+/// upper triangular with the shape `[..., k, n]`. The signs of the columns of `q` and of the
+/// rows of `r` depend on the backend.
+///
+/// The decomposition isn't differentiable.
+pub fn qr<B: Backend, const D: usize>(tensor: Tensor<B, D>) -> (Tensor<B, D>, Tensor<B, D>) {
+    check!(TensorCheck::linalg_matrix::<D>("QR"));
+
+    let (q, r) = B::qr(tensor.into_primitive());
+
+    (Tensor::from_primitive(q), Tensor::from_primitive(r))
+}
+
+/// Computes the reduced singular value decomposition `tensor = u @ diag(s) @ vt` of a matrix or
+/// of a batch of matrices of shape `[..., m, n]`.
+///
+/// With `k = min(m, n)`, `u` has the shape `[..., m, k]` and `vt` the shape `[..., k, n]`, both
+/// with orthonormal singular vectors, and the singular values `s` are sorted in descending
+/// order with the shape `[..., 1, k]`, so that `u.mul(s).matmul(vt)` reconstructs the tensor.
+/// The signs of the singular vectors depend on the backend.
+///
+/// The decomposition isn't differentiable.
+///
+/// # Example
+///
+/// ```rust
+/// use burn_tensor::backend::Backend;
+/// use burn_tensor::{linalg, Tensor};
+///
+/// fn example<B: Backend>() {
+///     let device = Default::default();
+///     let tensor = Tensor::<B, 2>::from_floats([[3.0, 0.0], [0.0, -2.0]], &device);
+///
+///     let (_u, s, _vt) = linalg::svd(tensor);
+///     println!("{}", s);
+///     // [[3.0, 2.0]]
+/// }
+/// ```
+pub fn svd<B: Backend, const D: usize>(
+    tensor: Tensor<B, D>,
+) -> (Tensor<B, D>, Tensor<B, D>, Tensor<B, D>) {
+    check!(TensorCheck::linalg_matrix::<D>("SVD"));
+
+    let (u, s, vt) = B::svd(tensor.into_primitive());
+
+    (
+        Tensor::from_primitive(u),
+        Tensor::from_primitive(s),
+        Tensor::from_primitive(vt),
+    )
+}
@@ -0,0 +1,411 @@
+use crate::{
+    backend::Backend,
+    ops::{FloatElem, FloatTensor},
+    Data, ElementConversion, Shape,
+};
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// Maximum number of sweeps of the Jacobi rotations computing the singular value decomposition.
+const SVD_MAX_SWEEPS: usize = 64;
+
+/// Solve the linear systems `lhs @ x = rhs` with a LU decomposition with partial pivoting.
+///
+/// # Remarks
+///
+/// Every system of the batch is solved on the host in double precision. Only an exactly null
+/// pivot is detected as a singular matrix and panics, ill-conditioned matrices give inaccurate
+/// solutions instead.
+pub(crate) fn solve<B: Backend, const D: usize>(
+    lhs: FloatTensor<B, D>,
+    rhs: FloatTensor<B, D>,
+) -> FloatTensor<B, D> {
+    let device = B::device(&lhs);
+    let (lhs, shape_lhs) = read::<B, D>(lhs);
+    let (rhs, shape_rhs) = read::<B, D>(rhs);
+
+    let n = shape_lhs.dims[D - 1];
+    let k = shape_rhs.dims[D - 1];
+    let mut output = Vec::with_capacity(rhs.len());
+
+    for (a, b) in lhs.chunks(n * n).zip(rhs.chunks(n * k)) {
+        output.extend(lu_solve(a, b, n, k));
+    }
+
+    write::<B, D>(output, shape_rhs, &device)
+}
+
+/// Invert the matrices by solving the linear systems with the identity matrix.
+///
+/// # Remarks
+///
+/// Each matrix costs a LU decomposition and `n` triangular solves, which is slower than a
+/// dedicated inversion but shares the pivoting of [solve], including its panic on singular
+/// matrices.
+pub(crate) fn inverse<B: Backend, const D: usize>(tensor: FloatTensor<B, D>) -> FloatTensor<B, D> {
+    let device = B::device(&tensor);
+    let (values, shape) = read::<B, D>(tensor);
+
+    let n = shape.dims[D - 1];
+    let identity = identity(n);
+    let mut output = Vec::with_capacity(values.len());
+
+    for a in values.chunks(n * n) {
+        output.extend(lu_solve(a, &identity, n, n));
+    }
+
+    write::<B, D>(output, shape, &device)
+}
+
+/// Compute the lower triangular Cholesky factor of symmetric positive-definite matrices.
+///
+/// # Remarks
+///
+/// Only the lower triangle of the matrices is read, the upper triangle is assumed to be its
+/// transpose. Panics when a diagonal element of the factor isn't positive, which means that the
+/// matrix isn't positive-definite.
+pub(crate) fn cholesky<B: Backend, const D: usize>(tensor: FloatTensor<B, D>) -> FloatTensor<B, D> {
+    let device = B::device(&tensor);
+    let (values, shape) = read::<B, D>(tensor);
+
+    let n = shape.dims[D - 1];
+    let mut output = Vec::with_capacity(values.len());
+
+    for a in values.chunks(n * n) {
+        output.extend(cholesky_lower(a, n));
+    }
+
+    write::<B, D>(output, shape, &device)
+}
+
+/// Compute the reduced QR decomposition of the matrices with Householder reflections.
+///
+/// # Remarks
+///
+/// The diagonal of `r` isn't normalized to be positive, so `q` and `r` can differ in the signs of
+/// their columns and rows from the decomposition computed by other backends, while their product
+/// is the same.
+pub(crate) fn qr<B: Backend, const D: usize>(
+    tensor: FloatTensor<B, D>,
+) -> (FloatTensor<B, D>, FloatTensor<B, D>) {
+    let device = B::device(&tensor);
+    let (values, shape) = read::<B, D>(tensor);
+
+    let m = shape.dims[D - 2];
+    let n = shape.dims[D - 1];
+    let k = usize::min(m, n);
+    let mut output_q = Vec::new();
+    let mut output_r = Vec::new();
+
+    for a in values.chunks(m * n) {
+        let (q, r) = householder_qr(a, m, n);
+        output_q.extend(q);
+        output_r.extend(r);
+    }
+
+    let q = write::<B, D>(output_q, matrix_shape(&shape, m, k), &device);
+    let r = write::<B, D>(output_r, matrix_shape(&shape, k, n), &device);
+
+    (q, r)
+}
+
+/// Compute the reduced singular value decomposition of the matrices with one-sided Jacobi
+/// rotations.
+///
+/// # Remarks
+///
+/// The rotations are repeated until the columns are orthogonal, for at most
+/// [SVD_MAX_SWEEPS] sweeps. Wide matrices are decomposed through their transpose, and the
+/// singular vectors of null singular values are completed to keep `u` orthonormal.
+pub(crate) fn svd<B: Backend, const D: usize>(
+    tensor: FloatTensor<B, D>,
+) -> (FloatTensor<B, D>, FloatTensor<B, D>, FloatTensor<B, D>) {
+    let device = B::device(&tensor);
+    let (values, shape) = read::<B, D>(tensor);
+
+    let m = shape.dims[D - 2];
+    let n = shape.dims[D - 1];
+    let k = usize::min(m, n);
+    let mut output_u = Vec::new();
+    let mut output_s = Vec::new();
+    let mut output_vt = Vec::new();
+
+    for a in values.chunks(m * n) {
+        let (u, s, vt) = match m >= n {
+            true => jacobi_svd(a, m, n),
+            false => {
+                // The decomposition of the transposed matrix is computed instead, A^T = U S V^T
+                // gives A = V S U^T.
+                let (u, s, vt) = jacobi_svd(&transpose(a, m, n), n, m);
+                (transpose(&vt, k, m), s, transpose(&u, n, k))
+            }
+        };
+        output_u.extend(u);
+        output_s.extend(s);
+        output_vt.extend(vt);
+    }
+
+    let u = write::<B, D>(output_u, matrix_shape(&shape, m, k), &device);
+    let s = write::<B, D>(output_s, matrix_shape(&shape, 1, k), &device);
+    let vt = write::<B, D>(output_vt, matrix_shape(&shape, k, n), &device);
+
+    (u, s, vt)
+}
+
+fn read<B: Backend, const D: usize>(tensor: FloatTensor<B, D>) -> (Vec<f64>, Shape<D>) {
+    let data = B::into_data(tensor)
+        .read_sync()
+        .expect("Can't decompose a tensor that can only be read asynchronously");
+    let values = data.value.into_iter().map(|value| value.elem()).collect();
+
+    (values, data.shape)
+}
+
+fn write<B: Backend, const D: usize>(
+    values: Vec<f64>,
+    shape: Shape<D>,
+    device: &B::Device,
+) -> FloatTensor<B, D> {
+    let values = values
+        .into_iter()
+        .map(|value| value.elem::<FloatElem<B>>())
+        .collect();
+
+    B::from_data(Data::new(values, shape), device)
+}
+
+/// The shape with the same batch dimensions as `shape` and matrices of `rows` by `cols`.
+fn matrix_shape<const D: usize>(shape: &Shape<D>, rows: usize, cols: usize) -> Shape<D> {
+    let mut dims = shape.dims;
+    dims[D - 2] = rows;
+    dims[D - 1] = cols;
+
+    Shape::new(dims)
+}
+
+fn identity(n: usize) -> Vec<f64> {
+    let mut values = vec![0.0; n * n];
+    (0..n).for_each(|i| values[i * n + i] = 1.0);
+
+    values
+}
+
+fn transpose(a: &[f64], rows: usize, cols: usize) -> Vec<f64> {
+    let mut values = vec![0.0; rows * cols];
+
+    for i in 0..rows {
+        for j in 0..cols {
+            values[j * rows + i] = a[i * cols + j];
+        }
+    }
+
+    values
+}
+
+/// Solve `a @ x = b` for the `n` by `n` matrix `a` and the `n` by `k` matrix `b`.
+fn lu_solve(a: &[f64], b: &[f64], n: usize, k: usize) -> Vec<f64> {
+    let mut a = a.to_vec();
+    let mut x = b.to_vec();
+
+    for col in 0..n {
+        let pivot = (col..n)
+            .max_by(|&i, &j| libm::fabs(a[i * n + col]).total_cmp(&libm::fabs(a[j * n + col])))
+            .unwrap();
+
+        if a[pivot * n + col] == 0.0 {
+            panic!("Can't solve a linear system with a singular matrix");
+        }
+
+        if pivot != col {
+            (0..n).for_each(|j| a.swap(col * n + j, pivot * n + j));
+            (0..k).for_each(|j| x.swap(col * k + j, pivot * k + j));
+        }
+
+        for row in col + 1..n {
+            let factor = a[row * n + col] / a[col * n + col];
+
+            for j in col..n {
+                a[row * n + j] -= factor * a[col * n + j];
+            }
+            for j in 0..k {
+                x[row * k + j] -= factor * x[col * k + j];
+            }
+        }
+    }
+
+    for row in (0..n).rev() {
+        for j in 0..k {
+            let sum = (row + 1..n)
+                .map(|i| a[row * n + i] * x[i * k + j])
+                .sum::<f64>();
+
+            x[row * k + j] = (x[row * k + j] - sum) / a[row * n + row];
+        }
+    }
+
+    x
+}
+
+fn cholesky_lower(a: &[f64], n: usize) -> Vec<f64> {
+    let mut l = vec![0.0; n * n];
+
+    for i in 0..n {
+        for j in 0..=i {
+            let sum = (0..j).map(|p| l[i * n + p] * l[j * n + p]).sum::<f64>();
+            let value = a[i * n + j] - sum;
+
+            if i == j {
+                if value <= 0.0 {
+                    panic!("Can't compute the Cholesky decomposition of a matrix that isn't positive-definite");
+                }
+                l[i * n + i] = libm::sqrt(value);
+            } else {
+                l[i * n + j] = value / l[j * n + j];
+            }
+        }
+    }
+
+    l
+}
+
+/// Decompose the `m` by `n` matrix `a` into `q`, `m` by `min(m, n)` with orthonormal columns,
+/// and the upper triangular `r`, `min(m, n)` by `n`.
+fn householder_qr(a: &[f64], m: usize, n: usize) -> (Vec<f64>, Vec<f64>) {
+    let k = usize::min(m, n);
+    let mut r = a.to_vec();
+    let mut q = identity(m);
+
+    for j in 0..k {
+        // Reflect the column below the diagonal onto its first element.
+        let mut v = (j..m).map(|i| r[i * n + j]).collect::<Vec<_>>();
+        let norm = libm::sqrt(v.iter().map(|x| x * x).sum());
+        let alpha = match v[0] > 0.0 {
+            true => -norm,
+            false => norm,
+        };
+        v[0] -= alpha;
+
+        let norm_v = libm::sqrt(v.iter().map(|x| x * x).sum());
+        if norm_v == 0.0 {
+            continue;
+        }
+        v.iter_mut().for_each(|x| *x /= norm_v);
+
+        for col in 0..n {
+            let dot = (0..m - j).map(|i| v[i] * r[(j + i) * n + col]).sum::<f64>();
+            (0..m - j).for_each(|i| r[(j + i) * n + col] -= 2.0 * v[i] * dot);
+        }
+        for row in 0..m {
+            let dot = (0..m - j).map(|i| q[row * m + j + i] * v[i]).sum::<f64>();
+            (0..m - j).for_each(|i| q[row * m + j + i] -= 2.0 * dot * v[i]);
+        }
+    }
+
+    let q = (0..m)
+        .flat_map(|i| (0..k).map(move |j| (i, j)))
+        .map(|(i, j)| q[i * m + j])
+        .collect();
+    let r = (0..k)
+        .flat_map(|i| (0..n).map(move |j| (i, j)))
+        .map(|(i, j)| if j >= i { r[i * n + j] } else { 0.0 })
+        .collect();
+
+    (q, r)
+}
+
+/// Decompose the `m` by `n` matrix `a`, with `m >= n`, into `u`, `m` by `n`, the `n` singular
+/// values in descending order and `vt`, `n` by `n`.
+fn jacobi_svd(a: &[f64], m: usize, n: usize) -> (Vec<f64>, Vec<f64>, Vec<f64>) {
+    let mut u = a.to_vec();
+    let mut v = identity(n);
+
+    for _ in 0..SVD_MAX_SWEEPS {
+        let mut rotated = false;
+
+        for p in 0..n.saturating_sub(1) {
+            for q in p + 1..n {
+                let alpha = (0..m).map(|i| u[i * n + p] * u[i * n + p]).sum::<f64>();
+                let beta = (0..m).map(|i| u[i * n + q] * u[i * n + q]).sum::<f64>();
+                let gamma = (0..m).map(|i| u[i * n + p] * u[i * n + q]).sum::<f64>();
+
+                if gamma == 0.0 || libm::fabs(gamma) <= f64::EPSILON * libm::sqrt(alpha * beta) {
+                    continue;
+                }
+                rotated = true;
+
+                // Rotate the columns p and q to make them orthogonal.
+                let zeta = (beta - alpha) / (2.0 * gamma);
+                let t =
+                    libm::copysign(1.0, zeta) / (libm::fabs(zeta) + libm::sqrt(1.0 + zeta * zeta));
+                let c = 1.0 / libm::sqrt(1.0 + t * t);
+                let s = c * t;
+
+                for (matrix, rows) in [(&mut u, m), (&mut v, n)] {
+                    for i in 0..rows {
+                        let x = matrix[i * n + p];
+                        let y = matrix[i * n + q];
+                        matrix[i * n + p] = c * x - s * y;
+                        matrix[i * n + q] = s * x + c * y;
+                    }
+                }
+            }
+        }
+
+        if !rotated {
+            break;
+        }
+    }
+
+    let singular_values = (0..n)
+        .map(|j| libm::sqrt((0..m).map(|i| u[i * n + j] * u[i * n + j]).sum()))
+        .collect::<Vec<f64>>();
+    let mut order = (0..n).collect::<Vec<_>>();
+    order.sort_by(|&i, &j| singular_values[j].total_cmp(&singular_values[i]));
+
+    let mut output_u = vec![0.0; m * n];
+    let mut output_vt = vec![0.0; n * n];
+    for (position, &j) in order.iter().enumerate() {
+        let norm = singular_values[j];
+
+        for i in 0..m {
+            output_u[i * n + position] = match norm > 0.0 {
+                true => u[i * n + j] / norm,
+                false => 0.0,
+            };
+        }
+        for i in 0..n {
+            output_vt[position * n + i] = v[i * n + j];
+        }
+    }
+    complete_orthonormal_columns(&mut output_u, m, n);
+
+    let singular_values = order.iter().map(|&j| singular_values[j]).collect();
+
+    (output_u, singular_values, output_vt)
+}
+
+/// Replace the null columns of the `m` by `n` matrix `u` so that its columns are orthonormal,
+/// which happens with the singular vectors of the null singular values.
+fn complete_orthonormal_columns(u: &mut [f64], m: usize, n: usize) {
+    for col in 0..n {
+        if (0..m).any(|i| u[i * n + col] != 0.0) {
+            continue;
+        }
+
+        for basis in 0..m {
+            let mut candidate = vec![0.0; m];
+            candidate[basis] = 1.0;
+
+            for other in (0..n).filter(|&other| other != col) {
+                let dot = (0..m).map(|i| candidate[i] * u[i * n + other]).sum::<f64>();
+                (0..m).for_each(|i| candidate[i] -= dot * u[i * n + other]);
+            }
+
+            let norm = libm::sqrt(candidate.iter().map(|x| x * x).sum());
+            if norm > 0.5 {
+                (0..m).for_each(|i| u[i * n + col] = candidate[i] / norm);
+                break;
+            }
+        }
+    }
+}
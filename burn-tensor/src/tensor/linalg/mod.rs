@@ -0,0 +1,5 @@
+mod base;
+
+pub(crate) mod host;
+
+pub use base::*;
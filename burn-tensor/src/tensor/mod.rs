@@ -19,6 +19,18 @@ pub mod backend;
 /// The container module.
 pub mod container;
 
+/// DLPack protocol.
+pub mod dlpack;
+
+/// Fast Fourier transforms.
+pub mod fft;
+
+/// Graph capture and replay.
+pub mod graph;
+
+/// The linear algebra module.
+pub mod linalg;
+
 /// The loss module.
 pub mod loss;
 
@@ -28,6 +40,9 @@ pub mod module;
 /// Operations on tensors module.
 pub mod ops;
 
+/// Sparse matrices.
+pub mod sparse;
+
 #[cfg(feature = "experimental-named-tensor")]
 mod named;
 #[cfg(feature = "experimental-named-tensor")]
@@ -92,6 +92,62 @@ where
     Tensor::new(B::unfold4d(x.primitive, kernel_size, options))
 }
 
+/// Applies a [scaled dot-product attention](crate::ops::ModuleOps::attention).
+pub fn attention<B>(
+    query: Tensor<B, 4>,
+    key: Tensor<B, 4>,
+    value: Tensor<B, 4>,
+    scale: f32,
+) -> Tensor<B, 4>
+where
+    B: Backend,
+{
+    Tensor::new(B::attention(
+        query.primitive,
+        key.primitive,
+        value.primitive,
+        scale,
+    ))
+}
+
+/// Applies a [matrix multiplication with 4-bit weights](crate::ops::ModuleOps::int4_matmul).
+pub fn int4_matmul<B>(
+    lhs: Tensor<B, 2>,
+    weight: Tensor<B, 2, Int>,
+    scales: Tensor<B, 2>,
+    zero_points: Tensor<B, 2>,
+    block_size: usize,
+) -> Tensor<B, 2>
+where
+    B: Backend,
+{
+    Tensor::new(B::int4_matmul(
+        lhs.primitive,
+        weight.primitive,
+        scales.primitive,
+        zero_points.primitive,
+        block_size,
+    ))
+}
+
+/// Dequantize [4-bit weights](crate::ops::ModuleOps::int4_dequantize).
+pub fn int4_dequantize<B>(
+    weight: Tensor<B, 2, Int>,
+    scales: Tensor<B, 2>,
+    zero_points: Tensor<B, 2>,
+    block_size: usize,
+) -> Tensor<B, 2>
+where
+    B: Backend,
+{
+    Tensor::new(B::int4_dequantize(
+        weight.primitive,
+        scales.primitive,
+        zero_points.primitive,
+        block_size,
+    ))
+}
+
 /// Applies a [1D max pooling](crate::ops::ModuleOps::max_pool1d).
 pub fn max_pool1d<B>(
     x: Tensor<B, 3>,
@@ -1,4 +1,4 @@
-use crate::backend::Backend;
+use crate::backend::{Backend, ComplexBackend};
 
 // We provide some type aliases to improve the readability of using associated types without
 // having to use the disambiguation syntax.
@@ -10,6 +10,8 @@ pub type Device<B> = <B as Backend>::Device;
 pub type FloatElem<B> = <B as Backend>::FloatElem;
 /// Integer element type used by backend.
 pub type IntElem<B> = <B as Backend>::IntElem;
+/// Complex element type used by backend.
+pub type ComplexElem<B> = <B as ComplexBackend>::ComplexElem;
 /// Full precision float element type used by the backend.
 pub type FullPrecisionBackend<B> = <B as Backend>::FullPrecisionBackend;
 
@@ -19,3 +21,5 @@ pub type FloatTensor<B, const D: usize> = <B as Backend>::TensorPrimitive<D>;
 pub type IntTensor<B, const D: usize> = <B as Backend>::IntTensorPrimitive<D>;
 /// Boolean tensor primitive type used by the backend.
 pub type BoolTensor<B, const D: usize> = <B as Backend>::BoolTensorPrimitive<D>;
+/// Complex tensor primitive type used by the backend.
+pub type ComplexTensor<B, const D: usize> = <B as ComplexBackend>::ComplexTensorPrimitive<D>;
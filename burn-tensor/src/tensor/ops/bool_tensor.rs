@@ -1,5 +1,5 @@
 use super::{BoolTensor, Device, FloatTensor, IntTensor};
-use crate::{backend::Backend, chunk, narrow, tensor::Shape, Bool, Data};
+use crate::{backend::Backend, chunk, narrow, nonzero, tensor::Shape, Bool, Data};
 use alloc::vec::Vec;
 use burn_common::reader::Reader;
 use core::ops::Range;
@@ -302,4 +302,22 @@ pub trait BoolTensorOps<B: Backend> {
     ) -> Vec<BoolTensor<B, D>> {
         chunk::<B, D, Bool>(tensor, chunks, dim)
     }
+
+    /// Gets the indices of the `true` elements of the tensor.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor.
+    ///
+    /// # Returns
+    ///
+    /// A vector with a 1-D tensor of indices for each dimension, in row-major order.
+    ///
+    /// # Remarks
+    ///
+    /// The length of the output depends on the values of the tensor, so the default
+    /// implementation reads the tensor back to the host.
+    fn bool_nonzero<const D: usize>(tensor: BoolTensor<B, D>) -> Vec<IntTensor<B, 1>> {
+        nonzero::<B, D>(tensor)
+    }
 }
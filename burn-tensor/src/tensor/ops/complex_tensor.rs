@@ -0,0 +1,213 @@
+use super::{ComplexElem, ComplexTensor, Device, FloatTensor};
+use crate::{backend::ComplexBackend, tensor::Shape, Data};
+use burn_common::reader::Reader;
+
+/// Complex Tensor API for basic operations, see [tensor](crate::Tensor)
+/// for documentation on each function.
+pub trait ComplexTensorOps<B: ComplexBackend> {
+    /// Creates a tensor from the data structure.
+    ///
+    /// # Arguments
+    ///
+    /// * `data` - The data structure.
+    /// * `device` - The device to create the tensor on.
+    ///
+    /// # Returns
+    ///
+    /// The tensor with the data.
+    fn complex_from_data<const D: usize>(
+        data: Data<ComplexElem<B>, D>,
+        device: &Device<B>,
+    ) -> ComplexTensor<B, D>;
+
+    /// Converts the tensor to a data structure.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor.
+    ///
+    /// # Returns
+    ///
+    /// The data structure with the tensor's data.
+    fn complex_into_data<const D: usize>(
+        tensor: ComplexTensor<B, D>,
+    ) -> Reader<Data<ComplexElem<B>, D>>;
+
+    /// Returns the shape of the tensor.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor.
+    ///
+    /// # Returns
+    ///
+    /// The shape of the tensor.
+    fn complex_shape<const D: usize>(tensor: &ComplexTensor<B, D>) -> Shape<D>;
+
+    /// Gets the device of the tensor.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor.
+    ///
+    /// # Returns
+    ///
+    /// The device of the tensor.
+    fn complex_device<const D: usize>(tensor: &ComplexTensor<B, D>) -> Device<B>;
+
+    /// Reshapes the tensor.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor.
+    /// * `shape` - The new shape of the tensor.
+    ///
+    /// # Returns
+    ///
+    /// The tensor with the new shape.
+    fn complex_reshape<const D1: usize, const D2: usize>(
+        tensor: ComplexTensor<B, D1>,
+        shape: Shape<D2>,
+    ) -> ComplexTensor<B, D2>;
+
+    /// Creates a complex tensor from its real and imaginary parts.
+    ///
+    /// # Arguments
+    ///
+    /// * `real` - The real part.
+    /// * `imag` - The imaginary part, with the same shape as the real part.
+    ///
+    /// # Returns
+    ///
+    /// The complex tensor `real + i * imag`.
+    fn complex_from_parts<const D: usize>(
+        real: FloatTensor<B, D>,
+        imag: FloatTensor<B, D>,
+    ) -> ComplexTensor<B, D>;
+
+    /// Returns the real part of the tensor.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor.
+    ///
+    /// # Returns
+    ///
+    /// The float tensor with the real part of each element.
+    fn complex_real<const D: usize>(tensor: ComplexTensor<B, D>) -> FloatTensor<B, D>;
+
+    /// Returns the imaginary part of the tensor.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor.
+    ///
+    /// # Returns
+    ///
+    /// The float tensor with the imaginary part of each element.
+    fn complex_imag<const D: usize>(tensor: ComplexTensor<B, D>) -> FloatTensor<B, D>;
+
+    /// Adds two tensors together.
+    ///
+    /// # Arguments
+    ///
+    /// * `lhs` - The left hand side tensor.
+    /// * `rhs` - The right hand side tensor.
+    ///
+    /// # Returns
+    ///
+    /// The result of adding the two tensors together.
+    fn complex_add<const D: usize>(
+        lhs: ComplexTensor<B, D>,
+        rhs: ComplexTensor<B, D>,
+    ) -> ComplexTensor<B, D>;
+
+    /// Subtracts two tensors.
+    ///
+    /// # Arguments
+    ///
+    /// * `lhs` - The left hand side tensor.
+    /// * `rhs` - The right hand side tensor.
+    ///
+    /// # Returns
+    ///
+    /// The result of subtracting the two tensors.
+    fn complex_sub<const D: usize>(
+        lhs: ComplexTensor<B, D>,
+        rhs: ComplexTensor<B, D>,
+    ) -> ComplexTensor<B, D>;
+
+    /// Multiplies two tensors together element-wise.
+    ///
+    /// # Arguments
+    ///
+    /// * `lhs` - The left hand side tensor.
+    /// * `rhs` - The right hand side tensor.
+    ///
+    /// # Returns
+    ///
+    /// The result of multiplying the two tensors together.
+    fn complex_mul<const D: usize>(
+        lhs: ComplexTensor<B, D>,
+        rhs: ComplexTensor<B, D>,
+    ) -> ComplexTensor<B, D>;
+
+    /// Divides two tensors element-wise.
+    ///
+    /// # Arguments
+    ///
+    /// * `lhs` - The left hand side tensor.
+    /// * `rhs` - The right hand side tensor.
+    ///
+    /// # Returns
+    ///
+    /// The result of dividing the two tensors.
+    fn complex_div<const D: usize>(
+        lhs: ComplexTensor<B, D>,
+        rhs: ComplexTensor<B, D>,
+    ) -> ComplexTensor<B, D>;
+
+    /// Negates the tensor element-wise.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor.
+    ///
+    /// # Returns
+    ///
+    /// The negated tensor.
+    fn complex_neg<const D: usize>(tensor: ComplexTensor<B, D>) -> ComplexTensor<B, D>;
+
+    /// Returns the complex conjugate of the tensor element-wise.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor.
+    ///
+    /// # Returns
+    ///
+    /// The tensor with the sign of the imaginary part flipped.
+    fn complex_conj<const D: usize>(tensor: ComplexTensor<B, D>) -> ComplexTensor<B, D>;
+
+    /// Returns the magnitude of the tensor element-wise.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor.
+    ///
+    /// # Returns
+    ///
+    /// The float tensor with the magnitude `sqrt(re^2 + im^2)` of each element.
+    fn complex_abs<const D: usize>(tensor: ComplexTensor<B, D>) -> FloatTensor<B, D>;
+
+    /// Returns the argument of the tensor element-wise.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor.
+    ///
+    /// # Returns
+    ///
+    /// The float tensor with the angle `atan2(im, re)` of each element, in `[-pi, pi]`.
+    fn complex_angle<const D: usize>(tensor: ComplexTensor<B, D>) -> FloatTensor<B, D>;
+}
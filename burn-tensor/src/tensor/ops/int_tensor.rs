@@ -1,6 +1,9 @@
 use super::{BoolTensor, Device, FloatTensor, IntElem, IntTensor};
-use crate::{backend::Backend, tensor::Shape, Data, ElementConversion, Int};
-use crate::{tensor::api::chunk, tensor::api::narrow};
+use crate::tensor::api::{
+    bincount, chunk, cumprod, cumsum, masked_select, narrow, pad, scatter_max, scatter_min,
+    searchsorted, sort_with_indices, unique,
+};
+use crate::{backend::Backend, tensor::Shape, Data, ElementConversion, Int, PadMode};
 use alloc::vec::Vec;
 use burn_common::reader::Reader;
 use core::ops::Range;
@@ -211,6 +214,60 @@ pub trait IntTensorOps<B: Backend> {
         value: IntTensor<B, D>,
     ) -> IntTensor<B, D>;
 
+    /// Scatter elements into a tensor, keeping the maximum of each element and of the values
+    /// scattered into it.
+    ///
+    /// # Arguments
+    ///
+    /// * `dim` - The dimension to scatter into.
+    /// * `tensor` - The tensor to scatter into.
+    /// * `indices` - The indices to scatter into.
+    /// * `value` - The value to scatter.
+    ///
+    /// # Returns
+    ///
+    /// The tensor with the maximum of its elements and of the scattered elements.
+    ///
+    /// # Remarks
+    ///
+    /// The default implementation reads the tensors back to the host, so backends should
+    /// override it with a native implementation.
+    fn int_scatter_max<const D: usize>(
+        dim: usize,
+        tensor: IntTensor<B, D>,
+        indices: IntTensor<B, D>,
+        value: IntTensor<B, D>,
+    ) -> IntTensor<B, D> {
+        scatter_max::<B, D, Int>(dim, tensor, indices, value)
+    }
+
+    /// Scatter elements into a tensor, keeping the minimum of each element and of the values
+    /// scattered into it.
+    ///
+    /// # Arguments
+    ///
+    /// * `dim` - The dimension to scatter into.
+    /// * `tensor` - The tensor to scatter into.
+    /// * `indices` - The indices to scatter into.
+    /// * `value` - The value to scatter.
+    ///
+    /// # Returns
+    ///
+    /// The tensor with the minimum of its elements and of the scattered elements.
+    ///
+    /// # Remarks
+    ///
+    /// The default implementation reads the tensors back to the host, so backends should
+    /// override it with a native implementation.
+    fn int_scatter_min<const D: usize>(
+        dim: usize,
+        tensor: IntTensor<B, D>,
+        indices: IntTensor<B, D>,
+        value: IntTensor<B, D>,
+    ) -> IntTensor<B, D> {
+        scatter_min::<B, D, Int>(dim, tensor, indices, value)
+    }
+
     /// Select tensor elements along the given dimension corresponding to the given indices.
     ///
     /// # Arguments
@@ -895,4 +952,180 @@ pub trait IntTensorOps<B: Backend> {
     ) -> Vec<IntTensor<B, D>> {
         chunk::<B, D, Int>(tensor, chunks, dim)
     }
+
+    /// Sort the elements of the tensor along the given dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to sort.
+    /// * `dim` - The dimension along which to sort.
+    /// * `descending` - If the elements are sorted in descending order.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the elements of `tensor` sorted along `dim`.
+    fn int_sort<const D: usize>(
+        tensor: IntTensor<B, D>,
+        dim: usize,
+        descending: bool,
+    ) -> IntTensor<B, D> {
+        B::int_sort_with_indices(tensor, dim, descending).0
+    }
+
+    /// Sort the elements of the tensor along the given dimension and returns their indices.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to sort.
+    /// * `dim` - The dimension along which to sort.
+    /// * `descending` - If the elements are sorted in descending order.
+    ///
+    /// # Returns
+    ///
+    /// A tuple with the elements of `tensor` sorted along `dim` and their indices in `tensor`.
+    fn int_sort_with_indices<const D: usize>(
+        tensor: IntTensor<B, D>,
+        dim: usize,
+        descending: bool,
+    ) -> (IntTensor<B, D>, IntTensor<B, D>) {
+        sort_with_indices::<B, D, Int>(tensor, dim, descending)
+    }
+
+    /// Gets the indices that sort the elements of the tensor along the given dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to sort.
+    /// * `dim` - The dimension along which to sort.
+    /// * `descending` - If the elements are sorted in descending order.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the indices of the sorted elements of `tensor` along `dim`.
+    fn int_argsort<const D: usize>(
+        tensor: IntTensor<B, D>,
+        dim: usize,
+        descending: bool,
+    ) -> IntTensor<B, D> {
+        B::int_sort_with_indices(tensor, dim, descending).1
+    }
+
+    /// Computes the cumulative sum of the elements along the given dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor.
+    /// * `dim` - The dimension along which the elements are summed.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as `tensor`, where each element is the sum of the elements
+    /// before it along `dim`, itself included.
+    fn int_cumsum<const D: usize>(tensor: IntTensor<B, D>, dim: usize) -> IntTensor<B, D> {
+        cumsum::<B, D, Int>(tensor, dim)
+    }
+
+    /// Computes the cumulative product of the elements along the given dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor.
+    /// * `dim` - The dimension along which the elements are multiplied.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as `tensor`, where each element is the product of the elements
+    /// before it along `dim`, itself included.
+    fn int_cumprod<const D: usize>(tensor: IntTensor<B, D>, dim: usize) -> IntTensor<B, D> {
+        cumprod::<B, D, Int>(tensor, dim)
+    }
+
+    /// Gets the elements of the tensor where the mask is `true`.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor.
+    /// * `mask` - The boolean mask, with the same shape as the tensor.
+    ///
+    /// # Returns
+    ///
+    /// A 1-D tensor with the selected elements, in row-major order.
+    fn int_masked_select<const D: usize>(
+        tensor: IntTensor<B, D>,
+        mask: BoolTensor<B, D>,
+    ) -> IntTensor<B, 1> {
+        masked_select::<B, D, Int>(tensor, mask)
+    }
+
+    /// Finds the indices where the values should be inserted in the sorted sequences to keep them
+    /// sorted.
+    ///
+    /// # Arguments
+    ///
+    /// * `sorted_sequence` - The sequences sorted in ascending order along the last dimension.
+    /// * `values` - The values to search, with the same leading dimensions as `sorted_sequence`.
+    /// * `right` - If `false`, the index of the first element greater than or equal to the value
+    ///   is returned, otherwise the index of the first element strictly greater than the value.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as `values` with the insertion indices.
+    fn int_searchsorted<const D: usize>(
+        sorted_sequence: IntTensor<B, D>,
+        values: IntTensor<B, D>,
+        right: bool,
+    ) -> IntTensor<B, D> {
+        searchsorted::<B, D, Int>(sorted_sequence, values, right)
+    }
+
+    /// Pads the tensor along each dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor.
+    /// * `padding` - The number of elements added before and after the tensor along each
+    ///   dimension.
+    /// * `mode` - How the padded elements are filled.
+    ///
+    /// # Returns
+    ///
+    /// The padded tensor, where the size of each dimension is increased by its padding.
+    fn int_pad<const D: usize>(
+        tensor: IntTensor<B, D>,
+        padding: [(usize, usize); D],
+        mode: PadMode,
+    ) -> IntTensor<B, D> {
+        pad::<B, D, Int>(tensor, padding, mode)
+    }
+
+    /// Gets the unique elements of the tensor.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor.
+    ///
+    /// # Returns
+    ///
+    /// A tuple with the unique elements in ascending order, the index of each element of `tensor`
+    /// in the unique elements, with the same shape as `tensor`, and the number of occurrences of
+    /// each unique element.
+    fn int_unique<const D: usize>(
+        tensor: IntTensor<B, D>,
+    ) -> (IntTensor<B, 1>, IntTensor<B, D>, IntTensor<B, 1>) {
+        unique::<B, D>(tensor)
+    }
+
+    /// Counts the number of occurrences of each value of the tensor.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor of non-negative integers.
+    /// * `min_length` - The minimum number of bins.
+    ///
+    /// # Returns
+    ///
+    /// The counts of the values from zero to the maximum of `tensor` and `min_length - 1`.
+    fn int_bincount(tensor: IntTensor<B, 1>, min_length: usize) -> IntTensor<B, 1> {
+        bincount::<B>(tensor, min_length)
+    }
 }
@@ -1,6 +1,7 @@
 mod activation;
 mod alias;
 mod bool_tensor;
+mod complex_tensor;
 mod int_tensor;
 mod modules;
 mod tensor;
@@ -8,6 +9,7 @@ mod tensor;
 pub use activation::*;
 pub use alias::*;
 pub use bool_tensor::*;
+pub use complex_tensor::*;
 pub use int_tensor::*;
 pub use modules::*;
 pub use tensor::*;
@@ -0,0 +1,112 @@
+use crate::{backend::Backend, ops::FloatTensor, ElementConversion};
+use alloc::vec::Vec;
+
+use super::AttentionBackward;
+
+/// Number of query rows processed at once, which bounds the size of the attention matrix
+/// materialized at any time to `[batch_size, n_heads, ATTENTION_BLOCK_SIZE, seq_length_2]`.
+const ATTENTION_BLOCK_SIZE: usize = 128;
+
+fn attention_weights<B: Backend>(
+    query: FloatTensor<B, 4>,
+    key: FloatTensor<B, 4>,
+    scale: f32,
+) -> FloatTensor<B, 4> {
+    let scores = B::mul_scalar(B::matmul(query, B::swap_dims(key, 2, 3)), scale.elem());
+    let scores = B::exp(B::sub(scores.clone(), B::max_dim(scores, 3)));
+
+    B::div(scores.clone(), B::sum_dim(scores, 3))
+}
+
+/// Calculate the [attention](crate::ops::ModuleOps::attention) by blocks of queries.
+pub fn attention_from_blocks<B: Backend>(
+    query: FloatTensor<B, 4>,
+    key: FloatTensor<B, 4>,
+    value: FloatTensor<B, 4>,
+    scale: f32,
+) -> FloatTensor<B, 4> {
+    let [batch_size, n_heads, seq_length, d_k] = B::shape(&query).dims;
+
+    let outputs = (0..seq_length)
+        .step_by(ATTENTION_BLOCK_SIZE)
+        .map(|start| {
+            let end = usize::min(start + ATTENTION_BLOCK_SIZE, seq_length);
+            let query = B::slice(
+                query.clone(),
+                [0..batch_size, 0..n_heads, start..end, 0..d_k],
+            );
+
+            B::matmul(
+                attention_weights::<B>(query, key.clone(), scale),
+                value.clone(),
+            )
+        })
+        .collect::<Vec<_>>();
+
+    B::cat(outputs, 2)
+}
+
+/// Calculate the [attention](crate::ops::ModuleOps::attention) backward pass by blocks of queries.
+pub fn attention_backward_from_blocks<B: Backend>(
+    query: FloatTensor<B, 4>,
+    key: FloatTensor<B, 4>,
+    value: FloatTensor<B, 4>,
+    output: FloatTensor<B, 4>,
+    grad: FloatTensor<B, 4>,
+    scale: f32,
+) -> AttentionBackward<B> {
+    let [batch_size, n_heads, seq_length, d_k] = B::shape(&query).dims;
+    let [_, _, _, d_v] = B::shape(&value).dims;
+
+    let value_transposed = B::swap_dims(value.clone(), 2, 3);
+    let mut query_grads = Vec::new();
+    let mut key_grads = Vec::new();
+    let mut value_grads = Vec::new();
+
+    for start in (0..seq_length).step_by(ATTENTION_BLOCK_SIZE) {
+        let end = usize::min(start + ATTENTION_BLOCK_SIZE, seq_length);
+        let query = B::slice(
+            query.clone(),
+            [0..batch_size, 0..n_heads, start..end, 0..d_k],
+        );
+        let output = B::slice(
+            output.clone(),
+            [0..batch_size, 0..n_heads, start..end, 0..d_v],
+        );
+        let grad = B::slice(
+            grad.clone(),
+            [0..batch_size, 0..n_heads, start..end, 0..d_v],
+        );
+
+        // The attention weights are recomputed instead of being kept from the forward pass.
+        let weights = attention_weights::<B>(query.clone(), key.clone(), scale);
+
+        value_grads.push(B::matmul(B::swap_dims(weights.clone(), 2, 3), grad.clone()));
+
+        // The softmax backward needs the row-wise sum of `grad_weights * weights`, which is equal
+        // to the row-wise sum of `grad * output`, avoiding another pass over the weights.
+        let delta = B::sum_dim(B::mul(grad.clone(), output), 3);
+        let grad_weights = B::matmul(grad, value_transposed.clone());
+        let grad_scores = B::mul_scalar(B::mul(weights, B::sub(grad_weights, delta)), scale.elem());
+
+        key_grads.push(B::matmul(B::swap_dims(grad_scores.clone(), 2, 3), query));
+        query_grads.push(B::matmul(grad_scores, key.clone()));
+    }
+
+    AttentionBackward::new(
+        B::cat(query_grads, 2),
+        sum_blocks::<B>(key_grads),
+        sum_blocks::<B>(value_grads),
+    )
+}
+
+/// Sum the contributions of every block of queries.
+///
+/// The sum starts from the first contribution instead of zeros, so that the graph of the
+/// gradients isn't merged into the graph of a new tensor when the backward pass is differentiated.
+fn sum_blocks<B: Backend>(grads: Vec<FloatTensor<B, 4>>) -> FloatTensor<B, 4> {
+    grads
+        .into_iter()
+        .reduce(B::add)
+        .expect("There should be at least one block of queries")
+}
@@ -1,4 +1,4 @@
-use super::{conv, pool, unfold::unfold4d_using_conv2d};
+use super::{attention, conv, pool, quantization, unfold::unfold4d_using_conv2d};
 use crate::{
     backend::Backend,
     ops::{FloatTensor, IntTensor},
@@ -65,6 +65,19 @@ pub struct Conv1dBackward<B: Backend> {
     pub bias_grad: Option<FloatTensor<B, 1>>,
 }
 
+/// Gradient computed during the backward pass for each tensor used by [attention](ModuleOps::attention).
+#[derive(new)]
+pub struct AttentionBackward<B: Backend> {
+    /// Query gradient.
+    pub query_grad: FloatTensor<B, 4>,
+
+    /// Key gradient.
+    pub key_grad: FloatTensor<B, 4>,
+
+    /// Value gradient.
+    pub value_grad: FloatTensor<B, 4>,
+}
+
 /// Convolution options.
 #[derive(new, Debug, Clone, Hash, PartialEq, Eq)]
 pub struct ConvOptions<const N: usize> {
@@ -274,6 +287,86 @@ pub trait ModuleOps<B: Backend> {
         unfold4d_using_conv2d::<B>(x, kernel_size, options)
     }
 
+    /// Scaled dot-product attention, computing `softmax(scale * query @ keyᵀ) @ value`.
+    ///
+    /// The queries are processed by blocks, so the full attention matrix is never materialized.
+    ///
+    /// # Shapes
+    ///
+    /// query:   `[batch_size, n_heads, seq_length_1, d_k]`,
+    /// key:     `[batch_size, n_heads, seq_length_2, d_k]`,
+    /// value:   `[batch_size, n_heads, seq_length_2, d_v]`,
+    /// returns: `[batch_size, n_heads, seq_length_1, d_v]`,
+    fn attention(
+        query: FloatTensor<B, 4>,
+        key: FloatTensor<B, 4>,
+        value: FloatTensor<B, 4>,
+        scale: f32,
+    ) -> FloatTensor<B, 4> {
+        attention::attention_from_blocks::<B>(query, key, value, scale)
+    }
+    /// Backward pass for the [attention](ModuleOps::attention) operation.
+    ///
+    /// The attention weights are recomputed by blocks from the query and the key, so only the
+    /// inputs and the output of the forward pass have to be kept. The default implementation is
+    /// composed of tensor operations, so it can be differentiated when autodiff backends are
+    /// nested.
+    fn attention_backward(
+        query: FloatTensor<B, 4>,
+        key: FloatTensor<B, 4>,
+        value: FloatTensor<B, 4>,
+        output: FloatTensor<B, 4>,
+        grad: FloatTensor<B, 4>,
+        scale: f32,
+    ) -> AttentionBackward<B> {
+        attention::attention_backward_from_blocks::<B>(query, key, value, output, grad, scale)
+    }
+
+    /// Dequantize a block-wise 4-bit weight matrix.
+    ///
+    /// The 4-bit values of 8 consecutive rows are packed in each int, the value of row
+    /// `8 * i + j` being stored in the bits `4 * j..4 * j + 4` of the row `i` with a two's
+    /// complement 32 bits representation. Each block of `block_size` rows has its own scale
+    /// and zero point per column, a value `q` being dequantized as `(q - zero_point) * scale`.
+    ///
+    /// # Shapes
+    ///
+    /// weight:      `[d_input / 8, d_output]`,
+    /// scales:      `[d_input / block_size, d_output]`,
+    /// zero_points: `[d_input / block_size, d_output]`,
+    /// returns:     `[d_input, d_output]`,
+    fn int4_dequantize(
+        weight: IntTensor<B, 2>,
+        scales: FloatTensor<B, 2>,
+        zero_points: FloatTensor<B, 2>,
+        block_size: usize,
+    ) -> FloatTensor<B, 2> {
+        quantization::int4_dequantize_from_int_ops::<B>(weight, scales, zero_points, block_size)
+    }
+
+    /// Matrix multiplication with a block-wise [4-bit weight matrix](ModuleOps::int4_dequantize).
+    ///
+    /// Backends with a fused kernel dequantize the weights on the fly, without materializing
+    /// the float weight matrix.
+    ///
+    /// # Shapes
+    ///
+    /// lhs:     `[batch_size, d_input]`,
+    /// weight:  `[d_input / 8, d_output]`,
+    /// returns: `[batch_size, d_output]`,
+    fn int4_matmul(
+        lhs: FloatTensor<B, 2>,
+        weight: IntTensor<B, 2>,
+        scales: FloatTensor<B, 2>,
+        zero_points: FloatTensor<B, 2>,
+        block_size: usize,
+    ) -> FloatTensor<B, 2> {
+        B::matmul(
+            lhs,
+            B::int4_dequantize(weight, scales, zero_points, block_size),
+        )
+    }
+
     /// One dimensional avg pooling.
     ///
     /// # Shapes
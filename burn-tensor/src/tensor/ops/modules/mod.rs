@@ -1,3 +1,6 @@
+/// Module with attention operations.
+pub mod attention;
+
 /// Module with convolution operations.
 pub mod conv;
 
@@ -7,6 +10,9 @@ pub(crate) mod unfold;
 /// Module with pooling operations.
 pub mod pool;
 
+/// Module with 4-bit quantization operations.
+pub mod quantization;
+
 mod base;
 
 pub use base::*;
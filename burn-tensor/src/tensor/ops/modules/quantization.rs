@@ -0,0 +1,57 @@
+use crate::{
+    backend::Backend,
+    ops::{FloatTensor, IntTensor},
+    ElementConversion, Shape,
+};
+use alloc::vec::Vec;
+
+/// Number of 4-bit values packed in each element of an int4 weight tensor.
+pub const INT4_PER_INT: usize = 8;
+
+pub(crate) fn int4_dequantize_from_int_ops<B: Backend>(
+    weight: IntTensor<B, 2>,
+    scales: FloatTensor<B, 2>,
+    zero_points: FloatTensor<B, 2>,
+    block_size: usize,
+) -> FloatTensor<B, 2> {
+    let [num_packed, d_output] = B::int_shape(&weight).dims;
+    let d_input = num_packed * INT4_PER_INT;
+
+    let mut packed = weight;
+    let mut values = Vec::with_capacity(INT4_PER_INT);
+    for _ in 0..INT4_PER_INT {
+        // The division truncates toward zero, it is corrected to a floor division so that the
+        // sign bit stored in the last value is unpacked correctly.
+        let quotient = B::int_div_scalar(packed.clone(), 16.elem());
+        let remainder = B::int_sub(packed, B::int_mul_scalar(quotient.clone(), 16.elem()));
+        let negative = B::int_lower_elem(remainder.clone(), 0.elem());
+
+        let value = B::int_mask_where(
+            remainder.clone(),
+            negative.clone(),
+            B::int_add_scalar(remainder, 16.elem()),
+        );
+        values.push(B::int_reshape(value, Shape::new([num_packed, 1, d_output])));
+        packed = B::int_mask_where(
+            quotient.clone(),
+            negative,
+            B::int_sub_scalar(quotient, 1.elem()),
+        );
+    }
+
+    let weight = B::int_reshape(B::int_cat(values, 1), Shape::new([d_input, d_output]));
+    let weight = B::int_into_float(weight);
+    let scales = expand_blocks::<B>(scales, block_size);
+    let zero_points = expand_blocks::<B>(zero_points, block_size);
+
+    B::mul(B::sub(weight, zero_points), scales)
+}
+
+/// Repeat each row of a tensor of shape `[num_blocks, d_output]` for all the rows of its block.
+fn expand_blocks<B: Backend>(tensor: FloatTensor<B, 2>, block_size: usize) -> FloatTensor<B, 2> {
+    let [num_blocks, d_output] = B::shape(&tensor).dims;
+    let tensor = B::reshape(tensor, Shape::new([num_blocks, 1, d_output]));
+    let tensor = B::repeat(tensor, 1, block_size);
+
+    B::reshape(tensor, Shape::new([num_blocks * block_size, d_output]))
+}
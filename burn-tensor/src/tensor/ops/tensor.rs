@@ -1,6 +1,15 @@
 use super::{BoolTensor, Device, FloatElem, FloatTensor, FullPrecisionBackend, IntElem, IntTensor};
-use crate::{backend::Backend, tensor::Shape, Data, Distribution, ElementConversion, Float};
-use crate::{tensor::api::chunk, tensor::api::narrow};
+use crate::tensor::api::{
+    chunk, cumprod, cumsum, masked_select, narrow, pad, scatter_max, scatter_min, searchsorted,
+    sort_with_indices,
+};
+use crate::{
+    backend::Backend,
+    fft::{self, FftError},
+    linalg, sparse,
+    tensor::Shape,
+    Data, Distribution, ElementConversion, Float, PadMode,
+};
 use alloc::vec::Vec;
 use burn_common::reader::Reader;
 use core::ops::Range;
@@ -495,6 +504,60 @@ pub trait TensorOps<B: Backend> {
         value: FloatTensor<B, D>,
     ) -> FloatTensor<B, D>;
 
+    /// Scatter elements into a tensor, keeping the maximum of each element and of the values
+    /// scattered into it.
+    ///
+    /// # Arguments
+    ///
+    /// * `dim` - The dimension to scatter into.
+    /// * `tensor` - The tensor to scatter into.
+    /// * `indices` - The indices to scatter into.
+    /// * `value` - The value to scatter.
+    ///
+    /// # Returns
+    ///
+    /// The tensor with the maximum of its elements and of the scattered elements.
+    ///
+    /// # Remarks
+    ///
+    /// The default implementation reads the tensors back to the host, so backends should
+    /// override it with a native implementation.
+    fn scatter_max<const D: usize>(
+        dim: usize,
+        tensor: FloatTensor<B, D>,
+        indices: IntTensor<B, D>,
+        value: FloatTensor<B, D>,
+    ) -> FloatTensor<B, D> {
+        scatter_max::<B, D, Float>(dim, tensor, indices, value)
+    }
+
+    /// Scatter elements into a tensor, keeping the minimum of each element and of the values
+    /// scattered into it.
+    ///
+    /// # Arguments
+    ///
+    /// * `dim` - The dimension to scatter into.
+    /// * `tensor` - The tensor to scatter into.
+    /// * `indices` - The indices to scatter into.
+    /// * `value` - The value to scatter.
+    ///
+    /// # Returns
+    ///
+    /// The tensor with the minimum of its elements and of the scattered elements.
+    ///
+    /// # Remarks
+    ///
+    /// The default implementation reads the tensors back to the host, so backends should
+    /// override it with a native implementation.
+    fn scatter_min<const D: usize>(
+        dim: usize,
+        tensor: FloatTensor<B, D>,
+        indices: IntTensor<B, D>,
+        value: FloatTensor<B, D>,
+    ) -> FloatTensor<B, D> {
+        scatter_min::<B, D, Float>(dim, tensor, indices, value)
+    }
+
     /// Select tensor elements along the given dimension corresponding for the given indices.
     ///
     /// # Arguments
@@ -1120,4 +1183,305 @@ pub trait TensorOps<B: Backend> {
     ) -> Vec<FloatTensor<B, D>> {
         chunk::<B, D, Float>(tensor, chunks, dim)
     }
+
+    /// Sort the elements of the tensor along the given dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to sort.
+    /// * `dim` - The dimension along which to sort.
+    /// * `descending` - If the elements are sorted in descending order.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the elements of `tensor` sorted along `dim`.
+    fn sort<const D: usize>(
+        tensor: FloatTensor<B, D>,
+        dim: usize,
+        descending: bool,
+    ) -> FloatTensor<B, D> {
+        B::sort_with_indices(tensor, dim, descending).0
+    }
+
+    /// Sort the elements of the tensor along the given dimension and returns their indices.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to sort.
+    /// * `dim` - The dimension along which to sort.
+    /// * `descending` - If the elements are sorted in descending order.
+    ///
+    /// # Returns
+    ///
+    /// A tuple with the elements of `tensor` sorted along `dim` and their indices in `tensor`.
+    fn sort_with_indices<const D: usize>(
+        tensor: FloatTensor<B, D>,
+        dim: usize,
+        descending: bool,
+    ) -> (FloatTensor<B, D>, IntTensor<B, D>) {
+        sort_with_indices::<B, D, Float>(tensor, dim, descending)
+    }
+
+    /// Gets the indices that sort the elements of the tensor along the given dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to sort.
+    /// * `dim` - The dimension along which to sort.
+    /// * `descending` - If the elements are sorted in descending order.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the indices of the sorted elements of `tensor` along `dim`.
+    fn argsort<const D: usize>(
+        tensor: FloatTensor<B, D>,
+        dim: usize,
+        descending: bool,
+    ) -> IntTensor<B, D> {
+        B::sort_with_indices(tensor, dim, descending).1
+    }
+
+    /// Computes the cumulative sum of the elements along the given dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor.
+    /// * `dim` - The dimension along which the elements are summed.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as `tensor`, where each element is the sum of the elements
+    /// before it along `dim`, itself included.
+    fn cumsum<const D: usize>(tensor: FloatTensor<B, D>, dim: usize) -> FloatTensor<B, D> {
+        cumsum::<B, D, Float>(tensor, dim)
+    }
+
+    /// Computes the cumulative product of the elements along the given dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor.
+    /// * `dim` - The dimension along which the elements are multiplied.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as `tensor`, where each element is the product of the elements
+    /// before it along `dim`, itself included.
+    fn cumprod<const D: usize>(tensor: FloatTensor<B, D>, dim: usize) -> FloatTensor<B, D> {
+        cumprod::<B, D, Float>(tensor, dim)
+    }
+
+    /// Gets the elements of the tensor where the mask is `true`.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor.
+    /// * `mask` - The boolean mask, with the same shape as the tensor.
+    ///
+    /// # Returns
+    ///
+    /// A 1-D tensor with the selected elements, in row-major order.
+    fn masked_select<const D: usize>(
+        tensor: FloatTensor<B, D>,
+        mask: BoolTensor<B, D>,
+    ) -> FloatTensor<B, 1> {
+        masked_select::<B, D, Float>(tensor, mask)
+    }
+
+    /// Finds the indices where the values should be inserted in the sorted sequences to keep them
+    /// sorted.
+    ///
+    /// # Arguments
+    ///
+    /// * `sorted_sequence` - The sequences sorted in ascending order along the last dimension.
+    /// * `values` - The values to search, with the same leading dimensions as `sorted_sequence`.
+    /// * `right` - If `false`, the index of the first element greater than or equal to the value
+    ///   is returned, otherwise the index of the first element strictly greater than the value.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as `values` with the insertion indices.
+    fn searchsorted<const D: usize>(
+        sorted_sequence: FloatTensor<B, D>,
+        values: FloatTensor<B, D>,
+        right: bool,
+    ) -> IntTensor<B, D> {
+        searchsorted::<B, D, Float>(sorted_sequence, values, right)
+    }
+
+    /// Pads the tensor along each dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor.
+    /// * `padding` - The number of elements added before and after the tensor along each
+    ///   dimension.
+    /// * `mode` - How the padded elements are filled.
+    ///
+    /// # Returns
+    ///
+    /// The padded tensor, where the size of each dimension is increased by its padding.
+    fn pad<const D: usize>(
+        tensor: FloatTensor<B, D>,
+        padding: [(usize, usize); D],
+        mode: PadMode,
+    ) -> FloatTensor<B, D> {
+        pad::<B, D, Float>(tensor, padding, mode)
+    }
+
+    /// Solves the linear systems `lhs @ x = rhs` for `x`.
+    ///
+    /// # Arguments
+    ///
+    /// * `lhs` - The square matrices, of shape `[..., n, n]`.
+    /// * `rhs` - The right hand side, of shape `[..., n, k]`.
+    ///
+    /// # Returns
+    ///
+    /// The solution `x`, of shape `[..., n, k]`.
+    fn solve<const D: usize>(lhs: FloatTensor<B, D>, rhs: FloatTensor<B, D>) -> FloatTensor<B, D> {
+        linalg::host::solve::<B, D>(lhs, rhs)
+    }
+
+    /// Computes the inverse of square matrices.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The square matrices, of shape `[..., n, n]`.
+    ///
+    /// # Returns
+    ///
+    /// The inverse of the matrices.
+    fn inverse<const D: usize>(tensor: FloatTensor<B, D>) -> FloatTensor<B, D> {
+        linalg::host::inverse::<B, D>(tensor)
+    }
+
+    /// Computes the Cholesky decomposition of symmetric positive-definite matrices.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The square matrices, of shape `[..., n, n]`.
+    ///
+    /// # Returns
+    ///
+    /// The lower triangular factor `l` with `tensor = l @ l^T`.
+    fn cholesky<const D: usize>(tensor: FloatTensor<B, D>) -> FloatTensor<B, D> {
+        linalg::host::cholesky::<B, D>(tensor)
+    }
+
+    /// Computes the reduced QR decomposition of matrices.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The matrices, of shape `[..., m, n]`.
+    ///
+    /// # Returns
+    ///
+    /// A tuple with `q`, of shape `[..., m, k]` with orthonormal columns, and the upper triangular
+    /// `r`, of shape `[..., k, n]`, where `k = min(m, n)`.
+    fn qr<const D: usize>(tensor: FloatTensor<B, D>) -> (FloatTensor<B, D>, FloatTensor<B, D>) {
+        linalg::host::qr::<B, D>(tensor)
+    }
+
+    /// Computes the reduced singular value decomposition of matrices.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The matrices, of shape `[..., m, n]`.
+    ///
+    /// # Returns
+    ///
+    /// A tuple with the left singular vectors `u`, of shape `[..., m, k]`, the singular values
+    /// in descending order, of shape `[..., 1, k]`, and the transposed right singular vectors
+    /// `vt`, of shape `[..., k, n]`, where `k = min(m, n)`.
+    fn svd<const D: usize>(
+        tensor: FloatTensor<B, D>,
+    ) -> (FloatTensor<B, D>, FloatTensor<B, D>, FloatTensor<B, D>) {
+        linalg::host::svd::<B, D>(tensor)
+    }
+
+    /// Computes the discrete Fourier transform of a complex signal, or its inverse, along the
+    /// given dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `real` - The real part of the signal.
+    /// * `imag` - The imaginary part of the signal.
+    /// * `dim` - The dimension of the transform.
+    /// * `inverse` - If the inverse transform is computed, normalized by the length of the signal.
+    ///
+    /// # Returns
+    ///
+    /// The real and imaginary parts of the transform, or [FftError::Unsupported] by default.
+    fn fft<const D: usize>(
+        real: FloatTensor<B, D>,
+        imag: FloatTensor<B, D>,
+        dim: usize,
+        inverse: bool,
+    ) -> Result<(FloatTensor<B, D>, FloatTensor<B, D>), FftError> {
+        let _ = (real, imag, dim, inverse);
+
+        Err(FftError::Unsupported)
+    }
+
+    /// Computes the discrete Fourier transform of a real signal along the given dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `signal` - The real signal.
+    /// * `dim` - The dimension of the transform.
+    ///
+    /// # Returns
+    ///
+    /// The real and imaginary parts of the `n / 2 + 1` non-negative frequencies.
+    fn rfft<const D: usize>(
+        signal: FloatTensor<B, D>,
+        dim: usize,
+    ) -> Result<(FloatTensor<B, D>, FloatTensor<B, D>), FftError> {
+        fft::rfft_with_fft::<B, D>(signal, dim)
+    }
+
+    /// Computes the real signal with the given non-negative frequencies along the given dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `real` - The real part of the `n / 2 + 1` non-negative frequencies.
+    /// * `imag` - The imaginary part of the `n / 2 + 1` non-negative frequencies.
+    /// * `dim` - The dimension of the transform.
+    /// * `n` - The length of the signal.
+    ///
+    /// # Returns
+    ///
+    /// The real signal of length `n` along `dim`.
+    fn irfft<const D: usize>(
+        real: FloatTensor<B, D>,
+        imag: FloatTensor<B, D>,
+        dim: usize,
+        n: usize,
+    ) -> Result<FloatTensor<B, D>, FftError> {
+        fft::irfft_with_fft::<B, D>(real, imag, dim, n)
+    }
+
+    /// Performs the matrix multiplication of a sparse matrix in the coordinate format with a
+    /// dense matrix.
+    ///
+    /// # Arguments
+    ///
+    /// * `indices` - The row indices followed by the column indices of the sparse matrix, of
+    ///   shape `[2, nnz]`.
+    /// * `values` - The values of the sparse matrix, of shape `[nnz]`.
+    /// * `shape` - The shape `[m, k]` of the sparse matrix.
+    /// * `dense` - The dense matrix, of shape `[k, n]`.
+    ///
+    /// # Returns
+    ///
+    /// The dense product of shape `[m, n]`, where the duplicate values are summed.
+    fn spmm(
+        indices: IntTensor<B, 2>,
+        values: FloatTensor<B, 1>,
+        shape: Shape<2>,
+        dense: FloatTensor<B, 2>,
+    ) -> FloatTensor<B, 2> {
+        sparse::spmm_with_select::<B>(indices, values, shape, dense)
+    }
 }
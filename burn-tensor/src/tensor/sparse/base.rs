@@ -0,0 +1,349 @@
+#![allow(clippy::single_range_in_vec_init)]
+
+use alloc::vec;
+
+use crate::backend::Backend;
+use crate::check::TensorCheck;
+use crate::ops::{FloatTensor, IntTensor};
+use crate::{check, Bool, Int, Shape, Tensor};
+
+/// The layout of a [sparse tensor](SparseTensor).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SparseLayout {
+    /// Coordinate format, with the row and the column index of each value.
+    Coo,
+    /// Compressed sparse row format, with the column index of each value and the offsets of the
+    /// rows in the values.
+    Csr,
+}
+
+#[derive(Debug, Clone)]
+enum SparseIndices<B: Backend> {
+    Coo {
+        indices: Tensor<B, 2, Int>,
+    },
+    Csr {
+        row_offsets: Tensor<B, 1, Int>,
+        col_indices: Tensor<B, 1, Int>,
+    },
+}
+
+/// A sparse matrix, storing only the indices and the values of its nonzero elements.
+///
+/// The values are a float tensor, so they are tracked by the autodiff backend like any other
+/// tensor. Duplicate indices are allowed, their values are summed.
+///
+/// # Example
+///
+/// ```rust
+/// use burn_tensor::backend::Backend;
+/// use burn_tensor::sparse::SparseTensor;
+/// use burn_tensor::{Int, Tensor};
+///
+/// fn example<B: Backend>() {
+///     let device = Default::default();
+///     let indices = Tensor::<B, 2, Int>::from_ints([[0, 1, 1], [1, 0, 2]], &device);
+///     let values = Tensor::<B, 1>::from_floats([2.0, 3.0, 4.0], &device);
+///     let adjacency = SparseTensor::from_coo(indices, values, [2, 3]);
+///
+///     let features = Tensor::<B, 2>::ones([3, 4], &device);
+///     let output = adjacency.matmul(features);
+///     println!("{}", output);
+///     // [[2.0, 2.0, 2.0, 2.0], [7.0, 7.0, 7.0, 7.0]]
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct SparseTensor<B: Backend> {
+    indices: SparseIndices<B>,
+    values: Tensor<B, 1>,
+    shape: Shape<2>,
+}
+
+impl<B: Backend> SparseTensor<B> {
+    /// Creates a sparse matrix in the coordinate format.
+    ///
+    /// # Arguments
+    ///
+    /// * `indices` - The row indices followed by the column indices, of shape `[2, nnz]`.
+    /// * `values` - The values, of shape `[nnz]`.
+    /// * `shape` - The shape of the matrix.
+    pub fn from_coo<S: Into<Shape<2>>>(
+        indices: Tensor<B, 2, Int>,
+        values: Tensor<B, 1>,
+        shape: S,
+    ) -> Self {
+        let shape = shape.into();
+        check!(TensorCheck::sparse_coo(
+            &indices.shape(),
+            &values.shape(),
+            &shape
+        ));
+
+        Self {
+            indices: SparseIndices::Coo { indices },
+            values,
+            shape,
+        }
+    }
+
+    /// Creates a sparse matrix in the compressed sparse row format.
+    ///
+    /// # Arguments
+    ///
+    /// * `row_offsets` - The offsets of the rows in the values, of shape `[rows + 1]`, starting
+    ///   with zero and ending with `nnz`.
+    /// * `col_indices` - The column index of each value, of shape `[nnz]`.
+    /// * `values` - The values, of shape `[nnz]`, ordered by row.
+    /// * `shape` - The shape of the matrix.
+    pub fn from_csr<S: Into<Shape<2>>>(
+        row_offsets: Tensor<B, 1, Int>,
+        col_indices: Tensor<B, 1, Int>,
+        values: Tensor<B, 1>,
+        shape: S,
+    ) -> Self {
+        let shape = shape.into();
+        check!(TensorCheck::sparse_csr(
+            &row_offsets.shape(),
+            &col_indices.shape(),
+            &values.shape(),
+            &shape
+        ));
+
+        Self {
+            indices: SparseIndices::Csr {
+                row_offsets,
+                col_indices,
+            },
+            values,
+            shape,
+        }
+    }
+
+    /// Creates a sparse matrix in the coordinate format with the nonzero elements of a dense
+    /// matrix.
+    ///
+    /// The positions of the nonzero elements are read back to the host, but the values are
+    /// gathered on the device, so their gradients flow back to the dense matrix.
+    pub fn from_dense(tensor: Tensor<B, 2>) -> Self {
+        let shape = tensor.shape();
+        let mask = tensor.clone().equal_elem(0.0).bool_not();
+        let (indices, positions) = nonzero_positions(mask);
+        let values = tensor.reshape([shape.num_elements()]).select(0, positions);
+
+        Self::from_coo(indices, values, shape)
+    }
+
+    /// Creates a sparse matrix in the coordinate format with a value of one where the mask is
+    /// `true`, e.g. the adjacency matrix of a graph.
+    ///
+    /// The mask is read back to the host.
+    pub fn from_mask(mask: Tensor<B, 2, Bool>) -> Self {
+        let shape = mask.shape();
+        let device = mask.device();
+        let (indices, positions) = nonzero_positions(mask);
+        let values = Tensor::ones(positions.shape(), &device);
+
+        Self::from_coo(indices, values, shape)
+    }
+
+    /// Returns the layout of the sparse matrix.
+    pub fn layout(&self) -> SparseLayout {
+        match self.indices {
+            SparseIndices::Coo { .. } => SparseLayout::Coo,
+            SparseIndices::Csr { .. } => SparseLayout::Csr,
+        }
+    }
+
+    /// Returns the shape of the matrix.
+    pub fn shape(&self) -> Shape<2> {
+        self.shape.clone()
+    }
+
+    /// Returns the number of stored values, duplicates and explicit zeros included.
+    pub fn nnz(&self) -> usize {
+        self.values.dims()[0]
+    }
+
+    /// Returns the device of the sparse matrix.
+    pub fn device(&self) -> B::Device {
+        self.values.device()
+    }
+
+    /// Returns the stored values, in the order of the indices.
+    pub fn values(&self) -> Tensor<B, 1> {
+        self.values.clone()
+    }
+
+    /// Converts the sparse matrix to the coordinate format.
+    pub fn to_coo(self) -> Self {
+        let shape = self.shape.clone();
+        let (indices, values) = self.into_coo();
+
+        Self {
+            indices: SparseIndices::Coo { indices },
+            values,
+            shape,
+        }
+    }
+
+    /// Converts the sparse matrix to the compressed sparse row format, sorting the values by row.
+    pub fn to_csr(self) -> Self {
+        let shape = self.shape.clone();
+        let (row_offsets, col_indices, values) = self.into_csr();
+
+        Self {
+            indices: SparseIndices::Csr {
+                row_offsets,
+                col_indices,
+            },
+            values,
+            shape,
+        }
+    }
+
+    /// Returns the indices, of shape `[2, nnz]`, and the values of the coordinate format.
+    pub fn into_coo(self) -> (Tensor<B, 2, Int>, Tensor<B, 1>) {
+        match self.indices {
+            SparseIndices::Coo { indices } => (indices, self.values),
+            SparseIndices::Csr {
+                row_offsets,
+                col_indices,
+            } => {
+                let nnz = col_indices.dims()[0];
+                let rows = self.shape.dims[0];
+                let device = col_indices.device();
+
+                // Each row offset marks the start of the next row, so the row of a value is
+                // the number of offsets, the first one excluded, up to its position.
+                let markers = Tensor::<B, 1, Int>::zeros([nnz + 1], &device).scatter(
+                    0,
+                    row_offsets.slice([1..rows + 1]),
+                    Tensor::ones([rows], &device),
+                );
+                let row_indices = markers.cumsum(0).slice([0..nnz]);
+                let indices = Tensor::stack(vec![row_indices, col_indices], 0);
+
+                (indices, self.values)
+            }
+        }
+    }
+
+    /// Returns the row offsets, of shape `[rows + 1]`, the column indices and the values of the
+    /// compressed sparse row format.
+    pub fn into_csr(self) -> (Tensor<B, 1, Int>, Tensor<B, 1, Int>, Tensor<B, 1>) {
+        match self.indices {
+            SparseIndices::Csr {
+                row_offsets,
+                col_indices,
+            } => (row_offsets, col_indices, self.values),
+            SparseIndices::Coo { indices } => {
+                let nnz = indices.dims()[1];
+                let rows = self.shape.dims[0];
+                let device = indices.device();
+
+                let (row_indices, order) = indices
+                    .clone()
+                    .slice([0..1, 0..nnz])
+                    .reshape([nnz])
+                    .sort_with_indices(0, false);
+                let col_indices = indices
+                    .slice([1..2, 0..nnz])
+                    .reshape([nnz])
+                    .select(0, order.clone());
+                let values = self.values.select(0, order);
+
+                let counts = Tensor::<B, 1, Int>::zeros([rows + 1], &device).scatter(
+                    0,
+                    row_indices.add_scalar(1),
+                    Tensor::ones([nnz], &device),
+                );
+
+                (counts.cumsum(0), col_indices, values)
+            }
+        }
+    }
+
+    /// Converts the sparse matrix to a dense matrix, summing the duplicate values.
+    pub fn to_dense(self) -> Tensor<B, 2> {
+        let shape = self.shape.clone();
+        let device = self.device();
+        let [_, cols] = shape.dims;
+        let (indices, values) = self.into_coo();
+        let nnz = values.dims()[0];
+
+        let rows = indices.clone().slice([0..1, 0..nnz]).reshape([nnz]);
+        let positions = indices
+            .slice([1..2, 0..nnz])
+            .reshape([nnz])
+            .add(rows.mul_scalar(cols as i64));
+
+        Tensor::zeros([shape.num_elements()], &device)
+            .select_assign(0, positions, values)
+            .reshape(shape)
+    }
+
+    /// Performs the matrix multiplication of the sparse matrix with a dense matrix.
+    ///
+    /// See [spmm].
+    pub fn matmul(self, rhs: Tensor<B, 2>) -> Tensor<B, 2> {
+        spmm(self, rhs)
+    }
+}
+
+/// Performs the sparse-dense matrix multiplication `lhs @ rhs`, summing the duplicate values of
+/// the sparse matrix.
+///
+/// The memory and the computation scale with the number of stored values of the sparse matrix
+/// instead of its number of elements.
+pub fn spmm<B: Backend>(lhs: SparseTensor<B>, rhs: Tensor<B, 2>) -> Tensor<B, 2> {
+    check!(TensorCheck::spmm(&lhs.shape, &rhs.shape()));
+
+    let shape = lhs.shape.clone();
+    let (indices, values) = lhs.into_coo();
+
+    Tensor::from_primitive(B::spmm(
+        indices.into_primitive(),
+        values.into_primitive(),
+        shape,
+        rhs.into_primitive(),
+    ))
+}
+
+/// Computes the sparse-dense matrix multiplication by gathering the rows of the dense matrix
+/// selected by the column indices, scaling them by the values and summing them into the rows of
+/// the output.
+pub(crate) fn spmm_with_select<B: Backend>(
+    indices: IntTensor<B, 2>,
+    values: FloatTensor<B, 1>,
+    shape: Shape<2>,
+    dense: FloatTensor<B, 2>,
+) -> FloatTensor<B, 2> {
+    let indices = Tensor::<B, 2, Int>::from_primitive(indices);
+    let values = Tensor::<B, 1>::from_primitive(values);
+    let dense = Tensor::<B, 2>::from_primitive(dense);
+
+    let device = dense.device();
+    let [rows, _] = shape.dims;
+    let [_, cols] = dense.dims();
+    let nnz = values.dims()[0];
+
+    let row_indices = indices.clone().slice([0..1, 0..nnz]).reshape([nnz]);
+    let col_indices = indices.slice([1..2, 0..nnz]).reshape([nnz]);
+    let products = dense.select(0, col_indices) * values.reshape([nnz, 1]);
+
+    Tensor::zeros([rows, cols], &device)
+        .select_assign(0, row_indices, products)
+        .into_primitive()
+}
+
+/// Returns the coordinates, of shape `[2, nnz]`, and the flat positions of the `true` elements of
+/// the mask, in row-major order.
+fn nonzero_positions<B: Backend>(
+    mask: Tensor<B, 2, Bool>,
+) -> (Tensor<B, 2, Int>, Tensor<B, 1, Int>) {
+    let [_, cols] = mask.dims();
+    let indices = mask.nonzero();
+    let positions = indices[0].clone().mul_scalar(cols as i64) + indices[1].clone();
+
+    (Tensor::stack(indices, 0), positions)
+}
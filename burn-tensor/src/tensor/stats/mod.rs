@@ -36,3 +36,57 @@ pub fn var_with_mean_n<B: Backend, const D: usize>(
 ) -> Tensor<B, D> {
     tensor.sub(mean).powf(2.0).sum_dim(dim).div_scalar(n as f32)
 }
+
+#[allow(clippy::single_range_in_vec_init)]
+pub fn histc<B: Backend, const D: usize>(
+    tensor: Tensor<B, D>,
+    bins: usize,
+    min: f64,
+    max: f64,
+) -> Tensor<B, 1> {
+    let device = tensor.device();
+    let n = tensor.shape().num_elements();
+    let tensor = tensor.reshape([n]);
+
+    // The elements outside of the range are counted in an extra bin that is dropped.
+    let indices = tensor
+        .clone()
+        .sub_scalar(min)
+        .mul_scalar(bins as f64 / (max - min))
+        .clamp(0.0, (bins - 1) as f64)
+        .int()
+        .mask_fill(tensor.clone().lower_elem(min), bins as i64)
+        .mask_fill(tensor.greater_elem(max), bins as i64);
+
+    Tensor::<B, 1>::zeros([bins + 1], &device)
+        .scatter(0, indices, Tensor::ones([n], &device))
+        .slice([0..bins])
+}
+
+pub fn quantile<B: Backend, const D: usize>(
+    tensor: Tensor<B, D>,
+    q: f64,
+    dim: usize,
+) -> Tensor<B, D> {
+    let n = tensor.shape().dims[dim];
+    let position = q * (n - 1) as f64;
+    let lower = position as usize;
+
+    let sorted = tensor.sort(dim, false);
+    let values_lower = sorted.clone().narrow(dim, lower, 1);
+
+    if lower + 1 >= n {
+        return values_lower;
+    }
+
+    let values_upper = sorted.narrow(dim, lower + 1, 1);
+    let weight = position - lower as f64;
+
+    values_lower.clone() + values_upper.sub(values_lower).mul_scalar(weight)
+}
+
+pub fn median<B: Backend, const D: usize>(tensor: Tensor<B, D>, dim: usize) -> Tensor<B, D> {
+    let n = tensor.shape().dims[dim];
+
+    tensor.sort(dim, false).narrow(dim, (n - 1) / 2, 1)
+}
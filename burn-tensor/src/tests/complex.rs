@@ -0,0 +1,192 @@
+#[burn_tensor_testgen::testgen(complex)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Complex, Data, Tensor};
+    use core::f32::consts::{FRAC_PI_2, FRAC_PI_4, PI, SQRT_2};
+
+    type TestTensorComplex<const D: usize> = Tensor<TestBackend, D, Complex>;
+
+    fn complex<const D: usize>(real: TestTensor<D>, imag: TestTensor<D>) -> TestTensorComplex<D> {
+        TestTensorComplex::from_parts(real, imag)
+    }
+
+    #[test]
+    fn should_support_real_and_imag_parts() {
+        let device = Default::default();
+        let tensor = complex(
+            TestTensor::from_floats([[1.0, 2.0], [3.0, 4.0]], &device),
+            TestTensor::from_floats([[-1.0, 0.5], [0.0, 2.0]], &device),
+        );
+
+        assert_eq!(tensor.dims(), [2, 2]);
+        tensor
+            .clone()
+            .real()
+            .into_data()
+            .assert_approx_eq(&Data::from([[1.0, 2.0], [3.0, 4.0]]), 3);
+        tensor
+            .imag()
+            .into_data()
+            .assert_approx_eq(&Data::from([[-1.0, 0.5], [0.0, 2.0]]), 3);
+    }
+
+    #[test]
+    fn should_support_data_roundtrip() {
+        let device = Default::default();
+        let tensor = complex(
+            TestTensor::from_floats([1.0, -2.0, 3.0], &device),
+            TestTensor::from_floats([0.5, 0.0, -4.0], &device),
+        );
+
+        let data = tensor.into_data();
+        let tensor = TestTensorComplex::from_data(data, &device);
+
+        tensor
+            .clone()
+            .real()
+            .into_data()
+            .assert_approx_eq(&Data::from([1.0, -2.0, 3.0]), 3);
+        tensor
+            .imag()
+            .into_data()
+            .assert_approx_eq(&Data::from([0.5, 0.0, -4.0]), 3);
+    }
+
+    #[test]
+    fn should_support_arithmetic() {
+        let device = Default::default();
+        let lhs = complex(
+            TestTensor::from_floats([1.0, 2.0], &device),
+            TestTensor::from_floats([2.0, -1.0], &device),
+        );
+        let rhs = complex(
+            TestTensor::from_floats([3.0, 0.0], &device),
+            TestTensor::from_floats([-1.0, 1.0], &device),
+        );
+
+        let add = lhs.clone() + rhs.clone();
+        let sub = lhs.clone() - rhs.clone();
+        let mul = lhs.clone() * rhs.clone();
+        let div = mul.clone() / rhs;
+        let neg = -lhs;
+
+        add.clone()
+            .real()
+            .into_data()
+            .assert_approx_eq(&Data::from([4.0, 2.0]), 3);
+        add.imag()
+            .into_data()
+            .assert_approx_eq(&Data::from([1.0, 0.0]), 3);
+        sub.clone()
+            .real()
+            .into_data()
+            .assert_approx_eq(&Data::from([-2.0, 2.0]), 3);
+        sub.imag()
+            .into_data()
+            .assert_approx_eq(&Data::from([3.0, -2.0]), 3);
+        // (1 + 2i)(3 - i) = 5 + 5i and (2 - i)i = 1 + 2i.
+        mul.clone()
+            .real()
+            .into_data()
+            .assert_approx_eq(&Data::from([5.0, 1.0]), 3);
+        mul.imag()
+            .into_data()
+            .assert_approx_eq(&Data::from([5.0, 2.0]), 3);
+        div.clone()
+            .real()
+            .into_data()
+            .assert_approx_eq(&Data::from([1.0, 2.0]), 3);
+        div.imag()
+            .into_data()
+            .assert_approx_eq(&Data::from([2.0, -1.0]), 3);
+        neg.clone()
+            .real()
+            .into_data()
+            .assert_approx_eq(&Data::from([-1.0, -2.0]), 3);
+        neg.imag()
+            .into_data()
+            .assert_approx_eq(&Data::from([-2.0, 1.0]), 3);
+    }
+
+    #[test]
+    fn should_support_conj() {
+        let device = Default::default();
+        let tensor = complex(
+            TestTensor::from_floats([1.0, -2.0], &device),
+            TestTensor::from_floats([3.0, -4.0], &device),
+        );
+
+        let output = tensor.conj();
+
+        output
+            .clone()
+            .real()
+            .into_data()
+            .assert_approx_eq(&Data::from([1.0, -2.0]), 3);
+        output
+            .imag()
+            .into_data()
+            .assert_approx_eq(&Data::from([-3.0, 4.0]), 3);
+    }
+
+    #[test]
+    fn should_support_magnitude_and_angle() {
+        let device = Default::default();
+        let tensor = complex(
+            TestTensor::from_floats([3.0, 0.0, -1.0, 1.0], &device),
+            TestTensor::from_floats([4.0, 2.0, 0.0, -1.0], &device),
+        );
+
+        tensor
+            .clone()
+            .magnitude()
+            .into_data()
+            .assert_approx_eq(&Data::from([5.0, 2.0, 1.0, SQRT_2]), 3);
+        tensor
+            .angle()
+            .into_data()
+            .assert_approx_eq(&Data::from([0.92730, FRAC_PI_2, PI, -FRAC_PI_4]), 3);
+    }
+
+    #[test]
+    fn should_support_layout_ops() {
+        let device = Default::default();
+        let tensor = complex(
+            TestTensor::from_floats([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]], &device),
+            TestTensor::from_floats([[-1.0, -2.0, -3.0], [-4.0, -5.0, -6.0]], &device),
+        );
+
+        let output = TestTensorComplex::cat(
+            vec![tensor.clone().slice([0..2, 1..3]), tensor.transpose()],
+            0,
+        )
+        .reshape([10]);
+
+        output.clone().real().into_data().assert_approx_eq(
+            &Data::from([2.0, 3.0, 5.0, 6.0, 1.0, 4.0, 2.0, 5.0, 3.0, 6.0]),
+            3,
+        );
+        output.imag().into_data().assert_approx_eq(
+            &Data::from([-2.0, -3.0, -5.0, -6.0, -1.0, -4.0, -2.0, -5.0, -3.0, -6.0]),
+            3,
+        );
+    }
+
+    #[test]
+    fn should_convert_float_tensor() {
+        let device = Default::default();
+        let tensor = TestTensor::from_floats([1.0, -2.0], &device);
+
+        let output = tensor.complex();
+
+        output
+            .clone()
+            .real()
+            .into_data()
+            .assert_approx_eq(&Data::from([1.0, -2.0]), 3);
+        output
+            .imag()
+            .into_data()
+            .assert_approx_eq(&Data::from([0.0, 0.0]), 3);
+    }
+}
@@ -0,0 +1,100 @@
+#[burn_tensor_testgen::testgen(fft)]
+mod tests {
+    use super::*;
+    use burn_tensor::{fft, Data};
+
+    #[test]
+    fn should_support_rfft() {
+        let device = Default::default();
+        let signal = TestTensor::from_floats([[1.0, 2.0, 3.0, 4.0], [1.0, 1.0, 1.0, 1.0]], &device);
+
+        let (real, imag) = fft::rfft(signal, 1).unwrap();
+
+        real.into_data()
+            .assert_approx_eq(&Data::from([[10.0, -2.0, -2.0], [4.0, 0.0, 0.0]]), 3);
+        imag.into_data()
+            .assert_approx_eq(&Data::from([[0.0, 2.0, 0.0], [0.0, 0.0, 0.0]]), 3);
+    }
+
+    #[test]
+    fn should_support_irfft_even_length() {
+        let device = Default::default();
+        let signal =
+            TestTensor::from_floats([[1.0, 2.0, 3.0, 4.0], [-1.0, 0.5, 2.0, 0.0]], &device);
+
+        let (real, imag) = fft::rfft(signal.clone(), 1).unwrap();
+        let output = fft::irfft(real, imag, 1, 4).unwrap();
+
+        output.into_data().assert_approx_eq(&signal.into_data(), 3);
+    }
+
+    #[test]
+    fn should_support_irfft_odd_length() {
+        let device = Default::default();
+        let signal = TestTensor::from_floats([[1.0, 2.0], [3.0, -4.0], [5.0, 0.0]], &device);
+
+        let (real, imag) = fft::rfft(signal.clone(), 0).unwrap();
+        assert_eq!(real.dims(), [2, 2]);
+        let output = fft::irfft(real, imag, 0, 3).unwrap();
+
+        output.into_data().assert_approx_eq(&signal.into_data(), 3);
+    }
+
+    #[test]
+    fn should_support_fft_of_complex_signal() {
+        let device = Default::default();
+        let real = TestTensor::from_floats([1.0, 0.0], &device);
+        let imag = TestTensor::from_floats([0.0, 1.0], &device);
+
+        let (real, imag) = fft::fft(real, imag, 0).unwrap();
+
+        real.into_data()
+            .assert_approx_eq(&Data::from([1.0, 1.0]), 3);
+        imag.into_data()
+            .assert_approx_eq(&Data::from([1.0, -1.0]), 3);
+    }
+
+    #[test]
+    fn should_support_ifft_roundtrip() {
+        let device = Default::default();
+        let real = TestTensor::from_floats([[1.0, -2.0, 0.5], [3.0, 0.0, 1.0]], &device);
+        let imag = TestTensor::from_floats([[0.0, 1.0, -1.5], [2.0, 2.0, -3.0]], &device);
+
+        let (spectrum_real, spectrum_imag) = fft::fft(real.clone(), imag.clone(), 1).unwrap();
+        let (output_real, output_imag) = fft::ifft(spectrum_real, spectrum_imag, 1).unwrap();
+
+        output_real
+            .into_data()
+            .assert_approx_eq(&real.into_data(), 3);
+        output_imag
+            .into_data()
+            .assert_approx_eq(&imag.into_data(), 3);
+    }
+
+    #[test]
+    fn should_support_fft2() {
+        let device = Default::default();
+        let real = TestTensor::from_floats([[1.0, 2.0], [3.0, 4.0]], &device);
+        let imag = TestTensor::zeros([2, 2], &device);
+
+        let (spectrum_real, spectrum_imag) = fft::fft2(real.clone(), imag.clone()).unwrap();
+
+        spectrum_real
+            .clone()
+            .into_data()
+            .assert_approx_eq(&Data::from([[10.0, -2.0], [-4.0, 0.0]]), 3);
+        spectrum_imag
+            .clone()
+            .into_data()
+            .assert_approx_eq(&Data::from([[0.0, 0.0], [0.0, 0.0]]), 3);
+
+        let (output_real, output_imag) = fft::ifft2(spectrum_real, spectrum_imag).unwrap();
+
+        output_real
+            .into_data()
+            .assert_approx_eq(&real.into_data(), 3);
+        output_imag
+            .into_data()
+            .assert_approx_eq(&imag.into_data(), 3);
+    }
+}
@@ -0,0 +1,41 @@
+#[burn_tensor_testgen::testgen(cholesky)]
+mod tests {
+    use super::*;
+    use burn_tensor::{linalg, Data};
+
+    #[test]
+    fn should_compute_lower_cholesky_factor() {
+        let device = Default::default();
+        let tensor = TestTensor::from_floats(
+            [
+                [4.0, 12.0, -16.0],
+                [12.0, 37.0, -43.0],
+                [-16.0, -43.0, 98.0],
+            ],
+            &device,
+        );
+
+        let output = linalg::cholesky(tensor);
+
+        output.into_data().assert_approx_eq(
+            &Data::from([[2.0, 0.0, 0.0], [6.0, 1.0, 0.0], [-8.0, 5.0, 3.0]]),
+            3,
+        );
+    }
+
+    #[test]
+    fn should_compute_cholesky_factor_of_batch() {
+        let device = Default::default();
+        let tensor = TestTensor::from_floats(
+            [[[4.0, 2.0], [2.0, 5.0]], [[9.0, 0.0], [0.0, 1.0]]],
+            &device,
+        );
+
+        let output = linalg::cholesky(tensor);
+
+        output.into_data().assert_approx_eq(
+            &Data::from([[[2.0, 0.0], [1.0, 2.0]], [[3.0, 0.0], [0.0, 1.0]]]),
+            3,
+        );
+    }
+}
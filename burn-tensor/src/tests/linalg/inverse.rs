@@ -0,0 +1,33 @@
+#[burn_tensor_testgen::testgen(inverse)]
+mod tests {
+    use super::*;
+    use burn_tensor::{linalg, Data, Tensor};
+
+    #[test]
+    fn should_inverse_matrix() {
+        let device = Default::default();
+        let tensor = TestTensor::from_floats([[4.0, 7.0], [2.0, 6.0]], &device);
+
+        let output = linalg::inverse(tensor);
+
+        output
+            .into_data()
+            .assert_approx_eq(&Data::from([[0.6, -0.7], [-0.2, 0.4]]), 3);
+    }
+
+    #[test]
+    fn should_give_identity_when_multiplied_by_inverse() {
+        let device = Default::default();
+        let tensor = TestTensor::from_floats(
+            [[2.0, -1.0, 0.0], [-1.0, 2.0, -1.0], [0.0, -1.0, 2.0]],
+            &device,
+        );
+
+        let output = tensor.clone().matmul(linalg::inverse(tensor));
+
+        output.into_data().assert_approx_eq(
+            &Tensor::<TestBackend, 2>::diagonal(3, &device).into_data(),
+            3,
+        );
+    }
+}
@@ -0,0 +1,5 @@
+mod cholesky;
+mod inverse;
+mod qr;
+mod solve;
+mod svd;
@@ -0,0 +1,45 @@
+#[burn_tensor_testgen::testgen(qr)]
+mod tests {
+    use super::*;
+    use burn_tensor::{linalg, Tensor};
+
+    #[test]
+    fn should_decompose_tall_matrix() {
+        let device = Default::default();
+        let tensor = TestTensor::from_floats([[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]], &device);
+
+        let (q, r) = linalg::qr(tensor.clone());
+
+        assert_eq!(q.dims(), [3, 2]);
+        assert_eq!(r.dims(), [2, 2]);
+        q.clone()
+            .matmul(r.clone())
+            .into_data()
+            .assert_approx_eq(&tensor.into_data(), 3);
+        q.clone()
+            .transpose()
+            .matmul(q)
+            .into_data()
+            .assert_approx_eq(
+                &Tensor::<TestBackend, 2>::diagonal(2, &device).into_data(),
+                3,
+            );
+        r.clone()
+            .into_data()
+            .assert_approx_eq(&r.triu(0).into_data(), 3);
+    }
+
+    #[test]
+    fn should_decompose_wide_matrix() {
+        let device = Default::default();
+        let tensor = TestTensor::from_floats([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]], &device);
+
+        let (q, r) = linalg::qr(tensor.clone());
+
+        assert_eq!(q.dims(), [2, 2]);
+        assert_eq!(r.dims(), [2, 3]);
+        q.matmul(r)
+            .into_data()
+            .assert_approx_eq(&tensor.into_data(), 3);
+    }
+}
@@ -0,0 +1,58 @@
+#[burn_tensor_testgen::testgen(solve)]
+mod tests {
+    use super::*;
+    use burn_tensor::{linalg, Data};
+
+    #[test]
+    fn should_solve_linear_system() {
+        let device = Default::default();
+        let lhs = TestTensor::from_floats([[3.0, 1.0], [1.0, 2.0]], &device);
+        let rhs = TestTensor::from_floats([[9.0, 1.0], [8.0, 2.0]], &device);
+
+        let output = linalg::solve(lhs, rhs);
+
+        output
+            .into_data()
+            .assert_approx_eq(&Data::from([[2.0, 0.0], [3.0, 1.0]]), 3);
+    }
+
+    #[test]
+    fn should_solve_with_pivoting() {
+        let device = Default::default();
+        let lhs =
+            TestTensor::from_floats([[0.0, 2.0, 1.0], [1.0, 1.0, 0.0], [2.0, 0.0, 3.0]], &device);
+        let rhs = TestTensor::from_floats([[7.0], [3.0], [11.0]], &device);
+
+        let output = linalg::solve(lhs, rhs);
+
+        output
+            .into_data()
+            .assert_approx_eq(&Data::from([[1.0], [2.0], [3.0]]), 3);
+    }
+
+    #[test]
+    fn should_solve_batch_of_linear_systems() {
+        let device = Default::default();
+        let lhs = TestTensor::from_floats(
+            [[[2.0, 0.0], [0.0, 4.0]], [[1.0, 1.0], [0.0, 1.0]]],
+            &device,
+        );
+        let rhs = TestTensor::from_floats([[[2.0], [2.0]], [[3.0], [1.0]]], &device);
+
+        let output = linalg::solve(lhs, rhs);
+
+        output
+            .into_data()
+            .assert_approx_eq(&Data::from([[[1.0], [0.5]], [[2.0], [1.0]]]), 3);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_lhs_is_not_square() {
+        let device = Default::default();
+        let lhs = TestTensor::from_floats([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]], &device);
+        let rhs = TestTensor::from_floats([[1.0], [2.0]], &device);
+
+        linalg::solve(lhs, rhs);
+    }
+}
@@ -0,0 +1,58 @@
+#[burn_tensor_testgen::testgen(svd)]
+mod tests {
+    use super::*;
+    use burn_tensor::{linalg, Data, Tensor};
+
+    #[test]
+    fn should_sort_singular_values_in_descending_order() {
+        let device = Default::default();
+        let tensor = TestTensor::from_floats([[2.0, 0.0], [0.0, -3.0]], &device);
+
+        let (_u, s, _vt) = linalg::svd(tensor);
+
+        s.into_data().assert_approx_eq(&Data::from([[3.0, 2.0]]), 3);
+    }
+
+    #[test]
+    fn should_reconstruct_tall_matrix() {
+        let device = Default::default();
+        let tensor = TestTensor::from_floats([[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]], &device);
+
+        let (u, s, vt) = linalg::svd(tensor.clone());
+
+        assert_eq!(u.dims(), [3, 2]);
+        assert_eq!(s.dims(), [1, 2]);
+        assert_eq!(vt.dims(), [2, 2]);
+        s.clone()
+            .into_data()
+            .assert_approx_eq(&Data::from([[9.5255, 0.5143]]), 3);
+        u.clone()
+            .transpose()
+            .matmul(u.clone())
+            .into_data()
+            .assert_approx_eq(
+                &Tensor::<TestBackend, 2>::diagonal(2, &device).into_data(),
+                3,
+            );
+        u.mul(s)
+            .matmul(vt)
+            .into_data()
+            .assert_approx_eq(&tensor.into_data(), 3);
+    }
+
+    #[test]
+    fn should_reconstruct_wide_matrix() {
+        let device = Default::default();
+        let tensor = TestTensor::from_floats([[1.0, 3.0, 5.0], [2.0, 4.0, 6.0]], &device);
+
+        let (u, s, vt) = linalg::svd(tensor.clone());
+
+        assert_eq!(u.dims(), [2, 2]);
+        assert_eq!(s.dims(), [1, 2]);
+        assert_eq!(vt.dims(), [2, 3]);
+        u.mul(s)
+            .matmul(vt)
+            .into_data()
+            .assert_approx_eq(&tensor.into_data(), 3);
+    }
+}
@@ -1,7 +1,11 @@
 mod activation;
 mod clone_invariance;
+mod complex;
+mod fft;
+mod linalg;
 mod module;
 mod ops;
+mod sparse;
 mod stats;
 
 #[allow(missing_docs)]
@@ -31,6 +35,8 @@ macro_rules! testgen_all {
         burn_tensor::testgen_module_avg_pool2d!();
         burn_tensor::testgen_module_adaptive_avg_pool1d!();
         burn_tensor::testgen_module_adaptive_avg_pool2d!();
+        burn_tensor::testgen_module_attention!();
+        burn_tensor::testgen_module_int4_matmul!();
 
         // test ops
         burn_tensor::testgen_add!();
@@ -43,8 +49,10 @@ macro_rules! testgen_all {
         burn_tensor::testgen_chunk!();
         burn_tensor::testgen_clamp!();
         burn_tensor::testgen_cos!();
+        burn_tensor::testgen_cumulative!();
         burn_tensor::testgen_create_like!();
         burn_tensor::testgen_div!();
+        burn_tensor::testgen_einsum!();
         burn_tensor::testgen_erf!();
         burn_tensor::testgen_exp!();
         burn_tensor::testgen_flatten!();
@@ -61,15 +69,19 @@ macro_rules! testgen_all {
         burn_tensor::testgen_mul!();
         burn_tensor::testgen_narrow!();
         burn_tensor::testgen_neg!();
+        burn_tensor::testgen_nonzero!();
         burn_tensor::testgen_one_hot!();
+        burn_tensor::testgen_pad!();
         burn_tensor::testgen_powf!();
         burn_tensor::testgen_random!();
         burn_tensor::testgen_recip!();
         burn_tensor::testgen_repeat!();
         burn_tensor::testgen_reshape!();
+        burn_tensor::testgen_searchsorted!();
         burn_tensor::testgen_select!();
         burn_tensor::testgen_sin!();
         burn_tensor::testgen_slice!();
+        burn_tensor::testgen_sort!();
         burn_tensor::testgen_stack!();
         burn_tensor::testgen_sqrt!();
         burn_tensor::testgen_abs!();
@@ -78,10 +90,23 @@ macro_rules! testgen_all {
         burn_tensor::testgen_tanh!();
         burn_tensor::testgen_transpose!();
         burn_tensor::testgen_tri!();
+        burn_tensor::testgen_unique!();
+
+        // test linalg
+        burn_tensor::testgen_cholesky!();
+        burn_tensor::testgen_inverse!();
+        burn_tensor::testgen_qr!();
+        burn_tensor::testgen_solve!();
+        burn_tensor::testgen_svd!();
+
+        // test sparse
+        burn_tensor::testgen_sparse!();
 
         // test stats
         burn_tensor::testgen_var!();
         burn_tensor::testgen_cov!();
+        burn_tensor::testgen_histc!();
+        burn_tensor::testgen_quantile!();
         burn_tensor::testgen_diagonal!();
         burn_tensor::testgen_display!();
 
@@ -0,0 +1,36 @@
+#[burn_tensor_testgen::testgen(module_attention)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::attention;
+    use burn_tensor::{Data, Tensor};
+
+    #[test]
+    fn test_attention_uniform_weights() {
+        let device = Default::default();
+        let query = Tensor::<TestBackend, 4>::zeros([1, 1, 2, 2], &device);
+        let key = Tensor::<TestBackend, 4>::from_floats([[[[1.0, 2.0], [3.0, 4.0]]]], &device);
+        let value =
+            Tensor::<TestBackend, 4>::from_floats([[[[1.0, 2.0, 3.0], [3.0, 4.0, 5.0]]]], &device);
+
+        let output = attention(query, key, value, 1.0);
+
+        output
+            .into_data()
+            .assert_approx_eq(&Data::from([[[[2.0, 3.0, 4.0], [2.0, 3.0, 4.0]]]]), 3);
+    }
+
+    #[test]
+    fn test_attention_scaled() {
+        let device = Default::default();
+        let query = Tensor::<TestBackend, 4>::from_floats([[[[1.0, 0.0]]]], &device);
+        let key = Tensor::<TestBackend, 4>::from_floats([[[[2.0, 0.0], [0.0, 0.0]]]], &device);
+        let value = Tensor::<TestBackend, 4>::from_floats([[[[1.0], [0.0]]]], &device);
+
+        // The scores are [1.0, 0.0] after scaling, so the weights are [e / (e + 1), 1 / (e + 1)].
+        let output = attention(query, key, value, 0.5);
+
+        output
+            .into_data()
+            .assert_approx_eq(&Data::from([[[[0.7311]]]]), 3);
+    }
+}
@@ -0,0 +1,62 @@
+#[burn_tensor_testgen::testgen(module_int4_matmul)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::{int4_dequantize, int4_matmul};
+    use burn_tensor::{Data, Int, Tensor};
+
+    #[test]
+    fn test_int4_dequantize() {
+        let (weight, scales, zero_points) = test_weights();
+
+        let output = int4_dequantize(weight, scales, zero_points, 4);
+
+        output.into_data().assert_approx_eq(
+            &Data::from([
+                [0.0, 0.0],
+                [1.0, 0.0],
+                [2.0, 0.0],
+                [3.0, 0.0],
+                [6.0, 0.25],
+                [8.0, 0.25],
+                [10.0, 0.25],
+                [12.0, 0.25],
+            ]),
+            3,
+        );
+    }
+
+    #[test]
+    fn test_int4_matmul() {
+        let device = Default::default();
+        let (weight, scales, zero_points) = test_weights();
+        let lhs = Tensor::<TestBackend, 2>::from_floats(
+            [
+                [1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0],
+                [1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 1.0],
+            ],
+            &device,
+        );
+
+        let output = int4_matmul(lhs, weight, scales, zero_points, 4);
+
+        output
+            .into_data()
+            .assert_approx_eq(&Data::from([[42.0, 1.0], [12.0, 0.25]]), 3);
+    }
+
+    /// The first column stores the values `0..8` and the second column the value 15 in every
+    /// row, which sets the sign bit of the packed int.
+    fn test_weights() -> (
+        Tensor<TestBackend, 2, Int>,
+        Tensor<TestBackend, 2>,
+        Tensor<TestBackend, 2>,
+    ) {
+        let device = Default::default();
+        let weight = Tensor::<TestBackend, 2, Int>::from_ints([[0x76543210, -1]], &device);
+        let scales = Tensor::<TestBackend, 2>::from_floats([[1.0, 0.5], [2.0, 0.25]], &device);
+        let zero_points =
+            Tensor::<TestBackend, 2>::from_floats([[0.0, 15.0], [1.0, 14.0]], &device);
+
+        (weight, scales, zero_points)
+    }
+}
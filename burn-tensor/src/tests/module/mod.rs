@@ -1,5 +1,6 @@
 mod adaptive_avgpool1d;
 mod adaptive_avgpool2d;
+mod attention;
 mod avgpool1d;
 mod avgpool2d;
 mod conv1d;
@@ -7,6 +8,7 @@ mod conv2d;
 mod conv_transpose1d;
 mod conv_transpose2d;
 mod forward;
+mod int4_matmul;
 mod maxpool1d;
 mod maxpool2d;
 mod unfold4d;
@@ -0,0 +1,58 @@
+#[burn_tensor_testgen::testgen(cumulative)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, Tensor};
+
+    #[test]
+    fn should_support_cumsum_ops() {
+        let tensor = TestTensor::from_floats(
+            [[1.0, 2.0, 3.0, 4.0, 5.0], [-1.0, 0.0, 2.0, -3.0, 1.0]],
+            &Default::default(),
+        );
+
+        let output = tensor.cumsum(1);
+
+        output.into_data().assert_approx_eq(
+            &Data::from([[1.0, 3.0, 6.0, 10.0, 15.0], [-1.0, -1.0, 1.0, -2.0, -1.0]]),
+            3,
+        );
+    }
+
+    #[test]
+    fn should_support_cumsum_ops_on_first_dim() {
+        let tensor =
+            TestTensor::from_floats([[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]], &Default::default());
+
+        let output = tensor.cumsum(0);
+
+        output
+            .into_data()
+            .assert_approx_eq(&Data::from([[1.0, 2.0], [4.0, 6.0], [9.0, 12.0]]), 3);
+    }
+
+    #[test]
+    fn should_support_cumprod_ops() {
+        let tensor = TestTensor::from_floats(
+            [[1.0, 2.0, 3.0, 4.0, 5.0], [2.0, 0.5, -1.0, 0.0, 3.0]],
+            &Default::default(),
+        );
+
+        let output = tensor.cumprod(1);
+
+        output.into_data().assert_approx_eq(
+            &Data::from([[1.0, 2.0, 6.0, 24.0, 120.0], [2.0, 1.0, -1.0, 0.0, 0.0]]),
+            3,
+        );
+    }
+
+    #[test]
+    fn should_support_cumulative_int_ops() {
+        let tensor = TestTensorInt::from([[1, 2, 3], [4, 5, 6]]);
+
+        let sum = tensor.clone().cumsum(1);
+        let prod = tensor.cumprod(0);
+
+        assert_eq!(sum.into_data(), Data::from([[1, 3, 6], [4, 9, 15]]));
+        assert_eq!(prod.into_data(), Data::from([[1, 2, 3], [4, 10, 18]]));
+    }
+}
@@ -0,0 +1,98 @@
+#[burn_tensor_testgen::testgen(einsum)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, Tensor};
+
+    #[test]
+    fn should_support_batched_matmul() {
+        let device = Default::default();
+        let lhs = TestTensor::from_floats(
+            [[[1.0, 2.0], [3.0, 4.0]], [[1.0, 0.0], [0.0, 1.0]]],
+            &device,
+        );
+        let rhs = TestTensor::from_floats(
+            [[[5.0, 6.0], [7.0, 8.0]], [[2.0, 3.0], [4.0, 5.0]]],
+            &device,
+        );
+
+        let output: TestTensor<3> = Tensor::einsum("bij,bjk->bik", lhs.clone(), rhs.clone());
+
+        output
+            .into_data()
+            .assert_approx_eq(&lhs.matmul(rhs).into_data(), 3);
+    }
+
+    #[test]
+    fn should_support_permuted_output() {
+        let device = Default::default();
+        let lhs = TestTensor::from_floats([[1.0, 2.0], [3.0, 4.0]], &device);
+        let rhs = TestTensor::from_floats([[5.0, 6.0, 7.0], [8.0, 9.0, 10.0]], &device);
+
+        let output: TestTensor<2> = Tensor::einsum("ij,jk->ki", lhs.clone(), rhs.clone());
+
+        output
+            .into_data()
+            .assert_approx_eq(&lhs.matmul(rhs).transpose().into_data(), 3);
+    }
+
+    #[test]
+    fn should_support_outer_product() {
+        let device = Default::default();
+        let lhs = TestTensor::from_floats([1.0, 2.0], &device);
+        let rhs = TestTensor::from_floats([3.0, 4.0, 5.0], &device);
+
+        let output: TestTensor<2> = Tensor::einsum("i,j->ij", lhs, rhs);
+
+        output
+            .into_data()
+            .assert_approx_eq(&Data::from([[3.0, 4.0, 5.0], [6.0, 8.0, 10.0]]), 3);
+    }
+
+    #[test]
+    fn should_support_dot_product() {
+        let device = Default::default();
+        let lhs = TestTensor::from_floats([1.0, 2.0, 3.0], &device);
+        let rhs = TestTensor::from_floats([4.0, 5.0, 6.0], &device);
+
+        let output: TestTensor<1> = Tensor::einsum("i,i->", lhs, rhs);
+
+        output.into_data().assert_approx_eq(&Data::from([32.0]), 3);
+    }
+
+    #[test]
+    fn should_sum_labels_of_a_single_operand() {
+        let device = Default::default();
+        let lhs = TestTensor::from_floats([[1.0, 2.0], [3.0, 4.0]], &device);
+        let rhs = TestTensor::from_floats([[1.0, 1.0, 1.0], [2.0, 2.0, 2.0]], &device);
+
+        // Sums the rows of lhs, then multiplies by the rows of rhs.
+        let output: TestTensor<1> = Tensor::einsum("ij,ik->k", lhs, rhs);
+
+        output
+            .into_data()
+            .assert_approx_eq(&Data::from([17.0, 17.0, 17.0]), 3);
+    }
+
+    #[test]
+    fn should_use_implicit_output() {
+        let device = Default::default();
+        let lhs = TestTensor::from_floats([[1.0, 2.0], [3.0, 4.0]], &device);
+        let rhs = TestTensor::from_floats([[5.0, 6.0], [7.0, 8.0]], &device);
+
+        let output: TestTensor<2> = Tensor::einsum("ij,jk", lhs.clone(), rhs.clone());
+
+        output
+            .into_data()
+            .assert_approx_eq(&lhs.matmul(rhs).into_data(), 3);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_sizes_mismatch() {
+        let device = Default::default();
+        let lhs = TestTensor::<2>::ones([2, 3], &device);
+        let rhs = TestTensor::<2>::ones([4, 2], &device);
+
+        let _output: TestTensor<2> = Tensor::einsum("ij,jk->ik", lhs, rhs);
+    }
+}
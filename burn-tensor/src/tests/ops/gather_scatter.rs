@@ -198,4 +198,106 @@ mod tests {
 
         tensor.scatter(0, indices, values);
     }
+
+    #[test]
+    fn should_scatter_add_like_scatter() {
+        let device = Default::default();
+        let tensor = TestTensor::from_floats([1.0, 1.0, 1.0], &device);
+        let values = TestTensor::from_floats([5.0, 4.0, 3.0, 2.0], &device);
+        let indices = TestTensorInt::from_ints([0, 2, 0, 2], &device);
+
+        let output = tensor.scatter_add(0, indices, values);
+
+        assert_eq!(output.into_data(), Data::from([9.0, 1.0, 7.0]));
+    }
+
+    #[test]
+    fn should_scatter_max_2d_dim1() {
+        let device = Default::default();
+        let tensor = TestTensor::from_floats([[0.0, 0.0, 0.0], [0.0, 0.0, 0.0]], &device);
+        let values = TestTensor::from_floats([[1.0, 5.0, -3.0], [4.0, 2.0, 6.0]], &device);
+        let indices = TestTensorInt::from_ints([[1, 1, 2], [0, 0, 2]], &device);
+
+        let output = tensor.scatter_max(1, indices, values);
+
+        assert_eq!(
+            output.into_data(),
+            Data::from([[0.0, 5.0, 0.0], [4.0, 0.0, 6.0]])
+        );
+    }
+
+    #[test]
+    fn should_scatter_min_int() {
+        let device = Default::default();
+        let tensor = TestTensorInt::from_ints([10, 10, 10], &device);
+        let values = TestTensorInt::from_ints([5, 12, 3, 7], &device);
+        let indices = TestTensorInt::from_ints([0, 1, 0, 2], &device);
+
+        let output = tensor.scatter_min(0, indices, values);
+
+        assert_eq!(output.into_data(), Data::from([3, 10, 7]));
+    }
+
+    #[test]
+    #[should_panic]
+    fn scatter_max_should_panic_on_mismatch_of_shapes() {
+        let device = Default::default();
+        let tensor = TestTensor::from_floats([0.0, 0.0, 0.0], &device);
+        let values = TestTensor::from_floats([5.0, 4.0], &device);
+        let indices = TestTensorInt::from_ints([1, 0, 2], &device);
+
+        tensor.scatter_max(0, indices, values);
+    }
+
+    #[test]
+    #[should_panic]
+    fn scatter_min_should_panic_on_index_out_of_range() {
+        let device = Default::default();
+        let tensor = TestTensorInt::from_ints([10, 10, 10], &device);
+        let values = TestTensorInt::from_ints([5, 12], &device);
+        let indices = TestTensorInt::from_ints([0, 3], &device);
+
+        tensor.scatter_min(0, indices, values).into_data();
+    }
+
+    #[test]
+    fn should_index_put() {
+        let device = Default::default();
+        let tensor = TestTensor::from_floats([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]], &device);
+        let indices = TestTensorInt::from_ints([[0, 2], [1, 0]], &device);
+        let values = TestTensor::from_floats([-1.0, -2.0], &device);
+
+        let output = tensor.index_put(indices, values, false);
+
+        assert_eq!(
+            output.into_data(),
+            Data::from([[1.0, 2.0, -1.0], [-2.0, 5.0, 6.0]])
+        );
+    }
+
+    #[test]
+    fn should_index_put_accumulate() {
+        let device = Default::default();
+        let tensor = TestTensor::from_floats([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]], &device);
+        let indices = TestTensorInt::from_ints([[0, 2], [1, 0], [0, 2]], &device);
+        let values = TestTensor::from_floats([1.0, 2.0, 3.0], &device);
+
+        let output = tensor.index_put(indices, values, true);
+
+        assert_eq!(
+            output.into_data(),
+            Data::from([[1.0, 2.0, 7.0], [6.0, 5.0, 6.0]])
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn index_put_should_panic_on_wrong_number_of_coordinates() {
+        let device = Default::default();
+        let tensor = TestTensor::from_floats([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]], &device);
+        let indices = TestTensorInt::from_ints([[0], [1]], &device);
+        let values = TestTensor::from_floats([1.0, 2.0], &device);
+
+        tensor.index_put(indices, values, false);
+    }
 }
@@ -9,8 +9,10 @@ mod cat;
 mod chunk;
 mod clamp;
 mod cos;
+mod cumulative;
 mod create_like;
 mod div;
+mod einsum;
 mod erf;
 mod exp;
 mod flatten;
@@ -27,15 +29,19 @@ mod maxmin;
 mod mul;
 mod narrow;
 mod neg;
+mod nonzero;
 mod one_hot;
+mod pad;
 mod powf;
 mod random;
 mod recip;
 mod repeat;
 mod reshape;
+mod searchsorted;
 mod select;
 mod sin;
 mod slice;
+mod sort;
 mod sqrt;
 mod squeeze;
 mod stack;
@@ -43,3 +49,4 @@ mod sub;
 mod tanh;
 mod transpose;
 mod tri;
+mod unique;
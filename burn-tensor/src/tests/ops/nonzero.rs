@@ -0,0 +1,65 @@
+#[burn_tensor_testgen::testgen(nonzero)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Bool, Data, Tensor};
+
+    #[test]
+    fn should_support_nonzero() {
+        let device = Default::default();
+        let tensor = Tensor::<TestBackend, 2, Bool>::from_bool(
+            Data::from([[true, false, true], [false, false, true]]),
+            &device,
+        );
+
+        let indices = tensor.nonzero();
+
+        assert_eq!(indices.len(), 2);
+        assert_eq!(indices[0].clone().into_data(), Data::from([0, 0, 1]));
+        assert_eq!(indices[1].clone().into_data(), Data::from([0, 2, 2]));
+    }
+
+    #[test]
+    fn should_support_nonzero_3d() {
+        let device = Default::default();
+        let tensor = Tensor::<TestBackend, 3, Bool>::from_bool(
+            Data::from([
+                [[false, true], [false, false]],
+                [[true, false], [false, true]],
+            ]),
+            &device,
+        );
+
+        let indices = tensor.nonzero();
+
+        assert_eq!(indices[0].clone().into_data(), Data::from([0, 1, 1]));
+        assert_eq!(indices[1].clone().into_data(), Data::from([0, 0, 1]));
+        assert_eq!(indices[2].clone().into_data(), Data::from([1, 0, 1]));
+    }
+
+    #[test]
+    fn should_support_masked_select_float() {
+        let device = Default::default();
+        let tensor = TestTensor::from_floats([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]], &device);
+        let mask = tensor.clone().greater_elem(2.5);
+
+        let output = tensor.masked_select(mask);
+
+        output
+            .into_data()
+            .assert_approx_eq(&Data::from([3.0, 4.0, 5.0, 6.0]), 3);
+    }
+
+    #[test]
+    fn should_support_masked_select_int() {
+        let device = Default::default();
+        let tensor = TestTensorInt::from_ints([[7, -1], [0, 3]], &device);
+        let mask = Tensor::<TestBackend, 2, Bool>::from_bool(
+            Data::from([[false, true], [true, true]]),
+            &device,
+        );
+
+        let output = tensor.masked_select(mask);
+
+        assert_eq!(output.into_data(), Data::from([-1, 0, 3]));
+    }
+}
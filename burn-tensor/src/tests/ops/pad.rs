@@ -0,0 +1,81 @@
+#[burn_tensor_testgen::testgen(pad)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, PadMode};
+
+    #[test]
+    fn should_support_pad_constant() {
+        let tensor = TestTensor::from_floats([[1.0, 2.0], [3.0, 4.0]], &Default::default());
+
+        let output = tensor.pad([(1, 0), (0, 2)], PadMode::Constant(0.5));
+
+        output.into_data().assert_approx_eq(
+            &Data::from([
+                [0.5, 0.5, 0.5, 0.5],
+                [1.0, 2.0, 0.5, 0.5],
+                [3.0, 4.0, 0.5, 0.5],
+            ]),
+            3,
+        );
+    }
+
+    #[test]
+    fn should_support_pad_reflect() {
+        let tensor =
+            TestTensor::from_floats([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]], &Default::default());
+
+        let output = tensor.clone().pad([(0, 0), (2, 1)], PadMode::Reflect);
+        output.into_data().assert_approx_eq(
+            &Data::from([
+                [3.0, 2.0, 1.0, 2.0, 3.0, 2.0],
+                [6.0, 5.0, 4.0, 5.0, 6.0, 5.0],
+            ]),
+            3,
+        );
+
+        let output = tensor.pad([(1, 1), (0, 0)], PadMode::Reflect);
+        output.into_data().assert_approx_eq(
+            &Data::from([
+                [4.0, 5.0, 6.0],
+                [1.0, 2.0, 3.0],
+                [4.0, 5.0, 6.0],
+                [1.0, 2.0, 3.0],
+            ]),
+            3,
+        );
+    }
+
+    #[test]
+    fn should_support_pad_replicate() {
+        let tensor = TestTensor::from_floats([[1.0, 2.0], [3.0, 4.0]], &Default::default());
+
+        let output = tensor.pad([(1, 1), (2, 0)], PadMode::Replicate);
+
+        output.into_data().assert_approx_eq(
+            &Data::from([
+                [1.0, 1.0, 1.0, 2.0],
+                [1.0, 1.0, 1.0, 2.0],
+                [3.0, 3.0, 3.0, 4.0],
+                [3.0, 3.0, 3.0, 4.0],
+            ]),
+            3,
+        );
+    }
+
+    #[test]
+    fn should_support_pad_int() {
+        let tensor = TestTensorInt::from_ints([1, 2, 3], &Default::default());
+
+        let output = tensor.pad([(2, 1)], PadMode::Constant(-1.0));
+
+        assert_eq!(output.into_data(), Data::from([-1, -1, 1, 2, 3, -1]));
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_reflect_padding_exceeds_size() {
+        let tensor = TestTensor::from_floats([[1.0, 2.0], [3.0, 4.0]], &Default::default());
+
+        let _output = tensor.pad([(0, 0), (2, 0)], PadMode::Reflect);
+    }
+}
@@ -0,0 +1,62 @@
+#[burn_tensor_testgen::testgen(searchsorted)]
+mod tests {
+    use super::*;
+    use burn_tensor::Data;
+
+    #[test]
+    fn should_support_searchsorted_left() {
+        let device = Default::default();
+        let sorted = TestTensor::from_floats([1.0, 3.0, 3.0, 5.0], &device);
+        let values = TestTensor::from_floats([0.0, 3.0, 4.0, 5.0, 6.0], &device);
+
+        let output = sorted.searchsorted(values, false);
+
+        assert_eq!(output.into_data(), Data::from([0, 1, 3, 3, 4]));
+    }
+
+    #[test]
+    fn should_support_searchsorted_right() {
+        let device = Default::default();
+        let sorted = TestTensor::from_floats([1.0, 3.0, 3.0, 5.0], &device);
+        let values = TestTensor::from_floats([0.0, 3.0, 4.0, 5.0, 6.0], &device);
+
+        let output = sorted.searchsorted(values, true);
+
+        assert_eq!(output.into_data(), Data::from([0, 3, 3, 4, 4]));
+    }
+
+    #[test]
+    fn should_support_searchsorted_per_row() {
+        let device = Default::default();
+        let sorted = TestTensorInt::from_ints([[1, 2, 3], [10, 20, 30]], &device);
+        let values = TestTensorInt::from_ints([[2, 4], [5, 25]], &device);
+
+        let output = sorted.searchsorted(values, false);
+
+        assert_eq!(output.into_data(), Data::from([[1, 3], [0, 2]]));
+    }
+
+    #[test]
+    fn should_support_bucketize() {
+        let device = Default::default();
+        let tensor = TestTensor::from_floats([[0.5, 1.0, 1.5], [2.0, 2.5, 4.0]], &device);
+        let boundaries = TestTensor::from_floats([1.0, 2.0, 3.0], &device);
+
+        let output = tensor.clone().bucketize(boundaries.clone(), false);
+        assert_eq!(output.into_data(), Data::from([[0, 0, 1], [1, 2, 3]]));
+
+        let output = tensor.bucketize(boundaries, true);
+        assert_eq!(output.into_data(), Data::from([[0, 1, 1], [2, 2, 3]]));
+    }
+
+    #[test]
+    fn should_support_bucketize_int() {
+        let device = Default::default();
+        let tensor = TestTensorInt::from_ints([0, 5, 10, 15, 20], &device);
+        let boundaries = TestTensorInt::from_ints([5, 15], &device);
+
+        let output = tensor.bucketize(boundaries, false);
+
+        assert_eq!(output.into_data(), Data::from([0, 0, 1, 1, 2]));
+    }
+}
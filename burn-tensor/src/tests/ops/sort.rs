@@ -0,0 +1,100 @@
+#[burn_tensor_testgen::testgen(sort)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, Tensor};
+
+    #[test]
+    fn should_sort_along_last_dim() {
+        let tensor =
+            TestTensor::from_floats([[3.0, 1.0, 2.0], [0.0, 5.0, 4.0]], &Default::default());
+
+        let output = tensor.sort(1, false);
+
+        assert_eq!(
+            output.into_data(),
+            Data::from([[1.0, 2.0, 3.0], [0.0, 4.0, 5.0]])
+        );
+    }
+
+    #[test]
+    fn should_sort_along_first_dim_descending() {
+        let tensor =
+            TestTensor::from_floats([[3.0, 1.0, 2.0], [0.0, 5.0, 4.0]], &Default::default());
+
+        let output = tensor.sort(0, true);
+
+        assert_eq!(
+            output.into_data(),
+            Data::from([[3.0, 5.0, 4.0], [0.0, 1.0, 2.0]])
+        );
+    }
+
+    #[test]
+    fn should_sort_with_indices_3d() {
+        let tensor =
+            TestTensor::from_floats([[[4.0, -1.0], [2.0, 6.0], [0.0, 3.0]]], &Default::default());
+
+        let (values, indices) = tensor.sort_with_indices(1, false);
+
+        assert_eq!(
+            values.into_data(),
+            Data::from([[[0.0, -1.0], [2.0, 3.0], [4.0, 6.0]]])
+        );
+        assert_eq!(indices.into_data(), Data::from([[[2, 0], [1, 2], [0, 1]]]));
+    }
+
+    #[test]
+    fn should_keep_the_order_of_equal_elements() {
+        let tensor = TestTensor::from_floats([2.0, 1.0, 2.0, 1.0, 0.0], &Default::default());
+
+        let (values, indices) = tensor.clone().sort_with_indices(0, false);
+        assert_eq!(values.into_data(), Data::from([0.0, 1.0, 1.0, 2.0, 2.0]));
+        assert_eq!(indices.into_data(), Data::from([4, 1, 3, 0, 2]));
+
+        let indices = tensor.sort_with_indices(0, true).1;
+        assert_eq!(indices.into_data(), Data::from([0, 2, 1, 3, 4]));
+    }
+
+    #[test]
+    fn should_argsort_int() {
+        let tensor = TestTensorInt::from([[10, 11, 2], [30, 4, 5]]);
+
+        let indices = tensor.clone().argsort(1);
+
+        assert_eq!(
+            indices.clone().into_data(),
+            Data::from([[2, 0, 1], [1, 2, 0]])
+        );
+        assert_eq!(
+            tensor.gather(1, indices).into_data(),
+            Data::from([[2, 10, 11], [4, 5, 30]])
+        );
+    }
+
+    #[test]
+    fn should_sort_int_descending() {
+        let tensor = TestTensorInt::from([[10, 11, 2], [30, 4, 5]]);
+
+        let output = tensor.sort(1, true);
+
+        assert_eq!(output.into_data(), Data::from([[11, 10, 2], [30, 5, 4]]));
+    }
+
+    #[test]
+    fn should_sort_nan_as_the_greatest_value() {
+        let tensor =
+            TestTensor::from_floats([2.0, f32::NAN, -1.0, -f32::NAN, 0.5], &Default::default());
+
+        let (values, indices) = tensor.clone().sort_with_indices(0, false);
+        let values = values.into_data().value;
+        assert_eq!(indices.into_data(), Data::from([2, 4, 0, 1, 3]));
+        assert_eq!(&values[..3], &[-1.0, 0.5, 2.0]);
+        assert!(values[3].is_nan() && values[4].is_nan());
+
+        let (values, indices) = tensor.sort_with_indices(0, true);
+        let values = values.into_data().value;
+        assert_eq!(indices.into_data(), Data::from([1, 3, 0, 4, 2]));
+        assert!(values[0].is_nan() && values[1].is_nan());
+        assert_eq!(&values[2..], &[2.0, 0.5, -1.0]);
+    }
+}
@@ -0,0 +1,72 @@
+#[burn_tensor_testgen::testgen(unique)]
+mod tests {
+    use super::*;
+    use burn_tensor::Data;
+
+    #[test]
+    fn should_support_unique() {
+        let tensor = TestTensorInt::from_ints([[4, 1, 4], [2, 1, 4]], &Default::default());
+
+        let output = tensor.unique();
+
+        assert_eq!(output.into_data(), Data::from([1, 2, 4]));
+    }
+
+    #[test]
+    fn should_support_unique_with_counts() {
+        let tensor = TestTensorInt::from_ints([[4, 1, 4], [2, 1, 4]], &Default::default());
+
+        let (values, counts) = tensor.unique_with_counts();
+
+        assert_eq!(values.into_data(), Data::from([1, 2, 4]));
+        assert_eq!(counts.into_data(), Data::from([2, 1, 3]));
+    }
+
+    #[test]
+    fn should_support_unique_with_inverse() {
+        let tensor = TestTensorInt::from_ints([[4, 1, 4], [2, 1, 4]], &Default::default());
+
+        let (values, inverse) = tensor.clone().unique_with_inverse();
+
+        assert_eq!(values.clone().into_data(), Data::from([1, 2, 4]));
+        assert_eq!(
+            inverse.clone().into_data(),
+            Data::from([[2, 0, 2], [1, 0, 2]])
+        );
+        assert_eq!(
+            values
+                .select(0, inverse.reshape([6]))
+                .reshape([2, 3])
+                .into_data(),
+            tensor.into_data()
+        );
+    }
+
+    #[test]
+    fn should_support_unique_single_element() {
+        let tensor = TestTensorInt::from_ints([7], &Default::default());
+
+        let (values, counts) = tensor.unique_with_counts();
+
+        assert_eq!(values.into_data(), Data::from([7]));
+        assert_eq!(counts.into_data(), Data::from([1]));
+    }
+
+    #[test]
+    fn should_support_bincount() {
+        let tensor = TestTensorInt::from_ints([1, 3, 1, 0, 1], &Default::default());
+
+        let output = tensor.bincount(0);
+
+        assert_eq!(output.into_data(), Data::from([1, 3, 0, 1]));
+    }
+
+    #[test]
+    fn should_support_bincount_min_length() {
+        let tensor = TestTensorInt::from_ints([2, 0, 2], &Default::default());
+
+        let output = tensor.bincount(5);
+
+        assert_eq!(output.into_data(), Data::from([1, 0, 2, 0, 0]));
+    }
+}
@@ -0,0 +1,122 @@
+#[burn_tensor_testgen::testgen(sparse)]
+mod tests {
+    use super::*;
+    use burn_tensor::sparse::{SparseLayout, SparseTensor};
+    use burn_tensor::{Bool, Data, Tensor};
+
+    #[test]
+    fn should_support_spmm_coo() {
+        let device = Default::default();
+        let indices = TestTensorInt::from_ints([[0, 1, 1], [1, 0, 2]], &device);
+        let values = TestTensor::from_floats([2.0, 3.0, 4.0], &device);
+        let dense = TestTensor::from_floats([[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]], &device);
+
+        let output = SparseTensor::from_coo(indices, values, [2, 3]).matmul(dense);
+
+        output
+            .into_data()
+            .assert_approx_eq(&Data::from([[6.0, 8.0], [23.0, 30.0]]), 3);
+    }
+
+    #[test]
+    fn should_support_spmm_csr() {
+        let device = Default::default();
+        let row_offsets = TestTensorInt::from_ints([0, 1, 3], &device);
+        let col_indices = TestTensorInt::from_ints([1, 0, 2], &device);
+        let values = TestTensor::from_floats([2.0, 3.0, 4.0], &device);
+        let dense = TestTensor::from_floats([[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]], &device);
+
+        let sparse = SparseTensor::from_csr(row_offsets, col_indices, values, [2, 3]);
+        let output = sparse.matmul(dense);
+
+        output
+            .into_data()
+            .assert_approx_eq(&Data::from([[6.0, 8.0], [23.0, 30.0]]), 3);
+    }
+
+    #[test]
+    fn should_sum_duplicate_values() {
+        let device = Default::default();
+        let indices = TestTensorInt::from_ints([[0, 0, 1], [1, 1, 0]], &device);
+        let values = TestTensor::from_floats([1.0, 2.0, 5.0], &device);
+
+        let output = SparseTensor::from_coo(indices, values, [2, 2]).to_dense();
+
+        output
+            .into_data()
+            .assert_approx_eq(&Data::from([[0.0, 3.0], [5.0, 0.0]]), 3);
+    }
+
+    #[test]
+    fn should_convert_csr_to_coo() {
+        let device = Default::default();
+        let row_offsets = TestTensorInt::from_ints([0, 1, 1, 3], &device);
+        let col_indices = TestTensorInt::from_ints([1, 0, 2], &device);
+        let values = TestTensor::from_floats([2.0, 3.0, 4.0], &device);
+
+        let sparse = SparseTensor::from_csr(row_offsets, col_indices, values, [3, 3]).to_coo();
+        assert_eq!(sparse.layout(), SparseLayout::Coo);
+
+        let (indices, values) = sparse.into_coo();
+
+        assert_eq!(indices.into_data(), Data::from([[0, 2, 2], [1, 0, 2]]));
+        values
+            .into_data()
+            .assert_approx_eq(&Data::from([2.0, 3.0, 4.0]), 3);
+    }
+
+    #[test]
+    fn should_convert_coo_to_csr() {
+        let device = Default::default();
+        let indices = TestTensorInt::from_ints([[2, 0, 2], [2, 1, 0]], &device);
+        let values = TestTensor::from_floats([4.0, 2.0, 3.0], &device);
+
+        let sparse = SparseTensor::from_coo(indices, values, [3, 3]).to_csr();
+        assert_eq!(sparse.layout(), SparseLayout::Csr);
+
+        let (row_offsets, _, _) = sparse.clone().into_csr();
+
+        assert_eq!(row_offsets.into_data(), Data::from([0, 1, 1, 3]));
+        sparse.to_dense().into_data().assert_approx_eq(
+            &Data::from([[0.0, 2.0, 0.0], [0.0, 0.0, 0.0], [3.0, 0.0, 4.0]]),
+            3,
+        );
+    }
+
+    #[test]
+    fn should_create_from_dense() {
+        let device = Default::default();
+        let tensor = TestTensor::from_floats([[0.0, 1.5, 0.0], [-2.0, 0.0, 0.0]], &device);
+
+        let sparse = SparseTensor::from_dense(tensor.clone());
+        assert_eq!(sparse.nnz(), 2);
+
+        let (indices, values) = sparse.clone().into_coo();
+
+        assert_eq!(indices.into_data(), Data::from([[0, 1], [1, 0]]));
+        values
+            .into_data()
+            .assert_approx_eq(&Data::from([1.5, -2.0]), 3);
+        sparse
+            .to_dense()
+            .into_data()
+            .assert_approx_eq(&tensor.into_data(), 3);
+    }
+
+    #[test]
+    fn should_create_from_mask() {
+        let device = Default::default();
+        let mask = Tensor::<TestBackend, 2, Bool>::from_bool(
+            Data::from([[true, false], [true, true]]),
+            &device,
+        );
+
+        let sparse = SparseTensor::from_mask(mask);
+        assert_eq!(sparse.nnz(), 3);
+
+        sparse
+            .to_dense()
+            .into_data()
+            .assert_approx_eq(&Data::from([[1.0, 0.0], [1.0, 1.0]]), 3);
+    }
+}
@@ -0,0 +1,28 @@
+#[burn_tensor_testgen::testgen(histc)]
+mod tests {
+    use super::*;
+    use burn_tensor::Data;
+
+    #[test]
+    fn test_histc() {
+        let tensor = TestTensor::from_data([0.5, 1.0, 1.5, 2.5, 4.0], &Default::default());
+
+        let data_actual = tensor.histc(4, 0.0, 4.0).into_data();
+
+        let data_expected = Data::from([1.0, 2.0, 1.0, 1.0]);
+        data_expected.assert_approx_eq(&data_actual, 3);
+    }
+
+    #[test]
+    fn test_histc_should_ignore_elements_out_of_range() {
+        let tensor = TestTensor::from_data(
+            [[-3.0, -1.0, -0.5, 0.0], [0.2, 0.9, 1.0, 2.5]],
+            &Default::default(),
+        );
+
+        let data_actual = tensor.histc(2, -1.0, 1.0).into_data();
+
+        let data_expected = Data::from([2.0, 4.0]);
+        data_expected.assert_approx_eq(&data_actual, 3);
+    }
+}
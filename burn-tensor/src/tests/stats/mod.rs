@@ -1,4 +1,6 @@
 mod cov;
 mod diagonal;
 mod display;
+mod histc;
+mod quantile;
 mod var;
@@ -0,0 +1,61 @@
+#[burn_tensor_testgen::testgen(quantile)]
+mod tests {
+    use super::*;
+    use burn_tensor::Data;
+
+    #[test]
+    fn test_quantile() {
+        let tensor = TestTensor::from_data(
+            [[0.5, 1.8, 0.2, -2.0], [3.0, -4.0, 5.0, 0.0]],
+            &Default::default(),
+        );
+
+        let data_actual = tensor.clone().quantile(0.5, 1).into_data();
+        let data_expected = Data::from([[0.35], [1.5]]);
+        data_expected.assert_approx_eq(&data_actual, 3);
+
+        let data_actual = tensor.clone().quantile(0.25, 1).into_data();
+        let data_expected = Data::from([[-0.35], [-1.0]]);
+        data_expected.assert_approx_eq(&data_actual, 3);
+
+        let data_actual = tensor.quantile(1.0, 1).into_data();
+        let data_expected = Data::from([[1.8], [5.0]]);
+        data_expected.assert_approx_eq(&data_actual, 3);
+    }
+
+    #[test]
+    fn test_quantile_dim_0() {
+        let tensor = TestTensor::from_data(
+            [[0.5, 1.8, 0.2, -2.0], [3.0, -4.0, 5.0, 0.0]],
+            &Default::default(),
+        );
+
+        let data_actual = tensor.quantile(0.5, 0).into_data();
+
+        let data_expected = Data::from([[1.75, -1.1, 2.6, -1.0]]);
+        data_expected.assert_approx_eq(&data_actual, 3);
+    }
+
+    #[test]
+    fn test_median() {
+        let tensor = TestTensor::from_data(
+            [[0.5, 1.8, 0.2, -2.0], [3.0, -4.0, 5.0, 0.0]],
+            &Default::default(),
+        );
+
+        let data_actual = tensor.median(1).into_data();
+
+        let data_expected = Data::from([[0.2], [0.0]]);
+        data_expected.assert_approx_eq(&data_actual, 3);
+    }
+
+    #[test]
+    fn test_median_odd() {
+        let tensor = TestTensor::from_data([[4.0, -1.0, 2.0]], &Default::default());
+
+        let data_actual = tensor.median(1).into_data();
+
+        let data_expected = Data::from([[2.0]]);
+        data_expected.assert_approx_eq(&data_actual, 3);
+    }
+}
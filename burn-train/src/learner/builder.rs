@@ -8,7 +8,7 @@ use crate::checkpoint::{
 };
 use crate::components::LearnerComponentsMarker;
 use crate::learner::base::TrainingInterrupter;
-use crate::learner::EarlyStoppingStrategy;
+use crate::learner::{EarlyStoppingStrategy, PruningSchedule};
 use crate::logger::{FileMetricLogger, MetricLogger};
 use crate::metric::processor::{FullEventProcessor, Metrics};
 use crate::metric::store::{Aggregate, Direction, EventStoreClient, LogEventStore, Split};
@@ -19,7 +19,7 @@ use burn_core::lr_scheduler::LrScheduler;
 use burn_core::module::AutodiffModule;
 use burn_core::optim::Optimizer;
 use burn_core::record::FileRecorder;
-use burn_core::tensor::backend::AutodiffBackend;
+use burn_core::tensor::backend::{default_device, AutodiffBackend};
 
 /// Struct to configure and create a [learner](Learner).
 pub struct LearnerBuilder<B, T, V, M, O, S>
@@ -53,6 +53,7 @@ where
     num_loggers: usize,
     checkpointer_strategy: Box<dyn CheckpointingStrategy>,
     early_stopping: Option<Box<dyn EarlyStoppingStrategy>>,
+    pruning: Option<PruningSchedule>,
 }
 
 impl<B, T, V, M, O, S> LearnerBuilder<B, T, V, M, O, S>
@@ -76,7 +77,7 @@ where
             checkpointers: None,
             directory: directory.to_string(),
             grad_accumulation: None,
-            devices: vec![B::Device::default()],
+            devices: vec![default_device::<B::Device>()],
             metrics: Metrics::default(),
             event_store: LogEventStore::default(),
             renderer: None,
@@ -94,6 +95,7 @@ where
                     .build(),
             ),
             early_stopping: None,
+            pruning: None,
         }
     }
 
@@ -223,6 +225,12 @@ where
         self
     }
 
+    /// Prune the model during the training following the [schedule](PruningSchedule).
+    pub fn pruning(mut self, schedule: PruningSchedule) -> Self {
+        self.pruning = Some(schedule);
+        self
+    }
+
     /// By default, Rust logs are captured and written into
     /// `experiment.log`. If disabled, standard Rust log handling
     /// will apply.
@@ -332,6 +340,7 @@ where
             devices: self.devices,
             interrupter: self.interrupter,
             early_stopping: self.early_stopping,
+            pruning: self.pruning,
         }
     }
 
@@ -0,0 +1,65 @@
+use crate::metric::{AccuracyInput, Adaptor, LossInput};
+use burn_core::nn::loss::DistillationLoss;
+use burn_core::tensor::backend::Backend;
+use burn_core::tensor::{Int, Tensor};
+
+/// Output of a student trained with knowledge distillation, adapted for multiple metrics.
+///
+/// The outputs of the student and of the [teacher](burn_core::module::Teacher) are both kept, so
+/// the metrics can compare them, e.g. the accuracy of the student against the hard labels.
+///
+/// # Example
+///
+/// ```rust, ignore
+/// impl<B: AutodiffBackend> TrainStep<Batch<B>, DistillationOutput<B>> for Student<B> {
+///     fn step(&self, batch: Batch<B>) -> TrainOutput<DistillationOutput<B>> {
+///         let output = self.model.forward(batch.images.clone());
+///         let teacher_output = self.teacher.forward(|teacher| teacher.forward(batch.images));
+///         let item =
+///             DistillationOutput::from_loss(&self.loss, output, teacher_output, batch.targets);
+///
+///         TrainOutput::new(self, item.loss.backward(), item)
+///     }
+/// }
+/// ```
+#[derive(new)]
+pub struct DistillationOutput<B: Backend> {
+    /// The loss.
+    pub loss: Tensor<B, 1>,
+
+    /// The output of the student.
+    pub output: Tensor<B, 2>,
+
+    /// The output of the teacher.
+    pub teacher_output: Tensor<B, 2>,
+
+    /// The targets.
+    pub targets: Tensor<B, 1, Int>,
+}
+
+impl<B: Backend> DistillationOutput<B> {
+    /// Compute the [distillation loss](DistillationLoss) from the outputs of the student and of
+    /// the teacher.
+    pub fn from_loss(
+        loss: &DistillationLoss<B>,
+        output: Tensor<B, 2>,
+        teacher_output: Tensor<B, 2>,
+        targets: Tensor<B, 1, Int>,
+    ) -> Self {
+        let loss = loss.forward(output.clone(), teacher_output.clone(), targets.clone());
+
+        Self::new(loss, output, teacher_output, targets)
+    }
+}
+
+impl<B: Backend> Adaptor<AccuracyInput<B>> for DistillationOutput<B> {
+    fn adapt(&self) -> AccuracyInput<B> {
+        AccuracyInput::new(self.output.clone(), self.targets.clone())
+    }
+}
+
+impl<B: Backend> Adaptor<LossInput<B>> for DistillationOutput<B> {
+    fn adapt(&self) -> LossInput<B> {
+        LossInput::new(self.loss.clone())
+    }
+}
@@ -1,6 +1,10 @@
 use burn_core::{
-    data::dataloader::DataLoader, lr_scheduler::LrScheduler, module::AutodiffModule,
-    optim::GradientsAccumulator, tensor::backend::Backend,
+    data::dataloader::DataLoader,
+    lr_scheduler::LrScheduler,
+    module::AutodiffModule,
+    optim::GradientsAccumulator,
+    pruning::PruningMasks,
+    tensor::backend::{AutodiffBackend, Backend},
 };
 use std::sync::Arc;
 
@@ -81,6 +85,7 @@ impl<TI> TrainEpoch<TI> {
     /// * `optim` - The optimizer to use.
     /// * `scheduler` - The learning rate scheduler to use.
     /// * `processor` - The event processor to use.
+    /// * `pruning` - The pruning masks applied after each optimization step.
     ///
     /// # Returns
     ///
@@ -92,6 +97,7 @@ impl<TI> TrainEpoch<TI> {
         scheduler: &mut LC::LrScheduler,
         processor: &mut LC::EventProcessor,
         interrupter: &TrainingInterrupter,
+        pruning: Option<&PruningMasks<LC::Backend>>,
     ) -> (LC::Model, LC::Optimizer)
     where
         LC::EventProcessor: EventProcessor<ItemTrain = TO>,
@@ -120,10 +126,14 @@ impl<TI> TrainEpoch<TI> {
                     if accumulation <= accumulation_current {
                         let grads = accumulator.grads();
                         model = model.optimize(&mut optim, lr, grads);
+                        model = prune(model, pruning);
                         accumulation_current = 0;
                     }
                 }
-                None => model = model.optimize(&mut optim, lr, item.grads),
+                None => {
+                    model = model.optimize(&mut optim, lr, item.grads);
+                    model = prune(model, pruning);
+                }
             }
 
             let item = LearnerItem::new(
@@ -158,10 +168,12 @@ impl<TI> TrainEpoch<TI> {
     /// * `lr_scheduler` - The learning rate scheduler to use.
     /// * `processor` - The event processor to use.
     /// * `devices` - The devices to use.
+    /// * `pruning` - The pruning masks applied after each optimization step.
     ///
     /// # Returns
     ///
     /// The trained model and the optimizer.
+    #[allow(clippy::too_many_arguments)]
     pub fn run_multi_device<LC: LearnerComponents, TO>(
         &self,
         mut model: LC::Model,
@@ -170,6 +182,7 @@ impl<TI> TrainEpoch<TI> {
         processor: &mut LC::EventProcessor,
         devices: Vec<<LC::Backend as Backend>::Device>,
         interrupter: &TrainingInterrupter,
+        pruning: Option<&PruningMasks<LC::Backend>>,
     ) -> (LC::Model, LC::Optimizer)
     where
         LC::EventProcessor: EventProcessor<ItemTrain = TO>,
@@ -214,6 +227,7 @@ impl<TI> TrainEpoch<TI> {
                 if accumulation <= accumulation_current {
                     let grads = accumulator.grads();
                     model = model.optimize(&mut optim, lr, grads);
+                    model = prune(model, pruning);
                     accumulation_current = 0;
                 }
 
@@ -245,3 +259,14 @@ impl<TI> TrainEpoch<TI> {
         (model, optim)
     }
 }
+
+/// Zero the pruned weights again, since the optimizer may have updated them.
+fn prune<B: AutodiffBackend, M: AutodiffModule<B>>(
+    model: M,
+    pruning: Option<&PruningMasks<B>>,
+) -> M {
+    match pruning {
+        Some(masks) => masks.apply(model),
+        None => model,
+    }
+}
@@ -1,8 +1,10 @@
 mod base;
 mod builder;
 mod classification;
+mod distillation;
 mod early_stopping;
 mod epoch;
+mod pruning;
 mod regression;
 mod step;
 mod train_val;
@@ -12,8 +14,10 @@ pub(crate) mod log;
 pub use base::*;
 pub use builder::*;
 pub use classification::*;
+pub use distillation::*;
 pub use early_stopping::*;
 pub use epoch::*;
+pub use pruning::*;
 pub use regression::*;
 pub use step::*;
 pub use train::*;
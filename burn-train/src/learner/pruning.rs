@@ -0,0 +1,52 @@
+use burn_core::pruning::{PruningConfig, PruningMethod};
+
+/// Schedule of an iterative pruning during training, where the sparsity is increased gradually
+/// and the model is fine-tuned between each pruning step.
+///
+/// The sparsity grows linearly from the first pruning epoch to the last one, reaching the final
+/// sparsity at the last pruning epoch. The masks are computed at the beginning of each of those
+/// epochs and applied again after each optimization step, so the pruned weights stay zero during
+/// the fine-tuning.
+#[derive(new, Debug, Clone)]
+pub struct PruningSchedule {
+    /// Criterion used to select the weights to prune.
+    method: PruningMethod,
+    /// Sparsity reached at the last pruning epoch.
+    final_sparsity: f64,
+    /// First epoch where the model is pruned.
+    start_epoch: usize,
+    /// Last epoch where the model is pruned.
+    end_epoch: usize,
+}
+
+impl PruningSchedule {
+    /// The pruning configuration for the given epoch, if the model is pruned at that epoch.
+    pub fn config(&self, epoch: usize) -> Option<PruningConfig> {
+        if epoch < self.start_epoch {
+            return None;
+        }
+
+        let num_steps = self.end_epoch.saturating_sub(self.start_epoch) + 1;
+        let step = usize::min(epoch - self.start_epoch + 1, num_steps);
+        let sparsity = self.final_sparsity * step as f64 / num_steps as f64;
+
+        Some(PruningConfig::new(sparsity).with_method(self.method.clone()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sparsity_increases_linearly() {
+        let schedule = PruningSchedule::new(PruningMethod::Magnitude, 0.75, 2, 4);
+        let sparsity = |epoch| schedule.config(epoch).map(|config| config.sparsity);
+
+        assert_eq!(sparsity(1), None);
+        assert_eq!(sparsity(2), Some(0.25));
+        assert_eq!(sparsity(3), Some(0.5));
+        assert_eq!(sparsity(4), Some(0.75));
+        assert_eq!(sparsity(7), Some(0.75));
+    }
+}
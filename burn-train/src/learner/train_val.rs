@@ -4,6 +4,7 @@ use crate::{Learner, TrainEpoch, ValidEpoch};
 use burn_core::data::dataloader::DataLoader;
 use burn_core::module::{AutodiffModule, Module};
 use burn_core::optim::{GradientsParams, Optimizer};
+use burn_core::pruning::PruningMasks;
 use burn_core::tensor::backend::AutodiffBackend;
 use std::sync::Arc;
 
@@ -143,7 +144,24 @@ impl<LC: LearnerComponents> Learner<LC> {
             None => 1,
         };
 
+        let mut pruning: Option<(f64, PruningMasks<LC::Backend>)> = None;
+
         for epoch in starting_epoch..self.num_epochs + 1 {
+            if let Some(config) = self.pruning.as_ref().and_then(|s| s.config(epoch)) {
+                let outdated = match &pruning {
+                    Some((sparsity, _)) => *sparsity != config.sparsity,
+                    None => true,
+                };
+
+                if outdated {
+                    log::info!("Pruning the model with sparsity {}", config.sparsity);
+                    let masks = PruningMasks::compute(&self.model, &config);
+                    self.model = masks.apply(self.model);
+                    pruning = Some((config.sparsity, masks));
+                }
+            }
+            let masks = pruning.as_ref().map(|(_, masks)| masks);
+
             let epoch_train = TrainEpoch::new(
                 dataloader_train.clone(),
                 epoch,
@@ -159,6 +177,7 @@ impl<LC: LearnerComponents> Learner<LC> {
                     &mut self.event_processor,
                     self.devices.clone(),
                     &self.interrupter,
+                    masks,
                 )
             } else {
                 (self.model, self.optim) = epoch_train.run::<LC, OutputTrain>(
@@ -167,6 +186,7 @@ impl<LC: LearnerComponents> Learner<LC> {
                     &mut self.lr_scheduler,
                     &mut self.event_processor,
                     &self.interrupter,
+                    masks,
                 );
             }
 
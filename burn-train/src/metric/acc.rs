@@ -1,7 +1,7 @@
 use super::state::{FormatOptions, NumericMetricState};
 use super::{MetricEntry, MetricMetadata};
 use crate::metric::{Metric, Numeric};
-use burn_core::tensor::backend::Backend;
+use burn_core::tensor::backend::{default_device, Backend};
 use burn_core::tensor::{ElementConversion, Int, Tensor};
 
 /// The accuracy metric.
@@ -40,12 +40,15 @@ impl<B: Backend> Metric for AccuracyMetric<B> {
     fn update(&mut self, input: &AccuracyInput<B>, _metadata: &MetricMetadata) -> MetricEntry {
         let [batch_size, _n_classes] = input.outputs.dims();
 
-        let targets = input.targets.clone().to_device(&B::Device::default());
+        let targets = input
+            .targets
+            .clone()
+            .to_device(&default_device::<B::Device>());
         let outputs = input
             .outputs
             .clone()
             .argmax(1)
-            .to_device(&B::Device::default())
+            .to_device(&default_device::<B::Device>())
             .reshape([batch_size]);
 
         let accuracy = match self.pad_token {
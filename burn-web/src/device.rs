@@ -0,0 +1,19 @@
+use burn_wgpu::{compute::init_async, AutoGraphicsApi, GraphicsApi, WgpuDevice};
+
+/// Initialize the default WebGPU device.
+///
+/// Must be awaited before executing any operation on the device, since the browser doesn't
+/// allow requesting the adapter synchronously.
+pub async fn init_device() -> WgpuDevice {
+    let device = WgpuDevice::default();
+    init_device_with::<AutoGraphicsApi>(&device).await;
+
+    device
+}
+
+/// Initialize the given device with the graphics API `G`.
+pub async fn init_device_with<G: GraphicsApi>(device: &WgpuDevice) {
+    log::info!("Initializing the WebGPU device {device:?}");
+
+    init_async::<G>(device).await;
+}
@@ -0,0 +1,47 @@
+use burn_core::record::RecorderError;
+use core::fmt::Display;
+use wasm_bindgen::JsValue;
+
+/// Error that can happen when deploying a model in the browser.
+#[derive(Debug)]
+pub enum WebError {
+    /// A JavaScript call failed.
+    Js(String),
+    /// The server responded with an unsuccessful status code.
+    Status(u16),
+    /// The downloaded record can't be decoded.
+    Record(RecorderError),
+    /// The shape of a tensor doesn't match its data or the expected rank.
+    Shape(String),
+}
+
+impl Display for WebError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Js(message) => write!(f, "JavaScript error: {message}"),
+            Self::Status(status) => write!(f, "Request failed with status {status}"),
+            Self::Record(err) => write!(f, "Failed to load record: {err}"),
+            Self::Shape(message) => write!(f, "Invalid shape: {message}"),
+        }
+    }
+}
+
+impl std::error::Error for WebError {}
+
+impl From<RecorderError> for WebError {
+    fn from(err: RecorderError) -> Self {
+        Self::Record(err)
+    }
+}
+
+impl From<JsValue> for WebError {
+    fn from(value: JsValue) -> Self {
+        Self::Js(value.as_string().unwrap_or_else(|| format!("{value:?}")))
+    }
+}
+
+impl From<WebError> for JsValue {
+    fn from(err: WebError) -> Self {
+        js_sys::Error::new(&err.to_string()).into()
+    }
+}
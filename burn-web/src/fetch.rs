@@ -0,0 +1,69 @@
+use crate::WebError;
+use js_sys::{Reflect, Uint8Array};
+use wasm_bindgen::{JsCast, JsValue};
+use wasm_bindgen_futures::JsFuture;
+use web_sys::{ReadableStreamDefaultReader, Response, Window, WorkerGlobalScope};
+
+/// Download the content at the given url, reading the body of the response as a stream.
+///
+/// The `progress` callback is called after each received chunk with the number of bytes
+/// downloaded so far and the total number of bytes, when the server provides it.
+///
+/// Works both on the main thread and in web workers.
+pub async fn fetch_bytes<F>(url: &str, mut progress: F) -> Result<Vec<u8>, WebError>
+where
+    F: FnMut(usize, Option<usize>),
+{
+    let response = fetch(url).await?;
+
+    if !response.ok() {
+        return Err(WebError::Status(response.status()));
+    }
+
+    let total = response
+        .headers()
+        .get("Content-Length")?
+        .and_then(|length| length.parse::<usize>().ok());
+    let body = match response.body() {
+        Some(body) => body,
+        None => return Ok(Vec::new()),
+    };
+    let reader: ReadableStreamDefaultReader = body.get_reader().unchecked_into();
+    let mut bytes = Vec::with_capacity(total.unwrap_or_default());
+
+    loop {
+        let chunk = JsFuture::from(reader.read()).await?;
+        let done = Reflect::get(&chunk, &JsValue::from_str("done"))?
+            .as_bool()
+            .unwrap_or(true);
+
+        if done {
+            break;
+        }
+
+        let value: Uint8Array = Reflect::get(&chunk, &JsValue::from_str("value"))?.unchecked_into();
+        let offset = bytes.len();
+        bytes.resize(offset + value.length() as usize, 0);
+        value.copy_to(&mut bytes[offset..]);
+
+        progress(bytes.len(), total);
+    }
+
+    Ok(bytes)
+}
+
+async fn fetch(url: &str) -> Result<Response, WebError> {
+    let global = js_sys::global();
+
+    let promise = if let Some(window) = global.dyn_ref::<Window>() {
+        window.fetch_with_str(url)
+    } else if let Some(worker) = global.dyn_ref::<WorkerGlobalScope>() {
+        worker.fetch_with_str(url)
+    } else {
+        return Err(WebError::Js("The fetch API isn't available".into()));
+    };
+
+    let response = JsFuture::from(promise).await?;
+
+    Ok(response.unchecked_into())
+}
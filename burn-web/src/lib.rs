@@ -0,0 +1,31 @@
+#![warn(missing_docs)]
+
+//! Toolkit to deploy burn models in the browser.
+//!
+//! A typical deployment compiles the model to `wasm32-unknown-unknown` with `wasm-bindgen` and:
+//!
+//! 1. Initializes the WebGPU device asynchronously with [init_device](device::init_device),
+//!    since blocking the main thread of the browser isn't allowed.
+//! 2. Streams the weights of the model from the network with [load_module], decoding them into
+//!    the module record once downloaded.
+//! 3. Exchanges inputs and outputs with JavaScript using [JsTensor], which maps to a
+//!    `Float32Array` and a shape.
+//!
+//! ## Feature Flags
+//!
+//! - `wgpu` (default): Provides the WebGPU device initialization.
+//! - `wasm-sync`: Must be activated when burn is compiled with the `wasm-sync` feature.
+
+mod error;
+mod fetch;
+mod record;
+mod tensor;
+
+/// Device initialization.
+#[cfg(feature = "wgpu")]
+pub mod device;
+
+pub use error::*;
+pub use fetch::*;
+pub use record::*;
+pub use tensor::*;
@@ -0,0 +1,56 @@
+use crate::{fetch_bytes, WebError};
+use burn_core::{
+    module::Module,
+    record::{BinBytesRecorder, FullPrecisionSettings, PrecisionSettings, Recorder},
+    tensor::backend::Backend,
+};
+
+/// Download the record at the given url and load it into the module.
+///
+/// The record must have been saved with a [BinBytesRecorder] using [FullPrecisionSettings],
+/// see [load_module_with] to use other precision settings.
+pub async fn load_module<B, M>(module: M, url: &str, device: &B::Device) -> Result<M, WebError>
+where
+    B: Backend,
+    M: Module<B>,
+{
+    load_module_with::<FullPrecisionSettings, B, M>(module, url, device).await
+}
+
+/// Download the record at the given url, saved with a [BinBytesRecorder] using the precision
+/// settings `S`, and load it into the module.
+pub async fn load_module_with<S, B, M>(
+    module: M,
+    url: &str,
+    device: &B::Device,
+) -> Result<M, WebError>
+where
+    S: PrecisionSettings,
+    B: Backend,
+    M: Module<B>,
+{
+    let bytes = fetch_bytes(url, |loaded, total| match total {
+        Some(total) => log::debug!("Downloaded {loaded}/{total} bytes from {url}"),
+        None => log::debug!("Downloaded {loaded} bytes from {url}"),
+    })
+    .await?;
+
+    load_module_from_bytes::<S, B, M>(module, bytes, device)
+}
+
+/// Load a record saved with a [BinBytesRecorder] using the precision settings `S` into the
+/// module.
+pub fn load_module_from_bytes<S, B, M>(
+    module: M,
+    bytes: Vec<u8>,
+    device: &B::Device,
+) -> Result<M, WebError>
+where
+    S: PrecisionSettings,
+    B: Backend,
+    M: Module<B>,
+{
+    let record = BinBytesRecorder::<S>::default().load(bytes)?;
+
+    Ok(module.load_record(record).to_device(device))
+}
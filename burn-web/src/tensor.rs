@@ -0,0 +1,87 @@
+use crate::WebError;
+use burn_core::tensor::{backend::Backend, Data, Shape, Tensor};
+use js_sys::Float32Array;
+use wasm_bindgen::prelude::*;
+
+/// Tensor exchanged with JavaScript.
+///
+/// On the JavaScript side, the data is a `Float32Array` and the shape an `Uint32Array`.
+#[wasm_bindgen]
+#[derive(Debug, Clone)]
+pub struct JsTensor {
+    data: Vec<f32>,
+    shape: Vec<u32>,
+}
+
+#[wasm_bindgen]
+impl JsTensor {
+    /// Create a new tensor, the number of elements must match the shape.
+    #[wasm_bindgen(constructor)]
+    pub fn new(data: Vec<f32>, shape: Vec<u32>) -> Result<JsTensor, WebError> {
+        let num_elements = shape.iter().map(|dim| *dim as usize).product::<usize>();
+
+        if num_elements != data.len() {
+            return Err(WebError::Shape(format!(
+                "The shape {shape:?} requires {num_elements} elements, got {}",
+                data.len()
+            )));
+        }
+
+        Ok(Self { data, shape })
+    }
+
+    /// The data of the tensor.
+    #[wasm_bindgen(getter)]
+    pub fn data(&self) -> Float32Array {
+        Float32Array::from(self.data.as_slice())
+    }
+
+    /// The shape of the tensor.
+    #[wasm_bindgen(getter)]
+    pub fn shape(&self) -> Vec<u32> {
+        self.shape.clone()
+    }
+}
+
+impl JsTensor {
+    /// Convert into a tensor on the given device, failing if the rank doesn't match.
+    pub fn into_tensor<B: Backend, const D: usize>(
+        self,
+        device: &B::Device,
+    ) -> Result<Tensor<B, D>, WebError> {
+        if self.shape.len() != D {
+            return Err(WebError::Shape(format!(
+                "Expected a tensor of rank {D}, got shape {:?}",
+                self.shape
+            )));
+        }
+
+        let mut dims = [0; D];
+        for (dim, size) in dims.iter_mut().zip(self.shape) {
+            *dim = size as usize;
+        }
+
+        let data = Data::new(self.data, Shape::new(dims));
+
+        Ok(Tensor::from_data(data.convert(), device))
+    }
+
+    /// Read the data of the tensor.
+    #[cfg(all(not(feature = "wasm-sync"), target_family = "wasm"))]
+    pub async fn from_tensor<B: Backend, const D: usize>(tensor: Tensor<B, D>) -> Self {
+        Self::from_data(tensor.into_data().await.convert())
+    }
+
+    /// Read the data of the tensor.
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    pub async fn from_tensor<B: Backend, const D: usize>(tensor: Tensor<B, D>) -> Self {
+        Self::from_data(tensor.into_data().convert())
+    }
+
+    fn from_data<const D: usize>(data: Data<f32, D>) -> Self {
+        Self {
+            data: data.value,
+            shape: data.shape.dims.iter().map(|dim| *dim as u32).collect(),
+        }
+    }
+}
@@ -201,22 +201,22 @@ impl ElemWiseKernelCodegen<BodyPhase> {
         for ops in operators.iter() {
             match ops {
                 Operator::Powf {
-                    lhs: _,
+                    lhs,
                     rhs: _,
                     out: _,
                 } => {
                     register_function(Function::Powf(
-                        Item::Scalar(Elem::F32).vectorize(self.vectorization),
+                        Item::Scalar(lhs.item().elem()).vectorize(self.vectorization),
                     ));
                 }
-                Operator::Erf { input: _, out: _ } => {
+                Operator::Erf { input, out: _ } => {
                     register_function(Function::Erf(
-                        Item::Scalar(Elem::F32).vectorize(self.vectorization),
+                        Item::Scalar(input.item().elem()).vectorize(self.vectorization),
                     ));
                 }
                 #[cfg(target_os = "macos")]
-                Operator::Tanh { input: _, out: _ } => register_function(Function::SafeTanh(
-                    Item::Scalar(Elem::F32).vectorize(self.vectorization),
+                Operator::Tanh { input, out: _ } => register_function(Function::SafeTanh(
+                    Item::Scalar(input.item().elem()).vectorize(self.vectorization),
                 )),
                 _ => {}
             }
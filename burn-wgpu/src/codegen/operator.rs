@@ -71,6 +71,10 @@ pub enum Operator {
         input: Variable,
         out: Variable,
     },
+    Not {
+        input: Variable,
+        out: Variable,
+    },
     Equal {
         lhs: Variable,
         rhs: Variable,
@@ -220,6 +224,10 @@ impl Operator {
                 input: input.vectorize(vectorize),
                 out: out.vectorize(vectorize),
             },
+            Operator::Not { input, out } => Operator::Not {
+                input: input.vectorize(vectorize),
+                out: out.vectorize(vectorize),
+            },
             Operator::AssignGlobal { input, out } => Operator::AssignGlobal {
                 input: input.vectorize(vectorize),
                 out: out.vectorize(vectorize),
@@ -315,6 +323,7 @@ impl Display for Operator {
             Operator::Recip { input, out } => {
                 f.write_fmt(format_args!("let {out} = 1.0 / {input};"))
             }
+            Operator::Not { input, out } => f.write_fmt(format_args!("let {out} = !{input};")),
             Operator::Equal { lhs, rhs, out } => comparison(lhs, rhs, out, "==", f),
             Operator::Lower { lhs, rhs, out } => comparison(lhs, rhs, out, "<", f),
             Operator::Greater { lhs, rhs, out } => comparison(lhs, rhs, out, ">", f),
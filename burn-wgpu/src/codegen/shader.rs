@@ -19,6 +19,7 @@ pub enum Visibility {
 #[derive(Debug, Clone, PartialEq, Eq, Copy, Serialize, Deserialize)]
 pub enum Elem {
     F32,
+    F16,
     I32,
     U32,
     Bool,
@@ -48,6 +49,7 @@ impl Elem {
     pub fn size(&self) -> usize {
         match self {
             Elem::F32 => core::mem::size_of::<f32>(),
+            Elem::F16 => core::mem::size_of::<half::f16>(),
             Elem::I32 => core::mem::size_of::<i32>(),
             Elem::U32 => core::mem::size_of::<u32>(),
             Elem::Bool => core::mem::size_of::<bool>(),
@@ -200,6 +202,7 @@ impl Display for Elem {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             Elem::F32 => f.write_str("f32"),
+            Elem::F16 => f.write_str("f16"),
             Elem::I32 => f.write_str("i32"),
             Elem::U32 => f.write_str("u32"),
             Elem::Bool => f.write_str("bool"),
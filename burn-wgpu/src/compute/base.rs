@@ -1,13 +1,12 @@
-use super::WgpuServer;
+use super::{BatchStrategy, ShaderCache, WgpuServer};
 use crate::{compute::WgpuStorage, GraphicsApi, WgpuDevice};
 use alloc::sync::Arc;
 use burn_compute::{
-    channel::MutexComputeChannel,
-    client::ComputeClient,
-    memory_management::{DeallocStrategy, SimpleMemoryManagement, SliceStrategy},
-    tune::Tuner,
-    Compute,
+    channel::MutexComputeChannel, client::ComputeClient, memory_management::SimpleMemoryManagement,
+    tune::Tuner, Compute,
 };
+
+pub use burn_compute::memory_management::{DeallocStrategy, MemoryUsage, SliceStrategy};
 use spin::Mutex;
 use wgpu::{AdapterInfo, DeviceDescriptor};
 
@@ -24,24 +23,93 @@ pub type WgpuHandle = burn_compute::server::Handle<Server>;
 /// Compute handle for the wgpu backend.
 static COMPUTE: Compute<WgpuDevice, WgpuServer<MemoryManagement>, Channel> = Compute::new();
 
+/// The options used to create the [compute client](ComputeClient) of a [device](WgpuDevice).
+///
+/// The clients created on first use have the [default](RuntimeOptions::default) options, use
+/// [init_sync] or [init_async_with_options] before using a device to change them.
+#[derive(Debug)]
+pub struct RuntimeOptions {
+    /// When the kernels are submitted to the queue.
+    pub batch_strategy: BatchStrategy,
+    /// How often the unused memory chunks are deallocated.
+    pub dealloc_strategy: DeallocStrategy,
+    /// When an unused memory chunk larger than requested can be reused.
+    pub slice_strategy: SliceStrategy,
+    /// Number of bytes kept in unused memory chunks before they are deallocated, without waiting
+    /// for the [deallocation strategy](DeallocStrategy). Unbounded when `None`.
+    pub max_pooled_bytes: Option<usize>,
+    /// Kernels compiled when the client is created, see [ShaderCache].
+    pub shader_cache: Option<ShaderCache>,
+}
+
+impl Default for RuntimeOptions {
+    fn default() -> Self {
+        // TODO: Support a way to modify max_tasks without std.
+        let (max_tasks, batch_strategy) = match std::env::var("BURN_WGPU_MAX_TASKS") {
+            Ok(value) => {
+                let max_tasks = value
+                    .parse::<usize>()
+                    .expect("BURN_WGPU_MAX_TASKS should be a positive integer.");
+                (max_tasks, BatchStrategy::Fixed(max_tasks))
+            }
+            Err(_) => (64, BatchStrategy::default()),
+        };
+
+        Self {
+            batch_strategy,
+            dealloc_strategy: DeallocStrategy::new_period_tick(max_tasks * 2),
+            slice_strategy: SliceStrategy::Ratio(0.8),
+            max_pooled_bytes: None,
+            shader_cache: None,
+        }
+    }
+}
+
 /// Get the [compute client](ComputeClient) for the given [device](WgpuDevice).
 pub fn compute_client<G: GraphicsApi>(device: &WgpuDevice) -> ComputeClient<Server, Channel> {
     let device = Arc::new(device);
 
     COMPUTE.client(&device, move || {
-        pollster::block_on(create_client::<G>(&device))
+        pollster::block_on(create_client::<G>(&device, RuntimeOptions::default()))
     })
 }
 
+/// Init the client of the device with the given options.
+///
+/// # Panics
+///
+/// If the device was already used or initialized.
+#[cfg(not(target_family = "wasm"))]
+pub fn init_sync<G: GraphicsApi>(device: &WgpuDevice, options: RuntimeOptions) {
+    pollster::block_on(init_async_with_options::<G>(device, options))
+}
+
 /// Init the client async, necessary for wasm.
 pub async fn init_async<G: GraphicsApi>(device: &WgpuDevice) {
+    init_async_with_options::<G>(device, RuntimeOptions::default()).await
+}
+
+/// Init the client async with the given options.
+///
+/// # Panics
+///
+/// If the device was already used or initialized.
+pub async fn init_async_with_options<G: GraphicsApi>(device: &WgpuDevice, options: RuntimeOptions) {
     let device = Arc::new(device);
-    let client = create_client::<G>(&device).await;
+    let client = create_client::<G>(&device, options).await;
 
     COMPUTE.register(&device, client)
 }
 
-async fn create_client<G: GraphicsApi>(device: &WgpuDevice) -> ComputeClient<Server, Channel> {
+/// The memory currently held by the [device](WgpuDevice).
+pub fn memory_usage<G: GraphicsApi>(device: &WgpuDevice) -> MemoryUsage {
+    compute_client::<G>(device).memory_usage()
+}
+
+async fn create_client<G: GraphicsApi>(
+    device: &WgpuDevice,
+    options: RuntimeOptions,
+) -> ComputeClient<Server, Channel> {
     let (device_wgpu, queue, info) = select_device::<G>(device).await;
 
     log::info!(
@@ -50,22 +118,19 @@ async fn create_client<G: GraphicsApi>(device: &WgpuDevice) -> ComputeClient<Ser
         info
     );
 
-    // TODO: Support a way to modify max_tasks without std.
-    let max_tasks = match std::env::var("BURN_WGPU_MAX_TASKS") {
-        Ok(value) => value
-            .parse::<usize>()
-            .expect("BURN_WGPU_MAX_TASKS should be a positive integer."),
-        Err(_) => 64, // 64 tasks by default
-    };
-
     let device = Arc::new(device_wgpu);
     let storage = WgpuStorage::new(device.clone());
-    let memory_management = SimpleMemoryManagement::new(
-        storage,
-        DeallocStrategy::new_period_tick(max_tasks * 2),
-        SliceStrategy::Ratio(0.8),
-    );
-    let server = WgpuServer::new(memory_management, device, queue, max_tasks);
+    let memory_management =
+        SimpleMemoryManagement::new(storage, options.dealloc_strategy, options.slice_strategy);
+    let memory_management = match options.max_pooled_bytes {
+        Some(max_pooled_bytes) => memory_management.with_max_pooled_bytes(max_pooled_bytes),
+        None => memory_management,
+    };
+    let mut server = WgpuServer::new(memory_management, device, queue, options.batch_strategy);
+
+    if let Some(shader_cache) = &options.shader_cache {
+        server.precompile(shader_cache);
+    }
     let channel = Channel::new(server);
 
     let tuner_device_id = tuner_device_id(info);
@@ -83,12 +148,16 @@ pub async fn select_device<G: GraphicsApi>(
     let adapter = select_adapter::<G>(device);
 
     let limits = adapter.limits();
+    // Half precision shaders are enabled when available, see `supports_f16`, as well as the
+    // timestamp queries used by the kernel profiler.
+    let features =
+        adapter.features() & (wgpu::Features::SHADER_F16 | wgpu::Features::TIMESTAMP_QUERY);
 
     let (device, queue) = adapter
         .request_device(
             &DeviceDescriptor {
                 label: None,
-                features: wgpu::Features::empty(),
+                features,
                 limits,
             },
             None,
@@ -106,8 +175,49 @@ pub async fn select_device<G: GraphicsApi>(
     (device, queue, adapter.get_info())
 }
 
+/// Whether the adapter selected for the [device](WgpuDevice) supports half precision shaders,
+/// which are needed to use [f16](half::f16) as the float element of the backend.
+///
+/// Applications can fall back to `f32` when it doesn't:
+///
+/// ```rust, ignore
+/// if supports_f16::<AutoGraphicsApi>(&device) {
+///     run::<Wgpu<AutoGraphicsApi, f16, i32>>(device);
+/// } else {
+///     run::<Wgpu<AutoGraphicsApi, f32, i32>>(device);
+/// }
+/// ```
+#[cfg(not(target_family = "wasm"))]
+pub fn supports_f16<G: GraphicsApi>(device: &WgpuDevice) -> bool {
+    select_adapter::<G>(device)
+        .features()
+        .contains(wgpu::Features::SHADER_F16)
+}
+
+/// The id of the device in the persistent autotune cache.
+///
+/// The driver and the version of burn-wgpu are part of the id, since the fastest kernels can
+/// change when the driver is updated or when the shaders are modified by a new release.
 fn tuner_device_id(info: AdapterInfo) -> String {
-    format!("wgpu-{}-{}", info.device, info.backend.to_str())
+    let id = format!(
+        "wgpu-{}-{}-{}-{}-{}-{}",
+        info.vendor,
+        info.device,
+        info.backend.to_str(),
+        info.driver,
+        info.driver_info,
+        env!("CARGO_PKG_VERSION"),
+    );
+
+    // The driver names and versions can have characters that aren't valid in file names.
+    id.chars()
+        .map(
+            |c| match c.is_ascii_alphanumeric() || c == '-' || c == '.' {
+                true => c,
+                false => '_',
+            },
+        )
+        .collect()
 }
 
 #[cfg(target_family = "wasm")]
@@ -1,11 +1,15 @@
 mod base;
 mod kernel;
+mod profiler;
 mod server;
+mod shader_cache;
 mod storage;
 mod tune_key;
 
 pub use base::*;
 pub use kernel::*;
+pub use profiler::*;
 pub use server::*;
+pub use shader_cache::*;
 pub use storage::*;
 pub use tune_key::*;
@@ -0,0 +1,255 @@
+use alloc::collections::BTreeMap;
+use core::fmt::Display;
+use core::sync::atomic::{AtomicBool, Ordering};
+use serde::Serialize;
+use std::sync::Mutex;
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+static STATE: Mutex<ProfilerState> = Mutex::new(ProfilerState::new());
+
+/// Name of the kernels dispatched before the profiler was enabled, such as the kernels of a
+/// [graph](super::WgpuGraph) captured earlier.
+pub const UNNAMED_KERNEL: &str = "unnamed";
+
+/// GPU profiler of the kernels dispatched by the wgpu backend.
+///
+/// While enabled, each kernel is executed in its own compute pass surrounded by timestamp
+/// queries, and the duration measured by the GPU is aggregated by kernel. The results are read
+/// back when the kernels are submitted, which serializes the execution, so the profiler should
+/// only be enabled while measuring.
+///
+/// Nothing is recorded when the adapter doesn't support the `timestamp-query` feature of wgpu.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// KernelProfiler::enable();
+/// let output = model.forward(input);
+/// Wgpu::sync(&device);
+/// KernelProfiler::disable();
+///
+/// println!("{}", KernelProfiler::report());
+/// std::fs::write("trace.json", KernelProfiler::report().to_chrome_trace())?;
+/// ```
+pub struct KernelProfiler;
+
+impl KernelProfiler {
+    /// Start profiling the kernels.
+    pub fn enable() {
+        ENABLED.store(true, Ordering::Relaxed);
+    }
+
+    /// Stop profiling the kernels, keeping the results recorded so far.
+    pub fn disable() {
+        ENABLED.store(false, Ordering::Relaxed);
+    }
+
+    /// If the kernels are profiled.
+    pub fn is_enabled() -> bool {
+        ENABLED.load(Ordering::Relaxed)
+    }
+
+    /// Clear the results recorded so far.
+    pub fn reset() {
+        *STATE.lock().unwrap() = ProfilerState::new();
+    }
+
+    /// Report the results recorded since the last [reset](KernelProfiler::reset).
+    pub fn report() -> KernelReport {
+        STATE.lock().unwrap().report()
+    }
+
+    /// Record the execution of a kernel, with its start and duration in nanoseconds.
+    pub(crate) fn record(name: &str, start_ns: f64, duration_ns: f64) {
+        STATE.lock().unwrap().record(name, start_ns, duration_ns);
+    }
+}
+
+/// Timing of the dispatches of a kernel, see [KernelReport].
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct KernelStats {
+    /// The id of the kernel.
+    pub name: String,
+    /// Number of dispatches of the kernel.
+    pub dispatches: usize,
+    /// Total execution time of the dispatches, in nanoseconds.
+    pub total_ns: f64,
+    /// Shortest execution time of a dispatch, in nanoseconds.
+    pub min_ns: f64,
+    /// Longest execution time of a dispatch, in nanoseconds.
+    pub max_ns: f64,
+}
+
+impl KernelStats {
+    /// Average execution time of a dispatch, in nanoseconds.
+    pub fn mean_ns(&self) -> f64 {
+        self.total_ns / self.dispatches as f64
+    }
+}
+
+/// A dispatch recorded by the [kernel profiler](KernelProfiler).
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct KernelEvent {
+    /// The id of the kernel.
+    pub name: String,
+    /// Timestamp of the start of the dispatch, in nanoseconds from an origin set by the GPU.
+    pub start_ns: f64,
+    /// Execution time of the dispatch, in nanoseconds.
+    pub duration_ns: f64,
+}
+
+/// Results of the [kernel profiler](KernelProfiler).
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct KernelReport {
+    /// Statistics of each kernel, sorted by decreasing total execution time.
+    pub kernels: Vec<KernelStats>,
+    /// Every dispatch, in the order of execution.
+    pub events: Vec<KernelEvent>,
+}
+
+impl KernelReport {
+    /// Total execution time of every dispatch, in nanoseconds.
+    pub fn total_ns(&self) -> f64 {
+        self.kernels.iter().map(|stats| stats.total_ns).sum()
+    }
+
+    /// Serialize the report as JSON.
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("Profiler report should be serializable")
+    }
+
+    /// Serialize the dispatches in the [Chrome trace event format], which can be opened with
+    /// `chrome://tracing` or [Perfetto](https://ui.perfetto.dev).
+    ///
+    /// [Chrome trace event format]: https://docs.google.com/document/d/1CvAClvFfyA5R-PhYUmn5OOQtYMH4h6I0nSsKchNAySU
+    pub fn to_chrome_trace(&self) -> String {
+        let events = self
+            .events
+            .iter()
+            .map(|event| {
+                serde_json::json!({
+                    "name": event.name,
+                    "cat": "kernel",
+                    "ph": "X",
+                    "ts": event.start_ns / 1000.0,
+                    "dur": event.duration_ns / 1000.0,
+                    "pid": 0,
+                    "tid": 0,
+                })
+            })
+            .collect::<Vec<_>>();
+
+        serde_json::json!({ "traceEvents": events }).to_string()
+    }
+}
+
+impl Display for KernelReport {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        writeln!(
+            f,
+            "{:>12} {:>10} {:>12} {:>7}  kernel",
+            "total (us)", "dispatches", "mean (us)", "%"
+        )?;
+
+        let total = self.total_ns();
+
+        for stats in self.kernels.iter() {
+            writeln!(
+                f,
+                "{:>12.1} {:>10} {:>12.2} {:>6.1}%  {}",
+                stats.total_ns / 1000.0,
+                stats.dispatches,
+                stats.mean_ns() / 1000.0,
+                100.0 * stats.total_ns / total,
+                stats.name
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+struct ProfilerState {
+    kernels: BTreeMap<String, KernelStats>,
+    events: Vec<KernelEvent>,
+}
+
+impl ProfilerState {
+    const fn new() -> Self {
+        Self {
+            kernels: BTreeMap::new(),
+            events: Vec::new(),
+        }
+    }
+
+    fn record(&mut self, name: &str, start_ns: f64, duration_ns: f64) {
+        let stats = self
+            .kernels
+            .entry(name.to_string())
+            .or_insert_with(|| KernelStats {
+                name: name.to_string(),
+                dispatches: 0,
+                total_ns: 0.0,
+                min_ns: f64::INFINITY,
+                max_ns: 0.0,
+            });
+
+        stats.dispatches += 1;
+        stats.total_ns += duration_ns;
+        stats.min_ns = stats.min_ns.min(duration_ns);
+        stats.max_ns = stats.max_ns.max(duration_ns);
+
+        self.events.push(KernelEvent {
+            name: name.to_string(),
+            start_ns,
+            duration_ns,
+        });
+    }
+
+    fn report(&self) -> KernelReport {
+        let mut kernels = self.kernels.values().cloned().collect::<Vec<_>>();
+        kernels.sort_by(|a, b| b.total_ns.total_cmp(&a.total_ns));
+
+        KernelReport {
+            kernels,
+            events: self.events.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_aggregate_dispatches_by_kernel() {
+        let mut state = ProfilerState::new();
+        state.record("matmul", 0.0, 3000.0);
+        state.record("relu", 3000.0, 500.0);
+        state.record("matmul", 3500.0, 1000.0);
+
+        let report = state.report();
+
+        assert_eq!(report.kernels.len(), 2);
+        assert_eq!(report.kernels[0].name, "matmul");
+        assert_eq!(report.kernels[0].dispatches, 2);
+        assert_eq!(report.kernels[0].mean_ns(), 2000.0);
+        assert_eq!(report.kernels[0].min_ns, 1000.0);
+        assert_eq!(report.kernels[0].max_ns, 3000.0);
+        assert_eq!(report.events.len(), 3);
+        assert_eq!(report.total_ns(), 4500.0);
+    }
+
+    #[test]
+    fn should_export_chrome_trace_in_microseconds() {
+        let mut state = ProfilerState::new();
+        state.record("relu", 2000.0, 500.0);
+
+        let trace: serde_json::Value =
+            serde_json::from_str(&state.report().to_chrome_trace()).unwrap();
+
+        assert_eq!(trace["traceEvents"][0]["name"], "relu");
+        assert_eq!(trace["traceEvents"][0]["ts"], 2.0);
+        assert_eq!(trace["traceEvents"][0]["dur"], 0.5);
+    }
+}
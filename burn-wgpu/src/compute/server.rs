@@ -1,11 +1,12 @@
-use super::{WgpuAutotuneKey, WgpuStorage, WorkGroup};
+use super::{KernelProfiler, ShaderCache, WgpuAutotuneKey, WgpuStorage, WorkGroup};
 use crate::kernel::SourceTemplate;
 use alloc::{borrow::Cow, sync::Arc};
 use burn_compute::{
-    memory_management::MemoryManagement,
-    server::{self, ComputeServer},
+    memory_management::{MemoryManagement, MemoryUsage},
+    server::{self, ComputeServer, ReadFuture},
 };
 use burn_tensor::Reader;
+use core::sync::atomic::{AtomicUsize, Ordering};
 use hashbrown::HashMap;
 use wgpu::{
     util::{BufferInitDescriptor, DeviceExt},
@@ -21,16 +22,98 @@ pub struct WgpuServer<MM: MemoryManagement<WgpuStorage>> {
     encoder: CommandEncoder,
     pipelines: HashMap<String, Arc<ComputePipeline>>,
     tasks: Vec<ComputeTask>,
-    max_tasks: usize,
+    // Workgroups dispatched by the tasks registered since the last submission.
+    num_workgroups: u64,
+    batch_strategy: BatchStrategy,
     manual_available: HashMap<usize, Vec<server::Handle<Self>>>,
     manual_taken: Vec<(usize, server::Handle<Self>)>,
+    capture: Option<Vec<CapturedTask<MM>>>,
 }
 
-#[derive(new, Debug)]
+/// The strategy defines when the kernels executed by the [server](WgpuServer) are submitted to the
+/// queue.
+///
+/// Every submission has a fixed overhead, which dominates the execution of small models, while
+/// waiting too long before submitting leaves the device idle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BatchStrategy {
+    /// Submit once the given number of kernels is registered.
+    Fixed(usize),
+    /// Submit once the registered kernels dispatch at least `max_workgroups` workgroups, or once
+    /// `max_tasks` kernels are registered.
+    ///
+    /// Many small kernels are batched in a single submission, while large kernels are submitted
+    /// early to keep the device busy.
+    Auto {
+        /// Number of workgroups dispatched before submitting.
+        max_workgroups: u64,
+        /// Number of kernels registered before submitting.
+        max_tasks: usize,
+    },
+}
+
+impl Default for BatchStrategy {
+    fn default() -> Self {
+        // Submits 64 large kernels at once, with a million elements each, like a fixed batch of 64,
+        // but up to 512 smaller ones.
+        BatchStrategy::Auto {
+            max_workgroups: 64 * 4096,
+            max_tasks: 512,
+        }
+    }
+}
+
+impl BatchStrategy {
+    /// If the registered kernels should be submitted.
+    pub fn should_submit(&self, num_tasks: usize, num_workgroups: u64) -> bool {
+        match self {
+            BatchStrategy::Fixed(max_tasks) => num_tasks >= *max_tasks,
+            BatchStrategy::Auto {
+                max_workgroups,
+                max_tasks,
+            } => num_tasks >= *max_tasks || num_workgroups >= *max_workgroups,
+        }
+    }
+}
+
+#[derive(new, Debug, Clone)]
 struct ComputeTask {
     pipeline: Arc<ComputePipeline>,
-    bind_group: BindGroup,
+    bind_group: Arc<BindGroup>,
     work_group: WorkGroup,
+    // Only kept while the kernels are profiled.
+    kernel_id: Option<Arc<str>>,
+}
+
+/// Kernels recorded by the [wgpu server](WgpuServer), with their bind groups already created.
+///
+/// Replaying the graph only dispatches the recorded compute pipelines, skipping the kernel
+/// selection, the autotune lookups and the creation of the bind groups.
+#[derive(Debug)]
+pub struct WgpuGraph<MM: MemoryManagement<WgpuStorage>> {
+    tasks: Arc<Vec<CapturedTask<MM>>>,
+}
+
+impl<MM: MemoryManagement<WgpuStorage>> Clone for WgpuGraph<MM> {
+    fn clone(&self) -> Self {
+        Self {
+            tasks: self.tasks.clone(),
+        }
+    }
+}
+
+impl<MM: MemoryManagement<WgpuStorage>> WgpuGraph<MM> {
+    /// The number of kernels in the graph.
+    pub fn num_kernels(&self) -> usize {
+        self.tasks.len()
+    }
+}
+
+#[derive(Debug)]
+struct CapturedTask<MM: MemoryManagement<WgpuStorage>> {
+    task: ComputeTask,
+    // The bind group refers to the memory of the handles, which must not be reused.
+    _handles: Vec<server::Handle<WgpuServer<MM>>>,
 }
 
 /// Kernel trait with the [source](SourceTemplate) that will be compiled and cached based on the
@@ -55,7 +138,7 @@ where
         memory_management: MM,
         device: Arc<wgpu::Device>,
         queue: wgpu::Queue,
-        max_tasks: usize,
+        batch_strategy: BatchStrategy,
     ) -> Self {
         let encoder = device.create_command_encoder(&wgpu::CommandEncoderDescriptor {
             label: Some("Command Encoder"),
@@ -68,9 +151,11 @@ where
             encoder,
             pipelines: HashMap::new(),
             tasks: Vec::new(),
-            max_tasks,
+            num_workgroups: 0,
+            batch_strategy,
             manual_available: HashMap::new(),
             manual_taken: Vec::new(),
+            capture: None,
         }
     }
 
@@ -129,11 +214,37 @@ where
         }
     }
 
+    fn push_task(&mut self, task: ComputeTask) {
+        let work_group = &task.work_group;
+        self.num_workgroups += work_group.x as u64 * work_group.y as u64 * work_group.z as u64;
+        self.tasks.push(task);
+
+        if self
+            .batch_strategy
+            .should_submit(self.tasks.len(), self.num_workgroups)
+        {
+            self.register_tasks();
+            self.submit();
+        }
+    }
+
     fn register_tasks(&mut self) {
         if self.tasks.is_empty() {
             return;
         }
 
+        self.num_workgroups = 0;
+
+        #[cfg(not(target_family = "wasm"))]
+        if KernelProfiler::is_enabled()
+            && self
+                .device
+                .features()
+                .contains(wgpu::Features::TIMESTAMP_QUERY)
+        {
+            return self.register_tasks_profiled();
+        }
+
         let mut compute = self
             .encoder
             .begin_compute_pass(&wgpu::ComputePassDescriptor {
@@ -151,6 +262,70 @@ where
         self.tasks.clear();
     }
 
+    /// Execute each task in its own compute pass with timestamp queries, and record the durations
+    /// in the [kernel profiler](KernelProfiler).
+    ///
+    /// The tasks are submitted and the timestamps are read back right away.
+    #[cfg(not(target_family = "wasm"))]
+    fn register_tasks_profiled(&mut self) {
+        let tasks = core::mem::take(&mut self.tasks);
+        let num_queries = 2 * tasks.len() as u32;
+        let query_set = self.device.create_query_set(&wgpu::QuerySetDescriptor {
+            label: Some("Kernel Profiler"),
+            ty: wgpu::QueryType::Timestamp,
+            count: num_queries,
+        });
+
+        for (i, task) in tasks.iter().enumerate() {
+            let mut compute = self
+                .encoder
+                .begin_compute_pass(&wgpu::ComputePassDescriptor {
+                    label: None,
+                    timestamp_writes: Some(wgpu::ComputePassTimestampWrites {
+                        query_set: &query_set,
+                        beginning_of_pass_write_index: Some(2 * i as u32),
+                        end_of_pass_write_index: Some(2 * i as u32 + 1),
+                    }),
+                });
+
+            compute.set_pipeline(&task.pipeline);
+            compute.set_bind_group(0, &task.bind_group, &[]);
+            compute.dispatch_workgroups(task.work_group.x, task.work_group.y, task.work_group.z);
+        }
+
+        let size = num_queries as u64 * core::mem::size_of::<u64>() as u64;
+        let buffer_resolve = self.device.create_buffer(&wgpu::BufferDescriptor {
+            label: None,
+            size,
+            usage: wgpu::BufferUsages::QUERY_RESOLVE | wgpu::BufferUsages::COPY_SRC,
+            mapped_at_creation: false,
+        });
+        let buffer_dest = self.device.create_buffer(&wgpu::BufferDescriptor {
+            label: None,
+            size,
+            usage: wgpu::BufferUsages::MAP_READ | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+
+        self.encoder
+            .resolve_query_set(&query_set, 0..num_queries, &buffer_resolve, 0);
+        self.encoder
+            .copy_buffer_to_buffer(&buffer_resolve, 0, &buffer_dest, 0, size);
+        self.submit();
+
+        let bytes = BufferReader::new(buffer_dest).read(&self.device);
+        let timestamps: &[u64] = bytemuck::cast_slice(&bytes);
+        let period = self.queue.get_timestamp_period() as f64;
+
+        for (task, timestamps) in tasks.iter().zip(timestamps.chunks_exact(2)) {
+            let name = task.kernel_id.as_deref().unwrap_or(super::UNNAMED_KERNEL);
+            let start = timestamps[0] as f64 * period;
+            let duration = timestamps[1].saturating_sub(timestamps[0]) as f64 * period;
+
+            KernelProfiler::record(name, start, duration);
+        }
+    }
+
     fn pipeline(&mut self, kernel: Box<dyn Kernel>) -> Arc<ComputePipeline> {
         let kernel_id = kernel.id();
         if let Some(pipeline) = self.pipelines.get(&kernel_id) {
@@ -158,16 +333,38 @@ where
         }
 
         let source = kernel.source().complete();
+        ShaderCache::record(&kernel_id, &source);
         let pipeline = self.compile_source(&source);
         self.pipelines.insert(kernel_id.clone(), pipeline.clone());
 
         pipeline
     }
 
+    /// Compile the kernels of the [shader cache](ShaderCache), so that they are ready when they are
+    /// first executed.
+    pub fn precompile(&mut self, shader_cache: &ShaderCache) {
+        for (kernel_id, source) in shader_cache.shaders() {
+            if !self.pipelines.contains_key(kernel_id) {
+                let pipeline = self.compile_source(source);
+                self.pipelines.insert(kernel_id.clone(), pipeline);
+            }
+        }
+    }
+
     fn compile_source(&self, source: &str) -> Arc<ComputePipeline> {
+        let source = match uses_f16(source) {
+            true => {
+                assert!(
+                    self.device.features().contains(wgpu::Features::SHADER_F16),
+                    "The adapter doesn't support f16 shaders, use f32 as the float element instead."
+                );
+                Cow::Owned(format!("enable f16;\n{source}"))
+            }
+            false => Cow::Borrowed(source),
+        };
         let module = self.device.create_shader_module(ShaderModuleDescriptor {
             label: None,
-            source: wgpu::ShaderSource::Wgsl(Cow::Borrowed(source)),
+            source: wgpu::ShaderSource::Wgsl(source),
         });
 
         Arc::new(
@@ -182,30 +379,42 @@ where
     }
 
     fn buffer_reader(&mut self, handle: &server::Handle<Self>) -> BufferReader {
-        // Register previous tasks before reading the buffer so that it is up to date.
-        self.register_tasks();
+        self.buffer_readers(&[handle]).remove(0)
+    }
 
-        let resource = self.memory_management.get(&handle.memory);
+    /// Copy the buffers of the handles in buffers that can be mapped, with a single submission.
+    fn buffer_readers(&mut self, handles: &[&server::Handle<Self>]) -> Vec<BufferReader> {
+        // Register previous tasks before reading the buffers so that they are up to date.
+        self.register_tasks();
 
-        let size = resource.size();
-        let buffer_dest = self.device.create_buffer(&wgpu::BufferDescriptor {
-            label: None,
-            size,
-            usage: wgpu::BufferUsages::MAP_READ | wgpu::BufferUsages::COPY_DST,
-            mapped_at_creation: false,
-        });
+        let readers = handles
+            .iter()
+            .map(|handle| {
+                let resource = self.memory_management.get(&handle.memory);
 
-        self.encoder.copy_buffer_to_buffer(
-            &resource.buffer,
-            resource.offset(),
-            &buffer_dest,
-            0,
-            size,
-        );
+                let size = resource.size();
+                let buffer_dest = self.device.create_buffer(&wgpu::BufferDescriptor {
+                    label: None,
+                    size,
+                    usage: wgpu::BufferUsages::MAP_READ | wgpu::BufferUsages::COPY_DST,
+                    mapped_at_creation: false,
+                });
+
+                self.encoder.copy_buffer_to_buffer(
+                    &resource.buffer,
+                    resource.offset(),
+                    &buffer_dest,
+                    0,
+                    size,
+                );
+
+                BufferReader::new(buffer_dest)
+            })
+            .collect();
 
         self.submit();
 
-        BufferReader::new(buffer_dest)
+        readers
     }
 }
 
@@ -259,6 +468,7 @@ where
     type Storage = WgpuStorage;
     type MemoryManagement = MM;
     type AutotuneKey = WgpuAutotuneKey;
+    type Graph = WgpuGraph<MM>;
 
     fn read(&mut self, handle: &server::Handle<Self>) -> Reader<Vec<u8>> {
         #[cfg(target_family = "wasm")]
@@ -271,6 +481,12 @@ where
         Reader::Concrete(self.buffer_reader(handle).read(&self.device))
     }
 
+    fn read_async(&mut self, handles: &[&server::Handle<Self>]) -> ReadFuture {
+        let readers = self.buffer_readers(handles);
+
+        Box::pin(read_buffers(readers, self.device.clone()))
+    }
+
     /// When we create a new handle from existing data, we use custom allocations so that we don't
     /// have to execute the current pending tasks.
     ///
@@ -304,9 +520,20 @@ where
 
     fn execute(&mut self, kernel: Self::Kernel, handles: &[&server::Handle<Self>]) {
         let work_group = kernel.workgroup();
+        let kernel_id = match KernelProfiler::is_enabled() {
+            true => Some(Arc::from(kernel.id())),
+            false => None,
+        };
         let pipeline = self.pipeline(kernel);
         let group_layout = pipeline.get_bind_group_layout(0);
 
+        let captured_handles = self.capture.as_ref().map(|_| {
+            handles
+                .iter()
+                .map(|handle| (*handle).clone())
+                .collect::<Vec<_>>()
+        });
+
         let handles = handles
             .iter()
             .map(|handle| self.memory_management.get(&handle.memory))
@@ -321,19 +548,22 @@ where
             })
             .collect::<Vec<_>>();
 
-        let bind_group = self.device.create_bind_group(&wgpu::BindGroupDescriptor {
+        let bind_group = Arc::new(self.device.create_bind_group(&wgpu::BindGroupDescriptor {
             label: None,
             layout: &group_layout,
             entries: &entries,
-        });
+        }));
 
-        self.tasks
-            .push(ComputeTask::new(pipeline, bind_group, work_group));
+        let task = ComputeTask::new(pipeline, bind_group, work_group, kernel_id);
 
-        if self.tasks.len() >= self.max_tasks {
-            self.register_tasks();
-            self.submit();
+        if let (Some(capture), Some(handles)) = (self.capture.as_mut(), captured_handles) {
+            capture.push(CapturedTask {
+                task: task.clone(),
+                _handles: handles,
+            });
         }
+
+        self.push_task(task);
     }
 
     fn sync(&mut self) {
@@ -344,4 +574,117 @@ where
 
         self.device.poll(wgpu::Maintain::Wait);
     }
+
+    fn capture_begin(&mut self) {
+        self.capture = Some(Vec::new());
+    }
+
+    fn capture_end(&mut self) -> Self::Graph {
+        WgpuGraph {
+            tasks: Arc::new(self.capture.take().unwrap_or_default()),
+        }
+    }
+
+    fn replay(&mut self, graph: &Self::Graph) {
+        for captured in graph.tasks.iter() {
+            self.push_task(captured.task.clone());
+        }
+    }
+
+    fn memory_usage(&self) -> MemoryUsage {
+        self.memory_management.memory_usage()
+    }
+}
+
+/// Map the buffers and read them once the device has completed their copies.
+async fn read_buffers(readers: Vec<BufferReader>, device: Arc<wgpu::Device>) -> Vec<Vec<u8>> {
+    let pending = Arc::new(AtomicUsize::new(readers.len()));
+    let receivers = readers
+        .iter()
+        .map(|reader| {
+            let (sender, receiver) = futures_intrusive::channel::shared::oneshot_channel();
+            let pending = pending.clone();
+
+            reader
+                .buffer
+                .slice(..)
+                .map_async(wgpu::MapMode::Read, move |result| {
+                    sender
+                        .send(result)
+                        .expect("Unable to send buffer slice result to async channel.");
+                    pending.fetch_sub(1, Ordering::Release);
+                });
+
+            receiver
+        })
+        .collect::<Vec<_>>();
+
+    poll_until_mapped(device, pending).await;
+
+    let mut data = Vec::with_capacity(readers.len());
+
+    for (reader, receiver) in readers.iter().zip(receivers) {
+        match receiver.receive().await {
+            Some(Ok(())) => {
+                let bytes = reader.buffer.slice(..).get_mapped_range().to_vec();
+                reader.buffer.unmap();
+                data.push(bytes);
+            }
+            result => panic!("Unable to read buffer {:?}", result),
+        }
+    }
+
+    data
+}
+
+/// On native platforms, the buffers are only mapped when the device is polled, which is done
+/// without waiting so that the executor isn't blocked.
+#[cfg(not(target_family = "wasm"))]
+async fn poll_until_mapped(device: Arc<wgpu::Device>, pending: Arc<AtomicUsize>) {
+    core::future::poll_fn(|cx| {
+        device.poll(wgpu::Maintain::Poll);
+
+        if pending.load(Ordering::Acquire) == 0 {
+            core::task::Poll::Ready(())
+        } else {
+            cx.waker().wake_by_ref();
+            core::task::Poll::Pending
+        }
+    })
+    .await
+}
+
+/// In browsers, the buffers are mapped by the event loop, which must not be starved by polling.
+#[cfg(target_family = "wasm")]
+async fn poll_until_mapped(_device: Arc<wgpu::Device>, _pending: Arc<AtomicUsize>) {}
+
+/// Whether the shader has a half precision type, which must be enabled by the shader.
+fn uses_f16(source: &str) -> bool {
+    source
+        .split(|c: char| !c.is_ascii_alphanumeric() && c != '_')
+        .any(|token| token == "f16")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_detect_half_precision_shaders() {
+        assert!(uses_f16("var<storage, read> input: array<vec4<f16>>;"));
+        assert!(!uses_f16("var<storage, read> input_f16: array<f32>;"));
+    }
+
+    #[test]
+    fn auto_batch_strategy_should_submit_small_kernels_later() {
+        let strategy = BatchStrategy::Auto {
+            max_workgroups: 1000,
+            max_tasks: 100,
+        };
+
+        assert!(!strategy.should_submit(99, 999));
+        assert!(strategy.should_submit(100, 10));
+        assert!(strategy.should_submit(2, 1000));
+        assert!(BatchStrategy::Fixed(2).should_submit(2, 0));
+    }
 }
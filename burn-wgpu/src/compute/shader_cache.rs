@@ -0,0 +1,150 @@
+use alloc::collections::BTreeMap;
+use core::sync::atomic::{AtomicBool, Ordering};
+use serde::{Deserialize, Serialize};
+use std::sync::Mutex;
+
+static RECORDING: AtomicBool = AtomicBool::new(false);
+static RECORDED: Mutex<ShaderCache> = Mutex::new(ShaderCache::new());
+
+/// The complete WGSL sources of kernels, compiled when the [compute client](super::WgpuComputeClient)
+/// of a device is created instead of when the kernels are first executed.
+///
+/// Compiling the shaders can take seconds on some platforms, such as DirectX 12, which all happens
+/// during the first inference otherwise. The sources of a run can be recorded, saved next to the
+/// application or embedded in its binary, and passed to the
+/// [runtime options](super::RuntimeOptions) of the next runs.
+///
+/// The sources are validated by naga when they are compiled, and a cache recorded by another
+/// version of burn-wgpu is ignored, since the kernels could have changed.
+///
+/// # Example
+///
+/// ```rust, ignore
+/// // When building the application.
+/// ShaderCache::start_recording();
+/// run_inference::<Wgpu>(&device);
+/// std::fs::write("shaders.json", ShaderCache::recorded().to_json())?;
+///
+/// // When running the application.
+/// let shader_cache = ShaderCache::from_json(include_str!("shaders.json"))?;
+/// init_sync::<AutoGraphicsApi>(
+///     &device,
+///     RuntimeOptions {
+///         shader_cache: Some(shader_cache),
+///         ..Default::default()
+///     },
+/// );
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ShaderCache {
+    version: String,
+    shaders: BTreeMap<String, String>,
+}
+
+impl ShaderCache {
+    /// Create an empty cache for the current version of burn-wgpu.
+    pub const fn new() -> Self {
+        Self {
+            version: String::new(),
+            shaders: BTreeMap::new(),
+        }
+    }
+
+    /// Start recording the sources of the kernels compiled on every device.
+    pub fn start_recording() {
+        RECORDING.store(true, Ordering::Relaxed);
+    }
+
+    /// Stop recording the sources of the kernels, keeping the ones recorded so far.
+    pub fn stop_recording() {
+        RECORDING.store(false, Ordering::Relaxed);
+    }
+
+    /// The sources of the kernels recorded so far.
+    pub fn recorded() -> ShaderCache {
+        RECORDED.lock().unwrap().clone()
+    }
+
+    /// Add the source of a kernel to the cache.
+    pub fn insert(&mut self, kernel_id: String, source: String) {
+        if self.version.is_empty() {
+            self.version = env!("CARGO_PKG_VERSION").to_string();
+        }
+
+        self.shaders.insert(kernel_id, source);
+    }
+
+    /// The number of kernels in the cache.
+    pub fn len(&self) -> usize {
+        self.shaders.len()
+    }
+
+    /// If the cache has no kernel.
+    pub fn is_empty(&self) -> bool {
+        self.shaders.is_empty()
+    }
+
+    /// Serialize the cache as JSON.
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).expect("Shader cache should be serializable")
+    }
+
+    /// Deserialize a cache serialized with [to_json](ShaderCache::to_json).
+    pub fn from_json(json: &str) -> Result<Self, serde_json::Error> {
+        serde_json::from_str(json)
+    }
+
+    /// The id and source of each kernel of the cache, unless it was recorded by another version of
+    /// burn-wgpu.
+    pub(crate) fn shaders(&self) -> impl Iterator<Item = (&String, &String)> {
+        let is_compatible = self.version == env!("CARGO_PKG_VERSION");
+
+        if !is_compatible && !self.is_empty() {
+            log::warn!(
+                "Ignoring the shader cache recorded by burn-wgpu {}, the current version is {}",
+                self.version,
+                env!("CARGO_PKG_VERSION")
+            );
+        }
+
+        self.shaders.iter().filter(move |_| is_compatible)
+    }
+
+    /// Record the source of a kernel, if recording.
+    pub(crate) fn record(kernel_id: &str, source: &str) {
+        if RECORDING.load(Ordering::Relaxed) {
+            RECORDED
+                .lock()
+                .unwrap()
+                .insert(kernel_id.to_string(), source.to_string());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_keep_shaders_after_json_round_trip() {
+        let mut cache = ShaderCache::new();
+        cache.insert("relu-f32".to_string(), "fn main() {}".to_string());
+
+        let cache = ShaderCache::from_json(&cache.to_json()).unwrap();
+
+        assert_eq!(cache.len(), 1);
+        assert_eq!(
+            cache.shaders().collect::<Vec<_>>(),
+            vec![(&"relu-f32".to_string(), &"fn main() {}".to_string())]
+        );
+    }
+
+    #[test]
+    fn should_ignore_shaders_of_another_version() {
+        let mut cache = ShaderCache::new();
+        cache.insert("relu-f32".to_string(), "fn main() {}".to_string());
+        cache.version = "0.0.1".to_string();
+
+        assert_eq!(cache.shaders().count(), 0);
+    }
+}
@@ -64,5 +64,24 @@ impl WgpuElement for f32 {
     }
 }
 
+impl WgpuElement for half::f16 {
+    fn type_name() -> &'static str {
+        "f16"
+    }
+    fn as_bytes(slice: &[Self]) -> &[u8] {
+        bytemuck::cast_slice(slice)
+    }
+    fn from_bytes(bytes: &[u8]) -> &[Self] {
+        bytemuck::cast_slice(bytes)
+    }
+
+    fn elem_type() -> crate::codegen::Elem {
+        crate::codegen::Elem::F16
+    }
+}
+
 impl FloatElement for f32 {}
+/// Half precision floats need an adapter supporting the `shader-f16` feature, see
+/// [supports_f16](crate::compute::supports_f16).
+impl FloatElement for half::f16 {}
 impl IntElement for i32 {}
@@ -1,12 +1,17 @@
-use super::{ElementWise, ElementWiseState};
+use super::{
+    ElementWise, ElementWiseState, MatmulEpilogue, MatmulEpilogueState, ReduceEpilogue,
+    ReduceEpilogueState,
+};
 use crate::{
     compute::{WgpuComputeClient, WgpuHandle},
     element::WgpuElement,
-    fusion::ElementWiseBuilder,
+    fusion::{ElementWiseBuilder, MatmulEpilogueBuilder, ReduceEpilogueBuilder},
     tensor::WgpuTensor,
     FloatElement, GraphicsApi, IntElement, Wgpu, WgpuDevice,
 };
-use burn_fusion::{client::MutexFusionClient, DeviceId, FusionBackend, FusionDevice};
+use burn_fusion::{
+    client::MutexFusionClient, CustomOptimization, DeviceId, FusionBackend, FusionDevice,
+};
 use burn_tensor::Shape;
 use core::marker::PhantomData;
 use serde::{Deserialize, Serialize};
@@ -17,6 +22,13 @@ use serde::{Deserialize, Serialize};
 pub enum WgpuOptimization<G: GraphicsApi, F: FloatElement, I: IntElement> {
     /// Element wise optimization.
     ElementWise(ElementWise<G, F, I>),
+    /// Matmul fused with the element wise operations applied on its output.
+    MatmulEpilogue(MatmulEpilogue<G, F, I>),
+    /// Reduction along a dimension fused with the element wise operations applied on its result.
+    ReduceEpilogue(ReduceEpilogue<G, F, I>),
+    /// Optimization built by a builder registered with
+    /// [register_optimization](burn_fusion::Fusion::register_optimization).
+    Custom(Box<dyn CustomOptimization<Wgpu<G, F, I>>>),
 }
 
 /// Fusion optimization state type for WGPU.
@@ -26,6 +38,10 @@ pub enum WgpuOptimization<G: GraphicsApi, F: FloatElement, I: IntElement> {
 pub enum WgpuOptimizationState {
     /// Element wise state.
     ElementWise(ElementWiseState),
+    /// Matmul epilogue state.
+    MatmulEpilogue(MatmulEpilogueState),
+    /// Reduce epilogue state.
+    ReduceEpilogue(ReduceEpilogueState),
 }
 
 impl<G: GraphicsApi, F: FloatElement, I: IntElement> burn_fusion::Optimization<Wgpu<G, F, I>>
@@ -34,18 +50,31 @@ impl<G: GraphicsApi, F: FloatElement, I: IntElement> burn_fusion::Optimization<W
     fn execute(&mut self, context: &mut burn_fusion::stream::Context<'_, Wgpu<G, F, I>>) {
         match self {
             Self::ElementWise(op) => op.execute(context),
+            Self::MatmulEpilogue(op) => op.execute(context),
+            Self::ReduceEpilogue(op) => op.execute(context),
+            Self::Custom(op) => op.execute(context),
         }
     }
 
     fn len(&self) -> usize {
         match self {
             Self::ElementWise(op) => op.len(),
+            Self::MatmulEpilogue(op) => op.len(),
+            Self::ReduceEpilogue(op) => op.len(),
+            Self::Custom(op) => op.len(),
         }
     }
 
+    fn is_persistent(&self) -> bool {
+        !matches!(self, Self::Custom(_))
+    }
+
     fn to_state(&self) -> WgpuOptimizationState {
         match self {
             Self::ElementWise(value) => WgpuOptimizationState::ElementWise(value.to_state()),
+            Self::MatmulEpilogue(value) => WgpuOptimizationState::MatmulEpilogue(value.to_state()),
+            Self::ReduceEpilogue(value) => WgpuOptimizationState::ReduceEpilogue(value.to_state()),
+            Self::Custom(_) => panic!("Custom optimizations can't be serialized"),
         }
     }
 
@@ -54,6 +83,12 @@ impl<G: GraphicsApi, F: FloatElement, I: IntElement> burn_fusion::Optimization<W
             WgpuOptimizationState::ElementWise(state) => {
                 Self::ElementWise(ElementWise::from_state(device, state))
             }
+            WgpuOptimizationState::MatmulEpilogue(state) => {
+                Self::MatmulEpilogue(MatmulEpilogue::from_state(device, state))
+            }
+            WgpuOptimizationState::ReduceEpilogue(state) => {
+                Self::ReduceEpilogue(ReduceEpilogue::from_state(device, state))
+            }
         }
     }
 }
@@ -83,7 +118,11 @@ where
     type FusionClient = MutexFusionClient<Self>;
 
     fn optimizations(device: WgpuDevice) -> Vec<Box<dyn burn_fusion::OptimizationBuilder<Self>>> {
-        vec![Box::new(ElementWiseBuilder::new(device))]
+        vec![
+            Box::new(ElementWiseBuilder::new(device.clone())),
+            Box::new(MatmulEpilogueBuilder::new(device.clone())),
+            Box::new(ReduceEpilogueBuilder::new(device)),
+        ]
     }
 
     fn float_tensor<const D: usize>(
@@ -118,6 +157,16 @@ where
     fn bool_tensor_handle<const D: usize>(tensor: Self::BoolTensorPrimitive<D>) -> Self::Handle {
         tensor.into()
     }
+
+    fn reusable_elem_size(handle: &Self::Handle, shape: &[usize]) -> Option<usize> {
+        // A contiguous buffer holds at least the elements of the shape.
+        if !handle.handle.can_mut() || handle.strides != strides_dyn_rank(shape) {
+            return None;
+        }
+
+        // Int and bool elements have the same size as float elements.
+        Some(core::mem::size_of::<F>())
+    }
 }
 
 pub fn strides_dyn_rank(shape: &[usize]) -> Vec<usize> {
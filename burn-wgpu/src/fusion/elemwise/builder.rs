@@ -7,8 +7,9 @@ use crate::{
 };
 use burn_fusion::{
     stream::{
-        BaseOpsDescription, BinaryOpsDescription, FloatOpsDescription, NumericOpsDescription,
-        ScalarOpsDescription, TensorOpsDescription, UnaryOpsDescription,
+        AliasDescription, BaseOpsDescription, BinaryOpsDescription, BoolOpsDescription,
+        FloatOpsDescription, IntOpsDescription, NumericOpsDescription, ScalarOpsDescription,
+        TensorOpsDescription, UnaryOpsDescription,
     },
     OptimizationBuilder, OptimizationProperties, OptimizationStatus, TensorDescription, TensorId,
 };
@@ -48,13 +49,19 @@ where
 
         match ops {
             TensorOpsDescription::BaseOpsFloat(ops) => {
-                if !self.register_base::<F>(ops) {
+                if !self.register_base(ops, F::elem_type()) {
                     self.status = OptimizationStatus::Closed;
                     return;
                 }
             }
             TensorOpsDescription::BaseOpsInt(ops) => {
-                if !self.register_base::<I>(ops) {
+                if !self.register_base(ops, I::elem_type()) {
+                    self.status = OptimizationStatus::Closed;
+                    return;
+                }
+            }
+            TensorOpsDescription::BaseOpsBool(ops) => {
+                if !self.register_base(ops, Elem::Bool) {
                     self.status = OptimizationStatus::Closed;
                     return;
                 }
@@ -77,6 +84,18 @@ where
                     return;
                 }
             }
+            TensorOpsDescription::IntOps(ops) => {
+                if !self.register_int(ops) {
+                    self.status = OptimizationStatus::Closed;
+                    return;
+                }
+            }
+            TensorOpsDescription::BoolOps(ops) => {
+                if !self.register_bool(ops) {
+                    self.status = OptimizationStatus::Closed;
+                    return;
+                }
+            }
             _ => {
                 self.status = OptimizationStatus::Closed;
                 return;
@@ -156,7 +175,7 @@ where
         }
     }
 
-    fn input_descriptions(&self) -> Vec<(TensorDescription, Elem)> {
+    pub(crate) fn input_descriptions(&self) -> Vec<(TensorDescription, Elem)> {
         self.inputs
             .iter()
             .map(|input| {
@@ -166,7 +185,7 @@ where
             .collect::<Vec<_>>()
     }
 
-    fn output_descriptions(&self) -> Vec<(TensorDescription, Elem)> {
+    pub(crate) fn output_descriptions(&self) -> Vec<(TensorDescription, Elem)> {
         let mut outputs = Vec::new();
         let mut local_tensor_ids_input = Vec::new();
         let mut local_tensor_ids_output = Vec::new();
@@ -194,8 +213,9 @@ where
                 Operator::AssignGlobal { input: _, out: _ } => {
                     // Nothing to do here.
                 }
-                Operator::AssignLocal { input: _, out: _ } => {
-                    // Nothing to do here.
+                Operator::AssignLocal { input, out } => {
+                    mark(input, &mut local_tensor_ids_input);
+                    mark(out, &mut local_tensor_ids_output);
                 }
                 Operator::ReadGlobalWithLayout {
                     variable: _,
@@ -317,6 +337,10 @@ where
                     mark(input, &mut local_tensor_ids_input);
                     mark(out, &mut local_tensor_ids_output);
                 }
+                Operator::Not { input, out } => {
+                    mark(input, &mut local_tensor_ids_input);
+                    mark(out, &mut local_tensor_ids_output);
+                }
             }
         }
 
@@ -378,7 +402,7 @@ where
         variable
     }
 
-    fn output_to_var(&mut self, tensor: &TensorDescription, elem: Elem) -> Variable {
+    pub(crate) fn output_to_var(&mut self, tensor: &TensorDescription, elem: Elem) -> Variable {
         // Update the tensor description to the new version.
         self.tensors
             .insert(tensor.id.clone(), (tensor.clone(), elem));
@@ -394,17 +418,84 @@ where
         Variable::Local(local_index, Item::Scalar(elem))
     }
 
-    fn register_base<E: WgpuElement>(&mut self, ops: &BaseOpsDescription) -> bool {
+    fn register_base(&mut self, ops: &BaseOpsDescription, elem: Elem) -> bool {
         match ops {
-            BaseOpsDescription::Equal(desc) => self.register_binary_ops(
-                desc,
-                (E::elem_type(), E::elem_type(), Elem::Bool),
-                |lhs, rhs, out| Operator::Equal { lhs, rhs, out },
-            ),
+            BaseOpsDescription::Equal(desc) => {
+                self.register_binary_ops(desc, (elem, elem, Elem::Bool), |lhs, rhs, out| {
+                    Operator::Equal { lhs, rhs, out }
+                })
+            }
+            BaseOpsDescription::Alias(desc) => self.register_alias(desc),
             _ => false,
         }
     }
 
+    fn register_alias(&mut self, desc: &AliasDescription) -> bool {
+        // The aliased tensors must be computed by the fused kernel to know their element type.
+        let mut elems = Vec::with_capacity(desc.inputs.len());
+        for input in desc.inputs.iter() {
+            match self.tensors.get(&input.id) {
+                Some((_, elem)) => elems.push(*elem),
+                None => return false,
+            }
+        }
+
+        for out in desc.outs.iter() {
+            if !self.output_is_compatible(out) {
+                return false;
+            }
+        }
+
+        for ((input, out), elem) in desc.inputs.iter().zip(desc.outs.iter()).zip(elems) {
+            let input = self.input_to_var(input, elem);
+            let out = self.output_to_var(out, elem);
+
+            self.operators.push(Operator::AssignLocal { input, out });
+        }
+
+        // The tensors released by the alias and read by the kernel are consumed instead of being
+        // written as outputs, the other ones are freed when the stream is drained.
+        for tensor in desc.released.iter() {
+            if let Some((_, elem)) = self.tensors.get(&tensor.id) {
+                let elem = *elem;
+                self.tensors
+                    .insert(tensor.id.clone(), (tensor.clone(), elem));
+            }
+        }
+
+        true
+    }
+
+    fn register_int(&mut self, ops: &IntOpsDescription) -> bool {
+        match ops {
+            IntOpsDescription::IntoFloat(desc) => {
+                self.register_unary_ops(desc, (I::elem_type(), F::elem_type()), |input, out| {
+                    Operator::AssignLocal { input, out }
+                })
+            }
+        }
+    }
+
+    fn register_bool(&mut self, ops: &BoolOpsDescription) -> bool {
+        match ops {
+            BoolOpsDescription::IntoFloat(desc) => {
+                self.register_unary_ops(desc, (Elem::Bool, F::elem_type()), |input, out| {
+                    Operator::AssignLocal { input, out }
+                })
+            }
+            BoolOpsDescription::IntoInt(desc) => {
+                self.register_unary_ops(desc, (Elem::Bool, I::elem_type()), |input, out| {
+                    Operator::AssignLocal { input, out }
+                })
+            }
+            BoolOpsDescription::Not(desc) => {
+                self.register_unary_ops(desc, (Elem::Bool, Elem::Bool), |input, out| {
+                    Operator::Not { input, out }
+                })
+            }
+        }
+    }
+
     fn register_float<E: WgpuElement>(&mut self, ops: &FloatOpsDescription) -> bool {
         match ops {
             FloatOpsDescription::Exp(desc) => {
@@ -452,6 +543,11 @@ where
                     Operator::Recip { input, out }
                 })
             }
+            FloatOpsDescription::IntoInt(desc) => {
+                self.register_unary_ops(desc, (E::elem_type(), I::elem_type()), |input, out| {
+                    Operator::AssignLocal { input, out }
+                })
+            }
             _ => false,
         }
     }
@@ -673,6 +769,9 @@ where
                 self.scalars_u32 += 1;
                 Variable::Scalar(self.scalars_u32 as u16 - 1, Item::Scalar(Elem::U32))
             }
+            Elem::F16 => {
+                panic!("F16 scalars not supported")
+            }
             Elem::Bool => {
                 panic!("Bool scalars not supported")
             }
@@ -8,7 +8,10 @@ use crate::{
     FloatElement, GraphicsApi, IntElement, Wgpu, WgpuDevice,
 };
 use burn_common::id::IdGenerator;
-use burn_fusion::{stream::Context, TensorDescription};
+use burn_fusion::{
+    stream::{plan_inplace, Context},
+    TensorDescription,
+};
 use burn_tensor::Device;
 use serde::{Deserialize, Serialize};
 
@@ -30,9 +33,9 @@ where
 
 #[derive(new, Clone, Serialize, Deserialize)]
 pub struct Scalars {
-    num_f32: usize,
-    num_u32: usize,
-    num_i32: usize,
+    pub(crate) num_f32: usize,
+    pub(crate) num_u32: usize,
+    pub(crate) num_i32: usize,
 }
 
 pub struct CompilationPhase;
@@ -99,49 +102,9 @@ where
             })
         }
 
-        let mut potential_inplace = self
-            .inputs
-            .iter()
-            .zip(inputs.iter())
-            .enumerate()
-            .filter(|(_pos, ((desc, _elem), _input))| match desc.status {
-                burn_fusion::TensorStatus::ReadOnly => false,
-                burn_fusion::TensorStatus::ReadWrite => true,
-                burn_fusion::TensorStatus::NotInit => false,
-            })
-            .map(|(pos, ((desc, elem), input))| (pos, desc, elem, input))
-            .collect::<Vec<_>>();
-
-        let mappings = self
-            .outputs
-            .iter()
-            .zip(outputs.iter())
-            .enumerate()
-            .filter_map(|(pos, ((desc, elem), _output))| {
-                if potential_inplace.is_empty() {
-                    return None;
-                }
-
-                let mut chosen = None;
-                for (index, (_pos_input, desc_input, elem_input, _input)) in
-                    potential_inplace.iter().enumerate()
-                {
-                    if chosen.is_some() {
-                        break;
-                    }
-                    if desc.shape == desc_input.shape && *elem_input == elem {
-                        chosen = Some(index);
-                    }
-                }
-
-                match chosen {
-                    Some(index) => {
-                        let input = potential_inplace.remove(index);
-                        Some(InplaceMapping::new(input.0, pos))
-                    }
-                    None => None,
-                }
-            })
+        let mappings = plan_inplace(&self.inputs, &self.outputs)
+            .into_iter()
+            .map(|candidate| InplaceMapping::new(candidate.input, candidate.output))
             .collect::<Vec<_>>();
 
         let scalar = ScalarElementWise::new(
@@ -283,10 +246,19 @@ where
 #[cfg(test)]
 mod tests {
     use super::*;
-    use burn_fusion::stream::Ops;
-    use burn_fusion::{Fusion, FusionBackend};
-    use burn_tensor::Int;
+    use burn_fusion::stream::{
+        replay, BinaryOpsDescription, FloatOpsDescription, NumericOpsDescription, Ops, StreamDump,
+        TensorOpsDescription, UnaryOpsDescription,
+    };
+    use burn_fusion::{
+        Fusion, FusionBackend, FusionConfig, FusionMetrics, FusionMode, HandleContainer,
+        OptimizationBuilder, OptimizationProperties, OptimizationStatus, TensorDescription,
+        TensorId, TensorStatus,
+    };
     use burn_tensor::{backend::Backend, Data, Tensor};
+    use burn_tensor::{Distribution, Int};
+    use core::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
 
     #[test]
     fn test_fusion_same_behavior() {
@@ -362,6 +334,497 @@ mod tests {
         result_ref.assert_approx_eq(&result_fused, 3);
     }
 
+    #[test]
+    fn test_fusion_same_behavior_int_bool() {
+        let data_1 = Tensor::<FusedBackend, 2>::random(
+            [32, 32],
+            burn_tensor::Distribution::Uniform(-10.0, 10.0),
+            &Default::default(),
+        )
+        .into_data()
+        .convert();
+        let data_2 = Tensor::<Backend, 2>::random(
+            [32, 32],
+            burn_tensor::Distribution::Uniform(-10.0, 10.0),
+            &Default::default(),
+        )
+        .into_data()
+        .convert();
+
+        fn func<B: burn_tensor::backend::Backend>(
+            data1: Data<i32, 2>,
+            data2: Data<i32, 2>,
+        ) -> Data<f32, 2> {
+            let x = Tensor::<B, 2, Int>::from_data(data1.convert(), &Default::default());
+            let y = Tensor::<B, 2, Int>::from_data(data2.convert(), &Default::default());
+
+            let mask = x.clone().lower(y.clone());
+            let mask_not = mask.clone().bool_not();
+            let index = x.mask_where(mask, y * 2) + 3;
+            let index = index.mask_fill(mask_not.clone(), 1);
+
+            let z = index.float() + mask_not.float();
+
+            z.into_data().convert()
+        }
+
+        type Backend = Wgpu;
+        type FusedBackend = Fusion<Wgpu>;
+
+        let result_fused = func::<FusedBackend>(data_1.clone(), data_2.clone());
+        let result_ref = func::<Backend>(data_1.clone(), data_2.clone());
+
+        result_ref.assert_approx_eq(&result_fused, 3);
+    }
+
+    #[test]
+    fn test_fusion_same_behavior_common_subexpressions() {
+        let data = Tensor::<Backend, 2>::random(
+            [32, 32],
+            burn_tensor::Distribution::Default,
+            &Default::default(),
+        )
+        .into_data();
+
+        fn func<B: burn_tensor::backend::Backend>(data: Data<f32, 2>) -> Data<f32, 2> {
+            let x = Tensor::<B, 2>::from_data(data.convert(), &Default::default());
+
+            // The same values are computed three times while the first result is still alive.
+            let a = x.clone().exp();
+            let b = x.clone().exp();
+            let c = x.exp().add_scalar(3.0);
+
+            (a * b + c).into_data().convert()
+        }
+
+        type Backend = Wgpu;
+        type FusedBackend = Fusion<Wgpu>;
+
+        let result_fused = func::<FusedBackend>(data.clone());
+        let result_ref = func::<Backend>(data.clone());
+
+        result_ref.assert_approx_eq(&result_fused, 3);
+    }
+
+    #[test]
+    fn test_fusion_same_behavior_dropped_tensors() {
+        let data = Tensor::<Backend, 2>::random(
+            [32, 32],
+            burn_tensor::Distribution::Default,
+            &Default::default(),
+        )
+        .into_data();
+
+        fn func<B: burn_tensor::backend::Backend>(data: Data<f32, 2>) -> Data<f32, 2> {
+            let x = Tensor::<B, 2>::from_data(data.convert(), &Default::default());
+
+            // Dropped before the device is synchronized, without being read.
+            let unused = x.clone().matmul(x.clone()).exp();
+            core::mem::drop(unused);
+
+            let y = x.clone().matmul(x.clone());
+            let unused = y.clone().log();
+            core::mem::drop(unused);
+
+            (y + x).into_data().convert()
+        }
+
+        type Backend = Wgpu;
+        type FusedBackend = Fusion<Wgpu>;
+
+        let result_fused = func::<FusedBackend>(data.clone());
+        let result_ref = func::<Backend>(data.clone());
+
+        result_ref.assert_approx_eq(&result_fused, 3);
+    }
+
+    #[test]
+    fn test_fusion_same_behavior_constant_folding() {
+        let data = Tensor::<Backend, 2>::random(
+            [32, 32],
+            burn_tensor::Distribution::Default,
+            &Default::default(),
+        )
+        .into_data();
+
+        fn func<B: burn_tensor::backend::Backend>(data: Data<f32, 2>) -> Data<f32, 2> {
+            let device = Default::default();
+            let x = Tensor::<B, 2>::from_data(data.convert(), &device);
+
+            // Only computed from constants, so folded at registration time with fusion.
+            let mask = Tensor::<B, 2>::ones([1, 32], &device).mul_scalar(-1.0e4);
+            let mask = mask + Tensor::<B, 2>::full([32, 1], 2.0, &device).div_scalar(4.0);
+            let offset = Tensor::<B, 2, Int>::ones([32, 32], &device).add_scalar(2) * 3;
+
+            (x + mask + offset.float()).into_data().convert()
+        }
+
+        type Backend = Wgpu;
+        type FusedBackend = Fusion<Wgpu>;
+
+        let result_fused = func::<FusedBackend>(data.clone());
+        let result_ref = func::<Backend>(data.clone());
+
+        result_ref.assert_approx_eq(&result_fused, 3);
+    }
+
+    #[test]
+    fn test_replay_dumped_stream() {
+        let device = WgpuDevice::default();
+        let x = Tensor::<Wgpu, 2>::random([32, 32], Distribution::Default, &device);
+        let tensor = |id, status| TensorDescription {
+            id: TensorId::new(id),
+            shape: vec![32, 32],
+            status,
+        };
+
+        // exp(x) + x
+        let stream = StreamDump {
+            operations: vec![
+                TensorOpsDescription::FloatOps(FloatOpsDescription::Exp(UnaryOpsDescription {
+                    input: tensor(0, TensorStatus::ReadOnly),
+                    out: tensor(1, TensorStatus::NotInit),
+                })),
+                TensorOpsDescription::NumericOpsFloat(NumericOpsDescription::Add(
+                    BinaryOpsDescription {
+                        lhs: tensor(1, TensorStatus::ReadWrite),
+                        rhs: tensor(0, TensorStatus::ReadWrite),
+                        out: tensor(2, TensorStatus::NotInit),
+                    },
+                )),
+            ],
+            fused: true,
+        };
+
+        let mut handles = HandleContainer::<Wgpu>::new(device.clone());
+        for input in stream.inputs() {
+            handles.register_float_tensor::<2>(&input.id, x.clone().into_primitive());
+        }
+        replay(&stream, &device, &mut handles).unwrap();
+
+        let output = handles.get_float_tensor::<2>(&tensor(2, TensorStatus::ReadWrite));
+        let output = Tensor::<Wgpu, 2>::from_primitive(output);
+
+        (x.clone().exp() + x)
+            .into_data()
+            .assert_approx_eq(&output.into_data(), 3);
+    }
+
+    #[test]
+    fn test_fusion_same_behavior_read_after_write_across_streams() {
+        let data_1 = Tensor::<Backend, 2>::random(
+            [32, 32],
+            burn_tensor::Distribution::Default,
+            &Default::default(),
+        )
+        .into_data();
+        let data_2 = Tensor::<Backend, 2>::random(
+            [32, 32],
+            burn_tensor::Distribution::Default,
+            &Default::default(),
+        )
+        .into_data();
+
+        fn func<B: burn_tensor::backend::Backend>(
+            data1: Data<f32, 2>,
+            data2: Data<f32, 2>,
+        ) -> Data<f32, 2> {
+            let x = Tensor::<B, 2>::from_data(data1.convert(), &Default::default());
+            let y = Tensor::<B, 2>::from_data(data2.convert(), &Default::default());
+
+            // Independent chains, registered on different streams.
+            let a = x.exp().mul_scalar(2.0);
+            let b = y.clone().sin().add_scalar(1.0);
+
+            // Reads tensors written by both streams, one of them being mutated in place.
+            let c = a.clone() * b;
+            let d = a + y;
+
+            (c - d).into_data().convert()
+        }
+
+        type Backend = Wgpu;
+        type FusedBackend = Fusion<Wgpu>;
+
+        let result_fused = func::<FusedBackend>(data_1.clone(), data_2.clone());
+        let result_ref = func::<Backend>(data_1.clone(), data_2.clone());
+
+        result_ref.assert_approx_eq(&result_fused, 3);
+    }
+
+    #[test]
+    fn test_fusion_same_behavior_tensor_shared_across_streams() {
+        let data = Tensor::<Backend, 2>::random(
+            [32, 32],
+            burn_tensor::Distribution::Default,
+            &Default::default(),
+        )
+        .into_data();
+
+        fn func<B: burn_tensor::backend::Backend>(data: Data<f32, 2>) -> Data<f32, 2> {
+            let x = Tensor::<B, 2>::from_data(data.convert(), &Default::default());
+            let z = Tensor::<B, 2>::ones([32, 32], &Default::default());
+
+            // The second chain reads `x` while the first one is still pending.
+            let a = z.add_scalar(3.0).log();
+            let b = x.clone().cos();
+            let c = a * x.clone();
+            let d = x.matmul(b.clone()) + b;
+
+            (c + d).into_data().convert()
+        }
+
+        type Backend = Wgpu;
+        type FusedBackend = Fusion<Wgpu>;
+
+        let result_fused = func::<FusedBackend>(data.clone());
+        let result_ref = func::<Backend>(data.clone());
+
+        result_ref.assert_approx_eq(&result_fused, 3);
+    }
+
+    #[test]
+    fn test_fusion_same_behavior_to_device_pipelining() {
+        type FusedBackend = Fusion<Wgpu>;
+
+        let _lock = OTHER_DEVICE_LOCK.lock();
+        let source = other_device();
+        let target = WgpuDevice::default();
+        let data_1 =
+            Tensor::<Wgpu, 2>::random([32, 32], Distribution::Default, &target).into_data();
+        let data_2 =
+            Tensor::<Wgpu, 2>::random([32, 32], Distribution::Default, &target).into_data();
+
+        let x_1 = Tensor::<FusedBackend, 2>::from_data(data_1.clone(), &source);
+        let x_2 = Tensor::<FusedBackend, 2>::from_data(data_2.clone(), &source);
+
+        // Independent chains of operations on the source device.
+        let a = x_1.exp();
+        let b = x_2.mul_scalar(2.0).log();
+
+        let before = Fusion::<Wgpu>::metrics(&source);
+        let b = b.to_device(&target);
+        let after = Fusion::<Wgpu>::metrics(&source);
+
+        // Only the chain computing the transferred tensor is executed, the other one stays lazy.
+        let num_executed =
+            |metrics: FusionMetrics| metrics.fused_operations + metrics.unfused_operations;
+        assert_eq!(num_executed(after) - num_executed(before), 2);
+
+        // The transfer back to the source device is pending while the source chain continues.
+        let c = b.add_scalar(1.0).to_device(&source);
+        let result_fused = (a.cos() + c).into_data();
+
+        let x_1 = Tensor::<Wgpu, 2>::from_data(data_1, &target);
+        let x_2 = Tensor::<Wgpu, 2>::from_data(data_2, &target);
+        let result_ref = (x_1.exp().cos() + x_2.mul_scalar(2.0).log().add_scalar(1.0)).into_data();
+
+        result_ref.assert_approx_eq(&result_fused, 3);
+
+        // Threads moving tensors between the devices in opposite directions don't deadlock.
+        let device_1 = target;
+        let device_2 = source;
+        let data = Tensor::<Wgpu, 2>::random([8, 32], Distribution::Default, &device_1).into_data();
+
+        let threads = [(device_1.clone(), device_2.clone()), (device_2, device_1)]
+            .into_iter()
+            .map(|(source, target)| {
+                let data = data.clone();
+
+                std::thread::spawn(move || {
+                    (0..8)
+                        .map(|_| {
+                            let x = Tensor::<FusedBackend, 2>::from_data(data.clone(), &source);
+                            let y = x.exp().to_device(&target).add_scalar(1.0);
+
+                            y.to_device(&source).log().into_data()
+                        })
+                        .collect::<Vec<_>>()
+                })
+            })
+            .collect::<Vec<_>>();
+
+        let x = Tensor::<Wgpu, 2>::from_data(data, &Default::default());
+        let result_ref = x.exp().add_scalar(1.0).log().into_data();
+
+        for thread in threads {
+            for result in thread.join().unwrap() {
+                result_ref.assert_approx_eq(&result, 3);
+            }
+        }
+    }
+
+    #[test]
+    fn test_fusion_same_behavior_eager_mode() {
+        type FusedBackend = Fusion<Wgpu>;
+
+        let _lock = OTHER_DEVICE_LOCK.lock();
+        let device = other_device();
+        let data = Tensor::<Wgpu, 2>::random([8, 32], Distribution::Default, &device).into_data();
+
+        Fusion::<Wgpu>::set_execution_mode(&device, FusionMode::Eager);
+        let before = Fusion::<Wgpu>::metrics(&device);
+
+        let x = Tensor::<FusedBackend, 2>::from_data(data.clone(), &device);
+        let y = x.exp().add_scalar(1.0).log();
+
+        // Each operation is executed as soon as it is registered, before reading the result.
+        let after = Fusion::<Wgpu>::metrics(&device);
+        let result_fused = y.into_data();
+
+        Fusion::<Wgpu>::set_execution_mode(&device, FusionMode::Lazy);
+
+        assert_eq!(after.fused_operations, before.fused_operations);
+        assert_eq!(after.unfused_operations - before.unfused_operations, 3);
+
+        let x = Tensor::<Wgpu, 2>::from_data(data, &device);
+        let result_ref = x.exp().add_scalar(1.0).log().into_data();
+
+        result_ref.assert_approx_eq(&result_fused, 3);
+    }
+
+    #[test]
+    fn test_fusion_frees_tensors_released_by_common_subexpressions() {
+        type FusedBackend = Fusion<Wgpu>;
+
+        let _lock = OTHER_DEVICE_LOCK.lock();
+        let device = other_device();
+        let data = Tensor::<Wgpu, 2>::random([8, 32], Distribution::Default, &device).into_data();
+
+        FusedBackend::sync(&device);
+        let before = Fusion::<Wgpu>::metrics(&device);
+
+        let x = Tensor::<FusedBackend, 2>::from_data(data, &device);
+        let a = x.clone().sum_dim(1);
+        // Same values as `a`, the input is used for the last time by the duplicated operation.
+        let b = x.sum_dim(1);
+        let _result = (a + b).into_data();
+
+        FusedBackend::sync(&device);
+        let after = Fusion::<Wgpu>::metrics(&device);
+
+        assert_eq!(after.handles, before.handles);
+    }
+
+    #[test]
+    fn test_fusion_frees_inputs_of_dead_operations() {
+        type FusedBackend = Fusion<Wgpu>;
+
+        let _lock = OTHER_DEVICE_LOCK.lock();
+        let device = other_device();
+        let data = Tensor::<Wgpu, 2>::random([8, 32], Distribution::Default, &device).into_data();
+
+        FusedBackend::sync(&device);
+        let before = Fusion::<Wgpu>::metrics(&device);
+
+        // The reduction isn't fused, so it's skipped since its output is dropped without being
+        // read, while its input is used for the last time.
+        let x = Tensor::<FusedBackend, 2>::from_data(data, &device);
+        core::mem::drop(x.sum_dim(1));
+
+        FusedBackend::sync(&device);
+        let after = Fusion::<Wgpu>::metrics(&device);
+
+        assert_eq!(after.handles, before.handles);
+    }
+
+    // Tests using the other device are executed one at a time, since they check its metrics.
+    static OTHER_DEVICE_LOCK: spin::Mutex<()> = spin::Mutex::new(());
+
+    /// A device with its own fusion server, using an adapter of the same type as the default
+    /// device so it is always available.
+    fn other_device() -> WgpuDevice {
+        let (_, _, info) = pollster::block_on(crate::compute::select_device::<
+            crate::AutoGraphicsApi,
+        >(&WgpuDevice::BestAvailable));
+
+        match info.device_type {
+            wgpu::DeviceType::DiscreteGpu | wgpu::DeviceType::Other => WgpuDevice::DiscreteGpu(0),
+            wgpu::DeviceType::IntegratedGpu => WgpuDevice::IntegratedGpu(0),
+            wgpu::DeviceType::VirtualGpu => WgpuDevice::VirtualGpu(0),
+            wgpu::DeviceType::Cpu => WgpuDevice::Cpu,
+        }
+    }
+
+    #[test]
+    fn test_fusion_same_behavior_concurrent_registrations() {
+        type FusedBackend = Fusion<Wgpu>;
+
+        let data_1 = Tensor::<Wgpu, 2>::random([8, 32], Distribution::Default, &Default::default())
+            .into_data();
+        let data_2 =
+            Tensor::<Wgpu, 2>::random([32, 32], Distribution::Default, &Default::default())
+                .into_data();
+
+        let result_ref = execute::<Wgpu>(
+            data_1.clone(),
+            data_2.clone(),
+            ImplementationDetails::Variant1,
+        );
+
+        // Each thread registers operations on the same device, exploring and executing
+        // optimizations while the other threads do the same.
+        let threads = (0..8)
+            .map(|_| {
+                let (data_1, data_2) = (data_1.clone(), data_2.clone());
+
+                std::thread::spawn(move || {
+                    (0..16)
+                        .map(|i| {
+                            let variant = match i % 2 {
+                                0 => ImplementationDetails::Variant1,
+                                _ => ImplementationDetails::Variant2,
+                            };
+                            execute::<FusedBackend>(data_1.clone(), data_2.clone(), variant)
+                        })
+                        .collect::<Vec<_>>()
+                })
+            })
+            .collect::<Vec<_>>();
+
+        for thread in threads {
+            for result in thread.join().unwrap() {
+                result_ref.assert_approx_eq(&result, 3);
+            }
+        }
+    }
+
+    #[test]
+    fn test_fusion_same_behavior_pooled_handles() {
+        type FusedBackend = Fusion<Wgpu>;
+
+        Fusion::<Wgpu>::configure(
+            &Default::default(),
+            FusionConfig {
+                max_pooled_handles: 16,
+                ..Default::default()
+            },
+        );
+
+        let data_1 = Tensor::<Wgpu, 2>::random([1, 32], Distribution::Default, &Default::default())
+            .into_data();
+        let data_2 =
+            Tensor::<Wgpu, 2>::random([32, 32], Distribution::Default, &Default::default())
+                .into_data();
+
+        let result_ref = execute::<Wgpu>(
+            data_1.clone(),
+            data_2.clone(),
+            ImplementationDetails::Variant1,
+        );
+
+        // The handles freed by the first executions are reused by the next ones.
+        for _ in 0..4 {
+            let result_fused = execute::<FusedBackend>(
+                data_1.clone(),
+                data_2.clone(),
+                ImplementationDetails::Variant1,
+            );
+
+            result_ref.assert_approx_eq(&result_fused, 3);
+        }
+    }
+
     #[test]
     fn test_fusion_same_behavior_different_variant() {
         type Backend = Wgpu;
@@ -417,6 +880,127 @@ mod tests {
         Backend::sync(&output.device());
     }
 
+    #[test]
+    fn test_save_and_load_optimizations() {
+        type Backend = Fusion<Wgpu>;
+        let device = Default::default();
+        let path = std::env::temp_dir().join("burn-wgpu-fusion-optimizations.json");
+
+        let data = Tensor::<Backend, 2>::ones([32, 32], &device).into_data();
+        execute::<Backend>(data.clone(), data, ImplementationDetails::Variant1);
+
+        Backend::save_optimizations(&device, &path).unwrap();
+        let content = std::fs::read_to_string(&path).unwrap();
+        assert!(content.contains("ElementWise"));
+
+        // The optimizations found by this process are already in the store.
+        assert_eq!(Backend::load_optimizations(&device, &path).unwrap(), 0);
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn test_metrics_count_fused_operations() {
+        type Backend = Fusion<Wgpu>;
+        let device = Default::default();
+        let data = Tensor::<Backend, 2>::ones([32, 32], &device).into_data();
+
+        let before = Backend::metrics(&device);
+        execute::<Backend>(data.clone(), data, ImplementationDetails::Variant1);
+        let after = Backend::metrics(&device);
+
+        // Other tests can use the same device concurrently, so only the increase is checked.
+        assert!(after.fused_operations > before.fused_operations);
+        assert!(after.executions > before.executions);
+        assert!(after.optimization_hits + after.optimization_misses > 0);
+    }
+
+    #[test]
+    fn test_drain_async_executes_lazy_operations() {
+        type Backend = Fusion<Wgpu>;
+        let device = Default::default();
+        let tensor = Tensor::<Backend, 2>::ones([32, 32], &device);
+        let before = Backend::metrics(&device);
+
+        // Lazy operations, only executed when the device is drained.
+        let _output = (tensor.clone() + tensor).exp();
+        block_on(Backend::drain_async(&device));
+
+        // Other tests can use the same device concurrently, so only the increase is checked.
+        assert!(Backend::metrics(&device).executions > before.executions);
+    }
+
+    /// Poll the future on the current thread until it's ready.
+    fn block_on<F: core::future::Future>(future: F) -> F::Output {
+        struct ThreadWaker(std::thread::Thread);
+
+        impl std::task::Wake for ThreadWaker {
+            fn wake(self: Arc<Self>) {
+                self.0.unpark();
+            }
+        }
+
+        let waker = Arc::new(ThreadWaker(std::thread::current())).into();
+        let mut context = core::task::Context::from_waker(&waker);
+        let mut future = core::pin::pin!(future);
+
+        loop {
+            match future.as_mut().poll(&mut context) {
+                core::task::Poll::Ready(output) => return output,
+                core::task::Poll::Pending => std::thread::park(),
+            }
+        }
+    }
+
+    #[test]
+    fn test_registered_optimization_builders_see_operations() {
+        type Backend = Fusion<Wgpu>;
+        let device = Default::default();
+        let num_registered = Arc::new(AtomicUsize::new(0));
+        let counter = num_registered.clone();
+
+        Backend::register_optimization(
+            &device,
+            Box::new(move |_: &WgpuDevice| {
+                Box::new(CountingBuilder {
+                    num_registered: counter.clone(),
+                }) as Box<dyn OptimizationBuilder<Wgpu>>
+            }),
+        );
+
+        let data = Tensor::<Backend, 2>::ones([32, 32], &device).into_data();
+        execute::<Backend>(data.clone(), data, ImplementationDetails::Variant1);
+
+        assert!(num_registered.load(Ordering::Relaxed) > 0);
+    }
+
+    /// Builder never ready, so it doesn't change the optimizations of the other tests.
+    struct CountingBuilder {
+        num_registered: Arc<AtomicUsize>,
+    }
+
+    impl OptimizationBuilder<Wgpu> for CountingBuilder {
+        fn register(&mut self, _ops: &TensorOpsDescription) {
+            self.num_registered.fetch_add(1, Ordering::Relaxed);
+        }
+
+        fn build(&self) -> <Wgpu as FusionBackend>::Optimization {
+            unreachable!("The builder is never ready")
+        }
+
+        fn reset(&mut self) {}
+
+        fn status(&self) -> OptimizationStatus {
+            OptimizationStatus::Closed
+        }
+
+        fn properties(&self) -> OptimizationProperties {
+            OptimizationProperties {
+                score: 0,
+                ready: false,
+            }
+        }
+    }
+
     struct FakeAddOps;
 
     impl<B: FusionBackend> Ops<B> for FakeAddOps {
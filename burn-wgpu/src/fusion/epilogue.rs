@@ -0,0 +1,103 @@
+use crate::{
+    codegen::{Elem, Function, Item, Operator, Variable},
+    fusion::Scalars,
+};
+
+/// Generate the bindings and the `epilogue` function of a kernel applying element wise operations
+/// on each value it computes before writing it to its output.
+///
+/// The epilogue inputs are bound starting at the given binding, followed by the scalars. Their
+/// layout is registered in the info buffer right after the one of the output, found at the given
+/// position, which is used to compute their index with broadcasting.
+#[allow(clippy::too_many_arguments)]
+pub fn epilogue_source(
+    inputs: &[Elem],
+    scalars: &Scalars,
+    operators: &[Operator],
+    value_local: u16,
+    output_local: u16,
+    elem: Elem,
+    first_binding: u32,
+    output_info_pos: usize,
+) -> String {
+    let mut source = String::new();
+    let mut binding = first_binding;
+
+    for (index, input) in inputs.iter().enumerate() {
+        let input = match input {
+            // U32 are used for bool tensors
+            Elem::Bool => Elem::U32,
+            _ => *input,
+        };
+        source.push_str(&format!(
+            "@group(0)
+@binding({binding})
+var<storage, read> input_{index}_global: array<{input}>;
+
+"
+        ));
+        binding += 1;
+    }
+
+    for (scalar, size) in [(Elem::F32, scalars.num_f32), (Elem::I32, scalars.num_i32)] {
+        if size > 0 {
+            source.push_str(&format!(
+                "@group(0)
+@binding({binding})
+var<storage, read> scalars_{scalar}: array<{scalar}, {size}>;
+
+"
+            ));
+            binding += 1;
+        }
+    }
+
+    source.push_str(&format!(
+        "fn epilogue(id: u32, value: {elem}) -> {elem} {{
+let rank: u32 = info[0];
+let local_{value_local} = value;
+"
+    ));
+
+    for (index, input) in inputs.iter().enumerate() {
+        let read = Operator::ReadGlobalWithLayout {
+            variable: Variable::Input(index as u16, Item::Scalar(*input)),
+            tensor_read_pos: output_info_pos + 1 + index,
+            tensor_layout_pos: output_info_pos,
+        };
+        source.push_str(&format!("{read}\n"));
+    }
+
+    let mut functions = Vec::new();
+    for ops in operators.iter() {
+        let function = match ops {
+            Operator::Powf {
+                lhs: _,
+                rhs: _,
+                out: _,
+            } => Some(Function::Powf(Item::Scalar(Elem::F32))),
+            Operator::Erf { input: _, out: _ } => Some(Function::Erf(Item::Scalar(Elem::F32))),
+            #[cfg(target_os = "macos")]
+            Operator::Tanh { input: _, out: _ } => {
+                Some(Function::SafeTanh(Item::Scalar(Elem::F32)))
+            }
+            _ => None,
+        };
+
+        if let Some(function) = function {
+            if !functions.contains(&function) {
+                functions.push(function);
+            }
+        }
+
+        source.push_str(&format!("{ops}\n"));
+    }
+
+    source.push_str(&format!("return local_{output_local};\n}}\n\n"));
+
+    for function in functions.iter() {
+        source.push_str(&format!("{function}\n\n"));
+    }
+
+    source
+}
@@ -1,9 +1,10 @@
+use crate::compute::WgpuComputeClient;
 use crate::compute::{compute_client, Kernel};
 use crate::fusion::strides_dyn_rank;
 use crate::fusion::WgpuFusionHandle;
-use crate::{FloatElement, GraphicsApi, IntElement, Wgpu};
+use crate::{FloatElement, GraphicsApi, IntElement, Wgpu, WgpuDevice};
 use burn_fusion::stream::Context;
-use burn_fusion::TensorDescription;
+use burn_fusion::{TensorDescription, TensorStatus};
 use burn_tensor::Device;
 
 /// Many kernels can be used for the same set of tensor operations fused into one.
@@ -78,18 +79,31 @@ impl FusionKernelSet {
             Vec::with_capacity((inputs.len() + outputs.len()) * inputs[0].shape.len() * 2);
         let mut handles = Vec::with_capacity(inputs.len() + outputs.len() + 2);
         let mut output_register = Vec::with_capacity(outputs_description_updated.len());
+        // The inputs read for the last time, which can be recycled once the kernel is launched.
+        let mut recyclable = Vec::new();
 
         // We register the info and handles for the inputs.
-        for (handle, tensor) in handles_input.into_iter().zip(inputs_description_updated) {
+        for ((handle, tensor), input) in handles_input
+            .into_iter()
+            .zip(inputs_description_updated)
+            .zip(inputs)
+        {
             register_info_tensor(&mut info, tensor, &handle);
+
+            if input.status == TensorStatus::ReadWrite {
+                recyclable.push((tensor.shape.clone(), handle.clone()));
+            }
             handles.push(handle.handle);
         }
 
-        // We register the info and handles for the outputs.
-        for (tensor, output_info) in outputs_description_updated
+        // The outputs are cloned, since handles are taken from the context while registering them.
+        let outputs_description_updated = outputs_description_updated
             .into_iter()
-            .zip(selected.info.iter())
-        {
+            .cloned()
+            .collect::<Vec<_>>();
+
+        // We register the info and handles for the outputs.
+        for (tensor, output_info) in outputs_description_updated.iter().zip(selected.info.iter()) {
             match output_info {
                 // Use the input inplace for this output.
                 OutputInfo::Inplace { input_index } => {
@@ -104,12 +118,8 @@ impl FusionKernelSet {
                 }
                 // Create a new buffer for this output.
                 OutputInfo::Array { size } => {
-                    let handle_fusion = WgpuFusionHandle {
-                        client: client.clone(),
-                        device: device.clone(),
-                        strides: strides_dyn_rank(&tensor.shape),
-                        handle: client.empty(*size),
-                    };
+                    let handle_fusion =
+                        output_handle::<G, F, I>(&tensor.shape, *size, context, &client, &device);
 
                     register_info_tensor(&mut info, tensor, &handle_fusion);
                     handles.push(handle_fusion.handle.clone());
@@ -138,6 +148,13 @@ impl FusionKernelSet {
 
         // Execute the kernel.
         client.execute(selected.kernel, &handles.iter().collect::<Vec<_>>());
+
+        // Kernels are executed in order, so the next ones can write to the recycled inputs. The
+        // inputs used inplace are still referenced by an output and won't be pooled.
+        drop(handles);
+        for (shape, handle) in recyclable {
+            context.handles.recycle(&shape, handle);
+        }
     }
 
     fn select_kernel(
@@ -166,6 +183,30 @@ impl FusionKernelSet {
     }
 }
 
+/// Create the handle of an output, reusing a pooled handle of the same shape when available.
+pub(crate) fn output_handle<G: GraphicsApi, F: FloatElement, I: IntElement>(
+    shape: &[usize],
+    size: usize,
+    context: &mut Context<'_, Wgpu<G, F, I>>,
+    client: &WgpuComputeClient,
+    device: &WgpuDevice,
+) -> WgpuFusionHandle {
+    let handle = match context
+        .handles
+        .take_pooled(shape, core::mem::size_of::<F>())
+    {
+        Some(pooled) => pooled.handle,
+        None => client.empty(size),
+    };
+
+    WgpuFusionHandle {
+        client: client.clone(),
+        device: device.clone(),
+        strides: strides_dyn_rank(shape),
+        handle,
+    }
+}
+
 fn register_info_tensor(
     info: &mut Vec<u32>,
     tensor: &TensorDescription,
@@ -0,0 +1,139 @@
+use super::MatmulEpilogue;
+use crate::{
+    codegen::Elem,
+    element::WgpuElement,
+    fusion::{ElementWiseBuilder, Scalars, WgpuOptimization},
+    FloatElement, GraphicsApi, IntElement, Wgpu,
+};
+use burn_fusion::{
+    stream::{BinaryOpsDescription, FloatOpsDescription, TensorOpsDescription},
+    OptimizationBuilder, OptimizationProperties, OptimizationStatus, TensorDescription,
+    TensorStatus,
+};
+use burn_tensor::Device;
+
+/// Fuse a matmul with the element wise operations applied on its output, such as adding a bias
+/// followed by an activation function.
+///
+/// The element wise operations are registered with an [element wise builder](ElementWiseBuilder)
+/// where the output of the matmul is the first local variable.
+pub(crate) struct MatmulEpilogueBuilder<G, F, I>
+where
+    G: GraphicsApi,
+    F: FloatElement,
+    I: IntElement,
+{
+    matmul: Option<BinaryOpsDescription>,
+    epilogue: ElementWiseBuilder<G, F, I>,
+    status: OptimizationStatus,
+    device: Device<Wgpu<G, F, I>>,
+}
+
+impl<G, F, I> OptimizationBuilder<Wgpu<G, F, I>> for MatmulEpilogueBuilder<G, F, I>
+where
+    G: GraphicsApi,
+    F: FloatElement,
+    I: IntElement,
+{
+    fn register(&mut self, ops: &TensorOpsDescription) {
+        if let OptimizationStatus::Closed = self.status {
+            return;
+        }
+
+        if self.matmul.is_some() {
+            self.epilogue.register(ops);
+            self.status = self.epilogue.status();
+            return;
+        }
+
+        match ops {
+            TensorOpsDescription::FloatOps(FloatOpsDescription::Matmul(desc)) => {
+                // The epilogue can only write values with the same shape as the matmul output.
+                self.epilogue.current_output_shape = desc.out.shape.clone();
+                self.epilogue.output_to_var(&desc.out, F::elem_type());
+                self.matmul = Some(desc.clone());
+            }
+            _ => self.status = OptimizationStatus::Closed,
+        }
+    }
+
+    fn build(&self) -> WgpuOptimization<G, F, I> {
+        let matmul = self.matmul.as_ref().unwrap();
+        let output = self.epilogue_output().unwrap();
+
+        let op = MatmulEpilogue::new(
+            matmul.lhs.clone(),
+            matmul.rhs.clone(),
+            self.epilogue.input_descriptions(),
+            output.clone(),
+            *self.epilogue.locals.get(&matmul.out.id).unwrap(),
+            *self.epilogue.locals.get(&output.0.id).unwrap(),
+            Scalars::new(
+                self.epilogue.scalars_f32,
+                self.epilogue.scalars_u32,
+                self.epilogue.scalars_i32,
+            ),
+            self.epilogue.operators.clone(),
+            self.device.clone(),
+        );
+
+        WgpuOptimization::MatmulEpilogue(op)
+    }
+
+    fn reset(&mut self) {
+        self.matmul = None;
+        self.epilogue.reset();
+        self.status = OptimizationStatus::Open;
+    }
+
+    fn status(&self) -> OptimizationStatus {
+        self.status
+    }
+
+    fn properties(&self) -> OptimizationProperties {
+        OptimizationProperties {
+            ready: self.epilogue_output().is_some(),
+            score: self.epilogue.operators.len() as u64 + 1,
+        }
+    }
+}
+
+impl<G, F, I> MatmulEpilogueBuilder<G, F, I>
+where
+    G: GraphicsApi,
+    F: FloatElement,
+    I: IntElement,
+{
+    pub fn new(device: Device<Wgpu<G, F, I>>) -> Self {
+        Self {
+            matmul: None,
+            epilogue: ElementWiseBuilder::new(device.clone()),
+            status: OptimizationStatus::Open,
+            device,
+        }
+    }
+
+    /// The tensor written by the fused kernel, if the registered operations can be fused.
+    ///
+    /// The result of the matmul is never written, so it must be consumed by the epilogue which
+    /// must have a single float output.
+    fn epilogue_output(&self) -> Option<(TensorDescription, Elem)> {
+        let matmul = self.matmul.as_ref()?;
+
+        if self.epilogue.operators.is_empty() {
+            return None;
+        }
+
+        match self.epilogue.tensors.get(&matmul.out.id) {
+            Some((tensor, _)) if tensor.status == TensorStatus::ReadWrite => {}
+            _ => return None,
+        }
+
+        let mut outputs = self.epilogue.output_descriptions();
+        if outputs.len() != 1 || outputs[0].1 != F::elem_type() {
+            return None;
+        }
+
+        outputs.pop()
+    }
+}
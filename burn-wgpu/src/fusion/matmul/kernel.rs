@@ -0,0 +1,43 @@
+use crate::{
+    codegen::Elem,
+    kernel::{
+        matmul::{B_K, B_M, B_N, WORKGROUP_SIZE},
+        DynamicKernelSource, SourceTemplate, StaticKernelSource,
+    },
+    kernel_wgsl,
+};
+use std::sync::Arc;
+
+kernel_wgsl!(
+    MatmulEpilogueRaw,
+    "../../template/matmul/blocktiling_2d/unpadded_epilogue.wgsl"
+);
+
+/// Tiling 2d matmul calling a generated `epilogue` function on each value of the output.
+#[derive(new)]
+pub struct MatmulEpilogueKernel {
+    id: String,
+    elem: Elem,
+    epilogue: String,
+}
+
+impl DynamicKernelSource for Arc<MatmulEpilogueKernel> {
+    fn source(&self) -> SourceTemplate {
+        MatmulEpilogueRaw::source()
+            .register("b_m", B_M.to_string())
+            .register("b_n", B_N.to_string())
+            .register("b_k", B_K.to_string())
+            .register("bm_x_bk_4", (B_M * B_K / 4).to_string())
+            .register("bk_x_bn_4", (B_K * B_N / 4).to_string())
+            .register("workgroup_size_x", WORKGROUP_SIZE.to_string())
+            .register("workgroup_size_y", WORKGROUP_SIZE.to_string())
+            .register("workgroup_size_z", "1".to_string())
+            .register("elem", self.elem.to_string())
+            .register("int", "i32")
+            .add_template(self.epilogue.clone())
+    }
+
+    fn id(&self) -> String {
+        self.id.clone()
+    }
+}
@@ -0,0 +1,6 @@
+mod builder;
+mod kernel;
+mod optimization;
+
+pub(crate) use builder::*;
+pub(crate) use optimization::*;
@@ -0,0 +1,312 @@
+use super::kernel::MatmulEpilogueKernel;
+use crate::{
+    codegen::{Elem, Operator},
+    compute::{compute_client, DynamicKernel, WorkGroup},
+    fusion::{
+        epilogue::epilogue_source, kernel::output_handle, strides_dyn_rank, Scalars,
+        WgpuFusionHandle,
+    },
+    kernel::{
+        into_contiguous_dyn,
+        matmul::{B_M, B_N},
+    },
+    FloatElement, GraphicsApi, IntElement, Wgpu, WgpuDevice,
+};
+use burn_common::id::IdGenerator;
+use burn_fusion::{stream::Context, TensorDescription};
+use burn_tensor::Device;
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+
+/// Matrix multiplication followed by element wise operations applied on its output, executed in
+/// one kernel without writing the result of the matmul to memory.
+pub struct MatmulEpilogue<G, F, I>
+where
+    G: GraphicsApi,
+    F: FloatElement,
+    I: IntElement,
+{
+    lhs: TensorDescription,
+    rhs: TensorDescription,
+    inputs: Vec<(TensorDescription, Elem)>,
+    output: (TensorDescription, Elem),
+    matmul_local: u16,
+    output_local: u16,
+    scalars: Scalars,
+    operators: Vec<Operator>,
+    device: Device<Wgpu<G, F, I>>,
+    kernel: Arc<MatmulEpilogueKernel>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct MatmulEpilogueState {
+    lhs: TensorDescription,
+    rhs: TensorDescription,
+    inputs: Vec<(TensorDescription, Elem)>,
+    output: (TensorDescription, Elem),
+    matmul_local: u16,
+    output_local: u16,
+    scalars: Scalars,
+    operators: Vec<Operator>,
+}
+
+impl<G, F, I> MatmulEpilogue<G, F, I>
+where
+    G: GraphicsApi,
+    F: FloatElement,
+    I: IntElement,
+{
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn new(
+        lhs: TensorDescription,
+        rhs: TensorDescription,
+        inputs: Vec<(TensorDescription, Elem)>,
+        output: (TensorDescription, Elem),
+        matmul_local: u16,
+        output_local: u16,
+        scalars: Scalars,
+        operators: Vec<Operator>,
+        device: Device<Wgpu<G, F, I>>,
+    ) -> Self {
+        let epilogue = epilogue_source(
+            &inputs.iter().map(|(_, elem)| *elem).collect::<Vec<_>>(),
+            &scalars,
+            &operators,
+            matmul_local,
+            output_local,
+            output.1,
+            // Bound after lhs, rhs, the output and the info buffer, where the layout of the output
+            // follows the ones of lhs and rhs.
+            4,
+            2,
+        );
+        let kernel = Arc::new(MatmulEpilogueKernel::new(
+            IdGenerator::generate(),
+            output.1,
+            epilogue,
+        ));
+
+        Self {
+            lhs,
+            rhs,
+            inputs,
+            output,
+            matmul_local,
+            output_local,
+            scalars,
+            operators,
+            device,
+            kernel,
+        }
+    }
+
+    pub(crate) fn execute(&mut self, context: &mut Context<'_, Wgpu<G, F, I>>) {
+        let client = compute_client::<G>(&self.device);
+
+        let (lhs, lhs_shape) = matmul_input::<G, F, I>(&self.lhs, context);
+        let (rhs, rhs_shape) = matmul_input::<G, F, I>(&self.rhs, context);
+        let output = context.tensors.get(&self.output.0.id).unwrap().clone();
+
+        let num_elems = output.shape.iter().product::<usize>();
+        let output_handle = output_handle(
+            &output.shape,
+            num_elems * core::mem::size_of::<F>(),
+            context,
+            &client,
+            &self.device,
+        );
+
+        let mut info = vec![output.shape.len() as u32];
+        let mut handles = vec![
+            lhs.handle.clone(),
+            rhs.handle.clone(),
+            output_handle.handle.clone(),
+        ];
+        register_info(&mut info, &lhs.strides, &lhs_shape);
+        register_info(&mut info, &rhs.strides, &rhs_shape);
+        register_info(&mut info, &output_handle.strides, &output.shape);
+
+        let mut handles_inputs = Vec::with_capacity(self.inputs.len());
+        for (tensor, _) in self.inputs.iter() {
+            // Important to take the status of the relative graph and not the global graph, since
+            // the status of the global graph might be of a later operation on the same tensor id.
+            let status = &tensor.status;
+            let tensor = context.tensors.get(&tensor.id).unwrap();
+            let handle = context.handles.get_handle(&tensor.id, status);
+
+            register_info(&mut info, &handle.strides, &tensor.shape);
+            handles_inputs.push(handle.handle);
+        }
+
+        // The info buffer is bound before the epilogue inputs.
+        handles.push(client.create(bytemuck::cast_slice(&info)));
+        handles.extend(handles_inputs);
+
+        if self.scalars.num_f32 > 0 {
+            handles.push(client.create(bytemuck::cast_slice(
+                &context.scalar_floats[0..self.scalars.num_f32],
+            )));
+        }
+
+        if self.scalars.num_i32 > 0 {
+            handles.push(client.create(bytemuck::cast_slice(
+                &context.scalar_ints[0..self.scalars.num_i32],
+            )));
+        }
+
+        context
+            .handles
+            .register_handle(output.id.clone(), output_handle);
+
+        client.execute(
+            Box::new(DynamicKernel::new(
+                self.kernel.clone(),
+                workgroup(&output.shape),
+            )),
+            &handles.iter().collect::<Vec<_>>(),
+        );
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        // The matmul is fused with the epilogue operations.
+        self.operators.len() + 1
+    }
+
+    pub(crate) fn from_state(device: &WgpuDevice, state: MatmulEpilogueState) -> Self {
+        Self::new(
+            state.lhs,
+            state.rhs,
+            state.inputs,
+            state.output,
+            state.matmul_local,
+            state.output_local,
+            state.scalars,
+            state.operators,
+            device.clone(),
+        )
+    }
+
+    pub(crate) fn to_state(&self) -> MatmulEpilogueState {
+        MatmulEpilogueState {
+            lhs: self.lhs.clone(),
+            rhs: self.rhs.clone(),
+            inputs: self.inputs.clone(),
+            output: self.output.clone(),
+            matmul_local: self.matmul_local,
+            output_local: self.output_local,
+            scalars: self.scalars.clone(),
+            operators: self.operators.clone(),
+        }
+    }
+}
+
+/// Get the handle of a matmul input, which is made contiguous if a batch dimension has been
+/// swapped with the row or col dimension since the kernel doesn't support it.
+fn matmul_input<G: GraphicsApi, F: FloatElement, I: IntElement>(
+    tensor: &TensorDescription,
+    context: &mut Context<'_, Wgpu<G, F, I>>,
+) -> (WgpuFusionHandle, Vec<usize>) {
+    let status = &tensor.status;
+    let tensor = context.tensors.get(&tensor.id).unwrap();
+    let handle = context.handles.get_handle(&tensor.id, status);
+    let rank = tensor.shape.len();
+
+    let batch_swapped_with_row_col = handle.strides[0..rank - 2]
+        .iter()
+        .any(|stride| *stride < handle.strides[rank - 2] || *stride < handle.strides[rank - 1]);
+
+    if !batch_swapped_with_row_col {
+        return (handle, tensor.shape.clone());
+    }
+
+    let strides = strides_dyn_rank(&tensor.shape);
+    let contiguous = into_contiguous_dyn::<F>(
+        handle.client.clone(),
+        handle.handle,
+        &tensor.shape,
+        &handle.strides,
+        &tensor.shape,
+        &strides,
+        tensor.shape.iter().product(),
+    );
+
+    (
+        WgpuFusionHandle::new(handle.client, contiguous, handle.device, strides),
+        tensor.shape.clone(),
+    )
+}
+
+fn register_info(info: &mut Vec<u32>, strides: &[usize], shape: &[usize]) {
+    info.extend(strides.iter().map(|s| *s as u32));
+    info.extend(shape.iter().map(|s| *s as u32));
+}
+
+fn workgroup(output_shape: &[usize]) -> WorkGroup {
+    let rank = output_shape.len();
+    let num_blocks_x = f32::ceil(output_shape[rank - 2] as f32 / B_M as f32) as u32;
+    let num_blocks_y = f32::ceil(output_shape[rank - 1] as f32 / B_N as f32) as u32;
+    let num_blocks_z = output_shape[0..rank - 2].iter().product::<usize>();
+
+    WorkGroup::new(num_blocks_x, num_blocks_y, num_blocks_z as u32)
+}
+
+#[cfg(test)]
+mod tests {
+    use burn_fusion::Fusion;
+    use burn_tensor::{activation, backend::Backend, Data, Distribution, Tensor};
+
+    type Reference = crate::Wgpu;
+    type Fused = Fusion<crate::Wgpu>;
+
+    #[test]
+    fn test_matmul_bias_relu_same_as_reference() {
+        test_same_as_reference([2, 75, 33], [2, 33, 67], Activation::Relu);
+    }
+
+    #[test]
+    fn test_matmul_bias_gelu_same_as_reference() {
+        test_same_as_reference([1, 8, 16], [1, 16, 8], Activation::Gelu);
+    }
+
+    #[test]
+    fn test_matmul_broadcasted_batch_same_as_reference() {
+        test_same_as_reference([3, 17, 9], [1, 9, 5], Activation::Relu);
+    }
+
+    enum Activation {
+        Relu,
+        Gelu,
+    }
+
+    fn test_same_as_reference(shape_lhs: [usize; 3], shape_rhs: [usize; 3], act: Activation) {
+        let device = Default::default();
+        let lhs = Tensor::<Reference, 3>::random(shape_lhs, Distribution::Default, &device);
+        let rhs = Tensor::<Reference, 3>::random(shape_rhs, Distribution::Default, &device);
+        let bias =
+            Tensor::<Reference, 3>::random([1, 1, shape_rhs[2]], Distribution::Default, &device);
+
+        let data = (lhs.into_data(), rhs.into_data(), bias.into_data());
+        let reference = execute::<Reference>(data.clone(), &act);
+        let fused = execute::<Fused>(data, &act);
+
+        reference.assert_approx_eq(&fused, 3);
+    }
+
+    fn execute<B: Backend>(
+        (lhs, rhs, bias): (Data<f32, 3>, Data<f32, 3>, Data<f32, 3>),
+        act: &Activation,
+    ) -> Data<f32, 3> {
+        let device = Default::default();
+        let lhs = Tensor::<B, 3>::from_data(lhs.convert(), &device);
+        let rhs = Tensor::<B, 3>::from_data(rhs.convert(), &device);
+        let bias = Tensor::<B, 3>::from_data(bias.convert(), &device);
+
+        let output = lhs.matmul(rhs) + bias;
+        let output = match act {
+            Activation::Relu => activation::relu(output),
+            Activation::Gelu => activation::gelu(output),
+        };
+
+        output.into_data().convert()
+    }
+}
@@ -1,8 +1,13 @@
 mod base;
 mod elemwise;
+mod matmul;
+mod reduce;
 
+pub(crate) mod epilogue;
 pub(crate) mod kernel;
 pub(crate) mod source;
 
 pub use base::*;
 pub(crate) use elemwise::*;
+pub(crate) use matmul::*;
+pub(crate) use reduce::*;
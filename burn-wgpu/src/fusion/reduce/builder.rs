@@ -0,0 +1,156 @@
+use super::{kernel::Reduction, ReduceEpilogue};
+use crate::{
+    codegen::Elem,
+    element::WgpuElement,
+    fusion::{ElementWiseBuilder, Scalars, WgpuOptimization},
+    FloatElement, GraphicsApi, IntElement, Wgpu,
+};
+use burn_fusion::{
+    stream::{NumericOpsDescription, ScalarOpsDescription, TensorOpsDescription},
+    OptimizationBuilder, OptimizationProperties, OptimizationStatus, TensorDescription,
+    TensorStatus,
+};
+use burn_tensor::Device;
+
+/// Fuse a reduction along a dimension with the element wise operations applied on its result,
+/// such as the operations of a softmax or a sum followed by a division.
+///
+/// The element wise operations are registered with an [element wise builder](ElementWiseBuilder)
+/// where the result of the reduction is the first local variable.
+pub(crate) struct ReduceEpilogueBuilder<G, F, I>
+where
+    G: GraphicsApi,
+    F: FloatElement,
+    I: IntElement,
+{
+    reduce: Option<(ScalarOpsDescription<usize>, Reduction)>,
+    epilogue: ElementWiseBuilder<G, F, I>,
+    status: OptimizationStatus,
+    device: Device<Wgpu<G, F, I>>,
+}
+
+impl<G, F, I> OptimizationBuilder<Wgpu<G, F, I>> for ReduceEpilogueBuilder<G, F, I>
+where
+    G: GraphicsApi,
+    F: FloatElement,
+    I: IntElement,
+{
+    fn register(&mut self, ops: &TensorOpsDescription) {
+        if let OptimizationStatus::Closed = self.status {
+            return;
+        }
+
+        if self.reduce.is_some() {
+            self.epilogue.register(ops);
+            self.status = self.epilogue.status();
+            return;
+        }
+
+        let reduce = match ops {
+            TensorOpsDescription::NumericOpsFloat(ops) => match ops {
+                NumericOpsDescription::SumDim(desc) => Some((desc, Reduction::Sum)),
+                NumericOpsDescription::MeanDim(desc) => Some((desc, Reduction::Mean)),
+                NumericOpsDescription::MaxDim(desc) => Some((desc, Reduction::Max)),
+                NumericOpsDescription::MinDim(desc) => Some((desc, Reduction::Min)),
+                _ => None,
+            },
+            _ => None,
+        };
+
+        match reduce {
+            Some((desc, reduction)) => {
+                // The output shape of the epilogue is set by its first operation, since it can be
+                // the shape of the reduced tensor or the one of the input.
+                self.epilogue.output_to_var(&desc.out, F::elem_type());
+                self.reduce = Some((desc.clone(), reduction));
+            }
+            None => self.status = OptimizationStatus::Closed,
+        }
+    }
+
+    fn build(&self) -> WgpuOptimization<G, F, I> {
+        let (reduce, reduction) = self.reduce.as_ref().unwrap();
+        let output = self.epilogue_output().unwrap();
+
+        let op = ReduceEpilogue::new(
+            reduce.lhs.clone(),
+            reduce.rhs,
+            *reduction,
+            self.epilogue.input_descriptions(),
+            output.clone(),
+            *self.epilogue.locals.get(&reduce.out.id).unwrap(),
+            *self.epilogue.locals.get(&output.0.id).unwrap(),
+            Scalars::new(
+                self.epilogue.scalars_f32,
+                self.epilogue.scalars_u32,
+                self.epilogue.scalars_i32,
+            ),
+            self.epilogue.operators.clone(),
+            self.device.clone(),
+        );
+
+        WgpuOptimization::ReduceEpilogue(op)
+    }
+
+    fn reset(&mut self) {
+        self.reduce = None;
+        self.epilogue.reset();
+        self.status = OptimizationStatus::Open;
+    }
+
+    fn status(&self) -> OptimizationStatus {
+        self.status
+    }
+
+    fn properties(&self) -> OptimizationProperties {
+        OptimizationProperties {
+            ready: self.epilogue_output().is_some(),
+            score: self.epilogue.operators.len() as u64 + 1,
+        }
+    }
+}
+
+impl<G, F, I> ReduceEpilogueBuilder<G, F, I>
+where
+    G: GraphicsApi,
+    F: FloatElement,
+    I: IntElement,
+{
+    pub fn new(device: Device<Wgpu<G, F, I>>) -> Self {
+        Self {
+            reduce: None,
+            epilogue: ElementWiseBuilder::new(device.clone()),
+            status: OptimizationStatus::Open,
+            device,
+        }
+    }
+
+    /// The tensor written by the fused kernel, if the registered operations can be fused.
+    ///
+    /// The result of the reduction is never written, so it must be consumed by the epilogue which
+    /// must have a single float output with the shape of the reduced tensor or of the input.
+    fn epilogue_output(&self) -> Option<(TensorDescription, Elem)> {
+        let (reduce, _) = self.reduce.as_ref()?;
+
+        if self.epilogue.operators.is_empty() {
+            return None;
+        }
+
+        match self.epilogue.tensors.get(&reduce.out.id) {
+            Some((tensor, _)) if tensor.status == TensorStatus::ReadWrite => {}
+            _ => return None,
+        }
+
+        let mut outputs = self.epilogue.output_descriptions();
+        if outputs.len() != 1 || outputs[0].1 != F::elem_type() {
+            return None;
+        }
+
+        let shape = &outputs[0].0.shape;
+        if *shape != reduce.out.shape && *shape != reduce.lhs.shape {
+            return None;
+        }
+
+        outputs.pop()
+    }
+}
@@ -0,0 +1,76 @@
+use crate::{
+    codegen::Elem,
+    kernel::{DynamicKernelSource, SourceTemplate, StaticKernelSource, WORKGROUP_DEFAULT},
+    kernel_wgsl,
+};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+
+kernel_wgsl!(
+    ReduceDimEpilogueRaw,
+    "../../template/reduction/reduce_dim_epilogue.wgsl"
+);
+
+/// The reduction applied along a dimension before the epilogue.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Reduction {
+    Sum,
+    Mean,
+    Max,
+    Min,
+}
+
+/// Reduction along a dimension calling a generated `epilogue` function on each value written to
+/// the output.
+#[derive(new)]
+pub struct ReduceEpilogueKernel {
+    id: String,
+    elem: Elem,
+    dim: usize,
+    reduction: Reduction,
+    epilogue: String,
+}
+
+impl DynamicKernelSource for Arc<ReduceEpilogueKernel> {
+    fn source(&self) -> SourceTemplate {
+        ReduceDimEpilogueRaw::source()
+            .register("workgroup_size_x", WORKGROUP_DEFAULT.to_string())
+            .register("workgroup_size_y", WORKGROUP_DEFAULT.to_string())
+            .register("elem", self.elem.to_string())
+            .register("dim", self.dim.to_string())
+            .add_template(reduce_source(self.reduction, self.elem))
+            .add_template(self.epilogue.clone())
+    }
+
+    fn id(&self) -> String {
+        self.id.clone()
+    }
+}
+
+/// Generate the `reduce_row` function, reducing the values of a row of the input.
+fn reduce_source(reduction: Reduction, elem: Elem) -> String {
+    let (init, start, accumulate) = match reduction {
+        Reduction::Sum | Reduction::Mean => ("0.0", "0u", "value + current"),
+        Reduction::Max => ("input[offset]", "1u", "max(value, current)"),
+        Reduction::Min => ("input[offset]", "1u", "min(value, current)"),
+    };
+    let finalize = match reduction {
+        Reduction::Mean => format!("value = value / {elem}(shape);\n"),
+        _ => String::new(),
+    };
+
+    format!(
+        "fn reduce_row(offset: u32, stride: u32, shape: u32) -> {elem} {{
+var value = {elem}({init});
+
+for (var i = {start}; i < shape; i++) {{
+    let current = input[offset + i * stride];
+    value = {accumulate};
+}}
+
+{finalize}return value;
+}}
+
+"
+    )
+}
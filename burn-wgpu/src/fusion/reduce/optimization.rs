@@ -0,0 +1,270 @@
+use super::kernel::{ReduceEpilogueKernel, Reduction};
+use crate::{
+    codegen::{Elem, Operator},
+    compute::{compute_client, DynamicKernel},
+    fusion::{epilogue::epilogue_source, kernel::output_handle, Scalars},
+    kernel::{elemwise_workgroup, WORKGROUP_DEFAULT},
+    FloatElement, GraphicsApi, IntElement, Wgpu, WgpuDevice,
+};
+use burn_common::id::IdGenerator;
+use burn_fusion::{stream::Context, TensorDescription};
+use burn_tensor::Device;
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+
+/// Reduction along a dimension followed by element wise operations applied on its result,
+/// executed in one kernel without writing the reduced values to memory.
+///
+/// The output of the element wise operations either has the shape of the reduced tensor, such as
+/// a sum divided by a scalar, or the shape of the input with the reduced value broadcasted along
+/// the reduced dimension, such as the values of a tensor minus their maximum.
+pub struct ReduceEpilogue<G, F, I>
+where
+    G: GraphicsApi,
+    F: FloatElement,
+    I: IntElement,
+{
+    input: TensorDescription,
+    dim: usize,
+    reduction: Reduction,
+    inputs: Vec<(TensorDescription, Elem)>,
+    output: (TensorDescription, Elem),
+    reduce_local: u16,
+    output_local: u16,
+    scalars: Scalars,
+    operators: Vec<Operator>,
+    device: Device<Wgpu<G, F, I>>,
+    kernel: Arc<ReduceEpilogueKernel>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct ReduceEpilogueState {
+    input: TensorDescription,
+    dim: usize,
+    reduction: Reduction,
+    inputs: Vec<(TensorDescription, Elem)>,
+    output: (TensorDescription, Elem),
+    reduce_local: u16,
+    output_local: u16,
+    scalars: Scalars,
+    operators: Vec<Operator>,
+}
+
+impl<G, F, I> ReduceEpilogue<G, F, I>
+where
+    G: GraphicsApi,
+    F: FloatElement,
+    I: IntElement,
+{
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn new(
+        input: TensorDescription,
+        dim: usize,
+        reduction: Reduction,
+        inputs: Vec<(TensorDescription, Elem)>,
+        output: (TensorDescription, Elem),
+        reduce_local: u16,
+        output_local: u16,
+        scalars: Scalars,
+        operators: Vec<Operator>,
+        device: Device<Wgpu<G, F, I>>,
+    ) -> Self {
+        let epilogue = epilogue_source(
+            &inputs.iter().map(|(_, elem)| *elem).collect::<Vec<_>>(),
+            &scalars,
+            &operators,
+            reduce_local,
+            output_local,
+            output.1,
+            // Bound after the input, the output and the info buffer, where the layout of the
+            // output follows the one of the input.
+            3,
+            1,
+        );
+        let kernel = Arc::new(ReduceEpilogueKernel::new(
+            IdGenerator::generate(),
+            output.1,
+            dim,
+            reduction,
+            epilogue,
+        ));
+
+        Self {
+            input,
+            dim,
+            reduction,
+            inputs,
+            output,
+            reduce_local,
+            output_local,
+            scalars,
+            operators,
+            device,
+            kernel,
+        }
+    }
+
+    pub(crate) fn execute(&mut self, context: &mut Context<'_, Wgpu<G, F, I>>) {
+        let client = compute_client::<G>(&self.device);
+
+        // The reduced input is read before the epilogue inputs, which might consume it.
+        let input = context.tensors.get(&self.input.id).unwrap().clone();
+        let input_handle = context.handles.get_handle(&input.id, &self.input.status);
+        let output = context.tensors.get(&self.output.0.id).unwrap().clone();
+
+        let num_elems = output.shape.iter().product::<usize>();
+        let output_handle = output_handle(
+            &output.shape,
+            num_elems * core::mem::size_of::<F>(),
+            context,
+            &client,
+            &self.device,
+        );
+
+        let mut info = vec![output.shape.len() as u32];
+        let mut handles = vec![input_handle.handle.clone(), output_handle.handle.clone()];
+        register_info(&mut info, &input_handle.strides, &input.shape);
+        register_info(&mut info, &output_handle.strides, &output.shape);
+
+        let mut handles_inputs = Vec::with_capacity(self.inputs.len());
+        for (tensor, _) in self.inputs.iter() {
+            // Important to take the status of the relative graph and not the global graph, since
+            // the status of the global graph might be of a later operation on the same tensor id.
+            let status = &tensor.status;
+            let tensor = context.tensors.get(&tensor.id).unwrap();
+            let handle = context.handles.get_handle(&tensor.id, status);
+
+            register_info(&mut info, &handle.strides, &tensor.shape);
+            handles_inputs.push(handle.handle);
+        }
+
+        // The info buffer is bound before the epilogue inputs.
+        handles.push(client.create(bytemuck::cast_slice(&info)));
+        handles.extend(handles_inputs);
+
+        if self.scalars.num_f32 > 0 {
+            handles.push(client.create(bytemuck::cast_slice(
+                &context.scalar_floats[0..self.scalars.num_f32],
+            )));
+        }
+
+        if self.scalars.num_i32 > 0 {
+            handles.push(client.create(bytemuck::cast_slice(
+                &context.scalar_ints[0..self.scalars.num_i32],
+            )));
+        }
+
+        context
+            .handles
+            .register_handle(output.id.clone(), output_handle);
+
+        // One invocation per row of the input along the reduced dimension.
+        let num_rows = input.shape.iter().product::<usize>() / input.shape[self.dim];
+
+        client.execute(
+            Box::new(DynamicKernel::new(
+                self.kernel.clone(),
+                elemwise_workgroup(num_rows, WORKGROUP_DEFAULT),
+            )),
+            &handles.iter().collect::<Vec<_>>(),
+        );
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        // The reduction is fused with the epilogue operations.
+        self.operators.len() + 1
+    }
+
+    pub(crate) fn from_state(device: &WgpuDevice, state: ReduceEpilogueState) -> Self {
+        Self::new(
+            state.input,
+            state.dim,
+            state.reduction,
+            state.inputs,
+            state.output,
+            state.reduce_local,
+            state.output_local,
+            state.scalars,
+            state.operators,
+            device.clone(),
+        )
+    }
+
+    pub(crate) fn to_state(&self) -> ReduceEpilogueState {
+        ReduceEpilogueState {
+            input: self.input.clone(),
+            dim: self.dim,
+            reduction: self.reduction,
+            inputs: self.inputs.clone(),
+            output: self.output.clone(),
+            reduce_local: self.reduce_local,
+            output_local: self.output_local,
+            scalars: self.scalars.clone(),
+            operators: self.operators.clone(),
+        }
+    }
+}
+
+fn register_info(info: &mut Vec<u32>, strides: &[usize], shape: &[usize]) {
+    info.extend(strides.iter().map(|s| *s as u32));
+    info.extend(shape.iter().map(|s| *s as u32));
+}
+
+#[cfg(test)]
+mod tests {
+    use burn_fusion::Fusion;
+    use burn_tensor::{activation, backend::Backend, Data, Distribution, Tensor};
+
+    type Reference = crate::Wgpu;
+    type Fused = Fusion<crate::Wgpu>;
+
+    #[test]
+    fn test_softmax_same_as_reference() {
+        test_same_as_reference([4, 17, 33], Pattern::Softmax(2));
+    }
+
+    #[test]
+    fn test_softmax_inner_dim_same_as_reference() {
+        test_same_as_reference([4, 17, 33], Pattern::Softmax(1));
+    }
+
+    #[test]
+    fn test_sum_div_same_as_reference() {
+        test_same_as_reference([8, 13, 5], Pattern::SumDiv(1));
+    }
+
+    #[test]
+    fn test_mean_dim_add_same_as_reference() {
+        test_same_as_reference([8, 13, 5], Pattern::MeanAdd(0));
+    }
+
+    enum Pattern {
+        Softmax(usize),
+        SumDiv(usize),
+        MeanAdd(usize),
+    }
+
+    fn test_same_as_reference(shape: [usize; 3], pattern: Pattern) {
+        let device = Default::default();
+        let tensor = Tensor::<Reference, 3>::random(shape, Distribution::Default, &device);
+
+        let data = tensor.into_data();
+        let reference = execute::<Reference>(data.clone(), &pattern);
+        let fused = execute::<Fused>(data, &pattern);
+
+        reference.assert_approx_eq(&fused, 3);
+    }
+
+    fn execute<B: Backend>(data: Data<f32, 3>, pattern: &Pattern) -> Data<f32, 3> {
+        let device = Default::default();
+        let tensor = Tensor::<B, 3>::from_data(data.convert(), &device);
+
+        let output = match pattern {
+            Pattern::Softmax(dim) => activation::softmax(tensor, *dim),
+            Pattern::SumDiv(dim) => tensor.sum_dim(*dim) / 4.0,
+            Pattern::MeanAdd(dim) => tensor.mean_dim(*dim).exp() + 1.0,
+        };
+
+        output.into_data().convert()
+    }
+}
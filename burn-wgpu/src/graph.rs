@@ -0,0 +1,66 @@
+use crate::{
+    compute::{compute_client, Server},
+    element::{FloatElement, IntElement},
+    kernel, GraphicsApi, Wgpu, WgpuDevice,
+};
+use burn_compute::server::ComputeServer;
+use burn_tensor::{graph::GraphBackend, ops::FloatTensor};
+
+impl<G: GraphicsApi + 'static, F: FloatElement, I: IntElement> GraphBackend for Wgpu<G, F, I> {
+    type Graph = <Server as ComputeServer>::Graph;
+
+    fn graph_capture_begin(device: &WgpuDevice) {
+        compute_client::<G>(device).capture_begin();
+    }
+
+    fn graph_capture_end(device: &WgpuDevice) -> Self::Graph {
+        compute_client::<G>(device).capture_end()
+    }
+
+    fn graph_replay(device: &WgpuDevice, graph: &Self::Graph) {
+        compute_client::<G>(device).replay(graph);
+    }
+
+    fn graph_write<const D: usize>(target: &FloatTensor<Self, D>, value: FloatTensor<Self, D>) {
+        let ranges = target.shape.dims.map(|dim| 0..dim);
+        kernel::slice_assign_inplace(target, ranges, value);
+    }
+
+    fn graph_read<const D: usize>(tensor: &FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        tensor.copy()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::TestBackend;
+    use burn_tensor::{graph::StaticGraph, Data, Tensor};
+
+    #[test]
+    fn replay_should_compute_with_new_inputs() {
+        let device = Default::default();
+        let weights = Tensor::<TestBackend, 2>::from_floats([[1.0, 2.0], [3.0, 4.0]], &device);
+        let forward = |x: Tensor<TestBackend, 2>| x.matmul(weights.clone()).exp().log() + 1.0;
+
+        let input = Tensor::from_floats([[1.0, 0.0]], &device);
+        forward(input.clone());
+
+        let graph = StaticGraph::capture(vec![input.clone()], |inputs| {
+            vec![forward(inputs[0].clone())]
+        });
+
+        let output_1 = graph.replay(vec![Tensor::from_floats([[0.0, 1.0]], &device)]);
+        let output_2 = graph.replay(vec![Tensor::from_floats([[1.0, 1.0]], &device)]);
+
+        output_1[0]
+            .to_data()
+            .assert_approx_eq(&Data::from([[4.0, 5.0]]), 3);
+        output_2[0]
+            .to_data()
+            .assert_approx_eq(&Data::from([[5.0, 7.0]]), 3);
+        // The input given to the capture is never overwritten.
+        input
+            .into_data()
+            .assert_approx_eq(&Data::from([[1.0, 0.0]]), 3);
+    }
+}
@@ -0,0 +1,318 @@
+use super::{build_info, into_contiguous, DynamicKernelSource, SourceTemplate, WORKGROUP_DEFAULT};
+use crate::{
+    compute::{DynamicKernel, WorkGroup},
+    element::WgpuElement,
+    tensor::WgpuTensor,
+};
+use burn_tensor::Shape;
+use std::marker::PhantomData;
+
+#[cfg(feature = "autodiff")]
+use crate::{FloatElement, GraphicsApi, IntElement, Wgpu};
+#[cfg(feature = "autodiff")]
+use burn_autodiff::Autodiff;
+#[cfg(feature = "autodiff")]
+use burn_tensor::Tensor;
+
+/// A custom compute shader written in WGSL and executed on wgpu tensors.
+///
+/// The source is a [template](SourceTemplate) where the following placeholders are filled
+/// automatically, along with the items added with [register](CustomKernel::register):
+///
+/// - `{{ elem }}`: the element type of the tensors, such as `f32`.
+/// - `{{ int }}`: the integer type, `i32`.
+/// - `{{ workgroup_size_x }}`, `{{ workgroup_size_y }}` and `{{ workgroup_size_z }}`: the
+///   [workgroup size](CustomKernel::with_workgroup_size).
+/// - `{{ workgroup_size }}`: the number of invocations per workgroup.
+///
+/// The buffers are bound to the group 0 in the following order:
+///
+/// 1. The contiguous inputs, from the binding `0` to `N - 1`.
+/// 2. The output, at the binding `N`.
+/// 3. The `array<u32>` of the [rank, strides and shapes](build_info) of the inputs and the output,
+///    at the binding `N + 1`.
+///
+/// # Example
+///
+/// ```rust, ignore
+/// let kernel = CustomKernel::new("square", include_str!("square.wgsl"))
+///     .with_workgroup_size(64, 1, 1);
+/// let output = kernel.execute(&[tensor.clone()], tensor.shape.clone());
+/// ```
+#[derive(Debug, Clone)]
+pub struct CustomKernel {
+    name: String,
+    template: String,
+    items: Vec<(String, String)>,
+    workgroup_size: [usize; 3],
+    workgroup: Option<WorkGroup>,
+}
+
+impl CustomKernel {
+    /// Create a new kernel from its WGSL template.
+    ///
+    /// The name identifies the kernel in the cache of compiled pipelines, so two different kernels
+    /// should never have the same name.
+    pub fn new<Name, Template>(name: Name, template: Template) -> Self
+    where
+        Name: Into<String>,
+        Template: Into<String>,
+    {
+        Self {
+            name: name.into(),
+            template: template.into(),
+            items: Vec::new(),
+            workgroup_size: [WORKGROUP_DEFAULT, WORKGROUP_DEFAULT, 1],
+            workgroup: None,
+        }
+    }
+
+    /// Set the number of invocations of each workgroup on the x, y and z axes.
+    pub fn with_workgroup_size(mut self, x: usize, y: usize, z: usize) -> Self {
+        self.workgroup_size = [x, y, z];
+        self
+    }
+
+    /// Set the number of workgroups to dispatch.
+    ///
+    /// By default, enough workgroups are dispatched to have one invocation per element of the
+    /// output, spread on the x and y axes.
+    pub fn with_workgroup(mut self, workgroup: WorkGroup) -> Self {
+        self.workgroup = Some(workgroup);
+        self
+    }
+
+    /// Register the value of a placeholder of the template.
+    pub fn register<Name, Value>(mut self, name: Name, value: Value) -> Self
+    where
+        Name: Into<String>,
+        Value: Into<String>,
+    {
+        self.items.push((name.into(), value.into()));
+        self
+    }
+
+    /// Execute the kernel lazily on the inputs, returning the output of the given shape.
+    ///
+    /// # Panics
+    ///
+    /// If there is no input, or the inputs aren't on the same device.
+    pub fn execute<E: WgpuElement, const D: usize>(
+        &self,
+        inputs: &[WgpuTensor<E, D>],
+        output_shape: Shape<D>,
+    ) -> WgpuTensor<E, D> {
+        let first = inputs
+            .first()
+            .expect("A custom kernel should have at least one input");
+        inputs
+            .iter()
+            .for_each(|input| first.assert_is_on_same_device(input));
+
+        let inputs = inputs
+            .iter()
+            .map(|input| into_contiguous(input.clone()))
+            .collect::<Vec<_>>();
+        let num_elems = output_shape.num_elements();
+        let handle = first.client.empty(num_elems * core::mem::size_of::<E>());
+        let output = WgpuTensor::new(
+            first.client.clone(),
+            first.device.clone(),
+            output_shape,
+            handle,
+        );
+
+        let tensors = inputs.iter().chain([&output]).collect::<Vec<_>>();
+        let info = build_info(&tensors);
+        let info_handle = first.client.create(bytemuck::cast_slice(&info));
+
+        let workgroup = match &self.workgroup {
+            Some(workgroup) => workgroup.clone(),
+            None => self.default_workgroup(num_elems),
+        };
+        let source = CustomKernelSource::<E> {
+            kernel: self.clone(),
+            _elem: PhantomData,
+        };
+
+        let mut handles = inputs.iter().map(|input| &input.handle).collect::<Vec<_>>();
+        handles.push(&output.handle);
+        handles.push(&info_handle);
+
+        first
+            .client
+            .execute(Box::new(DynamicKernel::new(source, workgroup)), &handles);
+
+        output
+    }
+
+    fn default_workgroup(&self, num_elems: usize) -> WorkGroup {
+        let [x, y, z] = self.workgroup_size;
+        let num_invocations = f32::ceil(num_elems as f32 / (x * y * z) as f32);
+        let workgroup_x = f32::ceil(f32::sqrt(num_invocations));
+        let workgroup_y = f32::ceil(num_invocations / workgroup_x);
+
+        WorkGroup::new(workgroup_x as u32, workgroup_y as u32, 1)
+    }
+}
+
+/// Execute a [custom kernel](CustomKernel) as a differentiable operation of the
+/// [Autodiff](burn_autodiff::Autodiff) backend decorator.
+///
+/// The `backward` closure receives the inputs, the output and the gradient of the output, and
+/// returns the gradient of each input. It can execute other custom kernels or any tensor
+/// operation of the wgpu backend.
+#[cfg(feature = "autodiff")]
+pub fn custom_kernel_autodiff<G, F, I, B, const D: usize, const N: usize>(
+    kernel: CustomKernel,
+    inputs: [Tensor<Autodiff<Wgpu<G, F, I>>, D>; N],
+    output_shape: Shape<D>,
+    backward: B,
+) -> Tensor<Autodiff<Wgpu<G, F, I>>, D>
+where
+    G: GraphicsApi + 'static,
+    F: FloatElement,
+    I: IntElement,
+    B: Fn(
+            &[Tensor<Wgpu<G, F, I>, D>; N],
+            &Tensor<Wgpu<G, F, I>, D>,
+            Tensor<Wgpu<G, F, I>, D>,
+        ) -> [Tensor<Wgpu<G, F, I>, D>; N]
+        + Send
+        + Sync
+        + 'static,
+{
+    burn_autodiff::ops::custom_op(
+        inputs,
+        move |inputs| {
+            let inputs = inputs.map(|tensor| tensor.into_primitive());
+            Tensor::from_primitive(kernel.execute(&inputs, output_shape))
+        },
+        backward,
+    )
+}
+
+struct CustomKernelSource<E> {
+    kernel: CustomKernel,
+    _elem: PhantomData<E>,
+}
+
+impl<E: WgpuElement> DynamicKernelSource for CustomKernelSource<E> {
+    fn source(&self) -> SourceTemplate {
+        let [x, y, z] = self.kernel.workgroup_size;
+        let source = SourceTemplate::new(self.kernel.template.clone())
+            .register("workgroup_size_x", x.to_string())
+            .register("workgroup_size_y", y.to_string())
+            .register("workgroup_size_z", z.to_string())
+            .register("workgroup_size", (x * y * z).to_string())
+            .register("elem", E::type_name())
+            .register("int", "i32");
+
+        self.kernel
+            .items
+            .iter()
+            .fold(source, |source, (name, value)| {
+                source.register(name.as_str(), value.as_str())
+            })
+    }
+
+    fn id(&self) -> String {
+        format!(
+            "custom-{}-{}-{:?}-{:?}",
+            self.kernel.name,
+            E::type_name(),
+            self.kernel.workgroup_size,
+            self.kernel.items
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::TestBackend;
+    use burn_tensor::{Data, Tensor};
+
+    #[cfg(feature = "autodiff")]
+    use burn_autodiff::Autodiff;
+
+    const AXPY: &str = r#"
+@group(0)
+@binding(0)
+var<storage, read> x: array<{{ elem }}>;
+
+@group(0)
+@binding(1)
+var<storage, read> y: array<{{ elem }}>;
+
+@group(0)
+@binding(2)
+var<storage, read_write> output: array<{{ elem }}>;
+
+@group(0)
+@binding(3)
+var<storage, read> info: array<u32>;
+
+@compute
+@workgroup_size({{ workgroup_size_x }}, {{ workgroup_size_y }}, {{ workgroup_size_z }})
+fn main(
+    @builtin(global_invocation_id) global_id: vec3<u32>,
+    @builtin(num_workgroups) num_workgroups: vec3<u32>,
+) {
+    let id = global_id.y * (num_workgroups.x * {{ workgroup_size_x }}u) + global_id.x;
+
+    if id >= arrayLength(&output) {
+        return;
+    }
+
+    output[id] = {{ alpha }} * x[id] + y[id];
+}
+"#;
+
+    #[test]
+    fn should_execute_custom_kernel() {
+        let device = Default::default();
+        let x = Tensor::<TestBackend, 2>::from_floats([[1.0, 2.0], [3.0, 4.0]], &device);
+        let y = Tensor::<TestBackend, 2>::from_floats([[0.5, 0.5], [1.0, 1.0]], &device);
+        let kernel = CustomKernel::new("axpy", AXPY)
+            .with_workgroup_size(16, 1, 1)
+            .register("alpha", "2.0");
+
+        let output = kernel.execute(
+            &[x.into_primitive(), y.into_primitive()],
+            Shape::new([2, 2]),
+        );
+
+        Tensor::<TestBackend, 2>::from_primitive(output)
+            .into_data()
+            .assert_approx_eq(&Data::from([[2.5, 4.5], [7.0, 9.0]]), 3);
+    }
+
+    #[cfg(feature = "autodiff")]
+    #[test]
+    fn should_differentiate_custom_kernel() {
+        let device = Default::default();
+        let x = Tensor::<Autodiff<TestBackend>, 1>::from_floats([1.0, 2.0, 3.0], &device)
+            .require_grad();
+        let y = Tensor::<Autodiff<TestBackend>, 1>::from_floats([1.0, 1.0, 1.0], &device)
+            .require_grad();
+        let kernel = CustomKernel::new("axpy", AXPY).register("alpha", "3.0");
+
+        let output = custom_kernel_autodiff(
+            kernel,
+            [x.clone(), y.clone()],
+            Shape::new([3]),
+            |_, _, grad| [grad.clone().mul_scalar(3.0), grad],
+        );
+        let grads = output.sum().backward();
+
+        x.grad(&grads)
+            .unwrap()
+            .into_data()
+            .assert_approx_eq(&Data::from([3.0, 3.0, 3.0]), 3);
+        y.grad(&grads)
+            .unwrap()
+            .into_data()
+            .assert_approx_eq(&Data::from([1.0, 1.0, 1.0]), 3);
+    }
+}
@@ -66,8 +66,20 @@ pub(crate) fn slice_assign<E: WgpuElement, const D1: usize, const D2: usize>(
         true => tensor,
         false => tensor.copy(),
     };
+
+    slice_assign_inplace(&tensor, indices, value);
+
+    tensor
+}
+
+/// Assign the value to the slice of the tensor, even when the tensor memory is shared.
+pub(crate) fn slice_assign_inplace<E: WgpuElement, const D1: usize, const D2: usize>(
+    tensor: &WgpuTensor<E, D1>,
+    indices: [Range<usize>; D2],
+    value: WgpuTensor<E, D1>,
+) {
     let num_elems = tensor.shape.num_elements();
-    let mut info = build_info(&[&tensor, &value]);
+    let mut info = build_info(&[tensor, &value]);
 
     for i in 0..D1 {
         let start = indices.get(i).map(|index| index.start).unwrap_or(0);
@@ -84,8 +96,6 @@ pub(crate) fn slice_assign<E: WgpuElement, const D1: usize, const D2: usize>(
         Box::new(kernel),
         &[&tensor.handle, &value.handle, &info_handle],
     );
-
-    tensor
 }
 
 #[cfg(test)]
@@ -0,0 +1,70 @@
+use crate::{
+    compute::{StaticKernel, WorkGroup},
+    element::WgpuElement,
+    kernel::{into_contiguous, KernelSettings},
+    kernel_wgsl,
+    ops::numeric::empty_device,
+    tensor::WgpuTensor,
+};
+use burn_tensor::{ops::quantization::INT4_PER_INT, Shape};
+
+kernel_wgsl!(MatmulInt4, "../../template/matmul/int4.wgsl");
+
+const WORKGROUP_SIZE: usize = 16;
+
+/// Matrix multiplication with block-wise 4-bit weights, dequantized on the fly without
+/// materializing the float weight matrix.
+pub fn matmul_int4<E: WgpuElement, I: WgpuElement>(
+    lhs: WgpuTensor<E, 2>,
+    weight: WgpuTensor<I, 2>,
+    scales: WgpuTensor<E, 2>,
+    zero_points: WgpuTensor<E, 2>,
+    block_size: usize,
+) -> WgpuTensor<E, 2> {
+    lhs.assert_is_on_same_device(&scales);
+
+    let lhs = into_contiguous(lhs);
+    let weight = into_contiguous(weight);
+    let scales = into_contiguous(scales);
+    let zero_points = into_contiguous(zero_points);
+
+    let [num_rows, d_input] = lhs.shape.dims;
+    let [num_packed, num_cols] = weight.shape.dims;
+    assert_eq!(
+        num_packed * INT4_PER_INT,
+        d_input,
+        "The packed weights don't match the input size."
+    );
+
+    let output = empty_device(
+        lhs.client.clone(),
+        lhs.device.clone(),
+        Shape::new([num_rows, num_cols]),
+    );
+
+    let workgroup = WorkGroup::new(
+        f32::ceil(num_rows as f32 / WORKGROUP_SIZE as f32) as u32,
+        f32::ceil(num_cols as f32 / WORKGROUP_SIZE as f32) as u32,
+        1,
+    );
+    let kernel = StaticKernel::<
+        KernelSettings<MatmulInt4, E, i32, WORKGROUP_SIZE, WORKGROUP_SIZE, 1>,
+    >::new(workgroup);
+
+    let info = [num_rows, d_input, num_cols, block_size].map(|value| value as u32);
+    let info_handle = lhs.client.create(bytemuck::cast_slice(&info));
+
+    lhs.client.execute(
+        Box::new(kernel),
+        &[
+            &lhs.handle,
+            &weight.handle,
+            &scales.handle,
+            &zero_points.handle,
+            &output.handle,
+            &info_handle,
+        ],
+    );
+
+    output
+}
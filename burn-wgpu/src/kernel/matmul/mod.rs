@@ -1,3 +1,4 @@
+mod int4;
 mod mem_coalescing;
 mod naive;
 mod tiling2d;
@@ -6,6 +7,7 @@ mod tune;
 /// Contains utilitary for matmul operation
 pub mod utils;
 
+pub use int4::*;
 pub use mem_coalescing::*;
 pub use naive::*;
 pub use tiling2d::*;
@@ -1,6 +1,8 @@
 mod base;
 mod padding;
 
+pub(crate) use base::{B_K, B_M, B_N, WORKGROUP_SIZE};
+
 /// WGSL vec4 primitives are used on left and right hand tensor,
 /// padding is avoided through the use of conditions in the kernel
 pub mod unpadded;
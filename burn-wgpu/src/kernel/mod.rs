@@ -4,14 +4,19 @@ mod cast;
 mod cat;
 mod clamp;
 mod comparison;
+mod custom;
 mod index;
 mod mask;
+mod scan;
+mod search;
+mod sort;
 mod source;
 mod unary;
 
 pub use base::*;
 pub use binary::*;
 pub use cast::*;
+pub use custom::*;
 pub use source::*;
 pub use unary::*;
 
@@ -31,3 +36,6 @@ pub(crate) use clamp::*;
 pub(crate) use comparison::*;
 pub(crate) use index::*;
 pub(crate) use mask::*;
+pub(crate) use scan::*;
+pub(crate) use search::*;
+pub(crate) use sort::*;
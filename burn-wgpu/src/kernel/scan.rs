@@ -0,0 +1,120 @@
+use crate::{
+    compute::StaticKernel,
+    element::WgpuElement,
+    kernel::{
+        build_info, elemwise_workgroup, KernelSettings, SourceTemplate, StaticKernelSource,
+        WORKGROUP_DEFAULT,
+    },
+    kernel_wgsl,
+    ops::numeric::empty_device,
+    tensor::WgpuTensor,
+};
+
+kernel_wgsl!(ScanRaw, "../template/scan.wgsl");
+
+pub(crate) struct CumSum;
+pub(crate) struct CumProd;
+
+impl StaticKernelSource for CumSum {
+    fn source() -> SourceTemplate {
+        ScanRaw::source()
+            .register("initial", "0")
+            .register("combine", "accumulator + value")
+    }
+}
+
+impl StaticKernelSource for CumProd {
+    fn source() -> SourceTemplate {
+        ScanRaw::source()
+            .register("initial", "1")
+            .register("combine", "accumulator * value")
+    }
+}
+
+/// Execute the cumulative sum kernel.
+pub(crate) fn cumsum<E: WgpuElement, const D: usize>(
+    input: WgpuTensor<E, D>,
+    dim: usize,
+) -> WgpuTensor<E, D> {
+    scan::<CumSum, E, D>(input, dim)
+}
+
+/// Execute the cumulative product kernel.
+pub(crate) fn cumprod<E: WgpuElement, const D: usize>(
+    input: WgpuTensor<E, D>,
+    dim: usize,
+) -> WgpuTensor<E, D> {
+    scan::<CumProd, E, D>(input, dim)
+}
+
+/// Inclusive scan along a dimension, with one invocation per lane accumulating its elements
+/// sequentially.
+fn scan<K: StaticKernelSource, E: WgpuElement, const D: usize>(
+    input: WgpuTensor<E, D>,
+    dim: usize,
+) -> WgpuTensor<E, D> {
+    let shape = input.shape.clone();
+    let num_lanes = shape.num_elements() / usize::max(shape.dims[dim], 1);
+    let output = empty_device(input.client.clone(), input.device.clone(), shape);
+
+    let mut info = build_info(&[&input, &output]);
+    info.push(dim as u32);
+    info.push(num_lanes as u32);
+    let info_handle = input.client.create(bytemuck::cast_slice(&info));
+
+    let kernel =
+        StaticKernel::<KernelSettings<K, E, i32, WORKGROUP_DEFAULT, WORKGROUP_DEFAULT, 1>>::new(
+            elemwise_workgroup(num_lanes, WORKGROUP_DEFAULT),
+        );
+
+    input.client.execute(
+        Box::new(kernel),
+        &[&input.handle, &output.handle, &info_handle],
+    );
+
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::{ReferenceBackend, TestBackend};
+    use burn_tensor::{backend::Backend, Distribution, Tensor};
+
+    #[test]
+    fn cumsum_should_match_reference_on_every_dim() {
+        for dim in 0..3 {
+            test_cumsum_same_as_ref([4, 300, 3], dim);
+        }
+    }
+
+    #[test]
+    fn cumprod_should_match_reference_with_transposed_input() {
+        TestBackend::seed(0);
+        let tensor =
+            Tensor::<TestBackend, 2>::random([8, 16], Distribution::Default, &Default::default());
+        let tensor_ref =
+            Tensor::<ReferenceBackend, 2>::from_data(tensor.to_data(), &Default::default());
+
+        let output = tensor.transpose().cumprod(1);
+        let output_ref = tensor_ref.transpose().cumprod(1);
+
+        output
+            .into_data()
+            .assert_approx_eq(&output_ref.into_data(), 3);
+    }
+
+    fn test_cumsum_same_as_ref<const D: usize>(shape: [usize; D], dim: usize) {
+        TestBackend::seed(0);
+        let tensor =
+            Tensor::<TestBackend, D>::random(shape, Distribution::Default, &Default::default());
+        let tensor_ref =
+            Tensor::<ReferenceBackend, D>::from_data(tensor.to_data(), &Default::default());
+
+        let output = tensor.cumsum(dim);
+        let output_ref = tensor_ref.cumsum(dim);
+
+        output
+            .into_data()
+            .assert_approx_eq(&output_ref.into_data(), 2);
+    }
+}
@@ -0,0 +1,110 @@
+use crate::{
+    compute::StaticKernel,
+    element::WgpuElement,
+    kernel::{self, elemwise_workgroup, KernelSettings, WORKGROUP_DEFAULT},
+    kernel_wgsl,
+    ops::numeric::empty_device,
+    tensor::WgpuTensor,
+};
+
+kernel_wgsl!(SearchSorted, "../template/search.wgsl");
+
+/// Find the indices where the values should be inserted in the sequences sorted along the last
+/// dimension, with one invocation per value doing a binary search in the sequence of its lane.
+pub(crate) fn searchsorted<E: WgpuElement, I: WgpuElement, const D: usize>(
+    sorted_sequence: WgpuTensor<E, D>,
+    values: WgpuTensor<E, D>,
+    right: bool,
+) -> WgpuTensor<I, D> {
+    let sorted_sequence = kernel::into_contiguous(sorted_sequence);
+    let values = kernel::into_contiguous(values);
+    let shape = values.shape.clone();
+    let num_elems = shape.num_elements();
+    let output = empty_device::<I, D>(values.client.clone(), values.device.clone(), shape);
+
+    let info = [
+        num_elems as u32,
+        sorted_sequence.shape.dims[D - 1] as u32,
+        values.shape.dims[D - 1] as u32,
+        right as u32,
+    ];
+    let info_handle = values.client.create(bytemuck::cast_slice(&info));
+
+    let kernel = StaticKernel::<
+        KernelSettings<SearchSorted, E, i32, WORKGROUP_DEFAULT, WORKGROUP_DEFAULT, 1>,
+    >::new(elemwise_workgroup(num_elems, WORKGROUP_DEFAULT));
+
+    values.client.execute(
+        Box::new(kernel),
+        &[
+            &sorted_sequence.handle,
+            &values.handle,
+            &output.handle,
+            &info_handle,
+        ],
+    );
+
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::{ReferenceBackend, TestBackend};
+    use burn_tensor::{backend::Backend, Distribution, Int, Tensor};
+
+    #[test]
+    fn searchsorted_should_match_reference() {
+        for right in [false, true] {
+            test_same_as_ref([3, 200], [3, 50], right);
+        }
+    }
+
+    #[test]
+    fn searchsorted_should_match_reference_with_duplicates() {
+        TestBackend::seed(0);
+        let device = Default::default();
+        let sorted =
+            Tensor::<TestBackend, 2>::random([4, 64], Distribution::Uniform(0., 8.), &device)
+                .int()
+                .sort(1);
+        let values =
+            Tensor::<TestBackend, 2>::random([4, 16], Distribution::Uniform(-1., 9.), &device)
+                .int();
+        let sorted_ref = Tensor::<ReferenceBackend, 2, Int>::from_data(
+            sorted.to_data().convert(),
+            &Default::default(),
+        );
+        let values_ref = Tensor::<ReferenceBackend, 2, Int>::from_data(
+            values.to_data().convert(),
+            &Default::default(),
+        );
+
+        for right in [false, true] {
+            let output = sorted.clone().searchsorted(values.clone(), right);
+            let output_ref = sorted_ref.clone().searchsorted(values_ref.clone(), right);
+
+            assert_eq!(output.into_data(), output_ref.into_data().convert());
+        }
+    }
+
+    fn test_same_as_ref<const D: usize>(
+        shape_sorted: [usize; D],
+        shape_values: [usize; D],
+        right: bool,
+    ) {
+        TestBackend::seed(0);
+        let device = Default::default();
+        let sorted = Tensor::<TestBackend, D>::random(shape_sorted, Distribution::Default, &device)
+            .sort(D - 1);
+        let values = Tensor::<TestBackend, D>::random(shape_values, Distribution::Default, &device);
+        let sorted_ref =
+            Tensor::<ReferenceBackend, D>::from_data(sorted.to_data(), &Default::default());
+        let values_ref =
+            Tensor::<ReferenceBackend, D>::from_data(values.to_data(), &Default::default());
+
+        let output = sorted.searchsorted(values, right);
+        let output_ref = sorted_ref.searchsorted(values_ref, right);
+
+        assert_eq!(output.into_data(), output_ref.into_data().convert());
+    }
+}
@@ -0,0 +1,132 @@
+use crate::{
+    compute::StaticKernel,
+    element::WgpuElement,
+    kernel::{self, elemwise_workgroup, KernelSettings, WORKGROUP_DEFAULT},
+    kernel_wgsl,
+    ops::{numeric::empty_device, reshape, swap_dims},
+    tensor::WgpuTensor,
+};
+use burn_tensor::Shape;
+
+kernel_wgsl!(BitonicInit, "../template/sort/bitonic_init.wgsl");
+kernel_wgsl!(BitonicStep, "../template/sort/bitonic_step.wgsl");
+
+/// Sort the tensor along the given dimension with a bitonic sorting network, returning the
+/// sorted tensor and the indices of the sorted elements.
+///
+/// Each lane is padded to the next power of two and sorted with one dispatch per step of the
+/// network, which is `log2(n) * (log2(n) + 1) / 2` dispatches for lanes of `n` elements.
+pub(crate) fn sort_with_indices<E: WgpuElement, I: WgpuElement, const D: usize>(
+    tensor: WgpuTensor<E, D>,
+    dim: usize,
+    descending: bool,
+) -> (WgpuTensor<E, D>, WgpuTensor<I, D>) {
+    // The sorted dimension is moved last so that each lane is contiguous.
+    let tensor = kernel::into_contiguous(swap_dims(tensor, dim, D - 1));
+    let shape = tensor.shape.clone();
+    let length = shape.dims[D - 1];
+    let length_padded = length.next_power_of_two();
+    let num_lanes = shape.num_elements() / usize::max(length, 1);
+
+    let shape_padded = Shape::new([num_lanes, length_padded]);
+    let num_elems = shape_padded.num_elements();
+    let values = empty_device::<E, 2>(
+        tensor.client.clone(),
+        tensor.device.clone(),
+        shape_padded.clone(),
+    );
+    let indices = empty_device::<I, 2>(tensor.client.clone(), tensor.device.clone(), shape_padded);
+
+    let info = [num_elems as u32, length as u32, length_padded as u32];
+    let info_handle = tensor.client.create(bytemuck::cast_slice(&info));
+    let kernel = StaticKernel::<
+        KernelSettings<BitonicInit, E, i32, WORKGROUP_DEFAULT, WORKGROUP_DEFAULT, 1>,
+    >::new(elemwise_workgroup(num_elems, WORKGROUP_DEFAULT));
+
+    tensor.client.execute(
+        Box::new(kernel),
+        &[
+            &tensor.handle,
+            &values.handle,
+            &indices.handle,
+            &info_handle,
+        ],
+    );
+
+    let mut block = 2;
+    while block <= length_padded {
+        let mut distance = block / 2;
+
+        while distance > 0 {
+            let info = [
+                num_elems as u32,
+                length as u32,
+                length_padded as u32,
+                descending as u32,
+                block as u32,
+                distance as u32,
+            ];
+            let info_handle = tensor.client.create(bytemuck::cast_slice(&info));
+            let kernel = StaticKernel::<
+                KernelSettings<BitonicStep, E, i32, WORKGROUP_DEFAULT, WORKGROUP_DEFAULT, 1>,
+            >::new(elemwise_workgroup(num_elems, WORKGROUP_DEFAULT));
+
+            tensor.client.execute(
+                Box::new(kernel),
+                &[&values.handle, &indices.handle, &info_handle],
+            );
+
+            distance /= 2;
+        }
+
+        block *= 2;
+    }
+
+    let values = kernel::slice(values, [0..num_lanes, 0..length]);
+    let indices = kernel::slice(indices, [0..num_lanes, 0..length]);
+
+    let values = reshape(values, shape.clone());
+    let indices = reshape(indices, shape);
+
+    (
+        swap_dims(values, dim, D - 1),
+        swap_dims(indices, dim, D - 1),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::{ReferenceBackend, TestBackend};
+    use burn_tensor::{backend::Backend, Distribution, Tensor};
+
+    #[test]
+    fn sort_should_match_reference_with_padded_lanes() {
+        test_same_as_ref([3, 100, 5], 1, false);
+    }
+
+    #[test]
+    fn sort_should_match_reference_descending() {
+        test_same_as_ref([4, 64], 1, true);
+    }
+
+    #[test]
+    fn sort_should_match_reference_on_first_dim() {
+        test_same_as_ref([37, 6], 0, false);
+    }
+
+    fn test_same_as_ref<const D: usize>(shape: [usize; D], dim: usize, descending: bool) {
+        TestBackend::seed(0);
+        let tensor =
+            Tensor::<TestBackend, D>::random(shape, Distribution::Default, &Default::default());
+        let tensor_ref =
+            Tensor::<ReferenceBackend, D>::from_data(tensor.to_data(), &Default::default());
+
+        let (values, indices) = tensor.sort_with_indices(dim, descending);
+        let (values_ref, indices_ref) = tensor_ref.sort_with_indices(dim, descending);
+
+        values
+            .into_data()
+            .assert_approx_eq(&values_ref.into_data(), 3);
+        assert_eq!(indices.into_data(), indices_ref.into_data());
+    }
+}
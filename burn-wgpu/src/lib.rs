@@ -29,6 +29,8 @@ pub use backend::*;
 mod graphics;
 pub use graphics::*;
 
+mod graph;
+
 #[cfg(any(feature = "fusion", test))]
 mod fusion;
 
@@ -1,17 +1,16 @@
 use super::numeric;
 use crate::codegen::{Elem, Item, Operator, Variable};
-#[cfg(not(feature = "autotune"))]
 use crate::kernel::matmul::init_matmul_output;
 #[cfg(feature = "autotune")]
 use crate::kernel::matmul::matmul_autotune;
-#[cfg(not(feature = "autotune"))]
 use crate::kernel::matmul::vec4::matmul_tiling_2d_vec4;
 use crate::kernel::prng::{random_bernoulli, random_normal, random_uniform};
-#[cfg(not(feature = "autotune"))]
 use crate::kernel::reduce::init_reduce_output;
 use crate::kernel::{self, reduce};
 use crate::WgpuDevice;
 use crate::{unary, FloatElement, GraphicsApi, IntElement, Wgpu};
+#[cfg(feature = "autotune")]
+use burn_tensor::backend::is_deterministic;
 use burn_tensor::ops::{
     BoolTensor, Device, FloatElem, FloatTensor, FullPrecisionBackend, IntTensor,
 };
@@ -150,16 +149,14 @@ where
         lhs: FloatTensor<Self, D>,
         rhs: FloatTensor<Self, D>,
     ) -> FloatTensor<Self, D> {
+        // The kernel selected by autotuning may differ between runs.
         #[cfg(feature = "autotune")]
-        {
-            matmul_autotune(lhs, rhs)
+        if !is_deterministic() {
+            return matmul_autotune(lhs, rhs);
         }
 
-        #[cfg(not(feature = "autotune"))]
-        {
-            let out = init_matmul_output(&lhs, &rhs);
-            matmul_tiling_2d_vec4(lhs, rhs, out)
-        }
+        let out = init_matmul_output(&lhs, &rhs);
+        matmul_tiling_2d_vec4(lhs, rhs, out)
     }
 
     fn swap_dims<const D: usize>(
@@ -318,28 +315,22 @@ where
 
     fn sum_dim<const D: usize>(tensor: FloatTensor<Self, D>, dim: usize) -> FloatTensor<Self, D> {
         #[cfg(feature = "autotune")]
-        {
-            reduce::sum_dim_autotune(tensor, dim)
+        if !is_deterministic() {
+            return reduce::sum_dim_autotune(tensor, dim);
         }
 
-        #[cfg(not(feature = "autotune"))]
-        {
-            let output = init_reduce_output(&tensor, dim);
-            reduce::sum_dim(tensor, output, dim)
-        }
+        let output = init_reduce_output(&tensor, dim);
+        reduce::sum_dim(tensor, output, dim)
     }
 
     fn mean_dim<const D: usize>(tensor: FloatTensor<Self, D>, dim: usize) -> FloatTensor<Self, D> {
         #[cfg(feature = "autotune")]
-        {
-            reduce::mean_dim_autotune(tensor, dim)
+        if !is_deterministic() {
+            return reduce::mean_dim_autotune(tensor, dim);
         }
 
-        #[cfg(not(feature = "autotune"))]
-        {
-            let output = init_reduce_output(&tensor, dim);
-            reduce::mean_dim(tensor, output, dim)
-        }
+        let output = init_reduce_output(&tensor, dim);
+        reduce::mean_dim(tensor, output, dim)
     }
 
     fn to_full_precision<const D: usize>(
@@ -509,4 +500,28 @@ where
     ) -> FloatTensor<Self, D> {
         kernel::repeat(tensor, dim, times)
     }
+
+    fn sort_with_indices<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+        descending: bool,
+    ) -> (FloatTensor<Self, D>, IntTensor<Self, D>) {
+        kernel::sort_with_indices(tensor, dim, descending)
+    }
+
+    fn searchsorted<const D: usize>(
+        sorted_sequence: FloatTensor<Self, D>,
+        values: FloatTensor<Self, D>,
+        right: bool,
+    ) -> IntTensor<Self, D> {
+        kernel::searchsorted(sorted_sequence, values, right)
+    }
+
+    fn cumsum<const D: usize>(tensor: FloatTensor<Self, D>, dim: usize) -> FloatTensor<Self, D> {
+        kernel::cumsum(tensor, dim)
+    }
+
+    fn cumprod<const D: usize>(tensor: FloatTensor<Self, D>, dim: usize) -> FloatTensor<Self, D> {
+        kernel::cumprod(tensor, dim)
+    }
 }
@@ -321,4 +321,28 @@ where
     ) -> IntTensor<Self, D> {
         kernel::repeat(tensor, dim, times)
     }
+
+    fn int_sort_with_indices<const D: usize>(
+        tensor: IntTensor<Self, D>,
+        dim: usize,
+        descending: bool,
+    ) -> (IntTensor<Self, D>, IntTensor<Self, D>) {
+        kernel::sort_with_indices(tensor, dim, descending)
+    }
+
+    fn int_searchsorted<const D: usize>(
+        sorted_sequence: IntTensor<Self, D>,
+        values: IntTensor<Self, D>,
+        right: bool,
+    ) -> IntTensor<Self, D> {
+        kernel::searchsorted(sorted_sequence, values, right)
+    }
+
+    fn int_cumsum<const D: usize>(tensor: IntTensor<Self, D>, dim: usize) -> IntTensor<Self, D> {
+        kernel::cumsum(tensor, dim)
+    }
+
+    fn int_cumprod<const D: usize>(tensor: IntTensor<Self, D>, dim: usize) -> IntTensor<Self, D> {
+        kernel::cumprod(tensor, dim)
+    }
 }
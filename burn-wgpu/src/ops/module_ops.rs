@@ -110,4 +110,14 @@ where
     ) -> FloatTensor<Self, 4> {
         kernel::pool::adaptive_avg_pool2d_backward(x, grad)
     }
+
+    fn int4_matmul(
+        lhs: FloatTensor<Self, 2>,
+        weight: IntTensor<Self, 2>,
+        scales: FloatTensor<Self, 2>,
+        zero_points: FloatTensor<Self, 2>,
+        block_size: usize,
+    ) -> FloatTensor<Self, 2> {
+        kernel::matmul::matmul_int4(lhs, weight, scales, zero_points, block_size)
+    }
 }
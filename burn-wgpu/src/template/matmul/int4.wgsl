@@ -0,0 +1,64 @@
+@group(0)
+@binding(0)
+var<storage, read> lhs: array<{{ elem }}>;
+
+@group(0)
+@binding(1)
+var<storage, read> weight: array<{{ int }}>;
+
+@group(0)
+@binding(2)
+var<storage, read> scales: array<{{ elem }}>;
+
+@group(0)
+@binding(3)
+var<storage, read> zero_points: array<{{ elem }}>;
+
+@group(0)
+@binding(4)
+var<storage, read_write> output: array<{{ elem }}>;
+
+@group(0)
+@binding(5)
+var<storage, read> info: array<u32>;
+
+const INT4_PER_INT = 8u;
+
+@compute
+@workgroup_size({{ workgroup_size_x }}, {{ workgroup_size_y }}, 1)
+fn main(
+    @builtin(global_invocation_id) global_id: vec3<u32>
+) {
+    // Indices
+    let row = global_id.x;
+    let col = global_id.y;
+
+    // Basic information
+    let n_rows = info[0];
+    let K = info[1];
+    let n_cols = info[2];
+    let block_size = info[3];
+
+    // Returns if outside the output dimension
+    if row >= n_rows || col >= n_cols {
+        return;
+    }
+
+    // Each packed int holds the 4-bit values of 8 consecutive rows of the weights, which are
+    // dequantized on the fly.
+    var sum = 0.0;
+    for (var p: u32 = 0u; p < K / INT4_PER_INT; p++) {
+        let packed = weight[p * n_cols + col];
+
+        for (var j: u32 = 0u; j < INT4_PER_INT; j++) {
+            let k = p * INT4_PER_INT + j;
+            let block_index = k / block_size * n_cols + col;
+            let value = {{ elem }}((packed >> (4u * j)) & 15);
+            let dequantized = (value - zero_points[block_index]) * scales[block_index];
+
+            sum += lhs[row * K + k] * dequantized;
+        }
+    }
+
+    output[row * n_cols + col] = sum;
+}
@@ -0,0 +1,64 @@
+@group(0)
+@binding(0)
+var<storage, read> input: array<{{ elem }}>;
+
+@group(0)
+@binding(1)
+var<storage, read_write> output: array<{{ elem }}>;
+
+@group(0)
+@binding(2)
+var<storage, read> info: array<u32>;
+
+const WORKGROUP_SIZE_X = {{ workgroup_size_x }}u;
+const DIM_REDUCE = {{ dim }}u;
+
+@compute
+@workgroup_size({{ workgroup_size_x }}, {{ workgroup_size_y }}, 1)
+fn main(
+    @builtin(global_invocation_id) global_id: vec3<u32>,
+    @builtin(num_workgroups) num_workgroups: vec3<u32>,
+) {
+    // Each invocation reduces one row of the input along the reduced dimension.
+    let id = global_id.y * (num_workgroups.x * WORKGROUP_SIZE_X) + global_id.x;
+    let rank: u32 = info[0];
+
+    var remaining = id;
+    var num_rows = 1u;
+    var offset_input = 0u;
+    var offset_output = 0u;
+
+    for (var j = 0u; j < rank; j++) {
+        let i = rank - j;
+        var shape = info[rank + i];
+
+        if i - 1u == DIM_REDUCE {
+            shape = 1u;
+        }
+
+        let coordinate = remaining % shape;
+        remaining = remaining / shape;
+        num_rows *= shape;
+
+        offset_input += coordinate * info[i];
+        offset_output += coordinate * info[2u * rank + i];
+    }
+
+    if id >= num_rows {
+        return;
+    }
+
+    let stride_input = info[DIM_REDUCE + 1u];
+    let shape_input = info[rank + DIM_REDUCE + 1u];
+    let stride_output = info[2u * rank + DIM_REDUCE + 1u];
+    let shape_output = info[3u * rank + DIM_REDUCE + 1u];
+
+    let value = reduce_row(offset_input, stride_input, shape_input);
+
+    // The output either has the shape of the reduced input, or the one of the input with the
+    // reduced value broadcasted along the reduced dimension.
+    for (var i = 0u; i < shape_output; i++) {
+        let position = offset_output + i * stride_output;
+        output[position] = epilogue(position, value);
+    }
+}
@@ -0,0 +1,57 @@
+@group(0)
+@binding(0)
+var<storage, read> input: array<{{ elem }}>;
+
+@group(0)
+@binding(1)
+var<storage, read_write> output: array<{{ elem }}>;
+
+@group(0)
+@binding(2)
+var<storage, read> info: array<u32>;
+
+const WORKGROUP_SIZE_X = {{ workgroup_size_x }}u;
+
+@compute
+@workgroup_size({{ workgroup_size_x }}, {{ workgroup_size_y }}, 1)
+fn main(
+    @builtin(global_invocation_id) global_id: vec3<u32>,
+    @builtin(num_workgroups) num_workgroups: vec3<u32>,
+) {
+    // Each invocation scans a lane of the tensor along the dimension.
+    let id = global_id.y * (num_workgroups.x * WORKGROUP_SIZE_X) + global_id.x;
+    let rank = info[0];
+    let dim = info[4u * rank + 1u];
+    let num_lanes = info[4u * rank + 2u];
+
+    if id >= num_lanes {
+        return;
+    }
+
+    var remaining = id;
+    var offset_input = 0u;
+    var offset_output = 0u;
+
+    for (var i = rank; i >= 1u; i--) {
+        if i - 1u != dim {
+            let shape = info[i + 2u * rank];
+            let index = remaining % shape;
+            remaining = remaining / shape;
+
+            offset_input += index * info[i];
+            offset_output += index * info[i + rank];
+        }
+    }
+
+    let stride_input = info[dim + 1u];
+    let stride_output = info[dim + 1u + rank];
+    let shape_dim = info[dim + 1u + 2u * rank];
+
+    var accumulator = {{ elem }}({{ initial }});
+
+    for (var i = 0u; i < shape_dim; i++) {
+        let value = input[offset_input + i * stride_input];
+        accumulator = {{ combine }};
+        output[offset_output + i * stride_output] = accumulator;
+    }
+}
@@ -0,0 +1,54 @@
+@group(0)
+@binding(0)
+var<storage, read> sorted_sequence: array<{{ elem }}>;
+
+@group(0)
+@binding(1)
+var<storage, read> values: array<{{ elem }}>;
+
+@group(0)
+@binding(2)
+var<storage, read_write> output: array<{{ int }}>;
+
+@group(0)
+@binding(3)
+var<storage, read> info: array<u32>;
+
+const WORKGROUP_SIZE_X = {{ workgroup_size_x }}u;
+
+@compute
+@workgroup_size({{ workgroup_size_x }}, {{ workgroup_size_y }}, 1)
+fn main(
+    @builtin(global_invocation_id) global_id: vec3<u32>,
+    @builtin(num_workgroups) num_workgroups: vec3<u32>,
+) {
+    // Each invocation searches a value in the sequence of its lane.
+    let id = global_id.y * (num_workgroups.x * WORKGROUP_SIZE_X) + global_id.x;
+    let num_elems = info[0];
+    let length = info[1];
+    let num_values = info[2];
+    let right = info[3];
+
+    if id >= num_elems {
+        return;
+    }
+
+    let offset = (id / num_values) * length;
+    let value = values[id];
+
+    var low = 0u;
+    var high = length;
+
+    while low < high {
+        let middle = (low + high) / 2u;
+        let element = sorted_sequence[offset + middle];
+
+        if select(element < value, element <= value, right == 1u) {
+            low = middle + 1u;
+        } else {
+            high = middle;
+        }
+    }
+
+    output[id] = {{ int }}(low);
+}
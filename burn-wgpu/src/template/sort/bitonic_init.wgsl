@@ -0,0 +1,43 @@
+@group(0)
+@binding(0)
+var<storage, read> input: array<{{ elem }}>;
+
+@group(0)
+@binding(1)
+var<storage, read_write> values: array<{{ elem }}>;
+
+@group(0)
+@binding(2)
+var<storage, read_write> indices: array<{{ int }}>;
+
+@group(0)
+@binding(3)
+var<storage, read> info: array<u32>;
+
+const WORKGROUP_SIZE_X = {{ workgroup_size_x }}u;
+
+@compute
+@workgroup_size({{ workgroup_size_x }}, {{ workgroup_size_y }}, 1)
+fn main(
+    @builtin(global_invocation_id) global_id: vec3<u32>,
+    @builtin(num_workgroups) num_workgroups: vec3<u32>,
+) {
+    let id = global_id.y * (num_workgroups.x * WORKGROUP_SIZE_X) + global_id.x;
+    let num_elems = info[0];
+    let length = info[1];
+    let length_padded = info[2];
+
+    if id >= num_elems {
+        return;
+    }
+
+    let lane = id / length_padded;
+    let position = id % length_padded;
+
+    // The padding is never compared by value, its index is enough to sort it last.
+    if position < length {
+        values[id] = input[lane * length + position];
+    }
+
+    indices[id] = {{ int }}(position);
+}
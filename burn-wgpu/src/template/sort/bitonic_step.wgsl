@@ -0,0 +1,97 @@
+@group(0)
+@binding(0)
+var<storage, read_write> values: array<{{ elem }}>;
+
+@group(0)
+@binding(1)
+var<storage, read_write> indices: array<{{ int }}>;
+
+@group(0)
+@binding(2)
+var<storage, read> info: array<u32>;
+
+const WORKGROUP_SIZE_X = {{ workgroup_size_x }}u;
+
+@compute
+@workgroup_size({{ workgroup_size_x }}, {{ workgroup_size_y }}, 1)
+fn main(
+    @builtin(global_invocation_id) global_id: vec3<u32>,
+    @builtin(num_workgroups) num_workgroups: vec3<u32>,
+) {
+    let id = global_id.y * (num_workgroups.x * WORKGROUP_SIZE_X) + global_id.x;
+    let num_elems = info[0];
+    let length = info[1];
+    let length_padded = info[2];
+    let descending = info[3] == 1u;
+    let block = info[4];
+    let distance = info[5];
+
+    if id >= num_elems {
+        return;
+    }
+
+    let position = id % length_padded;
+    let position_partner = position ^ distance;
+
+    // Each pair is compared by the invocation of its first element.
+    if position_partner <= position {
+        return;
+    }
+
+    let id_partner = id - position + position_partner;
+    let ascending = (position & block) == 0u;
+
+    let value = values[id];
+    let index = indices[id];
+    let value_partner = values[id_partner];
+    let index_partner = indices[id_partner];
+
+    let partner_first = is_before(
+        value_partner,
+        u32(index_partner),
+        value,
+        u32(index),
+        length,
+        descending,
+    );
+
+    if partner_first == ascending {
+        values[id] = value_partner;
+        indices[id] = index_partner;
+        values[id_partner] = value;
+        indices[id_partner] = index;
+    }
+}
+
+fn is_before(
+    value_lhs: {{ elem }},
+    index_lhs: u32,
+    value_rhs: {{ elem }},
+    index_rhs: u32,
+    length: u32,
+    descending: bool,
+) -> bool {
+    // The padding is always last, and equal values keep the order of their indices so the sort
+    // is stable.
+    if index_lhs >= length || index_rhs >= length || value_lhs == value_rhs {
+        return index_lhs < index_rhs;
+    }
+
+    // NaN values are greater than every other value, and are only equal to other NaN values.
+    let lhs_nan = value_lhs != value_lhs;
+    let rhs_nan = value_rhs != value_rhs;
+
+    if lhs_nan && rhs_nan {
+        return index_lhs < index_rhs;
+    }
+
+    if lhs_nan || rhs_nan {
+        return select(rhs_nan, lhs_nan, descending);
+    }
+
+    if descending {
+        return value_lhs > value_rhs;
+    }
+
+    return value_lhs < value_rhs;
+}
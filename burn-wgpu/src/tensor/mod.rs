@@ -1,2 +1,5 @@
 mod base;
+mod readback;
+
 pub use base::*;
+pub use readback::*;
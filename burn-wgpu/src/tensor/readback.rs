@@ -0,0 +1,98 @@
+use crate::{
+    element::{FloatElement, IntElement, WgpuElement},
+    kernel, GraphicsApi, Wgpu,
+};
+use burn_tensor::{Data, Tensor};
+
+/// Read the data of a float tensor without blocking the calling thread while the device completes
+/// the pending tasks, unlike [into_data](Tensor::into_data).
+///
+/// # Example
+///
+/// ```rust, ignore
+/// let output = model.forward(input);
+/// let data = into_data_async(output).await;
+/// ```
+pub async fn into_data_async<G, F, I, const D: usize>(
+    tensor: Tensor<Wgpu<G, F, I>, D>,
+) -> Data<F, D>
+where
+    G: GraphicsApi + 'static,
+    F: FloatElement,
+    I: IntElement,
+{
+    read_back_many(vec![tensor]).await.remove(0)
+}
+
+/// Read the data of many float tensors asynchronously, in the same order.
+///
+/// The tensors of each device are copied with a single submission, which is faster than reading
+/// them one by one.
+pub async fn read_back_many<G, F, I, const D: usize>(
+    tensors: Vec<Tensor<Wgpu<G, F, I>, D>>,
+) -> Vec<Data<F, D>>
+where
+    G: GraphicsApi + 'static,
+    F: FloatElement,
+    I: IntElement,
+{
+    let tensors = tensors
+        .into_iter()
+        .map(|tensor| kernel::into_contiguous(tensor.into_primitive()))
+        .collect::<Vec<_>>();
+
+    // Every read is scheduled before awaiting, so the devices work on them concurrently.
+    let mut batches = Vec::new();
+    let mut scheduled = vec![false; tensors.len()];
+
+    for (i, tensor) in tensors.iter().enumerate() {
+        if scheduled[i] {
+            continue;
+        }
+
+        let indices = (i..tensors.len())
+            .filter(|j| tensors[*j].device == tensor.device)
+            .collect::<Vec<_>>();
+        let handles = indices
+            .iter()
+            .map(|j| &tensors[*j].handle)
+            .collect::<Vec<_>>();
+        indices.iter().for_each(|j| scheduled[*j] = true);
+
+        batches.push((indices, tensor.client.read_async(&handles)));
+    }
+
+    let mut data = tensors.iter().map(|_| None).collect::<Vec<_>>();
+
+    for (indices, future) in batches {
+        for (i, bytes) in indices.into_iter().zip(future.await) {
+            data[i] = Some(Data::new(
+                F::from_bytes(&bytes).to_vec(),
+                tensors[i].shape.clone(),
+            ));
+        }
+    }
+
+    data.into_iter()
+        .map(|data| data.expect("Every tensor should be read"))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::TestBackend;
+
+    #[test]
+    fn should_read_back_many_tensors_in_order() {
+        let device = Default::default();
+        let tensor_1 = Tensor::<TestBackend, 2>::from_floats([[1.0, 2.0], [3.0, 4.0]], &device);
+        let tensor_2 = Tensor::<TestBackend, 2>::from_floats([[5.0, 6.0]], &device);
+        let expected = vec![tensor_1.to_data(), tensor_2.to_data()];
+
+        let data = pollster::block_on(read_back_many(vec![tensor_1, tensor_2.transpose()]));
+
+        assert_eq!(data[0], expected[0]);
+        assert_eq!(data[1], Data::from([[5.0], [6.0]]));
+    }
+}
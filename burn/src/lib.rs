@@ -56,6 +56,8 @@
 //!   - `train`: Enables features `dataset` and `autodiff` and provides a training environment
 //!   - `tui`: Includes Text UI with progress bar and plots
 //!   - `metrics`: Includes system info metrics (CPU/GPU usage, etc.)
+//! - Serving
+//!   - `serve`: Provides a model server with dynamic batching behind an HTTP endpoint
 //! - Dataset
 //!   - `dataset`: Includes a datasets library
 //!   - `audio`: Enables audio datasets (SpeechCommandsDataset)
@@ -87,3 +89,9 @@ pub use burn_core::*;
 pub mod train {
     pub use burn_train::*;
 }
+
+/// Serve module
+#[cfg(feature = "serve")]
+pub mod serve {
+    pub use burn_serve::*;
+}
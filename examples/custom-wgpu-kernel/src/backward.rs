@@ -23,7 +23,7 @@ impl<B: Backend> Backend for Autodiff<B> {
         bias: FloatTensor<Self, D>,
     ) -> FloatTensor<Self, D> {
         // Create our zero-sized type that will implement the Backward trait.
-        #[derive(Debug)]
+        #[derive(Debug, Clone)]
         struct FusedMatmulAddReluBackward<const D: usize>;
 
         // Implement the backward trait for the given backend B, the node gradient being of rank D
@@ -10,7 +10,10 @@ use burn::backend::wgpu::{compute::init_async, AutoGraphicsApi, Wgpu, WgpuDevice
 #[cfg(feature = "wgpu")]
 pub type Backend = Wgpu<AutoGraphicsApi, f32, i32>;
 
-#[cfg(all(feature = "ndarray", not(feature = "wgpu")))]
+#[cfg(all(feature = "candle", not(feature = "wgpu")))]
+pub type Backend = burn::backend::candle::Candle<f32, i64>;
+
+#[cfg(all(feature = "ndarray", not(feature = "wgpu"), not(feature = "candle")))]
 pub type Backend = burn::backend::ndarray::NdArray<f32>;
 
 static STATE_ENCODED: &[u8] = include_bytes!("../model.bin");
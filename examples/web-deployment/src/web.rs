@@ -0,0 +1,45 @@
+use crate::model::Model;
+use burn::{
+    backend::wgpu::{AutoGraphicsApi, Wgpu, WgpuDevice},
+    tensor::{activation::softmax, Tensor},
+};
+use burn_web::{device::init_device, load_module, JsTensor, WebError};
+use wasm_bindgen::prelude::*;
+
+type Backend = Wgpu<AutoGraphicsApi, f32, i32>;
+
+#[wasm_bindgen(start)]
+pub fn start() {
+    console_error_panic_hook::set_once();
+}
+
+/// Digit classifier exposed to JavaScript.
+#[wasm_bindgen]
+pub struct Classifier {
+    model: Model<Backend>,
+    device: WgpuDevice,
+}
+
+#[wasm_bindgen]
+impl Classifier {
+    /// Initialize the WebGPU device and download the weights of the model.
+    ///
+    /// Called from JavaScript with `await Classifier.load("model.bin")`.
+    pub async fn load(url: &str) -> Result<Classifier, WebError> {
+        let device = init_device().await;
+        let model = load_module(Model::new(&device), url, &device).await?;
+
+        Ok(Self { model, device })
+    }
+
+    /// Returns the probability of each digit for a batch of 28x28 grayscale images with values
+    /// between 0 and 255.
+    pub async fn classify(&self, images: JsTensor) -> Result<JsTensor, WebError> {
+        let images: Tensor<Backend, 3> = images.into_tensor(&self.device)?;
+        let images = ((images / 255) - 0.1307) / 0.3081;
+
+        let output = softmax(self.model.forward(images), 1);
+
+        Ok(JsTensor::from_tensor(output).await)
+    }
+}
@@ -0,0 +1,75 @@
+//! Run the benchmarks of the `backend-comparison` crate on multiple backends with one command.
+//!
+//! The results of each benchmark are saved as JSON in `~/.cache/burn/backend-comparison`, with
+//! the same structure for every backend so they can be compared.
+
+use crate::logging::init_logger;
+use crate::utils::format_duration;
+use crate::{endgroup, group};
+use std::process::{Command, Stdio};
+use std::time::Instant;
+
+/// The benchmarks of the `backend-comparison` crate.
+const BENCHES: [&str; 9] = [
+    "unary",
+    "binary",
+    "matmul",
+    "data",
+    "custom_gelu",
+    "conv2d",
+    "attention",
+    "mlp",
+    "resnet_block",
+];
+
+pub(crate) fn run(backends: Vec<String>, benches: Vec<String>) -> anyhow::Result<()> {
+    init_logger().init();
+
+    let benches = match benches.is_empty() {
+        true => BENCHES.iter().map(|bench| bench.to_string()).collect(),
+        false => benches,
+    };
+
+    if let Some(bench) = benches
+        .iter()
+        .find(|bench| !BENCHES.contains(&bench.as_str()))
+    {
+        anyhow::bail!("Unknown benchmark {bench}, expected one of {BENCHES:?}");
+    }
+
+    let start = Instant::now();
+
+    for backend in backends.iter() {
+        for bench in benches.iter() {
+            group!("Bench: {} ({})", bench, backend);
+            let args = [
+                "bench",
+                "-p",
+                "backend-comparison",
+                "--bench",
+                bench,
+                "--features",
+                backend,
+            ];
+            info!("cargo {}\n", args.join(" "));
+
+            let status = Command::new("cargo")
+                .args(args)
+                .stdout(Stdio::inherit())
+                .stderr(Stdio::inherit())
+                .status()?;
+            endgroup!();
+
+            if !status.success() {
+                anyhow::bail!("Benchmark {bench} failed on backend {backend}");
+            }
+        }
+    }
+
+    info!(
+        "Benchmarks completed in {}, results saved in ~/.cache/burn/backend-comparison",
+        format_duration(&start.elapsed())
+    );
+
+    Ok(())
+}
@@ -1,5 +1,6 @@
 use clap::{Parser, Subcommand};
 
+mod bench;
 mod logging;
 mod publish;
 mod runchecks;
@@ -17,6 +18,16 @@ struct Args {
 
 #[derive(Subcommand)]
 enum Command {
+    /// Run the benchmarks of `backend-comparison` on the given backends.
+    Bench {
+        /// The backends to benchmark, using the feature names of `backend-comparison`
+        /// (e.g. ndarray, wgpu, tch-cpu).
+        #[arg(short, long, value_delimiter = ',', required = true)]
+        backends: Vec<String>,
+        /// The benchmarks to run, all of them when not specified.
+        #[arg(long, value_delimiter = ',')]
+        benches: Vec<String>,
+    },
     /// Publish a crate to crates.io
     Publish {
         /// The name of the crate to publish on crates.io
@@ -33,6 +44,7 @@ fn main() -> anyhow::Result<()> {
     let args = Args::parse();
 
     match args.command {
+        Command::Bench { backends, benches } => bench::run(backends, benches),
         Command::RunChecks { env } => runchecks::run(env),
         Command::Publish { name } => publish::run(name),
     }
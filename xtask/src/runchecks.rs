@@ -390,6 +390,25 @@ fn check_examples() {
         );
         endgroup!();
     }
+
+    // Build the web example with the candle backend, which has its own random and record loading
+    // paths on wasm targets.
+    group!("Checks: Example - mnist-inference-web (candle, wasm)");
+    rustup("target", WASM32_TARGET);
+    run_cargo_with_path(
+        "build",
+        [
+            "--no-default-features",
+            "--features",
+            "candle",
+            "--target",
+            WASM32_TARGET,
+        ]
+        .into(),
+        Some("examples/mnist-inference-web"),
+        "Failed to build the candle wasm example",
+    );
+    endgroup!();
 }
 
 #[derive(clap::ValueEnum, Default, Copy, Clone, PartialEq, Eq)]